@@ -58,6 +58,20 @@ pub struct BehaviourShutdownFailed {
     // TODO: more detailed reasons
 }
 
+#[derive(Debug, Error)]
+#[error("Failed to import the state of the behaviour: {0}")]
+pub struct BehaviourStateImportFailed(pub String);
+
+#[derive(Debug, Error)]
+pub enum BehaviourHandoverError {
+    #[error("The reactive instance has no behaviour {0} which could be handed over!")]
+    BehaviourNotFound(BehaviourTypeId),
+    #[error("Failed to disconnect the previous behaviour: {0}")]
+    DisconnectPreviousBehaviourFailed(BehaviourTransitionError),
+    #[error("Failed to create the new behaviour: {0}")]
+    BehaviourCreationError(#[from] BehaviourCreationError),
+}
+
 #[derive(Debug, Error)]
 pub enum BehaviourInvalid {
     #[error("The behaviour is invalid because one or multiple properties are invalid: {0}")]
@@ -1,7 +1,10 @@
+use serde_json::Value;
+
 use reactive_graph_reactive_model_api::ReactiveInstance;
 use reactive_graph_reactive_model_api::ReactiveInstanceContainer;
 
 use crate::BehaviourState;
+use crate::BehaviourStateImportFailed;
 use crate::BehaviourTransitionError;
 use crate::BehaviourTransitions;
 use crate::BehaviourTypeId;
@@ -24,6 +27,16 @@ pub trait BehaviourFsm<ID: Clone, T: ReactiveInstance<ID> + BehaviourTypesContai
     /// Returns the validator.
     fn get_transitions(&self) -> &dyn BehaviourTransitions<ID, T>;
 
+    /// Exports the internal state of the behaviour.
+    fn export_state(&self) -> Option<Value> {
+        self.get_transitions().export_state()
+    }
+
+    /// Imports the internal state of a previous version of the behaviour.
+    fn import_state(&self, state: Value) -> Result<(), BehaviourStateImportFailed> {
+        self.get_transitions().import_state(state)
+    }
+
     /// Executes a behaviour transition.
     fn transition(&self, target_state: BehaviourState) -> Result<(), BehaviourTransitionError> {
         match self.get_state() {
@@ -47,6 +60,7 @@ pub trait BehaviourFsm<ID: Clone, T: ReactiveInstance<ID> + BehaviourTypesContai
                         .map(|_| self.set_state(target_state))
                         .map_err(BehaviourTransitionError::BehaviourConnectFailed)
                 }),
+                BehaviourState::Failed => Err(BehaviourTransitionError::InvalidTransition),
             },
             BehaviourState::Valid => match target_state {
                 BehaviourState::Created => Err(BehaviourTransitionError::InvalidTransition),
@@ -63,6 +77,7 @@ pub trait BehaviourFsm<ID: Clone, T: ReactiveInstance<ID> + BehaviourTypesContai
                         .map(|_| self.set_state(target_state))
                         .map_err(BehaviourTransitionError::BehaviourConnectFailed)
                 }),
+                BehaviourState::Failed => Err(BehaviourTransitionError::InvalidTransition),
             },
             BehaviourState::Ready => match target_state {
                 BehaviourState::Created => Err(BehaviourTransitionError::InvalidTransition),
@@ -74,6 +89,7 @@ pub trait BehaviourFsm<ID: Clone, T: ReactiveInstance<ID> + BehaviourTypesContai
                     .map(|_| self.get_reactive_instance().add_behaviour(self.ty().clone()))
                     .map(|_| self.set_state(target_state))
                     .map_err(BehaviourTransitionError::BehaviourConnectFailed),
+                BehaviourState::Failed => Err(BehaviourTransitionError::InvalidTransition),
             },
             BehaviourState::Connected => match target_state {
                 BehaviourState::Created => Err(BehaviourTransitionError::InvalidTransition),
@@ -85,6 +101,28 @@ pub trait BehaviourFsm<ID: Clone, T: ReactiveInstance<ID> + BehaviourTypesContai
                     .map(|_| self.set_state(target_state))
                     .map_err(BehaviourTransitionError::BehaviourDisconnectFailed),
                 BehaviourState::Connected => Err(BehaviourTransitionError::InvalidTransition),
+                // The reactive streams are left untouched, because the behaviour may not be reachable anymore.
+                BehaviourState::Failed => {
+                    self.set_state(target_state);
+                    Ok(())
+                }
+            },
+            BehaviourState::Failed => match target_state {
+                BehaviourState::Created => Err(BehaviourTransitionError::InvalidTransition),
+                BehaviourState::Valid => Err(BehaviourTransitionError::InvalidTransition),
+                BehaviourState::Ready => self
+                    .get_transitions()
+                    .disconnect()
+                    .map(|_| self.get_reactive_instance().remove_behaviour(self.ty()))
+                    .map(|_| self.set_state(target_state))
+                    .map_err(BehaviourTransitionError::BehaviourDisconnectFailed),
+                BehaviourState::Connected => self
+                    .get_transitions()
+                    .connect()
+                    .map(|_| self.get_reactive_instance().add_behaviour(self.ty().clone()))
+                    .map(|_| self.set_state(target_state))
+                    .map_err(BehaviourTransitionError::BehaviourConnectFailed),
+                BehaviourState::Failed => Err(BehaviourTransitionError::InvalidTransition),
             },
         }
     }
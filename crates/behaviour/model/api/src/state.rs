@@ -12,4 +12,7 @@ pub enum BehaviourState {
 
     /// The behaviour is connected.
     Connected,
+
+    /// The behaviour has failed, for example because the plugin providing the behaviour is not available.
+    Failed,
 }
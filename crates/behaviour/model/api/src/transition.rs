@@ -1,3 +1,5 @@
+use serde_json::Value;
+
 use reactive_graph_reactive_model_api::ReactiveInstance;
 use reactive_graph_reactive_model_api::ReactiveInstanceContainer;
 
@@ -6,6 +8,7 @@ use crate::BehaviourDisconnectFailed;
 use crate::BehaviourInitializationFailed;
 use crate::BehaviourReconnectFailed;
 use crate::BehaviourShutdownFailed;
+use crate::BehaviourStateImportFailed;
 use crate::BehaviourTypesContainer;
 
 #[allow(drop_bounds)]
@@ -18,6 +21,19 @@ pub trait BehaviourTransitions<ID: Clone, T: ReactiveInstance<ID> + BehaviourTyp
         self.connect().map_err(BehaviourReconnectFailed::BehaviourConnectFailed)?;
         Ok(())
     }
+
+    /// Exports the internal state of the behaviour (for example counters or timers), so that it
+    /// can be handed over to a newer version of the behaviour. Stateless behaviours return None.
+    fn export_state(&self) -> Option<Value> {
+        None
+    }
+
+    /// Imports the internal state which has been exported by a previous version of the behaviour.
+    ///
+    /// The state is imported after the behaviour has been connected.
+    fn import_state(&self, _state: Value) -> Result<(), BehaviourStateImportFailed> {
+        Ok(())
+    }
 }
 
 pub trait BehaviourInit<ID: Clone, T: ReactiveInstance<ID>> {
@@ -1,7 +1,96 @@
+use std::hash::Hash;
 use std::sync::Arc;
 
 use dashmap::DashMap;
+use log::warn;
 
 use reactive_graph_behaviour_model_api::prelude::*;
+use reactive_graph_reactive_model_api::ReactiveInstance;
 
 pub type BehaviourStorage<ID, T> = DashMap<ID, DashMap<BehaviourTypeId, Arc<dyn BehaviourFsm<ID, T> + Send + Sync>>>;
+
+/// Replaces the behaviour of the given reactive instance by a behaviour created by the given
+/// factory and hands over the exported state of the previous behaviour to the new behaviour.
+///
+/// The previous behaviour is taken out of the storage during the handover, so that no guard of
+/// the storage is held while the behaviours connect and disconnect their observers, which may
+/// access the storage. A concurrent handover of the same behaviour doesn't find the behaviour.
+/// If the new behaviour can't be created, the previous behaviour is connected again.
+pub fn handover_behaviour<ID, T>(
+    storage: &BehaviourStorage<ID, T>,
+    key: &ID,
+    ty: &BehaviourTypeId,
+    factory: &(dyn BehaviourFactory<ID, T> + Send + Sync),
+) -> Result<(), BehaviourHandoverError>
+where
+    ID: Clone + Eq + Hash,
+    T: ReactiveInstance<ID> + BehaviourTypesContainer,
+{
+    let Some((_, previous)) = storage.get(key).and_then(|instance_behaviours| instance_behaviours.remove(ty)) else {
+        return Err(BehaviourHandoverError::BehaviourNotFound(ty.clone()));
+    };
+    let state = previous.export_state();
+    if let Err(e) = previous.transition(BehaviourState::Ready) {
+        insert_behaviour(storage, key, ty, previous);
+        return Err(BehaviourHandoverError::DisconnectPreviousBehaviourFailed(e));
+    }
+    let behaviour = match factory.create(previous.get_reactive_instance().clone()) {
+        Ok(behaviour) => behaviour,
+        Err(e) => {
+            if let Err(e) = previous.transition(BehaviourState::Connected) {
+                warn!("Failed to reconnect the previous behaviour {ty} of {}: {e}", previous.get_reactive_instance());
+            }
+            insert_behaviour(storage, key, ty, previous);
+            return Err(e.into());
+        }
+    };
+    if let Some(state) = state {
+        if let Err(e) = behaviour.import_state(state) {
+            warn!("The state of the behaviour {ty} of {} has been lost: {e}", behaviour.get_reactive_instance());
+        }
+    }
+    let inserted = insert_behaviour(storage, key, ty, behaviour);
+    // Dropping the previous behaviour removes the behaviour type from the reactive instance.
+    let reactive_instance = previous.get_reactive_instance().clone();
+    drop(previous);
+    if inserted {
+        reactive_instance.add_behaviour(ty.clone());
+    }
+    Ok(())
+}
+
+/// Puts the behaviour back into the storage. The behaviour is dropped if the behaviours of the
+/// reactive instance have been removed in the meantime.
+fn insert_behaviour<ID, T>(storage: &BehaviourStorage<ID, T>, key: &ID, ty: &BehaviourTypeId, behaviour: Arc<dyn BehaviourFsm<ID, T> + Send + Sync>) -> bool
+where
+    ID: Clone + Eq + Hash,
+    T: ReactiveInstance<ID> + BehaviourTypesContainer,
+{
+    let Some(instance_behaviours) = storage.get(key) else {
+        return false;
+    };
+    instance_behaviours.insert(ty.clone(), behaviour);
+    true
+}
+
+/// Hands over all behaviours of the given behaviour type to behaviours created by the given
+/// factory. Returns the number of reactive instances whose behaviour has been handed over.
+pub fn handover_behaviours<ID, T>(storage: &BehaviourStorage<ID, T>, ty: &BehaviourTypeId, factory: &(dyn BehaviourFactory<ID, T> + Send + Sync)) -> usize
+where
+    ID: Clone + Eq + Hash,
+    T: ReactiveInstance<ID> + BehaviourTypesContainer,
+{
+    let keys: Vec<ID> = storage
+        .iter()
+        .filter(|instance_behaviours| instance_behaviours.value().contains_key(ty))
+        .map(|instance_behaviours| instance_behaviours.key().clone())
+        .collect();
+    let mut count = 0;
+    for key in keys {
+        match handover_behaviour(storage, &key, ty, factory) {
+            Ok(_) => count += 1,
+            Err(e) => warn!("Failed to hand over the behaviour {ty}: {e}"),
+        }
+    }
+    count
+}
@@ -21,6 +21,8 @@ pub use behaviour::PropertyObserverContainer;
 pub use behaviour::RelationPropertyObserverContainerImpl;
 pub use behaviour::RelationPropertyValidator;
 pub use behaviour::RelationReactiveInstanceContainerImpl;
+pub use behaviour::handover_behaviour;
+pub use behaviour::handover_behaviours;
 pub use entity::EntityBehaviourFactory;
 pub use entity::EntityBehaviourStorage;
 pub use entity::Expression;
@@ -5,6 +5,8 @@ use dashmap::DashMap;
 use reactive_graph_behaviour_model_api::prelude::*;
 
 use crate::BehaviourStorage;
+use crate::handover_behaviour;
+use crate::handover_behaviours;
 use reactive_graph_graph::RelationInstanceId;
 use reactive_graph_reactive_model_impl::ReactiveRelation;
 
@@ -48,6 +50,23 @@ impl RelationBehaviourStorage {
         }
     }
 
+    /// Replaces the behaviour of the given instance by a behaviour created by the given factory
+    /// and hands over the state of the previous behaviour.
+    pub fn handover(
+        &self,
+        key: &RelationInstanceId,
+        ty: &BehaviourTypeId,
+        factory: &(dyn BehaviourFactory<RelationInstanceId, ReactiveRelation> + Send + Sync),
+    ) -> Result<(), BehaviourHandoverError> {
+        handover_behaviour(&self.0, key, ty, factory)
+    }
+
+    /// Hands over all behaviours of the given behaviour type to behaviours created by the given
+    /// factory. Returns the number of instances whose behaviour has been handed over.
+    pub fn handover_by_behaviour(&self, ty: &BehaviourTypeId, factory: &(dyn BehaviourFactory<RelationInstanceId, ReactiveRelation> + Send + Sync)) -> usize {
+        handover_behaviours(&self.0, ty, factory)
+    }
+
     pub fn remove_all(&self, key: &RelationInstanceId) {
         self.0.remove(key);
     }
@@ -0,0 +1,203 @@
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use serde_json::Value;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::EntityBehaviourStorage;
+use crate::tests::utils::create_random_entity_instance;
+use reactive_graph_behaviour_model_api::prelude::*;
+use reactive_graph_reactive_model_api::ReactiveInstanceContainer;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+
+/// A behaviour with an internal counter which is not stored in a property.
+struct CounterBehaviour {
+    reactive_instance: ReactiveEntity,
+    ty: BehaviourTypeId,
+    state: RwLock<BehaviourState>,
+    counter: AtomicU64,
+}
+
+impl BehaviourFsm<Uuid, ReactiveEntity> for CounterBehaviour {
+    fn ty(&self) -> &BehaviourTypeId {
+        &self.ty
+    }
+
+    fn get_state(&self) -> BehaviourState {
+        *self.state.read().unwrap()
+    }
+
+    fn set_state(&self, state: BehaviourState) {
+        *self.state.write().unwrap() = state;
+    }
+
+    fn get_validator(&self) -> &dyn BehaviourValidator<Uuid, ReactiveEntity> {
+        self
+    }
+
+    fn get_transitions(&self) -> &dyn BehaviourTransitions<Uuid, ReactiveEntity> {
+        self
+    }
+}
+
+impl ReactiveInstanceContainer<Uuid, ReactiveEntity> for CounterBehaviour {
+    fn get_reactive_instance(&self) -> &ReactiveEntity {
+        &self.reactive_instance
+    }
+}
+
+impl BehaviourValidator<Uuid, ReactiveEntity> for CounterBehaviour {}
+
+impl BehaviourPropertyValidator<Uuid, ReactiveEntity> for CounterBehaviour {}
+
+impl BehaviourInit<Uuid, ReactiveEntity> for CounterBehaviour {}
+
+impl BehaviourShutdown<Uuid, ReactiveEntity> for CounterBehaviour {}
+
+impl BehaviourConnect<Uuid, ReactiveEntity> for CounterBehaviour {}
+
+impl BehaviourDisconnect<Uuid, ReactiveEntity> for CounterBehaviour {}
+
+impl BehaviourTransitions<Uuid, ReactiveEntity> for CounterBehaviour {
+    fn export_state(&self) -> Option<Value> {
+        Some(json!(self.counter.load(Ordering::SeqCst)))
+    }
+
+    fn import_state(&self, state: Value) -> Result<(), BehaviourStateImportFailed> {
+        let counter = state.as_u64().ok_or_else(|| BehaviourStateImportFailed(format!("Not a counter: {state}")))?;
+        self.counter.store(counter, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+impl Drop for CounterBehaviour {
+    fn drop(&mut self) {
+        self.reactive_instance.remove_behaviour(&self.ty);
+    }
+}
+
+struct CounterBehaviourFactory {
+    ty: BehaviourTypeId,
+    created: AtomicUsize,
+}
+
+impl CounterBehaviourFactory {
+    fn new(ty: BehaviourTypeId) -> Self {
+        CounterBehaviourFactory {
+            ty,
+            created: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl BehaviourFactory<Uuid, ReactiveEntity> for CounterBehaviourFactory {
+    fn create(&self, reactive_instance: ReactiveEntity) -> Result<Arc<dyn BehaviourFsm<Uuid, ReactiveEntity> + Send + Sync>, BehaviourCreationError> {
+        if reactive_instance.behaves_as(&self.ty) {
+            return Err(BehaviourCreationError::BehaviourAlreadyApplied(self.ty.clone()));
+        }
+        let behaviour = Arc::new(CounterBehaviour {
+            reactive_instance,
+            ty: self.ty.clone(),
+            state: RwLock::new(BehaviourState::Created),
+            counter: AtomicU64::new(0),
+        });
+        behaviour
+            .transition(BehaviourState::Connected)
+            .map_err(BehaviourCreationError::BehaviourTransitionError)?;
+        self.created.fetch_add(1, Ordering::SeqCst);
+        Ok(behaviour)
+    }
+
+    fn behaviour_ty(&self) -> &BehaviourTypeId {
+        &self.ty
+    }
+}
+
+#[test]
+fn handover_test() {
+    let ty = BehaviourTypeId::new_from_type("test", "counter");
+    let reactive_entity = create_random_entity_instance("value");
+    let storage = EntityBehaviourStorage::new();
+
+    let previous_factory = CounterBehaviourFactory::new(ty.clone());
+    let previous = previous_factory.create(reactive_entity.clone()).expect("Failed to create the behaviour");
+    previous.import_state(json!(42)).expect("Failed to import the state");
+    storage.insert(reactive_entity.id, ty.clone(), previous.clone());
+    drop(previous);
+
+    let factory = CounterBehaviourFactory::new(ty.clone());
+    storage.handover(&reactive_entity.id, &ty, &factory).expect("Failed to hand over the behaviour");
+    assert_eq!(1, factory.created.load(Ordering::SeqCst));
+
+    let behaviour = storage.get(&reactive_entity.id, &ty).expect("The behaviour is missing");
+    assert_eq!(BehaviourState::Connected, behaviour.get_state());
+    assert_eq!(Some(json!(42)), behaviour.export_state());
+    assert!(reactive_entity.behaves_as(&ty));
+
+    assert_eq!(1, storage.handover_by_behaviour(&ty, &previous_factory));
+    assert_eq!(Some(json!(42)), storage.get(&reactive_entity.id, &ty).unwrap().export_state());
+    assert!(reactive_entity.behaves_as(&ty));
+
+    let unknown_ty = BehaviourTypeId::new_from_type("test", "unknown");
+    assert!(matches!(
+        storage.handover(&reactive_entity.id, &unknown_ty, &factory),
+        Err(BehaviourHandoverError::BehaviourNotFound(_))
+    ));
+    assert!(matches!(storage.handover(&Uuid::new_v4(), &ty, &factory), Err(BehaviourHandoverError::BehaviourNotFound(_))));
+}
+
+/// Looks up the behaviour in the storage while creating the new behaviour, like behaviours whose
+/// observers access the behaviour storage.
+struct StorageLookupFactory {
+    storage: Arc<EntityBehaviourStorage>,
+    factory: CounterBehaviourFactory,
+}
+
+impl BehaviourFactory<Uuid, ReactiveEntity> for StorageLookupFactory {
+    fn create(&self, reactive_instance: ReactiveEntity) -> Result<Arc<dyn BehaviourFsm<Uuid, ReactiveEntity> + Send + Sync>, BehaviourCreationError> {
+        if self.storage.has(&reactive_instance.id, &self.factory.ty) {
+            return Err(BehaviourCreationError::BehaviourAlreadyApplied(self.factory.ty.clone()));
+        }
+        self.factory.create(reactive_instance)
+    }
+
+    fn behaviour_ty(&self) -> &BehaviourTypeId {
+        self.factory.behaviour_ty()
+    }
+}
+
+#[test]
+fn handover_with_storage_access_test() {
+    let ty = BehaviourTypeId::new_from_type("test", "counter");
+    let reactive_entity = create_random_entity_instance("value");
+    let storage = Arc::new(EntityBehaviourStorage::new());
+
+    let previous = CounterBehaviourFactory::new(ty.clone())
+        .create(reactive_entity.clone())
+        .expect("Failed to create the behaviour");
+    previous.import_state(json!(42)).expect("Failed to import the state");
+    storage.insert(reactive_entity.id, ty.clone(), previous);
+
+    let factory = StorageLookupFactory {
+        storage: storage.clone(),
+        factory: CounterBehaviourFactory::new(ty.clone()),
+    };
+    let (tx, rx) = mpsc::channel();
+    let id = reactive_entity.id;
+    let handover_storage = storage.clone();
+    let handover_ty = ty.clone();
+    thread::spawn(move || {
+        let _ = tx.send(handover_storage.handover(&id, &handover_ty, &factory).is_ok());
+    });
+    // The storage is not locked while the new behaviour is created
+    assert!(rx.recv_timeout(Duration::from_secs(10)).expect("The handover is blocked"));
+    assert_eq!(Some(json!(42)), storage.get(&reactive_entity.id, &ty).unwrap().export_state());
+    assert!(reactive_entity.behaves_as(&ty));
+}
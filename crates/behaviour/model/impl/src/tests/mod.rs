@@ -1,4 +1,5 @@
 mod expression_test;
+mod handover_test;
 
 #[cfg(not(tarpaulin_include))]
 pub mod utils;
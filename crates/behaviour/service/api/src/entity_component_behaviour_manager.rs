@@ -1,7 +1,10 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use springtime_di::injectable;
 use uuid::Uuid;
 
+use reactive_graph_behaviour_model_api::BehaviourFactory;
 use reactive_graph_behaviour_model_api::BehaviourTransitionError;
 use reactive_graph_behaviour_model_api::BehaviourTypeId;
 use reactive_graph_behaviour_model_api::ComponentBehaviourTypeId;
@@ -36,6 +39,10 @@ pub trait EntityComponentBehaviourManager: Send + Sync + Lifecycle {
     /// Removes all behaviours of the given behaviour type.
     fn remove_behaviours_by_behaviour(&self, behaviour_ty: &BehaviourTypeId);
 
+    /// Replaces the behaviours of the given behaviour type by behaviours created by the given
+    /// factory and hands over their state. Returns the number of handed over behaviours.
+    fn handover_behaviours(&self, behaviour_ty: &BehaviourTypeId, factory: Arc<dyn BehaviourFactory<Uuid, ReactiveEntity> + Send + Sync>) -> usize;
+
     /// Returns true, if the entity instance has the given behaviour.
     fn has(&self, entity_instance: ReactiveEntity, behaviour_ty: &BehaviourTypeId) -> bool;
 
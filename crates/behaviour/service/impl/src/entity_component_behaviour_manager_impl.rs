@@ -8,6 +8,7 @@ use uuid::Uuid;
 
 use reactive_graph_behaviour_model_api::BehaviourConnectFailed;
 use reactive_graph_behaviour_model_api::BehaviourDisconnectFailed;
+use reactive_graph_behaviour_model_api::BehaviourFactory;
 use reactive_graph_behaviour_model_api::BehaviourState;
 use reactive_graph_behaviour_model_api::BehaviourTransitionError;
 use reactive_graph_behaviour_model_api::BehaviourTypeId;
@@ -89,6 +90,12 @@ impl EntityComponentBehaviourManager for EntityComponentBehaviourManagerImpl {
         trace!("Removed all entity component behaviours of type {}", &behaviour_ty);
     }
 
+    fn handover_behaviours(&self, behaviour_ty: &BehaviourTypeId, factory: Arc<dyn BehaviourFactory<Uuid, ReactiveEntity> + Send + Sync>) -> usize {
+        let count = self.entity_behaviour_storage.handover_by_behaviour(behaviour_ty, factory.as_ref());
+        trace!("Handed over {count} entity component behaviours of type {}", &behaviour_ty);
+        count
+    }
+
     fn has(&self, entity_instance: ReactiveEntity, behaviour_ty: &BehaviourTypeId) -> bool {
         self.entity_behaviour_storage.has(&entity_instance.id, behaviour_ty)
     }
@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use reactive_graph_behaviour_model_api::BehaviourConnectFailed;
 use reactive_graph_behaviour_model_api::BehaviourDisconnectFailed;
+use reactive_graph_behaviour_model_api::BehaviourFactory;
 use reactive_graph_behaviour_model_api::BehaviourState;
 use reactive_graph_behaviour_model_api::BehaviourTransitionError;
 use reactive_graph_behaviour_model_api::BehaviourTypeId;
@@ -96,6 +97,16 @@ impl RelationComponentBehaviourManager for RelationComponentBehaviourManagerImpl
         trace!("Removed all relation behaviours of type {}", &behaviour_ty);
     }
 
+    fn handover_behaviours(
+        &self,
+        behaviour_ty: &BehaviourTypeId,
+        factory: Arc<dyn BehaviourFactory<RelationInstanceId, ReactiveRelation> + Send + Sync>,
+    ) -> usize {
+        let count = self.relation_behaviour_storage.handover_by_behaviour(behaviour_ty, factory.as_ref());
+        trace!("Handed over {count} relation component behaviours of type {}", &behaviour_ty);
+        count
+    }
+
     fn has(&self, relation_instance: ReactiveRelation, behaviour_ty: &BehaviourTypeId) -> bool {
         self.relation_behaviour_storage.has(&relation_instance.id(), behaviour_ty)
     }
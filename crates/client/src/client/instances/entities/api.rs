@@ -17,6 +17,7 @@ use cynic::http::ReqwestExt;
 use reactive_graph_graph::ComponentTypeId;
 use reactive_graph_graph::EntityInstance;
 use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::Page;
 use reactive_graph_graph::PropertyInstances;
 use reactive_graph_graph::PropertyType;
 use serde_json::Value;
@@ -32,7 +33,11 @@ impl EntityInstances {
     }
 
     pub async fn search(&self, search_query: SearchEntityInstancesVariables) -> Result<Option<Vec<EntityInstance>>, ReactiveGraphClientExecutionError> {
-        let entity_instances = self
+        Ok(self.search_page(search_query).await?.map(Page::into_nodes))
+    }
+
+    pub async fn search_page(&self, search_query: SearchEntityInstancesVariables) -> Result<Option<Page<EntityInstance>>, ReactiveGraphClientExecutionError> {
+        let page = self
             .client
             .client
             .post(self.client.url_reactive_graph())
@@ -40,9 +45,8 @@ impl EntityInstances {
             .await
             .map_err(ReactiveGraphClientExecutionError::FailedToSendRequest)?
             .data
-            .map(|data| crate::schema_graphql::instances::entity_instance::EntityInstances(data.instances.entities))
-            .map(From::from);
-        Ok(entity_instances)
+            .map(|data| data.instances.entities_connection.into());
+        Ok(page)
     }
 
     pub async fn get_entity_instance_by_id<ID: Into<Uuid>>(&self, id: ID) -> Result<Option<EntityInstance>, ReactiveGraphClientExecutionError> {
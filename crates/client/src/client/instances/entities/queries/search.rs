@@ -2,7 +2,7 @@
 pub mod queries {
     use crate::client::instances::entities::variables::search::variables::SearchEntityInstancesVariables;
     use crate::client::instances::entities::variables::search::variables::SearchEntityInstancesVariablesFields;
-    use crate::schema_graphql::instances::entity_instance::EntityInstance;
+    use crate::schema_graphql::instances::entity_instance::EntityInstanceConnection;
     use cynic::Operation;
     use cynic::QueryFragment;
 
@@ -15,9 +15,9 @@ pub mod queries {
     #[derive(QueryFragment, Debug)]
     #[cynic(graphql_type = "Instances", variables = "SearchEntityInstancesVariables")]
     pub struct SearchEntityInstancesInstances {
-        #[arguments(type: $ty, id: $id, label: $label, properties: $properties, components: $components
+        #[arguments(type: $ty, id: $id, label: $label, properties: $properties, components: $components, sort: $sort, first: $first, after: $after, last: $last, before: $before
         )]
-        pub entities: Vec<EntityInstance>,
+        pub entities_connection: EntityInstanceConnection,
     }
 
     pub fn search(vars: SearchEntityInstancesVariables) -> Operation<SearchEntityInstances, SearchEntityInstancesVariables> {
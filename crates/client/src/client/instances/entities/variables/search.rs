@@ -2,6 +2,7 @@
 pub mod variables {
     use crate::ComponentTypeId;
     use crate::PropertyInstanceDefinition;
+    use crate::schema_graphql::pagination::property_sort::PropertySort;
     use crate::schema_graphql::scalar::UUID;
     use crate::schema_graphql::types::entity_type::EntityTypeId;
     use cynic::QueryVariables;
@@ -26,6 +27,21 @@ pub mod variables {
         /// Filter by components.
         #[builder(default)]
         pub components: Option<Vec<ComponentTypeId>>,
+        /// Sorts the instances by the values of the given properties.
+        #[builder(default)]
+        pub sort: Option<Vec<PropertySort>>,
+        /// Returns the first n instances.
+        #[builder(default)]
+        pub first: Option<i32>,
+        /// Returns the instances after the given cursor.
+        #[builder(default)]
+        pub after: Option<String>,
+        /// Returns the last n instances.
+        #[builder(default)]
+        pub last: Option<i32>,
+        /// Returns the instances before the given cursor.
+        #[builder(default)]
+        pub before: Option<String>,
         // TODO: search for behaviours
     }
 }
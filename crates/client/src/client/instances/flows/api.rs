@@ -11,6 +11,7 @@ use crate::client::instances::flows::variables::search::variables::SearchFlowIns
 use cynic::http::ReqwestExt;
 use reactive_graph_graph::FlowInstance;
 use reactive_graph_graph::FlowTypeId;
+use reactive_graph_graph::Page;
 use reactive_graph_graph::PropertyInstances;
 use uuid::Uuid;
 
@@ -24,7 +25,11 @@ impl FlowInstances {
     }
 
     pub async fn search(&self, search_query: SearchFlowInstancesVariables) -> Result<Option<Vec<FlowInstance>>, ReactiveGraphClientExecutionError> {
-        let flow_instances = self
+        Ok(self.search_page(search_query).await?.map(Page::into_nodes))
+    }
+
+    pub async fn search_page(&self, search_query: SearchFlowInstancesVariables) -> Result<Option<Page<FlowInstance>>, ReactiveGraphClientExecutionError> {
+        let page = self
             .client
             .client
             .post(self.client.url_reactive_graph())
@@ -32,9 +37,8 @@ impl FlowInstances {
             .await
             .map_err(ReactiveGraphClientExecutionError::FailedToSendRequest)?
             .data
-            .map(|data| crate::schema_graphql::instances::flow_instance::FlowInstances(data.instances.flows))
-            .map(From::from);
-        Ok(flow_instances)
+            .map(|data| data.instances.flows_connection.into());
+        Ok(page)
     }
 
     pub async fn get_by_id<ID: Into<Uuid>>(&self, id: ID) -> Result<Option<FlowInstance>, ReactiveGraphClientExecutionError> {
@@ -2,7 +2,7 @@
 pub mod queries {
     use crate::client::instances::flows::variables::search::variables::SearchFlowInstancesVariables;
     use crate::client::instances::flows::variables::search::variables::SearchFlowInstancesVariablesFields;
-    use crate::schema_graphql::instances::flow_instance::FlowInstance;
+    use crate::schema_graphql::instances::flow_instance::FlowInstanceConnection;
     use cynic::Operation;
     use cynic::QueryFragment;
 
@@ -15,8 +15,8 @@ pub mod queries {
     #[derive(QueryFragment, Debug)]
     #[cynic(graphql_type = "Instances", variables = "SearchFlowInstancesVariables")]
     pub struct SearchFlowInstancesInstances {
-        #[arguments(type: $ty, id: $id, label: $label)]
-        pub flows: Vec<FlowInstance>,
+        #[arguments(type: $ty, id: $id, label: $label, sort: $sort, first: $first, after: $after, last: $last, before: $before)]
+        pub flows_connection: FlowInstanceConnection,
     }
 
     pub fn search(vars: SearchFlowInstancesVariables) -> Operation<SearchFlowInstances, SearchFlowInstancesVariables> {
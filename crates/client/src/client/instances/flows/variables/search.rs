@@ -3,6 +3,7 @@ pub mod variables {
     use cynic::QueryVariables;
     use typed_builder::TypedBuilder;
 
+    use crate::schema_graphql::pagination::property_sort::PropertySort;
     use crate::schema_graphql::scalar::UUID;
     use crate::schema_graphql::types::entity_type::EntityTypeId;
 
@@ -17,6 +18,21 @@ pub mod variables {
         /// Returns the flow instance with the given label.
         #[builder(default)]
         pub label: Option<String>,
+        /// Sorts the instances by the values of the given properties.
+        #[builder(default)]
+        pub sort: Option<Vec<PropertySort>>,
+        /// Returns the first n instances.
+        #[builder(default)]
+        pub first: Option<i32>,
+        /// Returns the instances after the given cursor.
+        #[builder(default)]
+        pub after: Option<String>,
+        /// Returns the last n instances.
+        #[builder(default)]
+        pub last: Option<i32>,
+        /// Returns the instances before the given cursor.
+        #[builder(default)]
+        pub before: Option<String>,
         // TODO: Filter by name.
         // TODO: Filter by description.
         // TODO: Filter by entity instance.
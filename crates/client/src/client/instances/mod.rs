@@ -1,12 +1,16 @@
 use crate::ReactiveGraphClient;
 use crate::client::instances::entities::api::EntityInstances;
 use crate::client::instances::flows::api::FlowInstances;
+use crate::client::instances::query::api::GraphQueries;
 use crate::client::instances::relations::api::RelationInstances;
+use crate::client::instances::search::api::Search;
 use std::sync::Arc;
 
 pub mod entities;
 pub mod flows;
+pub mod query;
 pub mod relations;
+pub mod search;
 pub mod variables;
 
 pub struct Instances {
@@ -29,4 +33,12 @@ impl Instances {
     pub fn flows(&self) -> FlowInstances {
         FlowInstances::new(self.client.clone())
     }
+
+    pub fn query(&self) -> GraphQueries {
+        GraphQueries::new(self.client.clone())
+    }
+
+    pub fn search(&self) -> Search {
+        Search::new(self.client.clone())
+    }
 }
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::client::ReactiveGraphClient;
+use crate::client::ReactiveGraphClientExecutionError;
+use crate::client::instances::query::queries::query::queries::execute_graph_query;
+use reactive_graph_graph::GraphQueryResult;
+
+pub struct GraphQueries {
+    client: Arc<ReactiveGraphClient>,
+}
+
+impl GraphQueries {
+    pub fn new(client: Arc<ReactiveGraphClient>) -> Self {
+        Self { client }
+    }
+
+    /// Executes a graph pattern query and returns the matched rows.
+    pub async fn execute<T: Into<String>>(&self, text: T) -> Result<GraphQueryResult, ReactiveGraphClientExecutionError> {
+        self.client.execute_graphql(execute_graph_query(text.into()), |data| data.query.into()).await
+    }
+
+    /// Executes a graph pattern query and returns the matched rows together with the execution plan.
+    pub async fn execute_with_plan<T: Into<String>>(&self, text: T) -> Result<(GraphQueryResult, String), ReactiveGraphClientExecutionError> {
+        self.client
+            .execute_graphql(execute_graph_query(text.into()), |data| {
+                let plan = data.query.plan.clone();
+                (data.query.into(), plan)
+            })
+            .await
+    }
+}
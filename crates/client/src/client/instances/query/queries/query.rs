@@ -0,0 +1,39 @@
+#[cynic::schema_for_derives(file = r#"../../schema/graphql/reactive-graph-schema.graphql"#, module = "crate::schema_graphql::schema")]
+pub mod queries {
+    use cynic::Operation;
+    use cynic::QueryFragment;
+    use cynic::QueryVariables;
+    use serde_json::Value;
+
+    use reactive_graph_graph::GraphQueryResult as GraphQueryResultModel;
+
+    #[derive(QueryVariables, Debug)]
+    pub struct GraphQueryVariables {
+        pub text: String,
+    }
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "GraphQueryVariables")]
+    pub struct ExecuteGraphQuery {
+        #[arguments(text: $text)]
+        pub query: GraphQueryResult,
+    }
+
+    #[derive(QueryFragment, Debug)]
+    pub struct GraphQueryResult {
+        pub columns: Vec<String>,
+        pub rows: Vec<Vec<Value>>,
+        pub plan: String,
+    }
+
+    impl From<GraphQueryResult> for GraphQueryResultModel {
+        fn from(result: GraphQueryResult) -> Self {
+            GraphQueryResultModel::new(result.columns, result.rows)
+        }
+    }
+
+    pub fn execute_graph_query(text: String) -> Operation<ExecuteGraphQuery, GraphQueryVariables> {
+        use cynic::QueryBuilder;
+        ExecuteGraphQuery::build(GraphQueryVariables { text })
+    }
+}
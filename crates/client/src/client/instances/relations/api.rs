@@ -14,6 +14,7 @@ use crate::client::instances::relations::queries::search::queries::search;
 use crate::client::instances::relations::variables::search::variables::SearchRelationInstancesVariables;
 use cynic::http::ReqwestExt;
 use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::Page;
 use reactive_graph_graph::PropertyInstances;
 use reactive_graph_graph::PropertyType;
 use reactive_graph_graph::RelationInstance;
@@ -30,7 +31,14 @@ impl RelationInstances {
     }
 
     pub async fn search(&self, search_query: SearchRelationInstancesVariables) -> Result<Option<Vec<RelationInstance>>, ReactiveGraphClientExecutionError> {
-        let relation_instances = self
+        Ok(self.search_page(search_query).await?.map(Page::into_nodes))
+    }
+
+    pub async fn search_page(
+        &self,
+        search_query: SearchRelationInstancesVariables,
+    ) -> Result<Option<Page<RelationInstance>>, ReactiveGraphClientExecutionError> {
+        let page = self
             .client
             .client
             .post(self.client.url_reactive_graph())
@@ -38,9 +46,8 @@ impl RelationInstances {
             .await
             .map_err(ReactiveGraphClientExecutionError::FailedToSendRequest)?
             .data
-            .map(|data| crate::schema_graphql::instances::relation_instance::RelationInstances(data.instances.relations))
-            .map(From::from);
-        Ok(relation_instances)
+            .map(|data| data.instances.relations_connection.into());
+        Ok(page)
     }
 
     pub async fn get_by_id<ID: Into<RelationInstanceId>>(&self, id: ID) -> Result<Option<RelationInstance>, ReactiveGraphClientExecutionError> {
@@ -2,7 +2,7 @@
 pub mod queries {
     use crate::client::instances::relations::variables::search::variables::SearchRelationInstancesVariables;
     use crate::client::instances::relations::variables::search::variables::SearchRelationInstancesVariablesFields;
-    use crate::schema_graphql::instances::relation_instance::RelationInstance;
+    use crate::schema_graphql::instances::relation_instance::RelationInstanceConnection;
     use cynic::Operation;
     use cynic::QueryFragment;
 
@@ -15,9 +15,9 @@ pub mod queries {
     #[derive(QueryFragment, Debug)]
     #[cynic(graphql_type = "Instances", variables = "SearchRelationInstancesVariables")]
     pub struct SearchRelationInstancesInstances {
-        #[arguments(outboundId: $outbound_id, type: $ty, inboundId: $inbound_id, properties: $properties, components: $components
+        #[arguments(outboundId: $outbound_id, type: $ty, inboundId: $inbound_id, properties: $properties, components: $components, sort: $sort, first: $first, after: $after, last: $last, before: $before
         )]
-        pub relations: Vec<RelationInstance>,
+        pub relations_connection: RelationInstanceConnection,
     }
 
     pub fn search(vars: SearchRelationInstancesVariables) -> Operation<SearchRelationInstances, SearchRelationInstancesVariables> {
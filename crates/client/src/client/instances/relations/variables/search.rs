@@ -1,5 +1,6 @@
 #[cynic::schema_for_derives(file = r#"../../schema/graphql/reactive-graph-schema.graphql"#, module = "crate::schema_graphql::schema")]
 pub mod variables {
+    use crate::schema_graphql::pagination::property_sort::PropertySort;
     use cynic::QueryVariables;
     use typed_builder::TypedBuilder;
 
@@ -27,6 +28,21 @@ pub mod variables {
         /// Filter by components.
         #[builder(default)]
         pub components: Option<Vec<crate::schema_graphql::types::component::ComponentTypeId>>,
+        /// Sorts the instances by the values of the given properties.
+        #[builder(default)]
+        pub sort: Option<Vec<PropertySort>>,
+        /// Returns the first n instances.
+        #[builder(default)]
+        pub first: Option<i32>,
+        /// Returns the instances after the given cursor.
+        #[builder(default)]
+        pub after: Option<String>,
+        /// Returns the last n instances.
+        #[builder(default)]
+        pub last: Option<i32>,
+        /// Returns the instances before the given cursor.
+        #[builder(default)]
+        pub before: Option<String>,
         // TODO: search for applied behaviours
     }
 }
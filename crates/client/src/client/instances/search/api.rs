@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use crate::client::ReactiveGraphClient;
+use crate::client::ReactiveGraphClientExecutionError;
+use crate::client::instances::search::queries::search::queries::SearchVariables;
+use crate::client::instances::search::queries::search::queries::search;
+use crate::schema_graphql::instances::search_hit::SearchHitInstance;
+use reactive_graph_graph::SearchHit;
+use reactive_graph_graph::SearchOptions;
+
+pub struct Search {
+    client: Arc<ReactiveGraphClient>,
+}
+
+impl Search {
+    pub fn new(client: Arc<ReactiveGraphClient>) -> Self {
+        Self { client }
+    }
+
+    /// Full-text search over the names, descriptions, labels and searchable string properties
+    /// of the instances.
+    pub async fn search<T: Into<String>>(&self, text: T) -> Result<Vec<SearchHit<SearchHitInstance>>, ReactiveGraphClientExecutionError> {
+        self.search_with_options(text, &SearchOptions::default()).await
+    }
+
+    /// Full-text search with the given options for matching the tokens.
+    pub async fn search_with_options<T: Into<String>>(
+        &self,
+        text: T,
+        options: &SearchOptions,
+    ) -> Result<Vec<SearchHit<SearchHitInstance>>, ReactiveGraphClientExecutionError> {
+        self.client
+            .execute_graphql(search(SearchVariables::new(text.into(), options)), |data| {
+                data.search.into_iter().filter_map(|hit| SearchHit::try_from(hit).ok()).collect()
+            })
+            .await
+    }
+}
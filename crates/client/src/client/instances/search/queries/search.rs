@@ -0,0 +1,39 @@
+#[cynic::schema_for_derives(file = r#"../../schema/graphql/reactive-graph-schema.graphql"#, module = "crate::schema_graphql::schema")]
+pub mod queries {
+    use crate::schema_graphql::instances::search_hit::SearchHit;
+    use cynic::Operation;
+    use cynic::QueryFragment;
+    use cynic::QueryVariables;
+    use reactive_graph_graph::SearchOptions;
+
+    #[derive(QueryVariables, Debug)]
+    pub struct SearchVariables {
+        pub text: String,
+        pub prefix: bool,
+        pub fuzzy: bool,
+        pub limit: i32,
+    }
+
+    impl SearchVariables {
+        pub fn new(text: String, options: &SearchOptions) -> Self {
+            Self {
+                text,
+                prefix: options.prefix,
+                fuzzy: options.fuzzy,
+                limit: i32::try_from(options.limit).unwrap_or(i32::MAX),
+            }
+        }
+    }
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "SearchVariables")]
+    pub struct Search {
+        #[arguments(text: $text, prefix: $prefix, fuzzy: $fuzzy, limit: $limit)]
+        pub search: Vec<SearchHit>,
+    }
+
+    pub fn search(vars: SearchVariables) -> Operation<Search, SearchVariables> {
+        use cynic::QueryBuilder;
+        Search::build(vars)
+    }
+}
@@ -18,6 +18,7 @@ use crate::client::instances::Instances;
 use crate::client::json_schema::JsonSchema;
 use crate::client::plugin::api::Plugins;
 use crate::client::runtime::Runtime;
+use crate::client::traversal::api::Traversal;
 use crate::client::types::Types;
 use reactive_graph_remotes_model::InstanceAddress;
 
@@ -25,6 +26,7 @@ pub mod instances;
 pub mod json_schema;
 pub mod plugin;
 pub mod runtime;
+pub mod traversal;
 pub mod types;
 
 #[derive(Debug)]
@@ -179,6 +181,10 @@ impl ReactiveGraphClient {
         Instances::new(self.clone())
     }
 
+    pub fn traversal(self: &Arc<Self>) -> Traversal {
+        Traversal::new(self.clone())
+    }
+
     pub fn json_schema(self: &Arc<Self>) -> JsonSchema {
         JsonSchema::new(self.clone())
     }
@@ -6,6 +6,7 @@ use crate::client::plugin::queries::get_all::queries::get_all;
 use crate::client::plugin::queries::get_by_name::queries::get_by_name;
 use crate::client::plugin::queries::get_dependencies::queries::get_dependencies;
 use crate::client::plugin::queries::get_dependents::queries::get_dependents;
+use crate::client::plugin::queries::get_diagnostics::queries::get_diagnostics;
 use crate::client::plugin::queries::get_unsatisfied_dependencies::queries::get_unsatisfied_dependencies;
 use crate::client::plugin::queries::search::queries::search;
 use crate::client::plugin::variables::search::variables::SearchPluginVariables;
@@ -13,6 +14,7 @@ use std::sync::Arc;
 
 use crate::ReactiveGraphClient;
 use crate::ReactiveGraphClientExecutionError;
+use crate::schema_plugin::PluginResolverDiagnostics;
 
 use reactive_graph_plugin_model::Plugin;
 
@@ -79,6 +81,11 @@ impl Plugins {
             .map(|plugins| plugins.map(|plugins| plugins.iter().map(|plugin| plugin.into()).collect()))
     }
 
+    /// Returns the diagnostics of the plugin resolver.
+    pub async fn diagnostics(&self) -> Result<PluginResolverDiagnostics, ReactiveGraphClientExecutionError> {
+        self.client.execute_plugins(get_diagnostics(), |data| data.diagnostics).await
+    }
+
     pub async fn start(&self, name: String) -> Result<Plugin, ReactiveGraphClientExecutionError> {
         self.client.execute_plugins(start(name), |data| (&data.start).into()).await
     }
@@ -0,0 +1,15 @@
+#[cynic::schema_for_derives(file = r#"../../schema/graphql/reactive-graph-plugin-schema.graphql"#, module = "crate::schema_plugin::schema")]
+pub mod queries {
+    use crate::schema_plugin::plugin::PluginResolverDiagnostics;
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query")]
+    pub struct GetPluginResolverDiagnostics {
+        pub diagnostics: PluginResolverDiagnostics,
+    }
+
+    pub fn get_diagnostics() -> cynic::Operation<GetPluginResolverDiagnostics, ()> {
+        use cynic::QueryBuilder;
+        GetPluginResolverDiagnostics::build(())
+    }
+}
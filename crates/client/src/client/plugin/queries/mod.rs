@@ -2,5 +2,6 @@ pub mod get_all;
 pub mod get_by_name;
 pub mod get_dependencies;
 pub mod get_dependents;
+pub mod get_diagnostics;
 pub mod get_unsatisfied_dependencies;
 pub mod search;
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::client::ReactiveGraphClient;
+use crate::client::ReactiveGraphClientExecutionError;
+use crate::client::traversal::queries::all_paths::queries::all_paths;
+use crate::client::traversal::queries::connected_components::queries::ConnectedComponentsVariables;
+use crate::client::traversal::queries::connected_components::queries::connected_components;
+use crate::client::traversal::queries::cycles::queries::CyclesVariables;
+use crate::client::traversal::queries::cycles::queries::cycles;
+use crate::client::traversal::queries::shortest_path::queries::PathVariables;
+use crate::client::traversal::queries::shortest_path::queries::shortest_path;
+use crate::client::traversal::queries::traverse::queries::TraverseVariables;
+use crate::client::traversal::queries::traverse::queries::traverse;
+use reactive_graph_graph::EntityInstance;
+use reactive_graph_graph::GraphPath;
+use reactive_graph_graph::TraversalOptions;
+use reactive_graph_graph::TraversalVisit;
+
+pub struct Traversal {
+    client: Arc<ReactiveGraphClient>,
+}
+
+impl Traversal {
+    pub fn new(client: Arc<ReactiveGraphClient>) -> Self {
+        Self { client }
+    }
+
+    /// Visits the entity instances reachable from the start entity instance.
+    pub async fn traverse<ID: Into<Uuid>>(&self, start: ID, options: TraversalOptions) -> Result<Vec<TraversalVisit>, ReactiveGraphClientExecutionError> {
+        let vars = TraverseVariables {
+            start: start.into().into(),
+            options: Some(options.into()),
+        };
+        self.client
+            .execute_graphql(traverse(vars), |data| data.traversal.traverse.into_iter().map(From::from).collect())
+            .await
+    }
+
+    /// Returns a path with the fewest relations from the start entity instance to the end entity instance.
+    pub async fn shortest_path<ID: Into<Uuid>>(
+        &self,
+        start: ID,
+        end: ID,
+        options: TraversalOptions,
+    ) -> Result<Option<GraphPath>, ReactiveGraphClientExecutionError> {
+        let vars = PathVariables {
+            start: start.into().into(),
+            end: end.into().into(),
+            options: Some(options.into()),
+        };
+        self.client
+            .execute_graphql(shortest_path(vars), |data| data.traversal.shortest_path.map(From::from))
+            .await
+    }
+
+    /// Returns all paths from the start entity instance to the end entity instance.
+    pub async fn all_paths<ID: Into<Uuid>>(&self, start: ID, end: ID, options: TraversalOptions) -> Result<Vec<GraphPath>, ReactiveGraphClientExecutionError> {
+        let vars = PathVariables {
+            start: start.into().into(),
+            end: end.into().into(),
+            options: Some(options.into()),
+        };
+        self.client
+            .execute_graphql(all_paths(vars), |data| data.traversal.all_paths.into_iter().map(From::from).collect())
+            .await
+    }
+
+    /// Groups all entity instances into connected components.
+    pub async fn connected_components(&self, options: TraversalOptions) -> Result<Vec<Vec<EntityInstance>>, ReactiveGraphClientExecutionError> {
+        let vars = ConnectedComponentsVariables { options: Some(options.into()) };
+        self.client
+            .execute_graphql(connected_components(vars), |data| {
+                data.traversal
+                    .connected_components
+                    .into_iter()
+                    .map(|component| component.into_iter().map(From::from).collect())
+                    .collect()
+            })
+            .await
+    }
+
+    /// Returns the cycles through the given entity instance.
+    pub async fn cycles<ID: Into<Uuid>>(&self, id: ID, options: TraversalOptions) -> Result<Vec<GraphPath>, ReactiveGraphClientExecutionError> {
+        let vars = CyclesVariables {
+            id: Some(id.into().into()),
+            flow_id: None,
+            options: Some(options.into()),
+        };
+        self.client
+            .execute_graphql(cycles(vars), |data| data.traversal.cycles.into_iter().map(From::from).collect())
+            .await
+    }
+
+    /// Returns the cycles through the entity instances of the given flow instance.
+    pub async fn flow_cycles<ID: Into<Uuid>>(&self, flow_id: ID, options: TraversalOptions) -> Result<Vec<GraphPath>, ReactiveGraphClientExecutionError> {
+        let vars = CyclesVariables {
+            id: None,
+            flow_id: Some(flow_id.into().into()),
+            options: Some(options.into()),
+        };
+        self.client
+            .execute_graphql(cycles(vars), |data| data.traversal.cycles.into_iter().map(From::from).collect())
+            .await
+    }
+}
@@ -0,0 +1,26 @@
+#[cynic::schema_for_derives(file = r#"../../schema/graphql/reactive-graph-schema.graphql"#, module = "crate::schema_graphql::schema")]
+pub mod queries {
+    use crate::client::traversal::queries::shortest_path::queries::PathVariables;
+    use crate::client::traversal::queries::shortest_path::queries::PathVariablesFields;
+    use crate::schema_graphql::traversal::graph_path::GraphPath;
+    use cynic::Operation;
+    use cynic::QueryFragment;
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "PathVariables")]
+    pub struct AllPaths {
+        pub traversal: AllPathsTraversal,
+    }
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Traversal", variables = "PathVariables")]
+    pub struct AllPathsTraversal {
+        #[arguments(start: $start, end: $end, options: $options)]
+        pub all_paths: Vec<GraphPath>,
+    }
+
+    pub fn all_paths(vars: PathVariables) -> Operation<AllPaths, PathVariables> {
+        use cynic::QueryBuilder;
+        AllPaths::build(vars)
+    }
+}
@@ -0,0 +1,31 @@
+#[cynic::schema_for_derives(file = r#"../../schema/graphql/reactive-graph-schema.graphql"#, module = "crate::schema_graphql::schema")]
+pub mod queries {
+    use crate::schema_graphql::instances::entity_instance::EntityInstance;
+    use crate::schema_graphql::traversal::traversal_options::TraversalOptions;
+    use cynic::Operation;
+    use cynic::QueryFragment;
+    use cynic::QueryVariables;
+
+    #[derive(QueryVariables, Debug)]
+    pub struct ConnectedComponentsVariables {
+        pub options: Option<TraversalOptions>,
+    }
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "ConnectedComponentsVariables")]
+    pub struct ConnectedComponents {
+        pub traversal: ConnectedComponentsTraversal,
+    }
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Traversal", variables = "ConnectedComponentsVariables")]
+    pub struct ConnectedComponentsTraversal {
+        #[arguments(options: $options)]
+        pub connected_components: Vec<Vec<EntityInstance>>,
+    }
+
+    pub fn connected_components(vars: ConnectedComponentsVariables) -> Operation<ConnectedComponents, ConnectedComponentsVariables> {
+        use cynic::QueryBuilder;
+        ConnectedComponents::build(vars)
+    }
+}
@@ -0,0 +1,34 @@
+#[cynic::schema_for_derives(file = r#"../../schema/graphql/reactive-graph-schema.graphql"#, module = "crate::schema_graphql::schema")]
+pub mod queries {
+    use crate::schema_graphql::scalar::UUID;
+    use crate::schema_graphql::traversal::graph_path::GraphPath;
+    use crate::schema_graphql::traversal::traversal_options::TraversalOptions;
+    use cynic::Operation;
+    use cynic::QueryFragment;
+    use cynic::QueryVariables;
+
+    #[derive(QueryVariables, Debug)]
+    pub struct CyclesVariables {
+        pub id: Option<UUID>,
+        pub flow_id: Option<UUID>,
+        pub options: Option<TraversalOptions>,
+    }
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "CyclesVariables")]
+    pub struct Cycles {
+        pub traversal: CyclesTraversal,
+    }
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Traversal", variables = "CyclesVariables")]
+    pub struct CyclesTraversal {
+        #[arguments(id: $id, flowId: $flow_id, options: $options)]
+        pub cycles: Vec<GraphPath>,
+    }
+
+    pub fn cycles(vars: CyclesVariables) -> Operation<Cycles, CyclesVariables> {
+        use cynic::QueryBuilder;
+        Cycles::build(vars)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod all_paths;
+pub mod connected_components;
+pub mod cycles;
+pub mod shortest_path;
+pub mod traverse;
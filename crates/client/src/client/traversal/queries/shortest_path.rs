@@ -0,0 +1,34 @@
+#[cynic::schema_for_derives(file = r#"../../schema/graphql/reactive-graph-schema.graphql"#, module = "crate::schema_graphql::schema")]
+pub mod queries {
+    use crate::schema_graphql::scalar::UUID;
+    use crate::schema_graphql::traversal::graph_path::GraphPath;
+    use crate::schema_graphql::traversal::traversal_options::TraversalOptions;
+    use cynic::Operation;
+    use cynic::QueryFragment;
+    use cynic::QueryVariables;
+
+    #[derive(QueryVariables, Debug)]
+    pub struct PathVariables {
+        pub start: UUID,
+        pub end: UUID,
+        pub options: Option<TraversalOptions>,
+    }
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "PathVariables")]
+    pub struct ShortestPath {
+        pub traversal: ShortestPathTraversal,
+    }
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Traversal", variables = "PathVariables")]
+    pub struct ShortestPathTraversal {
+        #[arguments(start: $start, end: $end, options: $options)]
+        pub shortest_path: Option<GraphPath>,
+    }
+
+    pub fn shortest_path(vars: PathVariables) -> Operation<ShortestPath, PathVariables> {
+        use cynic::QueryBuilder;
+        ShortestPath::build(vars)
+    }
+}
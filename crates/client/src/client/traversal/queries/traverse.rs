@@ -0,0 +1,33 @@
+#[cynic::schema_for_derives(file = r#"../../schema/graphql/reactive-graph-schema.graphql"#, module = "crate::schema_graphql::schema")]
+pub mod queries {
+    use crate::schema_graphql::scalar::UUID;
+    use crate::schema_graphql::traversal::traversal_options::TraversalOptions;
+    use crate::schema_graphql::traversal::traversal_visit::TraversalVisit;
+    use cynic::Operation;
+    use cynic::QueryFragment;
+    use cynic::QueryVariables;
+
+    #[derive(QueryVariables, Debug)]
+    pub struct TraverseVariables {
+        pub start: UUID,
+        pub options: Option<TraversalOptions>,
+    }
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "TraverseVariables")]
+    pub struct Traverse {
+        pub traversal: TraverseTraversal,
+    }
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Traversal", variables = "TraverseVariables")]
+    pub struct TraverseTraversal {
+        #[arguments(start: $start, options: $options)]
+        pub traverse: Vec<TraversalVisit>,
+    }
+
+    pub fn traverse(vars: TraverseVariables) -> Operation<Traverse, TraverseVariables> {
+        use cynic::QueryBuilder;
+        Traverse::build(vars)
+    }
+}
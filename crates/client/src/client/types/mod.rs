@@ -2,6 +2,7 @@ use crate::client::ReactiveGraphClient;
 use crate::client::types::components::api::Components;
 use crate::client::types::entities::api::EntityTypes;
 use crate::client::types::flows::api::FlowTypes;
+use crate::client::types::namespaces::api::Namespaces;
 use crate::client::types::relations::api::RelationTypes;
 use std::sync::Arc;
 
@@ -10,6 +11,7 @@ pub mod components;
 pub mod entities;
 pub mod extensions;
 pub mod flows;
+pub mod namespaces;
 pub mod properties;
 pub mod relations;
 
@@ -37,4 +39,8 @@ impl Types {
     pub fn flows(&self) -> FlowTypes {
         FlowTypes::new(self.client.clone())
     }
+
+    pub fn namespaces(&self) -> Namespaces {
+        Namespaces::new(self.client.clone())
+    }
 }
@@ -0,0 +1,44 @@
+use crate::client::ReactiveGraphClient;
+use crate::client::ReactiveGraphClientExecutionError;
+use crate::client::types::namespaces::queries::get_all::queries::get_all_namespaces_query;
+use crate::client::types::namespaces::queries::get_by_name::queries::get_namespace_by_name_query;
+use cynic::http::ReqwestExt;
+use reactive_graph_graph::Namespace;
+use std::sync::Arc;
+
+pub struct Namespaces {
+    client: Arc<ReactiveGraphClient>,
+}
+
+impl Namespaces {
+    pub fn new(client: Arc<ReactiveGraphClient>) -> Self {
+        Self { client }
+    }
+
+    pub async fn get_all_namespaces(&self) -> Result<Option<Vec<Namespace>>, ReactiveGraphClientExecutionError> {
+        let namespaces = self
+            .client
+            .client
+            .post(self.client.url_reactive_graph())
+            .run_graphql(get_all_namespaces_query())
+            .await
+            .map_err(ReactiveGraphClientExecutionError::FailedToSendRequest)?
+            .data
+            .map(|data| data.types.namespace_metadata.into_iter().map(From::from).collect());
+        Ok(namespaces)
+    }
+
+    pub async fn get_namespace_by_name(&self, name: &str) -> Result<Option<Namespace>, ReactiveGraphClientExecutionError> {
+        let namespace = self
+            .client
+            .client
+            .post(self.client.url_reactive_graph())
+            .run_graphql(get_namespace_by_name_query(name))
+            .await
+            .map_err(ReactiveGraphClientExecutionError::FailedToSendRequest)?
+            .data
+            .and_then(|data| data.types.namespace_metadata.into_iter().next())
+            .map(From::from);
+        Ok(namespace)
+    }
+}
@@ -0,0 +1,24 @@
+#[cynic::schema_for_derives(file = r#"../../schema/graphql/reactive-graph-schema.graphql"#, module = "crate::schema_graphql::schema")]
+pub mod queries {
+    use cynic::Operation;
+    use cynic::QueryFragment;
+
+    use crate::schema_graphql::types::namespace::Namespace;
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query")]
+    pub struct GetAllNamespaces {
+        pub types: GetAllNamespacesTypes,
+    }
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Types")]
+    pub struct GetAllNamespacesTypes {
+        pub namespace_metadata: Vec<Namespace>,
+    }
+
+    pub fn get_all_namespaces_query() -> Operation<GetAllNamespaces, ()> {
+        use cynic::QueryBuilder;
+        GetAllNamespaces::build(())
+    }
+}
@@ -0,0 +1,31 @@
+#[cynic::schema_for_derives(file = r#"../../schema/graphql/reactive-graph-schema.graphql"#, module = "crate::schema_graphql::schema")]
+pub mod queries {
+    use cynic::Operation;
+    use cynic::QueryFragment;
+    use cynic::QueryVariables;
+
+    use crate::schema_graphql::types::namespace::Namespace;
+
+    #[derive(QueryVariables, Debug)]
+    pub struct NamespaceNameVariables {
+        pub name: String,
+    }
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Query", variables = "NamespaceNameVariables")]
+    pub struct GetNamespaceByName {
+        pub types: GetNamespaceByNameTypes,
+    }
+
+    #[derive(QueryFragment, Debug)]
+    #[cynic(graphql_type = "Types", variables = "NamespaceNameVariables")]
+    pub struct GetNamespaceByNameTypes {
+        #[arguments(name: $name)]
+        pub namespace_metadata: Vec<Namespace>,
+    }
+
+    pub fn get_namespace_by_name_query(name: &str) -> Operation<GetNamespaceByName, NamespaceNameVariables> {
+        use cynic::QueryBuilder;
+        GetNamespaceByName::build(NamespaceNameVariables { name: name.to_string() })
+    }
+}
@@ -0,0 +1,42 @@
+use crate::schema_graphql::instances::entity_instance::EntityInstance;
+use crate::schema_graphql::pagination::page_info::PageInfo;
+use reactive_graph_graph::Edge;
+use reactive_graph_graph::Page;
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-schema.graphql",
+    schema_module = "crate::schema_graphql::schema"
+)]
+pub struct EntityInstanceConnection {
+    pub edges: Vec<EntityInstanceEdge>,
+    pub page_info: PageInfo,
+    pub total_count: i32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-schema.graphql",
+    schema_module = "crate::schema_graphql::schema"
+)]
+pub struct EntityInstanceEdge {
+    pub cursor: String,
+    pub node: EntityInstance,
+}
+
+impl From<EntityInstanceConnection> for Page<reactive_graph_graph::EntityInstance> {
+    fn from(connection: EntityInstanceConnection) -> Self {
+        Page {
+            edges: connection
+                .edges
+                .into_iter()
+                .map(|edge| Edge {
+                    cursor: edge.cursor,
+                    node: edge.node.into(),
+                })
+                .collect(),
+            page_info: connection.page_info.into(),
+            total_count: connection.total_count.max(0) as usize,
+        }
+    }
+}
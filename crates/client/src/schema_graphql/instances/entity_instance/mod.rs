@@ -1,6 +1,8 @@
 #[allow(clippy::module_inception)]
 pub mod entity_instance;
+pub mod entity_instance_connection;
 pub mod entity_instance_definition;
 
 pub use entity_instance::*;
+pub use entity_instance_connection::*;
 pub use entity_instance_definition::*;
@@ -1,6 +1,8 @@
 #[allow(clippy::module_inception)]
 pub mod flow_instance;
+pub mod flow_instance_connection;
 // pub mod flow_instance_definition;
 
 pub use flow_instance::*;
+pub use flow_instance_connection::*;
 // pub use flow_instance_definition::*;
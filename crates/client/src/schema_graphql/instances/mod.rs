@@ -2,3 +2,4 @@ pub mod entity_instance;
 pub mod flow_instance;
 pub mod property_instance;
 pub mod relation_instance;
+pub mod search_hit;
@@ -1,6 +1,8 @@
 #[allow(clippy::module_inception)]
 pub mod relation_instance;
+pub mod relation_instance_connection;
 // pub mod relation_instance_definition;
 
 pub use relation_instance::*;
+pub use relation_instance_connection::*;
 // pub use relation_instance_definition::*;
@@ -0,0 +1,42 @@
+use crate::schema_graphql::instances::relation_instance::RelationInstance;
+use crate::schema_graphql::pagination::page_info::PageInfo;
+use reactive_graph_graph::Edge;
+use reactive_graph_graph::Page;
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-schema.graphql",
+    schema_module = "crate::schema_graphql::schema"
+)]
+pub struct RelationInstanceConnection {
+    pub edges: Vec<RelationInstanceEdge>,
+    pub page_info: PageInfo,
+    pub total_count: i32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-schema.graphql",
+    schema_module = "crate::schema_graphql::schema"
+)]
+pub struct RelationInstanceEdge {
+    pub cursor: String,
+    pub node: RelationInstance,
+}
+
+impl From<RelationInstanceConnection> for Page<reactive_graph_graph::RelationInstance> {
+    fn from(connection: RelationInstanceConnection) -> Self {
+        Page {
+            edges: connection
+                .edges
+                .into_iter()
+                .map(|edge| Edge {
+                    cursor: edge.cursor,
+                    node: edge.node.into(),
+                })
+                .collect(),
+            page_info: connection.page_info.into(),
+            total_count: connection.total_count.max(0) as usize,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::schema_graphql::instances::entity_instance::EntityInstance;
+use crate::schema_graphql::instances::relation_instance::RelationInstance;
+use reactive_graph_graph::SearchField;
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-schema.graphql",
+    schema_module = "crate::schema_graphql::schema"
+)]
+pub struct SearchHit {
+    pub score: f64,
+    pub fields: Vec<String>,
+    pub entity: Option<EntityInstance>,
+    pub relation: Option<RelationInstance>,
+}
+
+/// An instance found by the full-text search.
+#[derive(Clone, Debug)]
+pub enum SearchHitInstance {
+    Entity(reactive_graph_graph::EntityInstance),
+    Relation(reactive_graph_graph::RelationInstance),
+}
+
+impl TryFrom<SearchHit> for reactive_graph_graph::SearchHit<SearchHitInstance> {
+    type Error = ();
+
+    fn try_from(hit: SearchHit) -> Result<Self, Self::Error> {
+        let instance = match (hit.entity, hit.relation) {
+            (Some(entity_instance), _) => SearchHitInstance::Entity(entity_instance.into()),
+            (None, Some(relation_instance)) => SearchHitInstance::Relation(relation_instance.into()),
+            (None, None) => return Err(()),
+        };
+        let fields = hit.fields.iter().map(|field| SearchField::from(field.as_str())).collect();
+        Ok(reactive_graph_graph::SearchHit::new(instance, hit.score, fields))
+    }
+}
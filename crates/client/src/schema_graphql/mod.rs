@@ -1,6 +1,8 @@
 pub mod instances;
+pub mod pagination;
 pub mod scalar;
 pub mod system;
+pub mod traversal;
 pub mod types;
 
 #[allow(clippy::module_inception)]
@@ -0,0 +1,3 @@
+pub mod page_info;
+pub mod property_sort;
+pub mod sort_direction;
@@ -0,0 +1,22 @@
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-schema.graphql",
+    schema_module = "crate::schema_graphql::schema"
+)]
+pub struct PageInfo {
+    pub has_previous_page: bool,
+    pub has_next_page: bool,
+    pub start_cursor: Option<String>,
+    pub end_cursor: Option<String>,
+}
+
+impl From<PageInfo> for reactive_graph_graph::PageInfo {
+    fn from(page_info: PageInfo) -> Self {
+        reactive_graph_graph::PageInfo {
+            has_previous_page: page_info.has_previous_page,
+            has_next_page: page_info.has_next_page,
+            start_cursor: page_info.start_cursor,
+            end_cursor: page_info.end_cursor,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::schema_graphql::pagination::sort_direction::SortDirection;
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-schema.graphql",
+    schema_module = "crate::schema_graphql::schema"
+)]
+pub struct PropertySort {
+    pub property: String,
+    pub direction: SortDirection,
+}
+
+impl From<reactive_graph_graph::PropertySort> for PropertySort {
+    fn from(sort: reactive_graph_graph::PropertySort) -> Self {
+        PropertySort {
+            property: sort.property,
+            direction: sort.direction.into(),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-schema.graphql",
+    schema_module = "crate::schema_graphql::schema"
+)]
+pub enum SortDirection {
+    /// Sorts from the lowest to the highest value.
+    Ascending,
+
+    /// Sorts from the highest to the lowest value.
+    Descending,
+}
+
+impl From<reactive_graph_graph::SortDirection> for SortDirection {
+    fn from(direction: reactive_graph_graph::SortDirection) -> Self {
+        match direction {
+            reactive_graph_graph::SortDirection::Ascending => SortDirection::Ascending,
+            reactive_graph_graph::SortDirection::Descending => SortDirection::Descending,
+        }
+    }
+}
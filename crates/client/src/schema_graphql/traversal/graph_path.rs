@@ -0,0 +1,25 @@
+use crate::schema_graphql::instances::entity_instance::EntityInstance;
+use crate::schema_graphql::instances::relation_instance::RelationInstance;
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-schema.graphql",
+    schema_module = "crate::schema_graphql::schema"
+)]
+pub struct GraphPath {
+    pub entities: Vec<EntityInstance>,
+    pub relations: Vec<RelationInstance>,
+}
+
+impl From<GraphPath> for reactive_graph_graph::GraphPath {
+    fn from(path: GraphPath) -> Self {
+        reactive_graph_graph::GraphPath {
+            entities: path.entities.into_iter().map(|entity_instance| entity_instance.id.into()).collect(),
+            relations: path
+                .relations
+                .into_iter()
+                .map(|relation_instance| reactive_graph_graph::RelationInstance::from(relation_instance).id())
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod graph_path;
+pub mod traversal_direction;
+pub mod traversal_options;
+pub mod traversal_strategy;
+pub mod traversal_visit;
@@ -0,0 +1,25 @@
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-schema.graphql",
+    schema_module = "crate::schema_graphql::schema"
+)]
+pub enum TraversalDirection {
+    /// Follows the relations in both directions.
+    Both,
+
+    /// Follows the relations from the inbound entity to the outbound entity.
+    Inbound,
+
+    /// Follows the relations from the outbound entity to the inbound entity.
+    Outbound,
+}
+
+impl From<reactive_graph_graph::TraversalDirection> for TraversalDirection {
+    fn from(direction: reactive_graph_graph::TraversalDirection) -> Self {
+        match direction {
+            reactive_graph_graph::TraversalDirection::Both => TraversalDirection::Both,
+            reactive_graph_graph::TraversalDirection::Inbound => TraversalDirection::Inbound,
+            reactive_graph_graph::TraversalDirection::Outbound => TraversalDirection::Outbound,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::schema_graphql::traversal::traversal_direction::TraversalDirection;
+use crate::schema_graphql::traversal::traversal_strategy::TraversalStrategy;
+use crate::schema_graphql::types::relation_type::RelationTypeId;
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-schema.graphql",
+    schema_module = "crate::schema_graphql::schema"
+)]
+pub struct TraversalOptions {
+    pub direction: TraversalDirection,
+    pub relation_types: Vec<RelationTypeId>,
+    pub max_depth: Option<i32>,
+    pub strategy: TraversalStrategy,
+    pub limit: Option<i32>,
+}
+
+impl From<reactive_graph_graph::TraversalOptions> for TraversalOptions {
+    fn from(options: reactive_graph_graph::TraversalOptions) -> Self {
+        TraversalOptions {
+            direction: options.direction.into(),
+            relation_types: options.relation_types.into_iter().map(RelationTypeId::from).collect(),
+            max_depth: options.max_depth.map(|max_depth| max_depth as i32),
+            strategy: options.strategy.into(),
+            limit: options.limit.map(|limit| limit as i32),
+        }
+    }
+}
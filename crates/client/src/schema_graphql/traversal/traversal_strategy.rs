@@ -0,0 +1,21 @@
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-schema.graphql",
+    schema_module = "crate::schema_graphql::schema"
+)]
+pub enum TraversalStrategy {
+    /// Visits the entities level by level.
+    BreadthFirst,
+
+    /// Follows each branch as far as possible before backtracking.
+    DepthFirst,
+}
+
+impl From<reactive_graph_graph::TraversalStrategy> for TraversalStrategy {
+    fn from(strategy: reactive_graph_graph::TraversalStrategy) -> Self {
+        match strategy {
+            reactive_graph_graph::TraversalStrategy::BreadthFirst => TraversalStrategy::BreadthFirst,
+            reactive_graph_graph::TraversalStrategy::DepthFirst => TraversalStrategy::DepthFirst,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::schema_graphql::instances::relation_instance::RelationInstance;
+use crate::schema_graphql::scalar::UUID;
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-schema.graphql",
+    schema_module = "crate::schema_graphql::schema"
+)]
+pub struct TraversalVisit {
+    pub id: UUID,
+    pub depth: i32,
+    pub relation: Option<RelationInstance>,
+}
+
+impl From<TraversalVisit> for reactive_graph_graph::TraversalVisit {
+    fn from(visit: TraversalVisit) -> Self {
+        let relation = visit
+            .relation
+            .map(|relation_instance| reactive_graph_graph::RelationInstance::from(relation_instance).id());
+        reactive_graph_graph::TraversalVisit::new(visit.id.into(), visit.depth as usize, relation)
+    }
+}
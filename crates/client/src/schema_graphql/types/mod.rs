@@ -4,6 +4,7 @@ pub mod entity_type;
 pub mod extension;
 pub mod flow_type;
 pub mod mutability;
+pub mod namespace;
 pub mod property_type;
 pub mod relation_type;
 pub mod socket_type;
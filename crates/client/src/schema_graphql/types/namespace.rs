@@ -0,0 +1,37 @@
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-schema.graphql",
+    schema_module = "crate::schema_graphql::schema"
+)]
+pub struct Namespace {
+    /// The name of the namespace.
+    pub name: String,
+
+    /// Textual description of the namespace.
+    pub description: String,
+
+    /// The name of the plugin which owns the namespace.
+    pub owner: Option<String>,
+
+    /// The version of the types of the namespace.
+    pub version: Option<String>,
+
+    /// The tags of the namespace.
+    pub tags: Vec<String>,
+
+    /// Links to the documentation of the namespace.
+    pub documentation: Vec<String>,
+}
+
+impl From<Namespace> for reactive_graph_graph::Namespace {
+    fn from(namespace: Namespace) -> Self {
+        reactive_graph_graph::Namespace {
+            name: namespace.name,
+            description: namespace.description,
+            owner: namespace.owner,
+            version: namespace.version,
+            tags: namespace.tags,
+            documentation: namespace.documentation,
+        }
+    }
+}
@@ -57,3 +57,14 @@ impl From<&Plugin> for reactive_graph_plugin_model::Plugin {
         }
     }
 }
+
+#[derive(Clone, Debug, cynic::QueryFragment)]
+#[cynic(
+    schema_path = "../../schema/graphql/reactive-graph-plugin-schema.graphql",
+    schema_module = "crate::schema_plugin::schema"
+)]
+pub struct PluginResolverDiagnostics {
+    pub report: String,
+    pub dot: String,
+    pub mermaid: String,
+}
@@ -7,6 +7,12 @@ const DEFAULT_HOT_DEPLOY_LOCATION: &str = "./plugins/deploy";
 
 const DEFAULT_INSTALL_LOCATION: &str = "./plugins/installed";
 
+const DEFAULT_CONFIG_LOCATION: &str = "./config/plugins";
+
+const DEFAULT_PLUGIN_HOST: &str = "reactive-graph-plugin-host";
+
+const DEFAULT_PLUGIN_HOST_MAX_RESTARTS: u32 = 5;
+
 /// Configuration of the plugin system.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PluginsConfig {
@@ -25,8 +31,33 @@ pub struct PluginsConfig {
     /// The folder which is watched for hot deployment.
     pub hot_deploy_location: Option<String>,
 
+    /// If true, hot deployed new versions of active plugins are upgraded side by side and the
+    /// state of their behaviours is handed over, instead of stopping and redeploying them.
+    pub hot_upgrade: Option<bool>,
+
     /// The folder which plugins are installed permanently.
     pub install_location: Option<String>,
+
+    /// The folder which contains the configuration files of the plugins.
+    pub config_location: Option<String>,
+
+    /// The base64 encoded ed25519 public keys which are trusted to sign plugin packages.
+    pub trusted_keys: Option<Vec<String>>,
+
+    /// If true, only plugin packages with a manifest signed by a trusted key are loaded.
+    pub require_signature: Option<bool>,
+
+    /// If true, plugins which don't declare capabilities get no capabilities instead of full access.
+    pub deny_undeclared_capabilities: Option<bool>,
+
+    /// The plugins which are running out of process in a plugin host.
+    pub isolated_plugins: Option<Vec<String>>,
+
+    /// The path of the plugin host executable.
+    pub plugin_host: Option<String>,
+
+    /// The maximum number of restarts of a crashed plugin host.
+    pub plugin_host_max_restarts: Option<u32>,
 }
 
 impl PluginsConfig {
@@ -41,9 +72,51 @@ impl PluginsConfig {
         fs::canonicalize(PathBuf::from(self.hot_deploy_location.clone().unwrap_or(DEFAULT_HOT_DEPLOY_LOCATION.to_string()))).ok()
     }
 
+    pub fn is_hot_upgrade(&self) -> bool {
+        self.hot_upgrade.unwrap_or(false)
+    }
+
     pub fn get_install_location(&self) -> Option<PathBuf> {
         fs::canonicalize(PathBuf::from(self.install_location.clone().unwrap_or(DEFAULT_INSTALL_LOCATION.to_string()))).ok()
     }
+
+    pub fn get_config_location(&self) -> PathBuf {
+        PathBuf::from(self.config_location.clone().unwrap_or(DEFAULT_CONFIG_LOCATION.to_string()))
+    }
+
+    pub fn get_trusted_keys(&self) -> Vec<String> {
+        self.trusted_keys.clone().unwrap_or_default()
+    }
+
+    pub fn is_signature_required(&self) -> bool {
+        self.require_signature.unwrap_or(false)
+    }
+
+    pub fn is_deny_undeclared_capabilities(&self) -> bool {
+        self.deny_undeclared_capabilities.unwrap_or(false)
+    }
+
+    pub fn get_isolated_plugins(&self) -> Vec<String> {
+        self.isolated_plugins.clone().unwrap_or_default()
+    }
+
+    /// Returns the path of the plugin host executable.
+    ///
+    /// Defaults to the plugin host which is located next to the current executable.
+    pub fn get_plugin_host(&self) -> PathBuf {
+        if let Some(plugin_host) = self.plugin_host.clone() {
+            return PathBuf::from(plugin_host);
+        }
+        let plugin_host = format!("{DEFAULT_PLUGIN_HOST}{}", std::env::consts::EXE_SUFFIX);
+        std::env::current_exe()
+            .ok()
+            .and_then(|current_exe| current_exe.parent().map(|directory| directory.join(&plugin_host)))
+            .unwrap_or(PathBuf::from(plugin_host))
+    }
+
+    pub fn get_plugin_host_max_restarts(&self) -> u32 {
+        self.plugin_host_max_restarts.unwrap_or(DEFAULT_PLUGIN_HOST_MAX_RESTARTS)
+    }
 }
 
 impl Default for PluginsConfig {
@@ -54,7 +127,15 @@ impl Default for PluginsConfig {
             enabled_plugins: None,
             hot_deploy: Some(true),
             hot_deploy_location: Some(DEFAULT_HOT_DEPLOY_LOCATION.to_string()),
+            hot_upgrade: Some(false),
             install_location: Some(DEFAULT_INSTALL_LOCATION.to_string()),
+            config_location: Some(DEFAULT_CONFIG_LOCATION.to_string()),
+            trusted_keys: Some(Vec::new()),
+            require_signature: Some(false),
+            deny_undeclared_capabilities: Some(false),
+            isolated_plugins: Some(Vec::new()),
+            plugin_host: None,
+            plugin_host_max_restarts: Some(DEFAULT_PLUGIN_HOST_MAX_RESTARTS),
         }
     }
 }
@@ -0,0 +1,100 @@
+use async_graphql::parser::types::FieldDefinition;
+use async_graphql::parser::types::InputValueDefinition;
+use async_graphql::parser::types::Type;
+
+use crate::schema::DynamicGraphSchema;
+use crate::schema::SchemaNamespace;
+use crate::schema::named_type;
+
+/// A typed query of a type of a namespace.
+pub struct QueryDocument<'a> {
+    /// The name of the operation.
+    pub operation_name: String,
+
+    /// The name of the field of the namespace in the query type.
+    pub namespace_field: String,
+
+    /// The queried field of the namespace.
+    pub field: &'a FieldDefinition,
+
+    /// The fields which are selected from the result.
+    pub selection: Vec<&'a FieldDefinition>,
+}
+
+impl<'a> QueryDocument<'a> {
+    /// Returns the arguments of the queried field.
+    pub fn arguments(&self) -> Vec<&'a InputValueDefinition> {
+        self.field.arguments.iter().map(|argument| &argument.node).collect()
+    }
+
+    /// Returns the name of the type of the queried field.
+    pub fn result_type(&self) -> &'a Type {
+        &self.field.ty.node
+    }
+
+    /// Returns the query document.
+    pub fn to_graphql(&self) -> String {
+        let arguments = self.arguments();
+        let mut document = format!("query {}", self.operation_name);
+        if !arguments.is_empty() {
+            let variables: Vec<String> = arguments
+                .iter()
+                .map(|argument| format!("${}: {}", argument.name.node, argument.ty.node))
+                .collect();
+            document.push_str(&format!("({})", variables.join(", ")));
+        }
+        document.push_str(" {\n");
+        document.push_str(&format!("  {} {{\n", self.namespace_field));
+        document.push_str(&format!("    {}", self.field.name.node));
+        if !arguments.is_empty() {
+            let arguments: Vec<String> = arguments.iter().map(|argument| format!("{0}: ${0}", argument.name.node)).collect();
+            document.push_str(&format!("({})", arguments.join(", ")));
+        }
+        document.push_str(" {\n");
+        if self.selection.is_empty() {
+            document.push_str("      __typename\n");
+        }
+        for field in &self.selection {
+            document.push_str(&format!("      {}\n", field.name.node));
+        }
+        document.push_str("    }\n  }\n}\n");
+        document
+    }
+}
+
+/// Returns a query for each type of the namespace.
+///
+/// Only fields returning object types or interfaces are queried. The scalar fields without
+/// arguments of the result type are selected.
+pub fn namespace_queries<'a>(schema: &'a DynamicGraphSchema, namespace: &SchemaNamespace<'a>) -> Vec<QueryDocument<'a>> {
+    let namespace_type = namespace.type_definition.name.node.as_str();
+    schema
+        .fields(namespace_type)
+        .into_iter()
+        .filter(|field| schema.is_composite(named_type(&field.ty.node)))
+        .map(|field| QueryDocument {
+            operation_name: format!("{}{}", pascal_case(&namespace.field_name), pascal_case(&field.name.node)),
+            namespace_field: namespace.field_name.clone(),
+            field,
+            selection: selection(schema, named_type(&field.ty.node)),
+        })
+        .collect()
+}
+
+/// Returns the scalar fields without arguments of the given type.
+pub fn selection<'a>(schema: &'a DynamicGraphSchema, type_name: &str) -> Vec<&'a FieldDefinition> {
+    schema
+        .fields(type_name)
+        .into_iter()
+        .filter(|field| field.arguments.is_empty() && schema.is_leaf(named_type(&field.ty.node)))
+        .collect()
+}
+
+/// Converts a camel case name into pascal case.
+pub fn pascal_case(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
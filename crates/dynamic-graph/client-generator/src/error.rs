@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ClientGeneratorError {
+    #[error("Failed to parse the dynamic graph schema: {0}")]
+    ParseSchema(#[from] async_graphql::parser::Error),
+    #[error("The dynamic graph schema has no query type {0}")]
+    MissingQueryType(String),
+    #[error("Invalid bearer token: {0}")]
+    InvalidBearer(#[from] reqwest::header::InvalidHeaderValue),
+    #[error("Failed to create the http client: {0}")]
+    HttpClient(#[from] reqwest::Error),
+    #[error("Failed to send the introspection query: {0}")]
+    IntrospectionRequest(#[from] cynic::http::CynicReqwestError),
+    #[error("The introspection query returned no data")]
+    IntrospectionQuery,
+    #[error("The introspection query returned an invalid schema: {0:?}")]
+    IntrospectionSchema(cynic_introspection::SchemaError),
+    #[error("Failed to write the generated client to {0}: {1}")]
+    WriteClient(PathBuf, std::io::Error),
+}
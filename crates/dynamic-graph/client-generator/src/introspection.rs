@@ -0,0 +1,28 @@
+use cynic::QueryBuilder;
+use cynic::http::ReqwestExt;
+use cynic_introspection::IntrospectionQuery;
+use reqwest::Client;
+use reqwest::header::AUTHORIZATION;
+use reqwest::header::HeaderValue;
+
+use crate::error::ClientGeneratorError;
+
+/// Fetches the schema of a running dynamic graph endpoint using an introspection query and
+/// returns it as SDL.
+pub async fn fetch_schema(url: &str, bearer: Option<&str>) -> Result<String, ClientGeneratorError> {
+    let mut client_builder = Client::builder();
+    if let Some(bearer) = bearer {
+        let header_value = HeaderValue::from_str(&format!("Bearer {bearer}"))?;
+        client_builder = client_builder.default_headers(std::iter::once((AUTHORIZATION, header_value)).collect());
+    }
+    let schema = client_builder
+        .build()?
+        .post(url)
+        .run_graphql(IntrospectionQuery::build(()))
+        .await?
+        .data
+        .ok_or(ClientGeneratorError::IntrospectionQuery)?
+        .into_schema()
+        .map_err(ClientGeneratorError::IntrospectionSchema)?;
+    Ok(schema.to_sdl())
+}
@@ -0,0 +1,155 @@
+//! Generates TypeScript types and typed query documents from the schema of the dynamic graph.
+//!
+//! For each namespace a TypeScript module and a GraphQL document are generated. The modules
+//! contains the types of the results and the variables of the queries of the entity types,
+//! relation types, flow types and components of the namespace.
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+pub use error::ClientGeneratorError;
+pub use introspection::fetch_schema;
+pub use schema::DynamicGraphSchema;
+
+use crate::document::namespace_queries;
+use crate::typescript::TYPED_DOCUMENT;
+use crate::typescript::TYPED_DOCUMENT_MODULE;
+use crate::typescript::TypeScriptModule;
+
+pub mod document;
+pub mod error;
+pub mod introspection;
+pub mod schema;
+pub mod typescript;
+
+/// The generated client.
+#[derive(Debug, Clone, Default)]
+pub struct GeneratedClient {
+    /// The generated files, relative to the output directory.
+    pub files: Vec<(PathBuf, String)>,
+}
+
+impl GeneratedClient {
+    /// Returns the content of the file with the given path.
+    pub fn get<P: AsRef<Path>>(&self, path: P) -> Option<&str> {
+        self.files.iter().find(|(file, _)| file == path.as_ref()).map(|(_, content)| content.as_str())
+    }
+
+    /// Writes the generated files into the given directory.
+    pub fn write_to_directory<P: AsRef<Path>>(&self, directory: P) -> Result<Vec<PathBuf>, ClientGeneratorError> {
+        let directory = directory.as_ref();
+        fs::create_dir_all(directory).map_err(|e| ClientGeneratorError::WriteClient(directory.to_path_buf(), e))?;
+        let mut paths = Vec::new();
+        for (path, content) in &self.files {
+            let path = directory.join(path);
+            fs::write(&path, content).map_err(|e| ClientGeneratorError::WriteClient(path.clone(), e))?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+}
+
+/// Generates the TypeScript types and the query documents from the given schema of the
+/// dynamic graph.
+pub fn generate(sdl: &str) -> Result<GeneratedClient, ClientGeneratorError> {
+    let schema = DynamicGraphSchema::parse(sdl)?;
+    let mut files = vec![(PathBuf::from(format!("{TYPED_DOCUMENT_MODULE}.ts")), TYPED_DOCUMENT.to_string())];
+    let mut index = Vec::new();
+    for namespace in schema.namespaces() {
+        let queries = namespace_queries(&schema, &namespace);
+        if queries.is_empty() {
+            continue;
+        }
+        let documents: Vec<String> = queries.iter().map(|query| query.to_graphql()).collect();
+        files.push((PathBuf::from(format!("{}.graphql", namespace.field_name)), documents.join("\n")));
+        files.push((PathBuf::from(format!("{}.ts", namespace.field_name)), TypeScriptModule::new(&schema).generate(&queries)));
+        index.push(format!("export * as {0} from \"./{0}\";", namespace.field_name));
+    }
+    index.push(format!("export type {{ TypedQueryDocument }} from \"./{TYPED_DOCUMENT_MODULE}\";"));
+    files.push((PathBuf::from("index.ts"), index.join("\n") + "\n"));
+    Ok(GeneratedClient { files })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate;
+
+    const SCHEMA: &str = r#"
+        scalar JSON
+
+        enum SortDirection {
+          ASC
+          DESC
+        }
+
+        input ShutdownFilter {
+          delay: Int
+          direction: SortDirection
+        }
+
+        interface Core_Labeled_Component {
+          label: String
+        }
+
+        "Shutting down the runtime"
+        type Core_Shutdown_Entity implements Core_Labeled_Component {
+          id: ID!
+          label: String
+          delay: Int
+          tags: [String!]
+          outbound(limit: Int): [Core_Shutdown_Entity!]!
+        }
+
+        "Queries for components, entities and relations on the namespace Core"
+        type CoreNamespace {
+          "Shutting down the runtime"
+          shutdown(id: String, label: String, filter: ShutdownFilter): [Core_Shutdown_Entity!]!
+          labeled: [Core_Labeled_Component!]!
+          shutdownSchema: JSON!
+        }
+
+        type NamespaceMetrics {
+          entityTypes: Int!
+        }
+
+        type Query {
+          core: CoreNamespace!
+          metrics: NamespaceMetrics!
+        }
+    "#;
+
+    #[test]
+    fn test_generate_graphql_documents() {
+        let client = generate(SCHEMA).expect("Failed to generate client");
+        assert!(client.get("metrics.graphql").is_none());
+        let documents = client.get("core.graphql").expect("Missing GraphQL documents of namespace core");
+        assert!(documents.contains("query CoreShutdown($id: String, $label: String, $filter: ShutdownFilter) {"));
+        assert!(documents.contains("    shutdown(id: $id, label: $label, filter: $filter) {"));
+        assert!(documents.contains("      delay\n      tags\n"));
+        // Fields with arguments are not selected
+        assert!(!documents.contains("outbound"));
+        assert!(documents.contains("query CoreLabeled {"));
+        // JSON schema fields are not queried
+        assert!(!documents.contains("shutdownSchema"));
+    }
+
+    #[test]
+    fn test_generate_typescript() {
+        let client = generate(SCHEMA).expect("Failed to generate client");
+        let module = client.get("core.ts").expect("Missing TypeScript module of namespace core");
+        assert!(module.contains("export type SortDirection = \"ASC\" | \"DESC\";"));
+        assert!(module.contains("export interface ShutdownFilter {\n  delay?: number | null;\n  direction?: SortDirection | null;\n}"));
+        assert!(module.contains(" * Shutting down the runtime\n */\nexport interface Core_Shutdown_Entity {"));
+        assert!(module.contains("  id: string;"));
+        assert!(module.contains("  tags: string[] | null;"));
+        assert!(module.contains("export interface CoreShutdownVariables {\n  id?: string | null;"));
+        assert!(module.contains("export interface CoreShutdownResult {\n  core: {\n    shutdown: Core_Shutdown_Entity[];\n  };\n}"));
+        assert!(module.contains("export type CoreLabeledVariables = Record<string, never>;"));
+        assert!(module.contains("as TypedQueryDocument<CoreShutdownResult, CoreShutdownVariables>;"));
+
+        let index = client.get("index.ts").expect("Missing index");
+        assert!(index.contains("export * as core from \"./core\";"));
+        assert!(client.get("typed-document.ts").is_some());
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use async_graphql::parser::parse_schema;
+use async_graphql::parser::types::BaseType;
+use async_graphql::parser::types::FieldDefinition;
+use async_graphql::parser::types::InputValueDefinition;
+use async_graphql::parser::types::Type;
+use async_graphql::parser::types::TypeDefinition;
+use async_graphql::parser::types::TypeKind;
+use async_graphql::parser::types::TypeSystemDefinition;
+
+use crate::error::ClientGeneratorError;
+
+/// The name of the query type if the schema doesn't define it.
+pub const DEFAULT_QUERY_TYPE: &str = "Query";
+
+/// The dynamic graph names the object types of the namespaces with this suffix.
+pub const NAMESPACE_TYPE_SUFFIX: &str = "Namespace";
+
+/// The scalars which are built into GraphQL.
+pub const BUILTIN_SCALARS: [&str; 5] = ["String", "ID", "Int", "Float", "Boolean"];
+
+/// A namespace of the dynamic graph.
+pub struct SchemaNamespace<'a> {
+    /// The name of the field of the namespace in the query type.
+    pub field_name: String,
+
+    /// The object type of the namespace.
+    pub type_definition: &'a TypeDefinition,
+}
+
+/// The parsed schema of the dynamic graph.
+pub struct DynamicGraphSchema {
+    types: HashMap<String, TypeDefinition>,
+    query_type: String,
+}
+
+impl DynamicGraphSchema {
+    /// Parses the given schema definition language.
+    pub fn parse(sdl: &str) -> Result<DynamicGraphSchema, ClientGeneratorError> {
+        let document = parse_schema(sdl)?;
+        let mut types = HashMap::new();
+        let mut query_type = DEFAULT_QUERY_TYPE.to_string();
+        for definition in document.definitions {
+            match definition {
+                TypeSystemDefinition::Schema(schema) => {
+                    if let Some(query) = schema.node.query {
+                        query_type = query.node.to_string();
+                    }
+                }
+                TypeSystemDefinition::Type(type_definition) => {
+                    types.insert(type_definition.node.name.node.to_string(), type_definition.node);
+                }
+                TypeSystemDefinition::Directive(_) => {}
+            }
+        }
+        if !types.contains_key(&query_type) {
+            return Err(ClientGeneratorError::MissingQueryType(query_type));
+        }
+        Ok(DynamicGraphSchema { types, query_type })
+    }
+
+    /// Returns the type with the given name.
+    pub fn get(&self, name: &str) -> Option<&TypeDefinition> {
+        self.types.get(name)
+    }
+
+    /// Returns the namespaces, sorted by the name of their fields.
+    pub fn namespaces(&self) -> Vec<SchemaNamespace<'_>> {
+        let mut namespaces: Vec<SchemaNamespace> = self
+            .fields(&self.query_type)
+            .iter()
+            .filter_map(|field| {
+                let type_name = named_type(&field.ty);
+                if !type_name.ends_with(NAMESPACE_TYPE_SUFFIX) {
+                    return None;
+                }
+                let type_definition = self.get(type_name)?;
+                match type_definition.kind {
+                    TypeKind::Object(_) => Some(SchemaNamespace {
+                        field_name: field.name.node.to_string(),
+                        type_definition,
+                    }),
+                    _ => None,
+                }
+            })
+            .collect();
+        namespaces.sort_by(|a, b| a.field_name.cmp(&b.field_name));
+        namespaces
+    }
+
+    /// Returns the fields of the object type or interface with the given name.
+    pub fn fields(&self, name: &str) -> Vec<&FieldDefinition> {
+        match self.get(name).map(|type_definition| &type_definition.kind) {
+            Some(TypeKind::Object(object)) => object.fields.iter().map(|field| &field.node).collect(),
+            Some(TypeKind::Interface(interface)) => interface.fields.iter().map(|field| &field.node).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the fields of the input object with the given name.
+    pub fn input_fields(&self, name: &str) -> Vec<&InputValueDefinition> {
+        match self.get(name).map(|type_definition| &type_definition.kind) {
+            Some(TypeKind::InputObject(input_object)) => input_object.fields.iter().map(|field| &field.node).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the values of the enum with the given name.
+    pub fn enum_values(&self, name: &str) -> Vec<String> {
+        match self.get(name).map(|type_definition| &type_definition.kind) {
+            Some(TypeKind::Enum(enum_type)) => enum_type.values.iter().map(|value| value.node.value.node.to_string()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns true, if the type with the given name is a scalar or an enum.
+    pub fn is_leaf(&self, name: &str) -> bool {
+        BUILTIN_SCALARS.contains(&name) || matches!(self.get(name).map(|type_definition| &type_definition.kind), Some(TypeKind::Scalar | TypeKind::Enum(_)))
+    }
+
+    /// Returns true, if the type with the given name is an object type or an interface.
+    pub fn is_composite(&self, name: &str) -> bool {
+        matches!(
+            self.get(name).map(|type_definition| &type_definition.kind),
+            Some(TypeKind::Object(_) | TypeKind::Interface(_))
+        )
+    }
+
+    /// Returns true, if the type with the given name is an enum.
+    pub fn is_enum(&self, name: &str) -> bool {
+        matches!(self.get(name).map(|type_definition| &type_definition.kind), Some(TypeKind::Enum(_)))
+    }
+
+    /// Returns true, if the type with the given name is an input object.
+    pub fn is_input_object(&self, name: &str) -> bool {
+        matches!(self.get(name).map(|type_definition| &type_definition.kind), Some(TypeKind::InputObject(_)))
+    }
+}
+
+/// Returns the name of the named type, ignoring lists and non null.
+pub fn named_type(ty: &Type) -> &str {
+    match &ty.base {
+        BaseType::Named(name) => name.as_str(),
+        BaseType::List(ty) => named_type(ty),
+    }
+}
+
+/// Returns the description of the type.
+pub fn type_description(type_definition: &TypeDefinition) -> Option<&str> {
+    type_definition.description.as_ref().map(|description| description.node.as_str())
+}
@@ -0,0 +1,201 @@
+use std::collections::BTreeSet;
+
+use async_graphql::parser::types::BaseType;
+use async_graphql::parser::types::Type;
+
+use crate::document::QueryDocument;
+use crate::schema::DynamicGraphSchema;
+use crate::schema::named_type;
+use crate::schema::type_description;
+
+/// The module which defines the typed query document.
+pub const TYPED_DOCUMENT_MODULE: &str = "typed-document";
+
+/// Defines a query document which carries the types of its result and its variables.
+pub const TYPED_DOCUMENT: &str = r#"/**
+ * A GraphQL query document which carries the types of its result and its variables.
+ */
+export type TypedQueryDocument<Result, Variables> = string & {
+  readonly __result?: Result;
+  readonly __variables?: Variables;
+};
+"#;
+
+/// Returns the TypeScript type of the named GraphQL type.
+pub fn scalar_type(name: &str) -> &str {
+    match name {
+        "String" | "ID" | "UUID" => "string",
+        "Int" | "Float" => "number",
+        "Boolean" => "boolean",
+        _ => "unknown",
+    }
+}
+
+/// Generates the TypeScript module of a namespace.
+pub struct TypeScriptModule<'a> {
+    schema: &'a DynamicGraphSchema,
+    objects: BTreeSet<String>,
+    inputs: BTreeSet<String>,
+    enums: BTreeSet<String>,
+}
+
+impl<'a> TypeScriptModule<'a> {
+    pub fn new(schema: &'a DynamicGraphSchema) -> Self {
+        TypeScriptModule {
+            schema,
+            objects: BTreeSet::new(),
+            inputs: BTreeSet::new(),
+            enums: BTreeSet::new(),
+        }
+    }
+
+    /// Generates the module containing the types and the query documents of the given queries.
+    pub fn generate(mut self, queries: &[QueryDocument]) -> String {
+        for query in queries {
+            self.objects.insert(named_type(query.result_type()).to_string());
+            for field in &query.selection {
+                self.collect(&field.ty.node);
+            }
+            for argument in query.arguments() {
+                self.collect(&argument.ty.node);
+            }
+        }
+        let mut items = Vec::new();
+        items.push(format!("import type {{ TypedQueryDocument }} from \"./{TYPED_DOCUMENT_MODULE}\";"));
+        for name in &self.enums {
+            items.push(self.enum_type(name));
+        }
+        for name in &self.inputs {
+            items.push(self.input_type(name));
+        }
+        for name in &self.objects {
+            items.push(self.object_type(name, queries));
+        }
+        for query in queries {
+            items.push(self.query(query));
+        }
+        let mut module = items.join("\n\n");
+        module.push('\n');
+        module
+    }
+
+    /// Collects the enums and input objects which are referenced by the given type.
+    fn collect(&mut self, ty: &Type) {
+        let name = named_type(ty);
+        if self.schema.is_enum(name) {
+            self.enums.insert(name.to_string());
+        } else if self.schema.is_input_object(name) && self.inputs.insert(name.to_string()) {
+            let field_types: Vec<Type> = self.schema.input_fields(name).iter().map(|field| field.ty.node.clone()).collect();
+            for ty in field_types {
+                self.collect(&ty);
+            }
+        }
+    }
+
+    fn enum_type(&self, name: &str) -> String {
+        let values: Vec<String> = self.schema.enum_values(name).iter().map(|value| format!("\"{value}\"")).collect();
+        format!("{}export type {name} = {};", self.type_doc_comment(name), values.join(" | "))
+    }
+
+    fn input_type(&self, name: &str) -> String {
+        let fields: Vec<String> = self
+            .schema
+            .input_fields(name)
+            .iter()
+            .map(|field| {
+                let optional = if field.ty.node.nullable { "?" } else { "" };
+                format!("  {}{optional}: {};", field.name.node, self.ts_type(&field.ty.node))
+            })
+            .collect();
+        format!("{}export interface {name} {{\n{}\n}}", self.type_doc_comment(name), fields.join("\n"))
+    }
+
+    /// The object types contain the fields which are selected by the queries.
+    fn object_type(&self, name: &str, queries: &[QueryDocument]) -> String {
+        let mut selected = BTreeSet::new();
+        for query in queries.iter().filter(|query| named_type(query.result_type()) == name) {
+            for field in &query.selection {
+                selected.insert((field.name.node.to_string(), self.ts_type(&field.ty.node)));
+            }
+        }
+        let fields: Vec<String> = selected.iter().map(|(field, ty)| format!("  {field}: {ty};")).collect();
+        if fields.is_empty() {
+            return format!("{}export interface {name} {{\n  __typename: string;\n}}", self.type_doc_comment(name));
+        }
+        format!("{}export interface {name} {{\n{}\n}}", self.type_doc_comment(name), fields.join("\n"))
+    }
+
+    fn query(&self, query: &QueryDocument) -> String {
+        let result = format!("{}Result", query.operation_name);
+        let variables = format!("{}Variables", query.operation_name);
+        let mut items = Vec::new();
+        let arguments = query.arguments();
+        if arguments.is_empty() {
+            items.push(format!("export type {variables} = Record<string, never>;"));
+        } else {
+            let fields: Vec<String> = arguments
+                .iter()
+                .map(|argument| {
+                    let optional = if argument.ty.node.nullable { "?" } else { "" };
+                    format!("  {}{optional}: {};", argument.name.node, self.ts_type(&argument.ty.node))
+                })
+                .collect();
+            items.push(format!("export interface {variables} {{\n{}\n}}", fields.join("\n")));
+        }
+        items.push(format!(
+            "export interface {result} {{\n  {}: {{\n    {}: {};\n  }};\n}}",
+            query.namespace_field,
+            query.field.name.node,
+            self.ts_type(query.result_type())
+        ));
+        let description = query
+            .field
+            .description
+            .as_ref()
+            .map(|description| description.node.as_str())
+            .unwrap_or_default();
+        items.push(format!(
+            "{}export const {}Document = `\n{}` as TypedQueryDocument<{result}, {variables}>;",
+            doc_comment(description),
+            query.operation_name,
+            query.to_graphql()
+        ));
+        items.join("\n\n")
+    }
+
+    /// Returns the TypeScript type of the given GraphQL type.
+    fn ts_type(&self, ty: &Type) -> String {
+        let base = match &ty.base {
+            BaseType::Named(name) => {
+                let name = name.as_str();
+                if self.schema.is_leaf(name) && !self.schema.is_enum(name) {
+                    scalar_type(name).to_string()
+                } else {
+                    name.to_string()
+                }
+            }
+            BaseType::List(ty) => {
+                let item = self.ts_type(ty);
+                if ty.nullable { format!("({item})[]") } else { format!("{item}[]") }
+            }
+        };
+        if ty.nullable { format!("{base} | null") } else { base }
+    }
+
+    fn type_doc_comment(&self, name: &str) -> String {
+        doc_comment(self.schema.get(name).and_then(type_description).unwrap_or_default())
+    }
+}
+
+fn doc_comment(description: &str) -> String {
+    let lines: Vec<&str> = description.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+    if lines.is_empty() {
+        return String::new();
+    }
+    let mut comment = "/**\n".to_string();
+    for line in lines {
+        comment.push_str(&format!(" * {}\n", line.replace("*/", "*\\/")));
+    }
+    comment.push_str(" */\n");
+    comment
+}
@@ -17,6 +17,7 @@ use springtime_di::Component;
 use springtime_di::component_alias;
 use uuid::Uuid;
 
+use crate::input::get_inputs;
 use crate::interface::component::get_interfaces;
 use crate::object::entity::mutation::register_entity_type_mutation_objects;
 use crate::object::entity::query::register_entity_type_query_objects;
@@ -84,6 +85,7 @@ pub struct DynamicGraphSchemaManagerImpl {
 
 async fn build_dynamic_schema(context: SchemaBuilderContext, schema: SchemaBuilder) -> Result<Schema, SchemaError> {
     let mut schema = get_scalars(schema);
+    schema = get_inputs(schema);
     schema = get_interfaces(schema, &context);
     schema = get_unions(schema, &context);
     schema = register_entity_type_query_objects(schema, &context);
@@ -99,6 +101,7 @@ async fn build_dynamic_schema(context: SchemaBuilderContext, schema: SchemaBuild
 
 fn build_dynamic_schema_sync(context: SchemaBuilderContext, schema: SchemaBuilder) -> Result<Schema, SchemaError> {
     let mut schema = get_scalars(schema);
+    schema = get_inputs(schema);
     schema = get_interfaces(schema, &context);
     schema = get_unions(schema, &context);
     schema = register_entity_type_query_objects(schema, &context);
@@ -1,5 +1,6 @@
-use crate::field::property::field_arguments::add_entity_type_properties_as_field_arguments;
+use crate::field::property::field_arguments::add_entity_type_properties_as_filter_arguments;
 use crate::field::property::filter::get_entity_instances_by_type_filter_by_properties;
+use crate::input::property_filter::property_filter_argument;
 use crate::object::types::DynamicGraphTypeDefinition;
 use async_graphql::dynamic::Field;
 use async_graphql::dynamic::FieldFuture;
@@ -54,7 +55,7 @@ pub fn entity_mutation_field(entity_type: &EntityType) -> Option<Field> {
                 return Ok(Some(field_value));
             }
             // TODO: implement label matching
-            let instances = get_entity_instances_by_type_filter_by_properties(&ctx, &entity_type, entity_instance_manager);
+            let instances = get_entity_instances_by_type_filter_by_properties(&ctx, &entity_type, entity_instance_manager)?;
             let field_value = FieldValue::owned_any(instances);
             Ok(Some(field_value))
         })
@@ -63,7 +64,8 @@ pub fn entity_mutation_field(entity_type: &EntityType) -> Option<Field> {
     .argument(InputValue::new("ids", TypeRef::named_nn_list(TypeRef::ID)))
     .argument(InputValue::new("id", TypeRef::named(TypeRef::ID)))
     // TODO: implement label matching
-    .argument(InputValue::new("label", TypeRef::named(TypeRef::STRING)));
-    field = add_entity_type_properties_as_field_arguments(field, entity_type, true, true);
+    .argument(InputValue::new("label", TypeRef::named(TypeRef::STRING)))
+    .argument(property_filter_argument());
+    field = add_entity_type_properties_as_filter_arguments(field, entity_type);
     Some(field)
 }
@@ -1,4 +1,5 @@
 use crate::field::to_input_type_ref;
+use crate::input::property_filter::is_property_argument;
 use async_graphql::dynamic::Field;
 use async_graphql::dynamic::InputValue;
 use reactive_graph_graph::EntityType;
@@ -16,3 +17,17 @@ pub fn add_entity_type_properties_as_field_arguments(mut field: Field, entity_ty
     }
     field
 }
+
+/// Adds the properties of the entity type as optional field arguments to a field which also provides
+/// the property filter argument.
+pub fn add_entity_type_properties_as_filter_arguments(mut field: Field, entity_type: &EntityType) -> Field {
+    for property in entity_type.properties.iter() {
+        if property.name == LABEL.property_name() || !is_property_argument(&property.name) {
+            continue;
+        }
+        if let Some(type_ref) = to_input_type_ref(property.value(), true) {
+            field = field.argument(InputValue::new(&property.name, type_ref));
+        }
+    }
+    field
+}
@@ -1,3 +1,5 @@
+use crate::input::property_filter::get_property_filter;
+use crate::input::property_filter::is_property_argument;
 use async_graphql::dynamic::ResolverContext;
 use reactive_graph_graph::DataType;
 use reactive_graph_graph::EntityType;
@@ -11,9 +13,14 @@ pub fn get_entity_instances_by_type_filter_by_properties(
     ctx: &ResolverContext,
     entity_type: &EntityType,
     entity_instance_manager: &Arc<dyn ReactiveEntityManager + Send + Sync>,
-) -> Vec<ReactiveEntity> {
-    let mut instances = entity_instance_manager.get_by_type(&entity_type.ty);
-    for property in entity_type.properties.iter() {
+) -> async_graphql::Result<Vec<ReactiveEntity>> {
+    let filter = get_property_filter(ctx)?;
+    // The property filter is applied first, because it may use the property indexes
+    let mut instances = match &filter {
+        Some(filter) => entity_instance_manager.get_by_type_and_filter(&entity_type.ty, filter),
+        None => entity_instance_manager.get_by_type(&entity_type.ty),
+    };
+    for property in entity_type.properties.iter().filter(|property| is_property_argument(&property.name)) {
         let Some(expected_value) = ctx.args.get(&property.name) else {
             continue;
         };
@@ -77,5 +84,5 @@ pub fn get_entity_instances_by_type_filter_by_properties(
             None => false,
         });
     }
-    instances
+    Ok(instances)
 }
@@ -1,5 +1,10 @@
-use crate::field::property::field_arguments::add_entity_type_properties_as_field_arguments;
+use crate::field::property::field_arguments::add_entity_type_properties_as_filter_arguments;
 use crate::field::property::filter::get_entity_instances_by_type_filter_by_properties;
+use crate::input::pagination::add_pagination_arguments;
+use crate::input::pagination::get_pagination;
+use crate::input::property_filter::property_filter_argument;
+use crate::input::property_sort::get_property_sorts;
+use crate::input::property_sort::property_sort_argument;
 use crate::object::types::DynamicGraphTypeDefinition;
 use async_graphql::Error;
 use async_graphql::dynamic::Field;
@@ -8,6 +13,7 @@ use async_graphql::dynamic::FieldValue;
 use async_graphql::dynamic::InputValue;
 use async_graphql::dynamic::TypeRef;
 use reactive_graph_graph::EntityType;
+use reactive_graph_graph::compare_by_properties;
 use reactive_graph_reactive_service_api::ReactiveEntityManager;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -37,13 +43,36 @@ pub fn entity_query_field(entity_type: &EntityType) -> Field {
                 }
                 return Ok(Some(FieldValue::list(vec![FieldValue::owned_any(entity_instance)])));
             }
-            let instances = get_entity_instances_by_type_filter_by_properties(&ctx, &entity_type, entity_instance_manager);
+            let instances = get_entity_instances_by_type_filter_by_properties(&ctx, &entity_type, entity_instance_manager)?;
             Ok(Some(FieldValue::list(instances.into_iter().map(FieldValue::owned_any))))
         })
     })
     .description(entity_type.description.clone())
     .argument(InputValue::new("id", TypeRef::named(TypeRef::STRING)))
-    .argument(InputValue::new("label", TypeRef::named(TypeRef::STRING)));
-    field = add_entity_type_properties_as_field_arguments(field, entity_type, true, true);
+    .argument(InputValue::new("label", TypeRef::named(TypeRef::STRING)))
+    .argument(property_filter_argument());
+    field = add_entity_type_properties_as_filter_arguments(field, entity_type);
+    field
+}
+
+pub fn entity_query_connection_field(entity_type: &EntityType) -> Field {
+    let entity_type_inner = entity_type.clone();
+    let dy_ty = DynamicGraphTypeDefinition::from(&entity_type.ty);
+    let mut field = Field::new(dy_ty.connection_field_name(), TypeRef::named_nn(dy_ty.connection_type_name()), move |ctx| {
+        let entity_type = entity_type_inner.clone();
+        FieldFuture::new(async move {
+            let entity_instance_manager = ctx.data::<Arc<dyn ReactiveEntityManager + Send + Sync>>()?;
+            let sort = get_property_sorts(&ctx)?;
+            let pagination = get_pagination(&ctx)?;
+            let mut instances = get_entity_instances_by_type_filter_by_properties(&ctx, &entity_type, entity_instance_manager)?;
+            instances.sort_by(|a, b| compare_by_properties(a, b, &sort).then_with(|| a.id.cmp(&b.id)));
+            Ok(Some(FieldValue::owned_any(pagination.paginate(instances)?)))
+        })
+    })
+    .description(format!("Returns the requested page of the entity instances of type {}", entity_type.ty))
+    .argument(property_filter_argument())
+    .argument(property_sort_argument());
+    field = add_pagination_arguments(field);
+    field = add_entity_type_properties_as_filter_arguments(field, entity_type);
     field
 }
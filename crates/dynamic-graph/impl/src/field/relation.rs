@@ -12,6 +12,13 @@ use crate::field::create_properties_from_field_arguments;
 use crate::field::to_field_value;
 use crate::field::to_input_type_ref;
 use crate::field::to_type_ref;
+use crate::input::pagination::add_pagination_arguments;
+use crate::input::pagination::get_pagination;
+use crate::input::property_filter::get_property_filter;
+use crate::input::property_filter::is_property_argument;
+use crate::input::property_filter::property_filter_argument;
+use crate::input::property_sort::get_property_sorts;
+use crate::input::property_sort::property_sort_argument;
 use crate::interface::relation::INTERFACE_RELATION_FIELD_INSTANCE_ID;
 use crate::interface::relation::INTERFACE_RELATION_FIELD_KEY;
 use crate::object::types::DynamicGraphTypeDefinition;
@@ -29,6 +36,8 @@ use reactive_graph_graph::PropertyTypeDefinition;
 use reactive_graph_graph::RelationInstanceId;
 use reactive_graph_graph::RelationInstanceTypeId;
 use reactive_graph_graph::RelationType;
+use reactive_graph_graph::compare_by_properties;
+use reactive_graph_reactive_model_api::ReactiveInstance;
 use reactive_graph_reactive_model_impl::ReactiveProperties;
 use reactive_graph_reactive_model_impl::ReactiveRelation;
 use reactive_graph_reactive_service_api::ReactiveEntityManager;
@@ -37,85 +46,47 @@ use reactive_graph_reactive_service_api::ReactiveRelationManager;
 use reactive_graph_runtime_model::LabeledProperties::LABEL;
 
 pub fn relation_query_field(relation_type: &RelationType) -> Field {
-    let ty = relation_type.ty.clone();
     let relation_type_inner = relation_type.clone();
-    let dy_ty = DynamicGraphTypeDefinition::from(&ty);
+    let dy_ty = DynamicGraphTypeDefinition::from(&relation_type.ty);
     let mut field = Field::new(dy_ty.field_name(), TypeRef::named_nn_list_nn(dy_ty.to_string()), move |ctx| {
-        let ty = ty.clone();
         let relation_type = relation_type_inner.clone();
         FieldFuture::new(async move {
             let relation_instance_manager = ctx.data::<Arc<dyn ReactiveRelationManager + Send + Sync>>()?;
-            let mut instances = relation_instance_manager.get_by_type(&ty);
-            for property in relation_type.properties.iter() {
-                let Some(expected_value) = ctx.args.get(&property.name) else {
-                    continue;
-                };
-                instances.retain(|instance| match instance.get(&property.name) {
-                    Some(actual_value) => match &property.data_type {
-                        DataType::Null => false,
-                        DataType::Bool => expected_value
-                            .boolean()
-                            .map(|expected_value| actual_value.as_bool().map(|actual_value| expected_value == actual_value).unwrap_or(false))
-                            .unwrap_or(false),
-                        DataType::Number => {
-                            if let Ok(expected_value) = expected_value.i64() {
-                                actual_value.as_i64().map(|actual_value| expected_value == actual_value).unwrap_or(false)
-                            } else if let Ok(expected_value) = expected_value.u64() {
-                                actual_value.as_u64().map(|actual_value| expected_value == actual_value).unwrap_or(false)
-                            } else if let Ok(expected_value) = expected_value.f64() {
-                                actual_value.as_f64().map(|actual_value| expected_value == actual_value).unwrap_or(false)
-                            } else {
-                                false
-                            }
-                        }
-                        DataType::String => expected_value
-                            .string()
-                            .map(|expected_value| actual_value.as_str().map(|actual_value| expected_value == actual_value).unwrap_or(false))
-                            .unwrap_or(false),
-                        DataType::Array => {
-                            if let Ok(_l) = expected_value.list() {
-                                if let Ok(expected_value) = expected_value.deserialize::<Value>() {
-                                    if expected_value.is_array() && actual_value.is_array() {
-                                        expected_value == actual_value
-                                    } else {
-                                        false
-                                    }
-                                } else {
-                                    false
-                                }
-                            } else {
-                                false
-                            }
-                        }
-                        DataType::Object => {
-                            if let Ok(_o) = expected_value.object() {
-                                if let Ok(expected_value) = expected_value.deserialize::<Value>() {
-                                    if expected_value.is_object() && actual_value.is_object() {
-                                        expected_value == actual_value
-                                    } else {
-                                        false
-                                    }
-                                } else {
-                                    false
-                                }
-                            } else {
-                                false
-                            }
-                        }
-                        DataType::Any => match expected_value.deserialize::<Value>() {
-                            Ok(expected_value) => expected_value == actual_value,
-                            Err(_) => false,
-                        },
-                    },
-                    None => false,
-                });
-            }
+            let instances = get_relation_instances_by_type_filter_by_properties(&ctx, &relation_type, relation_instance_manager)?;
             Ok(Some(FieldValue::list(instances.into_iter().map(FieldValue::owned_any))))
         })
     })
-    .description(relation_type.description.clone());
+    .description(relation_type.description.clone())
+    .argument(property_filter_argument());
+    field = add_relation_type_properties_as_field_arguments(field, relation_type);
+    field
+}
+
+pub fn relation_query_connection_field(relation_type: &RelationType) -> Field {
+    let relation_type_inner = relation_type.clone();
+    let dy_ty = DynamicGraphTypeDefinition::from(&relation_type.ty);
+    let mut field = Field::new(dy_ty.connection_field_name(), TypeRef::named_nn(dy_ty.connection_type_name()), move |ctx| {
+        let relation_type = relation_type_inner.clone();
+        FieldFuture::new(async move {
+            let relation_instance_manager = ctx.data::<Arc<dyn ReactiveRelationManager + Send + Sync>>()?;
+            let sort = get_property_sorts(&ctx)?;
+            let pagination = get_pagination(&ctx)?;
+            let mut instances = get_relation_instances_by_type_filter_by_properties(&ctx, &relation_type, relation_instance_manager)?;
+            instances.sort_by(|a, b| compare_by_properties(a, b, &sort).then_with(|| a.id().cmp(&b.id())));
+            Ok(Some(FieldValue::owned_any(pagination.paginate(instances)?)))
+        })
+    })
+    .description(format!("Returns the requested page of the relation instances of type {}", relation_type.ty))
+    .argument(property_filter_argument())
+    .argument(property_sort_argument());
+    field = add_pagination_arguments(field);
+    field = add_relation_type_properties_as_field_arguments(field, relation_type);
+    field
+}
+
+fn add_relation_type_properties_as_field_arguments(mut field: Field, relation_type: &RelationType) -> Field {
     for property in relation_type.properties.iter() {
-        if property.name == LABEL.property_name() {
+        if property.name == LABEL.property_name() || !is_property_argument(&property.name) {
             continue;
         }
         if let Some(type_ref) = to_input_type_ref(property.value(), true) {
@@ -125,6 +96,84 @@ pub fn relation_query_field(relation_type: &RelationType) -> Field {
     field
 }
 
+fn get_relation_instances_by_type_filter_by_properties(
+    ctx: &ResolverContext,
+    relation_type: &RelationType,
+    relation_instance_manager: &Arc<dyn ReactiveRelationManager + Send + Sync>,
+) -> async_graphql::Result<Vec<ReactiveRelation>> {
+    let filter = get_property_filter(ctx)?;
+    // The property filter is applied first, because it may use the property indexes
+    let mut instances = match &filter {
+        Some(filter) => relation_instance_manager.get_by_type_and_filter(&relation_type.ty, filter),
+        None => relation_instance_manager.get_by_type(&relation_type.ty),
+    };
+    for property in relation_type.properties.iter().filter(|property| is_property_argument(&property.name)) {
+        let Some(expected_value) = ctx.args.get(&property.name) else {
+            continue;
+        };
+        instances.retain(|instance| match instance.get(&property.name) {
+            Some(actual_value) => match &property.data_type {
+                DataType::Null => false,
+                DataType::Bool => expected_value
+                    .boolean()
+                    .map(|expected_value| actual_value.as_bool().map(|actual_value| expected_value == actual_value).unwrap_or(false))
+                    .unwrap_or(false),
+                DataType::Number => {
+                    if let Ok(expected_value) = expected_value.i64() {
+                        actual_value.as_i64().map(|actual_value| expected_value == actual_value).unwrap_or(false)
+                    } else if let Ok(expected_value) = expected_value.u64() {
+                        actual_value.as_u64().map(|actual_value| expected_value == actual_value).unwrap_or(false)
+                    } else if let Ok(expected_value) = expected_value.f64() {
+                        actual_value.as_f64().map(|actual_value| expected_value == actual_value).unwrap_or(false)
+                    } else {
+                        false
+                    }
+                }
+                DataType::String => expected_value
+                    .string()
+                    .map(|expected_value| actual_value.as_str().map(|actual_value| expected_value == actual_value).unwrap_or(false))
+                    .unwrap_or(false),
+                DataType::Array => {
+                    if let Ok(_l) = expected_value.list() {
+                        if let Ok(expected_value) = expected_value.deserialize::<Value>() {
+                            if expected_value.is_array() && actual_value.is_array() {
+                                expected_value == actual_value
+                            } else {
+                                false
+                            }
+                        } else {
+                            false
+                        }
+                    } else {
+                        false
+                    }
+                }
+                DataType::Object => {
+                    if let Ok(_o) = expected_value.object() {
+                        if let Ok(expected_value) = expected_value.deserialize::<Value>() {
+                            if expected_value.is_object() && actual_value.is_object() {
+                                expected_value == actual_value
+                            } else {
+                                false
+                            }
+                        } else {
+                            false
+                        }
+                    } else {
+                        false
+                    }
+                }
+                DataType::Any => match expected_value.deserialize::<Value>() {
+                    Ok(expected_value) => expected_value == actual_value,
+                    Err(_) => false,
+                },
+            },
+            None => false,
+        });
+    }
+    Ok(instances)
+}
+
 pub fn relation_creation_field(relation_type: &RelationType) -> Option<Field> {
     let ty = relation_type.ty.clone();
     let relation_type_inner = relation_type.clone();
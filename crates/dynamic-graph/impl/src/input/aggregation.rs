@@ -0,0 +1,70 @@
+use async_graphql::dynamic::Enum;
+use async_graphql::dynamic::EnumItem;
+use async_graphql::dynamic::InputObject;
+use async_graphql::dynamic::InputValue;
+use async_graphql::dynamic::ResolverContext;
+use async_graphql::dynamic::TypeRef;
+use reactive_graph_graph::AggregationGroupBy;
+
+pub const ENUM_AGGREGATION_GROUP_KIND: &str = "AggregationGroupKind";
+
+pub const INPUT_AGGREGATION_GROUP_BY: &str = "AggregationGroupBy";
+
+pub const FIELD_ARGUMENT_GROUP_BY: &str = "groupBy";
+
+pub const FIELD_ARGUMENT_PROPERTY: &str = "property";
+
+pub fn aggregation_group_kind_enum() -> Enum {
+    Enum::new(ENUM_AGGREGATION_GROUP_KIND)
+        .description("The kind of the grouping of an aggregation")
+        .item(EnumItem::new("TYPE").description("Groups the instances by their type"))
+        .item(EnumItem::new("COMPONENT").description("Groups the instances by their components"))
+        .item(EnumItem::new("PROPERTY").description("Groups the instances by the value of a property"))
+}
+
+pub fn aggregation_group_by_input() -> InputObject {
+    InputObject::new(INPUT_AGGREGATION_GROUP_BY)
+        .description("Defines how instances are grouped by an aggregation")
+        .field(InputValue::new("kind", TypeRef::named_nn(ENUM_AGGREGATION_GROUP_KIND)).description("The kind of the grouping"))
+        .field(
+            InputValue::new("property", TypeRef::named(TypeRef::STRING)).description("The name of the property to group by. Required, if the kind is PROPERTY"),
+        )
+}
+
+pub fn aggregation_group_by_argument() -> InputValue {
+    InputValue::new(FIELD_ARGUMENT_GROUP_BY, TypeRef::named(INPUT_AGGREGATION_GROUP_BY))
+        .description("Groups the instances by type, component or property value")
+}
+
+pub fn aggregation_property_argument() -> InputValue {
+    InputValue::new(FIELD_ARGUMENT_PROPERTY, TypeRef::named(TypeRef::STRING)).description("The name of the numeric property to aggregate")
+}
+
+/// Returns the grouping of the field arguments.
+pub fn get_aggregation_group_by(ctx: &ResolverContext) -> async_graphql::Result<Option<AggregationGroupBy>> {
+    let Some(group_by) = ctx.args.get(FIELD_ARGUMENT_GROUP_BY).filter(|group_by| !group_by.is_null()) else {
+        return Ok(None);
+    };
+    let group_by = group_by.object()?;
+    let kind = group_by.try_get("kind")?.enum_name()?;
+    let property = group_by
+        .get("property")
+        .filter(|property| !property.is_null())
+        .map(|property| property.string())
+        .transpose()?;
+    match (kind, property) {
+        ("TYPE", _) => Ok(Some(AggregationGroupBy::Type)),
+        ("COMPONENT", _) => Ok(Some(AggregationGroupBy::Component)),
+        ("PROPERTY", Some(property)) => Ok(Some(AggregationGroupBy::Property(property.to_string()))),
+        ("PROPERTY", None) => Err("Grouping by property requires the name of the property".into()),
+        (kind, _) => Err(format!("Unknown aggregation group kind {kind}").into()),
+    }
+}
+
+/// Returns the name of the property to aggregate of the field arguments.
+pub fn get_aggregation_property(ctx: &ResolverContext) -> async_graphql::Result<Option<String>> {
+    let Some(property) = ctx.args.get(FIELD_ARGUMENT_PROPERTY).filter(|property| !property.is_null()) else {
+        return Ok(None);
+    };
+    Ok(Some(property.string()?.to_string()))
+}
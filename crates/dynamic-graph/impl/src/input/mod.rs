@@ -0,0 +1,21 @@
+use async_graphql::dynamic::SchemaBuilder;
+
+use crate::input::aggregation::aggregation_group_by_input;
+use crate::input::aggregation::aggregation_group_kind_enum;
+use crate::input::property_filter::property_filter_input;
+use crate::input::property_sort::property_sort_input;
+use crate::input::property_sort::sort_direction_enum;
+
+pub mod aggregation;
+pub mod pagination;
+pub mod property_filter;
+pub mod property_sort;
+
+pub fn get_inputs(schema: SchemaBuilder) -> SchemaBuilder {
+    schema
+        .register(property_filter_input())
+        .register(sort_direction_enum())
+        .register(property_sort_input())
+        .register(aggregation_group_kind_enum())
+        .register(aggregation_group_by_input())
+}
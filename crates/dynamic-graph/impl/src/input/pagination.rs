@@ -0,0 +1,32 @@
+use async_graphql::dynamic::Field;
+use async_graphql::dynamic::InputValue;
+use async_graphql::dynamic::ResolverContext;
+use async_graphql::dynamic::TypeRef;
+use reactive_graph_graph::Pagination;
+use reactive_graph_graph::decode_count;
+
+/// Adds the arguments of a Relay connection to the given field.
+pub fn add_pagination_arguments(field: Field) -> Field {
+    field
+        .argument(InputValue::new("first", TypeRef::named(TypeRef::INT)).description("Returns the first n instances"))
+        .argument(InputValue::new("after", TypeRef::named(TypeRef::STRING)).description("Returns the instances after the given cursor"))
+        .argument(InputValue::new("last", TypeRef::named(TypeRef::INT)).description("Returns the last n instances"))
+        .argument(InputValue::new("before", TypeRef::named(TypeRef::STRING)).description("Returns the instances before the given cursor"))
+}
+
+/// Returns the pagination of the field arguments.
+pub fn get_pagination(ctx: &ResolverContext) -> async_graphql::Result<Pagination> {
+    let count = |name: &str| -> async_graphql::Result<Option<usize>> {
+        match ctx.args.get(name).filter(|value| !value.is_null()) {
+            Some(value) => Ok(Some(decode_count(name, value.i64()?)?)),
+            None => Ok(None),
+        }
+    };
+    let cursor = |name: &str| -> async_graphql::Result<Option<String>> {
+        match ctx.args.get(name).filter(|value| !value.is_null()) {
+            Some(value) => Ok(Some(value.string()?.to_string())),
+            None => Ok(None),
+        }
+    };
+    Ok(Pagination::new(count("first")?, cursor("after")?, count("last")?, cursor("before")?))
+}
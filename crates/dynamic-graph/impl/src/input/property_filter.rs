@@ -0,0 +1,54 @@
+use async_graphql::dynamic::InputObject;
+use async_graphql::dynamic::InputValue;
+use async_graphql::dynamic::ResolverContext;
+use async_graphql::dynamic::TypeRef;
+use reactive_graph_graph::PropertyFilter;
+use reactive_graph_graph::PropertyFilterExpression;
+
+pub const INPUT_PROPERTY_FILTER: &str = "PropertyFilter";
+
+pub const FIELD_ARGUMENT_FILTER: &str = "filter";
+
+pub fn property_filter_input() -> InputObject {
+    InputObject::new(INPUT_PROPERTY_FILTER)
+        .description("Filters instances by their properties. All operators of a filter are combined with and.")
+        .field(InputValue::new("name", TypeRef::named(TypeRef::STRING)).description("The name of the property"))
+        .field(InputValue::new("path", TypeRef::named(TypeRef::STRING)).description("Selects a value within the property value (for example $.position.x)"))
+        .field(InputValue::new("eq", TypeRef::named("JSON")).description("The value is equal to the given value"))
+        .field(InputValue::new("ne", TypeRef::named("JSON")).description("The value is not equal to the given value"))
+        .field(InputValue::new("gt", TypeRef::named("JSON")).description("The value is greater than the given value"))
+        .field(InputValue::new("gte", TypeRef::named("JSON")).description("The value is greater than or equal to the given value"))
+        .field(InputValue::new("lt", TypeRef::named("JSON")).description("The value is less than the given value"))
+        .field(InputValue::new("lte", TypeRef::named("JSON")).description("The value is less than or equal to the given value"))
+        .field(InputValue::new("between", TypeRef::named_nn_list("JSON")).description("The value is between the two given values (inclusive)"))
+        .field(InputValue::new("in", TypeRef::named_nn_list("JSON")).description("The value is one of the given values"))
+        .field(InputValue::new("contains", TypeRef::named("JSON")).description("The string, array or object contains the given value"))
+        .field(InputValue::new("startsWith", TypeRef::named(TypeRef::STRING)).description("The string starts with the given string"))
+        .field(InputValue::new("endsWith", TypeRef::named(TypeRef::STRING)).description("The string ends with the given string"))
+        .field(InputValue::new("regex", TypeRef::named(TypeRef::STRING)).description("The string matches the given regular expression"))
+        .field(InputValue::new("exists", TypeRef::named(TypeRef::BOOLEAN)).description("The property exists and is not null"))
+        .field(InputValue::new("and", TypeRef::named_nn_list(INPUT_PROPERTY_FILTER)).description("All of the given filters matches"))
+        .field(InputValue::new("or", TypeRef::named_nn_list(INPUT_PROPERTY_FILTER)).description("At least one of the given filters matches"))
+        .field(InputValue::new("not", TypeRef::named(INPUT_PROPERTY_FILTER)).description("The given filter doesn't match"))
+}
+
+/// Returns true, if the property with the given name can be used as field argument next to the
+/// property filter argument. A property named like the property filter argument can only be
+/// filtered using the property filter.
+pub fn is_property_argument(property_name: &str) -> bool {
+    property_name != FIELD_ARGUMENT_FILTER
+}
+
+pub fn property_filter_argument() -> InputValue {
+    InputValue::new(FIELD_ARGUMENT_FILTER, TypeRef::named(INPUT_PROPERTY_FILTER))
+        .description("Filters the instances by properties using comparison operators and boolean combinators")
+}
+
+/// Returns the compiled property filter of the field arguments.
+pub fn get_property_filter(ctx: &ResolverContext) -> async_graphql::Result<Option<PropertyFilterExpression>> {
+    let Some(filter) = ctx.args.get(FIELD_ARGUMENT_FILTER).filter(|filter| !filter.is_null()) else {
+        return Ok(None);
+    };
+    let filter = filter.deserialize::<PropertyFilter>()?;
+    Ok(Some(filter.compile()?))
+}
@@ -0,0 +1,39 @@
+use async_graphql::dynamic::Enum;
+use async_graphql::dynamic::EnumItem;
+use async_graphql::dynamic::InputObject;
+use async_graphql::dynamic::InputValue;
+use async_graphql::dynamic::ResolverContext;
+use async_graphql::dynamic::TypeRef;
+use reactive_graph_graph::PropertySort;
+
+pub const ENUM_SORT_DIRECTION: &str = "SortDirection";
+
+pub const INPUT_PROPERTY_SORT: &str = "PropertySort";
+
+pub const FIELD_ARGUMENT_SORT: &str = "sort";
+
+pub fn sort_direction_enum() -> Enum {
+    Enum::new(ENUM_SORT_DIRECTION)
+        .description("The direction of a sort")
+        .item(EnumItem::new("ASCENDING").description("Sorts in ascending order"))
+        .item(EnumItem::new("DESCENDING").description("Sorts in descending order"))
+}
+
+pub fn property_sort_input() -> InputObject {
+    InputObject::new(INPUT_PROPERTY_SORT)
+        .description("Sorts instances by the value of a property")
+        .field(InputValue::new("property", TypeRef::named_nn(TypeRef::STRING)).description("The name of the property"))
+        .field(InputValue::new("direction", TypeRef::named(ENUM_SORT_DIRECTION)).description("The direction. Instances without the property are sorted last"))
+}
+
+pub fn property_sort_argument() -> InputValue {
+    InputValue::new(FIELD_ARGUMENT_SORT, TypeRef::named_nn_list(INPUT_PROPERTY_SORT)).description("Sorts the instances by the values of the given properties")
+}
+
+/// Returns the property sorts of the field arguments.
+pub fn get_property_sorts(ctx: &ResolverContext) -> async_graphql::Result<Vec<PropertySort>> {
+    let Some(sort) = ctx.args.get(FIELD_ARGUMENT_SORT).filter(|sort| !sort.is_null()) else {
+        return Ok(Vec::new());
+    };
+    Ok(sort.deserialize::<Vec<PropertySort>>()?)
+}
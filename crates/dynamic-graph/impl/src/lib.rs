@@ -3,6 +3,7 @@ pub mod dynamic_graph_schema_manager_impl;
 pub mod dynamic_graph_system_impl;
 pub mod extension;
 pub mod field;
+pub mod input;
 pub mod interface;
 pub mod object;
 pub mod root;
@@ -0,0 +1,84 @@
+use async_graphql::dynamic::Field;
+use async_graphql::dynamic::FieldFuture;
+use async_graphql::dynamic::FieldValue;
+use async_graphql::dynamic::InputValue;
+use async_graphql::dynamic::Object;
+use async_graphql::dynamic::TypeRef;
+use reactive_graph_graph::AggregationGroup;
+use reactive_graph_graph::PropertyAggregate;
+
+use crate::field::to_field_value;
+
+pub const OBJECT_AGGREGATION_GROUP: &str = "AggregationGroup";
+
+pub const OBJECT_PROPERTY_AGGREGATE: &str = "PropertyAggregate";
+
+/// A group of instances with the aggregated property values of the group.
+pub fn aggregation_group_object() -> Object {
+    Object::new(OBJECT_AGGREGATION_GROUP)
+        .description("A group of instances with the aggregated property values of the group")
+        .field(Field::new("key", TypeRef::named_nn("JSON"), |ctx| {
+            FieldFuture::new(async move {
+                let group = ctx.parent_value.try_downcast_ref::<AggregationGroup>()?;
+                Ok(to_field_value(group.key.clone()))
+            })
+        }))
+        .field(Field::new("count", TypeRef::named_nn(TypeRef::INT), |ctx| {
+            FieldFuture::new(async move {
+                let group = ctx.parent_value.try_downcast_ref::<AggregationGroup>()?;
+                Ok(Some(FieldValue::value(group.count)))
+            })
+        }))
+        .field(Field::new("aggregate", TypeRef::named_nn(OBJECT_PROPERTY_AGGREGATE), |ctx| {
+            FieldFuture::new(async move {
+                let group = ctx.parent_value.try_downcast_ref::<AggregationGroup>()?;
+                Ok(Some(FieldValue::owned_any(group.aggregate.clone())))
+            })
+        }))
+}
+
+/// The aggregated numeric values of a property.
+pub fn property_aggregate_object() -> Object {
+    Object::new(OBJECT_PROPERTY_AGGREGATE)
+        .description("The aggregated numeric values of a property")
+        .field(Field::new("count", TypeRef::named_nn(TypeRef::INT), |ctx| {
+            FieldFuture::new(async move {
+                let aggregate = ctx.parent_value.try_downcast_ref::<PropertyAggregate>()?;
+                Ok(Some(FieldValue::value(aggregate.count())))
+            })
+        }))
+        .field(Field::new("sum", TypeRef::named_nn(TypeRef::FLOAT), |ctx| {
+            FieldFuture::new(async move {
+                let aggregate = ctx.parent_value.try_downcast_ref::<PropertyAggregate>()?;
+                Ok(Some(FieldValue::value(aggregate.sum())))
+            })
+        }))
+        .field(Field::new("min", TypeRef::named(TypeRef::FLOAT), |ctx| {
+            FieldFuture::new(async move {
+                let aggregate = ctx.parent_value.try_downcast_ref::<PropertyAggregate>()?;
+                Ok(aggregate.min().map(FieldValue::value))
+            })
+        }))
+        .field(Field::new("max", TypeRef::named(TypeRef::FLOAT), |ctx| {
+            FieldFuture::new(async move {
+                let aggregate = ctx.parent_value.try_downcast_ref::<PropertyAggregate>()?;
+                Ok(aggregate.max().map(FieldValue::value))
+            })
+        }))
+        .field(Field::new("avg", TypeRef::named(TypeRef::FLOAT), |ctx| {
+            FieldFuture::new(async move {
+                let aggregate = ctx.parent_value.try_downcast_ref::<PropertyAggregate>()?;
+                Ok(aggregate.avg().map(FieldValue::value))
+            })
+        }))
+        .field(
+            Field::new("percentile", TypeRef::named(TypeRef::FLOAT), |ctx| {
+                FieldFuture::new(async move {
+                    let aggregate = ctx.parent_value.try_downcast_ref::<PropertyAggregate>()?;
+                    let percentile = ctx.args.try_get("percentile")?.f64()?;
+                    Ok(aggregate.percentile(percentile).map(FieldValue::value))
+                })
+            })
+            .argument(InputValue::new("percentile", TypeRef::named_nn(TypeRef::FLOAT)).description("The percentile between 0 and 100")),
+        )
+}
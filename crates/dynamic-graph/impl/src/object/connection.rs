@@ -0,0 +1,90 @@
+use async_graphql::dynamic::Field;
+use async_graphql::dynamic::FieldFuture;
+use async_graphql::dynamic::FieldValue;
+use async_graphql::dynamic::Object;
+use async_graphql::dynamic::TypeRef;
+use reactive_graph_graph::Edge;
+use reactive_graph_graph::Page;
+use reactive_graph_graph::PageInfo;
+
+use crate::object::types::DynamicGraphTypeDefinition;
+
+pub const OBJECT_PAGE_INFO: &str = "PageInfo";
+
+/// Information about the position of a page within the whole result.
+pub fn page_info_object() -> Object {
+    Object::new(OBJECT_PAGE_INFO)
+        .description("Information about pagination in a connection")
+        .field(Field::new("hasPreviousPage", TypeRef::named_nn(TypeRef::BOOLEAN), |ctx| {
+            FieldFuture::new(async move {
+                let page_info = ctx.parent_value.try_downcast_ref::<PageInfo>()?;
+                Ok(Some(FieldValue::value(page_info.has_previous_page)))
+            })
+        }))
+        .field(Field::new("hasNextPage", TypeRef::named_nn(TypeRef::BOOLEAN), |ctx| {
+            FieldFuture::new(async move {
+                let page_info = ctx.parent_value.try_downcast_ref::<PageInfo>()?;
+                Ok(Some(FieldValue::value(page_info.has_next_page)))
+            })
+        }))
+        .field(Field::new("startCursor", TypeRef::named(TypeRef::STRING), |ctx| {
+            FieldFuture::new(async move {
+                let page_info = ctx.parent_value.try_downcast_ref::<PageInfo>()?;
+                Ok(page_info.start_cursor.clone().map(FieldValue::value))
+            })
+        }))
+        .field(Field::new("endCursor", TypeRef::named(TypeRef::STRING), |ctx| {
+            FieldFuture::new(async move {
+                let page_info = ctx.parent_value.try_downcast_ref::<PageInfo>()?;
+                Ok(page_info.end_cursor.clone().map(FieldValue::value))
+            })
+        }))
+}
+
+/// A page of instances of the given type (Relay connection).
+pub fn connection_object<T: Clone + Send + Sync + 'static>(dy_ty: &DynamicGraphTypeDefinition) -> Object {
+    Object::new(dy_ty.connection_type_name())
+        .description(format!("A page of {dy_ty}"))
+        .field(Field::new("edges", TypeRef::named_nn_list_nn(dy_ty.edge_type_name()), |ctx| {
+            FieldFuture::new(async move {
+                let page = ctx.parent_value.try_downcast_ref::<Page<T>>()?;
+                Ok(Some(FieldValue::list(page.edges.iter().cloned().map(FieldValue::owned_any))))
+            })
+        }))
+        .field(Field::new("nodes", TypeRef::named_nn_list_nn(dy_ty.to_string()), |ctx| {
+            FieldFuture::new(async move {
+                let page = ctx.parent_value.try_downcast_ref::<Page<T>>()?;
+                Ok(Some(FieldValue::list(page.nodes().cloned().map(FieldValue::owned_any))))
+            })
+        }))
+        .field(Field::new("pageInfo", TypeRef::named_nn(OBJECT_PAGE_INFO), |ctx| {
+            FieldFuture::new(async move {
+                let page = ctx.parent_value.try_downcast_ref::<Page<T>>()?;
+                Ok(Some(FieldValue::owned_any(page.page_info.clone())))
+            })
+        }))
+        .field(Field::new("totalCount", TypeRef::named_nn(TypeRef::INT), |ctx| {
+            FieldFuture::new(async move {
+                let page = ctx.parent_value.try_downcast_ref::<Page<T>>()?;
+                Ok(Some(FieldValue::value(page.total_count)))
+            })
+        }))
+}
+
+/// An instance of the given type and its cursor.
+pub fn edge_object<T: Clone + Send + Sync + 'static>(dy_ty: &DynamicGraphTypeDefinition) -> Object {
+    Object::new(dy_ty.edge_type_name())
+        .description("An edge in a connection")
+        .field(Field::new("cursor", TypeRef::named_nn(TypeRef::STRING), |ctx| {
+            FieldFuture::new(async move {
+                let edge = ctx.parent_value.try_downcast_ref::<Edge<T>>()?;
+                Ok(Some(FieldValue::value(edge.cursor.clone())))
+            })
+        }))
+        .field(Field::new("node", TypeRef::named_nn(dy_ty.to_string()), |ctx| {
+            FieldFuture::new(async move {
+                let edge = ctx.parent_value.try_downcast_ref::<Edge<T>>()?;
+                Ok(Some(FieldValue::owned_any(edge.node.clone())))
+            })
+        }))
+}
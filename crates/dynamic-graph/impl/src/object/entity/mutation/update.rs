@@ -27,8 +27,8 @@ pub fn entity_update_field(entity_type: &EntityType) -> Option<Field> {
                 // First validate all input fields for mutability and correct datatype
                 for property in entity_type.properties.iter() {
                     if let Ok(value) = ctx.args.try_get(&property.name) {
-                        // Fail on every property which is immutable
-                        if property.mutability == Immutable {
+                        // Fail on every property which is immutable, including computed properties
+                        if property.mutability == Immutable || reactive_entity.mutability(&property.name) == Some(Immutable) {
                             return Err(ImmutablePropertyError(property.key().clone()).into());
                         }
                         match &property.data_type {
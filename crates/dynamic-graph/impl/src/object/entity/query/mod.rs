@@ -9,6 +9,8 @@ use crate::field::outbound::inbound_field::outbound_entity_to_inbound_field;
 use crate::field::outbound::relation_field::entity_outbound_relation_field;
 use crate::field::property::property_field::entity_property_field;
 use crate::interface::entity::INTERFACE_ENTITY;
+use crate::object::connection::connection_object;
+use crate::object::connection::edge_object;
 use crate::object::types::DynamicGraphTypeDefinition;
 use async_graphql::dynamic::Object;
 use async_graphql::dynamic::SchemaBuilder;
@@ -17,14 +19,18 @@ use reactive_graph_dynamic_graph_api::SchemaBuilderContext;
 use reactive_graph_graph::ComponentOrEntityTypeId;
 use reactive_graph_graph::EntityType;
 use reactive_graph_graph::RelationTypes;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
 
 pub fn register_entity_type_query_objects(mut schema: SchemaBuilder, context: &SchemaBuilderContext) -> SchemaBuilder {
     for entity_type in context.entity_type_manager.get_all().iter() {
         let ty = ComponentOrEntityTypeId::EntityType(entity_type.key().clone());
         let outbound_types = context.relation_type_manager.get_outbound_relation_types(&ty, false);
         let inbound_types = context.relation_type_manager.get_inbound_relation_types(&ty, false);
+        let dy_ty = DynamicGraphTypeDefinition::from(entity_type.key());
         let entity_type = create_entity_type_query_object(entity_type.value(), outbound_types, inbound_types, context);
         schema = schema.register(entity_type);
+        schema = schema.register(connection_object::<ReactiveEntity>(&dy_ty));
+        schema = schema.register(edge_object::<ReactiveEntity>(&dy_ty));
     }
     schema
 }
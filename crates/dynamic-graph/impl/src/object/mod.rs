@@ -1,3 +1,5 @@
+pub mod aggregation;
+pub mod connection;
 pub mod entity;
 pub mod flow;
 pub mod namespace;
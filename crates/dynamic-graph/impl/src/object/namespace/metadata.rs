@@ -0,0 +1,60 @@
+use async_graphql::dynamic::Field;
+use async_graphql::dynamic::FieldFuture;
+use async_graphql::dynamic::FieldValue;
+use async_graphql::dynamic::Object;
+use async_graphql::dynamic::ResolverContext;
+use async_graphql::dynamic::TypeRef;
+use reactive_graph_graph::Namespace;
+use reactive_graph_type_system_api::NamespaceManager;
+use std::sync::Arc;
+
+const TYPE_METADATA: &str = "NamespaceMetadata";
+
+pub fn metadata_type_name() -> TypeRef {
+    TypeRef::named_nn(TYPE_METADATA)
+}
+
+pub fn metadata_object() -> Object {
+    Object::new(TYPE_METADATA)
+        .description("The metadata of a namespace")
+        .field(Field::new("name", TypeRef::named_nn(TypeRef::STRING), move |ctx| {
+            FieldFuture::new(async move { Ok(resolve_namespace(&ctx)?.map(|namespace| FieldValue::value(namespace.name))) })
+        }))
+        .field(Field::new("description", TypeRef::named_nn(TypeRef::STRING), move |ctx| {
+            FieldFuture::new(async move { Ok(resolve_namespace(&ctx)?.map(|namespace| FieldValue::value(namespace.description))) })
+        }))
+        .field(Field::new("owner", TypeRef::named(TypeRef::STRING), move |ctx| {
+            FieldFuture::new(async move { Ok(resolve_namespace(&ctx)?.and_then(|namespace| namespace.owner).map(FieldValue::value)) })
+        }))
+        .field(Field::new("version", TypeRef::named(TypeRef::STRING), move |ctx| {
+            FieldFuture::new(async move { Ok(resolve_namespace(&ctx)?.and_then(|namespace| namespace.version).map(FieldValue::value)) })
+        }))
+        .field(Field::new("tags", TypeRef::named_nn_list_nn(TypeRef::STRING), move |ctx| {
+            FieldFuture::new(async move {
+                let tags = resolve_namespace(&ctx)?.map(|namespace| namespace.tags).unwrap_or_default();
+                Ok(Some(FieldValue::list(tags.into_iter().map(FieldValue::value))))
+            })
+        }))
+        .field(Field::new("documentation", TypeRef::named_nn_list_nn(TypeRef::STRING), move |ctx| {
+            FieldFuture::new(async move {
+                let documentation = resolve_namespace(&ctx)?.map(|namespace| namespace.documentation).unwrap_or_default();
+                Ok(Some(FieldValue::list(documentation.into_iter().map(FieldValue::value))))
+            })
+        }))
+}
+
+pub fn metadata_field(namespace: String) -> Field {
+    Field::new("_metadata", metadata_type_name(), move |_ctx| {
+        let namespace = namespace.clone();
+        FieldFuture::new(async move { Ok(Some(FieldValue::value(namespace))) })
+    })
+}
+
+fn resolve_namespace(ctx: &ResolverContext) -> async_graphql::Result<Option<Namespace>> {
+    let namespace_manager = ctx.data::<Arc<dyn NamespaceManager + Send + Sync>>()?;
+    let name = ctx
+        .parent_value
+        .as_value()
+        .and_then(|v| v.clone().into_json().ok().and_then(|value| value.as_str().map(|s| s.to_owned())));
+    Ok(name.and_then(|name| namespace_manager.get(&name)))
+}
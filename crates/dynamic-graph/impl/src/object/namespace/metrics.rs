@@ -1,15 +1,31 @@
 use async_graphql::dynamic::Field;
 use async_graphql::dynamic::FieldFuture;
 use async_graphql::dynamic::FieldValue;
+use async_graphql::dynamic::InputValue;
 use async_graphql::dynamic::Object;
 use async_graphql::dynamic::ResolverContext;
 use async_graphql::dynamic::TypeRef;
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::NamespacedType;
+use reactive_graph_graph::RelationTypeId;
+use reactive_graph_graph::TYPE_ID_TYPE_SEPARATOR;
+use reactive_graph_reactive_service_api::ReactiveEntityManager;
+use reactive_graph_reactive_service_api::ReactiveRelationManager;
 use reactive_graph_type_system_api::ComponentManager;
 use reactive_graph_type_system_api::EntityTypeManager;
 use reactive_graph_type_system_api::FlowTypeManager;
 use reactive_graph_type_system_api::RelationTypeManager;
 use std::sync::Arc;
 
+use crate::input::aggregation::aggregation_group_by_argument;
+use crate::input::aggregation::aggregation_property_argument;
+use crate::input::aggregation::get_aggregation_group_by;
+use crate::input::aggregation::get_aggregation_property;
+use crate::input::property_filter::get_property_filter;
+use crate::input::property_filter::property_filter_argument;
+use crate::object::aggregation::OBJECT_AGGREGATION_GROUP;
+
 const TYPE_METRICS: &str = "NamespaceMetrics";
 const ROOT_NAMESPACE: &str = "root";
 
@@ -19,6 +35,26 @@ enum ResolvedNamespace {
     Namespace(String),
 }
 
+impl ResolvedNamespace {
+    fn namespace(&self) -> Option<&str> {
+        match self {
+            ResolvedNamespace::Root => None,
+            ResolvedNamespace::Namespace(namespace) => Some(namespace),
+        }
+    }
+
+    /// Resolves a type name of the namespace. In the root namespace the type name must be fully qualified.
+    fn resolve_type(&self, type_name: &str) -> async_graphql::Result<NamespacedType> {
+        match self {
+            ResolvedNamespace::Root => type_name
+                .split_once(TYPE_ID_TYPE_SEPARATOR)
+                .map(NamespacedType::from)
+                .ok_or_else(|| format!("The type name {type_name} must be qualified by its namespace").into()),
+            ResolvedNamespace::Namespace(namespace) => Ok(NamespacedType::from((namespace.as_str(), type_name))),
+        }
+    }
+}
+
 impl From<Option<String>> for ResolvedNamespace {
     fn from(value: Option<String>) -> Self {
         match value {
@@ -84,6 +120,96 @@ pub fn metrics_object() -> Object {
                 Ok(Some(FieldValue::value(count)))
             })
         }))
+        .field(Field::new("entityInstances", TypeRef::named_nn(TypeRef::INT), move |ctx| {
+            let namespace = extract_namespace_from_parent_value(&ctx);
+            FieldFuture::new(async move {
+                let entity_manager = ctx.data::<Arc<dyn ReactiveEntityManager + Send + Sync>>()?;
+                let count = match namespace {
+                    ResolvedNamespace::Root => entity_manager.count(),
+                    ResolvedNamespace::Namespace(namespace) => entity_manager.count_by_namespace(&namespace),
+                };
+                Ok(Some(FieldValue::value(count)))
+            })
+        }))
+        .field(Field::new("relationInstances", TypeRef::named_nn(TypeRef::INT), move |ctx| {
+            let namespace = extract_namespace_from_parent_value(&ctx);
+            FieldFuture::new(async move {
+                let relation_manager = ctx.data::<Arc<dyn ReactiveRelationManager + Send + Sync>>()?;
+                let count = match namespace {
+                    ResolvedNamespace::Root => relation_manager.count(),
+                    ResolvedNamespace::Namespace(namespace) => relation_manager.count_by_namespace(&namespace),
+                };
+                Ok(Some(FieldValue::value(count)))
+            })
+        }))
+        .field(
+            Field::new("aggregateEntityInstances", TypeRef::named_nn_list_nn(OBJECT_AGGREGATION_GROUP), move |ctx| {
+                let namespace = extract_namespace_from_parent_value(&ctx);
+                FieldFuture::new(async move {
+                    let entity_manager = ctx.data::<Arc<dyn ReactiveEntityManager + Send + Sync>>()?;
+                    let ty = get_type_argument(&ctx, "type", &namespace)?.map(EntityTypeId::from);
+                    let component_ty = get_type_argument(&ctx, "component", &namespace)?.map(ComponentTypeId::from);
+                    let filter = get_property_filter(&ctx)?;
+                    let group_by = get_aggregation_group_by(&ctx)?;
+                    let property = get_aggregation_property(&ctx)?;
+                    let groups = entity_manager.aggregate(
+                        ty.as_ref(),
+                        component_ty.as_ref(),
+                        namespace.namespace(),
+                        filter.as_ref(),
+                        group_by.as_ref(),
+                        property.as_deref(),
+                    );
+                    Ok(Some(FieldValue::list(groups.into_iter().map(FieldValue::owned_any))))
+                })
+            })
+            .description("Aggregates the entity instances of the namespace")
+            .argument(InputValue::new("type", TypeRef::named(TypeRef::STRING)).description("Aggregates the entity instances of the given entity type only"))
+            .argument(
+                InputValue::new("component", TypeRef::named(TypeRef::STRING)).description("Aggregates the entity instances with the given component only"),
+            )
+            .argument(property_filter_argument())
+            .argument(aggregation_group_by_argument())
+            .argument(aggregation_property_argument()),
+        )
+        .field(
+            Field::new("aggregateRelationInstances", TypeRef::named_nn_list_nn(OBJECT_AGGREGATION_GROUP), move |ctx| {
+                let namespace = extract_namespace_from_parent_value(&ctx);
+                FieldFuture::new(async move {
+                    let relation_manager = ctx.data::<Arc<dyn ReactiveRelationManager + Send + Sync>>()?;
+                    let ty = get_type_argument(&ctx, "type", &namespace)?.map(RelationTypeId::from);
+                    let component_ty = get_type_argument(&ctx, "component", &namespace)?.map(ComponentTypeId::from);
+                    let filter = get_property_filter(&ctx)?;
+                    let group_by = get_aggregation_group_by(&ctx)?;
+                    let property = get_aggregation_property(&ctx)?;
+                    let groups = relation_manager.aggregate(
+                        ty.as_ref(),
+                        component_ty.as_ref(),
+                        namespace.namespace(),
+                        filter.as_ref(),
+                        group_by.as_ref(),
+                        property.as_deref(),
+                    );
+                    Ok(Some(FieldValue::list(groups.into_iter().map(FieldValue::owned_any))))
+                })
+            })
+            .description("Aggregates the relation instances of the namespace")
+            .argument(InputValue::new("type", TypeRef::named(TypeRef::STRING)).description("Aggregates the relation instances of the given relation type only"))
+            .argument(
+                InputValue::new("component", TypeRef::named(TypeRef::STRING)).description("Aggregates the relation instances with the given component only"),
+            )
+            .argument(property_filter_argument())
+            .argument(aggregation_group_by_argument())
+            .argument(aggregation_property_argument()),
+        )
+}
+
+/// Returns the type of the field argument resolved in the namespace.
+fn get_type_argument(ctx: &ResolverContext, name: &str, namespace: &ResolvedNamespace) -> async_graphql::Result<Option<NamespacedType>> {
+    let Some(type_name) = ctx.args.get(name).filter(|type_name| !type_name.is_null()) else {
+        return Ok(None);
+    };
+    Ok(Some(namespace.resolve_type(type_name.string()?)?))
 }
 
 pub fn metrics_field(namespace: Option<String>) -> Field {
@@ -1,3 +1,4 @@
+pub mod metadata;
 pub mod metrics;
 pub mod mutation;
 pub mod query;
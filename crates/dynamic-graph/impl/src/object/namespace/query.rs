@@ -1,8 +1,11 @@
 use crate::field::component_query_field;
 use crate::field::flow::flow_query_field;
 use crate::field::namespace_type_name;
+use crate::field::query::entity_query_connection_field;
 use crate::field::query::entity_query_field;
+use crate::field::relation_query_connection_field;
 use crate::field::relation_query_field;
+use crate::object::namespace::metadata::metadata_field;
 use crate::object::namespace::metrics::metrics_field;
 use crate::object::namespace::schema::json_schema_field;
 use crate::object::namespace::sort::sort_by_key;
@@ -26,8 +29,11 @@ pub fn namespace_query(context: SchemaBuilderContext, namespace: &String) -> Opt
     let type_name = namespace_type_name(namespace);
     let namespace_field_value = namespace.clone();
 
-    let mut namespace =
-        Object::new(&type_name).description(format!("Queries for components, entities and relations on the namespace {}", &namespace.to_case(Pascal)));
+    let description = match context.namespace_manager.get(namespace) {
+        Some(metadata) if !metadata.description.is_empty() => metadata.description,
+        _ => format!("Queries for components, entities and relations on the namespace {}", &namespace.to_case(Pascal)),
+    };
+    let mut namespace = Object::new(&type_name).description(description);
 
     for component in components.iter().sorted_by(sort_by_key) {
         namespace = namespace.field(component_query_field(component.value()));
@@ -37,10 +43,12 @@ pub fn namespace_query(context: SchemaBuilderContext, namespace: &String) -> Opt
     for entity_type in entity_types.iter().sorted_by(sort_by_key) {
         let entity_type = entity_type.value();
         namespace = namespace.field(entity_query_field(entity_type));
+        namespace = namespace.field(entity_query_connection_field(entity_type));
         namespace = namespace.field(json_schema_field(&entity_type.ty, entity_type.json_schema()));
     }
     for relation_type in relation_types.iter().sorted_by(sort_by_key) {
         namespace = namespace.field(relation_query_field(relation_type.value()));
+        namespace = namespace.field(relation_query_connection_field(relation_type.value()));
         namespace = namespace.field(json_schema_field(&relation_type.ty, relation_type.json_schema()));
     }
 
@@ -53,6 +61,7 @@ pub fn namespace_query(context: SchemaBuilderContext, namespace: &String) -> Opt
         }
     }
 
+    namespace = namespace.field(metadata_field(namespace_field_value.clone()));
     namespace = namespace.field(metrics_field(Some(namespace_field_value)));
     Some(namespace)
 }
@@ -8,6 +8,8 @@ use crate::field::relation_key_field;
 use crate::field::relation_outbound_field;
 use crate::field::relation_property_field;
 use crate::interface::relation::INTERFACE_RELATION;
+use crate::object::connection::connection_object;
+use crate::object::connection::edge_object;
 use crate::object::types::DynamicGraphTypeDefinition;
 use async_graphql::dynamic::Object;
 use async_graphql::dynamic::SchemaBuilder;
@@ -15,10 +17,14 @@ use itertools::Itertools;
 use reactive_graph_dynamic_graph_api::SchemaBuilderContext;
 use reactive_graph_graph::RelationType;
 use reactive_graph_graph::RelationTypeId;
+use reactive_graph_reactive_model_impl::ReactiveRelation;
 
 pub fn register_relation_type_query_objects(mut schema: SchemaBuilder, context: &SchemaBuilderContext) -> SchemaBuilder {
     for relation_type in context.relation_type_manager.get_all().iter() {
+        let dy_ty = DynamicGraphTypeDefinition::from(relation_type.key());
         schema = schema.register(create_relation_type_query_object(relation_type.key(), relation_type.value(), context));
+        schema = schema.register(connection_object::<ReactiveRelation>(&dy_ty));
+        schema = schema.register(edge_object::<ReactiveRelation>(&dy_ty));
     }
     schema
 }
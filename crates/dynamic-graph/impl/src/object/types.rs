@@ -48,6 +48,18 @@ impl DynamicGraphTypeDefinition {
     pub fn mutation_type_name(&self) -> String {
         format!("{self}_Mutations")
     }
+
+    pub fn connection_field_name(&self) -> String {
+        format!("{}Connection", self.field_name())
+    }
+
+    pub fn connection_type_name(&self) -> String {
+        format!("{self}_Connection")
+    }
+
+    pub fn edge_type_name(&self) -> String {
+        format!("{self}_Edge")
+    }
 }
 
 impl NamespacedTypeGetter for DynamicGraphTypeDefinition {
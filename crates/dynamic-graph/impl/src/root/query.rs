@@ -1,4 +1,8 @@
 use crate::field::namespace_query_field;
+use crate::object::aggregation::aggregation_group_object;
+use crate::object::aggregation::property_aggregate_object;
+use crate::object::connection::page_info_object;
+use crate::object::namespace::metadata::metadata_object;
 use crate::object::namespace::metrics::metrics_field;
 use crate::object::namespace::metrics::metrics_object;
 use crate::object::namespace::query::namespace_query;
@@ -9,6 +13,10 @@ use reactive_graph_dynamic_graph_api::SchemaBuilderContext;
 pub fn get_query(mut schema: SchemaBuilder, context: &SchemaBuilderContext) -> SchemaBuilder {
     let mut query = Object::new("Query").description("Queries");
     schema = schema.register(metrics_object());
+    schema = schema.register(metadata_object());
+    schema = schema.register(page_info_object());
+    schema = schema.register(aggregation_group_object());
+    schema = schema.register(property_aggregate_object());
     query = query.field(metrics_field(None));
     for namespace in context.namespace_manager.get_all().iter().sorted() {
         if let Some(object_namespace) = namespace_query(context.clone(), namespace) {
@@ -0,0 +1,154 @@
+use reactive_graph_graph::EntityType;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::PropertyInstances;
+use reactive_graph_graph::PropertyType;
+use reactive_graph_graph::PropertyTypes;
+use reactive_graph_graph::RelationInstanceId;
+use reactive_graph_graph::RelationType;
+use reactive_graph_graph::RelationTypeId;
+use reactive_graph_runtime_api::Runtime;
+use reactive_graph_runtime_impl::get_runtime;
+use serde_json::Value;
+use serde_json::json;
+use std::sync::Arc;
+
+async fn query(rt: &Arc<dyn Runtime + Send + Sync>, query: &str) -> Value {
+    let schema_manager = rt.get_dynamic_graph_schema_manager();
+    schema_manager.regenerate_dynamic_schema().await.expect("Failed to generate the dynamic schema");
+    let result = rt
+        .get_dynamic_graph_query_service()
+        .query(query.to_string())
+        .await
+        .expect("Failed to run the query");
+    serde_json::from_str(&result).expect("Failed to parse the result")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dynamic_graph_aggregate_entity_instances() {
+    let rt: Arc<dyn Runtime + Send + Sync> = get_runtime();
+
+    let ty = EntityTypeId::new_from_type("inventory", "sensor");
+    let entity_type = EntityType::builder()
+        .ty(ty.clone())
+        .properties(
+            PropertyTypes::new()
+                .property(PropertyType::string("location"))
+                .property(PropertyType::number("temperature")),
+        )
+        .build();
+    rt.get_entity_type_manager().register(entity_type).expect("Failed to register entity type");
+
+    let reactive_entity_manager = rt.get_reactive_entity_manager();
+    for (location, temperature) in [("kitchen", 20.0), ("kitchen", 22.0), ("garden", 10.0)] {
+        reactive_entity_manager
+            .create_reactive_entity(&ty, PropertyInstances::new().property("location", location).property("temperature", temperature))
+            .expect("Failed to create entity");
+    }
+
+    let result = query(
+        &rt,
+        r#"
+        query {
+          inventory {
+            _metrics {
+              entityInstances
+              aggregateEntityInstances(type: "sensor", groupBy: { kind: PROPERTY, property: "location" }, property: "temperature") {
+                key
+                count
+                aggregate {
+                  sum
+                  max
+                }
+              }
+            }
+          }
+        }
+    "#,
+    )
+    .await;
+    assert_eq!(
+        json!({
+            "inventory": {
+                "_metrics": {
+                    "entityInstances": 3,
+                    "aggregateEntityInstances": [
+                        { "key": "kitchen", "count": 2, "aggregate": { "sum": 42.0, "max": 22.0 } },
+                        { "key": "garden", "count": 1, "aggregate": { "sum": 10.0, "max": 10.0 } }
+                    ]
+                }
+            }
+        }),
+        result["data"],
+        "{result}"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dynamic_graph_aggregate_relation_instances() {
+    let rt: Arc<dyn Runtime + Send + Sync> = get_runtime();
+
+    let entity_ty = EntityTypeId::new_from_type("network", "node");
+    let entity_type = EntityType::builder().ty(entity_ty.clone()).build();
+    rt.get_entity_type_manager().register(entity_type).expect("Failed to register entity type");
+    let relation_ty = RelationTypeId::new_from_type("network", "link");
+    let relation_type = RelationType::builder()
+        .outbound_type(entity_ty.clone())
+        .ty(relation_ty.clone())
+        .inbound_type(entity_ty.clone())
+        .properties(PropertyTypes::new().property(PropertyType::number("latency")))
+        .build();
+    rt.get_relation_type_manager()
+        .register(relation_type)
+        .expect("Failed to register relation type");
+
+    let reactive_entity_manager = rt.get_reactive_entity_manager();
+    let outbound = reactive_entity_manager
+        .create_reactive_entity(&entity_ty, PropertyInstances::new())
+        .expect("Failed to create entity");
+    let inbound = reactive_entity_manager
+        .create_reactive_entity(&entity_ty, PropertyInstances::new())
+        .expect("Failed to create entity");
+    let reactive_relation_manager = rt.get_reactive_relation_manager();
+    for (instance_id, latency) in [("primary", 5.0), ("secondary", 15.0)] {
+        let id = RelationInstanceId::new_unique_for_instance_id(outbound.id, relation_ty.clone(), instance_id.to_string(), inbound.id);
+        reactive_relation_manager
+            .create_reactive_relation(&id, PropertyInstances::new().property("latency", latency))
+            .expect("Failed to create relation");
+    }
+
+    let result = query(
+        &rt,
+        r#"
+        query {
+          network {
+            _metrics {
+              relationInstances
+              aggregateRelationInstances(type: "link", property: "latency") {
+                key
+                count
+                aggregate {
+                  avg
+                  min
+                }
+              }
+            }
+          }
+        }
+    "#,
+    )
+    .await;
+    assert_eq!(
+        json!({
+            "network": {
+                "_metrics": {
+                    "relationInstances": 2,
+                    "aggregateRelationInstances": [
+                        { "key": null, "count": 2, "aggregate": { "avg": 10.0, "min": 5.0 } }
+                    ]
+                }
+            }
+        }),
+        result["data"],
+        "{result}"
+    );
+}
@@ -1,2 +1,4 @@
+mod aggregation_test;
+mod property_filter_test;
 mod query_test;
 mod snapshots;
@@ -0,0 +1,54 @@
+use reactive_graph_graph::EntityType;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::PropertyInstances;
+use reactive_graph_graph::PropertyType;
+use reactive_graph_graph::PropertyTypes;
+use reactive_graph_runtime_api::Runtime;
+use reactive_graph_runtime_impl::get_runtime;
+use serde_json::Value;
+use serde_json::json;
+use std::sync::Arc;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dynamic_graph_query_property_named_filter() {
+    let rt: Arc<dyn Runtime + Send + Sync> = get_runtime();
+
+    // The property "filter" collides with the argument of the property filter
+    let ty = EntityTypeId::new_from_type("test", "item");
+    let entity_type = EntityType::builder()
+        .ty(ty.clone())
+        .properties(PropertyTypes::new().property(PropertyType::string("filter")))
+        .build();
+    rt.get_entity_type_manager().register(entity_type).expect("Failed to register entity type");
+
+    let reactive_entity_manager = rt.get_reactive_entity_manager();
+    reactive_entity_manager
+        .create_reactive_entity(&ty, PropertyInstances::new().property("filter", "a"))
+        .expect("Failed to create entity");
+    let b = reactive_entity_manager
+        .create_reactive_entity(&ty, PropertyInstances::new().property("filter", "b"))
+        .expect("Failed to create entity");
+
+    let schema_manager = rt.get_dynamic_graph_schema_manager();
+    schema_manager.regenerate_dynamic_schema().await.expect("Failed to generate the dynamic schema");
+    let sdl = schema_manager.get_dynamic_schema().await.expect("Failed to get the dynamic schema").sdl();
+    // The property filter argument takes precedence over the property argument
+    assert!(!sdl.contains("filter: String"), "{sdl}");
+
+    let query = r#"
+        query {
+          test {
+            item(filter: { name: "filter", eq: "b" }) {
+              id
+            }
+          }
+        }
+    "#;
+    let result = rt
+        .get_dynamic_graph_query_service()
+        .query(query.to_string())
+        .await
+        .expect("Failed to query items");
+    let result: Value = serde_json::from_str(&result).expect("Failed to parse the result");
+    assert_eq!(json!({ "test": { "item": [{ "id": b.id.to_string() }] } }), result["data"], "{result}");
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::AggregationGroup;
+use crate::PropertyAggregate;
+use crate::PropertyInstanceGetter;
+
+/// Groups the instances by the keys returned by the given function and aggregates the values
+/// of the property per group.
+///
+/// Without a key function all instances are aggregated in a single group. The groups are
+/// ordered by the number of instances (largest first).
+pub fn aggregate_instances<T, I, K>(instances: I, keys: Option<K>, property: Option<&str>) -> Vec<AggregationGroup>
+where
+    T: PropertyInstanceGetter,
+    I: IntoIterator<Item = T>,
+    K: Fn(&T) -> Vec<Value>,
+{
+    // The index of the group by the string representation of the key
+    let mut indexes: HashMap<String, usize> = HashMap::new();
+    let mut groups: Vec<(Value, usize, Vec<Value>)> = Vec::new();
+    for instance in instances {
+        let instance_keys = match &keys {
+            Some(keys) => keys(&instance),
+            None => vec![Value::Null],
+        };
+        let value = property.and_then(|property| instance.get(property));
+        for key in instance_keys {
+            let index = *indexes.entry(key.to_string()).or_insert_with(|| {
+                groups.push((key, 0, Vec::new()));
+                groups.len() - 1
+            });
+            let group = &mut groups[index];
+            group.1 += 1;
+            if let Some(value) = &value {
+                group.2.push(value.clone());
+            }
+        }
+    }
+    let mut groups: Vec<AggregationGroup> = groups
+        .into_iter()
+        .map(|(key, count, values)| AggregationGroup {
+            key,
+            count,
+            aggregate: PropertyAggregate::from_values(&values),
+        })
+        .collect();
+    groups.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.to_string().cmp(&b.key.to_string())));
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+    use serde_json::json;
+
+    use crate::EntityInstance;
+    use crate::EntityTypeId;
+    use crate::PropertyInstanceGetter;
+    use crate::PropertyInstances;
+    use crate::aggregate_instances;
+
+    fn create_entity_instance(location: &str, temperature: f64) -> EntityInstance {
+        EntityInstance::builder()
+            .ty(EntityTypeId::new_from_type("test", "sensor"))
+            .properties(
+                PropertyInstances::new()
+                    .property("location", json!(location))
+                    .property("temperature", json!(temperature)),
+            )
+            .build()
+    }
+
+    #[test]
+    fn aggregate_instances_test() {
+        let instances = vec![
+            create_entity_instance("kitchen", 20.0),
+            create_entity_instance("kitchen", 22.0),
+            create_entity_instance("garden", 10.0),
+        ];
+
+        let groups = aggregate_instances(instances.clone(), None::<fn(&EntityInstance) -> Vec<Value>>, Some("temperature"));
+        assert_eq!(1, groups.len());
+        assert_eq!(Value::Null, groups[0].key);
+        assert_eq!(3, groups[0].count);
+        assert_eq!(Some(22.0), groups[0].aggregate.max());
+
+        let groups = aggregate_instances(
+            instances.clone(),
+            Some(|instance: &EntityInstance| instance.get("location").into_iter().collect()),
+            Some("temperature"),
+        );
+        assert_eq!(2, groups.len());
+        assert_eq!(json!("kitchen"), groups[0].key);
+        assert_eq!(2, groups[0].count);
+        assert_eq!(Some(21.0), groups[0].aggregate.avg());
+        assert_eq!(json!("garden"), groups[1].key);
+        assert_eq!(10.0, groups[1].aggregate.sum());
+
+        let groups = aggregate_instances(instances, Some(|instance: &EntityInstance| instance.get("location").into_iter().collect()), None);
+        assert_eq!(2, groups.len());
+        assert_eq!(0, groups[0].aggregate.count());
+    }
+}
@@ -0,0 +1,16 @@
+use serde_json::Value;
+
+use crate::PropertyAggregate;
+
+/// A group of instances with the aggregated property values of the group.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AggregationGroup {
+    /// The value the instances are grouped by. Null, if the instances are not grouped.
+    pub key: Value,
+
+    /// The number of instances in the group.
+    pub count: usize,
+
+    /// The aggregated values of the property. Empty, if no property is aggregated.
+    pub aggregate: PropertyAggregate,
+}
@@ -0,0 +1,42 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::ComponentContainer;
+use crate::NamespacedType;
+use crate::NamespacedTypeGetter;
+use crate::PropertyInstanceGetter;
+
+/// Defines how instances are grouped by an aggregation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", content = "property", rename_all = "UPPERCASE")]
+pub enum AggregationGroupBy {
+    /// Groups the instances by their type.
+    Type,
+    /// Groups the instances by their components. An instance with multiple components is
+    /// counted in each group.
+    Component,
+    /// Groups the instances by the value of the property. Instances without the property
+    /// are grouped under null.
+    Property(String),
+}
+
+impl AggregationGroupBy {
+    /// Returns the keys of the groups the instance of the given type belongs to.
+    pub fn keys<T: PropertyInstanceGetter + ComponentContainer, TY: NamespacedTypeGetter>(&self, instance: &T, ty: &TY) -> Vec<Value> {
+        match self {
+            AggregationGroupBy::Type => vec![Value::String(NamespacedType::new(ty.namespace(), ty.type_name()).to_string())],
+            AggregationGroupBy::Component => {
+                let mut components: Vec<String> = instance
+                    .get_components()
+                    .into_iter()
+                    .map(|component_ty| NamespacedType::new(component_ty.namespace(), component_ty.type_name()).to_string())
+                    .collect();
+                components.sort();
+                components.into_iter().map(Value::String).collect()
+            }
+            AggregationGroupBy::Property(property) => vec![instance.get(property).unwrap_or(Value::Null)],
+        }
+    }
+}
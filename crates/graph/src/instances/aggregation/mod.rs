@@ -0,0 +1,10 @@
+pub use aggregation::*;
+pub use aggregation_group::*;
+pub use aggregation_group_by::*;
+pub use property_aggregate::*;
+
+#[allow(clippy::module_inception)]
+pub mod aggregation;
+pub mod aggregation_group;
+pub mod aggregation_group_by;
+pub mod property_aggregate;
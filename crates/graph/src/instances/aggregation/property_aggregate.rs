@@ -0,0 +1,88 @@
+use serde_json::Value;
+
+/// Aggregates the numeric values of a property.
+///
+/// Values which are not numbers are ignored.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PropertyAggregate {
+    /// The numeric values in ascending order.
+    values: Vec<f64>,
+}
+
+impl PropertyAggregate {
+    /// Collects the numeric values.
+    pub fn from_values<'a, I: IntoIterator<Item = &'a Value>>(values: I) -> Self {
+        let mut values: Vec<f64> = values.into_iter().filter_map(Value::as_f64).collect();
+        values.sort_by(f64::total_cmp);
+        PropertyAggregate { values }
+    }
+
+    /// Returns the number of numeric values.
+    pub fn count(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the sum of the values.
+    pub fn sum(&self) -> f64 {
+        self.values.iter().sum()
+    }
+
+    /// Returns the smallest value.
+    pub fn min(&self) -> Option<f64> {
+        self.values.first().copied()
+    }
+
+    /// Returns the largest value.
+    pub fn max(&self) -> Option<f64> {
+        self.values.last().copied()
+    }
+
+    /// Returns the arithmetic mean of the values.
+    pub fn avg(&self) -> Option<f64> {
+        if self.values.is_empty() {
+            return None;
+        }
+        Some(self.sum() / self.values.len() as f64)
+    }
+
+    /// Returns the percentile (between 0 and 100) of the values using linear interpolation
+    /// between the closest ranks.
+    pub fn percentile(&self, percentile: f64) -> Option<f64> {
+        if self.values.is_empty() || !(0.0..=100.0).contains(&percentile) {
+            return None;
+        }
+        let rank = percentile / 100.0 * (self.values.len() - 1) as f64;
+        let lower = self.values[rank.floor() as usize];
+        let upper = self.values[rank.ceil() as usize];
+        Some(lower + (upper - lower) * rank.fract())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::PropertyAggregate;
+
+    #[test]
+    fn property_aggregate_test() {
+        let values = vec![json!(4), json!(1.5), json!("abc"), json!(null), json!(10), json!(2.5)];
+        let aggregate = PropertyAggregate::from_values(&values);
+        assert_eq!(4, aggregate.count());
+        assert_eq!(18.0, aggregate.sum());
+        assert_eq!(Some(1.5), aggregate.min());
+        assert_eq!(Some(10.0), aggregate.max());
+        assert_eq!(Some(4.5), aggregate.avg());
+        assert_eq!(Some(1.5), aggregate.percentile(0.0));
+        assert_eq!(Some(3.25), aggregate.percentile(50.0));
+        assert_eq!(Some(10.0), aggregate.percentile(100.0));
+        assert_eq!(None, aggregate.percentile(101.0));
+
+        let aggregate = PropertyAggregate::from_values(&[json!("abc")]);
+        assert_eq!(0, aggregate.count());
+        assert_eq!(0.0, aggregate.sum());
+        assert_eq!(None, aggregate.min());
+        assert_eq!(None, aggregate.avg());
+        assert_eq!(None, aggregate.percentile(50.0));
+    }
+}
@@ -0,0 +1,106 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::ComputedPropertyDirection;
+use crate::ComputedPropertyFunction;
+use crate::ComputedPropertySource;
+use crate::RelationTypeId;
+
+/// Defines how the value of a computed property is derived from the instances which are
+/// connected to an entity instance.
+///
+/// For example, the number of inbound `connects` relations:
+///
+/// ```json
+/// { "direction": "inbound", "relation_type": { "namespace": "network", "type_name": "connects" }, "function": "count" }
+/// ```
+///
+/// Or the sum of the property `power` of the devices connected by outbound relations:
+///
+/// ```json
+/// { "direction": "outbound", "function": "sum", "property": "power" }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ComputedProperty {
+    /// The direction of the relations to follow.
+    #[serde(default)]
+    pub direction: ComputedPropertyDirection,
+
+    /// Only relations of this type are followed. If not set, all relations are followed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relation_type: Option<RelationTypeId>,
+
+    /// The function which computes the value.
+    pub function: ComputedPropertyFunction,
+
+    /// The property of the related instances which is aggregated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub property: Option<String>,
+
+    /// Whether the property is read from the related entity instances or from the relation instances.
+    #[serde(default)]
+    pub source: ComputedPropertySource,
+}
+
+impl ComputedProperty {
+    /// Returns true, if relations of the given type are followed.
+    pub fn follows(&self, relation_ty: &RelationTypeId) -> bool {
+        self.relation_type.as_ref().map(|ty| ty == relation_ty).unwrap_or(true)
+    }
+
+    /// Returns the property of the related instances which has to be observed, if any.
+    pub fn observed_property(&self) -> Option<&String> {
+        if self.function.requires_property() { self.property.as_ref() } else { None }
+    }
+
+    /// Computes the value from the values of the property of the related instances. Related
+    /// instances without the property are passed as null.
+    pub fn compute(&self, values: &[Value]) -> Value {
+        self.function.apply(values)
+    }
+}
+
+impl TryFrom<&Value> for ComputedProperty {
+    type Error = serde_json::Error;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        serde_json::from_value(value.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::ComputedProperty;
+    use crate::ComputedPropertyDirection;
+    use crate::ComputedPropertyFunction;
+    use crate::ComputedPropertySource;
+    use crate::RelationTypeId;
+
+    #[test]
+    fn computed_property_deserialize_test() {
+        let computed_property = ComputedProperty::try_from(&json!({
+            "direction": "inbound",
+            "relation_type": { "namespace": "network", "type_name": "connects" },
+            "function": "count"
+        }))
+        .unwrap();
+        assert_eq!(ComputedPropertyDirection::Inbound, computed_property.direction);
+        assert_eq!(ComputedPropertyFunction::Count, computed_property.function);
+        assert_eq!(ComputedPropertySource::Entity, computed_property.source);
+        assert!(computed_property.follows(&RelationTypeId::new_from_type("network", "connects")));
+        assert!(!computed_property.follows(&RelationTypeId::new_from_type("network", "contains")));
+        assert_eq!(None, computed_property.observed_property());
+
+        let computed_property = ComputedProperty::try_from(&json!({ "function": "sum", "property": "power" })).unwrap();
+        assert_eq!(ComputedPropertyDirection::Outbound, computed_property.direction);
+        assert!(computed_property.follows(&RelationTypeId::new_from_type("network", "connects")));
+        assert_eq!(Some(&"power".to_string()), computed_property.observed_property());
+        assert_eq!(json!(3.5), computed_property.compute(&[json!(1.5), json!(2)]));
+
+        assert!(ComputedProperty::try_from(&json!({ "function": "median" })).is_err());
+    }
+}
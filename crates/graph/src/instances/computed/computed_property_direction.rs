@@ -0,0 +1,28 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The direction of the relations which are followed by a computed property.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ComputedPropertyDirection {
+    /// Follows the outbound relations to the inbound entity instances.
+    #[default]
+    Outbound,
+    /// Follows the inbound relations to the outbound entity instances.
+    Inbound,
+    /// Follows both the outbound and the inbound relations.
+    Both,
+}
+
+impl ComputedPropertyDirection {
+    /// Returns true, if outbound relations are followed.
+    pub fn is_outbound(&self) -> bool {
+        matches!(self, ComputedPropertyDirection::Outbound | ComputedPropertyDirection::Both)
+    }
+
+    /// Returns true, if inbound relations are followed.
+    pub fn is_inbound(&self) -> bool {
+        matches!(self, ComputedPropertyDirection::Inbound | ComputedPropertyDirection::Both)
+    }
+}
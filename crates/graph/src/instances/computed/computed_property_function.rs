@@ -0,0 +1,75 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::PropertyAggregate;
+
+/// The function which derives the value of a computed property from the values of the
+/// related instances.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ComputedPropertyFunction {
+    /// The number of related instances.
+    Count,
+    /// The sum of the numeric values.
+    Sum,
+    /// The smallest numeric value or null.
+    Min,
+    /// The largest numeric value or null.
+    Max,
+    /// The arithmetic mean of the numeric values or null.
+    Avg,
+}
+
+impl ComputedPropertyFunction {
+    /// Returns true, if the function aggregates the values of a property.
+    pub fn requires_property(&self) -> bool {
+        *self != ComputedPropertyFunction::Count
+    }
+
+    /// Computes the value from the values of the related instances. Values which are not
+    /// numbers are ignored, except for counting.
+    pub fn apply(&self, values: &[Value]) -> Value {
+        if *self == ComputedPropertyFunction::Count {
+            return json!(values.len());
+        }
+        let aggregate = PropertyAggregate::from_values(values);
+        let value = match self {
+            ComputedPropertyFunction::Sum => Some(aggregate.sum()),
+            ComputedPropertyFunction::Min => aggregate.min(),
+            ComputedPropertyFunction::Max => aggregate.max(),
+            ComputedPropertyFunction::Avg => aggregate.avg(),
+            ComputedPropertyFunction::Count => None,
+        };
+        value.map(|value| json!(value)).unwrap_or(Value::Null)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+    use serde_json::json;
+
+    use crate::ComputedPropertyFunction;
+
+    #[test]
+    fn computed_property_function_test() {
+        let values = vec![json!(4), json!("abc"), json!(null), json!(2), json!(6)];
+        assert_eq!(json!(5), ComputedPropertyFunction::Count.apply(&values));
+        assert_eq!(json!(12.0), ComputedPropertyFunction::Sum.apply(&values));
+        assert_eq!(json!(2.0), ComputedPropertyFunction::Min.apply(&values));
+        assert_eq!(json!(6.0), ComputedPropertyFunction::Max.apply(&values));
+        assert_eq!(json!(4.0), ComputedPropertyFunction::Avg.apply(&values));
+    }
+
+    #[test]
+    fn computed_property_function_empty_test() {
+        assert_eq!(json!(0), ComputedPropertyFunction::Count.apply(&[]));
+        assert_eq!(json!(0.0), ComputedPropertyFunction::Sum.apply(&[]));
+        assert_eq!(Value::Null, ComputedPropertyFunction::Min.apply(&[]));
+        assert_eq!(Value::Null, ComputedPropertyFunction::Max.apply(&[]));
+        assert_eq!(Value::Null, ComputedPropertyFunction::Avg.apply(&[]));
+    }
+}
@@ -0,0 +1,14 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The instances whose property values are aggregated by a computed property.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ComputedPropertySource {
+    /// The entity instances at the other end of the relations.
+    #[default]
+    Entity,
+    /// The relation instances themselves.
+    Relation,
+}
@@ -0,0 +1,9 @@
+pub use computed_property::*;
+pub use computed_property_direction::*;
+pub use computed_property_function::*;
+pub use computed_property_source::*;
+
+pub mod computed_property;
+pub mod computed_property_direction;
+pub mod computed_property_function;
+pub mod computed_property_source;
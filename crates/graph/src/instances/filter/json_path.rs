@@ -0,0 +1,137 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use serde_json::Value;
+
+use crate::PropertyFilterError;
+
+/// A segment of a JSON path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JsonPathSegment {
+    /// Selects the value of an object by key.
+    Key(String),
+    /// Selects the element of an array by index.
+    Index(usize),
+}
+
+/// Selects a value within a JSON value.
+///
+/// Supports the dot notation (`$.position.x`, `position.x`), indexes (`tags[0]`), quoted keys
+/// (`$['first name']`) and JSON pointers (`/position/x`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonPath {
+    pub segments: Vec<JsonPathSegment>,
+}
+
+impl JsonPath {
+    /// Parses the given JSON path.
+    pub fn parse(path: &str) -> Result<JsonPath, PropertyFilterError> {
+        let invalid = || PropertyFilterError::InvalidJsonPath(path.to_string());
+        if let Some(pointer) = path.strip_prefix('/') {
+            let segments = pointer
+                .split('/')
+                .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+                .map(|segment| match segment.parse::<usize>() {
+                    Ok(index) => JsonPathSegment::Index(index),
+                    Err(_) => JsonPathSegment::Key(segment),
+                })
+                .collect();
+            return Ok(JsonPath { segments });
+        }
+        let mut segments = Vec::new();
+        let mut chars = path.strip_prefix('$').unwrap_or(path).chars();
+        let mut key = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '.' => {
+                    if !key.is_empty() {
+                        segments.push(JsonPathSegment::Key(std::mem::take(&mut key)));
+                    }
+                }
+                '[' => {
+                    if !key.is_empty() {
+                        segments.push(JsonPathSegment::Key(std::mem::take(&mut key)));
+                    }
+                    let mut inner = String::new();
+                    loop {
+                        match chars.next() {
+                            Some(']') => break,
+                            Some(c) => inner.push(c),
+                            None => return Err(invalid()),
+                        }
+                    }
+                    let inner = inner.trim();
+                    let quoted = inner
+                        .strip_prefix('\'')
+                        .and_then(|inner| inner.strip_suffix('\''))
+                        .or_else(|| inner.strip_prefix('"').and_then(|inner| inner.strip_suffix('"')));
+                    match quoted {
+                        Some(quoted) => segments.push(JsonPathSegment::Key(quoted.to_string())),
+                        None => segments.push(JsonPathSegment::Index(inner.parse().map_err(|_| invalid())?)),
+                    }
+                }
+                ']' => return Err(invalid()),
+                c => key.push(c),
+            }
+        }
+        if !key.is_empty() {
+            segments.push(JsonPathSegment::Key(key));
+        }
+        Ok(JsonPath { segments })
+    }
+
+    /// Returns the selected value.
+    pub fn select<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        self.segments.iter().try_fold(value, |value, segment| match segment {
+            JsonPathSegment::Key(key) => value.as_object()?.get(key),
+            JsonPathSegment::Index(index) => value.as_array()?.get(*index),
+        })
+    }
+}
+
+impl Display for JsonPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "$")?;
+        for segment in &self.segments {
+            match segment {
+                JsonPathSegment::Key(key) => write!(f, "['{key}']")?,
+                JsonPathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::JsonPath;
+    use crate::JsonPathSegment;
+
+    #[test]
+    fn json_path_parse_test() {
+        let expected = vec![
+            JsonPathSegment::Key("position".to_string()),
+            JsonPathSegment::Index(1),
+            JsonPathSegment::Key("x".to_string()),
+        ];
+        assert_eq!(expected, JsonPath::parse("$.position[1].x").unwrap().segments);
+        assert_eq!(expected, JsonPath::parse("position[1].x").unwrap().segments);
+        assert_eq!(expected, JsonPath::parse("$['position'][1][\"x\"]").unwrap().segments);
+        assert_eq!(expected, JsonPath::parse("/position/1/x").unwrap().segments);
+        assert!(JsonPath::parse("$").unwrap().segments.is_empty());
+        assert!(JsonPath::parse("tags[a]").is_err());
+        assert!(JsonPath::parse("tags[0").is_err());
+        assert_eq!("$['position'][1]", JsonPath::parse("position[1]").unwrap().to_string());
+    }
+
+    #[test]
+    fn json_path_select_test() {
+        let value = json!({ "position": [{ "x": 1 }, { "x": 2 }] });
+        assert_eq!(Some(&json!(2)), JsonPath::parse("$.position[1].x").unwrap().select(&value));
+        assert_eq!(Some(&value), JsonPath::parse("$").unwrap().select(&value));
+        assert_eq!(None, JsonPath::parse("$.position[2].x").unwrap().select(&value));
+        assert_eq!(None, JsonPath::parse("$.position.x").unwrap().select(&value));
+    }
+}
@@ -0,0 +1,9 @@
+pub use json_path::*;
+pub use property_filter::*;
+pub use property_filter_errors::*;
+pub use property_filter_expression::*;
+
+pub mod json_path;
+pub mod property_filter;
+pub mod property_filter_errors;
+pub mod property_filter_expression;
@@ -0,0 +1,237 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::JsonPath;
+use crate::PropertyCondition;
+use crate::PropertyFilterError;
+use crate::PropertyFilterExpression;
+use crate::PropertyFilterOperator;
+
+/// Filters instances by their properties.
+///
+/// All operators of a filter are combined with `and`. The operators are applied on the property
+/// with the given name. If a JSON path is given, the operators are applied on the selected value
+/// within the property value.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyFilter {
+    /// The name of the property.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Selects a value within the property value (for example `$.position.x` or `tags[0]`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// The value is equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<Value>,
+
+    /// The value is not equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ne: Option<Value>,
+
+    /// The value is greater than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gt: Option<Value>,
+
+    /// The value is greater than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gte: Option<Value>,
+
+    /// The value is less than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lt: Option<Value>,
+
+    /// The value is less than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lte: Option<Value>,
+
+    /// The value is between the two given values (inclusive).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub between: Option<Vec<Value>>,
+
+    /// The value is one of the given values.
+    #[serde(rename = "in", default, skip_serializing_if = "Option::is_none")]
+    pub in_values: Option<Vec<Value>>,
+
+    /// The string contains the given string, the array contains the given element or the
+    /// object contains the given key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains: Option<Value>,
+
+    /// The string starts with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_with: Option<String>,
+
+    /// The string ends with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_with: Option<String>,
+
+    /// The string matches the given regular expression.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regex: Option<String>,
+
+    /// The property (or the value selected by the path) exists and is not null.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exists: Option<bool>,
+
+    /// All of the given filters matches.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<PropertyFilter>>,
+
+    /// At least one of the given filters matches.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<PropertyFilter>>,
+
+    /// The given filter doesn't match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not: Option<Box<PropertyFilter>>,
+}
+
+impl PropertyFilter {
+    /// Constructs a filter which matches if the property is equal to the given value.
+    pub fn eq<S: Into<String>>(name: S, value: Value) -> Self {
+        PropertyFilter {
+            name: Some(name.into()),
+            eq: Some(value),
+            ..Default::default()
+        }
+    }
+
+    /// Validates the filter and compiles it into an expression.
+    pub fn compile(&self) -> Result<PropertyFilterExpression, PropertyFilterError> {
+        let mut expressions = Vec::new();
+        let operators = self.operators()?;
+        if !operators.is_empty() {
+            let name = self.name.clone().ok_or(PropertyFilterError::MissingPropertyName)?;
+            let path = match &self.path {
+                Some(path) => Some(JsonPath::parse(path)?),
+                None => None,
+            };
+            for operator in operators {
+                expressions.push(PropertyFilterExpression::Condition(PropertyCondition {
+                    name: name.clone(),
+                    path: path.clone(),
+                    operator,
+                }));
+            }
+        } else if self.path.is_some() {
+            return Err(PropertyFilterError::MissingOperator);
+        }
+        if let Some(and) = &self.and {
+            expressions.push(PropertyFilterExpression::And(and.iter().map(|filter| filter.compile()).collect::<Result<_, _>>()?));
+        }
+        if let Some(or) = &self.or {
+            expressions.push(PropertyFilterExpression::Or(or.iter().map(|filter| filter.compile()).collect::<Result<_, _>>()?));
+        }
+        if let Some(not) = &self.not {
+            expressions.push(PropertyFilterExpression::Not(Box::new(not.compile()?)));
+        }
+        Ok(match expressions.len() {
+            0 => PropertyFilterExpression::And(Vec::new()),
+            1 => expressions.remove(0),
+            _ => PropertyFilterExpression::And(expressions),
+        })
+    }
+
+    fn operators(&self) -> Result<Vec<PropertyFilterOperator>, PropertyFilterError> {
+        let mut operators = Vec::new();
+        if let Some(value) = &self.eq {
+            operators.push(PropertyFilterOperator::Eq(value.clone()));
+        }
+        if let Some(value) = &self.ne {
+            operators.push(PropertyFilterOperator::Ne(value.clone()));
+        }
+        if let Some(value) = &self.gt {
+            operators.push(PropertyFilterOperator::Gt(value.clone()));
+        }
+        if let Some(value) = &self.gte {
+            operators.push(PropertyFilterOperator::Gte(value.clone()));
+        }
+        if let Some(value) = &self.lt {
+            operators.push(PropertyFilterOperator::Lt(value.clone()));
+        }
+        if let Some(value) = &self.lte {
+            operators.push(PropertyFilterOperator::Lte(value.clone()));
+        }
+        if let Some(values) = &self.between {
+            let [lower, upper] = values.as_slice() else {
+                return Err(PropertyFilterError::InvalidBetween(values.len()));
+            };
+            operators.push(PropertyFilterOperator::Between(lower.clone(), upper.clone()));
+        }
+        if let Some(values) = &self.in_values {
+            operators.push(PropertyFilterOperator::In(values.clone()));
+        }
+        if let Some(value) = &self.contains {
+            operators.push(PropertyFilterOperator::Contains(value.clone()));
+        }
+        if let Some(value) = &self.starts_with {
+            operators.push(PropertyFilterOperator::StartsWith(value.clone()));
+        }
+        if let Some(value) = &self.ends_with {
+            operators.push(PropertyFilterOperator::EndsWith(value.clone()));
+        }
+        if let Some(pattern) = &self.regex {
+            let regex = regex::Regex::new(pattern).map_err(|e| PropertyFilterError::InvalidRegex(pattern.clone(), e.to_string()))?;
+            operators.push(PropertyFilterOperator::Regex(regex));
+        }
+        if let Some(exists) = self.exists {
+            operators.push(PropertyFilterOperator::Exists(exists));
+        }
+        Ok(operators)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::PropertyFilter;
+    use crate::PropertyFilterError;
+
+    #[test]
+    fn property_filter_deserialize_test() {
+        let filter: PropertyFilter = serde_json::from_value(json!({
+            "or": [
+                { "name": "temperature", "between": [10, 20] },
+                { "name": "name", "startsWith": "sensor", "in": ["sensor-1", "sensor-2"] }
+            ],
+            "not": { "name": "disabled", "eq": true }
+        }))
+        .unwrap();
+        assert_eq!(2, filter.or.as_ref().unwrap().len());
+        assert_eq!(Some(2), filter.or.as_ref().unwrap()[1].in_values.as_ref().map(|values| values.len()));
+        assert_eq!(Some(json!(true)), filter.not.unwrap().eq);
+    }
+
+    #[test]
+    fn property_filter_compile_errors_test() {
+        let filter = PropertyFilter {
+            eq: Some(json!(1)),
+            ..Default::default()
+        };
+        assert!(matches!(filter.compile(), Err(PropertyFilterError::MissingPropertyName)));
+        let filter = PropertyFilter {
+            name: Some("x".to_string()),
+            between: Some(vec![json!(1)]),
+            ..Default::default()
+        };
+        assert!(matches!(filter.compile(), Err(PropertyFilterError::InvalidBetween(1))));
+        let filter = PropertyFilter {
+            name: Some("x".to_string()),
+            regex: Some("(".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(filter.compile(), Err(PropertyFilterError::InvalidRegex(_, _))));
+        let filter = PropertyFilter {
+            name: Some("x".to_string()),
+            path: Some("$.a".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(filter.compile(), Err(PropertyFilterError::MissingOperator)));
+    }
+}
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PropertyFilterError {
+    #[error("The filter has operators but no property name")]
+    MissingPropertyName,
+    #[error("The filter has a path but no operator")]
+    MissingOperator,
+    #[error("The operator between requires exactly two values but got {0}")]
+    InvalidBetween(usize),
+    #[error("The regular expression {0} is invalid: {1}")]
+    InvalidRegex(String, String),
+    #[error("The JSON path {0} is invalid")]
+    InvalidJsonPath(String),
+}
@@ -0,0 +1,233 @@
+use std::cmp::Ordering;
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::JsonPath;
+use crate::PropertyInstanceGetter;
+
+/// A compiled property filter.
+#[derive(Clone, Debug)]
+pub enum PropertyFilterExpression {
+    /// Matches if all expressions are matching. Matches if empty.
+    And(Vec<PropertyFilterExpression>),
+    /// Matches if at least one expression is matching. Doesn't match if empty.
+    Or(Vec<PropertyFilterExpression>),
+    /// Matches if the expression doesn't match.
+    Not(Box<PropertyFilterExpression>),
+    /// Matches if the condition is fulfilled.
+    Condition(PropertyCondition),
+}
+
+/// Applies an operator on a property value.
+#[derive(Clone, Debug)]
+pub struct PropertyCondition {
+    /// The name of the property.
+    pub name: String,
+    /// Selects a value within the property value.
+    pub path: Option<JsonPath>,
+    /// The operator.
+    pub operator: PropertyFilterOperator,
+}
+
+#[derive(Clone, Debug)]
+pub enum PropertyFilterOperator {
+    Eq(Value),
+    Ne(Value),
+    Gt(Value),
+    Gte(Value),
+    Lt(Value),
+    Lte(Value),
+    Between(Value, Value),
+    In(Vec<Value>),
+    Contains(Value),
+    StartsWith(String),
+    EndsWith(String),
+    Regex(Regex),
+    Exists(bool),
+}
+
+impl PropertyFilterExpression {
+    /// Returns true, if the properties of the given instance are matching the expression.
+    pub fn matches<T: PropertyInstanceGetter>(&self, instance: &T) -> bool {
+        match self {
+            PropertyFilterExpression::And(expressions) => expressions.iter().all(|expression| expression.matches(instance)),
+            PropertyFilterExpression::Or(expressions) => expressions.iter().any(|expression| expression.matches(instance)),
+            PropertyFilterExpression::Not(expression) => !expression.matches(instance),
+            PropertyFilterExpression::Condition(condition) => condition.matches(instance),
+        }
+    }
+
+    /// Returns the equality conditions (`eq` and `in`) on whole property values which have to
+    /// be fulfilled by every matching instance. Used to select candidates from property indexes.
+    pub fn equality_conditions(&self) -> Vec<(&str, Vec<&Value>)> {
+        match self {
+            PropertyFilterExpression::And(expressions) => expressions.iter().flat_map(|expression| expression.equality_conditions()).collect(),
+            PropertyFilterExpression::Condition(PropertyCondition { name, path: None, operator }) => match operator {
+                PropertyFilterOperator::Eq(value) => vec![(name.as_str(), vec![value])],
+                PropertyFilterOperator::In(values) => vec![(name.as_str(), values.iter().collect())],
+                _ => Vec::new(),
+            },
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl PropertyCondition {
+    /// Returns true, if the property of the given instance fulfills the condition.
+    pub fn matches<T: PropertyInstanceGetter>(&self, instance: &T) -> bool {
+        let value = instance.get(&self.name);
+        let value = match &self.path {
+            Some(path) => value.and_then(|value| path.select(&value).cloned()),
+            None => value,
+        };
+        self.operator.test(value.as_ref())
+    }
+}
+
+impl PropertyFilterOperator {
+    /// Tests the given value. The value is none if the property doesn't exist.
+    pub fn test(&self, value: Option<&Value>) -> bool {
+        if let PropertyFilterOperator::Exists(exists) = self {
+            return value.map(|value| !value.is_null()).unwrap_or(false) == *exists;
+        }
+        let Some(value) = value else {
+            return false;
+        };
+        match self {
+            PropertyFilterOperator::Eq(expected) => json_eq(value, expected),
+            PropertyFilterOperator::Ne(expected) => !json_eq(value, expected),
+            PropertyFilterOperator::Gt(expected) => json_cmp(value, expected) == Some(Ordering::Greater),
+            PropertyFilterOperator::Gte(expected) => matches!(json_cmp(value, expected), Some(Ordering::Greater | Ordering::Equal)),
+            PropertyFilterOperator::Lt(expected) => json_cmp(value, expected) == Some(Ordering::Less),
+            PropertyFilterOperator::Lte(expected) => matches!(json_cmp(value, expected), Some(Ordering::Less | Ordering::Equal)),
+            PropertyFilterOperator::Between(lower, upper) => {
+                matches!(json_cmp(value, lower), Some(Ordering::Greater | Ordering::Equal))
+                    && matches!(json_cmp(value, upper), Some(Ordering::Less | Ordering::Equal))
+            }
+            PropertyFilterOperator::In(values) => values.iter().any(|expected| json_eq(value, expected)),
+            PropertyFilterOperator::Contains(expected) => match value {
+                Value::String(value) => expected.as_str().map(|expected| value.contains(expected)).unwrap_or(false),
+                Value::Array(values) => values.iter().any(|value| json_eq(value, expected)),
+                Value::Object(map) => expected.as_str().map(|key| map.contains_key(key)).unwrap_or(false),
+                _ => false,
+            },
+            PropertyFilterOperator::StartsWith(prefix) => value.as_str().map(|value| value.starts_with(prefix)).unwrap_or(false),
+            PropertyFilterOperator::EndsWith(suffix) => value.as_str().map(|value| value.ends_with(suffix)).unwrap_or(false),
+            PropertyFilterOperator::Regex(regex) => value.as_str().map(|value| regex.is_match(value)).unwrap_or(false),
+            PropertyFilterOperator::Exists(_) => unreachable!(),
+        }
+    }
+}
+
+/// Compares two JSON values. Numbers are compared by their numeric value.
+fn json_eq(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(_), Value::Number(_)) => json_cmp(a, b) == Some(Ordering::Equal),
+        _ => a == b,
+    }
+}
+
+/// Orders two JSON values of the same kind. Returns none if the values are not comparable.
+pub(crate) fn json_cmp(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => {
+            if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+                Some(a.cmp(&b))
+            } else if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+                Some(a.cmp(&b))
+            } else {
+                a.as_f64()?.partial_cmp(&b.as_f64()?)
+            }
+        }
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::PropertyFilter;
+    use crate::PropertyInstances;
+
+    fn properties() -> PropertyInstances {
+        PropertyInstances::new()
+            .property("name", json!("sensor-1"))
+            .property("temperature", json!(21.5))
+            .property("count", json!(3))
+            .property("tags", json!(["indoor", "kitchen"]))
+            .property("position", json!({ "x": 10, "y": [1, 2] }))
+            .property("disabled", json!(null))
+    }
+
+    fn matches(filter: serde_json::Value) -> bool {
+        let filter: PropertyFilter = serde_json::from_value(filter).expect("Invalid filter");
+        filter.compile().expect("Failed to compile filter").matches(&properties())
+    }
+
+    #[test]
+    fn comparison_operators_test() {
+        assert!(matches(json!({ "name": "count", "eq": 3.0 })));
+        assert!(matches(json!({ "name": "count", "ne": 4 })));
+        assert!(matches(json!({ "name": "temperature", "gt": 20 })));
+        assert!(!matches(json!({ "name": "temperature", "lt": 20 })));
+        assert!(matches(json!({ "name": "temperature", "between": [21.5, 30] })));
+        assert!(matches(json!({ "name": "count", "gte": 3, "lte": 3 })));
+        assert!(matches(json!({ "name": "name", "in": ["sensor-0", "sensor-1"] })));
+        // Values of different kinds are not comparable
+        assert!(!matches(json!({ "name": "name", "gt": 1 })));
+        // Missing properties doesn't match
+        assert!(!matches(json!({ "name": "missing", "ne": 1 })));
+    }
+
+    #[test]
+    fn string_operators_test() {
+        assert!(matches(json!({ "name": "name", "startsWith": "sensor" })));
+        assert!(matches(json!({ "name": "name", "endsWith": "-1" })));
+        assert!(matches(json!({ "name": "name", "contains": "nsor" })));
+        assert!(matches(json!({ "name": "name", "regex": "^sensor-[0-9]+$" })));
+        assert!(!matches(json!({ "name": "name", "regex": "^actor" })));
+    }
+
+    #[test]
+    fn container_and_path_operators_test() {
+        assert!(matches(json!({ "name": "tags", "contains": "kitchen" })));
+        assert!(matches(json!({ "name": "position", "contains": "x" })));
+        assert!(matches(json!({ "name": "position", "path": "$.x", "gte": 10 })));
+        assert!(matches(json!({ "name": "position", "path": "y[1]", "eq": 2 })));
+        assert!(matches(json!({ "name": "tags", "path": "[0]", "eq": "indoor" })));
+        assert!(!matches(json!({ "name": "position", "path": "$.z", "exists": true })));
+        assert!(matches(json!({ "name": "disabled", "exists": false })));
+        assert!(matches(json!({ "name": "missing", "exists": false })));
+    }
+
+    #[test]
+    fn combinators_test() {
+        assert!(matches(json!({ "and": [{ "name": "count", "eq": 3 }, { "name": "name", "startsWith": "sensor" }] })));
+        assert!(matches(json!({ "or": [{ "name": "count", "eq": 4 }, { "name": "name", "startsWith": "sensor" }] })));
+        assert!(!matches(json!({ "or": [] })));
+        assert!(matches(json!({ "not": { "name": "count", "eq": 4 } })));
+        assert!(!matches(json!({ "name": "count", "eq": 3, "not": { "name": "tags", "contains": "indoor" } })));
+        assert!(matches(json!({})));
+    }
+
+    #[test]
+    fn equality_conditions_test() {
+        let filter: PropertyFilter = serde_json::from_value(json!({
+            "name": "count",
+            "eq": 3,
+            "and": [{ "name": "name", "in": ["a", "b"] }, { "name": "position", "path": "$.x", "eq": 1 }],
+            "or": [{ "name": "tags", "eq": [] }]
+        }))
+        .unwrap();
+        let filter = filter.compile().unwrap();
+        let conditions = filter.equality_conditions();
+        assert_eq!(2, conditions.len());
+        assert_eq!(("count", vec![&json!(3)]), conditions[0]);
+        assert_eq!("name", conditions[1].0);
+        assert_eq!(2, conditions[1].1.len());
+    }
+}
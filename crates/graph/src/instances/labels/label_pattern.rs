@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use crate::LabelPatternParseError;
+
+/// The separator of the segments of a label.
+pub const LABEL_SEGMENT_SEPARATOR: char = '/';
+
+/// The maximum number of segments of a label pattern.
+pub const MAX_LABEL_PATTERN_SEGMENTS: usize = 64;
+
+/// A segment of a label pattern.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LabelPatternSegment {
+    /// Matches exactly the given segment.
+    Literal(String),
+    /// Matches exactly one segment (`*` or `+`).
+    Wildcard,
+    /// Matches zero or more segments (`**` or `#`).
+    MultiWildcard,
+    /// Matches exactly one segment and extracts it as parameter (`:name` or `{name}`).
+    Parameter(String),
+}
+
+impl Display for LabelPatternSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LabelPatternSegment::Literal(segment) => write!(f, "{segment}"),
+            LabelPatternSegment::Wildcard => write!(f, "*"),
+            LabelPatternSegment::MultiWildcard => write!(f, "**"),
+            LabelPatternSegment::Parameter(name) => write!(f, ":{name}"),
+        }
+    }
+}
+
+/// A pattern which matches hierarchical labels like `/org/inexor/sensors/*/temperature`.
+///
+/// Similar to MQTT topic filters, `*` (or `+`) matches a single segment and `**` (or `#`)
+/// matches any number of segments. Named segments (`:name` or `{name}`) are extracted as
+/// parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LabelPattern {
+    segments: Vec<LabelPatternSegment>,
+}
+
+impl LabelPattern {
+    /// Parses the given label pattern.
+    pub fn parse(pattern: &str) -> Result<Self, LabelPatternParseError> {
+        if pattern.is_empty() {
+            return Err(LabelPatternParseError::Empty);
+        }
+        if pattern.split(LABEL_SEGMENT_SEPARATOR).count() > MAX_LABEL_PATTERN_SEGMENTS {
+            return Err(LabelPatternParseError::TooManySegments(MAX_LABEL_PATTERN_SEGMENTS));
+        }
+        let mut names = HashSet::new();
+        let mut segments = Vec::new();
+        for (index, segment) in pattern.split(LABEL_SEGMENT_SEPARATOR).enumerate() {
+            let segment = match segment {
+                "*" | "+" => LabelPatternSegment::Wildcard,
+                "**" | "#" => LabelPatternSegment::MultiWildcard,
+                _ => match Self::parameter_name(segment) {
+                    Some("") => return Err(LabelPatternParseError::EmptyParameterName(index)),
+                    Some(name) => {
+                        if !names.insert(name.to_string()) {
+                            return Err(LabelPatternParseError::DuplicateParameter(name.to_string()));
+                        }
+                        LabelPatternSegment::Parameter(name.to_string())
+                    }
+                    None => LabelPatternSegment::Literal(segment.to_string()),
+                },
+            };
+            // Consecutive multi wildcards are equivalent to a single multi wildcard
+            if segment == LabelPatternSegment::MultiWildcard && segments.last() == Some(&LabelPatternSegment::MultiWildcard) {
+                continue;
+            }
+            segments.push(segment);
+        }
+        Ok(Self { segments })
+    }
+
+    /// Returns the segments of the pattern.
+    pub fn segments(&self) -> &Vec<LabelPatternSegment> {
+        &self.segments
+    }
+
+    /// Returns true, if the pattern contains neither wildcards nor parameters.
+    pub fn is_literal(&self) -> bool {
+        self.segments.iter().all(|segment| matches!(segment, LabelPatternSegment::Literal(_)))
+    }
+
+    /// Returns the names of the parameters of the pattern.
+    pub fn parameter_names(&self) -> Vec<String> {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                LabelPatternSegment::Parameter(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Matches the given label and returns the extracted parameters on success.
+    pub fn matches(&self, label: &str) -> Option<HashMap<String, String>> {
+        let label: Vec<&str> = label.split(LABEL_SEGMENT_SEPARATOR).collect();
+        match_segments(&self.segments, &label)
+    }
+
+    /// Returns true, if the given label matches the pattern.
+    pub fn is_match(&self, label: &str) -> bool {
+        self.matches(label).is_some()
+    }
+
+    fn parameter_name(segment: &str) -> Option<&str> {
+        segment
+            .strip_prefix(':')
+            .or_else(|| segment.strip_prefix('{').and_then(|segment| segment.strip_suffix('}')))
+    }
+}
+
+/// Matches the label segments against the pattern segments in O(pattern * label) time.
+///
+/// First, the table of which pattern suffixes match which label suffixes is computed. Then the
+/// parameters are extracted along a matching path, where multi wildcards match as few segments
+/// as possible.
+fn match_segments(pattern: &[LabelPatternSegment], label: &[&str]) -> Option<HashMap<String, String>> {
+    let columns = label.len() + 1;
+    // matches[i * columns + j] is true, if pattern[i..] matches label[j..]
+    let mut matches = vec![false; (pattern.len() + 1) * columns];
+    matches[pattern.len() * columns + label.len()] = true;
+    for (i, segment) in pattern.iter().enumerate().rev() {
+        for j in (0..=label.len()).rev() {
+            matches[i * columns + j] = match segment {
+                LabelPatternSegment::MultiWildcard => matches[(i + 1) * columns + j] || (j < label.len() && matches[i * columns + j + 1]),
+                LabelPatternSegment::Literal(literal) => j < label.len() && literal == label[j] && matches[(i + 1) * columns + j + 1],
+                LabelPatternSegment::Wildcard | LabelPatternSegment::Parameter(_) => j < label.len() && matches[(i + 1) * columns + j + 1],
+            };
+        }
+    }
+    if !matches[0] {
+        return None;
+    }
+    let mut params = HashMap::new();
+    let mut j = 0;
+    for (i, segment) in pattern.iter().enumerate() {
+        match segment {
+            LabelPatternSegment::MultiWildcard => {
+                while !matches[(i + 1) * columns + j] {
+                    j += 1;
+                }
+            }
+            LabelPatternSegment::Parameter(name) => {
+                params.insert(name.clone(), label[j].to_string());
+                j += 1;
+            }
+            _ => j += 1,
+        }
+    }
+    Some(params)
+}
+
+impl Display for LabelPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let segments: Vec<String> = self.segments.iter().map(|segment| segment.to_string()).collect();
+        write!(f, "{}", segments.join("/"))
+    }
+}
+
+impl FromStr for LabelPattern {
+    type Err = LabelPatternParseError;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        LabelPattern::parse(pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::LabelPattern;
+    use crate::LabelPatternParseError;
+    use crate::MAX_LABEL_PATTERN_SEGMENTS;
+
+    #[test]
+    fn literal_pattern_test() {
+        let pattern = LabelPattern::parse("/org/inexor/sensors/kitchen/temperature").unwrap();
+        assert!(pattern.is_literal());
+        assert!(pattern.is_match("/org/inexor/sensors/kitchen/temperature"));
+        assert!(!pattern.is_match("/org/inexor/sensors/kitchen"));
+        assert!(!pattern.is_match("/org/inexor/sensors/kitchen/temperature/value"));
+    }
+
+    #[test]
+    fn single_wildcard_test() {
+        let pattern = LabelPattern::parse("/org/inexor/sensors/*/temperature").unwrap();
+        assert!(!pattern.is_literal());
+        assert!(pattern.is_match("/org/inexor/sensors/kitchen/temperature"));
+        assert!(pattern.is_match("/org/inexor/sensors/bathroom/temperature"));
+        assert!(!pattern.is_match("/org/inexor/sensors/temperature"));
+        assert!(!pattern.is_match("/org/inexor/sensors/kitchen/window/temperature"));
+        assert_eq!(pattern, LabelPattern::parse("/org/inexor/sensors/+/temperature").unwrap());
+    }
+
+    #[test]
+    fn multi_wildcard_test() {
+        let pattern = LabelPattern::parse("/org/inexor/**").unwrap();
+        assert!(pattern.is_match("/org/inexor"));
+        assert!(pattern.is_match("/org/inexor/sensors"));
+        assert!(pattern.is_match("/org/inexor/sensors/kitchen/temperature"));
+        assert!(!pattern.is_match("/org/other/sensors"));
+
+        let pattern = LabelPattern::parse("/org/**/temperature").unwrap();
+        assert!(pattern.is_match("/org/temperature"));
+        assert!(pattern.is_match("/org/inexor/sensors/kitchen/temperature"));
+        assert!(!pattern.is_match("/org/inexor/sensors/kitchen/humidity"));
+        assert_eq!(pattern, LabelPattern::parse("/org/#/temperature").unwrap());
+    }
+
+    #[test]
+    fn parameter_extraction_test() {
+        let pattern = LabelPattern::parse("/org/inexor/sensors/:room/{quantity}").unwrap();
+        assert_eq!(vec!["room".to_string(), "quantity".to_string()], pattern.parameter_names());
+        let params = pattern.matches("/org/inexor/sensors/kitchen/temperature").unwrap();
+        assert_eq!(
+            HashMap::from([("room".to_string(), "kitchen".to_string()), ("quantity".to_string(), "temperature".to_string())]),
+            params
+        );
+        assert!(pattern.matches("/org/inexor/sensors/kitchen").is_none());
+
+        let pattern = LabelPattern::parse("/**/:room/temperature").unwrap();
+        let params = pattern.matches("/org/inexor/sensors/kitchen/temperature").unwrap();
+        assert_eq!(Some(&"kitchen".to_string()), params.get("room"));
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        assert_eq!(Err(LabelPatternParseError::Empty), LabelPattern::parse(""));
+        assert_eq!(Err(LabelPatternParseError::EmptyParameterName(2)), LabelPattern::parse("/org/:/temperature"));
+        assert_eq!(Err(LabelPatternParseError::DuplicateParameter("room".to_string())), LabelPattern::parse("/:room/:room"));
+        assert_eq!(
+            Err(LabelPatternParseError::TooManySegments(MAX_LABEL_PATTERN_SEGMENTS)),
+            LabelPattern::parse(&"/a".repeat(MAX_LABEL_PATTERN_SEGMENTS))
+        );
+    }
+
+    #[test]
+    fn consecutive_multi_wildcards_test() {
+        let pattern = LabelPattern::parse("/org/**/#/**/temperature").unwrap();
+        assert_eq!(LabelPattern::parse("/org/**/temperature").unwrap(), pattern);
+        assert!(pattern.is_match("/org/inexor/sensors/kitchen/temperature"));
+    }
+
+    #[test]
+    fn many_multi_wildcards_test() {
+        // Doesn't backtrack exponentially
+        let pattern = LabelPattern::parse(&format!("{}/x", "/**/a".repeat(20))).unwrap();
+        let label = "/a".repeat(50);
+        assert!(!pattern.is_match(&label));
+        assert!(pattern.is_match(&format!("{label}/x")));
+    }
+
+    #[test]
+    fn display_test() {
+        let pattern = LabelPattern::parse("/org/+/{room}/#").unwrap();
+        assert_eq!("/org/*/:room/**", pattern.to_string());
+        assert_eq!(pattern, pattern.to_string().parse::<LabelPattern>().unwrap());
+    }
+}
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum LabelPatternParseError {
+    #[error("The label pattern is empty")]
+    Empty,
+    #[error("The parameter at segment {0} has no name")]
+    EmptyParameterName(usize),
+    #[error("The parameter {0} is used more than once")]
+    DuplicateParameter(String),
+    #[error("The label pattern has more than {0} segments")]
+    TooManySegments(usize),
+}
@@ -0,0 +1,5 @@
+pub use label_pattern::*;
+pub use label_pattern_errors::*;
+
+pub mod label_pattern;
+pub mod label_pattern_errors;
@@ -1,14 +1,30 @@
+pub use aggregation::*;
 #[allow(unused_imports)]
 pub use components::*;
+pub use computed::*;
 pub use entities::*;
+pub use filter::*;
 pub use flows::*;
+pub use labels::*;
 pub use properties::*;
+pub use query::*;
 pub use relations::*;
+pub use saved_queries::*;
+pub use search::*;
+pub use traversal::*;
 
+pub mod aggregation;
 pub mod components;
+pub mod computed;
 pub mod entities;
+pub mod filter;
 pub mod flows;
+pub mod labels;
 pub mod properties;
+pub mod query;
 pub mod relations;
+pub mod saved_queries;
+pub mod search;
+pub mod traversal;
 
 pub mod named;
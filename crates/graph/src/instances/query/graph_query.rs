@@ -0,0 +1,253 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use serde_json::Value;
+
+use crate::GraphQueryParseError;
+use crate::PropertyFilter;
+use crate::parse_graph_query;
+
+/// A pattern matching query over the reactive graph.
+///
+/// ```text
+/// MATCH (a:namespace::sensor)-[:connects]->(b) WHERE a.value > 10 RETURN b
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphQuery {
+    /// The path patterns to match. Patterns sharing a variable are joined.
+    pub patterns: Vec<PathPattern>,
+
+    /// The predicate which have to be fulfilled by the matches.
+    pub predicate: Option<Predicate>,
+
+    /// The returned columns. If empty, all named variables are returned.
+    pub returns: Vec<ReturnItem>,
+
+    /// The maximum number of returned rows.
+    pub limit: Option<usize>,
+}
+
+impl GraphQuery {
+    /// Parses the given query text.
+    pub fn parse(text: &str) -> Result<GraphQuery, GraphQueryParseError> {
+        parse_graph_query(text)
+    }
+
+    /// Returns the names of the variables in order of their first occurrence.
+    pub fn variables(&self) -> Vec<String> {
+        let mut variables: Vec<String> = Vec::new();
+        for pattern in self.patterns.iter() {
+            for variable in pattern.variables() {
+                if !variables.contains(&variable) {
+                    variables.push(variable);
+                }
+            }
+        }
+        variables
+    }
+
+    /// Returns the names of the columns of the result.
+    pub fn columns(&self) -> Vec<String> {
+        if self.returns.is_empty() {
+            return self.variables();
+        }
+        self.returns.iter().map(|item| item.column_name()).collect()
+    }
+}
+
+impl FromStr for GraphQuery {
+    type Err = GraphQueryParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        GraphQuery::parse(s)
+    }
+}
+
+/// A path of alternating node and relation patterns.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathPattern {
+    /// The first node of the path.
+    pub start: NodePattern,
+
+    /// The relations and the nodes following the first node.
+    pub steps: Vec<(RelationPattern, NodePattern)>,
+}
+
+impl PathPattern {
+    /// Returns the names of the variables of the path.
+    pub fn variables(&self) -> Vec<String> {
+        let mut variables = Vec::new();
+        variables.extend(self.start.variable.clone());
+        for (relation, node) in self.steps.iter() {
+            variables.extend(relation.variable.clone());
+            variables.extend(node.variable.clone());
+        }
+        variables
+    }
+
+    /// Returns the nodes of the path.
+    pub fn nodes(&self) -> Vec<&NodePattern> {
+        let mut nodes = vec![&self.start];
+        nodes.extend(self.steps.iter().map(|(_, node)| node));
+        nodes
+    }
+}
+
+/// Matches entity instances.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodePattern {
+    /// The variable the matched entity instance is bound to.
+    pub variable: Option<String>,
+
+    /// The entity type or the component of the matched entity instance.
+    pub label: Option<TypePattern>,
+
+    /// The properties which have to be equal.
+    pub properties: Vec<(String, Value)>,
+}
+
+/// Matches relation instances.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RelationPattern {
+    /// The variable the matched relation instance is bound to.
+    pub variable: Option<String>,
+
+    /// The relation type or the component of the matched relation instance.
+    pub label: Option<TypePattern>,
+
+    /// The properties which have to be equal.
+    pub properties: Vec<(String, Value)>,
+
+    /// The direction of the relation, seen from the preceding node.
+    pub direction: PatternDirection,
+}
+
+/// The direction of a relation pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PatternDirection {
+    /// `(a)-[]->(b)`: From the preceding node to the following node.
+    Outbound,
+    /// `(a)<-[]-(b)`: From the following node to the preceding node.
+    Inbound,
+    /// `(a)-[]-(b)`: Either direction.
+    Both,
+}
+
+impl PatternDirection {
+    /// Returns the direction seen from the following node.
+    pub fn reverse(&self) -> PatternDirection {
+        match self {
+            PatternDirection::Outbound => PatternDirection::Inbound,
+            PatternDirection::Inbound => PatternDirection::Outbound,
+            PatternDirection::Both => PatternDirection::Both,
+        }
+    }
+}
+
+impl Display for PatternDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PatternDirection::Outbound => write!(f, "outbound"),
+            PatternDirection::Inbound => write!(f, "inbound"),
+            PatternDirection::Both => write!(f, "both"),
+        }
+    }
+}
+
+/// The label of a node or relation pattern, like `namespace::sensor` or `sensor`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypePattern {
+    /// The namespace. If missing, the type is resolved by its name.
+    pub namespace: Option<String>,
+
+    /// The name of the type.
+    pub type_name: String,
+}
+
+impl Display for TypePattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.namespace {
+            Some(namespace) => write!(f, "{}::{}", namespace, self.type_name),
+            None => write!(f, "{}", self.type_name),
+        }
+    }
+}
+
+/// A boolean expression over the properties of the bound instances.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Predicate {
+    And(Vec<Predicate>),
+    Or(Vec<Predicate>),
+    Not(Box<Predicate>),
+    /// Applies the property filter on the instance bound to the variable.
+    Condition {
+        variable: String,
+        filter: Box<PropertyFilter>,
+    },
+}
+
+impl Predicate {
+    /// Returns the variables referenced by the predicate.
+    pub fn variables(&self) -> BTreeSet<&str> {
+        match self {
+            Predicate::And(predicates) | Predicate::Or(predicates) => predicates.iter().flat_map(|predicate| predicate.variables()).collect(),
+            Predicate::Not(predicate) => predicate.variables(),
+            Predicate::Condition { variable, .. } => BTreeSet::from([variable.as_str()]),
+        }
+    }
+
+    /// Splits the predicate into its conjuncts.
+    pub fn conjuncts(self) -> Vec<Predicate> {
+        match self {
+            Predicate::And(predicates) => predicates.into_iter().flat_map(|predicate| predicate.conjuncts()).collect(),
+            predicate => vec![predicate],
+        }
+    }
+
+    /// Converts the predicate into a property filter, ignoring the variables.
+    pub fn to_property_filter(&self) -> PropertyFilter {
+        match self {
+            Predicate::And(predicates) => PropertyFilter {
+                and: Some(predicates.iter().map(|predicate| predicate.to_property_filter()).collect()),
+                ..Default::default()
+            },
+            Predicate::Or(predicates) => PropertyFilter {
+                or: Some(predicates.iter().map(|predicate| predicate.to_property_filter()).collect()),
+                ..Default::default()
+            },
+            Predicate::Not(predicate) => PropertyFilter {
+                not: Some(Box::new(predicate.to_property_filter())),
+                ..Default::default()
+            },
+            Predicate::Condition { filter, .. } => filter.as_ref().clone(),
+        }
+    }
+}
+
+/// A returned column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReturnItem {
+    /// The variable.
+    pub variable: String,
+
+    /// The property of the bound instance. If missing, the instance is returned.
+    pub property: Option<String>,
+
+    /// The name of the column.
+    pub alias: Option<String>,
+}
+
+impl ReturnItem {
+    /// Returns the name of the column.
+    pub fn column_name(&self) -> String {
+        if let Some(alias) = &self.alias {
+            return alias.clone();
+        }
+        match &self.property {
+            Some(property) => format!("{}.{}", self.variable, property),
+            None => self.variable.clone(),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum GraphQueryParseError {
+    #[error("Unexpected character '{0}' at position {1}")]
+    UnexpectedCharacter(char, usize),
+    #[error("Unterminated string starting at position {0}")]
+    UnterminatedString(usize),
+    #[error("Invalid number {0} at position {1}")]
+    InvalidNumber(String, usize),
+    #[error("Expected {0} but found {1} at position {2}")]
+    UnexpectedToken(String, String, usize),
+    #[error("Expected {0} but the query ended")]
+    UnexpectedEnd(String),
+    #[error("The variable {0} is not defined in the MATCH clause")]
+    UndefinedVariable(String),
+    #[error("The variable {0} is used for both an entity and a relation")]
+    ConflictingVariable(String),
+    #[error("The filter of the property {0} is invalid: {1}")]
+    InvalidFilter(String, String),
+}
@@ -0,0 +1,656 @@
+use serde_json::Map;
+use serde_json::Number;
+use serde_json::Value;
+
+use crate::GraphQuery;
+use crate::GraphQueryParseError;
+use crate::NodePattern;
+use crate::PathPattern;
+use crate::PatternDirection;
+use crate::Predicate;
+use crate::PropertyFilter;
+use crate::RelationPattern;
+use crate::ReturnItem;
+use crate::TypePattern;
+
+#[derive(Clone, Debug, PartialEq)]
+enum TokenKind {
+    Identifier(String),
+    String(String),
+    Number(Number),
+    LeftParen,
+    RightParen,
+    LeftBracket,
+    RightBracket,
+    LeftBrace,
+    RightBrace,
+    Colon,
+    DoubleColon,
+    Comma,
+    Dot,
+    Minus,
+    Star,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    RegexMatch,
+}
+
+impl TokenKind {
+    fn describe(&self) -> String {
+        match self {
+            TokenKind::Identifier(identifier) => identifier.clone(),
+            TokenKind::String(string) => format!("'{string}'"),
+            TokenKind::Number(number) => number.to_string(),
+            TokenKind::LeftParen => "(".to_string(),
+            TokenKind::RightParen => ")".to_string(),
+            TokenKind::LeftBracket => "[".to_string(),
+            TokenKind::RightBracket => "]".to_string(),
+            TokenKind::LeftBrace => "{".to_string(),
+            TokenKind::RightBrace => "}".to_string(),
+            TokenKind::Colon => ":".to_string(),
+            TokenKind::DoubleColon => "::".to_string(),
+            TokenKind::Comma => ",".to_string(),
+            TokenKind::Dot => ".".to_string(),
+            TokenKind::Minus => "-".to_string(),
+            TokenKind::Star => "*".to_string(),
+            TokenKind::Equal => "=".to_string(),
+            TokenKind::NotEqual => "<>".to_string(),
+            TokenKind::Less => "<".to_string(),
+            TokenKind::LessOrEqual => "<=".to_string(),
+            TokenKind::Greater => ">".to_string(),
+            TokenKind::GreaterOrEqual => ">=".to_string(),
+            TokenKind::RegexMatch => "=~".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Token {
+    kind: TokenKind,
+    position: usize,
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, GraphQueryParseError> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let position = i;
+        let next = chars.get(i + 1).copied();
+        let (kind, length) = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => (TokenKind::LeftParen, 1),
+            ')' => (TokenKind::RightParen, 1),
+            '[' => (TokenKind::LeftBracket, 1),
+            ']' => (TokenKind::RightBracket, 1),
+            '{' => (TokenKind::LeftBrace, 1),
+            '}' => (TokenKind::RightBrace, 1),
+            ',' => (TokenKind::Comma, 1),
+            '.' => (TokenKind::Dot, 1),
+            '-' => (TokenKind::Minus, 1),
+            '*' => (TokenKind::Star, 1),
+            ':' if next == Some(':') => (TokenKind::DoubleColon, 2),
+            ':' => (TokenKind::Colon, 1),
+            '=' if next == Some('~') => (TokenKind::RegexMatch, 2),
+            '=' => (TokenKind::Equal, 1),
+            '!' if next == Some('=') => (TokenKind::NotEqual, 2),
+            '<' if next == Some('>') => (TokenKind::NotEqual, 2),
+            '<' if next == Some('=') => (TokenKind::LessOrEqual, 2),
+            '<' => (TokenKind::Less, 1),
+            '>' if next == Some('=') => (TokenKind::GreaterOrEqual, 2),
+            '>' => (TokenKind::Greater, 1),
+            '\'' | '"' => {
+                let mut string = String::new();
+                let mut j = i + 1;
+                loop {
+                    match chars.get(j) {
+                        None => return Err(GraphQueryParseError::UnterminatedString(position)),
+                        Some('\\') => {
+                            match chars.get(j + 1) {
+                                Some('n') => string.push('\n'),
+                                Some('t') => string.push('\t'),
+                                Some(escaped) => string.push(*escaped),
+                                None => return Err(GraphQueryParseError::UnterminatedString(position)),
+                            }
+                            j += 2;
+                        }
+                        Some(quote) if *quote == c => break,
+                        Some(other) => {
+                            string.push(*other);
+                            j += 1;
+                        }
+                    }
+                }
+                (TokenKind::String(string), j + 1 - i)
+            }
+            '`' => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|c| *c == '`')
+                    .ok_or(GraphQueryParseError::UnterminatedString(position))?;
+                (TokenKind::Identifier(chars[i + 1..i + 1 + end].iter().collect()), end + 2)
+            }
+            c if c.is_ascii_digit() => {
+                let mut j = i;
+                while j < chars.len() && (chars[j].is_ascii_digit() || (chars[j] == '.' && chars.get(j + 1).is_some_and(|c| c.is_ascii_digit()))) {
+                    j += 1;
+                }
+                let literal: String = chars[i..j].iter().collect();
+                let number = if literal.contains('.') {
+                    literal.parse::<f64>().ok().and_then(Number::from_f64)
+                } else {
+                    literal.parse::<i64>().ok().map(Number::from)
+                };
+                let number = number.ok_or_else(|| GraphQueryParseError::InvalidNumber(literal.clone(), position))?;
+                (TokenKind::Number(number), j - i)
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut j = i;
+                while j < chars.len() && (chars[j].is_alphanumeric() || chars[j] == '_') {
+                    j += 1;
+                }
+                (TokenKind::Identifier(chars[i..j].iter().collect()), j - i)
+            }
+            c => return Err(GraphQueryParseError::UnexpectedCharacter(c, position)),
+        };
+        tokens.push(Token { kind, position });
+        i += length;
+    }
+    Ok(tokens)
+}
+
+/// Parses the text of a graph query.
+pub fn parse_graph_query(text: &str) -> Result<GraphQuery, GraphQueryParseError> {
+    let mut parser = GraphQueryParser {
+        tokens: tokenize(text)?,
+        index: 0,
+    };
+    let query = parser.query()?;
+    validate_variables(&query)?;
+    Ok(query)
+}
+
+struct GraphQueryParser {
+    tokens: Vec<Token>,
+    index: usize,
+}
+
+impl GraphQueryParser {
+    fn query(&mut self) -> Result<GraphQuery, GraphQueryParseError> {
+        self.expect_keyword("MATCH")?;
+        let mut patterns = vec![self.path_pattern()?];
+        while self.consume(&TokenKind::Comma) {
+            patterns.push(self.path_pattern()?);
+        }
+        let predicate = if self.consume_keyword("WHERE") { Some(self.or()?) } else { None };
+        self.expect_keyword("RETURN")?;
+        let mut returns = Vec::new();
+        if !self.consume(&TokenKind::Star) {
+            returns.push(self.return_item()?);
+            while self.consume(&TokenKind::Comma) {
+                returns.push(self.return_item()?);
+            }
+        }
+        let limit = if self.consume_keyword("LIMIT") {
+            match self.next("a number")? {
+                Token {
+                    kind: TokenKind::Number(number),
+                    ..
+                } if number.is_u64() => number.as_u64().map(|limit| limit as usize),
+                token => return Err(self.unexpected("a positive integer", &token)),
+            }
+        } else {
+            None
+        };
+        if let Some(token) = self.tokens.get(self.index).cloned() {
+            return Err(self.unexpected("the end of the query", &token));
+        }
+        Ok(GraphQuery {
+            patterns,
+            predicate,
+            returns,
+            limit,
+        })
+    }
+
+    fn path_pattern(&mut self) -> Result<PathPattern, GraphQueryParseError> {
+        let start = self.node_pattern()?;
+        let mut steps = Vec::new();
+        while self.peek_is(&TokenKind::Minus) || self.peek_is(&TokenKind::Less) {
+            let relation = self.relation_pattern()?;
+            let node = self.node_pattern()?;
+            steps.push((relation, node));
+        }
+        Ok(PathPattern { start, steps })
+    }
+
+    fn node_pattern(&mut self) -> Result<NodePattern, GraphQueryParseError> {
+        self.expect(&TokenKind::LeftParen)?;
+        let variable = self.optional_identifier();
+        let label = if self.consume(&TokenKind::Colon) { Some(self.type_pattern()?) } else { None };
+        let properties = if self.peek_is(&TokenKind::LeftBrace) {
+            self.properties()?
+        } else {
+            Vec::new()
+        };
+        self.expect(&TokenKind::RightParen)?;
+        Ok(NodePattern { variable, label, properties })
+    }
+
+    fn relation_pattern(&mut self) -> Result<RelationPattern, GraphQueryParseError> {
+        let inbound = self.consume(&TokenKind::Less);
+        self.expect(&TokenKind::Minus)?;
+        let mut relation = RelationPattern {
+            variable: None,
+            label: None,
+            properties: Vec::new(),
+            direction: PatternDirection::Both,
+        };
+        if self.consume(&TokenKind::LeftBracket) {
+            relation.variable = self.optional_identifier();
+            if self.consume(&TokenKind::Colon) {
+                relation.label = Some(self.type_pattern()?);
+            }
+            if self.peek_is(&TokenKind::LeftBrace) {
+                relation.properties = self.properties()?;
+            }
+            self.expect(&TokenKind::RightBracket)?;
+        }
+        self.expect(&TokenKind::Minus)?;
+        let outbound = self.peek_is(&TokenKind::Greater);
+        relation.direction = match (inbound, outbound) {
+            (true, true) => {
+                let token = self.tokens[self.index].clone();
+                return Err(self.unexpected("(", &token));
+            }
+            (true, false) => PatternDirection::Inbound,
+            (false, true) => {
+                self.index += 1;
+                PatternDirection::Outbound
+            }
+            (false, false) => PatternDirection::Both,
+        };
+        Ok(relation)
+    }
+
+    fn type_pattern(&mut self) -> Result<TypePattern, GraphQueryParseError> {
+        let mut segments = vec![self.identifier()?];
+        while self.consume(&TokenKind::DoubleColon) {
+            segments.push(self.identifier()?);
+        }
+        let type_name = segments.pop().unwrap_or_default();
+        let namespace = if segments.is_empty() { None } else { Some(segments.join("::")) };
+        Ok(TypePattern { namespace, type_name })
+    }
+
+    fn properties(&mut self) -> Result<Vec<(String, Value)>, GraphQueryParseError> {
+        self.expect(&TokenKind::LeftBrace)?;
+        let mut properties = Vec::new();
+        if !self.consume(&TokenKind::RightBrace) {
+            loop {
+                let name = self.identifier()?;
+                self.expect(&TokenKind::Colon)?;
+                properties.push((name, self.literal()?));
+                if !self.consume(&TokenKind::Comma) {
+                    break;
+                }
+            }
+            self.expect(&TokenKind::RightBrace)?;
+        }
+        Ok(properties)
+    }
+
+    fn or(&mut self) -> Result<Predicate, GraphQueryParseError> {
+        let mut predicates = vec![self.and()?];
+        while self.consume_keyword("OR") {
+            predicates.push(self.and()?);
+        }
+        Ok(if predicates.len() == 1 {
+            predicates.remove(0)
+        } else {
+            Predicate::Or(predicates)
+        })
+    }
+
+    fn and(&mut self) -> Result<Predicate, GraphQueryParseError> {
+        let mut predicates = vec![self.not()?];
+        while self.consume_keyword("AND") {
+            predicates.push(self.not()?);
+        }
+        Ok(if predicates.len() == 1 {
+            predicates.remove(0)
+        } else {
+            Predicate::And(predicates)
+        })
+    }
+
+    fn not(&mut self) -> Result<Predicate, GraphQueryParseError> {
+        if self.consume_keyword("NOT") {
+            return Ok(Predicate::Not(Box::new(self.not()?)));
+        }
+        if self.consume(&TokenKind::LeftParen) {
+            let predicate = self.or()?;
+            self.expect(&TokenKind::RightParen)?;
+            return Ok(predicate);
+        }
+        self.condition()
+    }
+
+    fn condition(&mut self) -> Result<Predicate, GraphQueryParseError> {
+        let variable = self.identifier()?;
+        self.expect(&TokenKind::Dot)?;
+        let name = self.identifier()?;
+        let mut path = Vec::new();
+        while self.consume(&TokenKind::Dot) {
+            path.push(self.identifier()?);
+        }
+        let mut filter = PropertyFilter {
+            name: Some(name.clone()),
+            path: if path.is_empty() { None } else { Some(format!("$.{}", path.join("."))) },
+            ..Default::default()
+        };
+        let token = self.next("a comparison operator")?;
+        match &token.kind {
+            TokenKind::Equal => filter.eq = Some(self.literal()?),
+            TokenKind::NotEqual => filter.ne = Some(self.literal()?),
+            TokenKind::Less => filter.lt = Some(self.literal()?),
+            TokenKind::LessOrEqual => filter.lte = Some(self.literal()?),
+            TokenKind::Greater => filter.gt = Some(self.literal()?),
+            TokenKind::GreaterOrEqual => filter.gte = Some(self.literal()?),
+            TokenKind::RegexMatch => filter.regex = Some(self.string()?),
+            TokenKind::Identifier(keyword) if keyword.eq_ignore_ascii_case("CONTAINS") => filter.contains = Some(self.literal()?),
+            TokenKind::Identifier(keyword) if keyword.eq_ignore_ascii_case("STARTS") => {
+                self.expect_keyword("WITH")?;
+                filter.starts_with = Some(self.string()?);
+            }
+            TokenKind::Identifier(keyword) if keyword.eq_ignore_ascii_case("ENDS") => {
+                self.expect_keyword("WITH")?;
+                filter.ends_with = Some(self.string()?);
+            }
+            TokenKind::Identifier(keyword) if keyword.eq_ignore_ascii_case("IN") => match self.literal()? {
+                Value::Array(values) => filter.in_values = Some(values),
+                _ => return Err(self.unexpected("a list", &token)),
+            },
+            TokenKind::Identifier(keyword) if keyword.eq_ignore_ascii_case("IS") => {
+                let not = self.consume_keyword("NOT");
+                self.expect_keyword("NULL")?;
+                filter.exists = Some(not);
+            }
+            _ => return Err(self.unexpected("a comparison operator", &token)),
+        }
+        filter.compile().map_err(|e| GraphQueryParseError::InvalidFilter(name, e.to_string()))?;
+        Ok(Predicate::Condition {
+            variable,
+            filter: Box::new(filter),
+        })
+    }
+
+    fn return_item(&mut self) -> Result<ReturnItem, GraphQueryParseError> {
+        let variable = self.identifier()?;
+        let property = if self.consume(&TokenKind::Dot) { Some(self.identifier()?) } else { None };
+        let alias = if self.consume_keyword("AS") { Some(self.identifier()?) } else { None };
+        Ok(ReturnItem { variable, property, alias })
+    }
+
+    fn literal(&mut self) -> Result<Value, GraphQueryParseError> {
+        let token = self.next("a value")?;
+        match token.kind {
+            TokenKind::String(string) => Ok(Value::String(string)),
+            TokenKind::Number(number) => Ok(Value::Number(number)),
+            TokenKind::Minus => match self.next("a number")? {
+                Token {
+                    kind: TokenKind::Number(number),
+                    ..
+                } => Ok(match number.as_i64() {
+                    Some(number) => Value::from(-number),
+                    None => Value::from(-number.as_f64().unwrap_or_default()),
+                }),
+                token => Err(self.unexpected("a number", &token)),
+            },
+            TokenKind::Identifier(keyword) if keyword.eq_ignore_ascii_case("true") => Ok(Value::Bool(true)),
+            TokenKind::Identifier(keyword) if keyword.eq_ignore_ascii_case("false") => Ok(Value::Bool(false)),
+            TokenKind::Identifier(keyword) if keyword.eq_ignore_ascii_case("null") => Ok(Value::Null),
+            TokenKind::LeftBracket => {
+                let mut values = Vec::new();
+                if !self.consume(&TokenKind::RightBracket) {
+                    loop {
+                        values.push(self.literal()?);
+                        if !self.consume(&TokenKind::Comma) {
+                            break;
+                        }
+                    }
+                    self.expect(&TokenKind::RightBracket)?;
+                }
+                Ok(Value::Array(values))
+            }
+            TokenKind::LeftBrace => {
+                self.index -= 1;
+                Ok(Value::Object(Map::from_iter(self.properties()?)))
+            }
+            _ => Err(self.unexpected("a value", &token)),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, GraphQueryParseError> {
+        match self.next("a string")? {
+            Token {
+                kind: TokenKind::String(string),
+                ..
+            } => Ok(string),
+            token => Err(self.unexpected("a string", &token)),
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, GraphQueryParseError> {
+        match self.next("an identifier")? {
+            Token {
+                kind: TokenKind::Identifier(identifier),
+                ..
+            } => Ok(identifier),
+            token => Err(self.unexpected("an identifier", &token)),
+        }
+    }
+
+    fn optional_identifier(&mut self) -> Option<String> {
+        match self.tokens.get(self.index) {
+            Some(Token {
+                kind: TokenKind::Identifier(identifier),
+                ..
+            }) => {
+                self.index += 1;
+                Some(identifier.clone())
+            }
+            _ => None,
+        }
+    }
+
+    fn next(&mut self, expected: &str) -> Result<Token, GraphQueryParseError> {
+        let token = self
+            .tokens
+            .get(self.index)
+            .cloned()
+            .ok_or_else(|| GraphQueryParseError::UnexpectedEnd(expected.to_string()))?;
+        self.index += 1;
+        Ok(token)
+    }
+
+    fn peek_is(&self, kind: &TokenKind) -> bool {
+        self.tokens.get(self.index).is_some_and(|token| &token.kind == kind)
+    }
+
+    fn consume(&mut self, kind: &TokenKind) -> bool {
+        if self.peek_is(kind) {
+            self.index += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, kind: &TokenKind) -> Result<(), GraphQueryParseError> {
+        let token = self.next(&kind.describe())?;
+        if &token.kind != kind {
+            return Err(self.unexpected(&kind.describe(), &token));
+        }
+        Ok(())
+    }
+
+    fn consume_keyword(&mut self, keyword: &str) -> bool {
+        match self.tokens.get(self.index) {
+            Some(Token {
+                kind: TokenKind::Identifier(identifier),
+                ..
+            }) if identifier.eq_ignore_ascii_case(keyword) => {
+                self.index += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), GraphQueryParseError> {
+        if self.consume_keyword(keyword) {
+            return Ok(());
+        }
+        match self.tokens.get(self.index).cloned() {
+            Some(token) => Err(self.unexpected(keyword, &token)),
+            None => Err(GraphQueryParseError::UnexpectedEnd(keyword.to_string())),
+        }
+    }
+
+    fn unexpected(&self, expected: &str, token: &Token) -> GraphQueryParseError {
+        GraphQueryParseError::UnexpectedToken(expected.to_string(), token.kind.describe(), token.position)
+    }
+}
+
+fn validate_variables(query: &GraphQuery) -> Result<(), GraphQueryParseError> {
+    let mut nodes = Vec::new();
+    let mut relations = Vec::new();
+    for pattern in query.patterns.iter() {
+        nodes.extend(pattern.nodes().into_iter().filter_map(|node| node.variable.clone()));
+        relations.extend(pattern.steps.iter().filter_map(|(relation, _)| relation.variable.clone()));
+    }
+    if let Some(variable) = nodes.iter().find(|variable| relations.contains(variable)) {
+        return Err(GraphQueryParseError::ConflictingVariable(variable.clone()));
+    }
+    let is_defined = |variable: &str| nodes.iter().chain(relations.iter()).any(|defined| defined == variable);
+    if let Some(predicate) = &query.predicate {
+        if let Some(variable) = predicate.variables().into_iter().find(|variable| !is_defined(variable)) {
+            return Err(GraphQueryParseError::UndefinedVariable(variable.to_string()));
+        }
+    }
+    if let Some(item) = query.returns.iter().find(|item| !is_defined(&item.variable)) {
+        return Err(GraphQueryParseError::UndefinedVariable(item.variable.clone()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::GraphQuery;
+    use crate::GraphQueryParseError;
+    use crate::PatternDirection;
+    use crate::Predicate;
+    use crate::PropertyFilter;
+    use crate::TypePattern;
+
+    #[test]
+    fn parse_graph_query_test() {
+        let query = GraphQuery::parse("MATCH (a:namespace::sensor)-[:connects]->(b) WHERE a.value > 10 RETURN b").expect("Failed to parse query");
+        assert_eq!(1, query.patterns.len());
+        let pattern = &query.patterns[0];
+        assert_eq!(Some("a".to_string()), pattern.start.variable);
+        assert_eq!(
+            Some(TypePattern {
+                namespace: Some("namespace".to_string()),
+                type_name: "sensor".to_string()
+            }),
+            pattern.start.label
+        );
+        let (relation, node) = &pattern.steps[0];
+        assert_eq!(None, relation.variable);
+        assert_eq!(PatternDirection::Outbound, relation.direction);
+        assert_eq!(
+            Some(TypePattern {
+                namespace: None,
+                type_name: "connects".to_string()
+            }),
+            relation.label
+        );
+        assert_eq!(Some("b".to_string()), node.variable);
+        assert_eq!(
+            Some(Predicate::Condition {
+                variable: "a".to_string(),
+                filter: Box::new(PropertyFilter {
+                    name: Some("value".to_string()),
+                    gt: Some(json!(10)),
+                    ..Default::default()
+                })
+            }),
+            query.predicate
+        );
+        assert_eq!(vec!["b".to_string()], query.columns());
+    }
+
+    #[test]
+    fn parse_directions_test() {
+        let query = GraphQuery::parse("match (a)<-[r]-(b)--(c)-->(d)<--(e) return *").expect("Failed to parse query");
+        let directions: Vec<PatternDirection> = query.patterns[0].steps.iter().map(|(relation, _)| relation.direction).collect();
+        assert_eq!(
+            vec![
+                PatternDirection::Inbound,
+                PatternDirection::Both,
+                PatternDirection::Outbound,
+                PatternDirection::Inbound
+            ],
+            directions
+        );
+        assert_eq!(vec!["a", "r", "b", "c", "d", "e"], query.columns());
+    }
+
+    #[test]
+    fn parse_predicate_test() {
+        let query = GraphQuery::parse(
+            "MATCH (a {name: 'x', tags: [1, 2]}), (b) WHERE NOT (a.value <= -1.5 OR a.name STARTS WITH \"s\") AND b.label IS NOT NULL RETURN a.name AS name, b LIMIT 5",
+        )
+        .expect("Failed to parse query");
+        assert_eq!(
+            vec![("name".to_string(), json!("x")), ("tags".to_string(), json!([1, 2]))],
+            query.patterns[0].start.properties
+        );
+        let Some(Predicate::And(conjuncts)) = &query.predicate else {
+            panic!("Expected a conjunction");
+        };
+        assert!(matches!(&conjuncts[0], Predicate::Not(predicate) if matches!(predicate.as_ref(), Predicate::Or(_))));
+        assert_eq!(Some(5), query.limit);
+        assert_eq!(vec!["name", "b"], query.columns());
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        assert_eq!(Err(GraphQueryParseError::UnexpectedEnd("RETURN".to_string())), GraphQuery::parse("MATCH (a)"));
+        assert_eq!(Err(GraphQueryParseError::UndefinedVariable("b".to_string())), GraphQuery::parse("MATCH (a) RETURN b"));
+        assert_eq!(
+            Err(GraphQueryParseError::ConflictingVariable("a".to_string())),
+            GraphQuery::parse("MATCH (a)-[a]->(b) RETURN b")
+        );
+        assert_eq!(Err(GraphQueryParseError::UnterminatedString(25)), GraphQuery::parse("MATCH (a) WHERE a.name = 'x RETURN a"));
+        assert!(matches!(
+            GraphQuery::parse("MATCH (a) WHERE a.name =~ '(' RETURN a"),
+            Err(GraphQueryParseError::InvalidFilter(_, _))
+        ));
+        assert!(matches!(
+            GraphQuery::parse("MATCH (a)<-[]->(b) RETURN a"),
+            Err(GraphQueryParseError::UnexpectedToken(_, _, _))
+        ));
+    }
+}
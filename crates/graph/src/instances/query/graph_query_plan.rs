@@ -0,0 +1,140 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ComponentTypeId;
+use crate::EntityTypeId;
+use crate::PatternDirection;
+use crate::Predicate;
+use crate::PropertyFilter;
+use crate::RelationTypeId;
+use crate::ReturnItem;
+
+/// The resolved label of a node or relation pattern.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InstanceLabel {
+    /// Matches entity instances of the entity type.
+    EntityType(EntityTypeId),
+    /// Matches relation instances of the relation type.
+    RelationType(RelationTypeId),
+    /// Matches instances having the component.
+    Component(ComponentTypeId),
+}
+
+impl Display for InstanceLabel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InstanceLabel::EntityType(ty) => write!(f, "entity type {ty}"),
+            InstanceLabel::RelationType(ty) => write!(f, "relation type {ty}"),
+            InstanceLabel::Component(ty) => write!(f, "component {ty}"),
+        }
+    }
+}
+
+/// A step of the execution plan of a graph query.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GraphQueryPlanStep {
+    /// Binds the matching entity instances to the variable. If the variable is already bound,
+    /// the bound entity instance is checked instead.
+    Scan {
+        variable: String,
+        label: Option<InstanceLabel>,
+        filter: Option<Box<PropertyFilter>>,
+        /// True, if the filter can be answered by a property index.
+        indexed: bool,
+        /// The estimated number of matching entity instances.
+        estimated_rows: usize,
+    },
+    /// Follows the relations of the entity instance bound to `from` and binds the relation
+    /// instance and the entity instance on the other end.
+    Expand {
+        from: String,
+        relation: String,
+        relation_label: Option<InstanceLabel>,
+        relation_filter: Option<Box<PropertyFilter>>,
+        direction: PatternDirection,
+        to: String,
+        to_label: Option<InstanceLabel>,
+        to_filter: Option<Box<PropertyFilter>>,
+    },
+    /// Removes the matches which doesn't fulfill the predicate.
+    Filter(Predicate),
+}
+
+impl Display for GraphQueryPlanStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let filter = |filter: &Option<Box<PropertyFilter>>| match filter.as_ref().and_then(|filter| serde_json::to_string(filter).ok()) {
+            Some(filter) => format!(" where {filter}"),
+            None => String::new(),
+        };
+        let label = |label: &Option<InstanceLabel>| match label {
+            Some(label) => format!(" of {label}"),
+            None => String::new(),
+        };
+        match self {
+            GraphQueryPlanStep::Scan {
+                variable,
+                label: node_label,
+                filter: node_filter,
+                indexed,
+                estimated_rows,
+            } => {
+                write!(f, "Scan ({variable}){}{}", label(node_label), filter(node_filter))?;
+                if *indexed {
+                    write!(f, " using index")?;
+                }
+                write!(f, " (estimated rows: {estimated_rows})")
+            }
+            GraphQueryPlanStep::Expand {
+                from,
+                relation,
+                relation_label,
+                relation_filter,
+                direction,
+                to,
+                to_label,
+                to_filter,
+            } => write!(
+                f,
+                "Expand ({from}) {direction} [{relation}{}{}] to ({to}){}{}",
+                label(relation_label),
+                filter(relation_filter),
+                label(to_label),
+                filter(to_filter)
+            ),
+            GraphQueryPlanStep::Filter(predicate) => write!(f, "Filter {:?}", predicate.variables()),
+        }
+    }
+}
+
+/// The execution plan of a graph query.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphQueryPlan {
+    /// The steps in order of execution.
+    pub steps: Vec<GraphQueryPlanStep>,
+
+    /// The returned columns.
+    pub returns: Vec<ReturnItem>,
+
+    /// The maximum number of returned rows.
+    pub limit: Option<usize>,
+}
+
+impl GraphQueryPlan {
+    /// Returns the names of the columns of the result.
+    pub fn columns(&self) -> Vec<String> {
+        self.returns.iter().map(|item| item.column_name()).collect()
+    }
+}
+
+impl Display for GraphQueryPlan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, step) in self.steps.iter().enumerate() {
+            writeln!(f, "{}. {step}", i + 1)?;
+        }
+        write!(f, "{}. Return {}", self.steps.len() + 1, self.columns().join(", "))?;
+        if let Some(limit) = self.limit {
+            write!(f, " limit {limit}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The rows matched by a graph query.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GraphQueryResult {
+    /// The names of the columns.
+    pub columns: Vec<String>,
+
+    /// The values of the rows. Entity and relation instances are returned as JSON objects.
+    pub rows: Vec<Vec<Value>>,
+}
+
+impl GraphQueryResult {
+    pub fn new(columns: Vec<String>, rows: Vec<Vec<Value>>) -> Self {
+        GraphQueryResult { columns, rows }
+    }
+
+    /// Returns the rows as JSON objects keyed by the column names.
+    pub fn to_objects(&self) -> Vec<Value> {
+        self.rows
+            .iter()
+            .map(|row| Value::Object(self.columns.iter().cloned().zip(row.iter().cloned()).collect()))
+            .collect()
+    }
+}
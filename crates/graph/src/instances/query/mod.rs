@@ -0,0 +1,11 @@
+pub use graph_query::*;
+pub use graph_query_errors::*;
+pub use graph_query_parser::*;
+pub use graph_query_plan::*;
+pub use graph_query_result::*;
+
+pub mod graph_query;
+pub mod graph_query_errors;
+pub mod graph_query_parser;
+pub mod graph_query_plan;
+pub mod graph_query_result;
@@ -0,0 +1,7 @@
+pub use saved_query::*;
+pub use saved_query_change::*;
+pub use saved_query_result_set::*;
+
+pub mod saved_query;
+pub mod saved_query_change;
+pub mod saved_query_result_set;
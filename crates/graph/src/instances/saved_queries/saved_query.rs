@@ -0,0 +1,64 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use typed_builder::TypedBuilder;
+
+use crate::EntityTypeId;
+use crate::PropertyFilter;
+use crate::PropertySort;
+
+/// A named query over the entity instances whose result set is materialized and kept up to date.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema, TypedBuilder)]
+pub struct SavedQuery {
+    /// The unique name of the saved query.
+    #[builder(setter(into))]
+    pub name: String,
+
+    /// Textual description of the saved query.
+    #[builder(default, setter(into))]
+    #[serde(default)]
+    pub description: String,
+
+    /// Only entity instances of this type are included. If not set, all entity instances are
+    /// candidates.
+    #[builder(default, setter(strip_option))]
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub ty: Option<EntityTypeId>,
+
+    /// Only entity instances matching the filter are included.
+    #[builder(default, setter(strip_option))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<PropertyFilter>,
+
+    /// The order of the results. The results are ordered by id last.
+    #[builder(default)]
+    #[serde(default)]
+    pub sort: Vec<PropertySort>,
+
+    /// The properties which are included in the results. If empty, all properties are included.
+    #[builder(default)]
+    #[serde(default)]
+    pub projection: Vec<String>,
+}
+
+impl SavedQuery {
+    /// Returns true, if entity instances of the given type are candidates of the saved query.
+    pub fn applies_to(&self, ty: &EntityTypeId) -> bool {
+        self.ty.as_ref().map(|query_ty| query_ty == ty).unwrap_or(true)
+    }
+
+    /// Returns the properties which are included in the results.
+    pub fn project(&self, properties: &BTreeMap<String, Value>) -> BTreeMap<String, Value> {
+        if self.projection.is_empty() {
+            return properties.clone();
+        }
+        properties
+            .iter()
+            .filter(|(name, _)| self.projection.contains(name))
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect()
+    }
+}
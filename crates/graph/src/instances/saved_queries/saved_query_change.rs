@@ -0,0 +1,47 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+/// An entity instance in the result set of a saved query.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SavedQueryRow {
+    /// The id of the entity instance.
+    pub id: Uuid,
+
+    /// The projected properties of the entity instance.
+    pub properties: BTreeMap<String, Value>,
+}
+
+/// A change of the result set of a saved query.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", content = "row", rename_all = "UPPERCASE")]
+pub enum SavedQueryChange {
+    /// The entity instance has been added to the result set.
+    Added(SavedQueryRow),
+    /// The projected properties of the entity instance have been changed.
+    Changed(SavedQueryRow),
+    /// The entity instance with the given id has been removed from the result set.
+    Removed(Uuid),
+}
+
+impl SavedQueryChange {
+    /// Returns the id of the entity instance.
+    pub fn id(&self) -> Uuid {
+        match self {
+            SavedQueryChange::Added(row) | SavedQueryChange::Changed(row) => row.id,
+            SavedQueryChange::Removed(id) => *id,
+        }
+    }
+
+    /// Returns the projected properties of the added or changed entity instance.
+    pub fn properties(&self) -> Option<&BTreeMap<String, Value>> {
+        match self {
+            SavedQueryChange::Added(row) | SavedQueryChange::Changed(row) => Some(&row.properties),
+            SavedQueryChange::Removed(_) => None,
+        }
+    }
+}
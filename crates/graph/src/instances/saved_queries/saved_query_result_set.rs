@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::PropertyFilterError;
+use crate::PropertyFilterExpression;
+use crate::PropertyInstances;
+use crate::SavedQuery;
+use crate::SavedQueryChange;
+use crate::SavedQueryRow;
+use crate::compare_by_properties;
+
+/// The materialized result set of a saved query.
+///
+/// The result set is updated incrementally with the properties of single entity instances and
+/// returns the resulting change, if any.
+#[derive(Clone, Debug)]
+pub struct SavedQueryResultSet {
+    saved_query: SavedQuery,
+    filter: Option<PropertyFilterExpression>,
+    /// The properties of the matching entity instances.
+    rows: HashMap<Uuid, BTreeMap<String, Value>>,
+}
+
+impl SavedQueryResultSet {
+    /// Creates an empty result set for the saved query. Fails if the filter is invalid.
+    pub fn new(saved_query: SavedQuery) -> Result<Self, PropertyFilterError> {
+        let filter = match &saved_query.filter {
+            Some(filter) => Some(filter.compile()?),
+            None => None,
+        };
+        Ok(SavedQueryResultSet {
+            saved_query,
+            filter,
+            rows: HashMap::new(),
+        })
+    }
+
+    /// Returns the saved query.
+    pub fn saved_query(&self) -> &SavedQuery {
+        &self.saved_query
+    }
+
+    /// Updates the entity instance with the given id. If the properties are none, the entity
+    /// instance has been deleted. Returns the change of the result set.
+    pub fn update(&mut self, id: Uuid, properties: Option<BTreeMap<String, Value>>) -> Option<SavedQueryChange> {
+        let properties = properties.filter(|properties| self.matches(properties));
+        match (self.rows.get(&id), properties) {
+            (None, None) => None,
+            (None, Some(properties)) => {
+                let row = self.row(id, &properties);
+                self.rows.insert(id, properties);
+                Some(SavedQueryChange::Added(row))
+            }
+            (Some(_), None) => {
+                self.rows.remove(&id);
+                Some(SavedQueryChange::Removed(id))
+            }
+            (Some(previous), Some(properties)) => {
+                let changed = self.saved_query.project(previous) != self.saved_query.project(&properties);
+                let row = self.row(id, &properties);
+                self.rows.insert(id, properties);
+                changed.then_some(SavedQueryChange::Changed(row))
+            }
+        }
+    }
+
+    /// Removes the entity instance with the given id.
+    pub fn remove(&mut self, id: Uuid) -> Option<SavedQueryChange> {
+        self.update(id, None)
+    }
+
+    /// Returns true, if the entity instance with the given id is in the result set.
+    pub fn contains(&self, id: &Uuid) -> bool {
+        self.rows.contains_key(id)
+    }
+
+    /// Returns the number of entity instances in the result set.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns true, if the result set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns the ids of the entity instances in the result set.
+    pub fn ids(&self) -> Vec<Uuid> {
+        self.rows.keys().cloned().collect()
+    }
+
+    /// Returns the ordered rows with the projected properties.
+    pub fn rows(&self) -> Vec<SavedQueryRow> {
+        let mut rows: Vec<(Uuid, PropertyInstances)> = self
+            .rows
+            .iter()
+            .map(|(id, properties)| (*id, PropertyInstances::from(properties.clone())))
+            .collect();
+        rows.sort_by(|(id_a, a), (id_b, b)| compare_by_properties(a, b, &self.saved_query.sort).then_with(|| id_a.cmp(id_b)));
+        rows.into_iter().map(|(id, properties)| self.row(id, &properties.to_map())).collect()
+    }
+
+    fn matches(&self, properties: &BTreeMap<String, Value>) -> bool {
+        match &self.filter {
+            Some(filter) => filter.matches(&PropertyInstances::from(properties.clone())),
+            None => true,
+        }
+    }
+
+    fn row(&self, id: Uuid, properties: &BTreeMap<String, Value>) -> SavedQueryRow {
+        SavedQueryRow {
+            id,
+            properties: self.saved_query.project(properties),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::Value;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use crate::PropertyFilter;
+    use crate::PropertySort;
+    use crate::SavedQuery;
+    use crate::SavedQueryChange;
+    use crate::SavedQueryResultSet;
+    use crate::SortDirection;
+
+    fn properties(name: &str, power: i64) -> Option<BTreeMap<String, Value>> {
+        Some(BTreeMap::from([
+            ("name".to_string(), json!(name)),
+            ("power".to_string(), json!(power)),
+            ("online".to_string(), json!(true)),
+        ]))
+    }
+
+    fn saved_query() -> SavedQuery {
+        SavedQuery::builder()
+            .name("online_devices")
+            .filter(PropertyFilter {
+                name: Some("power".to_string()),
+                gt: Some(json!(10)),
+                ..Default::default()
+            })
+            .sort(vec![PropertySort::new("power", SortDirection::Descending)])
+            .projection(vec!["name".to_string(), "power".to_string()])
+            .build()
+    }
+
+    #[test]
+    fn saved_query_result_set_changes_test() {
+        let mut result_set = SavedQueryResultSet::new(saved_query()).unwrap();
+        let id = Uuid::new_v4();
+
+        // Doesn't match the filter
+        assert_eq!(None, result_set.update(id, properties("lamp", 5)));
+        assert!(result_set.is_empty());
+
+        // Matches the filter
+        let change = result_set.update(id, properties("lamp", 20)).unwrap();
+        assert!(matches!(change, SavedQueryChange::Added(_)));
+        assert_eq!(id, change.id());
+        assert_eq!(Some(&json!(20)), change.properties().unwrap().get("power"));
+        assert_eq!(None, change.properties().unwrap().get("online"));
+        assert!(result_set.contains(&id));
+
+        // A projected property has been changed
+        let change = result_set.update(id, properties("lamp", 30)).unwrap();
+        assert!(matches!(change, SavedQueryChange::Changed(_)));
+
+        // A property which is not projected has been changed
+        let mut not_projected = properties("lamp", 30).unwrap();
+        not_projected.insert("online".to_string(), json!(false));
+        assert_eq!(None, result_set.update(id, Some(not_projected)));
+
+        // Doesn't match the filter anymore
+        assert_eq!(Some(SavedQueryChange::Removed(id)), result_set.update(id, properties("lamp", 1)));
+        assert!(result_set.is_empty());
+
+        // Deleted instances which are not in the result set are ignored
+        assert_eq!(None, result_set.remove(id));
+    }
+
+    #[test]
+    fn saved_query_result_set_rows_test() {
+        let mut result_set = SavedQueryResultSet::new(saved_query()).unwrap();
+        let id_1 = Uuid::new_v4();
+        let id_2 = Uuid::new_v4();
+        let id_3 = Uuid::new_v4();
+        result_set.update(id_1, properties("lamp", 20));
+        result_set.update(id_2, properties("heater", 2000));
+        result_set.update(id_3, properties("fridge", 150));
+        let ids: Vec<Uuid> = result_set.rows().iter().map(|row| row.id).collect();
+        assert_eq!(vec![id_2, id_3, id_1], ids);
+        assert_eq!(Some(SavedQueryChange::Removed(id_3)), result_set.remove(id_3));
+        assert_eq!(2, result_set.len());
+    }
+
+    #[test]
+    fn saved_query_invalid_filter_test() {
+        let saved_query = SavedQuery::builder()
+            .name("invalid")
+            .filter(PropertyFilter {
+                path: Some("$.x".to_string()),
+                ..Default::default()
+            })
+            .build();
+        assert!(SavedQueryResultSet::new(saved_query).is_err());
+    }
+}
@@ -0,0 +1,11 @@
+pub use search_field::*;
+pub use search_hit::*;
+pub use search_index::*;
+pub use search_options::*;
+pub use search_tokenizer::*;
+
+pub mod search_field;
+pub mod search_hit;
+pub mod search_index;
+pub mod search_options;
+pub mod search_tokenizer;
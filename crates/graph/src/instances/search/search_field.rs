@@ -0,0 +1,54 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The field of an instance which is indexed by the full-text search.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", content = "property", rename_all = "UPPERCASE")]
+pub enum SearchField {
+    /// The name of the instance.
+    Name,
+    /// The label of the instance.
+    Label,
+    /// The description of the instance.
+    Description,
+    /// A string property of the instance.
+    Property(String),
+}
+
+impl SearchField {
+    /// Returns the weight of a match in this field.
+    pub fn boost(&self) -> f64 {
+        match self {
+            SearchField::Name => 3.0,
+            SearchField::Label => 2.0,
+            SearchField::Description => 1.0,
+            SearchField::Property(_) => 1.0,
+        }
+    }
+}
+
+impl Display for SearchField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchField::Name => write!(f, "name"),
+            SearchField::Label => write!(f, "label"),
+            SearchField::Description => write!(f, "description"),
+            SearchField::Property(property_name) => write!(f, "{property_name}"),
+        }
+    }
+}
+
+impl From<&str> for SearchField {
+    fn from(field: &str) -> Self {
+        match field {
+            "name" => SearchField::Name,
+            "label" => SearchField::Label,
+            "description" => SearchField::Description,
+            property_name => SearchField::Property(property_name.to_string()),
+        }
+    }
+}
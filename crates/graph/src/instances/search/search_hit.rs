@@ -0,0 +1,27 @@
+use crate::SearchField;
+
+/// An instance which matches a full-text search.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchHit<T> {
+    /// The matched instance.
+    pub instance: T,
+    /// The relevance of the match. Higher is better.
+    pub score: f64,
+    /// The fields which contain matching tokens.
+    pub fields: Vec<SearchField>,
+}
+
+impl<T> SearchHit<T> {
+    pub fn new(instance: T, score: f64, fields: Vec<SearchField>) -> Self {
+        SearchHit { instance, score, fields }
+    }
+
+    /// Replaces the matched instance, for example an id with the instance itself.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> SearchHit<U> {
+        SearchHit {
+            instance: f(self.instance),
+            score: self.score,
+            fields: self.fields,
+        }
+    }
+}
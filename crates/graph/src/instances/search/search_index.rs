@@ -0,0 +1,301 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Bound;
+
+use crate::SearchField;
+use crate::SearchHit;
+use crate::SearchOptions;
+use crate::edit_distance;
+use crate::max_edits;
+use crate::tokenize;
+
+/// The weight of a token which matches exactly.
+const WEIGHT_EXACT: f64 = 1.0;
+
+/// The weight of a token which starts with the searched token.
+const WEIGHT_PREFIX: f64 = 0.5;
+
+/// The weight of a token which differs from the searched token by a single edit.
+const WEIGHT_FUZZY: f64 = 0.5;
+
+/// Inverted index for the full-text search over the fields of instances.
+///
+/// Each document (instance) consists of multiple fields. A document matches a search if every
+/// token of the search text matches at least one token of the document. The hits are ranked by
+/// the frequency of the matched tokens, the rarity of the tokens over all documents (idf) and
+/// the boost of the field.
+pub struct SearchIndex<K>
+where
+    K: Clone + Eq + Hash + Ord,
+{
+    /// The term frequencies by token, document and field.
+    postings: BTreeMap<String, HashMap<K, HashMap<SearchField, usize>>>,
+    /// The indexed tokens by document and field.
+    documents: HashMap<K, HashMap<SearchField, Vec<String>>>,
+}
+
+impl<K> SearchIndex<K>
+where
+    K: Clone + Eq + Hash + Ord,
+{
+    pub fn new() -> Self {
+        SearchIndex {
+            postings: BTreeMap::new(),
+            documents: HashMap::new(),
+        }
+    }
+
+    /// Indexes the text of the field of the given document. Replaces the previously indexed
+    /// text of the field.
+    pub fn index(&mut self, id: K, field: SearchField, text: &str) {
+        self.remove_field(&id, &field);
+        let mut term_frequencies: BTreeMap<String, usize> = BTreeMap::new();
+        for token in tokenize(text) {
+            *term_frequencies.entry(token).or_default() += 1;
+        }
+        for (token, term_frequency) in &term_frequencies {
+            self.postings
+                .entry(token.clone())
+                .or_default()
+                .entry(id.clone())
+                .or_default()
+                .insert(field.clone(), *term_frequency);
+        }
+        self.documents.entry(id).or_default().insert(field, term_frequencies.into_keys().collect());
+    }
+
+    /// Removes the indexed text of the field of the given document.
+    pub fn remove_field(&mut self, id: &K, field: &SearchField) {
+        let Some(tokens) = self.documents.get_mut(id).and_then(|document| document.remove(field)) else {
+            return;
+        };
+        for token in tokens {
+            self.remove_posting(&token, id, Some(field));
+        }
+    }
+
+    /// Removes the given document from the index.
+    pub fn remove(&mut self, id: &K) {
+        let Some(document) = self.documents.remove(id) else {
+            return;
+        };
+        for token in document.into_values().flatten() {
+            self.remove_posting(&token, id, None);
+        }
+    }
+
+    /// Returns true, if the given document is indexed.
+    pub fn contains(&self, id: &K) -> bool {
+        self.documents.contains_key(id)
+    }
+
+    /// Returns the number of indexed documents.
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    /// Returns true, if no document is indexed.
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Returns the number of distinct indexed tokens.
+    pub fn token_count(&self) -> usize {
+        self.postings.len()
+    }
+
+    /// Removes all documents.
+    pub fn clear(&mut self) {
+        self.postings.clear();
+        self.documents.clear();
+    }
+
+    /// Returns the ranked documents matching every token of the given text.
+    pub fn search(&self, text: &str, options: &SearchOptions) -> Vec<SearchHit<K>> {
+        let mut query_tokens = tokenize(text);
+        query_tokens.sort();
+        query_tokens.dedup();
+        if query_tokens.is_empty() {
+            return Vec::new();
+        }
+        let document_count = self.documents.len() as f64;
+        let mut matches: HashMap<&K, (f64, usize, BTreeSet<SearchField>)> = HashMap::new();
+        for query_token in &query_tokens {
+            // The best score of the query token per document
+            let mut token_matches: HashMap<&K, (f64, BTreeSet<SearchField>)> = HashMap::new();
+            for (token, weight) in self.matching_tokens(query_token, options) {
+                let Some(postings) = self.postings.get(token) else {
+                    continue;
+                };
+                let idf = (1.0 + document_count / postings.len() as f64).ln();
+                for (id, fields) in postings {
+                    let score: f64 = fields
+                        .iter()
+                        .map(|(field, term_frequency)| (*term_frequency as f64).sqrt() * field.boost())
+                        .sum::<f64>()
+                        * weight
+                        * idf;
+                    let token_match = token_matches.entry(id).or_insert_with(|| (0.0, BTreeSet::new()));
+                    token_match.0 = token_match.0.max(score);
+                    token_match.1.extend(fields.keys().cloned());
+                }
+            }
+            for (id, (score, fields)) in token_matches {
+                let document_match = matches.entry(id).or_insert_with(|| (0.0, 0, BTreeSet::new()));
+                document_match.0 += score;
+                document_match.1 += 1;
+                document_match.2.extend(fields);
+            }
+        }
+        let mut hits: Vec<SearchHit<K>> = matches
+            .into_iter()
+            .filter(|(_, (_, matched_tokens, _))| *matched_tokens == query_tokens.len())
+            .map(|(id, (score, _, fields))| SearchHit::new(id.clone(), score, fields.into_iter().collect()))
+            .collect();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.instance.cmp(&b.instance)));
+        hits.truncate(options.limit);
+        hits
+    }
+
+    /// Returns the indexed tokens matching the given token and the weight of the match.
+    fn matching_tokens<'a>(&'a self, query_token: &str, options: &SearchOptions) -> Vec<(&'a String, f64)> {
+        let mut tokens = Vec::new();
+        if let Some((token, _)) = self.postings.get_key_value(query_token) {
+            tokens.push((token, WEIGHT_EXACT));
+        }
+        if options.prefix {
+            tokens.extend(
+                self.postings
+                    .range::<str, _>((Bound::Excluded(query_token), Bound::Unbounded))
+                    .take_while(|(token, _)| token.starts_with(query_token))
+                    .map(|(token, _)| (token, WEIGHT_PREFIX * query_token.len() as f64 / token.len() as f64)),
+            );
+        }
+        let max_edits = max_edits(query_token);
+        if options.fuzzy && max_edits > 0 {
+            let length = query_token.chars().count();
+            tokens.extend(
+                self.postings
+                    .keys()
+                    .filter(|token| token.chars().count().abs_diff(length) <= max_edits)
+                    .filter(|token| !options.prefix || !token.starts_with(query_token))
+                    .filter_map(|token| {
+                        let distance = edit_distance(query_token, token);
+                        (distance > 0 && distance <= max_edits).then_some((token, WEIGHT_FUZZY / distance as f64))
+                    }),
+            );
+        }
+        tokens
+    }
+
+    fn remove_posting(&mut self, token: &str, id: &K, field: Option<&SearchField>) {
+        let Some(postings) = self.postings.get_mut(token) else {
+            return;
+        };
+        match field {
+            Some(field) => {
+                if let Some(fields) = postings.get_mut(id) {
+                    fields.remove(field);
+                    if fields.is_empty() {
+                        postings.remove(id);
+                    }
+                }
+            }
+            None => {
+                postings.remove(id);
+            }
+        }
+        if postings.is_empty() {
+            self.postings.remove(token);
+        }
+    }
+}
+
+impl<K> Default for SearchIndex<K>
+where
+    K: Clone + Eq + Hash + Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SearchField;
+    use crate::SearchIndex;
+    use crate::SearchOptions;
+
+    fn create_index() -> SearchIndex<u32> {
+        let mut index = SearchIndex::new();
+        index.index(1, SearchField::Name, "Temperature Sensor");
+        index.index(1, SearchField::Description, "Measures the temperature of the living room");
+        index.index(2, SearchField::Name, "Humidity Sensor");
+        index.index(2, SearchField::Property("location".to_string()), "Bathroom");
+        index.index(3, SearchField::Name, "Ceiling Lamp");
+        index.index(3, SearchField::Description, "Turns on when the temperature is low");
+        index
+    }
+
+    fn ids(index: &SearchIndex<u32>, text: &str, options: &SearchOptions) -> Vec<u32> {
+        index.search(text, options).into_iter().map(|hit| hit.instance).collect()
+    }
+
+    #[test]
+    fn search_exact_test() {
+        let index = create_index();
+        let exact = SearchOptions::builder().prefix(false).fuzzy(false).build();
+        assert_eq!(vec![1, 2], ids(&index, "sensor", &exact));
+        assert_eq!(vec![2], ids(&index, "BATHROOM", &exact));
+        // Every token has to match
+        assert_eq!(vec![1], ids(&index, "temperature sensor", &exact));
+        assert!(ids(&index, "bath", &exact).is_empty());
+        assert!(ids(&index, "", &exact).is_empty());
+    }
+
+    #[test]
+    fn search_ranking_test() {
+        let index = create_index();
+        // A match in the name ranks higher than a match in the description
+        let hits = index.search("temperature", &SearchOptions::default());
+        assert_eq!(vec![1, 3], hits.iter().map(|hit| hit.instance).collect::<Vec<_>>());
+        assert!(hits[0].score > hits[1].score);
+        assert_eq!(vec![SearchField::Name, SearchField::Description], hits[0].fields);
+        assert_eq!(vec![SearchField::Description], hits[1].fields);
+        // Limit
+        let options = SearchOptions::builder().limit(1).build();
+        assert_eq!(vec![1], ids(&index, "temperature", &options));
+    }
+
+    #[test]
+    fn search_prefix_and_fuzzy_test() {
+        let index = create_index();
+        let prefix = SearchOptions::builder().fuzzy(false).build();
+        assert_eq!(vec![2], ids(&index, "bath", &prefix));
+        assert_eq!(vec![1, 3], ids(&index, "temp", &prefix));
+        let fuzzy = SearchOptions::builder().prefix(false).build();
+        assert_eq!(vec![3], ids(&index, "lamb", &fuzzy));
+        assert_eq!(vec![1], ids(&index, "temprature sensr", &fuzzy));
+        assert!(ids(&index, "lamb", &prefix).is_empty());
+    }
+
+    #[test]
+    fn update_and_remove_test() {
+        let mut index = create_index();
+        assert_eq!(3, index.len());
+        index.index(2, SearchField::Property("location".to_string()), "Kitchen");
+        assert!(ids(&index, "bathroom", &SearchOptions::default()).is_empty());
+        assert_eq!(vec![2], ids(&index, "kitchen", &SearchOptions::default()));
+        index.remove_field(&2, &SearchField::Property("location".to_string()));
+        assert!(ids(&index, "kitchen", &SearchOptions::default()).is_empty());
+        assert!(index.contains(&2));
+        index.remove(&1);
+        assert!(!index.contains(&1));
+        assert_eq!(vec![2], ids(&index, "sensor", &SearchOptions::default()));
+        index.clear();
+        assert!(index.is_empty());
+        assert_eq!(0, index.token_count());
+    }
+}
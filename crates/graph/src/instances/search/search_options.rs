@@ -0,0 +1,40 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use typed_builder::TypedBuilder;
+
+/// The default maximum number of search hits.
+pub const DEFAULT_SEARCH_LIMIT: usize = 100;
+
+/// Controls how the tokens of a full-text search are matched.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema, TypedBuilder)]
+pub struct SearchOptions {
+    /// Tokens also match indexed tokens starting with the token.
+    #[builder(default = true)]
+    #[serde(default = "default_true")]
+    pub prefix: bool,
+
+    /// Tokens also match indexed tokens with a small number of typos.
+    #[builder(default = true)]
+    #[serde(default = "default_true")]
+    pub fuzzy: bool,
+
+    /// The maximum number of returned hits.
+    #[builder(default = DEFAULT_SEARCH_LIMIT)]
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        SearchOptions::builder().build()
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_limit() -> usize {
+    DEFAULT_SEARCH_LIMIT
+}
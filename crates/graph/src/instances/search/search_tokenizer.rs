@@ -0,0 +1,65 @@
+/// Splits the text into lowercase tokens. Any character which is not alphanumeric separates
+/// two tokens.
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Returns the number of edits (insertions, deletions, substitutions) needed to transform the
+/// first token into the second token.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// Returns the maximum number of edits for a fuzzy match of the given token. Short tokens
+/// have to match exactly.
+pub fn max_edits(token: &str) -> usize {
+    match token.chars().count() {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::edit_distance;
+    use crate::max_edits;
+    use crate::tokenize;
+
+    #[test]
+    fn tokenize_test() {
+        assert_eq!(vec!["temperature", "sensor", "42"], tokenize("Temperature-Sensor #42"));
+        assert_eq!(vec!["über", "straße"], tokenize("  Über_Straße "));
+        assert!(tokenize(" -- ").is_empty());
+    }
+
+    #[test]
+    fn edit_distance_test() {
+        assert_eq!(0, edit_distance("sensor", "sensor"));
+        assert_eq!(1, edit_distance("sensor", "senor"));
+        assert_eq!(1, edit_distance("sensor", "sensors"));
+        assert_eq!(2, edit_distance("sensor", "snesor"));
+        assert_eq!(6, edit_distance("", "sensor"));
+    }
+
+    #[test]
+    fn max_edits_test() {
+        assert_eq!(0, max_edits("led"));
+        assert_eq!(1, max_edits("lamp"));
+        assert_eq!(2, max_edits("temperature"));
+    }
+}
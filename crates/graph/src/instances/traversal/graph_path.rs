@@ -0,0 +1,81 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::RelationInstanceId;
+
+/// An entity instance visited by a traversal.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct TraversalVisit {
+    /// The id of the visited entity instance.
+    pub id: Uuid,
+
+    /// The number of relations between the start entity and the visited entity.
+    pub depth: usize,
+
+    /// The relation which was followed to reach the entity. None for the start entity.
+    pub relation: Option<RelationInstanceId>,
+}
+
+impl TraversalVisit {
+    pub fn new(id: Uuid, depth: usize, relation: Option<RelationInstanceId>) -> Self {
+        TraversalVisit { id, depth, relation }
+    }
+}
+
+/// A path through the graph: a sequence of entity instances connected by relation instances.
+///
+/// A path of length n contains n + 1 entities and n relations. The relation at index i
+/// connects the entities at index i and i + 1.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct GraphPath {
+    /// The ids of the entity instances in order.
+    pub entities: Vec<Uuid>,
+
+    /// The ids of the relation instances in order.
+    pub relations: Vec<RelationInstanceId>,
+}
+
+impl GraphPath {
+    /// Creates a path containing only the given entity.
+    pub fn new(start: Uuid) -> Self {
+        GraphPath {
+            entities: vec![start],
+            relations: Vec::new(),
+        }
+    }
+
+    /// Returns the path extended by the given relation and entity.
+    pub fn extend(&self, relation: RelationInstanceId, entity: Uuid) -> Self {
+        let mut path = self.clone();
+        path.entities.push(entity);
+        path.relations.push(relation);
+        path
+    }
+
+    /// Returns the number of relations of the path.
+    pub fn len(&self) -> usize {
+        self.relations.len()
+    }
+
+    /// Returns true, if the path doesn't contain any relation.
+    pub fn is_empty(&self) -> bool {
+        self.relations.is_empty()
+    }
+
+    /// Returns the id of the first entity.
+    pub fn start(&self) -> Option<Uuid> {
+        self.entities.first().copied()
+    }
+
+    /// Returns the id of the last entity.
+    pub fn end(&self) -> Option<Uuid> {
+        self.entities.last().copied()
+    }
+
+    /// Returns true, if the path contains the entity.
+    pub fn contains(&self, id: &Uuid) -> bool {
+        self.entities.contains(id)
+    }
+}
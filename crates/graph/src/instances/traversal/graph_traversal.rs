@@ -0,0 +1,421 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use uuid::Uuid;
+
+use crate::GraphPath;
+use crate::RelationInstanceId;
+use crate::TraversalDirection;
+use crate::TraversalOptions;
+use crate::TraversalStrategy;
+use crate::TraversalVisit;
+
+/// A graph of entity instances connected by relation instances which can be traversed.
+///
+/// Implementors only have to provide the relations of an entity, the traversal algorithms
+/// are provided.
+pub trait TraversalGraph {
+    /// Returns the relations having the given entity as outbound entity.
+    fn outbound_relations(&self, id: Uuid) -> Vec<RelationInstanceId>;
+
+    /// Returns the relations having the given entity as inbound entity.
+    fn inbound_relations(&self, id: Uuid) -> Vec<RelationInstanceId>;
+
+    /// Returns the followed relations of the entity together with the entity on the other end.
+    fn neighbours(&self, id: Uuid, options: &TraversalOptions) -> Vec<(RelationInstanceId, Uuid)> {
+        self.neighbours_in_direction(id, options.direction, options)
+    }
+
+    /// Returns the followed relations of the entity in the given direction together with the
+    /// entity on the other end.
+    fn neighbours_in_direction(&self, id: Uuid, direction: TraversalDirection, options: &TraversalOptions) -> Vec<(RelationInstanceId, Uuid)> {
+        let mut neighbours = Vec::new();
+        if direction != TraversalDirection::Inbound {
+            for relation in self.outbound_relations(id).into_iter().filter(|relation| options.follows(relation)) {
+                let inbound_id = relation.inbound_id;
+                neighbours.push((relation, inbound_id));
+            }
+        }
+        if direction != TraversalDirection::Outbound {
+            for relation in self.inbound_relations(id).into_iter().filter(|relation| options.follows(relation)) {
+                // Self loops have already been added as outbound relation
+                if direction == TraversalDirection::Both && relation.outbound_id == relation.inbound_id {
+                    continue;
+                }
+                let outbound_id = relation.outbound_id;
+                neighbours.push((relation, outbound_id));
+            }
+        }
+        neighbours.sort();
+        neighbours
+    }
+
+    /// Visits the entities reachable from the start entity, including the start entity itself.
+    fn traverse(&self, start: Uuid, options: &TraversalOptions) -> Vec<TraversalVisit> {
+        match options.strategy {
+            TraversalStrategy::BreadthFirst => self.breadth_first(start, options),
+            TraversalStrategy::DepthFirst => self.depth_first(start, options),
+        }
+    }
+
+    /// Visits the reachable entities level by level.
+    fn breadth_first(&self, start: Uuid, options: &TraversalOptions) -> Vec<TraversalVisit> {
+        let mut visits = Vec::new();
+        let mut visited = HashSet::from([start]);
+        let mut queue = VecDeque::from([TraversalVisit::new(start, 0, None)]);
+        while let Some(visit) = queue.pop_front() {
+            if options.is_exhausted(visits.len()) {
+                break;
+            }
+            if options.expands(visit.depth) {
+                for (relation, neighbour) in self.neighbours(visit.id, options) {
+                    if visited.insert(neighbour) {
+                        queue.push_back(TraversalVisit::new(neighbour, visit.depth + 1, Some(relation)));
+                    }
+                }
+            }
+            visits.push(visit);
+        }
+        visits
+    }
+
+    /// Follows each branch as far as possible before backtracking.
+    fn depth_first(&self, start: Uuid, options: &TraversalOptions) -> Vec<TraversalVisit> {
+        let mut visits = Vec::new();
+        let mut visited = HashSet::new();
+        let mut stack = vec![TraversalVisit::new(start, 0, None)];
+        while let Some(visit) = stack.pop() {
+            if options.is_exhausted(visits.len()) {
+                break;
+            }
+            if !visited.insert(visit.id) {
+                continue;
+            }
+            if options.expands(visit.depth) {
+                // Reversed, so that the first neighbour is visited first
+                for (relation, neighbour) in self.neighbours(visit.id, options).into_iter().rev() {
+                    if !visited.contains(&neighbour) {
+                        stack.push(TraversalVisit::new(neighbour, visit.depth + 1, Some(relation)));
+                    }
+                }
+            }
+            visits.push(visit);
+        }
+        visits
+    }
+
+    /// Returns a path with the fewest relations from the start entity to the end entity.
+    fn shortest_path(&self, start: Uuid, end: Uuid, options: &TraversalOptions) -> Option<GraphPath> {
+        if start == end {
+            return Some(GraphPath::new(start));
+        }
+        let mut parents: HashMap<Uuid, (Uuid, RelationInstanceId)> = HashMap::new();
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((id, depth)) = queue.pop_front() {
+            if !options.expands(depth) {
+                continue;
+            }
+            for (relation, neighbour) in self.neighbours(id, options) {
+                if neighbour == start || parents.contains_key(&neighbour) {
+                    continue;
+                }
+                parents.insert(neighbour, (id, relation));
+                if neighbour == end {
+                    return Some(path_from_parents(start, end, &parents));
+                }
+                queue.push_back((neighbour, depth + 1));
+            }
+        }
+        None
+    }
+
+    /// Returns all paths from the start entity to the end entity which doesn't visit an entity twice.
+    ///
+    /// The paths are generated breadth-first, so they are ordered by length and the search stops
+    /// as soon as the limit is reached.
+    fn all_paths(&self, start: Uuid, end: Uuid, options: &TraversalOptions) -> Vec<GraphPath> {
+        if start == end {
+            return vec![GraphPath::new(start)];
+        }
+        let mut paths = Vec::new();
+        let mut queue = VecDeque::from([GraphPath::new(start)]);
+        while let Some(path) = queue.pop_front() {
+            let Some(last) = path.end() else {
+                continue;
+            };
+            if !options.expands(path.len()) {
+                continue;
+            }
+            for (relation, neighbour) in self.neighbours(last, options) {
+                if neighbour == end {
+                    paths.push(path.extend(relation, neighbour));
+                    if options.is_exhausted(paths.len()) {
+                        return paths;
+                    }
+                } else if !path.contains(&neighbour) {
+                    queue.push_back(path.extend(relation, neighbour));
+                }
+            }
+        }
+        paths
+    }
+
+    /// Groups the given entities into connected components.
+    ///
+    /// The direction of the relations is ignored. Entities which are reachable from the given
+    /// entities are included as well. The components are ordered by size (largest first).
+    fn connected_components(&self, entities: Vec<Uuid>, options: &TraversalOptions) -> Vec<Vec<Uuid>> {
+        let mut components = Vec::new();
+        let mut assigned = HashSet::new();
+        for entity in entities {
+            if !assigned.insert(entity) {
+                continue;
+            }
+            let mut component = vec![entity];
+            let mut queue = VecDeque::from([entity]);
+            while let Some(id) = queue.pop_front() {
+                for (_, neighbour) in self.neighbours_in_direction(id, TraversalDirection::Both, options) {
+                    if assigned.insert(neighbour) {
+                        component.push(neighbour);
+                        queue.push_back(neighbour);
+                    }
+                }
+            }
+            component.sort();
+            components.push(component);
+        }
+        components.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        if let Some(limit) = options.limit {
+            components.truncate(limit);
+        }
+        components
+    }
+
+    /// Returns the cycles which contain at least one of the given entities.
+    ///
+    /// Each cycle starts and ends with the same entity and doesn't use a relation twice. A cycle
+    /// is only returned once, even if it contains multiple of the given entities. The maximum
+    /// depth limits the length of the cycles.
+    fn cycles(&self, entities: Vec<Uuid>, options: &TraversalOptions) -> Vec<GraphPath> {
+        let mut cycles = Vec::new();
+        let mut found: HashSet<BTreeSet<RelationInstanceId>> = HashSet::new();
+        for start in entities {
+            let mut stack = vec![GraphPath::new(start)];
+            while let Some(path) = stack.pop() {
+                if options.is_exhausted(cycles.len()) {
+                    return cycles;
+                }
+                let Some(last) = path.end() else {
+                    continue;
+                };
+                if !options.expands(path.len()) {
+                    continue;
+                }
+                for (relation, neighbour) in self.neighbours(last, options).into_iter().rev() {
+                    if path.relations.contains(&relation) {
+                        continue;
+                    }
+                    if neighbour == start {
+                        let cycle = path.extend(relation, neighbour);
+                        if found.insert(cycle.relations.iter().cloned().collect()) {
+                            cycles.push(cycle);
+                        }
+                    } else if !path.contains(&neighbour) {
+                        stack.push(path.extend(relation, neighbour));
+                    }
+                }
+            }
+        }
+        if let Some(limit) = options.limit {
+            cycles.truncate(limit);
+        }
+        cycles
+    }
+}
+
+/// Builds the path from the start entity to the end entity by following the recorded parents.
+fn path_from_parents(start: Uuid, end: Uuid, parents: &HashMap<Uuid, (Uuid, RelationInstanceId)>) -> GraphPath {
+    let mut entities = vec![end];
+    let mut relations = Vec::new();
+    let mut current = end;
+    while current != start {
+        let Some((parent, relation)) = parents.get(&current) else {
+            break;
+        };
+        relations.push(relation.clone());
+        entities.push(*parent);
+        current = *parent;
+    }
+    entities.reverse();
+    relations.reverse();
+    GraphPath { entities, relations }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use uuid::Uuid;
+
+    use crate::RelationInstanceId;
+    use crate::RelationTypeId;
+    use crate::TraversalDirection;
+    use crate::TraversalGraph;
+    use crate::TraversalOptions;
+    use crate::TraversalStrategy;
+
+    #[derive(Default)]
+    struct TestGraph {
+        outbound: HashMap<Uuid, Vec<RelationInstanceId>>,
+        inbound: HashMap<Uuid, Vec<RelationInstanceId>>,
+    }
+
+    impl TestGraph {
+        fn connect(&mut self, outbound_id: Uuid, ty: &RelationTypeId, inbound_id: Uuid) -> RelationInstanceId {
+            let relation = RelationInstanceId::new_with_random_instance_id(outbound_id, ty.clone(), inbound_id);
+            self.outbound.entry(outbound_id).or_default().push(relation.clone());
+            self.inbound.entry(inbound_id).or_default().push(relation.clone());
+            relation
+        }
+    }
+
+    impl TraversalGraph for TestGraph {
+        fn outbound_relations(&self, id: Uuid) -> Vec<RelationInstanceId> {
+            self.outbound.get(&id).cloned().unwrap_or_default()
+        }
+
+        fn inbound_relations(&self, id: Uuid) -> Vec<RelationInstanceId> {
+            self.inbound.get(&id).cloned().unwrap_or_default()
+        }
+    }
+
+    /// Creates the chain a -> b -> c -> d and the cycle c -> a.
+    fn create_graph() -> (TestGraph, RelationTypeId, RelationTypeId, Vec<Uuid>) {
+        let connects = RelationTypeId::new_from_type("test", "connects");
+        let feedback = RelationTypeId::new_from_type("test", "feedback");
+        let ids: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        let mut graph = TestGraph::default();
+        graph.connect(ids[0], &connects, ids[1]);
+        graph.connect(ids[1], &connects, ids[2]);
+        graph.connect(ids[2], &connects, ids[3]);
+        graph.connect(ids[2], &feedback, ids[0]);
+        (graph, connects, feedback, ids)
+    }
+
+    #[test]
+    fn traverse_test() {
+        let (graph, connects, _, ids) = create_graph();
+
+        let visits = graph.traverse(ids[0], &TraversalOptions::default());
+        assert_eq!(4, visits.len());
+        assert_eq!(ids[0], visits[0].id);
+        assert_eq!(0, visits[0].depth);
+        assert!(visits[0].relation.is_none());
+        assert_eq!(vec![0, 1, 2, 3], visits.iter().map(|visit| visit.depth).collect::<Vec<_>>());
+
+        let visits = graph.traverse(ids[0], &TraversalOptions::builder().max_depth(2).build());
+        assert_eq!(vec![ids[0], ids[1], ids[2]], visits.iter().map(|visit| visit.id).collect::<Vec<_>>());
+
+        let visits = graph.traverse(ids[3], &TraversalOptions::builder().direction(TraversalDirection::Inbound).build());
+        assert_eq!(4, visits.len());
+
+        let visits = graph.traverse(ids[3], &TraversalOptions::default());
+        assert_eq!(1, visits.len());
+
+        let visits = graph.traverse(ids[1], &TraversalOptions::builder().relation_types(vec![connects]).build());
+        assert_eq!(vec![ids[1], ids[2], ids[3]], visits.iter().map(|visit| visit.id).collect::<Vec<_>>());
+
+        let visits = graph.traverse(ids[0], &TraversalOptions::builder().strategy(TraversalStrategy::DepthFirst).limit(3).build());
+        assert_eq!(vec![ids[0], ids[1], ids[2]], visits.iter().map(|visit| visit.id).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn shortest_path_test() {
+        let (mut graph, connects, _, ids) = create_graph();
+        let shortcut = graph.connect(ids[0], &connects, ids[3]);
+
+        let path = graph.shortest_path(ids[0], ids[3], &TraversalOptions::default()).expect("Missing path");
+        assert_eq!(vec![ids[0], ids[3]], path.entities);
+        assert_eq!(vec![shortcut], path.relations);
+
+        let path = graph.shortest_path(ids[1], ids[0], &TraversalOptions::default()).expect("Missing path");
+        assert_eq!(2, path.len());
+        assert_eq!(Some(ids[1]), path.start());
+        assert_eq!(Some(ids[0]), path.end());
+
+        assert!(graph.shortest_path(ids[1], ids[0], &TraversalOptions::builder().max_depth(1).build()).is_none());
+        assert!(graph.shortest_path(ids[3], ids[0], &TraversalOptions::default()).is_none());
+        assert!(
+            graph
+                .shortest_path(ids[0], ids[0], &TraversalOptions::default())
+                .is_some_and(|path| path.is_empty())
+        );
+    }
+
+    #[test]
+    fn all_paths_test() {
+        let (mut graph, connects, _, ids) = create_graph();
+        graph.connect(ids[0], &connects, ids[3]);
+
+        let paths = graph.all_paths(ids[0], ids[3], &TraversalOptions::default());
+        assert_eq!(vec![1, 3], paths.iter().map(|path| path.len()).collect::<Vec<_>>());
+
+        let paths = graph.all_paths(ids[0], ids[3], &TraversalOptions::builder().max_depth(2).build());
+        assert_eq!(1, paths.len());
+
+        let paths = graph.all_paths(ids[3], ids[1], &TraversalOptions::builder().direction(TraversalDirection::Both).build());
+        assert_eq!(4, paths.len());
+
+        // The shortest paths are found first
+        let paths = graph.all_paths(ids[3], ids[1], &TraversalOptions::builder().direction(TraversalDirection::Both).limit(2).build());
+        assert_eq!(vec![2, 2], paths.iter().map(|path| path.len()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn connected_components_test() {
+        let (mut graph, connects, _, ids) = create_graph();
+        let e = Uuid::new_v4();
+        let f = Uuid::new_v4();
+        let g = Uuid::new_v4();
+        graph.connect(f, &connects, e);
+
+        let components = graph.connected_components(vec![ids[3], e, g], &TraversalOptions::default());
+        assert_eq!(3, components.len());
+        assert_eq!(4, components[0].len());
+        assert_eq!(2, components[1].len());
+        assert!(components[1].contains(&f));
+        assert_eq!(vec![g], components[2]);
+    }
+
+    #[test]
+    fn cycles_test() {
+        let (mut graph, connects, feedback, ids) = create_graph();
+        let self_loop = graph.connect(ids[3], &connects, ids[3]);
+
+        let cycles = graph.cycles(vec![ids[0], ids[1]], &TraversalOptions::default());
+        assert_eq!(1, cycles.len());
+        assert_eq!(vec![ids[0], ids[1], ids[2], ids[0]], cycles[0].entities);
+
+        let cycles = graph.cycles(vec![ids[3]], &TraversalOptions::default());
+        assert_eq!(1, cycles.len());
+        assert_eq!(vec![self_loop], cycles[0].relations);
+
+        assert!(graph.cycles(vec![ids[0]], &TraversalOptions::builder().max_depth(2).build()).is_empty());
+        assert!(
+            graph
+                .cycles(vec![ids[0]], &TraversalOptions::builder().relation_types(vec![connects.clone()]).build())
+                .is_empty()
+        );
+        assert_eq!(
+            1,
+            graph
+                .cycles(vec![ids[1]], &TraversalOptions::builder().relation_types(vec![connects, feedback]).build())
+                .len()
+        );
+
+        let cycles = graph.cycles(vec![ids[0]], &TraversalOptions::builder().direction(TraversalDirection::Both).build());
+        assert_eq!(1, cycles.len());
+    }
+}
@@ -0,0 +1,7 @@
+pub use graph_path::*;
+pub use graph_traversal::*;
+pub use traversal_options::*;
+
+pub mod graph_path;
+pub mod graph_traversal;
+pub mod traversal_options;
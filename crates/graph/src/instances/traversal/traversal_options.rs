@@ -0,0 +1,77 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use typed_builder::TypedBuilder;
+
+use crate::RelationInstanceId;
+use crate::RelationTypeId;
+
+/// The direction in which relations are followed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TraversalDirection {
+    /// Follows the relations from the outbound entity to the inbound entity.
+    #[default]
+    Outbound,
+    /// Follows the relations from the inbound entity to the outbound entity.
+    Inbound,
+    /// Follows the relations in both directions.
+    Both,
+}
+
+/// The order in which the reachable entities are visited.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TraversalStrategy {
+    /// Visits the entities level by level.
+    #[default]
+    BreadthFirst,
+    /// Follows each branch as far as possible before backtracking.
+    DepthFirst,
+}
+
+/// Restricts which relations are followed by a traversal.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema, TypedBuilder)]
+pub struct TraversalOptions {
+    /// The direction in which relations are followed.
+    #[builder(default)]
+    #[serde(default)]
+    pub direction: TraversalDirection,
+
+    /// Only relations of these relation types are followed. If empty, all relations are followed.
+    #[builder(default, setter(into))]
+    #[serde(default)]
+    pub relation_types: Vec<RelationTypeId>,
+
+    /// The maximum number of relations between the start entity and a visited entity.
+    #[builder(default, setter(strip_option))]
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+
+    /// The order in which the reachable entities are visited.
+    #[builder(default)]
+    #[serde(default)]
+    pub strategy: TraversalStrategy,
+
+    /// The maximum number of returned entities, paths or cycles.
+    #[builder(default, setter(strip_option))]
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+impl TraversalOptions {
+    /// Returns true, if the relation is followed.
+    pub fn follows(&self, relation: &RelationInstanceId) -> bool {
+        self.relation_types.is_empty() || self.relation_types.iter().any(|ty| relation.ty.is_a(ty))
+    }
+
+    /// Returns true, if the entities at the given depth may be expanded.
+    pub fn expands(&self, depth: usize) -> bool {
+        self.max_depth.is_none_or(|max_depth| depth < max_depth)
+    }
+
+    /// Returns true, if the given number of results reached the limit.
+    pub fn is_exhausted(&self, count: usize) -> bool {
+        self.limit.is_some_and(|limit| count >= limit)
+    }
+}
@@ -8,12 +8,22 @@ pub use types::relations::*;
 pub use types::type_id::*;
 pub use types::variables::*;
 
+pub use instances::aggregation::*;
 #[allow(unused_imports)]
 pub use instances::components::*;
+pub use instances::computed::*;
 pub use instances::entities::*;
+pub use instances::filter::*;
 pub use instances::flows::*;
+pub use instances::labels::*;
 pub use instances::properties::*;
+pub use instances::query::*;
 pub use instances::relations::*;
+pub use instances::saved_queries::*;
+pub use instances::search::*;
+pub use instances::traversal::*;
+
+pub use pagination::*;
 
 #[cfg(any(test, feature = "test"))]
 use reactive_graph_utils_test as test_utils;
@@ -22,6 +32,7 @@ use reactive_graph_utils_test as test_utils;
 pub use instances::entities::entity_instance_tests;
 
 pub mod instances;
+pub mod pagination;
 pub mod types;
 
 pub mod json_schema;
@@ -37,10 +48,20 @@ pub mod prelude {
     pub use crate::types::type_id::*;
     pub use crate::types::variables::*;
 
+    pub use crate::instances::aggregation::*;
     #[allow(unused_imports)]
     pub use crate::instances::components::*;
+    pub use crate::instances::computed::*;
     pub use crate::instances::entities::*;
+    pub use crate::instances::filter::*;
     pub use crate::instances::flows::*;
+    pub use crate::instances::labels::*;
     pub use crate::instances::properties::*;
+    pub use crate::instances::query::*;
     pub use crate::instances::relations::*;
+    pub use crate::instances::saved_queries::*;
+    pub use crate::instances::search::*;
+    pub use crate::instances::traversal::*;
+
+    pub use crate::pagination::*;
 }
@@ -0,0 +1,10 @@
+pub use page::*;
+pub use pagination::*;
+pub use pagination_errors::*;
+pub use sort::*;
+
+pub mod page;
+#[allow(clippy::module_inception)]
+pub mod pagination;
+pub mod pagination_errors;
+pub mod sort;
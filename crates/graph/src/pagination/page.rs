@@ -0,0 +1,65 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// An element of a page and the cursor pointing at the element.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Edge<T> {
+    /// The opaque cursor of the element.
+    pub cursor: String,
+    /// The element.
+    pub node: T,
+}
+
+/// Information about the position of a page within the whole result.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageInfo {
+    /// True, if there are elements before the page.
+    pub has_previous_page: bool,
+    /// True, if there are elements after the page.
+    pub has_next_page: bool,
+    /// The cursor of the first element of the page.
+    pub start_cursor: Option<String>,
+    /// The cursor of the last element of the page.
+    pub end_cursor: Option<String>,
+}
+
+/// A page of a sorted result (Relay connection).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+    /// The elements of the page.
+    pub edges: Vec<Edge<T>>,
+    /// The position of the page.
+    pub page_info: PageInfo,
+    /// The number of elements of the whole result.
+    pub total_count: usize,
+}
+
+impl<T> Page<T> {
+    /// Returns the elements of the page.
+    pub fn nodes(&self) -> impl Iterator<Item = &T> {
+        self.edges.iter().map(|edge| &edge.node)
+    }
+
+    /// Consumes the page and returns the elements.
+    pub fn into_nodes(self) -> Vec<T> {
+        self.edges.into_iter().map(|edge| edge.node).collect()
+    }
+
+    /// Converts the elements of the page.
+    pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> Page<U> {
+        Page {
+            edges: self
+                .edges
+                .into_iter()
+                .map(|edge| Edge {
+                    cursor: edge.cursor,
+                    node: f(edge.node),
+                })
+                .collect(),
+            page_info: self.page_info,
+            total_count: self.total_count,
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Edge;
+use crate::Page;
+use crate::PageInfo;
+use crate::PaginationError;
+
+/// Relay style pagination arguments.
+///
+/// The cursors are the offsets of the elements within the sorted result.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pagination {
+    /// Returns the first n elements.
+    pub first: Option<usize>,
+    /// Returns the elements after the given cursor.
+    pub after: Option<String>,
+    /// Returns the last n elements.
+    pub last: Option<usize>,
+    /// Returns the elements before the given cursor.
+    pub before: Option<String>,
+}
+
+impl Pagination {
+    pub fn new(first: Option<usize>, after: Option<String>, last: Option<usize>, before: Option<String>) -> Self {
+        Pagination { first, after, last, before }
+    }
+
+    /// Returns the page of the given sorted elements.
+    pub fn paginate<T>(&self, elements: Vec<T>) -> Result<Page<T>, PaginationError> {
+        let total_count = elements.len();
+        let mut start = match &self.after {
+            Some(after) => decode_cursor(after)?.saturating_add(1).min(total_count),
+            None => 0,
+        };
+        let mut end = match &self.before {
+            Some(before) => decode_cursor(before)?.min(total_count),
+            None => total_count,
+        };
+        if end < start {
+            end = start;
+        }
+        if let Some(first) = self.first {
+            end = end.min(start.saturating_add(first));
+        }
+        if let Some(last) = self.last {
+            start = start.max(end.saturating_sub(last));
+        }
+        let edges: Vec<Edge<T>> = elements
+            .into_iter()
+            .enumerate()
+            .skip(start)
+            .take(end - start)
+            .map(|(offset, node)| Edge {
+                cursor: encode_cursor(offset),
+                node,
+            })
+            .collect();
+        let page_info = PageInfo {
+            has_previous_page: start > 0,
+            has_next_page: end < total_count,
+            start_cursor: edges.first().map(|edge| edge.cursor.clone()),
+            end_cursor: edges.last().map(|edge| edge.cursor.clone()),
+        };
+        Ok(Page { edges, page_info, total_count })
+    }
+}
+
+/// Returns the cursor of the element at the given offset.
+pub fn encode_cursor(offset: usize) -> String {
+    offset.to_string()
+}
+
+/// Returns the offset of the element of the given cursor.
+pub fn decode_cursor(cursor: &str) -> Result<usize, PaginationError> {
+    cursor.parse().map_err(|_| PaginationError::InvalidCursor(cursor.to_string()))
+}
+
+/// Returns the number of elements of the argument with the given name. Negative numbers and
+/// numbers which exceed the address space are rejected.
+pub fn decode_count(name: &str, count: i64) -> Result<usize, PaginationError> {
+    usize::try_from(count).map_err(|_| PaginationError::InvalidCount(name.to_string(), count))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Pagination;
+    use crate::PaginationError;
+    use crate::decode_count;
+
+    fn offsets(pagination: Pagination) -> Vec<u32> {
+        pagination.paginate((0..10).collect()).expect("Failed to paginate").into_nodes()
+    }
+
+    #[test]
+    fn paginate_test() {
+        assert_eq!(10, offsets(Pagination::default()).len());
+        assert_eq!(vec![0, 1, 2], offsets(Pagination::new(Some(3), None, None, None)));
+        assert_eq!(vec![3, 4, 5], offsets(Pagination::new(Some(3), Some("2".to_string()), None, None)));
+        assert_eq!(vec![7, 8, 9], offsets(Pagination::new(None, None, Some(3), None)));
+        assert_eq!(vec![2, 3, 4], offsets(Pagination::new(None, None, Some(3), Some("5".to_string()))));
+        assert_eq!(vec![3, 4], offsets(Pagination::new(None, Some("2".to_string()), None, Some("5".to_string()))));
+        assert!(offsets(Pagination::new(Some(3), Some("9".to_string()), None, None)).is_empty());
+        assert!(offsets(Pagination::new(None, Some("5".to_string()), None, Some("2".to_string()))).is_empty());
+    }
+
+    #[test]
+    fn page_info_test() {
+        let page = Pagination::new(Some(3), Some("2".to_string()), None, None)
+            .paginate((0..10).collect::<Vec<u32>>())
+            .unwrap();
+        assert_eq!(10, page.total_count);
+        assert!(page.page_info.has_previous_page);
+        assert!(page.page_info.has_next_page);
+        assert_eq!(Some("3".to_string()), page.page_info.start_cursor);
+        assert_eq!(Some("5".to_string()), page.page_info.end_cursor);
+        let page = Pagination::new(None, None, Some(3), None).paginate((0..10).collect::<Vec<u32>>()).unwrap();
+        assert!(page.page_info.has_previous_page);
+        assert!(!page.page_info.has_next_page);
+    }
+
+    #[test]
+    fn overflow_test() {
+        let max = usize::MAX.to_string();
+        assert!(offsets(Pagination::new(None, Some(max.clone()), None, None)).is_empty());
+        assert_eq!(vec![3, 4, 5, 6, 7, 8, 9], offsets(Pagination::new(Some(usize::MAX), Some("2".to_string()), None, None)));
+        assert_eq!(vec![0, 1], offsets(Pagination::new(None, None, Some(usize::MAX), Some("2".to_string()))));
+        assert_eq!(10, offsets(Pagination::new(Some(usize::MAX), None, Some(usize::MAX), Some(max))).len());
+    }
+
+    #[test]
+    fn decode_count_test() {
+        assert_eq!(3, decode_count("first", 3).unwrap());
+        assert!(matches!(decode_count("first", -1), Err(PaginationError::InvalidCount(name, -1)) if name == "first"));
+    }
+
+    #[test]
+    fn invalid_cursor_test() {
+        let pagination = Pagination::new(None, Some("abc".to_string()), None, None);
+        assert!(matches!(pagination.paginate(vec![1]), Err(PaginationError::InvalidCursor(_))));
+    }
+}
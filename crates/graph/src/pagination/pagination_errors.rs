@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PaginationError {
+    #[error("The cursor {0} is invalid")]
+    InvalidCursor(String),
+    #[error("The argument {0} must be a non-negative number but was {1}")]
+    InvalidCount(String, i64),
+}
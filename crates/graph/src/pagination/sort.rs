@@ -0,0 +1,124 @@
+use std::cmp::Ordering;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::PropertyInstanceGetter;
+use crate::instances::filter::property_filter_expression::json_cmp;
+
+/// The direction of a sort.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// Applies the direction on the given ordering.
+    pub fn apply(&self, ordering: Ordering) -> Ordering {
+        match self {
+            SortDirection::Ascending => ordering,
+            SortDirection::Descending => ordering.reverse(),
+        }
+    }
+}
+
+/// Sorts instances by the value of a property.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PropertySort {
+    /// The name of the property.
+    pub property: String,
+
+    /// The direction.
+    #[serde(default)]
+    pub direction: SortDirection,
+}
+
+impl PropertySort {
+    pub fn new<S: Into<String>>(property: S, direction: SortDirection) -> Self {
+        PropertySort {
+            property: property.into(),
+            direction,
+        }
+    }
+
+    /// Compares the property values of the given instances.
+    ///
+    /// Instances without the property or with a null value are sorted last, regardless of
+    /// the direction.
+    pub fn compare<T: PropertyInstanceGetter>(&self, a: &T, b: &T) -> Ordering {
+        let a = a.get(&self.property).filter(|value| !value.is_null());
+        let b = b.get(&self.property).filter(|value| !value.is_null());
+        match (a, b) {
+            (Some(a), Some(b)) => self.direction.apply(compare_values(&a, &b)),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+/// Compares the given instances by the given sorts. The first sort has the highest priority.
+pub fn compare_by_properties<T: PropertyInstanceGetter>(a: &T, b: &T, sorts: &[PropertySort]) -> Ordering {
+    sorts
+        .iter()
+        .map(|sort| sort.compare(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Orders values of different kinds by kind (bool, number, string, array, object).
+fn compare_values(a: &Value, b: &Value) -> Ordering {
+    json_cmp(a, b).unwrap_or_else(|| kind_rank(a).cmp(&kind_rank(b)).then_with(|| a.to_string().cmp(&b.to_string())))
+}
+
+fn kind_rank(value: &Value) -> u8 {
+    match value {
+        Value::Null => 0,
+        Value::Bool(_) => 1,
+        Value::Number(_) => 2,
+        Value::String(_) => 3,
+        Value::Array(_) => 4,
+        Value::Object(_) => 5,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::PropertyInstanceGetter;
+    use crate::PropertyInstances;
+    use crate::PropertySort;
+    use crate::SortDirection;
+    use crate::compare_by_properties;
+
+    #[test]
+    fn compare_by_properties_test() {
+        let mut instances = vec![
+            PropertyInstances::new().property("name", json!("b")).property("count", json!(1)),
+            PropertyInstances::new().property("name", json!("a")).property("count", json!(2.5)),
+            PropertyInstances::new().property("name", json!("c")),
+            PropertyInstances::new().property("name", json!("d")).property("count", json!(2.5)),
+        ];
+        let sorts = vec![
+            PropertySort::new("count", SortDirection::Descending),
+            PropertySort::new("name", SortDirection::Ascending),
+        ];
+        instances.sort_by(|a, b| compare_by_properties(a, b, &sorts));
+        let names: Vec<_> = instances.iter().map(|instance| instance.get("name").unwrap()).collect();
+        assert_eq!(vec![json!("a"), json!("d"), json!("b"), json!("c")], names);
+    }
+
+    #[test]
+    fn sort_direction_deserialize_test() {
+        let sort: PropertySort = serde_json::from_value(json!({ "property": "name", "direction": "DESCENDING" })).unwrap();
+        assert_eq!(SortDirection::Descending, sort.direction);
+        let sort: PropertySort = serde_json::from_value(json!({ "property": "name" })).unwrap();
+        assert_eq!(SortDirection::Ascending, sort.direction);
+    }
+}
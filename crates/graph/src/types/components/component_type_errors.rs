@@ -29,6 +29,8 @@ pub enum RemoveComponentError {
 pub enum ComponentUpdateError {
     #[error("The component {0} does not exist")]
     ComponentDoesNotExist(ComponentTypeId),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -43,6 +45,8 @@ pub enum ComponentAddPropertyError {
     ComponentDoesNotExist(ComponentTypeId),
     #[error("Failed to add property {0}")]
     AddPropertyError(#[from] AddPropertyError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -51,6 +55,8 @@ pub enum ComponentUpdatePropertyError {
     ComponentDoesNotExist(ComponentTypeId),
     #[error("Failed to update property {0}")]
     UpdatePropertyError(#[from] UpdatePropertyError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -59,6 +65,8 @@ pub enum ComponentRemovePropertyError {
     ComponentDoesNotExist(ComponentTypeId),
     #[error("Failed to remove property {0}")]
     RemovePropertyError(#[from] RemovePropertyError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -67,6 +75,8 @@ pub enum ComponentAddExtensionError {
     ComponentDoesNotExist(ComponentTypeId),
     #[error("Failed to add extension {0}")]
     AddExtensionError(#[from] AddExtensionError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -75,6 +85,8 @@ pub enum ComponentUpdateExtensionError {
     ComponentDoesNotExist(ComponentTypeId),
     #[error("Failed to update extension {0}")]
     UpdateExtensionError(#[from] UpdateExtensionError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -83,4 +95,6 @@ pub enum ComponentRemoveExtensionError {
     ComponentDoesNotExist(ComponentTypeId),
     #[error("Failed to remove extension {0}")]
     RemoveExtensionError(#[from] RemoveExtensionError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
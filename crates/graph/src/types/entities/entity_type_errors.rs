@@ -13,6 +13,8 @@ use crate::UpdatePropertyError;
 pub enum EntityTypeUpdateError {
     #[error("The entity type {0} does not exist")]
     EntityTypeDoesNotExist(EntityTypeId),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -29,6 +31,8 @@ pub enum EntityTypeAddComponentError {
     ComponentDoesNotExist(ComponentTypeId),
     #[error("The entity type is already a component {0}")]
     IsAlreadyA(ComponentTypeId),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -45,6 +49,8 @@ pub enum EntityTypeRemoveComponentError {
     EntityTypeDoesNotExist(EntityTypeId),
     #[error("The entity type is not a {0}")]
     IsNotA(ComponentTypeId),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -53,6 +59,8 @@ pub enum EntityTypeAddPropertyError {
     EntityTypeDoesNotExist(EntityTypeId),
     #[error("Failed to add property {0}")]
     AddPropertyError(#[from] AddPropertyError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -69,6 +77,8 @@ pub enum EntityTypeRemovePropertyError {
     EntityTypeDoesNotExist(EntityTypeId),
     #[error("Failed to remove property {0}")]
     RemovePropertyError(#[from] RemovePropertyError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -83,6 +93,8 @@ pub enum EntityTypeAddExtensionError {
     EntityTypeDoesNotExist(EntityTypeId),
     #[error("Failed to add extension {0}")]
     AddExtensionError(#[from] AddExtensionError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -99,6 +111,8 @@ pub enum EntityTypeRemoveExtensionError {
     EntityTypeDoesNotExist(EntityTypeId),
     #[error("Failed to remove extension {0}")]
     RemoveExtensionError(#[from] RemoveExtensionError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -18,6 +18,8 @@ use thiserror::Error;
 pub enum FlowTypeUpdateError {
     #[error("The flow type {0} does not exist")]
     FlowTypeDoesNotExist(FlowTypeId),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -30,6 +32,8 @@ pub enum FlowTypeAddEntityInstanceError {
     FlowTypeDoesNotExist(FlowTypeId),
     #[error("Failed to add entity instance {0}")]
     AddEntityInstanceError(AddEntityInstanceError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -38,6 +42,8 @@ pub enum FlowTypeUpdateEntityInstanceError {
     FlowTypeDoesNotExist(FlowTypeId),
     #[error("Failed to update entity instance {0}")]
     UpdateEntityInstanceError(UpdateEntityInstanceError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -46,6 +52,8 @@ pub enum FlowTypeRemoveEntityInstanceError {
     FlowTypeDoesNotExist(FlowTypeId),
     #[error("Failed to remove entity instance {0}")]
     RemoveEntityInstanceError(RemoveEntityInstanceError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -78,6 +86,8 @@ pub enum FlowTypeAddVariableError {
     FlowTypeDoesNotExist(FlowTypeId),
     #[error("Failed to add variable {0}")]
     AddVariableError(AddVariableError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -86,6 +96,8 @@ pub enum FlowTypeUpdateVariableError {
     FlowTypeDoesNotExist(FlowTypeId),
     #[error("Failed to update variable {0}")]
     UpdateVariableError(UpdateVariableError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -94,6 +106,8 @@ pub enum FlowTypeRemoveVariableError {
     FlowTypeDoesNotExist(FlowTypeId),
     #[error("Failed to remove variable {0}")]
     RemoveVariableError(RemoveVariableError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -108,6 +122,8 @@ pub enum FlowTypeAddExtensionError {
     FlowTypeDoesNotExist(FlowTypeId),
     #[error("Failed to add extension {0}")]
     AddExtensionError(AddExtensionError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -116,6 +132,8 @@ pub enum FlowTypeUpdateExtensionError {
     FlowTypeDoesNotExist(FlowTypeId),
     #[error("Failed to update extension {0}")]
     UpdateExtensionError(UpdateExtensionError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -124,6 +142,8 @@ pub enum FlowTypeRemoveExtensionError {
     FlowTypeDoesNotExist(FlowTypeId),
     #[error("Failed to remove extension {0}")]
     RemoveExtensionError(RemoveExtensionError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -0,0 +1,140 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use typed_builder::TypedBuilder;
+
+/// The metadata of a namespace.
+///
+/// Namespaces which are only derived from the type ids have no metadata besides their name.
+/// Namespaces are ordered by their name first.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, JsonSchema, TypedBuilder)]
+pub struct Namespace {
+    /// The name of the namespace.
+    #[builder(setter(into))]
+    pub name: String,
+
+    /// Textual description of the namespace.
+    #[serde(default = "String::new")]
+    #[builder(default, setter(into))]
+    pub description: String,
+
+    /// The name of the plugin which owns the namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option, into))]
+    pub owner: Option<String>,
+
+    /// The version of the types of the namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option, into))]
+    pub version: Option<String>,
+
+    /// The tags of the namespace.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[builder(default)]
+    pub tags: Vec<String>,
+
+    /// Links to the documentation of the namespace.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[builder(default)]
+    pub documentation: Vec<String>,
+}
+
+impl Namespace {
+    /// Constructs a namespace without metadata.
+    pub fn new<N: Into<String>>(name: N) -> Namespace {
+        Namespace {
+            name: name.into(),
+            description: String::new(),
+            owner: None,
+            version: None,
+            tags: Vec::new(),
+            documentation: Vec::new(),
+        }
+    }
+
+    /// Returns true, if the namespace is owned by the given plugin.
+    pub fn is_owned_by(&self, owner: &str) -> bool {
+        self.owner.as_deref() == Some(owner)
+    }
+
+    /// Returns true, if the namespace has the given tag.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+}
+
+impl Display for Namespace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", &self.name)
+    }
+}
+
+impl From<&str> for Namespace {
+    fn from(name: &str) -> Self {
+        Namespace::new(name)
+    }
+}
+
+impl From<String> for Namespace {
+    fn from(name: String) -> Self {
+        Namespace::new(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::schema_for;
+    use serde_json::json;
+
+    use crate::Namespace;
+
+    #[test]
+    fn namespace_builder_test() {
+        let namespace = Namespace::builder()
+            .name("logical")
+            .description("Logical gates")
+            .owner("reactive-graph-plugin-logical")
+            .version("0.10.0")
+            .tags(vec!["logic".to_string()])
+            .build();
+        assert_eq!("logical", namespace.name);
+        assert!(namespace.is_owned_by("reactive-graph-plugin-logical"));
+        assert!(!namespace.is_owned_by("reactive-graph-plugin-arithmetic"));
+        assert!(namespace.has_tag("logic"));
+        assert_eq!(Namespace::new("logical"), Namespace::from("logical"));
+    }
+
+    #[test]
+    fn namespace_ord_test() {
+        let described = Namespace::builder().name("logical").description("Logical gates").build();
+        assert_ne!(Namespace::new("logical"), described);
+        assert!(Namespace::new("arithmetic") < Namespace::new("logical"));
+        assert!(Namespace::new("logical") < described);
+    }
+
+    #[test]
+    fn namespace_ser_test() {
+        let namespace = Namespace::builder().name("logical").owner("reactive-graph-plugin-logical").build();
+        assert_eq!(
+            json!({
+                "name": "logical",
+                "description": "",
+                "owner": "reactive-graph-plugin-logical"
+            }),
+            serde_json::to_value(&namespace).unwrap()
+        );
+        let namespace: Namespace = serde_json::from_value(json!({ "name": "arithmetic", "tags": ["math"] })).unwrap();
+        assert_eq!("arithmetic", namespace.name);
+        assert!(namespace.owner.is_none());
+        assert!(namespace.has_tag("math"));
+    }
+
+    #[test]
+    fn namespace_json_schema() {
+        let schema = schema_for!(Namespace);
+        println!("{}", serde_json::to_string_pretty(&schema).unwrap());
+    }
+}
@@ -12,6 +12,8 @@ use thiserror::Error;
 pub enum RelationTypeUpdateError {
     #[error("The relation type {0} does not exist")]
     RelationTypeDoesNotExist(RelationTypeId),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -28,6 +30,8 @@ pub enum RelationTypeAddComponentError {
     ComponentDoesNotExist(ComponentTypeId),
     #[error("The relation type is already a component {0}")]
     IsAlreadyA(ComponentTypeId),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -44,6 +48,8 @@ pub enum RelationTypeRemoveComponentError {
     RelationTypeDoesNotExist(RelationTypeId),
     #[error("The relation type is not a {0}")]
     IsNotA(ComponentTypeId),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -52,6 +58,8 @@ pub enum RelationTypeAddPropertyError {
     RelationTypeDoesNotExist(RelationTypeId),
     #[error("Failed to add property {0}")]
     AddPropertyError(AddPropertyError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -60,6 +68,8 @@ pub enum RelationTypeUpdatePropertyError {
     RelationTypeDoesNotExist(RelationTypeId),
     #[error("Failed to update property {0}")]
     UpdatePropertyError(UpdatePropertyError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -68,6 +78,8 @@ pub enum RelationTypeRemovePropertyError {
     RelationTypeDoesNotExist(RelationTypeId),
     #[error("Failed to remove property {0}")]
     RemovePropertyError(RemovePropertyError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -82,6 +94,8 @@ pub enum RelationTypeAddExtensionError {
     RelationTypeDoesNotExist(RelationTypeId),
     #[error("Failed to add extension {0}")]
     AddExtensionError(AddExtensionError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -90,6 +104,8 @@ pub enum RelationTypeUpdateExtensionError {
     RelationTypeDoesNotExist(RelationTypeId),
     #[error("Failed to update extension {0}")]
     UpdateExtensionError(UpdateExtensionError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -98,6 +114,8 @@ pub enum RelationTypeRemoveExtensionError {
     RelationTypeDoesNotExist(RelationTypeId),
     #[error("Failed to remove extension {0}")]
     RemoveExtensionError(RemoveExtensionError),
+    #[error("{0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
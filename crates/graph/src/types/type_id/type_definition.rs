@@ -16,7 +16,7 @@ use crate::TypeIdType;
 use crate::TypeIdTypeParseError;
 
 /// Definition of a type with the type of the type, the namespace and the name of the type.
-#[derive(Clone, Debug, PartialEq, Eq, JsonSchema, TypedBuilder)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, JsonSchema, TypedBuilder)]
 pub struct TypeDefinition {
     pub type_id_type: TypeIdType,
     pub namespace: String,
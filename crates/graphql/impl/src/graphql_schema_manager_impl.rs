@@ -20,14 +20,20 @@ use reactive_graph_graphql_schema::ReactiveGraphSchema;
 use reactive_graph_graphql_schema::ReactiveGraphSubscription;
 use reactive_graph_graphql_schema::directives;
 use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_reactive_service_api::GraphQueryManager;
+use reactive_graph_reactive_service_api::GraphTraversalManager;
 use reactive_graph_reactive_service_api::ReactiveEntityManager;
 use reactive_graph_reactive_service_api::ReactiveFlowManager;
+use reactive_graph_reactive_service_api::ReactiveInstanceEventManager;
 use reactive_graph_reactive_service_api::ReactiveRelationManager;
+use reactive_graph_reactive_service_api::SavedQueryManager;
+use reactive_graph_reactive_service_api::SearchManager;
 use reactive_graph_type_system_api::ComponentManager;
 use reactive_graph_type_system_api::EntityTypeManager;
 use reactive_graph_type_system_api::FlowTypeManager;
 use reactive_graph_type_system_api::NamespaceManager;
 use reactive_graph_type_system_api::RelationTypeManager;
+use reactive_graph_type_system_api::TypeDependencyManager;
 
 #[derive(Component)]
 pub struct GraphQLSchemaManagerImpl {
@@ -41,12 +47,24 @@ pub struct GraphQLSchemaManagerImpl {
 
     namespace_manager: Arc<dyn NamespaceManager + Send + Sync>,
 
+    type_dependency_manager: Arc<dyn TypeDependencyManager + Send + Sync>,
+
     entity_instance_manager: Arc<dyn ReactiveEntityManager + Send + Sync>,
 
     relation_instance_manager: Arc<dyn ReactiveRelationManager + Send + Sync>,
 
     flow_instance_manager: Arc<dyn ReactiveFlowManager + Send + Sync>,
 
+    reactive_instance_event_manager: Arc<dyn ReactiveInstanceEventManager + Send + Sync>,
+
+    graph_query_manager: Arc<dyn GraphQueryManager + Send + Sync>,
+
+    graph_traversal_manager: Arc<dyn GraphTraversalManager + Send + Sync>,
+
+    search_manager: Arc<dyn SearchManager + Send + Sync>,
+
+    saved_query_manager: Arc<dyn SavedQueryManager + Send + Sync>,
+
     entity_behaviour_registry: Arc<dyn EntityBehaviourRegistry + Send + Sync>,
 
     entity_component_behaviour_registry: Arc<dyn EntityComponentBehaviourRegistry + Send + Sync>,
@@ -78,9 +96,15 @@ impl GraphQLSchemaManager for GraphQLSchemaManagerImpl {
             .data(self.relation_type_manager.clone())
             .data(self.flow_type_manager.clone())
             .data(self.namespace_manager.clone())
+            .data(self.type_dependency_manager.clone())
             .data(self.entity_instance_manager.clone())
             .data(self.relation_instance_manager.clone())
             .data(self.flow_instance_manager.clone())
+            .data(self.reactive_instance_event_manager.clone())
+            .data(self.graph_query_manager.clone())
+            .data(self.graph_traversal_manager.clone())
+            .data(self.search_manager.clone())
+            .data(self.saved_query_manager.clone())
             .data(self.entity_behaviour_registry.clone())
             .data(self.entity_component_behaviour_registry.clone())
             .data(self.relation_behaviour_registry.clone())
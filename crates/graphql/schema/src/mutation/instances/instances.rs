@@ -3,6 +3,7 @@ use async_graphql::*;
 use crate::mutation::MutationEntityInstances;
 use crate::mutation::MutationFlowInstances;
 use crate::mutation::MutationRelationInstances;
+use crate::mutation::MutationSavedQueries;
 
 #[derive(Default)]
 pub struct MutationInstances;
@@ -24,4 +25,9 @@ impl MutationInstances {
     async fn flows(&self) -> MutationFlowInstances {
         MutationFlowInstances
     }
+
+    /// Mutations on saved queries.
+    async fn saved_queries(&self) -> MutationSavedQueries {
+        MutationSavedQueries
+    }
 }
@@ -6,6 +6,7 @@ pub use instances::*;
 pub use relation_instance::*;
 pub use relation_instance_definition::*;
 pub use relation_instance_id::*;
+pub use saved_query::*;
 
 pub mod entity_instance;
 pub mod entity_instance_definition;
@@ -16,3 +17,4 @@ pub mod instances;
 pub mod relation_instance;
 pub mod relation_instance_definition;
 pub mod relation_instance_id;
+pub mod saved_query;
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use async_graphql::*;
+
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::PropertyFilter;
+use reactive_graph_graph::PropertySort;
+use reactive_graph_graph::SavedQuery;
+use reactive_graph_reactive_service_api::SavedQueryManager;
+use reactive_graph_type_system_api::EntityTypeManager;
+
+use crate::mutation::EntityTypeIdDefinition;
+use crate::query::GraphQLPropertyFilter;
+use crate::query::GraphQLPropertySort;
+use crate::query::GraphQLSavedQuery;
+
+#[derive(Default)]
+pub struct MutationSavedQueries;
+
+/// Mutation of saved queries.
+#[Object]
+impl MutationSavedQueries {
+    /// Registers a saved query. The result set of the saved query is materialized and kept up
+    /// to date until the saved query is unregistered.
+    #[allow(clippy::too_many_arguments)]
+    async fn register(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "The unique name of the saved query.")] name: String,
+        #[graphql(desc = "Textual description of the saved query.")] description: Option<String>,
+        #[graphql(name = "type", desc = "Only entity instances of the given type are included.")] entity_ty: Option<EntityTypeIdDefinition>,
+        #[graphql(desc = "Only entity instances matching the filter are included.")] filter: Option<GraphQLPropertyFilter>,
+        #[graphql(desc = "The order of the results.")] sort: Option<Vec<GraphQLPropertySort>>,
+        #[graphql(desc = "The properties which are included in the results. If not given, all properties are included.")] projection: Option<Vec<String>>,
+    ) -> Result<GraphQLSavedQuery> {
+        let saved_query_manager = context.data::<Arc<dyn SavedQueryManager + Send + Sync>>()?;
+        let entity_type_manager = context.data::<Arc<dyn EntityTypeManager + Send + Sync>>()?;
+        let ty: Option<EntityTypeId> = entity_ty.map(|ty| ty.into());
+        if let Some(ty) = ty.as_ref().filter(|ty| !entity_type_manager.has(ty)) {
+            return Err(Error::new(format!("Entity type {ty} does not exist")));
+        }
+        let saved_query = SavedQuery {
+            name,
+            description: description.unwrap_or_default(),
+            ty,
+            filter: filter.map(PropertyFilter::from),
+            sort: sort.unwrap_or_default().into_iter().map(PropertySort::from).collect(),
+            projection: projection.unwrap_or_default(),
+        };
+        Ok(saved_query_manager.register(saved_query)?.into())
+    }
+
+    /// Unregisters the saved query with the given name.
+    async fn unregister(&self, context: &Context<'_>, #[graphql(desc = "The name of the saved query.")] name: String) -> Result<bool> {
+        let saved_query_manager = context.data::<Arc<dyn SavedQueryManager + Send + Sync>>()?;
+        Ok(saved_query_manager.unregister(&name))
+    }
+}
@@ -11,11 +11,13 @@ use reactive_graph_graph::ComponentAddExtensionError;
 use reactive_graph_graph::ComponentAddPropertyError;
 use reactive_graph_graph::ComponentRemoveExtensionError;
 use reactive_graph_graph::ComponentRemovePropertyError;
+use reactive_graph_graph::ComponentTypeId;
 use reactive_graph_graph::ComponentUpdateError;
 use reactive_graph_graph::ComponentUpdateExtensionError;
 use reactive_graph_graph::ComponentUpdatePropertyError;
 use reactive_graph_graph::RemoveExtensionError;
 use reactive_graph_graph::RemovePropertyError;
+use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_graph::UpdateExtensionError;
 use reactive_graph_graph::UpdatePropertyError;
 use reactive_graph_type_system_api::ComponentManager;
@@ -23,7 +25,9 @@ use reactive_graph_type_system_api::ComponentRegistrationError;
 
 use crate::mutation::ComponentTypeIdDefinition;
 use crate::mutation::ExtensionTypeIdDefinition;
+use crate::mutation::GraphQLTypeDeletePolicy;
 use crate::mutation::PropertyTypeDefinition;
+use crate::mutation::types::type_delete_policy::delete_with_policy;
 use crate::query::GraphQLComponent;
 use crate::query::GraphQLExtension;
 
@@ -94,6 +98,7 @@ impl MutationComponents {
             Err(ComponentUpdateError::ComponentDoesNotExist(ty)) => {
                 Err(Error::new(format!("Failed to update description of component {ty}: Component does not exist")))
             }
+            Err(ComponentUpdateError::PermissionDenied(reason)) => Err(Error::new(format!("Failed to update description of component {ty}: {reason}"))),
         }
     }
 
@@ -117,6 +122,7 @@ impl MutationComponents {
             Err(ComponentAddPropertyError::AddPropertyError(AddPropertyError::PropertyAlreadyExist(property_name))) => {
                 Err(Error::new(format!("Failed to add property to component {ty}: Property {property_name} already exists")))
             }
+            Err(ComponentAddPropertyError::PermissionDenied(reason)) => Err(Error::new(format!("Failed to add property to component {ty}: {reason}"))),
         }
     }
 
@@ -141,6 +147,7 @@ impl MutationComponents {
             Err(ComponentUpdatePropertyError::UpdatePropertyError(UpdatePropertyError::PropertyDoesNotExist(property_name))) => {
                 Err(Error::new(format!("Failed to update property of component {ty}: Property {property_name} does not exist")))
             }
+            Err(ComponentUpdatePropertyError::PermissionDenied(reason)) => Err(Error::new(format!("Failed to update property of component {ty}: {reason}"))),
         }
     }
 
@@ -164,6 +171,7 @@ impl MutationComponents {
             Err(ComponentRemovePropertyError::RemovePropertyError(RemovePropertyError::PropertyDoesNotExist(property_name))) => {
                 Err(Error::new(format!("Failed to remove property of component {ty}: Property {property_name} does not exist")))
             }
+            Err(ComponentRemovePropertyError::PermissionDenied(reason)) => Err(Error::new(format!("Failed to remove property of component {ty}: {reason}"))),
         }
     }
 
@@ -187,6 +195,7 @@ impl MutationComponents {
             Err(ComponentAddExtensionError::AddExtensionError(AddExtensionError::ExtensionAlreadyExist(extension_ty))) => {
                 Err(Error::new(format!("Failed to add extension to component {ty}: Extension {extension_ty} already exists")))
             }
+            Err(ComponentAddExtensionError::PermissionDenied(reason)) => Err(Error::new(format!("Failed to add extension to component {ty}: {reason}"))),
         }
     }
 
@@ -212,6 +221,9 @@ impl MutationComponents {
             Err(ComponentUpdateExtensionError::UpdateExtensionError(UpdateExtensionError::ExtensionDoesNotExist(extension_ty))) => Err(Error::new(format!(
                 "Failed to update extension of component {component_ty}: Extension {extension_ty} does not exist"
             ))),
+            Err(ComponentUpdateExtensionError::PermissionDenied(reason)) => {
+                Err(Error::new(format!("Failed to update extension of component {component_ty}: {reason}")))
+            }
         }
     }
 
@@ -236,12 +248,21 @@ impl MutationComponents {
             Err(ComponentRemoveExtensionError::RemoveExtensionError(RemoveExtensionError::ExtensionDoesNotExist(extension_ty))) => {
                 Err(Error::new(format!("Failed to remove extension of component {ty}: Extension {extension_ty} does not exist")))
             }
+            Err(ComponentRemoveExtensionError::PermissionDenied(reason)) => Err(Error::new(format!("Failed to remove extension of component {ty}: {reason}"))),
         }
     }
 
     /// Deletes the component with the given name.
-    async fn delete(&self, context: &Context<'_>, #[graphql(name = "type")] ty: ComponentTypeIdDefinition) -> Result<bool> {
-        let component_manager = context.data::<Arc<dyn ComponentManager + Send + Sync>>()?;
-        Ok(component_manager.delete(&ty.into()))
+    ///
+    /// The types and instances which are referencing the component are handled according to the
+    /// policy. By default, the component isn't deleted as long as it is referenced.
+    async fn delete(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type")] ty: ComponentTypeIdDefinition,
+        policy: Option<GraphQLTypeDeletePolicy>,
+    ) -> Result<bool> {
+        let ty: ComponentTypeId = ty.into();
+        delete_with_policy(context, &ty.type_definition(), policy.unwrap_or_default())
     }
 }
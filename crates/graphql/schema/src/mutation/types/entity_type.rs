@@ -17,14 +17,17 @@ use reactive_graph_graph::EntityTypeUpdateError;
 use reactive_graph_graph::EntityTypeUpdateExtensionError;
 use reactive_graph_graph::EntityTypeUpdatePropertyError;
 use reactive_graph_graph::Extension;
+use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_type_system_api::EntityTypeManager;
 
 use crate::mutation::ComponentTypeIdDefinition;
 use crate::mutation::ComponentTypeIdDefinitions;
 use crate::mutation::EntityTypeIdDefinition;
 use crate::mutation::ExtensionTypeIdDefinition;
+use crate::mutation::GraphQLTypeDeletePolicy;
 use crate::mutation::PropertyTypeDefinition;
 use crate::mutation::PropertyTypeDefinitions;
+use crate::mutation::types::type_delete_policy::delete_with_policy;
 use crate::query::GraphQLEntityType;
 use crate::query::GraphQLExtension;
 use crate::query::GraphQLExtensions;
@@ -79,6 +82,7 @@ impl MutationEntityTypes {
             Err(EntityTypeUpdateError::EntityTypeDoesNotExist(ty)) => {
                 Err(Error::new(format!("Failed to update description of entity type {ty}: Entity type does not exist")))
             }
+            Err(EntityTypeUpdateError::PermissionDenied(reason)) => Err(Error::new(format!("Failed to update description of entity type {ty}: {reason}"))),
         }
     }
 
@@ -234,8 +238,16 @@ impl MutationEntityTypes {
     }
 
     /// Deletes the entity type with the given name.
-    async fn delete(&self, context: &Context<'_>, #[graphql(name = "type")] ty: EntityTypeIdDefinition) -> Result<bool> {
-        let entity_type_manager = context.data::<Arc<dyn EntityTypeManager + Send + Sync>>()?;
-        Ok(entity_type_manager.delete(&ty.into()).is_some())
+    ///
+    /// The types and instances which are referencing the entity type are handled according to the
+    /// policy. By default, the entity type isn't deleted as long as it is referenced.
+    async fn delete(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type")] ty: EntityTypeIdDefinition,
+        policy: Option<GraphQLTypeDeletePolicy>,
+    ) -> Result<bool> {
+        let ty: EntityTypeId = ty.into();
+        delete_with_policy(context, &ty.type_definition(), policy.unwrap_or_default())
     }
 }
@@ -12,8 +12,10 @@ use crate::mutation::GraphQLEntityInstanceDefinition;
 use crate::mutation::GraphQLEntityInstanceDefinitions;
 use crate::mutation::GraphQLRelationInstanceDefinition;
 use crate::mutation::GraphQLRelationInstanceDefinitions;
+use crate::mutation::GraphQLTypeDeletePolicy;
 use crate::mutation::PropertyTypeDefinition;
 use crate::mutation::PropertyTypeDefinitions;
+use crate::mutation::types::type_delete_policy::delete_with_policy;
 use crate::query::GraphQLExtension;
 use crate::query::GraphQLExtensions;
 use crate::query::GraphQLFlowType;
@@ -30,6 +32,7 @@ use reactive_graph_graph::FlowTypeUpdateEntityInstanceError;
 use reactive_graph_graph::FlowTypeUpdateError;
 use reactive_graph_graph::FlowTypeUpdateExtensionError;
 use reactive_graph_graph::FlowTypeUpdateVariableError;
+use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_type_system_api::FlowTypeManager;
 
 #[derive(Default)]
@@ -87,6 +90,7 @@ impl MutationFlowTypes {
             Err(FlowTypeUpdateError::FlowTypeDoesNotExist(ty)) => {
                 Err(Error::new(format!("Failed to update description of flow type {ty}: Flow type does not exist")))
             }
+            Err(FlowTypeUpdateError::PermissionDenied(reason)) => Err(Error::new(format!("Failed to update description of flow type {ty}: {reason}"))),
         }
     }
 
@@ -257,9 +261,16 @@ impl MutationFlowTypes {
     }
 
     /// Deletes the flow type with the given name.
-    async fn delete(&self, context: &Context<'_>, #[graphql(name = "type", desc = "The flow type.")] ty: FlowTypeIdDefinition) -> Result<bool> {
-        let flow_type_manager = context.data::<Arc<dyn FlowTypeManager + Send + Sync>>()?;
+    ///
+    /// The instances which are referencing the flow type are handled according to the policy.
+    /// By default, the flow type isn't deleted as long as it is referenced.
+    async fn delete(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type", desc = "The flow type.")] ty: FlowTypeIdDefinition,
+        policy: Option<GraphQLTypeDeletePolicy>,
+    ) -> Result<bool> {
         let ty: FlowTypeId = ty.into();
-        Ok(flow_type_manager.delete(&ty).is_some())
+        delete_with_policy(context, &ty.type_definition(), policy.unwrap_or_default())
     }
 }
@@ -11,6 +11,7 @@ pub use flow_type_id::*;
 pub use property_type::*;
 pub use relation_type::*;
 pub use relation_type_id::*;
+pub use type_delete_policy::*;
 pub use types::*;
 
 pub mod behaviour_type_id;
@@ -26,5 +27,6 @@ pub mod flow_type_id;
 pub mod property_type;
 pub mod relation_type;
 pub mod relation_type_id;
+pub mod type_delete_policy;
 #[allow(clippy::module_inception)]
 pub mod types;
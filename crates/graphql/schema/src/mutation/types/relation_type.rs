@@ -15,15 +15,18 @@ use reactive_graph_graph::RelationTypeRemovePropertyError;
 use reactive_graph_graph::RelationTypeUpdateError;
 use reactive_graph_graph::RelationTypeUpdateExtensionError;
 use reactive_graph_graph::RelationTypeUpdatePropertyError;
+use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_type_system_api::RelationTypeManager;
 
 use crate::mutation::ComponentOrEntityTypeIdDefinition;
 use crate::mutation::ComponentTypeIdDefinition;
 use crate::mutation::ComponentTypeIdDefinitions;
 use crate::mutation::ExtensionTypeIdDefinition;
+use crate::mutation::GraphQLTypeDeletePolicy;
 use crate::mutation::PropertyTypeDefinition;
 use crate::mutation::PropertyTypeDefinitions;
 use crate::mutation::RelationTypeIdDefinition;
+use crate::mutation::types::type_delete_policy::delete_with_policy;
 use crate::query::GraphQLExtension;
 use crate::query::GraphQLExtensions;
 use crate::query::GraphQLRelationType;
@@ -90,6 +93,7 @@ impl MutationRelationTypes {
             Err(RelationTypeUpdateError::RelationTypeDoesNotExist(ty)) => {
                 Err(Error::new(format!("Failed to update description of relation type {ty}: Relation type does not exist")))
             }
+            Err(RelationTypeUpdateError::PermissionDenied(reason)) => Err(Error::new(format!("Failed to update description of relation type {ty}: {reason}"))),
         }
     }
 
@@ -246,8 +250,16 @@ impl MutationRelationTypes {
     }
 
     /// Deletes the relation type with the given name.
-    async fn delete(&self, context: &Context<'_>, #[graphql(name = "type")] relation_type: RelationTypeIdDefinition) -> Result<bool> {
-        let relation_type_manager = context.data::<Arc<dyn RelationTypeManager + Send + Sync>>()?;
-        Ok(relation_type_manager.delete(&relation_type.into()).is_some())
+    ///
+    /// The types and instances which are referencing the relation type are handled according to the
+    /// policy. By default, the relation type isn't deleted as long as it is referenced.
+    async fn delete(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type")] relation_type: RelationTypeIdDefinition,
+        policy: Option<GraphQLTypeDeletePolicy>,
+    ) -> Result<bool> {
+        let ty: RelationTypeId = relation_type.into();
+        delete_with_policy(context, &ty.type_definition(), policy.unwrap_or_default())
     }
 }
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use async_graphql::Context;
+use async_graphql::Enum;
+use async_graphql::Error;
+use async_graphql::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use strum::Display;
+
+use reactive_graph_graph::TypeDefinition;
+use reactive_graph_type_system_api::TypeDependencyManager;
+
+/// Controls what happens with the types and the instances which are referencing a deleted type.
+#[derive(Enum, Serialize, Deserialize, Copy, Clone, Debug, Default, Eq, PartialEq, Display)]
+#[graphql(name = "TypeDeletePolicy", remote = "reactive_graph_type_system_api::TypeDeletePolicy")]
+pub enum GraphQLTypeDeletePolicy {
+    /// Refuses to delete a type which is still referenced by other types or by instances.
+    #[default]
+    Refuse,
+
+    /// Deletes the type together with all types and instances which are referencing the type.
+    Cascade,
+
+    /// Deletes the type only. Types and instances which are referencing the type are left as orphans.
+    Orphan,
+}
+
+/// Deletes the given type using the type dependency manager.
+pub(crate) fn delete_with_policy(context: &Context<'_>, ty: &TypeDefinition, policy: GraphQLTypeDeletePolicy) -> Result<bool> {
+    let type_dependency_manager = context.data::<Arc<dyn TypeDependencyManager + Send + Sync>>()?;
+    type_dependency_manager
+        .delete(ty, policy.into())
+        .map(|report| !report.deleted_types.is_empty())
+        .map_err(|e| Error::new(e.to_string()))
+}
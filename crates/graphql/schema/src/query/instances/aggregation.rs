@@ -0,0 +1,121 @@
+use async_graphql::Enum;
+use async_graphql::InputObject;
+use async_graphql::Object;
+use async_graphql::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use strum::Display;
+
+use reactive_graph_graph::AggregationGroup;
+use reactive_graph_graph::AggregationGroupBy;
+use reactive_graph_graph::PropertyAggregate;
+
+/// The kind of the grouping of an aggregation.
+#[derive(Enum, Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Display)]
+#[graphql(name = "AggregationGroupKind")]
+pub enum GraphQLAggregationGroupKind {
+    /// Groups the instances by their type.
+    Type,
+
+    /// Groups the instances by their components.
+    Component,
+
+    /// Groups the instances by the value of a property.
+    Property,
+}
+
+/// Defines how instances are grouped by an aggregation.
+#[derive(Clone, Debug, InputObject)]
+#[graphql(name = "AggregationGroupBy")]
+pub struct GraphQLAggregationGroupBy {
+    /// The kind of the grouping.
+    pub kind: GraphQLAggregationGroupKind,
+
+    /// The name of the property to group by. Required, if the kind is PROPERTY.
+    pub property: Option<String>,
+}
+
+impl TryFrom<GraphQLAggregationGroupBy> for AggregationGroupBy {
+    type Error = async_graphql::Error;
+
+    fn try_from(group_by: GraphQLAggregationGroupBy) -> Result<Self> {
+        match (group_by.kind, group_by.property) {
+            (GraphQLAggregationGroupKind::Type, _) => Ok(AggregationGroupBy::Type),
+            (GraphQLAggregationGroupKind::Component, _) => Ok(AggregationGroupBy::Component),
+            (GraphQLAggregationGroupKind::Property, Some(property)) => Ok(AggregationGroupBy::Property(property)),
+            (GraphQLAggregationGroupKind::Property, None) => Err("Grouping by property requires the name of the property".into()),
+        }
+    }
+}
+
+/// The aggregated numeric values of a property.
+pub struct GraphQLPropertyAggregate {
+    aggregate: PropertyAggregate,
+}
+
+/// The aggregated numeric values of a property.
+#[Object(name = "PropertyAggregate")]
+impl GraphQLPropertyAggregate {
+    /// The number of numeric values.
+    async fn count(&self) -> usize {
+        self.aggregate.count()
+    }
+
+    /// The sum of the values.
+    async fn sum(&self) -> f64 {
+        self.aggregate.sum()
+    }
+
+    /// The smallest value.
+    async fn min(&self) -> Option<f64> {
+        self.aggregate.min()
+    }
+
+    /// The largest value.
+    async fn max(&self) -> Option<f64> {
+        self.aggregate.max()
+    }
+
+    /// The arithmetic mean of the values.
+    async fn avg(&self) -> Option<f64> {
+        self.aggregate.avg()
+    }
+
+    /// The percentile of the values.
+    async fn percentile(&self, #[graphql(desc = "The percentile between 0 and 100.")] percentile: f64) -> Option<f64> {
+        self.aggregate.percentile(percentile)
+    }
+}
+
+/// A group of instances with the aggregated property values of the group.
+pub struct GraphQLAggregationGroup {
+    group: AggregationGroup,
+}
+
+/// A group of instances with the aggregated property values of the group.
+#[Object(name = "AggregationGroup")]
+impl GraphQLAggregationGroup {
+    /// The value the instances are grouped by. Null, if the instances are not grouped.
+    async fn key(&self) -> Value {
+        self.group.key.clone()
+    }
+
+    /// The number of instances in the group.
+    async fn count(&self) -> usize {
+        self.group.count
+    }
+
+    /// The aggregated values of the property.
+    async fn aggregate(&self) -> GraphQLPropertyAggregate {
+        GraphQLPropertyAggregate {
+            aggregate: self.group.aggregate.clone(),
+        }
+    }
+}
+
+impl From<AggregationGroup> for GraphQLAggregationGroup {
+    fn from(group: AggregationGroup) -> Self {
+        GraphQLAggregationGroup { group }
+    }
+}
@@ -1,3 +1,4 @@
+use std::ops::Deref;
 use std::sync::Arc;
 
 use async_graphql::Context;
@@ -185,3 +186,11 @@ impl From<ReactiveEntity> for GraphQLEntityInstance {
         GraphQLEntityInstance { entity_instance }
     }
 }
+
+impl Deref for GraphQLEntityInstance {
+    type Target = ReactiveEntity;
+
+    fn deref(&self) -> &Self::Target {
+        &self.entity_instance
+    }
+}
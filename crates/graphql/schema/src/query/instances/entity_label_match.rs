@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use async_graphql::Object;
+use serde_json::Value;
+
+use reactive_graph_graph::PropertyInstanceGetter;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+use reactive_graph_runtime_model::LabeledProperties::LABEL;
+
+use crate::query::GraphQLEntityInstance;
+
+/// An entity instance whose label matches a label pattern.
+pub struct GraphQLEntityLabelMatch {
+    entity_instance: ReactiveEntity,
+    params: HashMap<String, String>,
+}
+
+/// An entity instance whose label matches a label pattern.
+#[Object(name = "EntityLabelMatch")]
+impl GraphQLEntityLabelMatch {
+    /// The label of the entity instance.
+    async fn label(&self) -> String {
+        self.entity_instance.as_string(LABEL.property_name()).unwrap_or_default()
+    }
+
+    /// The parameters which have been extracted from the label.
+    async fn params(&self) -> Value {
+        serde_json::to_value(&self.params).unwrap_or_default()
+    }
+
+    /// The matched entity instance.
+    async fn entity(&self) -> GraphQLEntityInstance {
+        self.entity_instance.clone().into()
+    }
+}
+
+impl From<(ReactiveEntity, HashMap<String, String>)> for GraphQLEntityLabelMatch {
+    fn from((entity_instance, params): (ReactiveEntity, HashMap<String, String>)) -> Self {
+        GraphQLEntityLabelMatch { entity_instance, params }
+    }
+}
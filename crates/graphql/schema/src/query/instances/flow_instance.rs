@@ -1,3 +1,4 @@
+use std::ops::Deref;
 use std::sync::Arc;
 
 use async_graphql::Context;
@@ -104,3 +105,11 @@ impl From<ReactiveFlow> for GraphQLFlowInstance {
         GraphQLFlowInstance { flow_instance: flow }
     }
 }
+
+impl Deref for GraphQLFlowInstance {
+    type Target = ReactiveFlow;
+
+    fn deref(&self) -> &Self::Target {
+        &self.flow_instance
+    }
+}
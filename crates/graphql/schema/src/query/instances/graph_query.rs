@@ -0,0 +1,41 @@
+use async_graphql::Object;
+use serde_json::Value;
+
+use reactive_graph_graph::GraphQueryPlan;
+use reactive_graph_graph::GraphQueryResult;
+
+/// The result of a graph pattern query.
+pub struct GraphQLGraphQueryResult {
+    plan: GraphQueryPlan,
+    result: GraphQueryResult,
+}
+
+impl GraphQLGraphQueryResult {
+    pub fn new(plan: GraphQueryPlan, result: GraphQueryResult) -> Self {
+        GraphQLGraphQueryResult { plan, result }
+    }
+}
+
+/// The result of a graph pattern query.
+#[Object(name = "GraphQueryResult")]
+impl GraphQLGraphQueryResult {
+    /// The names of the returned columns.
+    async fn columns(&self) -> Vec<String> {
+        self.result.columns.clone()
+    }
+
+    /// The number of matched rows.
+    async fn count(&self) -> usize {
+        self.result.rows.len()
+    }
+
+    /// The execution plan of the query.
+    async fn plan(&self) -> String {
+        self.plan.to_string()
+    }
+
+    /// The matched rows. Entity and relation instances are returned as JSON objects.
+    async fn rows(&self) -> Vec<Vec<Value>> {
+        self.result.rows.clone()
+    }
+}
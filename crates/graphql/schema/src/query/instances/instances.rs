@@ -1,3 +1,4 @@
+use std::ops::Deref;
 use std::sync::Arc;
 
 use async_graphql::Context;
@@ -7,23 +8,40 @@ use uuid::Uuid;
 
 use reactive_graph_behaviour_model_api::BehaviourTypeId;
 use reactive_graph_behaviour_model_api::BehaviourTypesContainer;
+use reactive_graph_graph::AggregationGroupBy;
 use reactive_graph_graph::ComponentContainer;
 use reactive_graph_graph::ComponentTypeId;
 use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::LabelPattern;
+use reactive_graph_graph::Pagination;
+use reactive_graph_graph::PropertyFilter;
+use reactive_graph_graph::PropertySort;
 use reactive_graph_graph::RelationTypeId;
+use reactive_graph_graph::compare_by_properties;
+use reactive_graph_reactive_model_api::ReactiveInstance;
 use reactive_graph_reactive_service_api::ReactiveEntityManager;
 use reactive_graph_reactive_service_api::ReactiveFlowManager;
 use reactive_graph_reactive_service_api::ReactiveRelationManager;
+use reactive_graph_reactive_service_api::SavedQueryManager;
 
 use crate::mutation::BehaviourTypeIdDefinition;
 use crate::mutation::ComponentTypeIdDefinition;
 use crate::mutation::EntityTypeIdDefinition;
 use crate::mutation::GraphQLRelationInstanceId;
 use crate::mutation::RelationTypeIdDefinition;
+use crate::query::GraphQLAggregationGroup;
+use crate::query::GraphQLAggregationGroupBy;
+use crate::query::GraphQLConnection;
 use crate::query::GraphQLEntityInstance;
+use crate::query::GraphQLEntityLabelMatch;
 use crate::query::GraphQLFlowInstance;
+use crate::query::GraphQLPropertyFilter;
+use crate::query::GraphQLPropertyIndexStatistics;
 use crate::query::GraphQLPropertyInstance;
+use crate::query::GraphQLPropertySort;
 use crate::query::GraphQLRelationInstance;
+use crate::query::GraphQLSavedQuery;
+use crate::query::to_connection;
 
 #[derive(Default)]
 pub struct Instances;
@@ -46,8 +64,10 @@ impl Instances {
         #[graphql(desc = "Filters the entity instances by applied components.")] components: Option<Vec<ComponentTypeIdDefinition>>,
         #[graphql(desc = "Filters the entity instances by applied behaviours.")] behaviours: Option<Vec<BehaviourTypeIdDefinition>>,
         #[graphql(name = "properties", desc = "Query by properties.")] property_query: Option<Vec<GraphQLPropertyInstance>>,
+        #[graphql(desc = "Filters the instances by properties using comparison operators and boolean combinators.")] filter: Option<GraphQLPropertyFilter>,
     ) -> Result<Vec<GraphQLEntityInstance>> {
         let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityManager + Send + Sync>>()?;
+        let filter = filter.map(|filter| PropertyFilter::from(filter).compile()).transpose()?;
         if let Some(id) = id {
             let entity_instance = entity_instance_manager.get(id).map(|entity_instance| {
                 let entity_instance: GraphQLEntityInstance = entity_instance.into();
@@ -70,8 +90,12 @@ impl Instances {
                 Ok(Vec::new())
             };
         }
-        let entities = entity_instance_manager
-            .get_all()
+        // Uses the property indexes of the entity type, if available
+        let entity_instances = match (&entity_type, &filter) {
+            (Some(entity_type), Some(filter)) => entity_instance_manager.get_by_type_and_filter(&entity_type.clone().into(), filter),
+            _ => entity_instance_manager.get_all(),
+        };
+        let entities = entity_instances
             .iter()
             .filter(|entity_instance| entity_type.is_none() || entity_instance.ty == EntityTypeId::from(entity_type.clone().unwrap()))
             .filter(|entity_instance| {
@@ -103,6 +127,7 @@ impl Instances {
                         })
                 }
             })
+            .filter(|entity_instance| filter.as_ref().map(|filter| filter.matches(*entity_instance)).unwrap_or(true))
             .map(|entity_instance| {
                 let entity_instance: GraphQLEntityInstance = entity_instance.clone().into();
                 entity_instance
@@ -111,6 +136,34 @@ impl Instances {
         Ok(entities)
     }
 
+    /// Search for entity instances and returns the requested page as connection.
+    ///
+    /// The entity instances are sorted by the given properties and by id.
+    #[allow(clippy::too_many_arguments)]
+    async fn entities_connection(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "Returns only the entity instance with the given id.")] id: Option<Uuid>,
+        #[graphql(desc = "Returns the entity instance with the given label.")] label: Option<String>,
+        #[graphql(name = "type", desc = "Filters the entity instances by type.")] entity_type: Option<EntityTypeIdDefinition>,
+        #[graphql(desc = "Filters the entity instances by applied components.")] components: Option<Vec<ComponentTypeIdDefinition>>,
+        #[graphql(desc = "Filters the entity instances by applied behaviours.")] behaviours: Option<Vec<BehaviourTypeIdDefinition>>,
+        #[graphql(name = "properties", desc = "Query by properties.")] property_query: Option<Vec<GraphQLPropertyInstance>>,
+        #[graphql(desc = "Filters the instances by properties using comparison operators and boolean combinators.")] filter: Option<GraphQLPropertyFilter>,
+        #[graphql(desc = "Sorts the instances by the values of the given properties.")] sort: Option<Vec<GraphQLPropertySort>>,
+        #[graphql(desc = "Returns the first n instances.")] first: Option<usize>,
+        #[graphql(desc = "Returns the instances after the given cursor.")] after: Option<String>,
+        #[graphql(desc = "Returns the last n instances.")] last: Option<usize>,
+        #[graphql(desc = "Returns the instances before the given cursor.")] before: Option<String>,
+    ) -> Result<GraphQLConnection<GraphQLEntityInstance>> {
+        let mut entities = self
+            .entities(context, id, label, entity_type, components, behaviours, property_query, filter)
+            .await?;
+        let sort = property_sorts(sort);
+        entities.sort_by(|a, b| compare_by_properties(a.deref(), b.deref(), &sort).then_with(|| a.deref().id.cmp(&b.deref().id)));
+        to_connection(entities, &Pagination::new(first, after, last, before))
+    }
+
     async fn count_entity_instances(
         &self,
         context: &Context<'_>,
@@ -148,6 +201,24 @@ impl Instances {
         Ok(count)
     }
 
+    /// Returns the entity instances whose label matches the given label pattern, ordered by label.
+    ///
+    /// `*` (or `+`) matches exactly one segment, `**` (or `#`) matches any number of segments.
+    /// Named segments (`:name` or `{name}`) are returned as parameters.
+    async fn entities_by_label(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "The label pattern, for example /org/inexor/sensors/*/temperature")] pattern: String,
+    ) -> Result<Vec<GraphQLEntityLabelMatch>> {
+        let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityManager + Send + Sync>>()?;
+        let pattern = LabelPattern::parse(&pattern)?;
+        Ok(entity_instance_manager
+            .get_by_label_pattern(&pattern)
+            .into_iter()
+            .map(GraphQLEntityLabelMatch::from)
+            .collect())
+    }
+
     /// Search for relations instances.
     ///
     /// Relation instances can be searched by relation type name, the entity type of the outbound
@@ -177,8 +248,10 @@ impl Instances {
         #[graphql(desc = "Filters the relation instances by applied components.")] components: Option<Vec<ComponentTypeIdDefinition>>,
         #[graphql(desc = "Filters the relation instances by applied behaviours.")] behaviours: Option<Vec<BehaviourTypeIdDefinition>>,
         #[graphql(name = "properties", desc = "Query by properties.")] property_query: Option<Vec<GraphQLPropertyInstance>>,
+        #[graphql(desc = "Filters the instances by properties using comparison operators and boolean combinators.")] filter: Option<GraphQLPropertyFilter>,
     ) -> Result<Vec<GraphQLRelationInstance>> {
         let relation_instance_manager = context.data::<Arc<dyn ReactiveRelationManager + Send + Sync>>()?;
+        let filter = filter.map(|filter| PropertyFilter::from(filter).compile()).transpose()?;
         if let Some(id) = id {
             let id = id.into();
             let relation_instance = relation_instance_manager.get(&id).map(|relation_instance| {
@@ -201,8 +274,12 @@ impl Instances {
             components.map(|components| components.iter().cloned().map(|component_ty| component_ty.into()).collect());
         let behaviours: Option<Vec<BehaviourTypeId>> =
             behaviours.map(|behaviours| behaviours.iter().cloned().map(|behaviour_ty| behaviour_ty.into()).collect());
-        let relation_instances = relation_instance_manager
-            .get_all()
+        // Uses the property indexes of the relation type, if available
+        let relation_instances = match (&relation_ty, &filter) {
+            (Some(relation_ty), Some(filter)) => relation_instance_manager.get_by_type_and_filter(relation_ty, filter),
+            _ => relation_instance_manager.get_all(),
+        };
+        let relation_instances = relation_instances
             .iter()
             .filter(|relation_instance| outbound_entity_ty.is_none() || relation_instance.outbound.ty == outbound_entity_ty.clone().unwrap())
             .filter(|relation_instance| outbound_component_ty.is_none() || relation_instance.outbound.is_a(&outbound_component_ty.clone().unwrap()))
@@ -240,6 +317,7 @@ impl Instances {
                         })
                 }
             })
+            .filter(|relation_instance| filter.as_ref().map(|filter| filter.matches(*relation_instance)).unwrap_or(true))
             .map(|relation_instance| {
                 let relation_instance: GraphQLRelationInstance = relation_instance.clone().into();
                 relation_instance
@@ -248,6 +326,61 @@ impl Instances {
         Ok(relation_instances)
     }
 
+    /// Search for relation instances and returns the requested page as connection.
+    ///
+    /// The relation instances are sorted by the given properties and by id.
+    #[allow(clippy::too_many_arguments)]
+    async fn relations_connection(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "Returns only the relation instance with the given id.")] id: Option<GraphQLRelationInstanceId>,
+        #[graphql(desc = "Filters the relation instances by the entity type of the outbound entity instance.")] outbound_entity_ty: Option<
+            EntityTypeIdDefinition,
+        >,
+        #[graphql(desc = "Filters the relation instances by the component of the outbound entity instance.")] outbound_component_ty: Option<
+            ComponentTypeIdDefinition,
+        >,
+        #[graphql(desc = "Filters the relation instances by the id of the outbound entity instance")] outbound_id: Option<Uuid>,
+        #[graphql(name = "type", desc = "Filters the relation instances by relation type")] relation_ty: Option<RelationTypeIdDefinition>,
+        #[graphql(desc = "Filters the relation instances by the entity type of the inbound entity instance.")] inbound_entity_ty: Option<
+            EntityTypeIdDefinition,
+        >,
+        #[graphql(desc = "Filters the relation instances by the component of the inbound entity instance.")] inbound_component_ty: Option<
+            ComponentTypeIdDefinition,
+        >,
+        #[graphql(desc = "Filters the relation instances by the id of the inbound entity instance")] inbound_id: Option<Uuid>,
+        #[graphql(desc = "Filters the relation instances by applied components.")] components: Option<Vec<ComponentTypeIdDefinition>>,
+        #[graphql(desc = "Filters the relation instances by applied behaviours.")] behaviours: Option<Vec<BehaviourTypeIdDefinition>>,
+        #[graphql(name = "properties", desc = "Query by properties.")] property_query: Option<Vec<GraphQLPropertyInstance>>,
+        #[graphql(desc = "Filters the instances by properties using comparison operators and boolean combinators.")] filter: Option<GraphQLPropertyFilter>,
+        #[graphql(desc = "Sorts the instances by the values of the given properties.")] sort: Option<Vec<GraphQLPropertySort>>,
+        #[graphql(desc = "Returns the first n instances.")] first: Option<usize>,
+        #[graphql(desc = "Returns the instances after the given cursor.")] after: Option<String>,
+        #[graphql(desc = "Returns the last n instances.")] last: Option<usize>,
+        #[graphql(desc = "Returns the instances before the given cursor.")] before: Option<String>,
+    ) -> Result<GraphQLConnection<GraphQLRelationInstance>> {
+        let mut relations = self
+            .relations(
+                context,
+                id,
+                outbound_entity_ty,
+                outbound_component_ty,
+                outbound_id,
+                relation_ty,
+                inbound_entity_ty,
+                inbound_component_ty,
+                inbound_id,
+                components,
+                behaviours,
+                property_query,
+                filter,
+            )
+            .await?;
+        let sort = property_sorts(sort);
+        relations.sort_by(|a, b| compare_by_properties(a.deref(), b.deref(), &sort).then_with(|| a.deref().id().cmp(&b.deref().id())));
+        to_connection(relations, &Pagination::new(first, after, last, before))
+    }
+
     async fn count_relation_instances(
         &self,
         context: &Context<'_>,
@@ -262,6 +395,78 @@ impl Instances {
         Ok(count)
     }
 
+    /// Aggregates entity instances.
+    ///
+    /// The entity instances can be grouped by type, component or the value of a property. For
+    /// each group the numeric values of the given property are aggregated.
+    #[allow(clippy::too_many_arguments)]
+    async fn aggregate_entity_instances(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type", desc = "Aggregates the entity instances of the given type only.")] ty: Option<EntityTypeIdDefinition>,
+        #[graphql(name = "component", desc = "Aggregates the entity instances which are composed by the given component only.")] component_ty: Option<
+            ComponentTypeIdDefinition,
+        >,
+        #[graphql(desc = "Aggregates the entity instances of the given namespace only.")] namespace: Option<String>,
+        #[graphql(desc = "Filters the instances by properties using comparison operators and boolean combinators.")] filter: Option<GraphQLPropertyFilter>,
+        #[graphql(desc = "Groups the entity instances by type, component or property value.")] group_by: Option<GraphQLAggregationGroupBy>,
+        #[graphql(desc = "The name of the numeric property to aggregate.")] property: Option<String>,
+    ) -> Result<Vec<GraphQLAggregationGroup>> {
+        let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityManager + Send + Sync>>()?;
+        let ty: Option<EntityTypeId> = ty.map(|ty| ty.into());
+        let component_ty: Option<ComponentTypeId> = component_ty.map(|component_ty| component_ty.into());
+        let filter = filter.map(|filter| PropertyFilter::from(filter).compile()).transpose()?;
+        let group_by = group_by.map(AggregationGroupBy::try_from).transpose()?;
+        Ok(entity_instance_manager
+            .aggregate(
+                ty.as_ref(),
+                component_ty.as_ref(),
+                namespace.as_deref(),
+                filter.as_ref(),
+                group_by.as_ref(),
+                property.as_deref(),
+            )
+            .into_iter()
+            .map(GraphQLAggregationGroup::from)
+            .collect())
+    }
+
+    /// Aggregates relation instances.
+    ///
+    /// The relation instances can be grouped by type, component or the value of a property. For
+    /// each group the numeric values of the given property are aggregated.
+    #[allow(clippy::too_many_arguments)]
+    async fn aggregate_relation_instances(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type", desc = "Aggregates the relation instances of the given type only.")] ty: Option<RelationTypeIdDefinition>,
+        #[graphql(name = "component", desc = "Aggregates the relation instances which are composed by the given component only.")] component_ty: Option<
+            ComponentTypeIdDefinition,
+        >,
+        #[graphql(desc = "Aggregates the relation instances of the given namespace only.")] namespace: Option<String>,
+        #[graphql(desc = "Filters the instances by properties using comparison operators and boolean combinators.")] filter: Option<GraphQLPropertyFilter>,
+        #[graphql(desc = "Groups the relation instances by type, component or property value.")] group_by: Option<GraphQLAggregationGroupBy>,
+        #[graphql(desc = "The name of the numeric property to aggregate.")] property: Option<String>,
+    ) -> Result<Vec<GraphQLAggregationGroup>> {
+        let relation_instance_manager = context.data::<Arc<dyn ReactiveRelationManager + Send + Sync>>()?;
+        let ty: Option<RelationTypeId> = ty.map(|ty| ty.into());
+        let component_ty: Option<ComponentTypeId> = component_ty.map(|component_ty| component_ty.into());
+        let filter = filter.map(|filter| PropertyFilter::from(filter).compile()).transpose()?;
+        let group_by = group_by.map(AggregationGroupBy::try_from).transpose()?;
+        Ok(relation_instance_manager
+            .aggregate(
+                ty.as_ref(),
+                component_ty.as_ref(),
+                namespace.as_deref(),
+                filter.as_ref(),
+                group_by.as_ref(),
+                property.as_deref(),
+            )
+            .into_iter()
+            .map(GraphQLAggregationGroup::from)
+            .collect())
+    }
+
     /// Search for flows and their contained instances.
     async fn flows(
         &self,
@@ -302,6 +507,51 @@ impl Instances {
         Ok(flow_instances)
     }
 
+    /// Returns the saved queries and their materialized result sets.
+    async fn saved_queries(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "Returns only the saved query with the given name.")] name: Option<String>,
+    ) -> Result<Vec<GraphQLSavedQuery>> {
+        let saved_query_manager = context.data::<Arc<dyn SavedQueryManager + Send + Sync>>()?;
+        let saved_queries = match name {
+            Some(name) => saved_query_manager.get(&name).into_iter().collect(),
+            None => saved_query_manager.get_all(),
+        };
+        Ok(saved_queries.into_iter().map(GraphQLSavedQuery::from).collect())
+    }
+
+    /// Returns the statistics of the secondary property indexes of the entity and relation instances.
+    async fn property_indexes(&self, context: &Context<'_>) -> Result<Vec<GraphQLPropertyIndexStatistics>> {
+        let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityManager + Send + Sync>>()?;
+        let relation_instance_manager = context.data::<Arc<dyn ReactiveRelationManager + Send + Sync>>()?;
+        let mut statistics = entity_instance_manager.get_property_index_statistics();
+        statistics.extend(relation_instance_manager.get_property_index_statistics());
+        Ok(statistics.into_iter().map(GraphQLPropertyIndexStatistics::from).collect())
+    }
+
+    /// Search for flows and returns the requested page as connection.
+    ///
+    /// The flows are sorted by the given properties of the wrapper entity instance and by id.
+    #[allow(clippy::too_many_arguments)]
+    async fn flows_connection(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "Filters by the id of the flow")] id: Option<Uuid>,
+        #[graphql(desc = "Filters by the label of the flow")] label: Option<String>,
+        #[graphql(name = "type", desc = "Filters by the entity type of the flow instance")] entity_ty: Option<EntityTypeIdDefinition>,
+        #[graphql(desc = "Sorts the flows by the values of the given properties.")] sort: Option<Vec<GraphQLPropertySort>>,
+        #[graphql(desc = "Returns the first n flows.")] first: Option<usize>,
+        #[graphql(desc = "Returns the flows after the given cursor.")] after: Option<String>,
+        #[graphql(desc = "Returns the last n flows.")] last: Option<usize>,
+        #[graphql(desc = "Returns the flows before the given cursor.")] before: Option<String>,
+    ) -> Result<GraphQLConnection<GraphQLFlowInstance>> {
+        let mut flows = self.flows(context, id, label, entity_ty).await?;
+        let sort = property_sorts(sort);
+        flows.sort_by(|a, b| compare_by_properties(a.deref(), b.deref(), &sort).then_with(|| a.deref().id.cmp(&b.deref().id)));
+        to_connection(flows, &Pagination::new(first, after, last, before))
+    }
+
     async fn count_flow_instances(
         &self,
         context: &Context<'_>,
@@ -320,3 +570,7 @@ impl Instances {
         Ok(count)
     }
 }
+
+fn property_sorts(sort: Option<Vec<GraphQLPropertySort>>) -> Vec<PropertySort> {
+    sort.unwrap_or_default().into_iter().map(PropertySort::from).collect()
+}
@@ -1,12 +1,26 @@
+pub use aggregation::*;
 pub use entity_instance::*;
+pub use entity_label_match::*;
 pub use flow_instance::*;
+pub use graph_query::*;
 pub use instances::*;
 pub use properties::*;
+pub use property_filter::*;
+pub use property_index_statistics::*;
 pub use relation_instance::*;
+pub use saved_query::*;
+pub use search_hit::*;
 
+pub mod aggregation;
 pub mod entity_instance;
+pub mod entity_label_match;
 pub mod flow_instance;
+pub mod graph_query;
 #[allow(clippy::module_inception)]
 pub mod instances;
 pub mod properties;
+pub mod property_filter;
+pub mod property_index_statistics;
 pub mod relation_instance;
+pub mod saved_query;
+pub mod search_hit;
@@ -0,0 +1,93 @@
+use async_graphql::InputObject;
+use serde_json::Value;
+
+use reactive_graph_graph::PropertyFilter;
+
+/// Filters instances by their properties.
+///
+/// All operators of a filter are combined with `and`. The operators are applied on the property
+/// with the given name or, if a JSON path is given, on the selected value within the property.
+#[derive(Clone, Debug, Default, InputObject)]
+#[graphql(name = "PropertyFilter")]
+pub struct GraphQLPropertyFilter {
+    /// The name of the property.
+    pub name: Option<String>,
+
+    /// Selects a value within the property value (for example `$.position.x` or `tags[0]`).
+    pub path: Option<String>,
+
+    /// The value is equal to the given value.
+    pub eq: Option<Value>,
+
+    /// The value is not equal to the given value.
+    pub ne: Option<Value>,
+
+    /// The value is greater than the given value.
+    pub gt: Option<Value>,
+
+    /// The value is greater than or equal to the given value.
+    pub gte: Option<Value>,
+
+    /// The value is less than the given value.
+    pub lt: Option<Value>,
+
+    /// The value is less than or equal to the given value.
+    pub lte: Option<Value>,
+
+    /// The value is between the two given values (inclusive).
+    pub between: Option<Vec<Value>>,
+
+    /// The value is one of the given values.
+    #[graphql(name = "in")]
+    pub in_values: Option<Vec<Value>>,
+
+    /// The string contains the given string, the array contains the given element or the object
+    /// contains the given key.
+    pub contains: Option<Value>,
+
+    /// The string starts with the given string.
+    pub starts_with: Option<String>,
+
+    /// The string ends with the given string.
+    pub ends_with: Option<String>,
+
+    /// The string matches the given regular expression.
+    pub regex: Option<String>,
+
+    /// The property (or the value selected by the path) exists and is not null.
+    pub exists: Option<bool>,
+
+    /// All of the given filters matches.
+    pub and: Option<Vec<GraphQLPropertyFilter>>,
+
+    /// At least one of the given filters matches.
+    pub or: Option<Vec<GraphQLPropertyFilter>>,
+
+    /// The given filter doesn't match.
+    pub not: Option<Box<GraphQLPropertyFilter>>,
+}
+
+impl From<GraphQLPropertyFilter> for PropertyFilter {
+    fn from(filter: GraphQLPropertyFilter) -> Self {
+        PropertyFilter {
+            name: filter.name,
+            path: filter.path,
+            eq: filter.eq,
+            ne: filter.ne,
+            gt: filter.gt,
+            gte: filter.gte,
+            lt: filter.lt,
+            lte: filter.lte,
+            between: filter.between,
+            in_values: filter.in_values,
+            contains: filter.contains,
+            starts_with: filter.starts_with,
+            ends_with: filter.ends_with,
+            regex: filter.regex,
+            exists: filter.exists,
+            and: filter.and.map(|filters| filters.into_iter().map(PropertyFilter::from).collect()),
+            or: filter.or.map(|filters| filters.into_iter().map(PropertyFilter::from).collect()),
+            not: filter.not.map(|filter| Box::new(PropertyFilter::from(*filter))),
+        }
+    }
+}
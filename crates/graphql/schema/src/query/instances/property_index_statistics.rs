@@ -0,0 +1,53 @@
+use async_graphql::Object;
+
+use reactive_graph_reactive_service_api::PropertyIndexStatistics;
+
+/// The statistics of a secondary property index.
+pub struct GraphQLPropertyIndexStatistics {
+    statistics: PropertyIndexStatistics,
+}
+
+/// The statistics of a secondary property index.
+#[Object(name = "PropertyIndexStatistics")]
+impl GraphQLPropertyIndexStatistics {
+    /// The kind of the indexed type (Entity or Relation).
+    async fn kind(&self) -> String {
+        self.statistics.ty.type_definition.type_id_type.full_name()
+    }
+
+    /// The namespace of the indexed type.
+    async fn namespace(&self) -> String {
+        self.statistics.ty.type_definition.namespace.clone()
+    }
+
+    /// The name of the indexed type.
+    async fn type_name(&self) -> String {
+        self.statistics.ty.type_definition.type_name.clone()
+    }
+
+    /// The name of the indexed property.
+    async fn property(&self) -> String {
+        self.statistics.ty.property.clone()
+    }
+
+    /// The number of indexed instances.
+    async fn entries(&self) -> usize {
+        self.statistics.entries
+    }
+
+    /// The number of distinct indexed values.
+    async fn distinct_values(&self) -> usize {
+        self.statistics.distinct_values
+    }
+
+    /// The number of lookups using the index.
+    async fn lookups(&self) -> usize {
+        self.statistics.lookups
+    }
+}
+
+impl From<PropertyIndexStatistics> for GraphQLPropertyIndexStatistics {
+    fn from(statistics: PropertyIndexStatistics) -> Self {
+        GraphQLPropertyIndexStatistics { statistics }
+    }
+}
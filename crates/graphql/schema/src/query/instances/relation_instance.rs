@@ -1,3 +1,4 @@
+use std::ops::Deref;
 use std::sync::Arc;
 
 use async_graphql::Context;
@@ -165,3 +166,11 @@ impl From<ReactiveRelation> for GraphQLRelationInstance {
         GraphQLRelationInstance { relation_instance }
     }
 }
+
+impl Deref for GraphQLRelationInstance {
+    type Target = ReactiveRelation;
+
+    fn deref(&self) -> &Self::Target {
+        &self.relation_instance
+    }
+}
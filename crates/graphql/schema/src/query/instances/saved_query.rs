@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use async_graphql::Context;
+use async_graphql::Object;
+use async_graphql::Result;
+use serde_json::Value;
+use uuid::Uuid;
+
+use reactive_graph_graph::SavedQuery;
+use reactive_graph_graph::SavedQueryRow;
+use reactive_graph_reactive_service_api::ReactiveEntityManager;
+use reactive_graph_reactive_service_api::SavedQueryManager;
+use reactive_graph_type_system_api::EntityTypeManager;
+
+use crate::query::GraphQLEntityInstance;
+use crate::query::GraphQLEntityType;
+
+/// A named query over the entity instances whose result set is materialized and kept up to date.
+pub struct GraphQLSavedQuery {
+    saved_query: SavedQuery,
+}
+
+/// A named query over the entity instances whose result set is materialized and kept up to date.
+#[Object(name = "SavedQuery")]
+impl GraphQLSavedQuery {
+    /// The unique name of the saved query.
+    async fn name(&self) -> String {
+        self.saved_query.name.clone()
+    }
+
+    /// Textual description of the saved query.
+    async fn description(&self) -> String {
+        self.saved_query.description.clone()
+    }
+
+    /// Only entity instances of this type are included.
+    #[graphql(name = "type")]
+    async fn entity_type(&self, context: &Context<'_>) -> Option<GraphQLEntityType> {
+        let ty = self.saved_query.ty.as_ref()?;
+        let entity_type_manager = context.data::<Arc<dyn EntityTypeManager + Send + Sync>>().ok()?;
+        entity_type_manager.get(ty).map(|entity_type| entity_type.into())
+    }
+
+    /// The property filter.
+    async fn filter(&self) -> Option<Value> {
+        self.saved_query.filter.as_ref().and_then(|filter| serde_json::to_value(filter).ok())
+    }
+
+    /// The order of the results.
+    async fn sort(&self) -> Value {
+        serde_json::to_value(&self.saved_query.sort).unwrap_or_default()
+    }
+
+    /// The properties which are included in the results. If empty, all properties are included.
+    async fn projection(&self) -> Vec<String> {
+        self.saved_query.projection.clone()
+    }
+
+    /// The number of entity instances in the result set.
+    async fn count(&self, context: &Context<'_>) -> Result<usize> {
+        let saved_query_manager = context.data::<Arc<dyn SavedQueryManager + Send + Sync>>()?;
+        Ok(saved_query_manager.count(&self.saved_query.name).unwrap_or_default())
+    }
+
+    /// The ordered rows of the materialized result set.
+    async fn rows(&self, context: &Context<'_>) -> Result<Vec<GraphQLSavedQueryRow>> {
+        let saved_query_manager = context.data::<Arc<dyn SavedQueryManager + Send + Sync>>()?;
+        Ok(saved_query_manager
+            .get_rows(&self.saved_query.name)
+            .unwrap_or_default()
+            .into_iter()
+            .map(GraphQLSavedQueryRow::from)
+            .collect())
+    }
+}
+
+impl From<SavedQuery> for GraphQLSavedQuery {
+    fn from(saved_query: SavedQuery) -> Self {
+        GraphQLSavedQuery { saved_query }
+    }
+}
+
+/// An entity instance in the result set of a saved query.
+pub struct GraphQLSavedQueryRow {
+    row: SavedQueryRow,
+}
+
+/// An entity instance in the result set of a saved query.
+#[Object(name = "SavedQueryRow")]
+impl GraphQLSavedQueryRow {
+    /// The id of the entity instance.
+    async fn id(&self) -> Uuid {
+        self.row.id
+    }
+
+    /// The projected properties of the entity instance.
+    async fn properties(&self) -> Value {
+        serde_json::to_value(&self.row.properties).unwrap_or_default()
+    }
+
+    /// The entity instance.
+    async fn entity(&self, context: &Context<'_>) -> Result<Option<GraphQLEntityInstance>> {
+        let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityManager + Send + Sync>>()?;
+        Ok(entity_instance_manager.get(self.row.id).map(|entity_instance| entity_instance.into()))
+    }
+}
+
+impl From<SavedQueryRow> for GraphQLSavedQueryRow {
+    fn from(row: SavedQueryRow) -> Self {
+        GraphQLSavedQueryRow { row }
+    }
+}
@@ -0,0 +1,48 @@
+use async_graphql::Object;
+
+use reactive_graph_graph::SearchHit;
+use reactive_graph_reactive_service_api::SearchDocument;
+
+use crate::query::GraphQLEntityInstance;
+use crate::query::GraphQLRelationInstance;
+
+/// An instance found by the full-text search.
+pub struct GraphQLSearchHit {
+    hit: SearchHit<SearchDocument>,
+}
+
+/// An instance found by the full-text search.
+#[Object(name = "SearchHit")]
+impl GraphQLSearchHit {
+    /// The relevance of the match. Higher is better.
+    async fn score(&self) -> f64 {
+        self.hit.score
+    }
+
+    /// The fields (name, label, description or the name of a property) which contain matching tokens.
+    async fn fields(&self) -> Vec<String> {
+        self.hit.fields.iter().map(|field| field.to_string()).collect()
+    }
+
+    /// The matched entity instance.
+    async fn entity(&self) -> Option<GraphQLEntityInstance> {
+        match &self.hit.instance {
+            SearchDocument::Entity(reactive_entity) => Some(reactive_entity.clone().into()),
+            SearchDocument::Relation(_) => None,
+        }
+    }
+
+    /// The matched relation instance.
+    async fn relation(&self) -> Option<GraphQLRelationInstance> {
+        match &self.hit.instance {
+            SearchDocument::Entity(_) => None,
+            SearchDocument::Relation(reactive_relation) => Some(reactive_relation.clone().into()),
+        }
+    }
+}
+
+impl From<SearchHit<SearchDocument>> for GraphQLSearchHit {
+    fn from(hit: SearchHit<SearchDocument>) -> Self {
+        GraphQLSearchHit { hit }
+    }
+}
@@ -1,14 +1,25 @@
+use std::sync::Arc;
+
 use async_graphql::*;
 use uuid::Uuid;
 
+use reactive_graph_graph::GraphQuery;
+use reactive_graph_graph::SearchOptions;
+use reactive_graph_reactive_service_api::GraphQueryManager;
+use reactive_graph_reactive_service_api::SearchManager;
+
 pub use behaviours::*;
 pub use instances::*;
 pub use json_schema::*;
+pub use pagination::*;
+pub use traversal::*;
 pub use types::*;
 
 pub mod behaviours;
 pub mod instances;
 pub mod json_schema;
+pub mod pagination;
+pub mod traversal;
 pub mod types;
 
 pub struct ReactiveGraphQuery;
@@ -26,6 +37,12 @@ impl ReactiveGraphQuery {
         Instances
     }
 
+    /// Traverse the entity instances along the relation instances (reachability, paths,
+    /// connected components and cycles).
+    async fn traversal(&self) -> Traversal {
+        Traversal
+    }
+
     /// Search for behaviours (entity behaviours, entity component behaviours, relation behaviours,
     /// relation component behaviours).
     async fn behaviours(&self) -> Behaviours {
@@ -37,6 +54,34 @@ impl ReactiveGraphQuery {
         JsonSchema
     }
 
+    /// Executes a graph pattern query.
+    ///
+    /// Example: MATCH (a:namespace::sensor)-[:connects]->(b) WHERE a.value > 10 RETURN b
+    async fn query(&self, context: &Context<'_>, #[graphql(desc = "The graph pattern query.")] text: String) -> Result<GraphQLGraphQueryResult> {
+        let graph_query_manager = context.data::<Arc<dyn GraphQueryManager + Send + Sync>>()?;
+        let query = GraphQuery::parse(&text)?;
+        let plan = graph_query_manager.plan(&query)?;
+        let result = graph_query_manager.execute_plan(&plan)?;
+        Ok(GraphQLGraphQueryResult::new(plan, result))
+    }
+
+    /// Full-text search over the names, descriptions, labels and searchable string properties
+    /// of the entity instances and relation instances.
+    ///
+    /// Every token of the text has to match. The hits are ranked by relevance.
+    async fn search(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "The text to search for.")] text: String,
+        #[graphql(desc = "Tokens also match tokens starting with the token.", default = true)] prefix: bool,
+        #[graphql(desc = "Tokens also match tokens with a small number of typos.", default = true)] fuzzy: bool,
+        #[graphql(desc = "The maximum number of hits.", default = 100)] limit: usize,
+    ) -> Result<Vec<GraphQLSearchHit>> {
+        let search_manager = context.data::<Arc<dyn SearchManager + Send + Sync>>()?;
+        let options = SearchOptions::builder().prefix(prefix).fuzzy(fuzzy).limit(limit).build();
+        Ok(search_manager.search(&text, &options).into_iter().map(GraphQLSearchHit::from).collect())
+    }
+
     async fn random_uuid(&self, _context: &Context<'_>) -> String {
         Uuid::new_v4().to_string()
     }
@@ -0,0 +1,29 @@
+use async_graphql::OutputType;
+use async_graphql::Result;
+use async_graphql::SimpleObject;
+use async_graphql::connection::Connection;
+use async_graphql::connection::Edge;
+
+use reactive_graph_graph::Pagination;
+
+/// The additional fields of the connections.
+#[derive(SimpleObject)]
+pub struct GraphQLConnectionFields {
+    /// The number of elements of the whole result.
+    total_count: usize,
+}
+
+/// A Relay connection of the given node type.
+pub type GraphQLConnection<Node> = Connection<String, Node, GraphQLConnectionFields>;
+
+/// Returns the requested page of the given sorted nodes as Relay connection.
+pub fn to_connection<Node: OutputType>(nodes: Vec<Node>, pagination: &Pagination) -> Result<GraphQLConnection<Node>> {
+    let page = pagination.paginate(nodes)?;
+    let mut connection = Connection::with_additional_fields(
+        page.page_info.has_previous_page,
+        page.page_info.has_next_page,
+        GraphQLConnectionFields { total_count: page.total_count },
+    );
+    connection.edges.extend(page.edges.into_iter().map(|edge| Edge::new(edge.cursor, edge.node)));
+    Ok(connection)
+}
@@ -0,0 +1,7 @@
+pub use connection::*;
+pub use property_sort::*;
+pub use sort_direction::*;
+
+pub mod connection;
+pub mod property_sort;
+pub mod sort_direction;
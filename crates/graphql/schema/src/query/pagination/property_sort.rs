@@ -0,0 +1,23 @@
+use async_graphql::InputObject;
+
+use reactive_graph_graph::PropertySort;
+
+use crate::query::GraphQLSortDirection;
+
+/// Sorts instances by the value of a property.
+#[derive(Clone, Debug, InputObject)]
+#[graphql(name = "PropertySort")]
+pub struct GraphQLPropertySort {
+    /// The name of the property.
+    pub property: String,
+
+    /// The direction. Instances without the property are sorted last.
+    #[graphql(default)]
+    pub direction: GraphQLSortDirection,
+}
+
+impl From<GraphQLPropertySort> for PropertySort {
+    fn from(sort: GraphQLPropertySort) -> Self {
+        PropertySort::new(sort.property, sort.direction.into())
+    }
+}
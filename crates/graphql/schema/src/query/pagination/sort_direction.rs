@@ -0,0 +1,16 @@
+use async_graphql::Enum;
+use serde::Deserialize;
+use serde::Serialize;
+use strum::Display;
+
+/// The direction of a sort.
+#[derive(Enum, Serialize, Deserialize, Copy, Clone, Debug, Default, Eq, PartialEq, Display)]
+#[graphql(name = "SortDirection", remote = "reactive_graph_graph::SortDirection")]
+pub enum GraphQLSortDirection {
+    /// Sorts in ascending order.
+    #[default]
+    Ascending,
+
+    /// Sorts in descending order.
+    Descending,
+}
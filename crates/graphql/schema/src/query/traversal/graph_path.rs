@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use async_graphql::Context;
+use async_graphql::Object;
+use async_graphql::Result;
+
+use reactive_graph_graph::GraphPath;
+use reactive_graph_reactive_service_api::ReactiveEntityManager;
+use reactive_graph_reactive_service_api::ReactiveRelationManager;
+
+use crate::query::GraphQLEntityInstance;
+use crate::query::GraphQLRelationInstance;
+
+/// A path of entity instances connected by relation instances.
+pub struct GraphQLGraphPath {
+    path: GraphPath,
+}
+
+/// A path of entity instances connected by relation instances.
+#[Object(name = "GraphPath")]
+impl GraphQLGraphPath {
+    /// The number of relations of the path.
+    async fn length(&self) -> usize {
+        self.path.len()
+    }
+
+    /// The entity instances in order.
+    async fn entities(&self, context: &Context<'_>) -> Result<Vec<GraphQLEntityInstance>> {
+        let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityManager + Send + Sync>>()?;
+        Ok(self
+            .path
+            .entities
+            .iter()
+            .filter_map(|id| entity_instance_manager.get(*id))
+            .map(GraphQLEntityInstance::from)
+            .collect())
+    }
+
+    /// The relation instances in order. The relation at index i connects the entities at index i and i + 1.
+    async fn relations(&self, context: &Context<'_>) -> Result<Vec<GraphQLRelationInstance>> {
+        let relation_instance_manager = context.data::<Arc<dyn ReactiveRelationManager + Send + Sync>>()?;
+        Ok(self
+            .path
+            .relations
+            .iter()
+            .filter_map(|id| relation_instance_manager.get(id))
+            .map(GraphQLRelationInstance::from)
+            .collect())
+    }
+}
+
+impl From<GraphPath> for GraphQLGraphPath {
+    fn from(path: GraphPath) -> Self {
+        GraphQLGraphPath { path }
+    }
+}
@@ -0,0 +1,14 @@
+pub use graph_path::*;
+pub use traversal::*;
+pub use traversal_direction::*;
+pub use traversal_options::*;
+pub use traversal_strategy::*;
+pub use traversal_visit::*;
+
+pub mod graph_path;
+#[allow(clippy::module_inception)]
+pub mod traversal;
+pub mod traversal_direction;
+pub mod traversal_options;
+pub mod traversal_strategy;
+pub mod traversal_visit;
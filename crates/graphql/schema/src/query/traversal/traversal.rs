@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use async_graphql::Context;
+use async_graphql::Error;
+use async_graphql::Object;
+use async_graphql::Result;
+use uuid::Uuid;
+
+use reactive_graph_graph::TraversalOptions;
+use reactive_graph_reactive_service_api::GraphTraversalManager;
+use reactive_graph_reactive_service_api::ReactiveEntityManager;
+
+use crate::query::GraphQLEntityInstance;
+use crate::query::GraphQLGraphPath;
+use crate::query::GraphQLTraversalOptions;
+use crate::query::GraphQLTraversalVisit;
+
+/// The maximum depth of the search for all paths, if no maximum depth is given.
+pub const ALL_PATHS_DEFAULT_MAX_DEPTH: usize = 10;
+
+/// The largest maximum depth of the search for all paths.
+pub const ALL_PATHS_MAX_DEPTH: usize = 32;
+
+/// The maximum number of returned paths or cycles, if no limit is given.
+pub const ALL_PATHS_DEFAULT_LIMIT: usize = 100;
+
+/// Bounds the search for all paths or cycles, which grows exponentially with the depth.
+fn bounded_options(options: Option<GraphQLTraversalOptions>) -> Result<TraversalOptions> {
+    let mut options: TraversalOptions = options.unwrap_or_default().into();
+    match options.max_depth {
+        Some(max_depth) if max_depth > ALL_PATHS_MAX_DEPTH => {
+            return Err(Error::new(format!("The maximum depth {max_depth} exceeds the limit of {ALL_PATHS_MAX_DEPTH}")));
+        }
+        Some(_) => {}
+        None => options.max_depth = Some(ALL_PATHS_DEFAULT_MAX_DEPTH),
+    }
+    if options.limit.is_none() {
+        options.limit = Some(ALL_PATHS_DEFAULT_LIMIT);
+    }
+    Ok(options)
+}
+
+#[derive(Default)]
+pub struct Traversal;
+
+/// Traverses the entity instances along the relation instances.
+#[Object]
+impl Traversal {
+    /// Visits the entity instances reachable from the start entity instance, including the start entity instance.
+    async fn traverse(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "The id of the start entity instance.")] start: Uuid,
+        #[graphql(desc = "Restricts which relations are followed.")] options: Option<GraphQLTraversalOptions>,
+    ) -> Result<Vec<GraphQLTraversalVisit>> {
+        let graph_traversal_manager = context.data::<Arc<dyn GraphTraversalManager + Send + Sync>>()?;
+        let options: TraversalOptions = options.unwrap_or_default().into();
+        Ok(graph_traversal_manager
+            .traverse(start, &options)?
+            .into_iter()
+            .map(GraphQLTraversalVisit::from)
+            .collect())
+    }
+
+    /// Returns a path with the fewest relations from the start entity instance to the end entity instance.
+    async fn shortest_path(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "The id of the start entity instance.")] start: Uuid,
+        #[graphql(desc = "The id of the end entity instance.")] end: Uuid,
+        #[graphql(desc = "Restricts which relations are followed.")] options: Option<GraphQLTraversalOptions>,
+    ) -> Result<Option<GraphQLGraphPath>> {
+        let graph_traversal_manager = context.data::<Arc<dyn GraphTraversalManager + Send + Sync>>()?;
+        let options: TraversalOptions = options.unwrap_or_default().into();
+        Ok(graph_traversal_manager.shortest_path(start, end, &options)?.map(GraphQLGraphPath::from))
+    }
+
+    /// Returns all paths from the start entity instance to the end entity instance which doesn't visit an entity instance twice.
+    ///
+    /// The paths are ordered by length. The maximum depth defaults to 10 and must not exceed 32.
+    /// At most 100 paths are returned, if no limit is given.
+    async fn all_paths(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "The id of the start entity instance.")] start: Uuid,
+        #[graphql(desc = "The id of the end entity instance.")] end: Uuid,
+        #[graphql(desc = "Restricts which relations are followed.")] options: Option<GraphQLTraversalOptions>,
+    ) -> Result<Vec<GraphQLGraphPath>> {
+        let graph_traversal_manager = context.data::<Arc<dyn GraphTraversalManager + Send + Sync>>()?;
+        let options = bounded_options(options)?;
+        Ok(graph_traversal_manager
+            .all_paths(start, end, &options)?
+            .into_iter()
+            .map(GraphQLGraphPath::from)
+            .collect())
+    }
+
+    /// Groups all entity instances into connected components, ignoring the direction of the relations.
+    async fn connected_components(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "Restricts which relations are followed.")] options: Option<GraphQLTraversalOptions>,
+    ) -> Result<Vec<Vec<GraphQLEntityInstance>>> {
+        let graph_traversal_manager = context.data::<Arc<dyn GraphTraversalManager + Send + Sync>>()?;
+        let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityManager + Send + Sync>>()?;
+        let options: TraversalOptions = options.unwrap_or_default().into();
+        Ok(graph_traversal_manager
+            .connected_components(&options)
+            .into_iter()
+            .map(|component| {
+                component
+                    .into_iter()
+                    .filter_map(|id| entity_instance_manager.get(id))
+                    .map(GraphQLEntityInstance::from)
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Returns the cycles through the given entity instance or through the entity instances of the given flow instance.
+    ///
+    /// The maximum depth defaults to 10 and must not exceed 32. At most 100 cycles are returned, if no limit is given.
+    async fn cycles(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "The id of the entity instance.")] id: Option<Uuid>,
+        #[graphql(desc = "The id of the flow instance.")] flow_id: Option<Uuid>,
+        #[graphql(desc = "Restricts which relations are followed.")] options: Option<GraphQLTraversalOptions>,
+    ) -> Result<Vec<GraphQLGraphPath>> {
+        let graph_traversal_manager = context.data::<Arc<dyn GraphTraversalManager + Send + Sync>>()?;
+        let options = bounded_options(options)?;
+        let cycles = match (id, flow_id) {
+            (Some(id), None) => graph_traversal_manager.cycles(id, &options)?,
+            (None, Some(flow_id)) => graph_traversal_manager.flow_cycles(flow_id, &options)?,
+            _ => return Err("Either the id of an entity instance or the id of a flow instance must be given".into()),
+        };
+        Ok(cycles.into_iter().map(GraphQLGraphPath::from).collect())
+    }
+}
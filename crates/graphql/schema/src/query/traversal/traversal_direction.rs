@@ -0,0 +1,19 @@
+use async_graphql::Enum;
+use serde::Deserialize;
+use serde::Serialize;
+use strum::Display;
+
+/// The direction in which relations are followed.
+#[derive(Enum, Serialize, Deserialize, Copy, Clone, Debug, Default, Eq, PartialEq, Display)]
+#[graphql(name = "TraversalDirection", remote = "reactive_graph_graph::TraversalDirection")]
+pub enum GraphQLTraversalDirection {
+    /// Follows the relations from the outbound entity to the inbound entity.
+    #[default]
+    Outbound,
+
+    /// Follows the relations from the inbound entity to the outbound entity.
+    Inbound,
+
+    /// Follows the relations in both directions.
+    Both,
+}
@@ -0,0 +1,43 @@
+use async_graphql::InputObject;
+
+use reactive_graph_graph::RelationTypeId;
+use reactive_graph_graph::TraversalOptions;
+
+use crate::mutation::RelationTypeIdDefinition;
+use crate::query::GraphQLTraversalDirection;
+use crate::query::GraphQLTraversalStrategy;
+
+/// Restricts which relations are followed by a traversal.
+#[derive(Clone, Debug, Default, InputObject)]
+#[graphql(name = "TraversalOptions")]
+pub struct GraphQLTraversalOptions {
+    /// The direction in which relations are followed.
+    #[graphql(default)]
+    pub direction: GraphQLTraversalDirection,
+
+    /// Only relations of these relation types are followed. If empty, all relations are followed.
+    #[graphql(default)]
+    pub relation_types: Vec<RelationTypeIdDefinition>,
+
+    /// The maximum number of relations between the start entity and a visited entity.
+    pub max_depth: Option<usize>,
+
+    /// The order in which the reachable entities are visited.
+    #[graphql(default)]
+    pub strategy: GraphQLTraversalStrategy,
+
+    /// The maximum number of returned entities, paths or cycles.
+    pub limit: Option<usize>,
+}
+
+impl From<GraphQLTraversalOptions> for TraversalOptions {
+    fn from(options: GraphQLTraversalOptions) -> Self {
+        TraversalOptions {
+            direction: options.direction.into(),
+            relation_types: options.relation_types.into_iter().map(RelationTypeId::from).collect(),
+            max_depth: options.max_depth,
+            strategy: options.strategy.into(),
+            limit: options.limit,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use async_graphql::Enum;
+use serde::Deserialize;
+use serde::Serialize;
+use strum::Display;
+
+/// The order in which the reachable entities are visited.
+#[derive(Enum, Serialize, Deserialize, Copy, Clone, Debug, Default, Eq, PartialEq, Display)]
+#[graphql(name = "TraversalStrategy", remote = "reactive_graph_graph::TraversalStrategy")]
+pub enum GraphQLTraversalStrategy {
+    /// Visits the entities level by level.
+    #[default]
+    BreadthFirst,
+
+    /// Follows each branch as far as possible before backtracking.
+    DepthFirst,
+}
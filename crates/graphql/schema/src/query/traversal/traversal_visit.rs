@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use async_graphql::Context;
+use async_graphql::Object;
+use async_graphql::Result;
+use uuid::Uuid;
+
+use reactive_graph_graph::TraversalVisit;
+use reactive_graph_reactive_service_api::ReactiveEntityManager;
+use reactive_graph_reactive_service_api::ReactiveRelationManager;
+
+use crate::query::GraphQLEntityInstance;
+use crate::query::GraphQLRelationInstance;
+
+/// An entity instance visited by a traversal.
+pub struct GraphQLTraversalVisit {
+    visit: TraversalVisit,
+}
+
+/// An entity instance visited by a traversal.
+#[Object(name = "TraversalVisit")]
+impl GraphQLTraversalVisit {
+    /// The id of the visited entity instance.
+    async fn id(&self) -> Uuid {
+        self.visit.id
+    }
+
+    /// The number of relations between the start entity and the visited entity.
+    async fn depth(&self) -> usize {
+        self.visit.depth
+    }
+
+    /// The visited entity instance.
+    async fn entity(&self, context: &Context<'_>) -> Result<Option<GraphQLEntityInstance>> {
+        let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityManager + Send + Sync>>()?;
+        Ok(entity_instance_manager.get(self.visit.id).map(GraphQLEntityInstance::from))
+    }
+
+    /// The relation instance which was followed to reach the entity instance. Null for the start entity instance.
+    async fn relation(&self, context: &Context<'_>) -> Result<Option<GraphQLRelationInstance>> {
+        let relation_instance_manager = context.data::<Arc<dyn ReactiveRelationManager + Send + Sync>>()?;
+        Ok(self
+            .visit
+            .relation
+            .as_ref()
+            .and_then(|relation| relation_instance_manager.get(relation))
+            .map(GraphQLRelationInstance::from))
+    }
+}
+
+impl From<TraversalVisit> for GraphQLTraversalVisit {
+    fn from(visit: TraversalVisit) -> Self {
+        GraphQLTraversalVisit { visit }
+    }
+}
@@ -1,3 +1,4 @@
+use std::ops::Deref;
 use std::sync::Arc;
 
 use crate::mutation::ExtensionTypeIdDefinition;
@@ -8,6 +9,9 @@ use crate::query::GraphQLExtension;
 use crate::query::GraphQLPropertyType;
 use crate::query::GraphQLRelationType;
 use crate::query::GraphQLRelationTypes;
+use crate::query::GraphQLTypeDependency;
+use crate::query::types::type_dependency::count_instance_references;
+use crate::query::types::type_dependency::get_dependents;
 use async_graphql::Context;
 use async_graphql::Object;
 use async_graphql::Result;
@@ -16,6 +20,7 @@ use reactive_graph_behaviour_service_api::RelationComponentBehaviourRegistry;
 use reactive_graph_graph::Component;
 use reactive_graph_graph::JsonSchemaIdGetter;
 use reactive_graph_graph::NamespacedTypeGetter;
+use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_graph::TypeDefinitionJsonSchemaGetter;
 use reactive_graph_type_system_api::EntityTypeManager;
 use reactive_graph_type_system_api::RelationTypeManager;
@@ -184,6 +189,16 @@ impl GraphQLComponent {
         Ok(component_behaviour_types)
     }
 
+    /// Returns the types which are referencing this component.
+    async fn dependents(&self, context: &Context<'_>) -> Result<Vec<GraphQLTypeDependency>> {
+        get_dependents(context, &self.component.ty.type_definition())
+    }
+
+    /// Returns the number of instances which are referencing this component.
+    async fn count_instance_references(&self, context: &Context<'_>) -> Result<usize> {
+        count_instance_references(context, &self.component.ty.type_definition())
+    }
+
     /// Returns the JSON schema of the component.
     async fn json_schema(&self) -> Value {
         self.component.json_schema().to_value()
@@ -200,3 +215,11 @@ impl From<Component> for GraphQLComponent {
         GraphQLComponent { component }
     }
 }
+
+impl Deref for GraphQLComponent {
+    type Target = Component;
+
+    fn deref(&self) -> &Self::Target {
+        &self.component
+    }
+}
@@ -9,6 +9,7 @@ use reactive_graph_graph::EntityType;
 use reactive_graph_graph::EntityTypes;
 use reactive_graph_graph::JsonSchemaIdGetter;
 use reactive_graph_graph::NamespacedTypeGetter;
+use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_graph::TypeDefinitionJsonSchemaGetter;
 use reactive_graph_type_system_api::ComponentManager;
 use reactive_graph_type_system_api::EntityTypeManager;
@@ -21,6 +22,9 @@ use crate::query::GraphQLEntityBehaviour;
 use crate::query::GraphQLExtension;
 use crate::query::GraphQLPropertyType;
 use crate::query::GraphQLRelationType;
+use crate::query::GraphQLTypeDependency;
+use crate::query::types::type_dependency::count_instance_references;
+use crate::query::types::type_dependency::get_dependents;
 
 pub struct GraphQLEntityType {
     entity_type: EntityType,
@@ -221,6 +225,16 @@ impl GraphQLEntityType {
         Ok(entity_behaviour_types)
     }
 
+    /// Returns the types which are referencing this entity type.
+    async fn dependents(&self, context: &Context<'_>) -> Result<Vec<GraphQLTypeDependency>> {
+        get_dependents(context, &self.entity_type.ty.type_definition())
+    }
+
+    /// Returns the number of instances which are referencing this entity type.
+    async fn count_instance_references(&self, context: &Context<'_>) -> Result<usize> {
+        count_instance_references(context, &self.entity_type.ty.type_definition())
+    }
+
     /// Returns the JSON schema of the entity type.
     async fn json_schema(&self) -> Value {
         self.entity_type.json_schema().to_value()
@@ -238,6 +252,14 @@ impl From<EntityType> for GraphQLEntityType {
     }
 }
 
+impl Deref for GraphQLEntityType {
+    type Target = EntityType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.entity_type
+    }
+}
+
 pub struct GraphQLEntityTypes(Vec<GraphQLEntityType>);
 
 impl Deref for GraphQLEntityTypes {
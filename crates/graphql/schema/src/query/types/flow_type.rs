@@ -1,3 +1,4 @@
+use std::ops::Deref;
 use std::sync::Arc;
 
 use async_graphql::Context;
@@ -6,6 +7,7 @@ use async_graphql::Object;
 use reactive_graph_graph::FlowType;
 use reactive_graph_graph::JsonSchemaIdGetter;
 use reactive_graph_graph::NamespacedTypeGetter;
+use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_reactive_model_impl::ReactiveEntity;
 use reactive_graph_reactive_model_impl::ReactiveRelation;
 use reactive_graph_type_system_api::EntityTypeManager;
@@ -19,6 +21,9 @@ use crate::query::GraphQLExtension;
 use crate::query::GraphQLPropertyInstance;
 use crate::query::GraphQLPropertyType;
 use crate::query::GraphQLRelationInstance;
+use crate::query::GraphQLTypeDependency;
+use crate::query::types::type_dependency::count_instance_references;
+use crate::query::types::type_dependency::get_dependents;
 use async_graphql::Result;
 
 pub struct GraphQLFlowType {
@@ -202,6 +207,16 @@ impl GraphQLFlowType {
         }
     }
 
+    /// Returns the types which are referencing this flow type.
+    async fn dependents(&self, context: &Context<'_>) -> Result<Vec<GraphQLTypeDependency>> {
+        get_dependents(context, &self.flow_type.ty.type_definition())
+    }
+
+    /// Returns the number of instances which are referencing this flow type.
+    async fn count_instance_references(&self, context: &Context<'_>) -> Result<usize> {
+        count_instance_references(context, &self.flow_type.ty.type_definition())
+    }
+
     /// Returns the JSON schema of the flow type.
     async fn json_schema(&self, context: &Context<'_>) -> Result<Value> {
         let entity_type_manager = context.data::<Arc<dyn EntityTypeManager + Send + Sync>>()?;
@@ -226,3 +241,11 @@ impl From<FlowType> for GraphQLFlowType {
         GraphQLFlowType { flow_type }
     }
 }
+
+impl Deref for GraphQLFlowType {
+    type Target = FlowType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.flow_type
+    }
+}
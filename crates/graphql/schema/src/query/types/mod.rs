@@ -4,9 +4,11 @@ pub use entity_type::*;
 pub use extension::*;
 pub use flow_type::*;
 pub use mutability::*;
+pub use namespace::*;
 pub use property_type::*;
 pub use relation_type::*;
 pub use socket_type::*;
+pub use type_dependency::*;
 pub use types::*;
 
 pub mod component;
@@ -15,8 +17,10 @@ pub mod entity_type;
 pub mod extension;
 pub mod flow_type;
 pub mod mutability;
+pub mod namespace;
 pub mod property_type;
 pub mod relation_type;
 pub mod socket_type;
+pub mod type_dependency;
 #[allow(clippy::module_inception)]
 pub mod types;
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use async_graphql::Context;
+use async_graphql::Object;
+use async_graphql::Result;
+
+use reactive_graph_graph::Namespace;
+use reactive_graph_graph::NamespacedTypeContainer;
+use reactive_graph_type_system_api::ComponentManager;
+use reactive_graph_type_system_api::EntityTypeManager;
+use reactive_graph_type_system_api::FlowTypeManager;
+use reactive_graph_type_system_api::RelationTypeManager;
+
+use crate::query::GraphQLComponent;
+use crate::query::GraphQLEntityType;
+use crate::query::GraphQLFlowType;
+use crate::query::GraphQLRelationType;
+
+pub struct GraphQLNamespace {
+    namespace: Namespace,
+}
+
+/// A namespace groups the components, entity types, relation types and flow types of a
+/// domain. Namespaces are usually registered and owned by a plugin.
+#[Object(name = "Namespace")]
+impl GraphQLNamespace {
+    /// The name of the namespace.
+    async fn name(&self) -> String {
+        self.namespace.name.clone()
+    }
+
+    /// Textual description of the namespace.
+    async fn description(&self) -> String {
+        self.namespace.description.clone()
+    }
+
+    /// The name of the plugin which owns the namespace.
+    async fn owner(&self) -> Option<String> {
+        self.namespace.owner.clone()
+    }
+
+    /// The version of the types of the namespace.
+    async fn version(&self) -> Option<String> {
+        self.namespace.version.clone()
+    }
+
+    /// The tags of the namespace.
+    async fn tags(&self) -> Vec<String> {
+        self.namespace.tags.clone()
+    }
+
+    /// Links to the documentation of the namespace.
+    async fn documentation(&self) -> Vec<String> {
+        self.namespace.documentation.clone()
+    }
+
+    /// The components of the namespace.
+    async fn components(&self, context: &Context<'_>) -> Result<Vec<GraphQLComponent>> {
+        let component_manager = context.data::<Arc<dyn ComponentManager + Send + Sync>>()?;
+        Ok(component_manager
+            .get_by_namespace(&self.namespace.name)
+            .to_vec()
+            .into_iter()
+            .map(|component| component.into())
+            .collect())
+    }
+
+    /// The entity types of the namespace.
+    async fn entity_types(&self, context: &Context<'_>) -> Result<Vec<GraphQLEntityType>> {
+        let entity_type_manager = context.data::<Arc<dyn EntityTypeManager + Send + Sync>>()?;
+        Ok(entity_type_manager
+            .get_by_namespace(&self.namespace.name)
+            .to_vec()
+            .into_iter()
+            .map(|entity_type| entity_type.into())
+            .collect())
+    }
+
+    /// The relation types of the namespace.
+    async fn relation_types(&self, context: &Context<'_>) -> Result<Vec<GraphQLRelationType>> {
+        let relation_type_manager = context.data::<Arc<dyn RelationTypeManager + Send + Sync>>()?;
+        Ok(relation_type_manager
+            .get_by_namespace(&self.namespace.name)
+            .to_vec()
+            .into_iter()
+            .map(|relation_type| relation_type.into())
+            .collect())
+    }
+
+    /// The flow types of the namespace.
+    async fn flow_types(&self, context: &Context<'_>) -> Result<Vec<GraphQLFlowType>> {
+        let flow_type_manager = context.data::<Arc<dyn FlowTypeManager + Send + Sync>>()?;
+        Ok(flow_type_manager
+            .get_by_namespace(&self.namespace.name)
+            .to_vec()
+            .into_iter()
+            .map(|flow_type| flow_type.into())
+            .collect())
+    }
+}
+
+impl From<Namespace> for GraphQLNamespace {
+    fn from(namespace: Namespace) -> Self {
+        GraphQLNamespace { namespace }
+    }
+}
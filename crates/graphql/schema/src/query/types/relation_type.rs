@@ -10,6 +10,7 @@ use reactive_graph_graph::JsonSchemaIdGetter;
 use reactive_graph_graph::NamespacedTypeGetter;
 use reactive_graph_graph::RelationType;
 use reactive_graph_graph::RelationTypes;
+use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_graph::TypeDefinitionJsonSchemaGetter;
 use reactive_graph_type_system_api::ComponentManager;
 use reactive_graph_type_system_api::EntityTypeManager;
@@ -23,6 +24,9 @@ use crate::query::GraphQLEntityTypes;
 use crate::query::GraphQLExtension;
 use crate::query::GraphQLPropertyType;
 use crate::query::GraphQLRelationBehaviour;
+use crate::query::GraphQLTypeDependency;
+use crate::query::types::type_dependency::count_instance_references;
+use crate::query::types::type_dependency::get_dependents;
 
 pub struct GraphQLRelationType {
     relation_type: RelationType,
@@ -230,6 +234,16 @@ impl GraphQLRelationType {
         Ok(relation_behaviour_types)
     }
 
+    /// Returns the types which are referencing this relation type.
+    async fn dependents(&self, context: &Context<'_>) -> Result<Vec<GraphQLTypeDependency>> {
+        get_dependents(context, &self.relation_type.ty.type_definition())
+    }
+
+    /// Returns the number of instances which are referencing this relation type.
+    async fn count_instance_references(&self, context: &Context<'_>) -> Result<usize> {
+        count_instance_references(context, &self.relation_type.ty.type_definition())
+    }
+
     /// Returns the JSON schema of the relation type.
     async fn json_schema(&self) -> Value {
         self.relation_type.json_schema().to_value()
@@ -247,6 +261,14 @@ impl From<RelationType> for GraphQLRelationType {
     }
 }
 
+impl Deref for GraphQLRelationType {
+    type Target = RelationType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.relation_type
+    }
+}
+
 pub struct GraphQLRelationTypes(Vec<GraphQLRelationType>);
 
 impl Deref for GraphQLRelationTypes {
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use async_graphql::Context;
+use async_graphql::Enum;
+use async_graphql::Object;
+use async_graphql::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use strum::Display;
+
+use reactive_graph_graph::TypeDefinition;
+use reactive_graph_type_system_api::TypeDependency;
+use reactive_graph_type_system_api::TypeDependencyManager;
+use reactive_graph_type_system_api::TypeValidationReport;
+
+/// The kind of reference from a dependent type to the type it depends on.
+#[derive(Enum, Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Display)]
+#[graphql(name = "TypeDependencyKind", remote = "reactive_graph_type_system_api::TypeDependencyKind")]
+pub enum GraphQLTypeDependencyKind {
+    /// The entity type or relation type is composed of the component.
+    Component,
+
+    /// The relation type uses the component or entity type as outbound type.
+    OutboundType,
+
+    /// The relation type uses the component or entity type as inbound type.
+    InboundType,
+
+    /// The flow type uses the entity type for the wrapper entity instance.
+    WrapperEntityType,
+
+    /// The flow type contains an entity instance of the entity type.
+    EntityInstance,
+
+    /// The flow type contains a relation instance of the relation type.
+    RelationInstance,
+}
+
+pub struct GraphQLTypeDependency {
+    dependency: TypeDependency,
+}
+
+/// A reference from a dependent type to the type it depends on.
+#[Object(name = "TypeDependency")]
+impl GraphQLTypeDependency {
+    /// The fully qualified type id of the type which references the other type.
+    async fn dependent(&self) -> String {
+        self.dependency.dependent.to_string()
+    }
+
+    /// The fully qualified type id of the referenced type.
+    async fn dependency(&self) -> String {
+        self.dependency.dependency.to_string()
+    }
+
+    /// How the dependent type references the other type.
+    async fn kind(&self) -> GraphQLTypeDependencyKind {
+        self.dependency.kind.into()
+    }
+}
+
+impl From<TypeDependency> for GraphQLTypeDependency {
+    fn from(dependency: TypeDependency) -> Self {
+        GraphQLTypeDependency { dependency }
+    }
+}
+
+pub struct GraphQLTypeValidationReport {
+    report: TypeValidationReport,
+}
+
+/// The result of validating a type.
+#[Object(name = "TypeValidationReport")]
+impl GraphQLTypeValidationReport {
+    /// The fully qualified type id of the validated type.
+    async fn type_id(&self) -> String {
+        self.report.ty.to_string()
+    }
+
+    /// True, if no problems have been found.
+    async fn is_valid(&self) -> bool {
+        self.report.is_valid()
+    }
+
+    /// The problems which have been found.
+    async fn issues(&self) -> Vec<String> {
+        self.report.issues.iter().map(|issue| issue.to_string()).collect()
+    }
+}
+
+impl From<TypeValidationReport> for GraphQLTypeValidationReport {
+    fn from(report: TypeValidationReport) -> Self {
+        GraphQLTypeValidationReport { report }
+    }
+}
+
+/// Returns the types which are referencing the given type.
+pub(crate) fn get_dependents(context: &Context<'_>, ty: &TypeDefinition) -> Result<Vec<GraphQLTypeDependency>> {
+    let type_dependency_manager = context.data::<Arc<dyn TypeDependencyManager + Send + Sync>>()?;
+    Ok(type_dependency_manager
+        .get_dependents(ty)
+        .into_iter()
+        .map(|dependency| dependency.into())
+        .collect())
+}
+
+/// Returns the number of instances which are referencing the given type.
+pub(crate) fn count_instance_references(context: &Context<'_>, ty: &TypeDefinition) -> Result<usize> {
+    let type_dependency_manager = context.data::<Arc<dyn TypeDependencyManager + Send + Sync>>()?;
+    Ok(type_dependency_manager.count_instance_references(ty))
+}
@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::ops::Deref;
 use std::sync::Arc;
 
 use async_graphql::Context;
@@ -10,13 +11,16 @@ use reactive_graph_graph::ComponentTypeIdContainer;
 use reactive_graph_graph::EntityTypeId;
 use reactive_graph_graph::ExtensionContainer;
 use reactive_graph_graph::NamespacedTypeGetter;
+use reactive_graph_graph::Pagination;
 use reactive_graph_graph::PropertyTypeContainer;
 use reactive_graph_graph::RelationTypeId;
+use reactive_graph_graph::SortDirection;
 use reactive_graph_type_system_api::ComponentManager;
 use reactive_graph_type_system_api::EntityTypeManager;
 use reactive_graph_type_system_api::FlowTypeManager;
 use reactive_graph_type_system_api::NamespaceManager;
 use reactive_graph_type_system_api::RelationTypeManager;
+use reactive_graph_type_system_api::TypeDependencyManager;
 
 use crate::mutation::ComponentTypeIdDefinition;
 use crate::mutation::EntityTypeIdDefinition;
@@ -24,9 +28,14 @@ use crate::mutation::ExtensionTypeIdDefinition;
 use crate::mutation::FlowTypeIdDefinition;
 use crate::mutation::RelationTypeIdDefinition;
 use crate::query::GraphQLComponent;
+use crate::query::GraphQLConnection;
 use crate::query::GraphQLEntityType;
 use crate::query::GraphQLFlowType;
+use crate::query::GraphQLNamespace;
 use crate::query::GraphQLRelationType;
+use crate::query::GraphQLSortDirection;
+use crate::query::GraphQLTypeValidationReport;
+use crate::query::to_connection;
 
 #[derive(Default)]
 pub struct Types;
@@ -114,6 +123,28 @@ impl Types {
         Ok(components)
     }
 
+    /// Search for components and returns the requested page as connection.
+    #[allow(clippy::too_many_arguments)]
+    async fn components_connection(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type", desc = "The component type")] ty: Option<ComponentTypeIdDefinition>,
+        #[graphql(name = "namespace", desc = "Searches by the namespace of the components.")] namespace: Option<String>,
+        #[graphql(desc = "Searches by the name of the components. Allowed wildcards are: ? and *")] search: Option<String>,
+        #[graphql(desc = "Filters by having all of these properties.")] properties: Option<Vec<String>>,
+        #[graphql(desc = "Filters by having all of these extensions.")] extensions: Option<Vec<ExtensionTypeIdDefinition>>,
+        #[graphql(desc = "Sorts by namespace and type name in the given direction.")] direction: Option<GraphQLSortDirection>,
+        #[graphql(desc = "Returns the first n types.")] first: Option<usize>,
+        #[graphql(desc = "Returns the types after the given cursor.")] after: Option<String>,
+        #[graphql(desc = "Returns the last n types.")] last: Option<usize>,
+        #[graphql(desc = "Returns the types before the given cursor.")] before: Option<String>,
+    ) -> Result<GraphQLConnection<GraphQLComponent>> {
+        let mut types = self.components(context, ty, namespace, search, properties, extensions).await?;
+        let direction: SortDirection = direction.unwrap_or_default().into();
+        types.sort_by(|a, b| direction.apply(a.deref().cmp(b.deref())));
+        to_connection(types, &Pagination::new(first, after, last, before))
+    }
+
     async fn count_components(&self, context: &Context<'_>) -> usize {
         if let Ok(component_manager) = context.data::<Arc<dyn ComponentManager + Send + Sync>>() {
             return component_manager.count();
@@ -188,6 +219,28 @@ impl Types {
         Ok(entity_types)
     }
 
+    /// Search for entity types and returns the requested page as connection.
+    #[allow(clippy::too_many_arguments)]
+    async fn entities_connection(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type", desc = "The entity type")] ty: Option<EntityTypeIdDefinition>,
+        #[graphql(desc = "Searches by the name of the entity types. Allowed wildcards are: ? and *")] search: Option<String>,
+        #[graphql(desc = "Filters by having all of these properties.")] properties: Option<Vec<String>>,
+        #[graphql(desc = "Filters by having all of these components.")] components: Option<Vec<ComponentTypeIdDefinition>>,
+        #[graphql(desc = "Filters by having all of these extensions.")] extensions: Option<Vec<ExtensionTypeIdDefinition>>,
+        #[graphql(desc = "Sorts by namespace and type name in the given direction.")] direction: Option<GraphQLSortDirection>,
+        #[graphql(desc = "Returns the first n types.")] first: Option<usize>,
+        #[graphql(desc = "Returns the types after the given cursor.")] after: Option<String>,
+        #[graphql(desc = "Returns the last n types.")] last: Option<usize>,
+        #[graphql(desc = "Returns the types before the given cursor.")] before: Option<String>,
+    ) -> Result<GraphQLConnection<GraphQLEntityType>> {
+        let mut types = self.entities(context, ty, search, properties, components, extensions).await?;
+        let direction: SortDirection = direction.unwrap_or_default().into();
+        types.sort_by(|a, b| direction.apply(a.deref().cmp(b.deref())));
+        to_connection(types, &Pagination::new(first, after, last, before))
+    }
+
     async fn count_entity_types(&self, context: &Context<'_>) -> usize {
         if let Ok(entity_type_manager) = context.data::<Arc<dyn EntityTypeManager + Send + Sync>>() {
             return entity_type_manager.count();
@@ -404,6 +457,45 @@ impl Types {
         Ok(relation_types)
     }
 
+    /// Search for relation types and returns the requested page as connection.
+    #[allow(clippy::too_many_arguments)]
+    async fn relations_connection(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type", desc = "The relation type.")] ty: Option<RelationTypeIdDefinition>,
+        #[graphql(desc = "Searches by the name of the relation types. Allowed wildcards are: ? and *")] search: Option<String>,
+        #[graphql(desc = "Filters by outbound component")] outbound_component: Option<ComponentTypeIdDefinition>,
+        #[graphql(desc = "Filters by outbound entity type")] outbound_entity_type: Option<EntityTypeIdDefinition>,
+        #[graphql(desc = "Filters by inbound component")] inbound_component: Option<ComponentTypeIdDefinition>,
+        #[graphql(desc = "Filters by inbound entity type")] inbound_entity_type: Option<EntityTypeIdDefinition>,
+        #[graphql(desc = "Filters by having all of these properties.")] properties: Option<Vec<String>>,
+        #[graphql(desc = "Filters by having all of these components.")] components: Option<Vec<ComponentTypeIdDefinition>>,
+        #[graphql(desc = "Filters by having all of these extensions.")] extensions: Option<Vec<ExtensionTypeIdDefinition>>,
+        #[graphql(desc = "Sorts by namespace and type name in the given direction.")] direction: Option<GraphQLSortDirection>,
+        #[graphql(desc = "Returns the first n types.")] first: Option<usize>,
+        #[graphql(desc = "Returns the types after the given cursor.")] after: Option<String>,
+        #[graphql(desc = "Returns the last n types.")] last: Option<usize>,
+        #[graphql(desc = "Returns the types before the given cursor.")] before: Option<String>,
+    ) -> Result<GraphQLConnection<GraphQLRelationType>> {
+        let mut types = self
+            .relations(
+                context,
+                ty,
+                search,
+                outbound_component,
+                outbound_entity_type,
+                inbound_component,
+                inbound_entity_type,
+                properties,
+                components,
+                extensions,
+            )
+            .await?;
+        let direction: SortDirection = direction.unwrap_or_default().into();
+        types.sort_by(|a, b| direction.apply(a.deref().cmp(b.deref())));
+        to_connection(types, &Pagination::new(first, after, last, before))
+    }
+
     async fn count_relation_types(&self, context: &Context<'_>) -> usize {
         if let Ok(relation_type_manager) = context.data::<Arc<dyn RelationTypeManager + Send + Sync>>() {
             return relation_type_manager.count();
@@ -455,6 +547,26 @@ impl Types {
         Ok(flow_types)
     }
 
+    /// Search for flow types and returns the requested page as connection.
+    #[allow(clippy::too_many_arguments)]
+    async fn flows_connection(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type", desc = "The flow type")] ty: Option<FlowTypeIdDefinition>,
+        #[graphql(desc = "Searches by the name of the flow types. Allowed wildcards are: ? and *")] search: Option<String>,
+        #[graphql(desc = "Filters by having all of these extensions.")] extensions: Option<Vec<ExtensionTypeIdDefinition>>,
+        #[graphql(desc = "Sorts by namespace and type name in the given direction.")] direction: Option<GraphQLSortDirection>,
+        #[graphql(desc = "Returns the first n types.")] first: Option<usize>,
+        #[graphql(desc = "Returns the types after the given cursor.")] after: Option<String>,
+        #[graphql(desc = "Returns the last n types.")] last: Option<usize>,
+        #[graphql(desc = "Returns the types before the given cursor.")] before: Option<String>,
+    ) -> Result<GraphQLConnection<GraphQLFlowType>> {
+        let mut types = self.flows(context, ty, search, extensions).await?;
+        let direction: SortDirection = direction.unwrap_or_default().into();
+        types.sort_by(|a, b| direction.apply(a.deref().cmp(b.deref())));
+        to_connection(types, &Pagination::new(first, after, last, before))
+    }
+
     async fn count_flow_types(&self, context: &Context<'_>) -> usize {
         if let Ok(flow_type_manager) = context.data::<Arc<dyn FlowTypeManager + Send + Sync>>() {
             return flow_type_manager.count();
@@ -468,4 +580,35 @@ impl Types {
         };
         namespace_manager.get_all()
     }
+
+    /// Returns the metadata of the namespaces, ordered by name.
+    ///
+    /// Optionally the namespaces can be filtered by name, by the owning plugin or by a tag.
+    async fn namespace_metadata(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "The name of the namespace.")] name: Option<String>,
+        #[graphql(desc = "Filters by the plugin which owns the namespaces.")] owner: Option<String>,
+        #[graphql(desc = "Filters by having the tag.")] tag: Option<String>,
+    ) -> Result<Vec<GraphQLNamespace>> {
+        let namespace_manager = context.data::<Arc<dyn NamespaceManager + Send + Sync>>()?;
+        if let Some(name) = name {
+            return Ok(namespace_manager.get(&name).map(|namespace| namespace.into()).into_iter().collect());
+        }
+        Ok(namespace_manager
+            .get_namespaces()
+            .into_iter()
+            .filter(|namespace| owner.as_ref().map(|owner| namespace.is_owned_by(owner)).unwrap_or(true))
+            .filter(|namespace| tag.as_ref().map(|tag| namespace.has_tag(tag)).unwrap_or(true))
+            .map(|namespace| namespace.into())
+            .collect())
+    }
+
+    /// Validates all types and returns the reports of the invalid types.
+    ///
+    /// A type is invalid if it references types which doesn't exist.
+    async fn validate(&self, context: &Context<'_>) -> Result<Vec<GraphQLTypeValidationReport>> {
+        let type_dependency_manager = context.data::<Arc<dyn TypeDependencyManager + Send + Sync>>()?;
+        Ok(type_dependency_manager.validate_all().into_iter().map(|report| report.into()).collect())
+    }
 }
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::task::Poll;
+use std::time::Duration;
+
+use async_graphql::Object;
+use crossbeam::channel::Receiver;
+use crossbeam::channel::Sender;
+use futures_util::Stream;
+use log::debug;
+use rand::Rng;
+use serde_json::Value;
+use uuid::Uuid;
+
+use reactive_graph_graph::LabelPattern;
+use reactive_graph_graph::PropertyInstanceGetter;
+use reactive_graph_reactive_model_api::ReactivePropertyContainer;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+use reactive_graph_reactive_service_api::ReactiveEntityManager;
+use reactive_graph_reactive_service_api::ReactiveInstanceEventManager;
+use reactive_graph_reactive_service_api::ReactiveInstanceEventTypes;
+use reactive_graph_runtime_model::EventProperties::EVENT;
+use reactive_graph_runtime_model::LabeledProperties::LABEL;
+
+/// A property of an entity instance whose label matches a label pattern has been changed.
+#[derive(Clone, Debug)]
+pub struct GraphQLEntityLabelPropertyChanged {
+    id: Uuid,
+    label: String,
+    params: HashMap<String, String>,
+    property_name: String,
+    value: Value,
+}
+
+/// A property of an entity instance whose label matches a label pattern has been changed.
+#[Object(name = "EntityLabelPropertyChanged")]
+impl GraphQLEntityLabelPropertyChanged {
+    /// The id of the entity instance.
+    async fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// The label of the entity instance.
+    async fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    /// The parameters which have been extracted from the label.
+    async fn params(&self) -> Value {
+        serde_json::to_value(&self.params).unwrap_or_default()
+    }
+
+    /// The name of the property.
+    async fn property_name(&self) -> String {
+        self.property_name.clone()
+    }
+
+    /// The new value of the property.
+    async fn value(&self) -> Value {
+        self.value.clone()
+    }
+}
+
+/// Observes the given property of all entity instances whose label matches the label pattern.
+///
+/// Entity instances which are created while the subscription is open are observed as well.
+pub struct EntityLabelPropertyStream {
+    pattern: LabelPattern,
+    property_name: String,
+    handle_id: u128,
+    entity_instances: Arc<RwLock<HashMap<Uuid, ReactiveEntity>>>,
+    event_instance: Option<ReactiveEntity>,
+    receiver: Receiver<GraphQLEntityLabelPropertyChanged>,
+}
+
+impl EntityLabelPropertyStream {
+    pub fn new(
+        entity_instance_manager: Arc<dyn ReactiveEntityManager + Send + Sync>,
+        reactive_instance_event_manager: Option<&Arc<dyn ReactiveInstanceEventManager + Send + Sync>>,
+        pattern: LabelPattern,
+        property_name: String,
+    ) -> EntityLabelPropertyStream {
+        debug!("Opened subscription entitiesByLabel({pattern})[{property_name}]");
+        let mut rng = rand::rng();
+        let handle_id = rng.random::<u128>();
+        let (sender, receiver) = crossbeam::channel::unbounded();
+        let entity_instances = Arc::new(RwLock::new(HashMap::new()));
+        let observer = LabelPropertyObserver {
+            property_name: property_name.clone(),
+            handle_id,
+            entity_instances: entity_instances.clone(),
+            sender,
+        };
+        for (entity_instance, params) in entity_instance_manager.get_by_label_pattern(&pattern) {
+            observer.observe(entity_instance, params);
+        }
+        let event_instance = reactive_instance_event_manager.and_then(|reactive_instance_event_manager| {
+            reactive_instance_event_manager.get_reactive_instance_event_instance(ReactiveInstanceEventTypes::EntityInstanceCreated)
+        });
+        if let Some(event_instance) = &event_instance {
+            let pattern = pattern.clone();
+            event_instance.observe_with_handle(
+                &EVENT.property_name(),
+                move |value: &Value| {
+                    let Some(entity_instance) = serde_json::from_value(value.clone()).ok().and_then(|id| entity_instance_manager.get(id)) else {
+                        return;
+                    };
+                    if let Some(params) = entity_instance.as_string(LABEL.property_name()).and_then(|label| pattern.matches(&label)) {
+                        observer.observe(entity_instance, params);
+                    }
+                },
+                handle_id,
+            );
+        }
+        EntityLabelPropertyStream {
+            pattern,
+            property_name,
+            handle_id,
+            entity_instances,
+            event_instance,
+            receiver,
+        }
+    }
+}
+
+impl Stream for EntityLabelPropertyStream {
+    type Item = GraphQLEntityLabelPropertyChanged;
+
+    fn poll_next(self: Pin<&mut Self>, _context: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.receiver.try_recv() {
+            Ok(value) => {
+                std::thread::sleep(Duration::from_millis(10));
+                Poll::Ready(Some(value))
+            }
+            Err(_) => {
+                std::thread::sleep(Duration::from_millis(100));
+                Poll::Ready(None)
+            }
+        }
+    }
+}
+
+impl Drop for EntityLabelPropertyStream {
+    fn drop(&mut self) {
+        debug!("Closing subscription entitiesByLabel({})[{}]", self.pattern, self.property_name);
+        if let Some(event_instance) = &self.event_instance {
+            event_instance.remove_observer(&EVENT.property_name(), self.handle_id);
+        }
+        for entity_instance in self.entity_instances.read().unwrap().values() {
+            entity_instance.remove_observer(&self.property_name, self.handle_id);
+        }
+    }
+}
+
+/// Registers the property observers of the matching entity instances.
+struct LabelPropertyObserver {
+    property_name: String,
+    handle_id: u128,
+    entity_instances: Arc<RwLock<HashMap<Uuid, ReactiveEntity>>>,
+    sender: Sender<GraphQLEntityLabelPropertyChanged>,
+}
+
+impl LabelPropertyObserver {
+    fn observe(&self, entity_instance: ReactiveEntity, params: HashMap<String, String>) {
+        if !entity_instance.properties.contains_key(&self.property_name) {
+            return;
+        }
+        let mut writer = self.entity_instances.write().unwrap();
+        if writer.contains_key(&entity_instance.id) {
+            return;
+        }
+        let id = entity_instance.id;
+        let label = entity_instance.as_string(LABEL.property_name()).unwrap_or_default();
+        let property_name = self.property_name.clone();
+        let sender = self.sender.clone();
+        entity_instance.observe_with_handle(
+            &self.property_name,
+            move |value: &Value| {
+                let _ = sender.send(GraphQLEntityLabelPropertyChanged {
+                    id,
+                    label: label.clone(),
+                    params: params.clone(),
+                    property_name: property_name.clone(),
+                    value: value.clone(),
+                });
+            },
+            self.handle_id,
+        );
+        writer.insert(id, entity_instance);
+    }
+}
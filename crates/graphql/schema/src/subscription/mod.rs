@@ -12,15 +12,23 @@ use serde_json::Value;
 use uuid::Uuid;
 
 pub use entity_instance::*;
+pub use entity_label::*;
 pub use relation_instance::*;
+pub use saved_query::*;
 
 use crate::mutation::GraphQLRelationInstanceId;
 use crate::query::GraphQLPropertyInstance;
+use reactive_graph_graph::LabelPattern;
+use reactive_graph_graph::SavedQueryChange;
 use reactive_graph_reactive_service_api::ReactiveEntityManager;
+use reactive_graph_reactive_service_api::ReactiveInstanceEventManager;
 use reactive_graph_reactive_service_api::ReactiveRelationManager;
+use reactive_graph_reactive_service_api::SavedQueryManager;
 
 pub mod entity_instance;
+pub mod entity_label;
 pub mod relation_instance;
+pub mod saved_query;
 
 pub struct ReactiveGraphSubscription;
 
@@ -73,6 +81,34 @@ impl ReactiveGraphSubscription {
         }
     }
 
+    /// Subscribes to the changes of a property of all entity instances whose label matches
+    /// the given label pattern. Entity instances which are created later are included as well.
+    async fn entities_by_label(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "The label pattern, for example /org/inexor/sensors/*/temperature")] pattern: String,
+        #[graphql(desc = "The name of the property")] property_name: String,
+    ) -> Result<impl Stream<Item = GraphQLEntityLabelPropertyChanged>> {
+        let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityManager + Send + Sync>>()?;
+        let reactive_instance_event_manager = context.data_opt::<Arc<dyn ReactiveInstanceEventManager + Send + Sync>>();
+        let pattern = LabelPattern::parse(&pattern)?;
+        let mut stream = EntityLabelPropertyStream::new(entity_instance_manager.clone(), reactive_instance_event_manager, pattern, property_name);
+
+        Ok(async_stream::stream! {
+            loop {
+                match stream.next().await {
+                    Some(value_changed) => {
+                        futures_timer::Delay::new(Duration::from_millis(10)).await;
+                        yield value_changed;
+                    }
+                    None => {
+                        futures_timer::Delay::new(Duration::from_millis(100)).await;
+                    }
+                };
+            }
+        })
+    }
+
     async fn relation(
         &self,
         context: &Context<'_>,
@@ -107,6 +143,43 @@ impl ReactiveGraphSubscription {
             Err(_) => Err("Error: REIM".into()),
         }
     }
+
+    /// Subscribes to the changes of the materialized result set of a saved query.
+    ///
+    /// Instead of the full result set, only the added, changed and removed entity instances
+    /// are pushed. If initial is true, the current result set is pushed as added entity
+    /// instances first.
+    async fn saved_query(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "The name of the saved query")] name: String,
+        #[graphql(desc = "Pushes the current result set first", default = true)] initial: bool,
+    ) -> Result<impl Stream<Item = GraphQLSavedQueryChange>> {
+        let saved_query_manager = context.data::<Arc<dyn SavedQueryManager + Send + Sync>>()?;
+        let mut stream = SavedQueryChangeStream::new(saved_query_manager.clone(), name.clone()).ok_or(format!("Saved query {name} does not exist"))?;
+        let initial_rows = if initial {
+            saved_query_manager.get_rows(&name).unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+
+        Ok(async_stream::stream! {
+            for row in initial_rows {
+                yield GraphQLSavedQueryChange::from(SavedQueryChange::Added(row));
+            }
+            loop {
+                match stream.next().await {
+                    Some(change) => {
+                        futures_timer::Delay::new(Duration::from_millis(10)).await;
+                        yield GraphQLSavedQueryChange::from(change);
+                    }
+                    None => {
+                        futures_timer::Delay::new(Duration::from_millis(100)).await;
+                    }
+                };
+            }
+        })
+    }
 }
 
 #[derive(Serialize)]
@@ -0,0 +1,113 @@
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Poll;
+use std::time::Duration;
+
+use async_graphql::Enum;
+use async_graphql::Object;
+use crossbeam::channel::Receiver;
+use futures_util::Stream;
+use log::debug;
+use rand::Rng;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use strum::Display;
+use uuid::Uuid;
+
+use reactive_graph_graph::SavedQueryChange;
+use reactive_graph_reactive_service_api::SavedQueryManager;
+
+/// The kind of a change of the result set of a saved query.
+#[derive(Enum, Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Display)]
+#[graphql(name = "SavedQueryChangeKind")]
+pub enum GraphQLSavedQueryChangeKind {
+    /// The entity instance has been added to the result set.
+    Added,
+
+    /// The projected properties of the entity instance have been changed.
+    Changed,
+
+    /// The entity instance has been removed from the result set.
+    Removed,
+}
+
+/// A change of the result set of a saved query.
+pub struct GraphQLSavedQueryChange {
+    change: SavedQueryChange,
+}
+
+/// A change of the result set of a saved query.
+#[Object(name = "SavedQueryChange")]
+impl GraphQLSavedQueryChange {
+    /// The kind of the change.
+    async fn kind(&self) -> GraphQLSavedQueryChangeKind {
+        match self.change {
+            SavedQueryChange::Added(_) => GraphQLSavedQueryChangeKind::Added,
+            SavedQueryChange::Changed(_) => GraphQLSavedQueryChangeKind::Changed,
+            SavedQueryChange::Removed(_) => GraphQLSavedQueryChangeKind::Removed,
+        }
+    }
+
+    /// The id of the entity instance.
+    async fn id(&self) -> Uuid {
+        self.change.id()
+    }
+
+    /// The projected properties of the added or changed entity instance.
+    async fn properties(&self) -> Option<Value> {
+        self.change.properties().and_then(|properties| serde_json::to_value(properties).ok())
+    }
+}
+
+impl From<SavedQueryChange> for GraphQLSavedQueryChange {
+    fn from(change: SavedQueryChange) -> Self {
+        GraphQLSavedQueryChange { change }
+    }
+}
+
+pub struct SavedQueryChangeStream {
+    saved_query_manager: Arc<dyn SavedQueryManager + Send + Sync>,
+    name: String,
+    handle_id: u128,
+    receiver: Receiver<SavedQueryChange>,
+}
+
+impl SavedQueryChangeStream {
+    pub fn new(saved_query_manager: Arc<dyn SavedQueryManager + Send + Sync>, name: String) -> Option<SavedQueryChangeStream> {
+        let mut rng = rand::rng();
+        let handle_id = rng.random::<u128>();
+        let receiver = saved_query_manager.subscribe(&name, handle_id)?;
+        debug!("Opened subscription savedQuery({name})");
+        Some(SavedQueryChangeStream {
+            saved_query_manager,
+            name,
+            handle_id,
+            receiver,
+        })
+    }
+}
+
+impl Stream for SavedQueryChangeStream {
+    type Item = SavedQueryChange;
+
+    fn poll_next(self: Pin<&mut Self>, _context: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.receiver.try_recv() {
+            Ok(change) => {
+                std::thread::sleep(Duration::from_millis(10));
+                Poll::Ready(Some(change))
+            }
+            Err(_) => {
+                std::thread::sleep(Duration::from_millis(100));
+                Poll::Ready(None)
+            }
+        }
+    }
+}
+
+impl Drop for SavedQueryChangeStream {
+    fn drop(&mut self) {
+        debug!("Closing subscription savedQuery({})", self.name);
+        self.saved_query_manager.unsubscribe(&self.name, self.handle_id);
+    }
+}
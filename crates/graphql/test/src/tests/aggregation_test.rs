@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+use serde_json::json;
+
+use reactive_graph_graph::EntityType;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::PropertyInstances;
+use reactive_graph_graph::PropertyType;
+use reactive_graph_graph::PropertyTypes;
+use reactive_graph_graph::RelationInstanceId;
+use reactive_graph_graph::RelationType;
+use reactive_graph_graph::RelationTypeId;
+use reactive_graph_runtime_api::Runtime;
+use reactive_graph_runtime_impl::get_runtime;
+
+async fn query(rt: &Arc<dyn Runtime + Send + Sync>, query: &str) -> Value {
+    let result = rt.get_graphql_query_service().query(query).await.expect("Failed to run the query");
+    serde_json::from_str(&result).expect("Failed to parse the result")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_graphql_aggregate_entity_instances() {
+    let rt: Arc<dyn Runtime + Send + Sync> = get_runtime();
+
+    let ty = EntityTypeId::new_from_type("inventory", "sensor");
+    let entity_type = EntityType::builder()
+        .ty(ty.clone())
+        .properties(
+            PropertyTypes::new()
+                .property(PropertyType::string("location"))
+                .property(PropertyType::number("temperature")),
+        )
+        .build();
+    rt.get_entity_type_manager().register(entity_type).expect("Failed to register entity type");
+
+    let reactive_entity_manager = rt.get_reactive_entity_manager();
+    for (location, temperature) in [("kitchen", 20.0), ("kitchen", 22.0), ("garden", 10.0)] {
+        reactive_entity_manager
+            .create_reactive_entity(&ty, PropertyInstances::new().property("location", location).property("temperature", temperature))
+            .expect("Failed to create entity");
+    }
+
+    let result = query(
+        &rt,
+        r#"
+        query {
+          instances {
+            aggregateEntityInstances(
+              type: { namespace: "inventory", name: "sensor" }
+              filter: { name: "temperature", gt: 15 }
+              groupBy: { kind: PROPERTY, property: "location" }
+              property: "temperature"
+            ) {
+              key
+              count
+              aggregate {
+                avg
+              }
+            }
+          }
+        }
+    "#,
+    )
+    .await;
+    assert_eq!(
+        json!({
+            "instances": {
+                "aggregateEntityInstances": [
+                    { "key": "kitchen", "count": 2, "aggregate": { "avg": 21.0 } }
+                ]
+            }
+        }),
+        result["data"],
+        "{result}"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_graphql_aggregate_relation_instances() {
+    let rt: Arc<dyn Runtime + Send + Sync> = get_runtime();
+
+    let entity_ty = EntityTypeId::new_from_type("network", "node");
+    let entity_type = EntityType::builder().ty(entity_ty.clone()).build();
+    rt.get_entity_type_manager().register(entity_type).expect("Failed to register entity type");
+    let relation_ty = RelationTypeId::new_from_type("network", "link");
+    let relation_type = RelationType::builder()
+        .outbound_type(entity_ty.clone())
+        .ty(relation_ty.clone())
+        .inbound_type(entity_ty.clone())
+        .properties(PropertyTypes::new().property(PropertyType::number("latency")))
+        .build();
+    rt.get_relation_type_manager()
+        .register(relation_type)
+        .expect("Failed to register relation type");
+
+    let reactive_entity_manager = rt.get_reactive_entity_manager();
+    let outbound = reactive_entity_manager
+        .create_reactive_entity(&entity_ty, PropertyInstances::new())
+        .expect("Failed to create entity");
+    let inbound = reactive_entity_manager
+        .create_reactive_entity(&entity_ty, PropertyInstances::new())
+        .expect("Failed to create entity");
+    let reactive_relation_manager = rt.get_reactive_relation_manager();
+    for (instance_id, latency) in [("primary", 5.0), ("secondary", 15.0)] {
+        let id = RelationInstanceId::new_unique_for_instance_id(outbound.id, relation_ty.clone(), instance_id.to_string(), inbound.id);
+        reactive_relation_manager
+            .create_reactive_relation(&id, PropertyInstances::new().property("latency", latency))
+            .expect("Failed to create relation");
+    }
+
+    let result = query(
+        &rt,
+        r#"
+        query {
+          instances {
+            aggregateRelationInstances(namespace: "network", groupBy: { kind: TYPE }, property: "latency") {
+              key
+              count
+              aggregate {
+                sum
+                max
+              }
+            }
+          }
+        }
+    "#,
+    )
+    .await;
+    assert_eq!(
+        json!({
+            "instances": {
+                "aggregateRelationInstances": [
+                    { "key": "network__link", "count": 2, "aggregate": { "sum": 20.0, "max": 15.0 } }
+                ]
+            }
+        }),
+        result["data"],
+        "{result}"
+    );
+}
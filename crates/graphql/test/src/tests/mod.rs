@@ -1 +1,2 @@
+mod aggregation_test;
 mod query_test;
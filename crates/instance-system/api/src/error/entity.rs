@@ -1,10 +1,13 @@
 use reactive_graph_reactive_service_api::ReactiveEntityCreationError;
+use reactive_graph_serde::error::DeserializationError;
+use reactive_graph_serde::error::SerializationError;
 use uuid::Uuid;
 
 #[derive(Debug)]
 pub enum EntityInstanceImportError {
     Io(std::io::Error),
-    Deserialization(serde_json::Error),
+    UnsupportedFormat(String),
+    Deserialization(DeserializationError),
     EntityAlreadyExists(Uuid),
     ReactiveEntityCreationError(ReactiveEntityCreationError),
 }
@@ -15,8 +18,8 @@ impl From<std::io::Error> for EntityInstanceImportError {
     }
 }
 
-impl From<serde_json::Error> for EntityInstanceImportError {
-    fn from(e: serde_json::Error) -> Self {
+impl From<DeserializationError> for EntityInstanceImportError {
+    fn from(e: DeserializationError) -> Self {
         EntityInstanceImportError::Deserialization(e)
     }
 }
@@ -25,5 +28,6 @@ impl From<serde_json::Error> for EntityInstanceImportError {
 pub enum EntityInstanceExportError {
     EntityNotFound(Uuid),
     Io(String),
-    Serialization(serde_json::Error),
+    UnsupportedFormat(String),
+    Serialization(SerializationError),
 }
@@ -3,11 +3,14 @@
 
 use reactive_graph_graph::RelationInstanceId;
 use reactive_graph_reactive_service_api::ReactiveRelationCreationError;
+use reactive_graph_serde::error::DeserializationError;
+use reactive_graph_serde::error::SerializationError;
 
 #[derive(Debug)]
 pub enum RelationInstanceImportError {
     Io(std::io::Error),
-    Deserialize(serde_json::Error),
+    UnsupportedFormat(String),
+    Deserialize(DeserializationError),
     RelationAlreadyExists(RelationInstanceId),
     ReactiveRelationCreationError(ReactiveRelationCreationError),
 }
@@ -18,8 +21,8 @@ impl From<std::io::Error> for RelationInstanceImportError {
     }
 }
 
-impl From<serde_json::Error> for RelationInstanceImportError {
-    fn from(e: serde_json::Error) -> Self {
+impl From<DeserializationError> for RelationInstanceImportError {
+    fn from(e: DeserializationError) -> Self {
         RelationInstanceImportError::Deserialize(e)
     }
 }
@@ -28,5 +31,6 @@ impl From<serde_json::Error> for RelationInstanceImportError {
 pub enum RelationInstanceExportError {
     RelationNotFound(RelationInstanceId),
     Io(String),
-    Serialization(serde_json::Error),
+    UnsupportedFormat(String),
+    Serialization(SerializationError),
 }
@@ -1,5 +1,4 @@
-use std::fs::File;
-use std::io::BufReader;
+use std::fs;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -14,6 +13,8 @@ use reactive_graph_instance_system_api::EntityInstanceImportExportManager;
 use reactive_graph_lifecycle::Lifecycle;
 use reactive_graph_reactive_model_impl::ReactiveEntity;
 use reactive_graph_reactive_service_api::ReactiveEntityManager;
+use reactive_graph_serde::error::SerializationFormatError;
+use reactive_graph_serde::format::SerializationFormat;
 
 #[derive(Component)]
 pub struct EntityInstanceImportExportManagerImpl {
@@ -24,9 +25,10 @@ pub struct EntityInstanceImportExportManagerImpl {
 #[component_alias]
 impl EntityInstanceImportExportManager for EntityInstanceImportExportManagerImpl {
     async fn import(&self, path: &str) -> Result<ReactiveEntity, EntityInstanceImportError> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        let entity_instance: EntityInstance = serde_json::from_reader(reader)?;
+        let format = SerializationFormat::from_path(path)
+            .map_err(|SerializationFormatError::UnsupportedFormat(format)| EntityInstanceImportError::UnsupportedFormat(format))?;
+        let content = fs::read_to_string(path)?;
+        let entity_instance: EntityInstance = format.deserialize(&content)?;
         if self.reactive_entity_manager.has(entity_instance.id) {
             return Err(EntityInstanceImportError::EntityAlreadyExists(entity_instance.id));
         }
@@ -39,11 +41,11 @@ impl EntityInstanceImportExportManager for EntityInstanceImportExportManagerImpl
         let Some(entity_instance) = self.reactive_entity_manager.get(id) else {
             return Err(EntityInstanceExportError::EntityNotFound(id));
         };
-        let Ok(file) = File::create(path) else {
-            return Err(EntityInstanceExportError::Io(String::from(path)));
-        };
+        let format = SerializationFormat::from_path(path)
+            .map_err(|SerializationFormatError::UnsupportedFormat(format)| EntityInstanceExportError::UnsupportedFormat(format))?;
         let entity_instance = EntityInstance::from(entity_instance);
-        serde_json::to_writer_pretty(&file, &entity_instance).map_err(EntityInstanceExportError::Serialization)
+        let content = format.serialize(&entity_instance).map_err(EntityInstanceExportError::Serialization)?;
+        fs::write(path, content).map_err(|_| EntityInstanceExportError::Io(String::from(path)))
     }
 }
 
@@ -53,14 +55,17 @@ impl Lifecycle for EntityInstanceImportExportManagerImpl {}
 #[cfg(test)]
 mod tests {
     use std::env;
+    use std::fs;
 
     use default_test::DefaultTest;
+    use serde_json::Value;
 
     use crate::InstanceSystemImpl;
     use reactive_graph_graph::EntityInstance;
     use reactive_graph_graph::EntityType;
     use reactive_graph_graph::NamespacedTypeGetter;
     use reactive_graph_instance_system_api::InstanceSystem;
+    use reactive_graph_serde::format::SerializationFormat;
     use reactive_graph_utils_test::DefaultFrom;
 
     // Do not remove! This import is necessary to make the dependency injection work
@@ -112,4 +117,52 @@ mod tests {
         assert_eq!(uuid, imported_reactive_entity.id, "The imported reactive entity's id doesn't match");
         assert!(reactive_entity_manager.has(uuid), "The reactive entity should have been registered during import");
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_entity_instance_import_export_formats() {
+        reactive_graph_utils_test::init_logger();
+        let instance_system = reactive_graph_di::get_container::<InstanceSystemImpl>();
+        let reactive_system = instance_system.reactive_system();
+        let type_system = reactive_system.type_system();
+        let entity_type_manager = type_system.get_entity_type_manager();
+        let reactive_entity_manager = reactive_system.get_reactive_entity_manager();
+        let entity_instance_import_export_manager = instance_system.get_entity_instance_import_export_manager();
+        let schema: Value = serde_json::from_str(include_str!("../../../../schema/json/entity-instance.schema.json")).expect("Invalid JSON schema");
+
+        let entity_type = entity_type_manager
+            .register(EntityType::default_test())
+            .expect("Failed to register entity type");
+
+        for format in SerializationFormat::all() {
+            let mut path = env::temp_dir();
+            path.push(format!("{}.{}", entity_type.type_name(), format.extension()));
+            let path = path.into_os_string().into_string().unwrap();
+
+            let reactive_entity = reactive_entity_manager
+                .create_reactive_instance(EntityInstance::default_from(&entity_type))
+                .expect("Failed to create reactive instance");
+            let uuid = reactive_entity.id;
+
+            entity_instance_import_export_manager
+                .export(uuid, &path)
+                .await
+                .expect("Failed to export entity instance");
+            let document: Value = format
+                .deserialize(&fs::read_to_string(&path).expect("Failed to read exported entity instance"))
+                .expect("Failed to parse exported entity instance");
+            assert_eq!(schema["$id"], document["$id"], "The exported {format} document must reference the JSON schema");
+
+            assert!(reactive_entity_manager.delete(uuid), "Failed to delete reactive entity!");
+            let imported_reactive_entity = entity_instance_import_export_manager
+                .import(&path)
+                .await
+                .expect("Failed to import entity instance");
+            assert_eq!(uuid, imported_reactive_entity.id, "The {format} round trip should not change the id");
+            assert_eq!(
+                EntityInstance::from(reactive_entity),
+                EntityInstance::from(imported_reactive_entity),
+                "The {format} round trip should not change the entity instance"
+            );
+        }
+    }
 }
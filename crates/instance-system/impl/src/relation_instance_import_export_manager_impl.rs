@@ -1,5 +1,4 @@
-use std::fs::File;
-use std::io::BufReader;
+use std::fs;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -14,6 +13,8 @@ use reactive_graph_instance_system_api::RelationInstanceImportExportManager;
 use reactive_graph_lifecycle::Lifecycle;
 use reactive_graph_reactive_model_impl::ReactiveRelation;
 use reactive_graph_reactive_service_api::ReactiveRelationManager;
+use reactive_graph_serde::error::SerializationFormatError;
+use reactive_graph_serde::format::SerializationFormat;
 
 #[derive(Component)]
 pub struct RelationInstanceImportExportManagerImpl {
@@ -25,9 +26,10 @@ pub struct RelationInstanceImportExportManagerImpl {
 #[component_alias]
 impl RelationInstanceImportExportManager for RelationInstanceImportExportManagerImpl {
     async fn import(&self, path: &str) -> Result<ReactiveRelation, RelationInstanceImportError> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        let relation_instance: RelationInstance = serde_json::from_reader(reader)?;
+        let format = SerializationFormat::from_path(path)
+            .map_err(|SerializationFormatError::UnsupportedFormat(format)| RelationInstanceImportError::UnsupportedFormat(format))?;
+        let content = fs::read_to_string(path)?;
+        let relation_instance: RelationInstance = format.deserialize(&content)?;
         let id = relation_instance.id();
         if self.reactive_relation_manager.has(&id) {
             return Err(RelationInstanceImportError::RelationAlreadyExists(id));
@@ -41,11 +43,11 @@ impl RelationInstanceImportExportManager for RelationInstanceImportExportManager
         let Some(relation_instance) = self.reactive_relation_manager.get(id) else {
             return Err(RelationInstanceExportError::RelationNotFound(id.clone()));
         };
-        let Ok(file) = File::create(path) else {
-            return Err(RelationInstanceExportError::Io(String::from(path)));
-        };
+        let format = SerializationFormat::from_path(path)
+            .map_err(|SerializationFormatError::UnsupportedFormat(format)| RelationInstanceExportError::UnsupportedFormat(format))?;
         let relation_instance = RelationInstance::from(relation_instance);
-        serde_json::to_writer_pretty(&file, &relation_instance).map_err(RelationInstanceExportError::Serialization)
+        let content = format.serialize(&relation_instance).map_err(RelationInstanceExportError::Serialization)?;
+        fs::write(path, content).map_err(|_| RelationInstanceExportError::Io(String::from(path)))
     }
 }
 
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
+pub enum PluginCapabilityViolation {
+    #[error("The plugin is not permitted to write into namespace {0}")]
+    Namespace(String),
+    #[error("The plugin is not permitted to register a web resource provider for context path {0}")]
+    WebContextPath(String),
+    #[error("The plugin is not permitted to register command {0}")]
+    Command(String),
+    #[error("The plugin is not permitted to read the configuration {0}")]
+    Config(String),
+}
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PluginConfigError {
+    #[error("The plugin declaration of the plugin is not loaded")]
+    MissingPluginDeclaration,
+    #[error("Failed to read the configuration file {0}: {1}")]
+    ReadError(String, String),
+    #[error("The configuration file {0} is not valid TOML: {1}")]
+    ParseError(String, String),
+    #[error("The configuration schema of the plugin is not a valid JSON schema: {0}")]
+    InvalidSchema(String),
+    #[error("The configuration of the plugin is invalid: {}", .0.join(", "))]
+    ValidationFailed(Vec<String>),
+}
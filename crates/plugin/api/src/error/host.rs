@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PluginHostError {
+    #[error("Failed to start the plugin host {0}: {1}")]
+    SpawnFailed(String, String),
+    #[error("The plugin host of {0} did not become ready")]
+    NotReady(String),
+    #[error("The plugin host of {0} is not running")]
+    NotRunning(String),
+    #[error("The plugin host of {0} has been disconnected")]
+    Disconnected(String),
+    #[error("The plugin host of {0} did not respond in time")]
+    Timeout(String),
+    #[error("The plugin host of {0} failed: {1}")]
+    RequestFailed(String, String),
+    #[error("The plugin host can't run {0}: {1}")]
+    Failed(String, String),
+}
@@ -4,5 +4,6 @@ pub enum HotDeployError {
     NoDynamicLinkLibrary,
     InvalidInstallPath,
     MoveError,
+    PackageIncomplete,
     ArchiveError, // TODO: Nested error
 }
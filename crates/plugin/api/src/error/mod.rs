@@ -1,4 +1,10 @@
 pub mod activation;
+pub mod capability;
+pub mod config;
+pub mod host;
 pub mod hot_deploy;
 pub mod lifecycle;
 pub mod loading;
+pub mod package;
+pub mod registry;
+pub mod upgrade;
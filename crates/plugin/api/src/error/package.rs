@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PluginPackageError {
+    #[error("The plugin package has no manifest {0}")]
+    ManifestMissing(String),
+    #[error("Failed to read {0}: {1}")]
+    ReadError(String, String),
+    #[error("The manifest {0} is invalid: {1}")]
+    ManifestInvalid(String, String),
+    #[error("The manifest {0} is not signed")]
+    SignatureMissing(String),
+    #[error("The signature of the manifest {0} is invalid: {1}")]
+    SignatureInvalid(String, String),
+    #[error("The checksum of the dynamic link library {0} does not match the manifest: Expected {1} - Actual {2}")]
+    ChecksumMismatch(String, String, String),
+}
@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PluginRegistryError {
+    #[error("Failed to read the plugin registry index {0}: {1}")]
+    ReadError(String, String),
+    #[error("The plugin registry index {0} is invalid: {1}")]
+    ParseError(String, String),
+    #[error("The version {1} of plugin {0} is not a valid semantic version: {2}")]
+    InvalidVersion(String, String, String),
+    #[error("The version requirement {1} on plugin {0} is invalid: {2}")]
+    InvalidVersionRequirement(String, String, String),
+    #[error("The plugin {0} doesn't exist in the plugin registry")]
+    PluginNotFound(String),
+    #[error("The plugin registry contains no version of plugin {0} matching {1}")]
+    NoMatchingVersion(String, String),
+    #[error("Conflicting version requirements on plugin {}: {}", .0, .1.join(", "))]
+    Conflict(String, Vec<String>),
+    #[error("The installed plugin {0} requires {1} {2}, but {1} {3} would be installed")]
+    DependentConflict(String, String, String, String),
+    #[error("The checksum of the plugin package {0} does not match the plugin registry index: Expected {1} - Actual {2}")]
+    ChecksumMismatch(String, String, String),
+    #[error("Failed to install the plugin package {0}: {1}")]
+    InstallError(String, String),
+    #[error("The package file {2} of plugin {0} {1} is not a file name")]
+    InvalidPackageFile(String, String, String),
+    #[error("The installed plugin {0} has no manifest: Its name, version and dependencies are unknown")]
+    ManifestMissing(String),
+    #[error("The manifest {0} of the installed plugin is invalid: {1}")]
+    ManifestInvalid(String, String),
+}
@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PluginUpgradeError {
+    #[error("The plugin {0} doesn't exist")]
+    PluginNotFound(String),
+    #[error("The plugin {0} can't be upgraded because it is not active")]
+    NotActive(String),
+    #[error("The new version of plugin {0} doesn't exist at {1}")]
+    NewVersionNotFound(String, String),
+    #[error("Failed to install the new version of plugin {0}: {1}")]
+    InstallError(String, String),
+    #[error("The new version of plugin {0} could not be resolved: {1}")]
+    ResolveFailed(String, String),
+    #[error("The new version of plugin {0} could not be activated: {1}")]
+    ActivationFailed(String, String),
+}
@@ -6,6 +6,7 @@ use reactive_graph_behaviour_model_api::BehaviourTypeId;
 use reactive_graph_graph::ComponentTypeId;
 use reactive_graph_graph::EntityInstance;
 use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::LabelPattern;
 use reactive_graph_reactive_model_impl::ReactiveEntity;
 use reactive_graph_reactive_service_api::ReactiveEntityComponentAddError;
 use reactive_graph_reactive_service_api::ReactiveEntityCreationError;
@@ -26,6 +27,10 @@ pub trait EntityInstanceManager: Send + Sync {
     /// /io/reactive-graph/local/users/PeterPenacka returns: (instance, {"user_id": "PeterPenacka"})
     fn get_by_label_with_params(&self, label: &str) -> Option<(ReactiveEntity, HashMap<String, String>)>;
 
+    /// Returns all reactive entity instances whose label matches the given label pattern
+    /// together with the extracted parameters, ordered by label.
+    fn get_by_label_pattern(&self, pattern: &LabelPattern) -> Vec<(ReactiveEntity, HashMap<String, String>)>;
+
     /// Returns all reactive entity instances.
     fn get_all(&self) -> Vec<ReactiveEntity>;
 
@@ -1,6 +1,8 @@
 #[cfg(feature = "json5")]
 pub use json5;
 pub use serde_json;
+#[cfg(feature = "yaml")]
+pub use serde_norway;
 pub use springtime_di;
 pub use springtime_di::Component;
 pub use springtime_di::component_alias;
@@ -19,9 +21,15 @@ pub use behaviours::entities::entity_component_behaviour_registry::*;
 pub use behaviours::relations::relation_behaviour_registry::*;
 pub use behaviours::relations::relation_component_behaviour_registry::*;
 pub use error::activation::*;
+pub use error::capability::*;
+pub use error::config::*;
+pub use error::host::*;
 pub use error::hot_deploy::*;
 pub use error::lifecycle::*;
 pub use error::loading::*;
+pub use error::package::*;
+pub use error::registry::*;
+pub use error::upgrade::*;
 pub use graphql::graphql_query_service::*;
 pub use graphql::http_body::HttpBody;
 pub use graphql::web_resource_manager::*;
@@ -31,6 +39,7 @@ pub use instances::flows::flow_instance_manager::*;
 pub use instances::relations::relation_instance_manager::*;
 pub use plugin::PLUGIN_NAME_PREFIX;
 pub use plugin::Plugin;
+pub use plugin_capabilities::*;
 pub use plugin_context::*;
 pub use plugin_declaration::*;
 pub use plugin_dependency::*;
@@ -57,6 +66,7 @@ pub use types::entities::entity_type_provider_registry::*;
 pub use types::flows::flow_type_import_export_manager::*;
 pub use types::flows::flow_type_manager::*;
 pub use types::flows::flow_type_provider_registry::*;
+pub use types::namespace_manager::*;
 pub use types::relations::relation_type_import_export_manager::*;
 pub use types::relations::relation_type_manager::*;
 pub use types::relations::relation_type_provider_registry::*;
@@ -75,6 +85,8 @@ pub mod embedded_asset_provider;
 pub mod error;
 pub mod graphql;
 pub mod plugin;
+pub mod plugin_capabilities;
+pub mod plugin_config;
 pub mod plugin_context;
 pub mod plugin_declaration;
 pub mod plugin_dependency;
@@ -90,6 +102,32 @@ macro_rules! export_plugin {
         $crate::construct_plugin!();
         $crate::register_plugin!();
         $crate::plugin_dependencies!();
+        $crate::plugin_config_schema!();
+        $crate::plugin_capabilities!();
+        $crate::export_plugin_declaration!();
+    };
+    ({
+        "config_schema": $config_schema: expr
+        $(, "capabilities": $capabilities: expr)?
+        $(,)?
+    }) => {
+        $crate::export_plugin_constants!();
+        $crate::construct_plugin!();
+        $crate::register_plugin!();
+        $crate::plugin_dependencies!();
+        $crate::plugin_config_schema!($config_schema);
+        $crate::plugin_capabilities!($($capabilities)?);
+        $crate::export_plugin_declaration!();
+    };
+    ({
+        "capabilities": $capabilities: expr $(,)?
+    }) => {
+        $crate::export_plugin_constants!();
+        $crate::construct_plugin!();
+        $crate::register_plugin!();
+        $crate::plugin_dependencies!();
+        $crate::plugin_config_schema!();
+        $crate::plugin_capabilities!($capabilities);
         $crate::export_plugin_declaration!();
     };
     ({
@@ -97,7 +135,10 @@ macro_rules! export_plugin {
             "name": $name: expr,
             "description": $description: expr,
             "version": $version: expr $(,)?
-        } $(,)?
+        }
+        $(, "config_schema": $config_schema: expr)?
+        $(, "capabilities": $capabilities: expr)?
+        $(,)?
     }) => {
         $crate::export_plugin_constants!(
             "plugin": {
@@ -109,6 +150,8 @@ macro_rules! export_plugin {
         $crate::construct_plugin!();
         $crate::register_plugin!();
         $crate::plugin_dependencies!();
+        $crate::plugin_config_schema!($($config_schema)?);
+        $crate::plugin_capabilities!($($capabilities)?);
         $crate::export_plugin_declaration!();
     };
     ({
@@ -118,6 +161,9 @@ macro_rules! export_plugin {
                 "version": $version_range: expr $(,)?
             } $(,)?)*
         ]
+        $(, "config_schema": $config_schema: expr)?
+        $(, "capabilities": $capabilities: expr)?
+        $(,)?
     }) => {
         $crate::export_plugin_constants!();
         $crate::construct_plugin!();
@@ -130,6 +176,8 @@ macro_rules! export_plugin {
                 },)*
             ],
         );
+        $crate::plugin_config_schema!($($config_schema)?);
+        $crate::plugin_capabilities!($($capabilities)?);
         $crate::export_plugin_declaration!();
     };
     ({
@@ -144,6 +192,8 @@ macro_rules! export_plugin {
                 "version": $version_range: expr $(,)?
             } $(,)?)*
         ]
+        $(, "config_schema": $config_schema: expr)?
+        $(, "capabilities": $capabilities: expr)?
         $(,)?
     }) => {
         $crate::export_plugin_constants!(
@@ -163,6 +213,8 @@ macro_rules! export_plugin {
                 },)*
             ],
         );
+        $crate::plugin_config_schema!($($config_schema)?);
+        $crate::plugin_capabilities!($($capabilities)?);
         $crate::export_plugin_declaration!();
     };
 }
@@ -369,16 +421,15 @@ macro_rules! construct_plugin {
                 .map_err($crate::PluginLoadingError::ComponentDefinitionRegistryError)
                 .map(|component_factory| component_factory.build())
                 .and_then(|mut component_factory| {
-                    // Get the
-                    $crate::springtime_di::instance_provider::TypedComponentInstanceProvider::primary_instance_typed::<dyn $crate::Plugin + Send + Sync>(
-                        &mut component_factory,
-                    )
-                    .map_err($crate::PluginLoadingError::ComponentInstanceProviderError)
+                    // Get the plugin component
+                    $crate::plugin::get_plugin_component(&mut component_factory, PLUGIN_NAME)
+                        .map_err($crate::PluginLoadingError::ComponentInstanceProviderError)
                 })
         }
     };
 }
 
+#[cfg(not(feature = "static"))]
 #[macro_export]
 macro_rules! register_plugin {
     () => {
@@ -399,6 +450,25 @@ macro_rules! register_plugin {
     };
 }
 
+/// A statically linked plugin shares the logger of the server.
+#[cfg(feature = "static")]
+#[macro_export]
+macro_rules! register_plugin {
+    () => {
+        /// The `register` method is
+        #[allow(improper_ctypes_definitions)]
+        extern "C" fn register(registrar: &mut dyn $crate::PluginRegistrar) -> Result<(), $crate::PluginLoadingError> {
+            match construct_plugin(registrar.context()) {
+                Ok(plugin) => {
+                    registrar.register_plugin(Box::new(plugin));
+                    Ok(())
+                }
+                Err(e) => Err(e),
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! get_context {
     ($context: expr, $err: expr) => {
@@ -1,4 +1,9 @@
 use async_trait::async_trait;
+use serde_json::Value;
+use springtime_di::factory::ComponentFactory;
+use springtime_di::instance_provider::ComponentInstanceProviderError;
+use springtime_di::instance_provider::ComponentInstancePtr;
+use springtime_di::instance_provider::TypedComponentInstanceProvider;
 
 use crate::PluginActivationError;
 use crate::PluginDeactivationError;
@@ -18,4 +23,25 @@ pub trait Plugin: Send + Sync {
     async fn deactivate(&self) -> Result<(), PluginDeactivationError> {
         Ok(())
     }
+
+    /// Called when the configuration file of the plugin has been changed.
+    ///
+    /// The configuration has already been validated against the configuration schema of the plugin.
+    async fn config_changed(&self, _config: Value) {}
+}
+
+/// Returns the component which implements the trait `Plugin`.
+///
+/// A component which is named after the plugin takes precedence over the primary instance. If
+/// multiple plugins are linked statically into one binary, all plugins share the same component
+/// registry and have to name their plugin component, for example
+/// `#[component(names = ["reactive-graph-plugin-base"])]`.
+#[doc(hidden)]
+pub fn get_plugin_component(
+    component_factory: &mut ComponentFactory,
+    name: &str,
+) -> Result<ComponentInstancePtr<dyn Plugin + Send + Sync>, ComponentInstanceProviderError> {
+    component_factory
+        .instance_by_name_typed::<dyn Plugin + Send + Sync>(name)
+        .or_else(|_| component_factory.primary_instance_typed::<dyn Plugin + Send + Sync>())
 }
@@ -0,0 +1,102 @@
+/// The capabilities a plugin requires.
+///
+/// If a plugin declares its capabilities, the plugin context handed out to the plugin only
+/// permits the declared namespaces, web context paths, commands and config access. Plugins
+/// which don't declare capabilities are unrestricted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PluginCapabilities {
+    /// The namespaces the plugin may write types and behaviours into.
+    pub namespaces: Vec<&'static str>,
+
+    /// The context paths the plugin may register web resource providers for.
+    pub web_context_paths: Vec<&'static str>,
+
+    /// The names of the commands the plugin may register.
+    pub commands: Vec<&'static str>,
+
+    /// If true, the plugin may read the configuration of the application. The plugin can always
+    /// read its own plugin configuration.
+    pub config: bool,
+}
+
+impl PluginCapabilities {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Permits writing types and behaviours into the given namespace.
+    pub fn namespace(mut self, namespace: &'static str) -> Self {
+        self.namespaces.push(namespace);
+        self
+    }
+
+    /// Permits registering web resource providers for the given context path.
+    pub fn web_context_path(mut self, web_context_path: &'static str) -> Self {
+        self.web_context_paths.push(web_context_path);
+        self
+    }
+
+    /// Permits registering the command with the given name.
+    pub fn command(mut self, command: &'static str) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Permits reading the configuration of the application.
+    pub fn config(mut self) -> Self {
+        self.config = true;
+        self
+    }
+
+    /// Returns true, if the plugin may write into the given namespace.
+    pub fn allows_namespace(&self, namespace: &str) -> bool {
+        self.namespaces.iter().any(|permitted| namespace == *permitted)
+    }
+
+    /// Returns true, if the plugin may register a web resource provider for the given context path.
+    pub fn allows_web_context_path(&self, web_context_path: &str) -> bool {
+        self.web_context_paths.iter().any(|permitted| web_context_path == *permitted)
+    }
+
+    /// Returns true, if the plugin may register the command with the given name.
+    pub fn allows_command(&self, command: &str) -> bool {
+        self.commands.iter().any(|permitted| command == *permitted)
+    }
+}
+
+/// Exports the function which returns the capabilities of the plugin.
+#[macro_export]
+macro_rules! plugin_capabilities {
+    () => {
+        #[allow(improper_ctypes_definitions)]
+        extern "C" fn get_capabilities() -> std::option::Option<$crate::PluginCapabilities> {
+            std::option::Option::None
+        }
+    };
+
+    ($capabilities: expr) => {
+        #[allow(improper_ctypes_definitions)]
+        extern "C" fn get_capabilities() -> std::option::Option<$crate::PluginCapabilities> {
+            std::option::Option::Some($capabilities)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PluginCapabilities;
+
+    #[test]
+    fn plugin_capabilities_test() {
+        let capabilities = PluginCapabilities::new().namespace("base").web_context_path("graph").command("shutdown");
+        assert!(capabilities.allows_namespace("base"));
+        assert!(!capabilities.allows_namespace("basement"));
+        assert!(!capabilities.allows_namespace("logical"));
+        assert!(capabilities.allows_web_context_path("graph"));
+        assert!(!capabilities.allows_web_context_path("graphql-client"));
+        assert!(capabilities.allows_command("shutdown"));
+        assert!(!capabilities.allows_command("restart"));
+        assert!(!capabilities.config);
+        assert!(capabilities.config().config);
+    }
+}
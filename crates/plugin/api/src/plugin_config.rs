@@ -0,0 +1,20 @@
+/// Exports the function which returns the JSON schema of the configuration of the plugin.
+///
+/// The configuration of the plugin is read from `<config_location>/<short name>.toml` and is
+/// validated against the JSON schema before the plugin is activated.
+#[macro_export]
+macro_rules! plugin_config_schema {
+    () => {
+        #[allow(improper_ctypes_definitions)]
+        extern "C" fn get_config_schema() -> std::option::Option<&'static str> {
+            std::option::Option::None
+        }
+    };
+
+    ($config_schema: expr) => {
+        #[allow(improper_ctypes_definitions)]
+        extern "C" fn get_config_schema() -> std::option::Option<&'static str> {
+            std::option::Option::Some($config_schema)
+        }
+    };
+}
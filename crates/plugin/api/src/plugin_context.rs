@@ -16,6 +16,7 @@ use crate::FlowTypeImportExportManager;
 use crate::FlowTypeManager;
 use crate::FlowTypeProviderRegistry;
 use crate::GraphQLQueryService;
+use crate::NamespaceManager;
 use crate::RelationBehaviourRegistry;
 use crate::RelationComponentBehaviourRegistry;
 use crate::RelationInstanceManager;
@@ -67,6 +68,9 @@ pub trait PluginContext: Send + Sync {
     /// Returns the flow type provider registry.
     fn get_flow_type_provider_registry(&self) -> Arc<dyn FlowTypeProviderRegistry + Send + Sync>;
 
+    /// Returns the namespace manager.
+    fn get_namespace_manager(&self) -> Arc<dyn NamespaceManager + Send + Sync>;
+
     /// Returns the system event manager.
     fn get_type_system_event_manager(&self) -> Arc<dyn TypeSystemEventManager + Send + Sync>;
 
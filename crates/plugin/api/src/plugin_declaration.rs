@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::Plugin;
+use crate::PluginCapabilities;
 use crate::PluginContext;
 use crate::PluginDependency;
 use crate::PluginLoadingError;
@@ -29,6 +30,14 @@ pub struct PluginDeclaration {
     /// Function to get the dependencies of the plugin.
     #[allow(improper_ctypes_definitions)]
     pub get_dependencies: unsafe extern "C" fn() -> Vec<PluginDependency>,
+
+    /// Function to get the JSON schema of the configuration of the plugin.
+    #[allow(improper_ctypes_definitions)]
+    pub get_config_schema: unsafe extern "C" fn() -> Option<&'static str>,
+
+    /// Function to get the capabilities of the plugin. If the plugin doesn't declare capabilities, the plugin is unrestricted.
+    #[allow(improper_ctypes_definitions)]
+    pub get_capabilities: unsafe extern "C" fn() -> Option<PluginCapabilities>,
 }
 
 /// Contains the registration
@@ -40,11 +49,41 @@ pub trait PluginRegistrar {
     fn context(&self) -> Arc<dyn PluginContext + Send + Sync>;
 }
 
+/// The plugin API version is exported as a separate symbol, because the layout of the plugin
+/// declaration depends on the plugin API version. The runtime only reads the plugin declaration
+/// if the plugin API version matches.
+#[cfg(not(feature = "static"))]
 #[macro_export]
 macro_rules! export_plugin_declaration {
     () => {
         #[doc(hidden)]
         #[unsafe(no_mangle)]
+        pub static plugin_api_version: &str = $crate::PLUGIN_API_VERSION;
+
+        #[doc(hidden)]
+        #[unsafe(no_mangle)]
+        pub static plugin_declaration: $crate::PluginDeclaration = $crate::PluginDeclaration {
+            rustc_version: $crate::RUSTC_VERSION,
+            plugin_api_version: $crate::PLUGIN_API_VERSION,
+            name: PLUGIN_NAME,
+            description: PLUGIN_DESCRIPTION,
+            version: PLUGIN_VERSION,
+            register,
+            get_dependencies,
+            get_config_schema,
+            get_capabilities,
+        };
+    };
+}
+
+/// If the plugins are linked statically, the plugin declaration is not exported as unmangled
+/// symbol, because the symbols of multiple plugins would collide.
+#[cfg(feature = "static")]
+#[macro_export]
+macro_rules! export_plugin_declaration {
+    () => {
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals)]
         pub static plugin_declaration: $crate::PluginDeclaration = $crate::PluginDeclaration {
             rustc_version: $crate::RUSTC_VERSION,
             plugin_api_version: $crate::PLUGIN_API_VERSION,
@@ -53,6 +92,8 @@ macro_rules! export_plugin_declaration {
             version: PLUGIN_VERSION,
             register,
             get_dependencies,
+            get_config_schema,
+            get_capabilities,
         };
     };
 }
@@ -34,6 +34,21 @@ pub enum PluginState {
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum PluginResolveState {
+    /// The plugin package has no manifest, but a signed manifest is required.
+    ManifestMissing,
+
+    /// The manifest of the plugin package can't be read or parsed.
+    ManifestInvalid,
+
+    /// The manifest of the plugin package is not signed, but a signature is required.
+    SignatureMissing,
+
+    /// The signature of the manifest can't be verified with any of the trusted keys.
+    SignatureInvalid,
+
+    /// The checksum of the dynamic link library doesn't match the checksum in the manifest.
+    ChecksumMismatch,
+
     /// The runtime has loaded the dynamic link library.
     Loaded,
 
@@ -46,6 +61,9 @@ pub enum PluginResolveState {
     /// The plugin was compiled with an incompatible version of the plugin api.
     PluginApiVersionMismatch,
 
+    /// The name, the version or the dependencies of the plugin declaration don't match the manifest.
+    ManifestMismatch,
+
     /// The plugin is compatible.
     PluginCompatible,
 
@@ -145,6 +163,7 @@ pub enum PluginDeployError {
     Uninstalled,
     Disabled,
     NotFound,
+    StaticallyLinked,
 }
 
 #[derive(Debug)]
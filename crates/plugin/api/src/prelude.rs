@@ -11,6 +11,7 @@ pub mod plugin {
     pub use crate::Component;
     pub use crate::Plugin;
     pub use crate::PluginActivationError;
+    pub use crate::PluginCapabilities;
     pub use crate::PluginContext;
     pub use crate::PluginDeactivationError;
     pub use crate::TypeProvider;
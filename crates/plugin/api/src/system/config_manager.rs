@@ -2,6 +2,7 @@ use reactive_graph_config_model::GraphQLServerConfig;
 use reactive_graph_config_model::InstanceConfig;
 use reactive_graph_config_model::PluginsConfig;
 use reactive_graph_config_model::RemotesConfig;
+use serde_json::Value;
 use springtime_di::injectable;
 
 #[injectable]
@@ -17,4 +18,11 @@ pub trait ConfigManager: Send + Sync {
 
     /// Returns the remotes configuration.
     fn get_remotes_config(&self) -> RemotesConfig;
+
+    /// Returns the validated configuration of the plugin with the given name.
+    ///
+    /// The configuration is read from the configuration file of the plugin and has been
+    /// validated against the configuration schema of the plugin. A plugin can only read its own
+    /// configuration.
+    fn get_plugin_config(&self, plugin_name: &str) -> Option<Value>;
 }
@@ -16,6 +16,7 @@ use crate::FlowTypeImportExportManager;
 use crate::FlowTypeManager;
 use crate::FlowTypeProviderRegistry;
 use crate::GraphQLQueryService;
+use crate::NamespaceManager;
 use crate::PluginContext;
 use crate::RelationBehaviourRegistry;
 use crate::RelationComponentBehaviourRegistry;
@@ -77,6 +78,10 @@ impl PluginContext for TestPluginContext {
         panic!();
     }
 
+    fn get_namespace_manager(&self) -> Arc<dyn NamespaceManager + Send + Sync> {
+        panic!();
+    }
+
     fn get_type_system_event_manager(&self) -> Arc<dyn TypeSystemEventManager + Send + Sync> {
         panic!();
     }
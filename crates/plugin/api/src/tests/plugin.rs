@@ -1,3 +1,5 @@
+use serde_json::json;
+
 use crate::Plugin;
 
 /// Fake plugin
@@ -9,4 +11,5 @@ async fn plugin_api_default_trait_impl_test() {
     let plugin = TestPlugin {};
     assert_eq!(true, plugin.activate().await.is_ok());
     assert_eq!(true, plugin.deactivate().await.is_ok());
+    plugin.config_changed(json!({})).await;
 }
@@ -1,6 +1,7 @@
 pub mod components;
 pub mod entities;
 pub mod flows;
+pub mod namespace_manager;
 pub mod registry;
 pub mod relations;
 pub mod type_system_event_manager;
@@ -0,0 +1,19 @@
+use reactive_graph_graph::Namespace;
+use reactive_graph_type_system_api::NamespaceRegistrationError;
+
+pub trait NamespaceManager: Send + Sync {
+    /// Returns the metadata of all namespaces, ordered by name.
+    fn get_namespaces(&self) -> Vec<Namespace>;
+
+    /// Returns the metadata of the namespace with the given name.
+    fn get(&self, name: &str) -> Option<Namespace>;
+
+    /// Returns true, if the namespace with the given name is registered or used by a type.
+    fn has(&self, name: &str) -> bool;
+
+    /// Registers the metadata of a namespace. The namespace is owned by the plugin.
+    fn register(&self, namespace: Namespace) -> Result<Namespace, NamespaceRegistrationError>;
+
+    /// Unregisters the metadata of the namespace with the given name, if the namespace is owned by the plugin.
+    fn unregister(&self, name: &str) -> Option<Namespace>;
+}
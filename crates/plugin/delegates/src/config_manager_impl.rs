@@ -1,17 +1,44 @@
 use std::sync::Arc;
 
+use serde_json::Value;
+
 use reactive_graph_config_model::GraphQLServerConfig;
 use reactive_graph_config_model::InstanceConfig;
 use reactive_graph_config_model::PluginsConfig;
 use reactive_graph_config_model::RemotesConfig;
+use reactive_graph_plugin_service_api::PluginConfigManager;
 
 pub struct ConfigManagerDelegate {
     config_manager: Arc<dyn reactive_graph_config_api::ConfigManager + Send + Sync>,
+    plugin_config_manager: Arc<dyn PluginConfigManager + Send + Sync>,
+    /// The name of the plugin which can read its configuration via this delegate.
+    owner: Option<String>,
 }
 
 impl ConfigManagerDelegate {
-    pub fn new(config_manager: Arc<dyn reactive_graph_config_api::ConfigManager + Send + Sync>) -> Self {
-        Self { config_manager }
+    pub fn new(
+        config_manager: Arc<dyn reactive_graph_config_api::ConfigManager + Send + Sync>,
+        plugin_config_manager: Arc<dyn PluginConfigManager + Send + Sync>,
+    ) -> Self {
+        Self {
+            config_manager,
+            plugin_config_manager,
+            owner: None,
+        }
+    }
+
+    /// Creates a delegate for the plugin with the given name. The plugin can only read its own
+    /// configuration.
+    pub fn owned(
+        config_manager: Arc<dyn reactive_graph_config_api::ConfigManager + Send + Sync>,
+        plugin_config_manager: Arc<dyn PluginConfigManager + Send + Sync>,
+        owner: &str,
+    ) -> Self {
+        Self {
+            config_manager,
+            plugin_config_manager,
+            owner: Some(owner.to_string()),
+        }
     }
 }
 
@@ -31,4 +58,13 @@ impl reactive_graph_plugin_api::ConfigManager for ConfigManagerDelegate {
     fn get_remotes_config(&self) -> RemotesConfig {
         self.config_manager.get_remotes_config()
     }
+
+    fn get_plugin_config(&self, plugin_name: &str) -> Option<Value> {
+        if let Some(owner) = &self.owner {
+            if owner != plugin_name {
+                return None;
+            }
+        }
+        self.plugin_config_manager.get_config_by_name(plugin_name)
+    }
 }
@@ -30,7 +30,9 @@ impl EntityBehaviourRegistryDelegate {
 #[async_trait]
 impl reactive_graph_plugin_api::EntityBehaviourRegistry for EntityBehaviourRegistryDelegate {
     async fn register(&self, entity_behaviour_ty: EntityBehaviourTypeId, factory: Arc<dyn BehaviourFactory<Uuid, ReactiveEntity> + Send + Sync>) {
-        self.entity_behaviour_registry.register(entity_behaviour_ty.clone(), factory);
+        self.entity_behaviour_registry.register(entity_behaviour_ty.clone(), factory.clone());
+        // Behaviours created by a previous version of the factory are handed over to the new factory
+        self.entity_behaviour_manager.handover_behaviours(&entity_behaviour_ty.behaviour_ty, factory);
         self.reactive_entity_manager.add_behaviour_to_all_entity_instances(&entity_behaviour_ty);
     }
 
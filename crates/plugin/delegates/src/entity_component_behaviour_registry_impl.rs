@@ -30,7 +30,11 @@ impl EntityComponentBehaviourRegistryDelegate {
 #[async_trait]
 impl reactive_graph_plugin_api::EntityComponentBehaviourRegistry for EntityComponentBehaviourRegistryDelegate {
     async fn register(&self, component_behaviour_ty: ComponentBehaviourTypeId, factory: Arc<dyn BehaviourFactory<Uuid, ReactiveEntity> + Send + Sync>) {
-        self.entity_component_behaviour_registry.register(component_behaviour_ty.clone(), factory);
+        self.entity_component_behaviour_registry
+            .register(component_behaviour_ty.clone(), factory.clone());
+        // Behaviours created by a previous version of the factory are handed over to the new factory
+        self.entity_component_behaviour_manager
+            .handover_behaviours(&component_behaviour_ty.behaviour_ty, factory);
         self.reactive_entity_manager.add_behaviour_to_all_entity_components(&component_behaviour_ty);
     }
 
@@ -7,6 +7,7 @@ use reactive_graph_behaviour_model_api::BehaviourTypeId;
 use reactive_graph_graph::ComponentTypeId;
 use reactive_graph_graph::EntityInstance;
 use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::LabelPattern;
 use reactive_graph_reactive_model_impl::ReactiveEntity;
 use reactive_graph_reactive_service_api::ReactiveEntityComponentAddError;
 use reactive_graph_reactive_service_api::ReactiveEntityCreationError;
@@ -52,6 +53,10 @@ impl reactive_graph_plugin_api::EntityInstanceManager for EntityInstanceManagerD
         self.reactive_entity_manager.get_by_label_with_params(label)
     }
 
+    fn get_by_label_pattern(&self, pattern: &LabelPattern) -> Vec<(ReactiveEntity, HashMap<String, String>)> {
+        self.reactive_entity_manager.get_by_label_pattern(pattern)
+    }
+
     fn get_all(&self) -> Vec<ReactiveEntity> {
         self.reactive_entity_manager.get_all()
     }
@@ -14,6 +14,7 @@ pub use flow_type_import_export_manager_impl::FlowTypeImportExportManagerDelegat
 pub use flow_type_manager_impl::FlowTypeManagerDelegate;
 pub use flow_type_provider_registry_delegate::FlowTypeProviderRegistryDelegate;
 pub use graphql_query_service_impl::GraphQLQueryServiceDelegate;
+pub use namespace_manager_impl::NamespaceManagerDelegate;
 pub use relation_behaviour_registry_impl::RelationBehaviourRegistryDelegate;
 pub use relation_component_behaviour_registry_impl::RelationComponentBehaviourRegistryDelegate;
 pub use relation_instance_manager_impl::RelationInstanceManagerDelegate;
@@ -39,6 +40,7 @@ pub mod flow_type_import_export_manager_impl;
 pub mod flow_type_manager_impl;
 pub mod flow_type_provider_registry_delegate;
 pub mod graphql_query_service_impl;
+pub mod namespace_manager_impl;
 pub mod relation_behaviour_registry_impl;
 pub mod relation_component_behaviour_registry_impl;
 pub mod relation_instance_manager_impl;
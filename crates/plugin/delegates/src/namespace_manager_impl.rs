@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use reactive_graph_graph::Namespace;
+use reactive_graph_type_system_api::NamespaceRegistrationError;
+
+pub struct NamespaceManagerDelegate {
+    namespace_manager: Arc<dyn reactive_graph_type_system_api::NamespaceManager + Send + Sync>,
+    /// The name of the plugin which owns the namespaces registered via this delegate.
+    owner: Option<String>,
+}
+
+impl NamespaceManagerDelegate {
+    pub fn new(namespace_manager: Arc<dyn reactive_graph_type_system_api::NamespaceManager + Send + Sync>) -> Self {
+        Self {
+            namespace_manager,
+            owner: None,
+        }
+    }
+
+    /// Creates a delegate for the plugin with the given name. The namespaces registered via the
+    /// delegate are owned by the plugin and the plugin can only unregister its own namespaces.
+    pub fn owned(namespace_manager: Arc<dyn reactive_graph_type_system_api::NamespaceManager + Send + Sync>, owner: &str) -> Self {
+        Self {
+            namespace_manager,
+            owner: Some(owner.to_string()),
+        }
+    }
+}
+
+impl reactive_graph_plugin_api::NamespaceManager for NamespaceManagerDelegate {
+    fn get_namespaces(&self) -> Vec<Namespace> {
+        self.namespace_manager.get_namespaces()
+    }
+
+    fn get(&self, name: &str) -> Option<Namespace> {
+        self.namespace_manager.get(name)
+    }
+
+    fn has(&self, name: &str) -> bool {
+        self.namespace_manager.has(name)
+    }
+
+    fn register(&self, mut namespace: Namespace) -> Result<Namespace, NamespaceRegistrationError> {
+        if let Some(owner) = &self.owner {
+            namespace.owner = Some(owner.clone());
+        }
+        self.namespace_manager.register(namespace)
+    }
+
+    fn unregister(&self, name: &str) -> Option<Namespace> {
+        if let Some(owner) = &self.owner {
+            if !self.namespace_manager.get(name)?.is_owned_by(owner) {
+                return None;
+            }
+        }
+        self.namespace_manager.unregister(name)
+    }
+}
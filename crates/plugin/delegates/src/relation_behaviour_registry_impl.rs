@@ -33,7 +33,10 @@ impl reactive_graph_plugin_api::RelationBehaviourRegistry for RelationBehaviourR
         relation_behaviour_ty: RelationBehaviourTypeId,
         factory: Arc<dyn BehaviourFactory<RelationInstanceId, ReactiveRelation> + Send + Sync>,
     ) {
-        self.relation_behaviour_registry.register(relation_behaviour_ty.clone(), factory);
+        self.relation_behaviour_registry.register(relation_behaviour_ty.clone(), factory.clone());
+        // Behaviours created by a previous version of the factory are handed over to the new factory
+        self.relation_behaviour_manager
+            .handover_behaviours(&relation_behaviour_ty.behaviour_ty, factory);
         self.reactive_relation_manager.add_behaviour_to_all_relation_instances(&relation_behaviour_ty);
     }
 
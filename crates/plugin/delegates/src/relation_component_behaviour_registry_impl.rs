@@ -33,7 +33,11 @@ impl reactive_graph_plugin_api::RelationComponentBehaviourRegistry for RelationC
         component_behaviour_ty: ComponentBehaviourTypeId,
         factory: Arc<dyn BehaviourFactory<RelationInstanceId, ReactiveRelation> + Send + Sync>,
     ) {
-        self.relation_component_behaviour_registry.register(component_behaviour_ty.clone(), factory);
+        self.relation_component_behaviour_registry
+            .register(component_behaviour_ty.clone(), factory.clone());
+        // Behaviours created by a previous version of the factory are handed over to the new factory
+        self.relation_component_behaviour_manager
+            .handover_behaviours(&component_behaviour_ty.behaviour_ty, factory);
         self.reactive_relation_manager.add_behaviour_to_all_relation_components(&component_behaviour_ty);
     }
 
@@ -11,13 +11,21 @@ use reactive_graph_plugin_graphql_api::PluginSchemaManager;
 use reactive_graph_plugin_graphql_schema::PluginSchema;
 use reactive_graph_plugin_graphql_schema::mutation::PluginMutation;
 use reactive_graph_plugin_graphql_schema::query::PluginQuery;
+use reactive_graph_plugin_service_api::PluginConfigManager;
 use reactive_graph_plugin_service_api::PluginContainerManager;
+use reactive_graph_plugin_service_api::PluginContextFactory;
 use reactive_graph_plugin_service_api::PluginResolver;
+use reactive_graph_plugin_service_api::PluginResourceManager;
+use reactive_graph_plugin_service_api::PluginUpgradeManager;
 
 #[derive(Component)]
 pub struct PluginSchemaManagerImpl {
     plugin_container_manager: Arc<dyn PluginContainerManager + Send + Sync>,
+    plugin_config_manager: Arc<dyn PluginConfigManager + Send + Sync>,
+    plugin_context_factory: Arc<dyn PluginContextFactory + Send + Sync>,
     plugin_resolver: Arc<dyn PluginResolver + Send + Sync>, // Deferred<Arc<dyn PluginResolver + Send + Sync>>,
+    plugin_resource_manager: Arc<dyn PluginResourceManager + Send + Sync>,
+    plugin_upgrade_manager: Arc<dyn PluginUpgradeManager + Send + Sync>,
 }
 
 #[async_trait]
@@ -28,7 +36,11 @@ impl PluginSchemaManager for PluginSchemaManagerImpl {
             .with_sorted_fields()
             .with_sorted_enums()
             .data(self.plugin_container_manager.clone())
+            .data(self.plugin_config_manager.clone())
+            .data(self.plugin_context_factory.clone())
             .data(self.plugin_resolver.clone())
+            .data(self.plugin_resource_manager.clone())
+            .data(self.plugin_upgrade_manager.clone())
             .finish()
     }
 }
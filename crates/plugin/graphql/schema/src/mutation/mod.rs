@@ -1,9 +1,12 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use async_graphql::*;
 
 use reactive_graph_plugin_service_api::PluginContainerManager;
 use reactive_graph_plugin_service_api::PluginResolver;
+use reactive_graph_plugin_service_api::PluginUpgradeManager;
+use reactive_graph_plugin_service_api::get_deploy_path;
 
 use crate::query::GraphQLPlugin;
 
@@ -99,4 +102,23 @@ impl PluginMutation {
         plugin_resolver.transition_to_fallback_states().await;
         Ok(GraphQLPlugin { id })
     }
+
+    /// Upgrades an active plugin to the new version of the plugin in the deploy folder.
+    ///
+    /// The new version is loaded side by side and the state of the behaviours is handed over
+    /// to the new version before the previous version is stopped.
+    async fn upgrade(&self, context: &Context<'_>, name: String) -> Result<GraphQLPlugin> {
+        let plugin_container_manager = context.data::<Arc<dyn PluginContainerManager + Send + Sync>>()?;
+        let plugin_upgrade_manager = context.data::<Arc<dyn PluginUpgradeManager + Send + Sync>>()?;
+        let id = plugin_container_manager.get_id(&name).ok_or_else(|| Error::new("Plugin with name not found"))?;
+        let deploy_path = plugin_container_manager
+            .get_plugin_path(&id)
+            .and_then(|path| get_deploy_path(&PathBuf::from(path)))
+            .ok_or_else(|| Error::new("Plugin path not found"))?;
+        let id = plugin_upgrade_manager
+            .upgrade(&id, &deploy_path)
+            .await
+            .map_err(|e| Error::new(format!("Failed to upgrade {}: {}", &id, e)))?;
+        Ok(GraphQLPlugin { id })
+    }
 }
@@ -1,12 +1,19 @@
 use async_graphql::*;
 use reactive_graph_plugin_api::PluginState;
 use reactive_graph_plugin_service_api::PluginContainerManager;
+use reactive_graph_plugin_service_api::PluginResolver;
 use std::sync::Arc;
 use uuid::Uuid;
 
 pub use plugin::*;
+pub use plugin_capabilities::*;
+pub use plugin_diagnostics::*;
+pub use plugin_resources::*;
 
 pub mod plugin;
+pub mod plugin_capabilities;
+pub mod plugin_diagnostics;
+pub mod plugin_resources;
 
 pub struct PluginQuery;
 
@@ -76,6 +83,15 @@ impl PluginQuery {
             .collect();
         Ok(plugins)
     }
+
+    /// Returns the diagnostics of the plugin resolver: the state, the unmet dependencies and the
+    /// state transitions of each plugin and the dependency graph.
+    async fn diagnostics(&self, context: &Context<'_>) -> Result<GraphQLPluginResolverDiagnostics> {
+        let plugin_resolver = context.data::<Arc<dyn PluginResolver + Send + Sync>>()?;
+        Ok(GraphQLPluginResolverDiagnostics {
+            diagnostics: plugin_resolver.diagnostics(),
+        })
+    }
 }
 
 // #[cfg(test)]
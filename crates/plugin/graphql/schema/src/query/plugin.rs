@@ -2,9 +2,16 @@ use std::sync::Arc;
 
 use async_graphql::*;
 use reactive_graph_plugin_api::PLUGIN_NAME_PREFIX;
+use reactive_graph_plugin_service_api::PluginConfigManager;
 use reactive_graph_plugin_service_api::PluginContainerManager;
+use reactive_graph_plugin_service_api::PluginContextFactory;
+use reactive_graph_plugin_service_api::PluginResourceManager;
+use serde_json::Value;
 use uuid::Uuid;
 
+use crate::query::GraphQLPluginCapabilities;
+use crate::query::GraphQLPluginResources;
+
 pub struct GraphQLPlugin {
     pub id: Uuid,
 }
@@ -80,6 +87,47 @@ impl GraphQLPlugin {
             .ok_or_else(|| Error::new("Failed to resolve plugin plugin_api_version"))
     }
 
+    async fn config_path(&self, context: &Context<'_>) -> Result<Option<String>> {
+        let plugin_config_manager = context.data::<Arc<dyn PluginConfigManager + Send + Sync>>()?;
+        Ok(plugin_config_manager.get_config_path(&self.id).map(|path| path.display().to_string()))
+    }
+
+    async fn config_schema(&self, context: &Context<'_>) -> Result<Option<Value>> {
+        let plugin_config_manager = context.data::<Arc<dyn PluginConfigManager + Send + Sync>>()?;
+        Ok(plugin_config_manager.get_config_schema(&self.id))
+    }
+
+    async fn config(&self, context: &Context<'_>) -> Result<Option<Value>> {
+        let plugin_config_manager = context.data::<Arc<dyn PluginConfigManager + Send + Sync>>()?;
+        Ok(plugin_config_manager.get_config(&self.id))
+    }
+
+    /// The resources which are currently registered by the plugin.
+    async fn resources(&self, context: &Context<'_>) -> Result<Option<GraphQLPluginResources>> {
+        let plugin_resource_manager = context.data::<Arc<dyn PluginResourceManager + Send + Sync>>()?;
+        Ok(plugin_resource_manager
+            .get_resources(&self.id)
+            .map(|resources| GraphQLPluginResources { resources }))
+    }
+
+    /// The capabilities declared by the plugin. The plugin is unrestricted if it doesn't declare capabilities.
+    async fn capabilities(&self, context: &Context<'_>) -> Result<Option<GraphQLPluginCapabilities>> {
+        let plugin_container_manager = context.data::<Arc<dyn PluginContainerManager + Send + Sync>>()?;
+        Ok(plugin_container_manager
+            .capabilities(&self.id)
+            .map(|capabilities| GraphQLPluginCapabilities { capabilities }))
+    }
+
+    /// The calls of the plugin which have been denied because they exceed the declared capabilities.
+    async fn capability_violations(&self, context: &Context<'_>) -> Result<Vec<String>> {
+        let plugin_context_factory = context.data::<Arc<dyn PluginContextFactory + Send + Sync>>()?;
+        Ok(plugin_context_factory
+            .get_capability_violations(&self.id)
+            .iter()
+            .map(|violation| violation.to_string())
+            .collect())
+    }
+
     async fn dependencies(&self, context: &Context<'_>) -> Result<Vec<GraphQLPlugin>> {
         let plugin_container_manager = context.data::<Arc<dyn PluginContainerManager + Send + Sync>>()?;
         let mut dependencies = Vec::new();
@@ -0,0 +1,34 @@
+use async_graphql::*;
+use reactive_graph_plugin_api::PluginCapabilities;
+
+/// The capabilities which a plugin has declared.
+pub struct GraphQLPluginCapabilities {
+    pub capabilities: PluginCapabilities,
+}
+
+#[Object(name = "PluginCapabilities")]
+impl GraphQLPluginCapabilities {
+    /// The namespaces the plugin may write types and behaviours into.
+    async fn namespaces(&self) -> Vec<String> {
+        to_strings(&self.capabilities.namespaces)
+    }
+
+    /// The context paths the plugin may register web resource providers for.
+    async fn web_context_paths(&self) -> Vec<String> {
+        to_strings(&self.capabilities.web_context_paths)
+    }
+
+    /// The names of the commands the plugin may register.
+    async fn commands(&self) -> Vec<String> {
+        to_strings(&self.capabilities.commands)
+    }
+
+    /// True, if the plugin may read the configuration of the application.
+    async fn config(&self) -> bool {
+        self.capabilities.config
+    }
+}
+
+fn to_strings(items: &[&'static str]) -> Vec<String> {
+    items.iter().map(|item| item.to_string()).collect()
+}
@@ -0,0 +1,162 @@
+use std::time::UNIX_EPOCH;
+
+use async_graphql::*;
+use reactive_graph_plugin_service_api::PluginDependencyDiagnostics;
+use reactive_graph_plugin_service_api::PluginDiagnostics;
+use reactive_graph_plugin_service_api::PluginResolverDiagnostics;
+use reactive_graph_plugin_service_api::PluginStateTransition;
+use uuid::Uuid;
+
+/// The diagnostics of the plugin resolver.
+pub struct GraphQLPluginResolverDiagnostics {
+    pub diagnostics: PluginResolverDiagnostics,
+}
+
+#[Object(name = "PluginResolverDiagnostics")]
+impl GraphQLPluginResolverDiagnostics {
+    /// The diagnostics of each plugin.
+    async fn plugins(&self) -> Vec<GraphQLPluginDiagnostics> {
+        self.diagnostics
+            .plugins
+            .iter()
+            .cloned()
+            .map(|plugin| GraphQLPluginDiagnostics { plugin })
+            .collect()
+    }
+
+    /// A human-readable report.
+    async fn report(&self) -> String {
+        self.diagnostics.to_report()
+    }
+
+    /// The dependency graph in the DOT format of graphviz.
+    async fn dot(&self) -> String {
+        self.diagnostics.to_dot()
+    }
+
+    /// The dependency graph as mermaid flowchart.
+    async fn mermaid(&self) -> String {
+        self.diagnostics.to_mermaid()
+    }
+}
+
+/// The diagnostics of a single plugin.
+pub struct GraphQLPluginDiagnostics {
+    pub plugin: PluginDiagnostics,
+}
+
+#[Object(name = "PluginDiagnostics")]
+impl GraphQLPluginDiagnostics {
+    async fn id(&self) -> Uuid {
+        self.plugin.id
+    }
+
+    async fn stem(&self) -> String {
+        self.plugin.stem.clone()
+    }
+
+    async fn name(&self) -> Option<String> {
+        self.plugin.name.clone()
+    }
+
+    async fn version(&self) -> Option<String> {
+        self.plugin.version.clone()
+    }
+
+    async fn state(&self) -> String {
+        format!("{:?}", self.plugin.state)
+    }
+
+    /// The reason why the plugin is incompatible with the runtime.
+    async fn incompatibility(&self) -> Option<String> {
+        self.plugin.incompatibility()
+    }
+
+    /// True, if the plugin has attempted calls which exceed its capabilities.
+    async fn capability_violated(&self) -> bool {
+        self.plugin.has_capability_violations()
+    }
+
+    /// The calls of the plugin which have been denied because they exceed its capabilities.
+    async fn capability_violations(&self) -> Vec<String> {
+        self.plugin.capability_violations.clone()
+    }
+
+    async fn dependencies(&self) -> Vec<GraphQLPluginDependencyDiagnostics> {
+        self.plugin
+            .dependencies
+            .iter()
+            .cloned()
+            .map(|dependency| GraphQLPluginDependencyDiagnostics { dependency })
+            .collect()
+    }
+
+    /// The state transitions of the plugin, oldest first.
+    async fn history(&self) -> Vec<GraphQLPluginStateTransition> {
+        self.plugin
+            .history
+            .iter()
+            .map(|transition| GraphQLPluginStateTransition { transition: *transition })
+            .collect()
+    }
+}
+
+/// A dependency of a plugin.
+pub struct GraphQLPluginDependencyDiagnostics {
+    pub dependency: PluginDependencyDiagnostics,
+}
+
+#[Object(name = "PluginDependencyDiagnostics")]
+impl GraphQLPluginDependencyDiagnostics {
+    async fn name(&self) -> String {
+        self.dependency.name.clone()
+    }
+
+    async fn version_range(&self) -> String {
+        self.dependency.version_range.clone()
+    }
+
+    async fn state(&self) -> String {
+        format!("{:?}", self.dependency.state)
+    }
+
+    /// True, if the dependency is active.
+    async fn met(&self) -> bool {
+        self.dependency.is_met()
+    }
+
+    /// The id of the plugin which matches the version range.
+    async fn satisfied_by(&self) -> Option<Uuid> {
+        self.dependency.satisfied_by
+    }
+
+    /// The installed versions of the required plugin.
+    async fn available_versions(&self) -> Vec<String> {
+        self.dependency.available_versions.clone()
+    }
+}
+
+/// A change of the state of a plugin.
+pub struct GraphQLPluginStateTransition {
+    pub transition: PluginStateTransition,
+}
+
+#[Object(name = "PluginStateTransition")]
+impl GraphQLPluginStateTransition {
+    async fn from(&self) -> String {
+        format!("{:?}", self.transition.from)
+    }
+
+    async fn to(&self) -> String {
+        format!("{:?}", self.transition.to)
+    }
+
+    /// The milliseconds since the unix epoch.
+    async fn timestamp(&self) -> u64 {
+        self.transition
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt::Display;
+
+use async_graphql::*;
+use reactive_graph_plugin_service_api::PluginResources;
+use uuid::Uuid;
+
+/// The resources which a plugin has registered and not yet unregistered.
+pub struct GraphQLPluginResources {
+    pub resources: PluginResources,
+}
+
+#[Object(name = "PluginResources")]
+impl GraphQLPluginResources {
+    /// The number of registrations.
+    async fn count(&self) -> usize {
+        self.resources.count()
+    }
+
+    async fn component_providers(&self) -> Vec<String> {
+        self.resources.component_providers.clone()
+    }
+
+    async fn components(&self) -> Vec<String> {
+        to_strings(&self.resources.components)
+    }
+
+    async fn entity_type_providers(&self) -> Vec<String> {
+        self.resources.entity_type_providers.clone()
+    }
+
+    async fn entity_types(&self) -> Vec<String> {
+        to_strings(&self.resources.entity_types)
+    }
+
+    async fn relation_type_providers(&self) -> Vec<String> {
+        self.resources.relation_type_providers.clone()
+    }
+
+    async fn relation_types(&self) -> Vec<String> {
+        to_strings(&self.resources.relation_types)
+    }
+
+    async fn flow_type_providers(&self) -> Vec<String> {
+        self.resources.flow_type_providers.clone()
+    }
+
+    async fn flow_types(&self) -> Vec<String> {
+        to_strings(&self.resources.flow_types)
+    }
+
+    /// The components created through the component manager.
+    async fn created_components(&self) -> Vec<String> {
+        to_strings(&self.resources.created_components)
+    }
+
+    /// The entity types created through the entity type manager.
+    async fn created_entity_types(&self) -> Vec<String> {
+        to_strings(&self.resources.created_entity_types)
+    }
+
+    /// The relation types created through the relation type manager.
+    async fn created_relation_types(&self) -> Vec<String> {
+        to_strings(&self.resources.created_relation_types)
+    }
+
+    /// The flow types created through the flow type manager.
+    async fn created_flow_types(&self) -> Vec<String> {
+        to_strings(&self.resources.created_flow_types)
+    }
+
+    async fn entity_behaviours(&self) -> Vec<String> {
+        to_strings(&self.resources.entity_behaviours)
+    }
+
+    async fn entity_component_behaviours(&self) -> Vec<String> {
+        to_strings(&self.resources.entity_component_behaviours)
+    }
+
+    async fn relation_behaviours(&self) -> Vec<String> {
+        to_strings(&self.resources.relation_behaviours)
+    }
+
+    async fn relation_component_behaviours(&self) -> Vec<String> {
+        to_strings(&self.resources.relation_component_behaviours)
+    }
+
+    /// The ids of the flow instances.
+    async fn flow_instances(&self) -> Vec<Uuid> {
+        self.resources.flow_instances.clone()
+    }
+
+    /// The ids of the web resource providers.
+    async fn web_resource_providers(&self) -> Vec<Uuid> {
+        self.resources.web_resource_providers.clone()
+    }
+
+    /// The ids of the command entity instances.
+    async fn commands(&self) -> Vec<Uuid> {
+        self.resources.commands.clone()
+    }
+}
+
+fn to_strings<T: Display>(items: &[T]) -> Vec<String> {
+    items.iter().map(|item| item.to_string()).collect()
+}
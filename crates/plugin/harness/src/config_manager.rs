@@ -0,0 +1,43 @@
+use dashmap::DashMap;
+use serde_json::Value;
+
+use reactive_graph_config_model::GraphQLServerConfig;
+use reactive_graph_config_model::InstanceConfig;
+use reactive_graph_config_model::PluginsConfig;
+use reactive_graph_config_model::RemotesConfig;
+use reactive_graph_plugin_api::ConfigManager;
+
+/// Provides the default configuration and the plugin configurations which have been set by the test.
+#[derive(Default)]
+pub struct HarnessConfigManager {
+    plugin_configs: DashMap<String, Value>,
+}
+
+impl HarnessConfigManager {
+    /// Sets the configuration of the plugin with the given name.
+    pub fn set_plugin_config(&self, plugin_name: &str, config: Value) {
+        self.plugin_configs.insert(plugin_name.to_string(), config);
+    }
+}
+
+impl ConfigManager for HarnessConfigManager {
+    fn get_instance_config(&self) -> InstanceConfig {
+        InstanceConfig::default()
+    }
+
+    fn get_graphql_server_config(&self) -> GraphQLServerConfig {
+        GraphQLServerConfig::default()
+    }
+
+    fn get_plugins_config(&self) -> PluginsConfig {
+        PluginsConfig::default()
+    }
+
+    fn get_remotes_config(&self) -> RemotesConfig {
+        RemotesConfig::default()
+    }
+
+    fn get_plugin_config(&self, plugin_name: &str) -> Option<Value> {
+        self.plugin_configs.get(plugin_name).map(|config| config.value().clone())
+    }
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use serde::de::Error as _;
+use serde_json::Error;
+
+use reactive_graph_plugin_api::GraphQLQueryService;
+
+/// The plugin harness doesn't provide a GraphQL server, so every query fails.
+pub struct HarnessGraphQLQueryService;
+
+#[async_trait]
+impl GraphQLQueryService for HarnessGraphQLQueryService {
+    async fn query(&self, _request: &str) -> Result<String, Error> {
+        Err(Error::custom("The GraphQL query service is not available in the plugin harness"))
+    }
+}
@@ -0,0 +1,306 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use reactive_graph_behaviour_model_api::BehaviourTypeId;
+use reactive_graph_behaviour_model_api::BehaviourTypesContainer;
+use reactive_graph_behaviour_model_api::ComponentBehaviourTypeId;
+use reactive_graph_behaviour_model_api::EntityBehaviourTypeId;
+use reactive_graph_behaviour_model_api::RelationBehaviourTypeId;
+use reactive_graph_behaviour_service_api::BehaviourSystem;
+use reactive_graph_command_api::CommandSystem;
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::FlowTypeId;
+use reactive_graph_graph::PropertyInstanceGetter;
+use reactive_graph_graph::PropertyInstanceSetter;
+use reactive_graph_graph::PropertyInstances;
+use reactive_graph_graph::RelationTypeId;
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_plugin_api::Plugin;
+use reactive_graph_plugin_api::PluginActivationError;
+use reactive_graph_plugin_api::PluginContext;
+use reactive_graph_plugin_api::PluginDeactivationError;
+use reactive_graph_plugin_api::PluginLoadingError;
+use reactive_graph_plugin_api::WebResourceProvider;
+use reactive_graph_plugin_delegates::CommandManagerDelegate;
+use reactive_graph_plugin_delegates::ComponentImportExportManagerDelegate;
+use reactive_graph_plugin_delegates::ComponentManagerDelegate;
+use reactive_graph_plugin_delegates::ComponentProviderRegistryDelegate;
+use reactive_graph_plugin_delegates::EntityBehaviourRegistryDelegate;
+use reactive_graph_plugin_delegates::EntityComponentBehaviourRegistryDelegate;
+use reactive_graph_plugin_delegates::EntityInstanceManagerDelegate;
+use reactive_graph_plugin_delegates::EntityTypeImportExportManagerDelegate;
+use reactive_graph_plugin_delegates::EntityTypeManagerDelegate;
+use reactive_graph_plugin_delegates::EntityTypeProviderRegistryDelegate;
+use reactive_graph_plugin_delegates::FlowInstanceManagerDelegate;
+use reactive_graph_plugin_delegates::FlowTypeImportExportManagerDelegate;
+use reactive_graph_plugin_delegates::FlowTypeManagerDelegate;
+use reactive_graph_plugin_delegates::FlowTypeProviderRegistryDelegate;
+use reactive_graph_plugin_delegates::NamespaceManagerDelegate;
+use reactive_graph_plugin_delegates::RelationBehaviourRegistryDelegate;
+use reactive_graph_plugin_delegates::RelationComponentBehaviourRegistryDelegate;
+use reactive_graph_plugin_delegates::RelationInstanceManagerDelegate;
+use reactive_graph_plugin_delegates::RelationTypeImportExportManagerDelegate;
+use reactive_graph_plugin_delegates::RelationTypeManagerDelegate;
+use reactive_graph_plugin_delegates::RelationTypeProviderRegistryDelegate;
+use reactive_graph_plugin_delegates::TypeSystemEventManagerDelegate;
+use reactive_graph_plugin_service_impl::PluginContextImpl;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+use reactive_graph_reactive_service_api::ReactiveEntityCreationError;
+use reactive_graph_reactive_service_api::ReactiveSystem;
+use reactive_graph_type_system_api::TypeSystem;
+
+use crate::HarnessConfigManager;
+use crate::HarnessGraphQLQueryService;
+use crate::HarnessWebResourceManager;
+use crate::PluginHarnessSystem;
+
+/// Runs plugins in-process with the real type system, reactive system and behaviour system, but
+/// without the GraphQL server, the web server and the plugin system of the runtime.
+///
+/// ```ignore
+/// let harness = PluginHarness::new().await;
+/// let plugin = harness.load(crate::construct_plugin).expect("Failed to load plugin");
+/// harness.activate(&plugin).await.expect("Failed to activate plugin");
+/// harness.assert_entity_type(&ENTITY_TYPE_ID);
+/// ```
+pub struct PluginHarness {
+    system: Arc<PluginHarnessSystem>,
+    context: Arc<dyn PluginContext + Send + Sync>,
+    config_manager: Arc<HarnessConfigManager>,
+    web_resource_manager: Arc<HarnessWebResourceManager>,
+}
+
+impl PluginHarness {
+    /// Constructs and initializes a new harness. Each harness has its own type system, reactive
+    /// system and behaviour system.
+    pub async fn new() -> Self {
+        let system = reactive_graph_di::get_container::<PluginHarnessSystem>();
+        system.init().await;
+        system.post_init().await;
+        let config_manager = Arc::new(HarnessConfigManager::default());
+        let web_resource_manager = Arc::new(HarnessWebResourceManager::default());
+        let context = construct_plugin_context(&system, config_manager.clone(), web_resource_manager.clone());
+        PluginHarness {
+            system,
+            context,
+            config_manager,
+            web_resource_manager,
+        }
+    }
+
+    /// Returns the plugin context which is passed to the plugins.
+    pub fn context(&self) -> Arc<dyn PluginContext + Send + Sync> {
+        self.context.clone()
+    }
+
+    /// Returns the type system.
+    pub fn type_system(&self) -> Arc<dyn TypeSystem + Send + Sync> {
+        self.system.type_system.clone()
+    }
+
+    /// Returns the reactive system.
+    pub fn reactive_system(&self) -> Arc<dyn ReactiveSystem + Send + Sync> {
+        self.system.reactive_system.clone()
+    }
+
+    /// Returns the behaviour system.
+    pub fn behaviour_system(&self) -> Arc<dyn BehaviourSystem + Send + Sync> {
+        self.system.behaviour_system.clone()
+    }
+
+    /// Sets the configuration which is returned to the plugin with the given name.
+    pub fn set_plugin_config(&self, plugin_name: &str, config: Value) {
+        self.config_manager.set_plugin_config(plugin_name, config);
+    }
+
+    /// Constructs the plugin by calling the `construct_plugin` function which is exported by
+    /// `export_plugin!`. The plugin crate is linked statically instead of loading a dynamic library.
+    ///
+    /// The plugin context is stored in a static of the plugin crate, which is only set once per
+    /// process. Therefore, only one harness per test binary can load a particular plugin crate.
+    pub fn load<F>(&self, construct_plugin: F) -> Result<Arc<dyn Plugin + Send + Sync>, PluginLoadingError>
+    where
+        F: FnOnce(Arc<dyn PluginContext + Send + Sync>) -> Result<Arc<dyn Plugin + Send + Sync>, PluginLoadingError>,
+    {
+        construct_plugin(self.context.clone())
+    }
+
+    /// Activates the plugin.
+    pub async fn activate(&self, plugin: &Arc<dyn Plugin + Send + Sync>) -> Result<(), PluginActivationError> {
+        plugin.activate().await
+    }
+
+    /// Deactivates the plugin.
+    pub async fn deactivate(&self, plugin: &Arc<dyn Plugin + Send + Sync>) -> Result<(), PluginDeactivationError> {
+        plugin.deactivate().await
+    }
+
+    /// Shuts down the type system, the reactive system and the behaviour system.
+    pub async fn shutdown(&self) {
+        self.system.pre_shutdown().await;
+        self.system.shutdown().await;
+    }
+
+    /// Creates a reactive entity of the given entity type. The behaviours of the entity type and
+    /// of its components are applied.
+    pub fn create_entity(&self, ty: &EntityTypeId, properties: PropertyInstances) -> Result<ReactiveEntity, ReactiveEntityCreationError> {
+        self.system.reactive_system.get_reactive_entity_manager().create_reactive_entity(ty, properties)
+    }
+
+    /// Returns the web resource provider with the given context path.
+    pub fn get_web_resource_provider(&self, context_path: &str) -> Option<Arc<dyn WebResourceProvider>> {
+        self.web_resource_manager.get_by_context_path(context_path)
+    }
+
+    /// Asserts that the component has been registered.
+    pub fn assert_component(&self, ty: &ComponentTypeId) {
+        assert!(self.system.type_system.get_component_manager().has(ty), "The component {ty} is not registered");
+    }
+
+    /// Asserts that the entity type has been registered.
+    pub fn assert_entity_type(&self, ty: &EntityTypeId) {
+        assert!(self.system.type_system.get_entity_type_manager().has(ty), "The entity type {ty} is not registered");
+    }
+
+    /// Asserts that the relation type has been registered.
+    pub fn assert_relation_type(&self, ty: &RelationTypeId) {
+        assert!(self.system.type_system.get_relation_type_manager().has(ty), "The relation type {ty} is not registered");
+    }
+
+    /// Asserts that the flow type has been registered.
+    pub fn assert_flow_type(&self, ty: &FlowTypeId) {
+        assert!(self.system.type_system.get_flow_type_manager().has(ty), "The flow type {ty} is not registered");
+    }
+
+    /// Asserts that the entity behaviour has been registered.
+    pub fn assert_entity_behaviour(&self, ty: &EntityBehaviourTypeId) {
+        assert!(
+            self.system.behaviour_system.get_entity_behaviour_registry().get_all().contains(ty),
+            "The entity behaviour {ty} is not registered"
+        );
+    }
+
+    /// Asserts that the entity component behaviour has been registered.
+    pub fn assert_entity_component_behaviour(&self, ty: &ComponentBehaviourTypeId) {
+        assert!(
+            self.system.behaviour_system.get_entity_component_behaviour_registry().get_all().contains(ty),
+            "The entity component behaviour {ty} is not registered"
+        );
+    }
+
+    /// Asserts that the relation behaviour has been registered.
+    pub fn assert_relation_behaviour(&self, ty: &RelationBehaviourTypeId) {
+        assert!(
+            self.system.behaviour_system.get_relation_behaviour_registry().get_all().contains(ty),
+            "The relation behaviour {ty} is not registered"
+        );
+    }
+
+    /// Asserts that the relation component behaviour has been registered.
+    pub fn assert_relation_component_behaviour(&self, ty: &ComponentBehaviourTypeId) {
+        assert!(
+            self.system.behaviour_system.get_relation_component_behaviour_registry().get_all().contains(ty),
+            "The relation component behaviour {ty} is not registered"
+        );
+    }
+
+    /// Asserts that the behaviour has been applied to the reactive instance.
+    pub fn assert_behaves_as<T: BehaviourTypesContainer>(&self, reactive_instance: &T, ty: &BehaviourTypeId) {
+        assert!(reactive_instance.behaves_as(ty), "The reactive instance doesn't behave as {ty}");
+    }
+
+    /// Asserts that setting the input property propagates the expected value to the output property.
+    pub fn assert_propagation<T: PropertyInstanceSetter>(&self, reactive_instance: &T, input: &str, value: Value, output: &str, expected: Value) {
+        reactive_instance.set(input, value.clone());
+        assert_eq!(Some(expected), reactive_instance.get(output), "Setting {input} to {value} hasn't been propagated to {output}");
+    }
+}
+
+fn construct_plugin_context(
+    system: &PluginHarnessSystem,
+    config_manager: Arc<HarnessConfigManager>,
+    web_resource_manager: Arc<HarnessWebResourceManager>,
+) -> Arc<dyn PluginContext + Send + Sync> {
+    let type_system = &system.type_system;
+    let reactive_system = &system.reactive_system;
+    let behaviour_system = &system.behaviour_system;
+    // Type System
+    let component_manager = ComponentManagerDelegate::new(type_system.get_component_manager());
+    let component_import_export_manager = ComponentImportExportManagerDelegate::new(type_system.get_component_import_export_manager());
+    let component_provider_registry = ComponentProviderRegistryDelegate::new(&type_system.get_component_provider_registry());
+    let entity_type_manager = EntityTypeManagerDelegate::new(type_system.get_entity_type_manager());
+    let entity_type_import_export_manager = EntityTypeImportExportManagerDelegate::new(type_system.get_entity_type_import_export_manager());
+    let entity_type_provider_registry = EntityTypeProviderRegistryDelegate::new(&type_system.get_entity_type_provider_registry());
+    let relation_type_manager = RelationTypeManagerDelegate::new(type_system.get_relation_type_manager());
+    let relation_type_import_export_manager = RelationTypeImportExportManagerDelegate::new(type_system.get_relation_type_import_export_manager());
+    let relation_type_provider_registry = RelationTypeProviderRegistryDelegate::new(&type_system.get_relation_type_provider_registry());
+    let flow_type_manager = FlowTypeManagerDelegate::new(type_system.get_flow_type_manager());
+    let flow_type_import_export_manager = FlowTypeImportExportManagerDelegate::new(type_system.get_flow_type_import_export_manager());
+    let flow_type_provider_registry = FlowTypeProviderRegistryDelegate::new(&type_system.get_flow_type_provider_registry());
+    let namespace_manager = NamespaceManagerDelegate::new(type_system.get_namespace_manager());
+    let type_system_event_manager = TypeSystemEventManagerDelegate::new(type_system.get_type_system_event_manager());
+    // Instance System
+    let entity_instance_manager = EntityInstanceManagerDelegate::new(
+        type_system.get_component_manager(),
+        type_system.get_entity_type_manager(),
+        reactive_system.get_reactive_entity_manager(),
+    );
+    let relation_instance_manager = RelationInstanceManagerDelegate::new(
+        type_system.get_component_manager(),
+        type_system.get_relation_type_manager(),
+        reactive_system.get_reactive_relation_manager(),
+    );
+    let flow_instance_manager = FlowInstanceManagerDelegate::new(reactive_system.get_reactive_flow_manager());
+    // Behaviour Registries
+    let entity_behaviour_registry = EntityBehaviourRegistryDelegate::new(
+        behaviour_system.get_entity_behaviour_manager(),
+        behaviour_system.get_entity_behaviour_registry(),
+        reactive_system.get_reactive_entity_manager(),
+    );
+    let entity_component_behaviour_registry = EntityComponentBehaviourRegistryDelegate::new(
+        behaviour_system.get_entity_component_behaviour_manager(),
+        behaviour_system.get_entity_component_behaviour_registry(),
+        reactive_system.get_reactive_entity_manager(),
+    );
+    let relation_behaviour_registry = RelationBehaviourRegistryDelegate::new(
+        behaviour_system.get_relation_behaviour_manager(),
+        behaviour_system.get_relation_behaviour_registry(),
+        reactive_system.get_reactive_relation_manager(),
+    );
+    let relation_component_behaviour_registry = RelationComponentBehaviourRegistryDelegate::new(
+        behaviour_system.get_relation_component_behaviour_manager(),
+        behaviour_system.get_relation_component_behaviour_registry(),
+        reactive_system.get_reactive_relation_manager(),
+    );
+    // System Services
+    let command_manager = CommandManagerDelegate::new(system.command_system.get_command_manager());
+    Arc::new(PluginContextImpl::new(
+        Arc::new(component_manager),
+        Arc::new(component_import_export_manager),
+        Arc::new(component_provider_registry),
+        Arc::new(entity_type_manager),
+        Arc::new(entity_type_import_export_manager),
+        Arc::new(entity_type_provider_registry),
+        Arc::new(relation_type_manager),
+        Arc::new(relation_type_import_export_manager),
+        Arc::new(relation_type_provider_registry),
+        Arc::new(flow_type_manager),
+        Arc::new(flow_type_import_export_manager),
+        Arc::new(flow_type_provider_registry),
+        Arc::new(namespace_manager),
+        Arc::new(type_system_event_manager),
+        Arc::new(entity_instance_manager),
+        Arc::new(relation_instance_manager),
+        Arc::new(flow_instance_manager),
+        Arc::new(entity_behaviour_registry),
+        Arc::new(entity_component_behaviour_registry),
+        Arc::new(relation_behaviour_registry),
+        Arc::new(relation_component_behaviour_registry),
+        config_manager,
+        Arc::new(HarnessGraphQLQueryService),
+        web_resource_manager,
+        Arc::new(command_manager),
+    ))
+}
@@ -0,0 +1,15 @@
+pub use config_manager::*;
+pub use graphql_query_service::*;
+pub use harness::*;
+pub use system::*;
+pub use web_resource_manager::*;
+
+pub mod config_manager;
+pub mod graphql_query_service;
+pub mod harness;
+pub mod system;
+pub mod web_resource_manager;
+
+#[cfg(test)]
+#[cfg(not(tarpaulin_include))]
+pub mod tests;
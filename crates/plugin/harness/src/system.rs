@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use springtime_di::Component;
+
+use reactive_graph_behaviour_service_api::BehaviourSystem;
+use reactive_graph_command_api::CommandSystem;
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_reactive_service_api::ReactiveSystem;
+use reactive_graph_type_system_api::TypeSystem;
+
+// Do not remove! This import is necessary to make the dependency injection work
+#[allow(unused_imports)]
+use reactive_graph_behaviour_service_impl::BehaviourSystemImpl;
+// Do not remove! This import is necessary to make the dependency injection work
+#[allow(unused_imports)]
+use reactive_graph_command_impl::CommandSystemImpl;
+// Do not remove! This import is necessary to make the dependency injection work
+#[allow(unused_imports)]
+use reactive_graph_reactive_service_impl::ReactiveSystemImpl;
+// Do not remove! This import is necessary to make the dependency injection work
+#[allow(unused_imports)]
+use reactive_graph_type_system_impl::TypeSystemImpl;
+
+/// The subset of the runtime which is needed to run plugins: the type system, the reactive
+/// system, the behaviour system and the command system.
+#[derive(Component)]
+pub struct PluginHarnessSystem {
+    pub type_system: Arc<dyn TypeSystem + Send + Sync>,
+    pub reactive_system: Arc<dyn ReactiveSystem + Send + Sync>,
+    pub behaviour_system: Arc<dyn BehaviourSystem + Send + Sync>,
+    pub command_system: Arc<dyn CommandSystem + Send + Sync>,
+}
+
+#[async_trait]
+impl Lifecycle for PluginHarnessSystem {
+    async fn init(&self) {
+        self.type_system.init().await;
+        self.reactive_system.init().await;
+        self.behaviour_system.init().await;
+        self.command_system.init().await;
+    }
+
+    async fn post_init(&self) {
+        self.type_system.post_init().await;
+        self.reactive_system.post_init().await;
+        self.behaviour_system.post_init().await;
+        self.command_system.post_init().await;
+    }
+
+    async fn pre_shutdown(&self) {
+        self.command_system.pre_shutdown().await;
+        self.behaviour_system.pre_shutdown().await;
+        self.reactive_system.pre_shutdown().await;
+        self.type_system.pre_shutdown().await;
+    }
+
+    async fn shutdown(&self) {
+        self.command_system.shutdown().await;
+        self.behaviour_system.shutdown().await;
+        self.reactive_system.shutdown().await;
+        self.type_system.shutdown().await;
+    }
+}
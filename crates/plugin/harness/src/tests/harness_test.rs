@@ -0,0 +1,188 @@
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use serde_json::Value;
+use serde_json::json;
+use uuid::Uuid;
+
+use reactive_graph_behaviour_model_api::prelude::*;
+use reactive_graph_graph::ComponentTypeIds;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::Extensions;
+use reactive_graph_graph::PropertyInstanceGetter;
+use reactive_graph_graph::PropertyInstanceSetter;
+use reactive_graph_graph::PropertyInstances;
+use reactive_graph_graph::PropertyType;
+use reactive_graph_graph::PropertyTypes;
+use reactive_graph_plugin_api::Plugin;
+use reactive_graph_plugin_api::PluginActivationError;
+use reactive_graph_plugin_api::PluginContext;
+use reactive_graph_plugin_api::PluginDeactivationError;
+use reactive_graph_plugin_api::PluginLoadingError;
+use reactive_graph_reactive_model_api::ReactiveInstanceContainer;
+use reactive_graph_reactive_model_api::ReactivePropertyContainer;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+
+use crate::PluginHarness;
+
+static ENTITY_TY: LazyLock<EntityTypeId> = LazyLock::new(|| EntityTypeId::new_from_type("harness", "copy"));
+
+static BEHAVIOUR_TY: LazyLock<BehaviourTypeId> = LazyLock::new(|| BehaviourTypeId::new_from_type("harness", "copy"));
+
+const LHS: &str = "lhs";
+
+const RESULT: &str = "result";
+
+/// Copies the value of the input property to the output property.
+struct CopyBehaviour {
+    reactive_instance: ReactiveEntity,
+    ty: BehaviourTypeId,
+    state: RwLock<BehaviourState>,
+    handle_id: u128,
+}
+
+impl BehaviourFsm<Uuid, ReactiveEntity> for CopyBehaviour {
+    fn ty(&self) -> &BehaviourTypeId {
+        &self.ty
+    }
+
+    fn get_state(&self) -> BehaviourState {
+        *self.state.read().unwrap()
+    }
+
+    fn set_state(&self, state: BehaviourState) {
+        *self.state.write().unwrap() = state;
+    }
+
+    fn get_validator(&self) -> &dyn BehaviourValidator<Uuid, ReactiveEntity> {
+        self
+    }
+
+    fn get_transitions(&self) -> &dyn BehaviourTransitions<Uuid, ReactiveEntity> {
+        self
+    }
+}
+
+impl ReactiveInstanceContainer<Uuid, ReactiveEntity> for CopyBehaviour {
+    fn get_reactive_instance(&self) -> &ReactiveEntity {
+        &self.reactive_instance
+    }
+}
+
+impl BehaviourValidator<Uuid, ReactiveEntity> for CopyBehaviour {}
+
+impl BehaviourPropertyValidator<Uuid, ReactiveEntity> for CopyBehaviour {}
+
+impl BehaviourInit<Uuid, ReactiveEntity> for CopyBehaviour {}
+
+impl BehaviourShutdown<Uuid, ReactiveEntity> for CopyBehaviour {}
+
+impl BehaviourConnect<Uuid, ReactiveEntity> for CopyBehaviour {
+    fn connect(&self) -> Result<(), BehaviourConnectFailed> {
+        let reactive_instance = self.reactive_instance.clone();
+        self.reactive_instance
+            .observe_with_handle(LHS, move |value: &Value| reactive_instance.set(RESULT, value.clone()), self.handle_id);
+        Ok(())
+    }
+}
+
+impl BehaviourDisconnect<Uuid, ReactiveEntity> for CopyBehaviour {
+    fn disconnect(&self) -> Result<(), BehaviourDisconnectFailed> {
+        self.reactive_instance.remove_observer(LHS, self.handle_id);
+        Ok(())
+    }
+}
+
+impl BehaviourTransitions<Uuid, ReactiveEntity> for CopyBehaviour {}
+
+impl Drop for CopyBehaviour {
+    fn drop(&mut self) {
+        let _ = self.disconnect();
+        self.reactive_instance.remove_behaviour(&self.ty);
+    }
+}
+
+struct CopyBehaviourFactory;
+
+impl BehaviourFactory<Uuid, ReactiveEntity> for CopyBehaviourFactory {
+    fn create(&self, reactive_instance: ReactiveEntity) -> Result<Arc<dyn BehaviourFsm<Uuid, ReactiveEntity> + Send + Sync>, BehaviourCreationError> {
+        if reactive_instance.behaves_as(&BEHAVIOUR_TY) {
+            return Err(BehaviourCreationError::BehaviourAlreadyApplied(BEHAVIOUR_TY.clone()));
+        }
+        let behaviour = Arc::new(CopyBehaviour {
+            reactive_instance,
+            ty: BEHAVIOUR_TY.clone(),
+            state: RwLock::new(BehaviourState::Created),
+            handle_id: Uuid::new_v4().as_u128(),
+        });
+        behaviour
+            .transition(BehaviourState::Connected)
+            .map_err(BehaviourCreationError::BehaviourTransitionError)?;
+        Ok(behaviour)
+    }
+
+    fn behaviour_ty(&self) -> &BehaviourTypeId {
+        &BEHAVIOUR_TY
+    }
+}
+
+/// Registers an entity type and a behaviour, like a real plugin.
+struct CopyPlugin {
+    context: Arc<dyn PluginContext + Send + Sync>,
+}
+
+#[async_trait]
+impl Plugin for CopyPlugin {
+    async fn activate(&self) -> Result<(), PluginActivationError> {
+        let properties = PropertyTypes::new()
+            .property(PropertyType::number_input(LHS))
+            .property(PropertyType::number_output(RESULT));
+        self.context
+            .get_entity_type_manager()
+            .create(&ENTITY_TY, "Copies lhs to result", ComponentTypeIds::new(), properties, Extensions::new())
+            .map_err(|e| PluginActivationError::ActivationFailed(format!("{e:?}")))?;
+        self.context
+            .get_entity_behaviour_registry()
+            .register(EntityBehaviourTypeId::new(ENTITY_TY.clone(), BEHAVIOUR_TY.clone()), Arc::new(CopyBehaviourFactory))
+            .await;
+        Ok(())
+    }
+
+    async fn deactivate(&self) -> Result<(), PluginDeactivationError> {
+        self.context
+            .get_entity_behaviour_registry()
+            .unregister(&EntityBehaviourTypeId::new(ENTITY_TY.clone(), BEHAVIOUR_TY.clone()))
+            .await;
+        Ok(())
+    }
+}
+
+fn construct_plugin(context: Arc<dyn PluginContext + Send + Sync>) -> Result<Arc<dyn Plugin + Send + Sync>, PluginLoadingError> {
+    Ok(Arc::new(CopyPlugin { context }))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn plugin_harness_test() {
+    reactive_graph_utils_test::init_logger();
+
+    let harness = PluginHarness::new().await;
+    let plugin = harness.load(construct_plugin).expect("Failed to load the plugin");
+    harness.activate(&plugin).await.expect("Failed to activate the plugin");
+
+    harness.assert_entity_type(&ENTITY_TY);
+    harness.assert_entity_behaviour(&EntityBehaviourTypeId::new(ENTITY_TY.clone(), BEHAVIOUR_TY.clone()));
+
+    let properties = PropertyInstances::new().property(LHS, json!(0)).property(RESULT, json!(0));
+    let reactive_entity = harness.create_entity(&ENTITY_TY, properties).expect("Failed to create the entity");
+    harness.assert_behaves_as(&reactive_entity, &BEHAVIOUR_TY);
+    harness.assert_propagation(&reactive_entity, LHS, json!(42), RESULT, json!(42));
+
+    harness.deactivate(&plugin).await.expect("Failed to deactivate the plugin");
+    assert!(!reactive_entity.behaves_as(&BEHAVIOUR_TY));
+    reactive_entity.set(LHS, json!(1));
+    assert_eq!(Some(json!(42)), reactive_entity.get(RESULT));
+
+    harness.shutdown().await;
+}
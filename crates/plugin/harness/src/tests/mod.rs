@@ -0,0 +1,3 @@
+mod harness_test;
+mod restricted_context_test;
+mod tracking_context_test;
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::Extensions;
+use reactive_graph_graph::PropertyTypes;
+use reactive_graph_plugin_api::PluginCapabilities;
+use reactive_graph_plugin_api::PluginCapabilityViolation;
+use reactive_graph_plugin_api::PluginContext;
+use reactive_graph_plugin_service_impl::PluginCapabilityGuard;
+use reactive_graph_plugin_service_impl::RestrictedPluginContext;
+use reactive_graph_type_system_api::ComponentCreationError;
+
+use crate::PluginHarness;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn restricted_context_denies_components_in_undeclared_namespaces_test() {
+    let harness = PluginHarness::new().await;
+    let capabilities = PluginCapabilities::new().namespace("restricted");
+    let guard = Arc::new(PluginCapabilityGuard::new(Uuid::new_v4(), "reactive-graph-plugin-restricted".to_string(), capabilities));
+    let context = RestrictedPluginContext::new(harness.context(), guard);
+    let component_manager = context.get_component_manager();
+
+    let permitted = ComponentTypeId::new_from_type("restricted", "permitted");
+    assert!(component_manager.create(&permitted, "", PropertyTypes::new(), Extensions::new()).is_ok());
+    assert!(context.get_violations().is_empty());
+
+    let denied = ComponentTypeId::new_from_type("undeclared", "denied");
+    assert!(matches!(
+        component_manager.create(&denied, "", PropertyTypes::new(), Extensions::new()),
+        Err(ComponentCreationError::PermissionDenied(_))
+    ));
+    assert_eq!(vec![PluginCapabilityViolation::Namespace("undeclared".to_string())], context.get_violations());
+    assert!(!harness.type_system().get_component_manager().has(&denied));
+    // Components in undeclared namespaces can't be deleted either
+    assert!(!component_manager.delete(&denied));
+
+    harness.shutdown().await;
+}
@@ -0,0 +1,35 @@
+use uuid::Uuid;
+
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::Extensions;
+use reactive_graph_graph::PropertyTypes;
+use reactive_graph_plugin_api::PluginContext;
+use reactive_graph_plugin_service_impl::TrackingPluginContext;
+
+use crate::PluginHarness;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tracking_context_reports_created_components_test() {
+    let harness = PluginHarness::new().await;
+    let context = TrackingPluginContext::new(Uuid::new_v4(), harness.context());
+    let component_manager = context.get_component_manager();
+
+    let leaked = ComponentTypeId::new_from_type("tracking", "leaked");
+    let deleted = ComponentTypeId::new_from_type("tracking", "deleted");
+    component_manager
+        .create(&leaked, "", PropertyTypes::new(), Extensions::new())
+        .expect("Failed to create the component");
+    component_manager
+        .create(&deleted, "", PropertyTypes::new(), Extensions::new())
+        .expect("Failed to create the component");
+    assert_eq!(vec![deleted.clone(), leaked.clone()], context.get_resources().created_components);
+
+    // The component which has been deleted by the plugin is not reported as leak
+    assert!(component_manager.delete(&deleted));
+    let leaks = context.cleanup().await;
+    assert_eq!(vec![leaked.clone()], leaks.created_components);
+    assert_eq!(1, leaks.count());
+    assert!(!harness.type_system().get_component_manager().has(&leaked));
+
+    harness.shutdown().await;
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dashmap::DashMap;
+use uuid::Uuid;
+
+use reactive_graph_plugin_api::WebResourceManager;
+use reactive_graph_plugin_api::WebResourceProvider;
+
+/// Collects the web resource providers of the plugin instead of serving them.
+#[derive(Default)]
+pub struct HarnessWebResourceManager {
+    web_resource_providers: DashMap<Uuid, Arc<dyn WebResourceProvider>>,
+}
+
+impl HarnessWebResourceManager {
+    /// Returns the web resource provider with the given context path.
+    pub fn get_by_context_path(&self, context_path: &str) -> Option<Arc<dyn WebResourceProvider>> {
+        self.web_resource_providers
+            .iter()
+            .find(|provider| provider.get_context_path() == context_path)
+            .map(|provider| provider.value().clone())
+    }
+
+    /// Returns the number of registered web resource providers.
+    pub fn count(&self) -> usize {
+        self.web_resource_providers.len()
+    }
+}
+
+#[async_trait]
+impl WebResourceManager for HarnessWebResourceManager {
+    async fn register_provider(&self, web_resource_provider: Arc<dyn WebResourceProvider>) {
+        self.web_resource_providers.insert(web_resource_provider.id(), web_resource_provider);
+    }
+
+    async fn unregister_provider(&self, id: Uuid) {
+        self.web_resource_providers.remove(&id);
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use reactive_graph_config_model::GraphQLServerConfig;
+use reactive_graph_config_model::InstanceConfig;
+use reactive_graph_config_model::PluginsConfig;
+use reactive_graph_config_model::RemotesConfig;
+use reactive_graph_plugin_api::ConfigManager;
+use reactive_graph_plugin_service_api::PluginHostCall;
+
+use crate::connection::RuntimeConnection;
+
+/// Reads the configuration from the config manager of the runtime.
+pub struct RemoteConfigManager {
+    connection: Arc<RuntimeConnection>,
+}
+
+impl RemoteConfigManager {
+    pub fn new(connection: Arc<RuntimeConnection>) -> Self {
+        RemoteConfigManager { connection }
+    }
+}
+
+impl ConfigManager for RemoteConfigManager {
+    fn get_instance_config(&self) -> InstanceConfig {
+        self.connection.call_blocking_as(PluginHostCall::GetInstanceConfig)
+    }
+
+    fn get_graphql_server_config(&self) -> GraphQLServerConfig {
+        self.connection.call_blocking_as(PluginHostCall::GetGraphQLServerConfig)
+    }
+
+    fn get_plugins_config(&self) -> PluginsConfig {
+        self.connection.call_blocking_as(PluginHostCall::GetPluginsConfig)
+    }
+
+    fn get_remotes_config(&self) -> RemotesConfig {
+        self.connection.call_blocking_as(PluginHostCall::GetRemotesConfig)
+    }
+
+    fn get_plugin_config(&self, plugin_name: &str) -> Option<Value> {
+        self.connection.call_blocking_as(PluginHostCall::GetPluginConfig {
+            plugin_name: plugin_name.to_string(),
+        })
+    }
+}
@@ -0,0 +1,146 @@
+use std::io::Write;
+use std::net::TcpStream;
+use std::sync::Mutex;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use log::error;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tokio::runtime::Handle;
+use tokio::runtime::RuntimeFlavor;
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+use reactive_graph_plugin_service_api::PLUGIN_HOST_DISCONNECTED;
+use reactive_graph_plugin_service_api::PluginHostCall;
+use reactive_graph_plugin_service_api::PluginHostChannel;
+use reactive_graph_plugin_service_api::PluginHostMessage;
+use reactive_graph_plugin_service_api::PluginHostPropertyCache;
+use reactive_graph_plugin_service_api::PluginHostTypeKind;
+use reactive_graph_plugin_service_api::PluginHostTypes;
+
+/// How long to wait for the response of the runtime.
+const CALL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The connection of the plugin host to the runtime.
+///
+/// The messages are written to the socket which is dedicated to the protocol. The socket is
+/// locked while writing a message, so that messages of different threads can't interleave.
+pub struct RuntimeConnection {
+    pub channel: PluginHostChannel,
+    property_cache: PluginHostPropertyCache,
+}
+
+impl RuntimeConnection {
+    pub fn new(stream: TcpStream) -> Self {
+        let stream = Mutex::new(stream);
+        RuntimeConnection {
+            channel: PluginHostChannel::new(move |message| {
+                let Ok(mut stream) = stream.lock() else {
+                    return false;
+                };
+                stream.write_all(message.to_line().as_bytes()).is_ok() && stream.flush().is_ok()
+            }),
+            property_cache: PluginHostPropertyCache::new(),
+        }
+    }
+
+    /// Tells the runtime that the plugin can't be run.
+    pub fn fail(&self, error: String) {
+        self.channel.send(PluginHostMessage::Failed { error });
+    }
+
+    /// Calls the runtime and waits for the response.
+    pub async fn call(&self, call: PluginHostCall) -> Result<Value, String> {
+        let (sender, receiver) = oneshot::channel();
+        self.channel.call(
+            call,
+            Box::new(move |result| {
+                let _ = sender.send(result);
+            }),
+        );
+        match tokio::time::timeout(CALL_TIMEOUT, receiver).await {
+            Ok(Ok(result)) => result,
+            _ => Err(PLUGIN_HOST_DISCONNECTED.to_string()),
+        }
+    }
+
+    /// Calls the runtime and blocks the current thread until the response has been received.
+    ///
+    /// The managers of the plugin context are synchronous, but may be called from within an
+    /// asynchronous task of the plugin.
+    pub fn call_blocking(&self, call: PluginHostCall) -> Result<Value, String> {
+        let (sender, receiver) = mpsc::channel();
+        self.channel.call(
+            call,
+            Box::new(move |result| {
+                let _ = sender.send(result);
+            }),
+        );
+        let receive = move || {
+            receiver
+                .recv_timeout(CALL_TIMEOUT)
+                .unwrap_or_else(|_| Err(PLUGIN_HOST_DISCONNECTED.to_string()))
+        };
+        match Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => tokio::task::block_in_place(receive),
+            _ => receive(),
+        }
+    }
+
+    /// Calls the runtime and deserializes the response. Errors are logged.
+    pub fn call_blocking_as<T: DeserializeOwned + Default>(&self, call: PluginHostCall) -> T {
+        match self
+            .call_blocking(call)
+            .and_then(|value| serde_json::from_value(value).map_err(|e| e.to_string()))
+        {
+            Ok(result) => result,
+            Err(e) => {
+                error!("Failed to call the runtime: {e}");
+                T::default()
+            }
+        }
+    }
+
+    pub async fn register_types(&self, provider_id: &str, types: PluginHostTypes) {
+        let call = PluginHostCall::RegisterTypes {
+            provider_id: provider_id.to_string(),
+            types,
+        };
+        if let Err(e) = self.call(call).await {
+            error!("Failed to register the types of {provider_id}: {e}");
+        }
+    }
+
+    pub async fn unregister_types(&self, provider_id: &str, kind: PluginHostTypeKind) {
+        let call = PluginHostCall::UnregisterTypes {
+            provider_id: provider_id.to_string(),
+            kind,
+        };
+        if let Err(e) = self.call(call).await {
+            error!("Failed to unregister the types of {provider_id}: {e}");
+        }
+    }
+
+    /// Sends the changed value of a property of a mirror to the runtime.
+    pub fn property_changed(&self, id: Uuid, property: &str, value: &Value) {
+        if !self.property_cache.should_send(id, property, value) {
+            return;
+        }
+        self.channel.send(PluginHostMessage::PropertyChanged {
+            id,
+            property: property.to_string(),
+            value: value.clone(),
+        });
+    }
+
+    /// Remembers the value of a property which has been received from the runtime.
+    pub fn property_received(&self, id: Uuid, property: &str, value: Value) {
+        self.property_cache.received(id, property, value);
+    }
+
+    pub fn forget_properties(&self, id: Uuid) {
+        self.property_cache.remove(id);
+    }
+}
@@ -0,0 +1,187 @@
+use std::process::exit;
+use std::sync::Arc;
+
+use log::error;
+
+use reactive_graph_plugin_api::CommandManager;
+use reactive_graph_plugin_api::ComponentImportExportManager;
+use reactive_graph_plugin_api::ComponentManager;
+use reactive_graph_plugin_api::ComponentProviderRegistry;
+use reactive_graph_plugin_api::ConfigManager;
+use reactive_graph_plugin_api::EntityBehaviourRegistry;
+use reactive_graph_plugin_api::EntityComponentBehaviourRegistry;
+use reactive_graph_plugin_api::EntityInstanceManager;
+use reactive_graph_plugin_api::EntityTypeImportExportManager;
+use reactive_graph_plugin_api::EntityTypeManager;
+use reactive_graph_plugin_api::EntityTypeProviderRegistry;
+use reactive_graph_plugin_api::FlowInstanceManager;
+use reactive_graph_plugin_api::FlowTypeImportExportManager;
+use reactive_graph_plugin_api::FlowTypeManager;
+use reactive_graph_plugin_api::FlowTypeProviderRegistry;
+use reactive_graph_plugin_api::GraphQLQueryService;
+use reactive_graph_plugin_api::NamespaceManager;
+use reactive_graph_plugin_api::PluginContext;
+use reactive_graph_plugin_api::RelationBehaviourRegistry;
+use reactive_graph_plugin_api::RelationComponentBehaviourRegistry;
+use reactive_graph_plugin_api::RelationInstanceManager;
+use reactive_graph_plugin_api::RelationTypeImportExportManager;
+use reactive_graph_plugin_api::RelationTypeManager;
+use reactive_graph_plugin_api::RelationTypeProviderRegistry;
+use reactive_graph_plugin_api::TypeSystemEventManager;
+use reactive_graph_plugin_api::WebResourceManager;
+
+use crate::config_manager::RemoteConfigManager;
+use crate::connection::RuntimeConnection;
+use crate::entity_instance_manager::RemoteEntityInstanceManager;
+use crate::mirrors::EntityInstanceMirrors;
+use crate::registries::RemoteBehaviourFactories;
+use crate::registries::RemoteComponentProviderRegistry;
+use crate::registries::RemoteEntityBehaviourRegistry;
+use crate::registries::RemoteEntityComponentBehaviourRegistry;
+use crate::registries::RemoteEntityTypeProviderRegistry;
+use crate::registries::RemoteFlowTypeProviderRegistry;
+use crate::registries::RemoteRelationTypeProviderRegistry;
+
+/// The plugin context of a plugin running in the plugin host.
+///
+/// Only the type provider registries, the entity behaviour registries, the entity instance
+/// manager and the config manager are available to plugins running out of process.
+pub struct RemotePluginContext {
+    connection: Arc<RuntimeConnection>,
+    component_provider_registry: Arc<RemoteComponentProviderRegistry>,
+    entity_type_provider_registry: Arc<RemoteEntityTypeProviderRegistry>,
+    relation_type_provider_registry: Arc<RemoteRelationTypeProviderRegistry>,
+    flow_type_provider_registry: Arc<RemoteFlowTypeProviderRegistry>,
+    entity_instance_manager: Arc<RemoteEntityInstanceManager>,
+    entity_behaviour_registry: Arc<RemoteEntityBehaviourRegistry>,
+    entity_component_behaviour_registry: Arc<RemoteEntityComponentBehaviourRegistry>,
+    config_manager: Arc<RemoteConfigManager>,
+}
+
+impl RemotePluginContext {
+    pub fn new(connection: Arc<RuntimeConnection>, mirrors: Arc<EntityInstanceMirrors>, factories: Arc<RemoteBehaviourFactories>) -> Self {
+        RemotePluginContext {
+            component_provider_registry: Arc::new(RemoteComponentProviderRegistry::new(connection.clone())),
+            entity_type_provider_registry: Arc::new(RemoteEntityTypeProviderRegistry::new(connection.clone())),
+            relation_type_provider_registry: Arc::new(RemoteRelationTypeProviderRegistry::new(connection.clone())),
+            flow_type_provider_registry: Arc::new(RemoteFlowTypeProviderRegistry::new(connection.clone())),
+            entity_instance_manager: Arc::new(RemoteEntityInstanceManager::new(connection.clone(), mirrors)),
+            entity_behaviour_registry: Arc::new(RemoteEntityBehaviourRegistry::new(connection.clone(), factories.clone())),
+            entity_component_behaviour_registry: Arc::new(RemoteEntityComponentBehaviourRegistry::new(connection.clone(), factories)),
+            config_manager: Arc::new(RemoteConfigManager::new(connection.clone())),
+            connection,
+        }
+    }
+
+    /// Reports the service which is not available out of process to the runtime and exits.
+    ///
+    /// The runtime fails the activation of the plugin and doesn't restart the plugin host.
+    fn unavailable(&self, service: &str) -> ! {
+        let error = format!("The {service} is not available to plugins running out of process");
+        error!("{error}");
+        self.connection.fail(error);
+        exit(1)
+    }
+}
+
+impl PluginContext for RemotePluginContext {
+    fn get_component_manager(&self) -> Arc<dyn ComponentManager + Send + Sync> {
+        self.unavailable("component manager")
+    }
+
+    fn get_component_import_export_manager(&self) -> Arc<dyn ComponentImportExportManager + Send + Sync> {
+        self.unavailable("component import export manager")
+    }
+
+    fn get_component_provider_registry(&self) -> Arc<dyn ComponentProviderRegistry + Send + Sync> {
+        self.component_provider_registry.clone()
+    }
+
+    fn get_entity_type_manager(&self) -> Arc<dyn EntityTypeManager + Send + Sync> {
+        self.unavailable("entity type manager")
+    }
+
+    fn get_entity_type_import_export_manager(&self) -> Arc<dyn EntityTypeImportExportManager + Send + Sync> {
+        self.unavailable("entity type import export manager")
+    }
+
+    fn get_entity_type_provider_registry(&self) -> Arc<dyn EntityTypeProviderRegistry + Send + Sync> {
+        self.entity_type_provider_registry.clone()
+    }
+
+    fn get_relation_type_manager(&self) -> Arc<dyn RelationTypeManager + Send + Sync> {
+        self.unavailable("relation type manager")
+    }
+
+    fn get_relation_type_import_export_manager(&self) -> Arc<dyn RelationTypeImportExportManager + Send + Sync> {
+        self.unavailable("relation type import export manager")
+    }
+
+    fn get_relation_type_provider_registry(&self) -> Arc<dyn RelationTypeProviderRegistry + Send + Sync> {
+        self.relation_type_provider_registry.clone()
+    }
+
+    fn get_flow_type_manager(&self) -> Arc<dyn FlowTypeManager + Send + Sync> {
+        self.unavailable("flow type manager")
+    }
+
+    fn get_flow_type_import_export_manager(&self) -> Arc<dyn FlowTypeImportExportManager + Send + Sync> {
+        self.unavailable("flow type import export manager")
+    }
+
+    fn get_flow_type_provider_registry(&self) -> Arc<dyn FlowTypeProviderRegistry + Send + Sync> {
+        self.flow_type_provider_registry.clone()
+    }
+
+    fn get_namespace_manager(&self) -> Arc<dyn NamespaceManager + Send + Sync> {
+        self.unavailable("namespace manager")
+    }
+
+    fn get_type_system_event_manager(&self) -> Arc<dyn TypeSystemEventManager + Send + Sync> {
+        self.unavailable("type system event manager")
+    }
+
+    fn get_entity_instance_manager(&self) -> Arc<dyn EntityInstanceManager + Send + Sync> {
+        self.entity_instance_manager.clone()
+    }
+
+    fn get_relation_instance_manager(&self) -> Arc<dyn RelationInstanceManager + Send + Sync> {
+        self.unavailable("relation instance manager")
+    }
+
+    fn get_flow_instance_manager(&self) -> Arc<dyn FlowInstanceManager + Send + Sync> {
+        self.unavailable("flow instance manager")
+    }
+
+    fn get_entity_behaviour_registry(&self) -> Arc<dyn EntityBehaviourRegistry + Send + Sync> {
+        self.entity_behaviour_registry.clone()
+    }
+
+    fn get_entity_component_behaviour_registry(&self) -> Arc<dyn EntityComponentBehaviourRegistry + Send + Sync> {
+        self.entity_component_behaviour_registry.clone()
+    }
+
+    fn get_relation_behaviour_registry(&self) -> Arc<dyn RelationBehaviourRegistry + Send + Sync> {
+        self.unavailable("relation behaviour registry")
+    }
+
+    fn get_relation_component_behaviour_registry(&self) -> Arc<dyn RelationComponentBehaviourRegistry + Send + Sync> {
+        self.unavailable("relation component behaviour registry")
+    }
+
+    fn get_graphql_query_service(&self) -> Arc<dyn GraphQLQueryService + Send + Sync> {
+        self.unavailable("GraphQL query service")
+    }
+
+    fn get_web_resource_manager(&self) -> Arc<dyn WebResourceManager + Send + Sync> {
+        self.unavailable("web resource manager")
+    }
+
+    fn get_config_manager(&self) -> Arc<dyn ConfigManager + Send + Sync> {
+        self.config_manager.clone()
+    }
+
+    fn get_command_manager(&self) -> Arc<dyn CommandManager + Send + Sync> {
+        self.unavailable("command manager")
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use log::error;
+use uuid::Uuid;
+
+use reactive_graph_behaviour_model_api::BehaviourTypeId;
+use reactive_graph_graph::ComponentContainer;
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::EntityInstance;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::LabelPattern;
+use reactive_graph_graph::PropertyInstanceGetter;
+use reactive_graph_graph::PropertyTypeDefinition;
+use reactive_graph_plugin_api::EntityInstanceManager;
+use reactive_graph_plugin_service_api::PluginHostCall;
+use reactive_graph_plugin_service_api::PluginHostEntityInstanceError;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+use reactive_graph_reactive_service_api::ReactiveEntityComponentAddError;
+use reactive_graph_reactive_service_api::ReactiveEntityCreationError;
+use reactive_graph_reactive_service_api::ReactiveEntityRegistrationError;
+use reactive_graph_runtime_model::LabeledProperties::LABEL;
+
+use crate::connection::RuntimeConnection;
+use crate::mirrors::EntityInstanceMirrors;
+
+/// Calls the entity instance manager of the runtime.
+///
+/// The entity instances returned to the plugin are mirrors of the entity instances of the runtime.
+pub struct RemoteEntityInstanceManager {
+    connection: Arc<RuntimeConnection>,
+    mirrors: Arc<EntityInstanceMirrors>,
+}
+
+impl RemoteEntityInstanceManager {
+    pub fn new(connection: Arc<RuntimeConnection>, mirrors: Arc<EntityInstanceMirrors>) -> Self {
+        RemoteEntityInstanceManager { connection, mirrors }
+    }
+
+    fn mirror_all(&self, entity_instances: Vec<EntityInstance>) -> Vec<ReactiveEntity> {
+        entity_instances
+            .into_iter()
+            .map(|entity_instance| self.mirrors.mirror(entity_instance))
+            .collect()
+    }
+
+    fn create_remote(&self, entity_instance: EntityInstance) -> Result<EntityInstance, PluginHostEntityInstanceError> {
+        let id = entity_instance.id;
+        self.connection
+            .call_blocking(PluginHostCall::CreateEntityInstance { entity_instance })
+            .and_then(|value| serde_json::from_value::<Result<EntityInstance, PluginHostEntityInstanceError>>(value).map_err(|e| e.to_string()))
+            .unwrap_or_else(|e| {
+                error!("Failed to create entity instance {id}: {e}");
+                Err(PluginHostEntityInstanceError::CreatedInstanceMissing)
+            })
+    }
+}
+
+impl EntityInstanceManager for RemoteEntityInstanceManager {
+    fn has(&self, id: Uuid) -> bool {
+        self.connection.call_blocking_as(PluginHostCall::HasEntityInstance { id })
+    }
+
+    fn get(&self, id: Uuid) -> Option<ReactiveEntity> {
+        self.connection
+            .call_blocking_as::<Option<EntityInstance>>(PluginHostCall::GetEntityInstance { id })
+            .map(|entity_instance| self.mirrors.mirror(entity_instance))
+    }
+
+    fn get_by_label(&self, label: &str) -> Option<ReactiveEntity> {
+        self.connection
+            .call_blocking_as::<Option<EntityInstance>>(PluginHostCall::GetEntityInstanceByLabel { label: label.to_string() })
+            .map(|entity_instance| self.mirrors.mirror(entity_instance))
+    }
+
+    fn get_by_label_with_params(&self, label: &str) -> Option<(ReactiveEntity, HashMap<String, String>)> {
+        self.connection
+            .call_blocking_as::<Option<(EntityInstance, HashMap<String, String>)>>(PluginHostCall::GetEntityInstanceByLabelWithParams {
+                label: label.to_string(),
+            })
+            .map(|(entity_instance, params)| (self.mirrors.mirror(entity_instance), params))
+    }
+
+    fn get_by_label_pattern(&self, pattern: &LabelPattern) -> Vec<(ReactiveEntity, HashMap<String, String>)> {
+        let mut matches: Vec<(String, ReactiveEntity, HashMap<String, String>)> = self
+            .get_all()
+            .into_iter()
+            .filter_map(|entity| {
+                let label = entity.get(LABEL.property_name()).and_then(|v| v.as_str().map(|s| s.to_string()))?;
+                let params = pattern.matches(&label)?;
+                Some((label, entity, params))
+            })
+            .collect();
+        matches.sort_by(|a, b| a.0.cmp(&b.0));
+        matches.into_iter().map(|(_, entity, params)| (entity, params)).collect()
+    }
+
+    fn get_all(&self) -> Vec<ReactiveEntity> {
+        self.mirror_all(self.connection.call_blocking_as(PluginHostCall::GetEntityInstances))
+    }
+
+    fn get_by_type(&self, ty: &EntityTypeId) -> Vec<ReactiveEntity> {
+        self.mirror_all(self.connection.call_blocking_as(PluginHostCall::GetEntityInstancesByType { ty: ty.clone() }))
+    }
+
+    fn get_ids(&self) -> Vec<Uuid> {
+        self.connection.call_blocking_as(PluginHostCall::GetEntityInstanceIds)
+    }
+
+    fn count(&self) -> usize {
+        self.connection.call_blocking_as(PluginHostCall::CountEntityInstances)
+    }
+
+    fn count_by_type(&self, ty: &EntityTypeId) -> usize {
+        self.connection.call_blocking_as(PluginHostCall::CountEntityInstancesByType { ty: ty.clone() })
+    }
+
+    fn count_by_component(&self, component: &ComponentTypeId) -> usize {
+        self.connection
+            .call_blocking_as(PluginHostCall::CountEntityInstancesByComponent { component: component.clone() })
+    }
+
+    fn count_by_behaviour(&self, behaviour_ty: &BehaviourTypeId) -> usize {
+        self.connection.call_blocking_as(PluginHostCall::CountEntityInstancesByBehaviour {
+            behaviour_ty: behaviour_ty.clone(),
+        })
+    }
+
+    fn create(&self, entity_instance: EntityInstance) -> Result<ReactiveEntity, ReactiveEntityCreationError> {
+        let entity_instance = self.create_remote(entity_instance)?;
+        Ok(self.mirrors.mirror(entity_instance))
+    }
+
+    fn register(&self, reactive_entity: ReactiveEntity) -> Result<ReactiveEntity, ReactiveEntityRegistrationError> {
+        match self.create_remote(EntityInstance::from(&reactive_entity)) {
+            Ok(_) => {
+                // The given reactive entity becomes the mirror of the created entity instance
+                self.mirrors.register(reactive_entity.clone());
+                Ok(reactive_entity)
+            }
+            Err(PluginHostEntityInstanceError::UuidTaken(id)) => Err(ReactiveEntityRegistrationError::UuidTaken(id)),
+            Err(PluginHostEntityInstanceError::PermissionDenied(reason)) => Err(ReactiveEntityRegistrationError::PermissionDenied(reason)),
+            Err(_) => Err(ReactiveEntityRegistrationError::UnknownEntityType(reactive_entity.ty.clone())),
+        }
+    }
+
+    fn add_component(&self, id: Uuid, component: &ComponentTypeId) -> Result<(), ReactiveEntityComponentAddError> {
+        let call = PluginHostCall::AddComponent {
+            id,
+            component: component.clone(),
+        };
+        let result = self
+            .connection
+            .call_blocking(call)
+            .and_then(|value| serde_json::from_value::<Result<(), PluginHostEntityInstanceError>>(value).map_err(|e| e.to_string()))
+            .unwrap_or_else(|e| {
+                error!("Failed to add component {component} to {id}: {e}");
+                Err(PluginHostEntityInstanceError::MissingInstance(id))
+            });
+        match result {
+            Ok(_) => {
+                // Mirror the properties which have been added by the component
+                self.get(id);
+                Ok(())
+            }
+            Err(PluginHostEntityInstanceError::MissingComponent(ty)) => Err(ReactiveEntityComponentAddError::MissingComponent(ty)),
+            Err(PluginHostEntityInstanceError::PermissionDenied(reason)) => Err(ReactiveEntityComponentAddError::PermissionDenied(reason)),
+            Err(_) => Err(ReactiveEntityComponentAddError::MissingInstance(id)),
+        }
+    }
+
+    fn remove_component(&self, id: Uuid, component: &ComponentTypeId) {
+        let call = PluginHostCall::RemoveComponent {
+            id,
+            component: component.clone(),
+        };
+        if let Err(e) = self.connection.call_blocking(call) {
+            error!("Failed to remove component {component} from {id}: {e}");
+            return;
+        }
+        if let Some(mirror) = self.mirrors.get(id) {
+            mirror.remove_component(component);
+        }
+    }
+
+    fn delete(&self, id: Uuid) -> bool {
+        self.mirrors.remove(id);
+        self.connection.call_blocking_as(PluginHostCall::DeleteEntityInstance { id })
+    }
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use log::warn;
+use serde_json::Value;
+use uuid::Uuid;
+
+use reactive_graph_behaviour_model_api::BehaviourFsm;
+use reactive_graph_behaviour_model_api::BehaviourState;
+use reactive_graph_behaviour_model_api::BehaviourTypeId;
+use reactive_graph_graph::EntityInstance;
+use reactive_graph_plugin_api::Plugin;
+use reactive_graph_plugin_service_api::PluginHostRequest;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+
+use crate::connection::RuntimeConnection;
+use crate::mirrors::EntityInstanceMirrors;
+use crate::registries::RemoteBehaviourFactories;
+
+/// Serves the requests of the runtime to the plugin.
+pub struct PluginHost {
+    pub connection: Arc<RuntimeConnection>,
+    pub mirrors: Arc<EntityInstanceMirrors>,
+    factories: Arc<RemoteBehaviourFactories>,
+    behaviours: DashMap<(Uuid, BehaviourTypeId), Arc<dyn BehaviourFsm<Uuid, ReactiveEntity> + Send + Sync>>,
+    plugin: Arc<dyn Plugin>,
+}
+
+impl PluginHost {
+    pub fn new(
+        connection: Arc<RuntimeConnection>,
+        mirrors: Arc<EntityInstanceMirrors>,
+        factories: Arc<RemoteBehaviourFactories>,
+        plugin: Arc<dyn Plugin>,
+    ) -> Self {
+        PluginHost {
+            connection,
+            mirrors,
+            factories,
+            behaviours: DashMap::new(),
+            plugin,
+        }
+    }
+
+    pub async fn handle_request(&self, request: PluginHostRequest) -> Result<Value, String> {
+        match request {
+            PluginHostRequest::Activate => self.plugin.activate().await.map(|_| Value::Null).map_err(|e| e.to_string()),
+            PluginHostRequest::Deactivate => {
+                let result = self.plugin.deactivate().await.map(|_| Value::Null).map_err(|e| e.to_string());
+                self.disconnect_behaviours();
+                result
+            }
+            PluginHostRequest::ConfigChanged { config } => {
+                self.plugin.config_changed(config).await;
+                Ok(Value::Null)
+            }
+            PluginHostRequest::ConnectBehaviour { behaviour_ty, entity_instance } => self.connect_behaviour(behaviour_ty, entity_instance),
+            PluginHostRequest::DisconnectBehaviour { behaviour_ty, id } => {
+                self.disconnect_behaviour(id, behaviour_ty);
+                Ok(Value::Null)
+            }
+        }
+    }
+
+    /// Creates the behaviour on the mirror of the entity instance.
+    fn connect_behaviour(&self, behaviour_ty: BehaviourTypeId, entity_instance: EntityInstance) -> Result<Value, String> {
+        let Some(factory) = self.factories.get(&behaviour_ty).map(|factory| factory.value().clone()) else {
+            return Err(format!("The behaviour {behaviour_ty} is not provided by the plugin"));
+        };
+        let id = entity_instance.id;
+        // The behaviour is connected again after the plugin host has been restarted
+        self.disconnect_behaviour(id, behaviour_ty.clone());
+        let mirror = self.mirrors.mirror(entity_instance);
+        let behaviour = factory.create(mirror).map_err(|e| e.to_string())?;
+        self.behaviours.insert((id, behaviour_ty), behaviour);
+        Ok(Value::Null)
+    }
+
+    fn disconnect_behaviour(&self, id: Uuid, behaviour_ty: BehaviourTypeId) {
+        if let Some((_, behaviour)) = self.behaviours.remove(&(id, behaviour_ty)) {
+            if let Err(e) = behaviour.transition(BehaviourState::Ready) {
+                warn!("Failed to disconnect behaviour {} of {id}: {e}", behaviour.ty());
+            }
+        }
+    }
+
+    fn disconnect_behaviours(&self) {
+        let keys: Vec<(Uuid, BehaviourTypeId)> = self.behaviours.iter().map(|behaviour| behaviour.key().clone()).collect();
+        for (id, behaviour_ty) in keys {
+            self.disconnect_behaviour(id, behaviour_ty);
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use std::alloc::System;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::process::exit;
+use std::sync::Arc;
+use std::thread;
+
+use clap::Parser;
+use dashmap::DashMap;
+use libloading::Library;
+use log::LevelFilter;
+use log::debug;
+use log::error;
+use log::warn;
+use log4rs::Config;
+use log4rs::append::console::ConsoleAppender;
+use log4rs::append::console::Target;
+use log4rs::config::Appender;
+use log4rs::config::Root;
+use serde_json::Value;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use reactive_graph_plugin_api::PLUGIN_API_VERSION;
+use reactive_graph_plugin_api::Plugin;
+use reactive_graph_plugin_api::PluginContext;
+use reactive_graph_plugin_api::PluginDeclaration;
+use reactive_graph_plugin_service_api::PLUGIN_HOST_TOKEN_ENV;
+use reactive_graph_plugin_service_api::PluginHostMessage;
+
+use crate::connection::RuntimeConnection;
+use crate::context::RemotePluginContext;
+use crate::host::PluginHost;
+use crate::mirrors::EntityInstanceMirrors;
+
+pub mod config_manager;
+pub mod connection;
+pub mod context;
+pub mod entity_instance_manager;
+pub mod host;
+pub mod mirrors;
+pub mod registries;
+
+#[global_allocator]
+static ALLOCATOR: System = System;
+
+/// Runs a plugin out of process.
+///
+/// The plugin host is started by the runtime and connects to a loopback socket of the runtime
+/// which is dedicated to the protocol. The standard output is left to the plugin. The plugin
+/// host exits if the runtime has closed the connection.
+#[derive(Parser, Debug)]
+#[command(name = "reactive-graph-plugin-host", author, version, about, long_about = None)]
+pub struct PluginHostArguments {
+    /// The path of the dynamic link library of the plugin.
+    plugin: PathBuf,
+
+    /// The address of the socket of the runtime.
+    #[arg(long)]
+    connect: SocketAddr,
+
+    /// The token which authenticates the connection to the runtime.
+    #[arg(long, env = PLUGIN_HOST_TOKEN_ENV, hide_env_values = true)]
+    token: String,
+
+    /// The log level.
+    #[arg(long, env = "REACTIVE_GRAPH_PLUGIN_HOST_LOG_LEVEL", default_value = "info")]
+    log_level: LevelFilter,
+}
+
+struct PluginHostRegistrar {
+    plugin: Option<Arc<dyn Plugin>>,
+    context: Arc<dyn PluginContext + Send + Sync>,
+}
+
+impl reactive_graph_plugin_api::PluginRegistrar for PluginHostRegistrar {
+    fn register_plugin(&mut self, plugin: Box<Arc<dyn Plugin>>) {
+        self.plugin = Some(*plugin);
+    }
+
+    fn context(&self) -> Arc<dyn PluginContext + Send + Sync> {
+        self.context.clone()
+    }
+}
+
+fn main() {
+    let args = PluginHostArguments::parse();
+    init_logging(args.log_level);
+    let runtime = match tokio::runtime::Builder::new_multi_thread().enable_all().build() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            error!("Failed to create the runtime of the plugin host: {e}");
+            exit(1);
+        }
+    };
+    let stream = match connect(args.connect, &args.token) {
+        Ok(stream) => stream,
+        Err(e) => {
+            error!("Failed to connect to the runtime at {}: {e}", args.connect);
+            exit(1);
+        }
+    };
+    let code = runtime.block_on(run(args.plugin, stream));
+    exit(code);
+}
+
+/// Connects to the runtime and authenticates the connection.
+fn connect(address: SocketAddr, token: &str) -> std::io::Result<TcpStream> {
+    let mut stream = TcpStream::connect(address)?;
+    stream.set_nodelay(true)?;
+    stream.write_all(format!("{token}\n").as_bytes())?;
+    stream.flush()?;
+    Ok(stream)
+}
+
+/// The logs are written to the standard error.
+fn init_logging(level: LevelFilter) {
+    let stderr = ConsoleAppender::builder().target(Target::Stderr).build();
+    let Ok(config) = Config::builder()
+        .appender(Appender::builder().build("stderr", Box::new(stderr)))
+        .build(Root::builder().appender("stderr").build(level))
+    else {
+        eprintln!("Failed to create the logger of the plugin host");
+        return;
+    };
+    if let Err(e) = log4rs::init_config(config) {
+        eprintln!("Failed to configure the logger of the plugin host: {e}");
+    }
+}
+
+/// Reports to the runtime that the plugin can't be run.
+fn fail(connection: &RuntimeConnection, error: String) -> i32 {
+    error!("{error}");
+    connection.fail(error);
+    1
+}
+
+async fn run(path: PathBuf, stream: TcpStream) -> i32 {
+    let reader = match stream.try_clone() {
+        Ok(reader) => BufReader::new(reader),
+        Err(e) => {
+            error!("Failed to read from the connection to the runtime: {e}");
+            return 1;
+        }
+    };
+    let connection = Arc::new(RuntimeConnection::new(stream));
+    let library = match unsafe { Library::new(path.as_os_str()) } {
+        Ok(library) => library,
+        Err(e) => return fail(&connection, format!("Failed to load {}: {e}", path.display())),
+    };
+    // The layout of the plugin declaration depends on the plugin API version
+    let plugin_api_version = match unsafe { library.get::<*const &str>(b"plugin_api_version\0") } {
+        Ok(plugin_api_version) => unsafe { plugin_api_version.read().to_string() },
+        Err(e) => return fail(&connection, format!("Failed to get symbol plugin_api_version of {}: {e}", path.display())),
+    };
+    if plugin_api_version != PLUGIN_API_VERSION {
+        return fail(
+            &connection,
+            format!(
+                "The plugin {} is not compatible: Expected plugin_api_version {PLUGIN_API_VERSION} - Actual {plugin_api_version}",
+                path.display()
+            ),
+        );
+    }
+    let plugin_declaration = match unsafe { library.get::<*mut PluginDeclaration>(b"plugin_declaration\0") } {
+        Ok(plugin_declaration) => unsafe { plugin_declaration.read() },
+        Err(e) => return fail(&connection, format!("Failed to get symbol plugin_declaration of {}: {e}", path.display())),
+    };
+
+    let mirrors = Arc::new(EntityInstanceMirrors::new(connection.clone()));
+    let factories = Arc::new(DashMap::new());
+    let mut registrar = PluginHostRegistrar {
+        plugin: None,
+        context: Arc::new(RemotePluginContext::new(connection.clone(), mirrors.clone(), factories.clone())),
+    };
+    if let Err(e) = unsafe { (plugin_declaration.register)(&mut registrar) } {
+        return fail(&connection, format!("Failed to register plugin {}: {e}", plugin_declaration.name));
+    }
+    let Some(plugin) = registrar.plugin.take() else {
+        return fail(&connection, format!("The plugin {} did not register itself", plugin_declaration.name));
+    };
+    let host = Arc::new(PluginHost::new(connection.clone(), mirrors.clone(), factories, plugin));
+
+    // The requests are handled in order
+    let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
+    let request_host = host.clone();
+    tokio::spawn(async move {
+        while let Some((id, request)) = request_receiver.recv().await {
+            let result = request_host.handle_request(request).await;
+            request_host.connection.channel.send(PluginHostMessage::Response { id, result });
+        }
+    });
+
+    // Setting a property of a mirror propagates synchronously through the reactive streams of the behaviours
+    let (property_sender, property_receiver) = std::sync::mpsc::channel::<(Uuid, String, Value)>();
+    let property_mirrors = mirrors.clone();
+    thread::spawn(move || {
+        while let Ok((id, property, value)) = property_receiver.recv() {
+            property_mirrors.property_changed(id, &property, value);
+        }
+    });
+
+    connection.channel.send(PluginHostMessage::Ready {
+        name: plugin_declaration.name.to_string(),
+        version: plugin_declaration.version.to_string(),
+    });
+
+    let reader_connection = connection.clone();
+    let _ = tokio::task::spawn_blocking(move || {
+        for line in reader.lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            match PluginHostMessage::parse(&line) {
+                Some(PluginHostMessage::Request { id, request }) => {
+                    let _ = request_sender.send((id, request));
+                }
+                Some(PluginHostMessage::Response { id, result }) => {
+                    reader_connection.channel.respond(id, result);
+                }
+                Some(PluginHostMessage::PropertyChanged { id, property, value }) => {
+                    let _ = property_sender.send((id, property, value));
+                }
+                Some(_) => warn!("Unexpected message from the runtime"),
+                None => warn!("Invalid message from the runtime: {line}"),
+            }
+        }
+    })
+    .await;
+    debug!("The runtime has closed the connection to the plugin host of {}", plugin_declaration.name);
+    connection.channel.close();
+    // The plugin is not unloaded, because the threads of the plugin may still be running
+    std::mem::forget(library);
+    0
+}
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+use std::sync::Weak;
+
+use dashmap::DashMap;
+use serde_json::Value;
+use uuid::Uuid;
+
+use reactive_graph_graph::ComponentContainer;
+use reactive_graph_graph::EntityInstance;
+use reactive_graph_graph::Mutability::Mutable;
+use reactive_graph_graph::PropertyInstanceSetter;
+use reactive_graph_reactive_model_api::ReactivePropertyContainer;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+
+use crate::connection::RuntimeConnection;
+
+/// The mirrors of the entity instances of the runtime which are known to the plugin.
+///
+/// The properties of a mirror are synchronized with the properties of the entity instance
+/// in the runtime in both directions.
+pub struct EntityInstanceMirrors {
+    connection: Arc<RuntimeConnection>,
+    mirrors: DashMap<Uuid, ReactiveEntity>,
+    handle_id: u128,
+}
+
+impl EntityInstanceMirrors {
+    pub fn new(connection: Arc<RuntimeConnection>) -> Self {
+        EntityInstanceMirrors {
+            connection,
+            mirrors: DashMap::new(),
+            handle_id: Uuid::new_v4().as_u128(),
+        }
+    }
+
+    /// Returns the mirror of the given entity instance. The mirror is created if it doesn't exist yet.
+    pub fn mirror(&self, entity_instance: EntityInstance) -> ReactiveEntity {
+        let Some(mirror) = self.get(entity_instance.id) else {
+            let components: Vec<_> = entity_instance.components.iter().map(|component| component.key().clone()).collect();
+            let mirror = ReactiveEntity::from(entity_instance);
+            for component in components {
+                mirror.add_component(component);
+            }
+            self.register(mirror.clone());
+            return mirror;
+        };
+        // Components and properties may have been added in the meantime
+        for component in entity_instance.components.iter() {
+            if !mirror.is_a(component.key()) {
+                mirror.add_component(component.key().clone());
+            }
+        }
+        for property in entity_instance.properties.iter() {
+            if !mirror.has_property(property.key()) {
+                mirror.add_property(property.key().clone(), Mutable, property.value().clone());
+                self.observe(&mirror, property.key());
+            }
+        }
+        mirror
+    }
+
+    /// Mirrors the given reactive entity.
+    pub fn register(&self, mirror: ReactiveEntity) {
+        if self.mirrors.insert(mirror.id, mirror.clone()).is_some() {
+            return;
+        }
+        let property_names: Vec<String> = mirror.properties.iter().map(|property| property.key().clone()).collect();
+        for property_name in property_names {
+            self.observe(&mirror, &property_name);
+        }
+    }
+
+    pub fn get(&self, id: Uuid) -> Option<ReactiveEntity> {
+        self.mirrors.get(&id).map(|mirror| mirror.value().clone())
+    }
+
+    /// Stops synchronizing the mirror.
+    pub fn remove(&self, id: Uuid) {
+        if let Some((_, mirror)) = self.mirrors.remove(&id) {
+            let property_names: Vec<String> = mirror.properties.iter().map(|property| property.key().clone()).collect();
+            for property_name in property_names {
+                mirror.remove_observer(&property_name, self.handle_id);
+            }
+        }
+        self.connection.forget_properties(id);
+    }
+
+    /// Sets the value of a property which has been changed in the runtime.
+    pub fn property_changed(&self, id: Uuid, property: &str, value: Value) {
+        let Some(mirror) = self.get(id) else {
+            return;
+        };
+        self.connection.property_received(id, property, value.clone());
+        mirror.set(property, value);
+    }
+
+    fn observe(&self, mirror: &ReactiveEntity, property_name: &str) {
+        let id = mirror.id;
+        let property = property_name.to_string();
+        let connection: Weak<RuntimeConnection> = Arc::downgrade(&self.connection);
+        mirror.observe_with_handle(
+            property_name,
+            move |value: &Value| {
+                if let Some(connection) = connection.upgrade() {
+                    connection.property_changed(id, &property, value);
+                }
+            },
+            self.handle_id,
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dashmap::DashMap;
+use log::error;
+use uuid::Uuid;
+
+use reactive_graph_behaviour_model_api::BehaviourFactory;
+use reactive_graph_behaviour_model_api::BehaviourTypeId;
+use reactive_graph_behaviour_model_api::ComponentBehaviourTypeId;
+use reactive_graph_behaviour_model_api::EntityBehaviourTypeId;
+use reactive_graph_graph::Components;
+use reactive_graph_graph::EntityTypes;
+use reactive_graph_graph::FlowTypes;
+use reactive_graph_graph::RelationTypes;
+use reactive_graph_plugin_api::ComponentProviderRegistry;
+use reactive_graph_plugin_api::EntityBehaviourRegistry;
+use reactive_graph_plugin_api::EntityComponentBehaviourRegistry;
+use reactive_graph_plugin_api::EntityTypeProviderRegistry;
+use reactive_graph_plugin_api::FlowTypeProviderRegistry;
+use reactive_graph_plugin_api::RelationTypeProviderRegistry;
+use reactive_graph_plugin_api::TypeProvider;
+use reactive_graph_plugin_service_api::PluginHostCall;
+use reactive_graph_plugin_service_api::PluginHostTypeKind;
+use reactive_graph_plugin_service_api::PluginHostTypes;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+
+use crate::connection::RuntimeConnection;
+
+/// The behaviour factories of the plugin. The behaviours are created in the plugin host.
+pub type RemoteBehaviourFactories = DashMap<BehaviourTypeId, Arc<dyn BehaviourFactory<Uuid, ReactiveEntity> + Send + Sync>>;
+
+macro_rules! remote_type_provider_registry {
+    ($registry: ident, $registry_trait: ident, $types: ident, $kind: ident) => {
+        /// Sends the types of the type providers of the plugin to the runtime.
+        pub struct $registry {
+            connection: Arc<RuntimeConnection>,
+        }
+
+        impl $registry {
+            pub fn new(connection: Arc<RuntimeConnection>) -> Self {
+                $registry { connection }
+            }
+        }
+
+        #[async_trait]
+        impl $registry_trait for $registry {
+            async fn register_provider(&self, provider: Arc<dyn TypeProvider<$types>>) {
+                self.connection
+                    .register_types(provider.id(), PluginHostTypes::$kind(provider.get_types()))
+                    .await
+            }
+
+            async fn unregister_provider(&self, id: &str) {
+                self.connection.unregister_types(id, PluginHostTypeKind::$kind).await
+            }
+        }
+    };
+}
+
+remote_type_provider_registry!(RemoteComponentProviderRegistry, ComponentProviderRegistry, Components, Components);
+remote_type_provider_registry!(RemoteEntityTypeProviderRegistry, EntityTypeProviderRegistry, EntityTypes, EntityTypes);
+remote_type_provider_registry!(RemoteRelationTypeProviderRegistry, RelationTypeProviderRegistry, RelationTypes, RelationTypes);
+remote_type_provider_registry!(RemoteFlowTypeProviderRegistry, FlowTypeProviderRegistry, FlowTypes, FlowTypes);
+
+/// Registers the entity behaviours of the plugin in the runtime and keeps the factories.
+pub struct RemoteEntityBehaviourRegistry {
+    connection: Arc<RuntimeConnection>,
+    factories: Arc<RemoteBehaviourFactories>,
+}
+
+impl RemoteEntityBehaviourRegistry {
+    pub fn new(connection: Arc<RuntimeConnection>, factories: Arc<RemoteBehaviourFactories>) -> Self {
+        RemoteEntityBehaviourRegistry { connection, factories }
+    }
+}
+
+#[async_trait]
+impl EntityBehaviourRegistry for RemoteEntityBehaviourRegistry {
+    async fn register(&self, entity_behaviour_ty: EntityBehaviourTypeId, factory: Arc<dyn BehaviourFactory<Uuid, ReactiveEntity> + Send + Sync>) {
+        self.factories.insert(entity_behaviour_ty.behaviour_ty.clone(), factory);
+        if let Err(e) = self.connection.call(PluginHostCall::RegisterEntityBehaviour { ty: entity_behaviour_ty }).await {
+            error!("Failed to register entity behaviour: {e}");
+        }
+    }
+
+    async fn unregister(&self, entity_behaviour_ty: &EntityBehaviourTypeId) {
+        let call = PluginHostCall::UnregisterEntityBehaviour {
+            ty: entity_behaviour_ty.clone(),
+        };
+        if let Err(e) = self.connection.call(call).await {
+            error!("Failed to unregister entity behaviour: {e}");
+        }
+        self.factories.remove(&entity_behaviour_ty.behaviour_ty);
+    }
+}
+
+/// Registers the entity component behaviours of the plugin in the runtime and keeps the factories.
+pub struct RemoteEntityComponentBehaviourRegistry {
+    connection: Arc<RuntimeConnection>,
+    factories: Arc<RemoteBehaviourFactories>,
+}
+
+impl RemoteEntityComponentBehaviourRegistry {
+    pub fn new(connection: Arc<RuntimeConnection>, factories: Arc<RemoteBehaviourFactories>) -> Self {
+        RemoteEntityComponentBehaviourRegistry { connection, factories }
+    }
+}
+
+#[async_trait]
+impl EntityComponentBehaviourRegistry for RemoteEntityComponentBehaviourRegistry {
+    async fn register(&self, component_behaviour_ty: ComponentBehaviourTypeId, factory: Arc<dyn BehaviourFactory<Uuid, ReactiveEntity> + Send + Sync>) {
+        self.factories.insert(component_behaviour_ty.behaviour_ty.clone(), factory);
+        let call = PluginHostCall::RegisterEntityComponentBehaviour { ty: component_behaviour_ty };
+        if let Err(e) = self.connection.call(call).await {
+            error!("Failed to register entity component behaviour: {e}");
+        }
+    }
+
+    async fn unregister(&self, component_behaviour_ty: &ComponentBehaviourTypeId) {
+        let call = PluginHostCall::UnregisterEntityComponentBehaviour {
+            ty: component_behaviour_ty.clone(),
+        };
+        if let Err(e) = self.connection.call(call).await {
+            error!("Failed to unregister entity component behaviour: {e}");
+        }
+        self.factories.remove(&component_behaviour_ty.behaviour_ty);
+    }
+}
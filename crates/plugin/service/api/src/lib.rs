@@ -1,19 +1,33 @@
 pub use config::*;
+pub use plugin_config_manager::*;
 pub use plugin_container_manager::*;
 pub use plugin_context_factory::*;
+pub use plugin_diagnostics::*;
+pub use plugin_host::*;
+pub use plugin_package::*;
 pub use plugin_paths::*;
+pub use plugin_registry::*;
 pub use plugin_repository_manager::*;
 pub use plugin_resolver::*;
+pub use plugin_resources::*;
 pub use plugin_system::*;
+pub use plugin_upgrade_manager::*;
 pub use resolver::*;
 pub use transition::*;
 
 pub mod config;
+pub mod plugin_config_manager;
 pub mod plugin_container_manager;
 pub mod plugin_context_factory;
+pub mod plugin_diagnostics;
+pub mod plugin_host;
+pub mod plugin_package;
 pub mod plugin_paths;
+pub mod plugin_registry;
 pub mod plugin_repository_manager;
 pub mod plugin_resolver;
+pub mod plugin_resources;
 pub mod plugin_system;
+pub mod plugin_upgrade_manager;
 pub mod resolver;
 pub mod transition;
@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use serde_json::Value;
+use springtime_di::injectable;
+use uuid::Uuid;
+
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_plugin_api::PluginConfigError;
+
+#[injectable]
+#[async_trait]
+pub trait PluginConfigManager: Send + Sync + Lifecycle {
+    /// Returns the path of the configuration file of the plugin with the given id.
+    fn get_config_path(&self, id: &Uuid) -> Option<PathBuf>;
+
+    /// Returns the JSON schema of the configuration of the plugin with the given id.
+    fn get_config_schema(&self, id: &Uuid) -> Option<Value>;
+
+    /// Returns the validated configuration of the plugin with the given id.
+    fn get_config(&self, id: &Uuid) -> Option<Value>;
+
+    /// Returns the validated configuration of the plugin with the given name or short name.
+    fn get_config_by_name(&self, name: &str) -> Option<Value>;
+
+    /// Validates the given configuration against the JSON schema of the plugin with the given id.
+    fn validate_config(&self, id: &Uuid, config: &Value) -> Result<(), PluginConfigError>;
+
+    /// Reads and validates the configuration file of the plugin with the given id.
+    ///
+    /// If the configuration file doesn't exist, the configuration is an empty object. If the
+    /// configuration is valid, it replaces the current configuration of the plugin.
+    fn load_config(&self, id: &Uuid) -> Result<Value, PluginConfigError>;
+
+    /// Removes the configuration of the plugin with the given id.
+    fn remove_config(&self, id: &Uuid);
+
+    /// Watches the folder which contains the configuration files of the plugins.
+    ///
+    /// If the configuration file of an active plugin is changed, the configuration is validated
+    /// and passed to the plugin.
+    fn watch_config(&self);
+
+    fn unwatch_config(&self);
+}
@@ -3,12 +3,18 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use dashmap::DashSet;
+use serde_json::Value;
 use springtime_di::injectable;
 use uuid::Uuid;
 
+use crate::PluginIsolation;
+use crate::PluginStateTransition;
 use crate::PluginTransitionResult;
+use crate::PluginTrustPolicy;
 use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_plugin_api::PluginCapabilities;
 use reactive_graph_plugin_api::PluginContext;
+use reactive_graph_plugin_api::PluginDeclaration;
 use reactive_graph_plugin_api::PluginDependency;
 use reactive_graph_plugin_api::PluginDeployError;
 use reactive_graph_plugin_api::PluginDisableError;
@@ -26,6 +32,14 @@ pub trait PluginContainerManager: Send + Sync + Lifecycle {
     /// library is located at the given path which have to be in the plugin installation folder.
     fn create_and_register_plugin_container(&self, stem: String, path: PathBuf) -> Option<Uuid>;
 
+    /// Creates a new plugin container for a plugin which is linked statically into the binary.
+    /// The plugin runs through the same lifecycle as a dynamically linked plugin.
+    fn create_and_register_static_plugin_container(&self, plugin_declaration: PluginDeclaration) -> Option<Uuid>;
+
+    /// Creates a new plugin container for a newer version of the plugin with the given id. The
+    /// new version is loaded side by side with the running version, so it shares the file stem.
+    fn create_and_register_upgrade_container(&self, id: &Uuid, path: PathBuf) -> Option<Uuid>;
+
     /// Returns the plugin with the given id from the list of plugin containers.
     fn remove_plugin_container(&self, id: &Uuid);
 
@@ -37,6 +51,9 @@ pub trait PluginContainerManager: Send + Sync + Lifecycle {
     /// Returns the id of the plugin with the given file stem.
     fn get_id(&self, stem: &str) -> Option<Uuid>;
 
+    /// Returns the id of the plugin with the given name or short name.
+    fn get_id_by_name(&self, name: &str) -> Option<Uuid>;
+
     /// Returns the file stem of the plugin with the given id.
     fn get_stem(&self, id: &Uuid) -> Option<String>;
 
@@ -61,6 +78,12 @@ pub trait PluginContainerManager: Send + Sync + Lifecycle {
     /// Returns the version of the plugin API the plugin with the given id was compiled with.
     fn plugin_api_version(&self, id: &Uuid) -> Option<String>;
 
+    /// Returns the JSON schema of the configuration of the plugin with the given id.
+    fn config_schema(&self, id: &Uuid) -> Option<String>;
+
+    /// Returns the capabilities declared by the plugin with the given id. Returns None, if the plugin is unrestricted.
+    fn capabilities(&self, id: &Uuid) -> Option<PluginCapabilities>;
+
     // Statistics
 
     /// Returns the count of all plugins.
@@ -82,6 +105,9 @@ pub trait PluginContainerManager: Send + Sync + Lifecycle {
     /// Returns the state of the plugin with the given id.
     fn get_plugin_state(&self, id: &Uuid) -> Option<PluginState>;
 
+    /// Returns the recent state transitions of the plugin with the given id, oldest first.
+    fn get_state_history(&self, id: &Uuid) -> Vec<PluginStateTransition>;
+
     // Queries
 
     /// Returns a list of ids of all plugins.
@@ -100,6 +126,9 @@ pub trait PluginContainerManager: Send + Sync + Lifecycle {
     /// Returns the id of the plugin by a dependency coordinate.
     fn get_plugin_by_dependency(&self, plugin_dependency: &PluginDependency) -> Option<Uuid>;
 
+    /// Returns the versions of the installed plugins with the given name.
+    fn get_versions_by_name(&self, name: &str) -> Vec<String>;
+
     // Transitions
 
     /// Deploys the dynamic linked library file from the plugin hot deploy folder into the
@@ -109,8 +138,8 @@ pub trait PluginContainerManager: Send + Sync + Lifecycle {
     /// version of the DLL will be loaded.
     fn deploy_dll(&self, id: &Uuid) -> PluginTransitionResult;
 
-    /// Loads the dynamic linked library into memory.
-    fn load_dll(&self, id: &Uuid) -> PluginTransitionResult;
+    /// Verifies the plugin package using the trust policy and loads the dynamic linked library into memory.
+    fn load_dll(&self, id: &Uuid, trust_policy: &PluginTrustPolicy) -> PluginTransitionResult;
 
     /// Loads the plugin declaration of the plugin with the given id.
     ///
@@ -163,8 +192,9 @@ pub trait PluginContainerManager: Send + Sync + Lifecycle {
 
     /// Constructs a plugin proxy object for the plugin with the given id.
     ///
-    /// The plugin proxy makes sure it can't outlive the library it came from.
-    fn construct_proxy(&self, id: &Uuid, plugin_context: Arc<dyn PluginContext + Send + Sync>) -> PluginTransitionResult;
+    /// The plugin proxy makes sure it can't outlive the library it came from. If an isolation
+    /// is given, the plugin runs in a separate plugin host process.
+    fn construct_proxy(&self, id: &Uuid, plugin_context: Arc<dyn PluginContext + Send + Sync>, isolation: Option<PluginIsolation>) -> PluginTransitionResult;
 
     /// Registers providers of the plugin with the given id.
     fn register(&self, id: &Uuid) -> PluginTransitionResult;
@@ -172,6 +202,9 @@ pub trait PluginContainerManager: Send + Sync + Lifecycle {
     /// Calls the activate method of the plugin with the given id.
     async fn activate(&self, id: &Uuid) -> PluginTransitionResult;
 
+    /// Passes the changed configuration to the plugin with the given id, if the plugin is active.
+    async fn config_changed(&self, id: &Uuid, config: Value);
+
     // Lifecycle management
 
     /// Returns true, if all plugins are stopped.
@@ -2,8 +2,11 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use springtime_di::injectable;
+use uuid::Uuid;
 
 use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_plugin_api::PluginCapabilities;
+use reactive_graph_plugin_api::PluginCapabilityViolation;
 use reactive_graph_plugin_api::PluginContext;
 
 #[injectable]
@@ -12,4 +15,23 @@ pub trait PluginContextFactory: Send + Sync + Lifecycle {
     fn construct_plugin_context(&self);
 
     fn get(&self) -> Option<Arc<dyn PluginContext + Send + Sync>>;
+
+    /// Creates a plugin context for the plugin with the given name. The namespaces registered
+    /// via this plugin context are owned by the plugin and the plugin can only read its own
+    /// configuration.
+    fn create(&self, name: &str) -> Arc<dyn PluginContext + Send + Sync>;
+
+    /// Returns a plugin context for the plugin with the given id which only permits the given
+    /// capabilities. If the plugin doesn't declare capabilities, the plugin context is returned unrestricted,
+    /// unless undeclared capabilities are denied by the plugins configuration.
+    fn restrict(
+        &self,
+        id: &Uuid,
+        name: &str,
+        plugin_context: Arc<dyn PluginContext + Send + Sync>,
+        capabilities: Option<PluginCapabilities>,
+    ) -> Arc<dyn PluginContext + Send + Sync>;
+
+    /// Returns the capability violations of the plugin with the given id.
+    fn get_capability_violations(&self, id: &Uuid) -> Vec<PluginCapabilityViolation>;
 }
@@ -0,0 +1,455 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::time::SystemTime;
+
+use uuid::Uuid;
+
+use reactive_graph_plugin_api::PLUGIN_API_VERSION;
+use reactive_graph_plugin_api::PluginResolveState;
+use reactive_graph_plugin_api::PluginState;
+use reactive_graph_plugin_api::RUSTC_VERSION;
+
+/// The maximum number of state transitions which are kept per plugin.
+pub const MAX_PLUGIN_STATE_HISTORY: usize = 64;
+
+/// A change of the state of a plugin.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PluginStateTransition {
+    /// The previous state.
+    pub from: PluginState,
+
+    /// The new state.
+    pub to: PluginState,
+
+    /// The point in time of the transition.
+    pub timestamp: SystemTime,
+}
+
+impl PluginStateTransition {
+    pub fn new(from: PluginState, to: PluginState) -> Self {
+        PluginStateTransition {
+            from,
+            to,
+            timestamp: SystemTime::now(),
+        }
+    }
+}
+
+/// A dependency of a plugin and the plugins which are available for the dependency.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PluginDependencyDiagnostics {
+    /// The name of the required plugin.
+    pub name: String,
+
+    /// The required version range.
+    pub version_range: String,
+
+    /// The state of the dependency. Uninstalled, if no plugin matches the version range.
+    pub state: PluginState,
+
+    /// The id of the plugin which matches the version range.
+    pub satisfied_by: Option<Uuid>,
+
+    /// The versions of the required plugin which are installed, regardless of the version range.
+    pub available_versions: Vec<String>,
+}
+
+impl PluginDependencyDiagnostics {
+    /// Returns true, if the dependency is active.
+    pub fn is_met(&self) -> bool {
+        self.state == PluginState::Active
+    }
+}
+
+/// The diagnostics of a single plugin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PluginDiagnostics {
+    pub id: Uuid,
+
+    /// The file stem of the plugin.
+    pub stem: String,
+
+    /// The name of the plugin, if the plugin declaration has been loaded.
+    pub name: Option<String>,
+
+    /// The version of the plugin, if the plugin declaration has been loaded.
+    pub version: Option<String>,
+
+    pub state: PluginState,
+
+    /// The version of the rust compiler the plugin was compiled with.
+    pub rustc_version: Option<String>,
+
+    /// The version of the plugin API the plugin was compiled with.
+    pub plugin_api_version: Option<String>,
+
+    pub dependencies: Vec<PluginDependencyDiagnostics>,
+
+    /// The state transitions of the plugin, oldest first.
+    pub history: Vec<PluginStateTransition>,
+
+    /// The calls of the plugin which have been denied because they exceed the capabilities of the plugin.
+    pub capability_violations: Vec<String>,
+}
+
+impl PluginDiagnostics {
+    /// Returns the dependencies which are not active.
+    pub fn unmet_dependencies(&self) -> Vec<&PluginDependencyDiagnostics> {
+        self.dependencies.iter().filter(|dependency| !dependency.is_met()).collect()
+    }
+
+    /// Returns true, if the plugin has attempted calls which exceed its capabilities.
+    pub fn has_capability_violations(&self) -> bool {
+        !self.capability_violations.is_empty()
+    }
+
+    /// Returns the name and the version of the plugin or the file stem, if the plugin declaration hasn't been loaded.
+    pub fn label(&self) -> String {
+        match (&self.name, &self.version) {
+            (Some(name), Some(version)) => format!("{name} {version}"),
+            (Some(name), None) => name.clone(),
+            _ => self.stem.clone(),
+        }
+    }
+
+    /// Returns the reason why the plugin can't be resolved, if the plugin is incompatible with the runtime or
+    /// the plugin package can't be trusted. If the plugin is being uninstalled, the reason is taken from the history.
+    pub fn incompatibility(&self) -> Option<String> {
+        match self.rejected_resolve_state()? {
+            PluginResolveState::CompilerVersionMismatch => Some(format!(
+                "The plugin was compiled with rustc {}, the runtime with rustc {RUSTC_VERSION}",
+                self.rustc_version.as_deref().unwrap_or("unknown")
+            )),
+            PluginResolveState::PluginApiVersionMismatch => Some(format!(
+                "The plugin was compiled against plugin API {}, the runtime provides plugin API {PLUGIN_API_VERSION}",
+                self.plugin_api_version.as_deref().unwrap_or("unknown")
+            )),
+            PluginResolveState::ManifestMissing => Some("The plugin package has no manifest, but a signed manifest is required".to_string()),
+            PluginResolveState::ManifestInvalid => Some("The manifest of the plugin package can't be read or parsed".to_string()),
+            PluginResolveState::SignatureMissing => Some("The manifest of the plugin package is not signed, but a signature is required".to_string()),
+            PluginResolveState::SignatureInvalid => {
+                Some("The signature of the manifest can't be verified with any of the trusted keys. The plugin package has been rejected".to_string())
+            }
+            PluginResolveState::ChecksumMismatch => Some(
+                "The checksum of the library doesn't match the checksum in the manifest. The library has been modified after signing and has been rejected"
+                    .to_string(),
+            ),
+            PluginResolveState::ManifestMismatch => Some(format!(
+                "The plugin declaration of {} doesn't match the name, the version or the dependencies in the manifest. The plugin package has been rejected",
+                self.label()
+            )),
+            _ => None,
+        }
+    }
+
+    /// Returns the resolve state of the plugin or, if the plugin is being uninstalled, the resolve state
+    /// which led to the uninstallation.
+    fn rejected_resolve_state(&self) -> Option<PluginResolveState> {
+        if let Some(resolve_state) = resolve_state(&self.state) {
+            return Some(resolve_state);
+        }
+        match self.state {
+            PluginState::Uninstalling(_) | PluginState::Uninstalled => self
+                .history
+                .iter()
+                .rev()
+                .find(|transition| matches!(transition.to, PluginState::Uninstalling(_)))
+                .and_then(|transition| resolve_state(&transition.from)),
+            _ => None,
+        }
+    }
+}
+
+/// The diagnostics of the plugin resolver: the state, the dependencies and the state
+/// transitions of every plugin.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PluginResolverDiagnostics {
+    /// The plugins, sorted by their label.
+    pub plugins: Vec<PluginDiagnostics>,
+}
+
+impl PluginResolverDiagnostics {
+    /// Returns the plugins which have attempted calls which exceed their capabilities.
+    pub fn capability_violating(&self) -> Vec<&PluginDiagnostics> {
+        self.plugins.iter().filter(|plugin| plugin.has_capability_violations()).collect()
+    }
+
+    /// Returns the plugins which are neither active nor resolved.
+    pub fn unresolved(&self) -> Vec<&PluginDiagnostics> {
+        self.plugins
+            .iter()
+            .filter(|plugin| plugin.state != PluginState::Active && plugin.state != PluginState::Resolved)
+            .collect()
+    }
+
+    /// Returns a human-readable report.
+    pub fn to_report(&self) -> String {
+        let mut report = String::new();
+        for plugin in &self.plugins {
+            let capability_violated = if plugin.has_capability_violations() { " [CAPABILITIES VIOLATED]" } else { "" };
+            let _ = writeln!(report, "{} ({}): {:?}{capability_violated}", plugin.label(), plugin.stem, plugin.state);
+            if let Some(incompatibility) = plugin.incompatibility() {
+                let _ = writeln!(report, "  {incompatibility}");
+            }
+            for violation in &plugin.capability_violations {
+                let _ = writeln!(report, "  Denied: {violation}");
+            }
+            for dependency in plugin.unmet_dependencies() {
+                let available_versions = if dependency.available_versions.is_empty() {
+                    "none".to_string()
+                } else {
+                    dependency.available_versions.join(", ")
+                };
+                let _ = writeln!(
+                    report,
+                    "  Unmet dependency {} {}: {:?} (available versions: {available_versions})",
+                    dependency.name, dependency.version_range, dependency.state
+                );
+            }
+            for transition in &plugin.history {
+                let _ = writeln!(report, "  {:?} -> {:?}", transition.from, transition.to);
+            }
+        }
+        report
+    }
+
+    /// Exports the dependency graph in the DOT format of graphviz.
+    ///
+    /// Unmet dependencies are red and dependencies on plugins which aren't installed are dashed. Dependencies
+    /// which another installed version of the required plugin doesn't match are labelled as version mismatch.
+    pub fn to_dot(&self) -> String {
+        let graph = self.dependency_graph();
+        let mut dot = String::from("digraph plugins {\n    rankdir=LR;\n    node [shape=box];\n");
+        for (node, label, missing) in &graph.nodes {
+            let style = if *missing { ", style=dashed" } else { "" };
+            let _ = writeln!(dot, "    {node} [label=\"{}\"{style}];", escape_dot(label));
+        }
+        for (from, to, version_range, met) in &graph.edges {
+            let color = if *met { "" } else { ", color=red" };
+            let _ = writeln!(dot, "    {from} -> {to} [label=\"{}\"{color}];", escape_dot(version_range));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Exports the dependency graph as mermaid flowchart.
+    ///
+    /// Unmet dependencies are dotted links. Dependencies which another installed version of the required plugin
+    /// doesn't match are labelled as version mismatch.
+    pub fn to_mermaid(&self) -> String {
+        let graph = self.dependency_graph();
+        let mut mermaid = String::from("graph LR\n");
+        for (node, label, _) in &graph.nodes {
+            let _ = writeln!(mermaid, "    {node}[\"{}\"]", escape_mermaid(label).replace('\n', "<br/>"));
+        }
+        for (from, to, version_range, met) in &graph.edges {
+            let link = if *met { "-->" } else { "-.->" };
+            let _ = writeln!(mermaid, "    {from} {link}|\"{}\"| {to}", escape_mermaid(version_range));
+        }
+        mermaid
+    }
+
+    fn dependency_graph(&self) -> DependencyGraph {
+        let mut graph = DependencyGraph::default();
+        let mut plugin_nodes = HashMap::new();
+        let mut name_nodes: HashMap<&str, String> = HashMap::new();
+        for (index, plugin) in self.plugins.iter().enumerate() {
+            let node = format!("p{index}");
+            graph.nodes.push((node.clone(), format!("{}\n{:?}", plugin.label(), plugin.state), false));
+            plugin_nodes.insert(plugin.id, node.clone());
+            if let Some(name) = &plugin.name {
+                name_nodes.entry(name.as_str()).or_insert(node);
+            }
+        }
+        let mut missing_nodes: HashMap<String, String> = HashMap::new();
+        for plugin in &self.plugins {
+            let from = plugin_nodes[&plugin.id].clone();
+            for dependency in &plugin.dependencies {
+                let (to, label) = match dependency.satisfied_by.and_then(|id| plugin_nodes.get(&id)) {
+                    Some(node) => (node.clone(), dependency.version_range.clone()),
+                    // Another version of the required plugin is installed
+                    None => match name_nodes.get(dependency.name.as_str()) {
+                        Some(node) => (node.clone(), format!("{} (version mismatch)", dependency.version_range)),
+                        None => match missing_nodes.get(&dependency.name) {
+                            Some(node) => (node.clone(), dependency.version_range.clone()),
+                            None => {
+                                let node = format!("m{}", missing_nodes.len());
+                                graph.nodes.push((node.clone(), format!("{}\nmissing", dependency.name), true));
+                                missing_nodes.insert(dependency.name.clone(), node.clone());
+                                (node, dependency.version_range.clone())
+                            }
+                        },
+                    },
+                };
+                graph.edges.push((from.clone(), to, label, dependency.is_met()));
+            }
+        }
+        graph
+    }
+}
+
+#[derive(Default)]
+struct DependencyGraph {
+    /// The node id, the label and whether the required plugin isn't installed.
+    nodes: Vec<(String, String, bool)>,
+    /// The node ids, the label and whether the dependency is met.
+    edges: Vec<(String, String, String, bool)>,
+}
+
+fn resolve_state(state: &PluginState) -> Option<PluginResolveState> {
+    match state {
+        PluginState::Resolving(resolve_state) => Some(*resolve_state),
+        PluginState::Refreshing(reactive_graph_plugin_api::PluginRefreshingState::Resolving(resolve_state)) => Some(*resolve_state),
+        _ => None,
+    }
+}
+
+fn escape_dot(label: &str) -> String {
+    label.replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn escape_mermaid(label: &str) -> String {
+    label.replace('"', "#quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use reactive_graph_plugin_api::PluginRefreshingState;
+    use reactive_graph_plugin_api::PluginResolveState;
+    use reactive_graph_plugin_api::PluginState;
+    use reactive_graph_plugin_api::PluginUninstallingState;
+
+    use crate::PluginDependencyDiagnostics;
+    use crate::PluginDiagnostics;
+    use crate::PluginResolverDiagnostics;
+    use crate::PluginStateTransition;
+
+    fn plugin(stem: &str, version: &str, state: PluginState, dependencies: Vec<PluginDependencyDiagnostics>) -> PluginDiagnostics {
+        PluginDiagnostics {
+            id: Uuid::new_v4(),
+            stem: stem.to_string(),
+            name: Some(format!("reactive-graph-plugin-{stem}")),
+            version: Some(version.to_string()),
+            state,
+            rustc_version: None,
+            plugin_api_version: None,
+            dependencies,
+            history: vec![PluginStateTransition::new(PluginState::Installed, state)],
+            capability_violations: Vec::new(),
+        }
+    }
+
+    fn diagnostics() -> PluginResolverDiagnostics {
+        let mut base = plugin("base", "0.10.0", PluginState::Active, Vec::new());
+        base.capability_violations
+            .push("The plugin is not permitted to write into namespace logical".to_string());
+        let logical = plugin(
+            "logical",
+            "0.10.0",
+            PluginState::Active,
+            vec![PluginDependencyDiagnostics {
+                name: "reactive-graph-plugin-base".to_string(),
+                version_range: ">=0.10.0".to_string(),
+                state: PluginState::Active,
+                satisfied_by: Some(base.id),
+                available_versions: vec!["0.10.0".to_string()],
+            }],
+        );
+        let arithmetic = plugin(
+            "arithmetic",
+            "0.10.0",
+            PluginState::Resolving(PluginResolveState::DependenciesNotActive),
+            vec![
+                PluginDependencyDiagnostics {
+                    name: "reactive-graph-plugin-base".to_string(),
+                    version_range: "^1.0".to_string(),
+                    state: PluginState::Uninstalled,
+                    satisfied_by: None,
+                    available_versions: vec!["0.10.0".to_string()],
+                },
+                PluginDependencyDiagnostics {
+                    name: "reactive-graph-plugin-numeric".to_string(),
+                    version_range: ">=0.10.0".to_string(),
+                    state: PluginState::Uninstalled,
+                    satisfied_by: None,
+                    available_versions: Vec::new(),
+                },
+            ],
+        );
+        PluginResolverDiagnostics {
+            plugins: vec![arithmetic, base, logical],
+        }
+    }
+
+    #[test]
+    fn report_test() {
+        let diagnostics = diagnostics();
+        assert_eq!(1, diagnostics.unresolved().len());
+        assert_eq!(1, diagnostics.capability_violating().len());
+        let report = diagnostics.to_report();
+        assert!(report.contains("reactive-graph-plugin-arithmetic 0.10.0 (arithmetic): Resolving(DependenciesNotActive)"));
+        assert!(report.contains("Unmet dependency reactive-graph-plugin-base ^1.0: Uninstalled (available versions: 0.10.0)"));
+        assert!(report.contains("Unmet dependency reactive-graph-plugin-numeric >=0.10.0: Uninstalled (available versions: none)"));
+        assert!(report.contains("Installed -> Active"));
+        assert!(report.contains("reactive-graph-plugin-base 0.10.0 (base): Active [CAPABILITIES VIOLATED]"));
+        assert!(report.contains("  Denied: The plugin is not permitted to write into namespace logical"));
+    }
+
+    #[test]
+    fn dot_test() {
+        let dot = diagnostics().to_dot();
+        assert!(dot.starts_with("digraph plugins {"));
+        assert!(dot.contains("p1 [label=\"reactive-graph-plugin-base 0.10.0\\nActive\"];"));
+        assert!(dot.contains("m0 [label=\"reactive-graph-plugin-numeric\\nmissing\", style=dashed];"));
+        assert!(!dot.contains("reactive-graph-plugin-base\\nmissing"));
+        assert!(dot.contains("p2 -> p1 [label=\">=0.10.0\"];"));
+        assert!(dot.contains("p0 -> p1 [label=\"^1.0 (version mismatch)\", color=red];"));
+        assert!(dot.contains("p0 -> m0 [label=\">=0.10.0\", color=red];"));
+    }
+
+    #[test]
+    fn mermaid_test() {
+        let mermaid = diagnostics().to_mermaid();
+        assert!(mermaid.starts_with("graph LR\n"));
+        assert!(mermaid.contains("p1[\"reactive-graph-plugin-base 0.10.0<br/>Active\"]"));
+        assert!(mermaid.contains("p2 -->|\">=0.10.0\"| p1"));
+        assert!(mermaid.contains("p0 -.->|\"^1.0 (version mismatch)\"| p1"));
+        assert!(mermaid.contains("p0 -.->|\">=0.10.0\"| m0"));
+    }
+
+    #[test]
+    fn incompatibility_test() {
+        let mut plugin = plugin("base", "0.10.0", PluginState::Resolving(PluginResolveState::PluginApiVersionMismatch), Vec::new());
+        plugin.plugin_api_version = Some("0.9.0".to_string());
+        assert!(plugin.incompatibility().unwrap().contains("plugin API 0.9.0"));
+        plugin.state = PluginState::Resolving(PluginResolveState::SignatureInvalid);
+        assert!(plugin.incompatibility().unwrap().contains("can't be verified with any of the trusted keys"));
+        plugin.state = PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::ChecksumMismatch));
+        assert!(plugin.incompatibility().unwrap().contains("doesn't match the checksum in the manifest"));
+        plugin.state = PluginState::Resolving(PluginResolveState::ManifestMismatch);
+        assert!(
+            plugin
+                .incompatibility()
+                .unwrap()
+                .contains("reactive-graph-plugin-base 0.10.0 doesn't match the name, the version or the dependencies in the manifest")
+        );
+        // The reason is kept while the rejected plugin is being uninstalled
+        plugin.history.push(PluginStateTransition::new(
+            PluginState::Resolving(PluginResolveState::ManifestMismatch),
+            PluginState::Uninstalling(PluginUninstallingState::UnloadDll),
+        ));
+        plugin.state = PluginState::Uninstalling(PluginUninstallingState::UnloadDll);
+        assert!(
+            plugin
+                .incompatibility()
+                .unwrap()
+                .contains("doesn't match the name, the version or the dependencies")
+        );
+        plugin.state = PluginState::Resolving(PluginResolveState::DependenciesNotActive);
+        assert_eq!(None, plugin.incompatibility());
+        plugin.state = PluginState::Active;
+        assert_eq!(None, plugin.incompatibility());
+    }
+}
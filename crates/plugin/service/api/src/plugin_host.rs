@@ -0,0 +1,554 @@
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use dashmap::DashMap;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use reactive_graph_behaviour_model_api::BehaviourTypeId;
+use reactive_graph_behaviour_model_api::ComponentBehaviourTypeId;
+use reactive_graph_behaviour_model_api::EntityBehaviourTypeId;
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::Components;
+use reactive_graph_graph::EntityInstance;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::EntityTypes;
+use reactive_graph_graph::FlowTypes;
+use reactive_graph_graph::RelationTypes;
+use reactive_graph_reactive_service_api::ReactiveEntityComponentAddError;
+use reactive_graph_reactive_service_api::ReactiveEntityCreationError;
+use reactive_graph_reactive_service_api::ReactiveEntityRegistrationError;
+
+/// The error of requests and calls which couldn't be delivered.
+pub const PLUGIN_HOST_DISCONNECTED: &str = "The plugin host has been disconnected";
+
+/// The environment variable which passes the token to the plugin host. The plugin host
+/// authenticates the connection to the runtime with the token.
+pub const PLUGIN_HOST_TOKEN_ENV: &str = "REACTIVE_GRAPH_PLUGIN_HOST_TOKEN";
+
+/// Runs a plugin out of process in a plugin host.
+#[derive(Debug, Clone)]
+pub struct PluginIsolation {
+    /// The path of the plugin host executable.
+    pub plugin_host: PathBuf,
+
+    /// The maximum number of restarts of a crashed plugin host.
+    pub max_restarts: u32,
+}
+
+impl PluginIsolation {
+    pub fn new(plugin_host: PathBuf, max_restarts: u32) -> Self {
+        PluginIsolation { plugin_host, max_restarts }
+    }
+}
+
+/// A message exchanged between the runtime and a plugin host.
+///
+/// The messages are serialized as JSON, one message per line. The messages are exchanged over
+/// a loopback connection which is dedicated to the protocol, so that the output of the plugin
+/// can't corrupt the protocol. The standard output of the plugin host is only logged.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PluginHostMessage {
+    /// The plugin host has loaded the plugin.
+    Ready { name: String, version: String },
+
+    /// A request of the runtime to the plugin.
+    Request { id: u64, request: PluginHostRequest },
+
+    /// A call of the plugin to the runtime.
+    Call { id: u64, call: PluginHostCall },
+
+    /// The response to a request or to a call.
+    Response { id: u64, result: Result<Value, String> },
+
+    /// The value of a property of an entity instance has been changed.
+    PropertyChanged { id: Uuid, property: String, value: Value },
+
+    /// The plugin host can't run the plugin and exits. Restarting the plugin host won't help.
+    Failed { error: String },
+}
+
+impl PluginHostMessage {
+    /// Parses a line of the protocol.
+    pub fn parse(line: &str) -> Option<PluginHostMessage> {
+        serde_json::from_str(line.trim()).ok()
+    }
+
+    /// Serializes the message as a line of the protocol.
+    pub fn to_line(&self) -> String {
+        let mut line = serde_json::to_string(self).unwrap_or_default();
+        line.push('\n');
+        line
+    }
+}
+
+/// The requests of the runtime to an out-of-process plugin.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "request", rename_all = "snake_case")]
+pub enum PluginHostRequest {
+    /// Activates the plugin.
+    Activate,
+
+    /// Deactivates the plugin.
+    Deactivate,
+
+    /// Passes the changed configuration to the plugin.
+    ConfigChanged { config: Value },
+
+    /// Creates and connects the behaviour of the given type on the mirror of the entity instance.
+    ConnectBehaviour {
+        behaviour_ty: BehaviourTypeId,
+        entity_instance: EntityInstance,
+    },
+
+    /// Disconnects and removes the behaviour of the given type from the mirror of the entity instance.
+    DisconnectBehaviour { behaviour_ty: BehaviourTypeId, id: Uuid },
+}
+
+/// The calls of an out-of-process plugin to the managers of the runtime.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "call", rename_all = "snake_case")]
+pub enum PluginHostCall {
+    // Type Provider Registries
+    RegisterTypes {
+        provider_id: String,
+        types: PluginHostTypes,
+    },
+    UnregisterTypes {
+        provider_id: String,
+        kind: PluginHostTypeKind,
+    },
+
+    // Behaviour Registries
+    RegisterEntityBehaviour {
+        ty: EntityBehaviourTypeId,
+    },
+    UnregisterEntityBehaviour {
+        ty: EntityBehaviourTypeId,
+    },
+    RegisterEntityComponentBehaviour {
+        ty: ComponentBehaviourTypeId,
+    },
+    UnregisterEntityComponentBehaviour {
+        ty: ComponentBehaviourTypeId,
+    },
+
+    // Entity Instance Manager
+    HasEntityInstance {
+        id: Uuid,
+    },
+    GetEntityInstance {
+        id: Uuid,
+    },
+    GetEntityInstanceByLabel {
+        label: String,
+    },
+    GetEntityInstanceByLabelWithParams {
+        label: String,
+    },
+    GetEntityInstances,
+    GetEntityInstancesByType {
+        ty: EntityTypeId,
+    },
+    GetEntityInstanceIds,
+    CountEntityInstances,
+    CountEntityInstancesByType {
+        ty: EntityTypeId,
+    },
+    CountEntityInstancesByComponent {
+        component: ComponentTypeId,
+    },
+    CountEntityInstancesByBehaviour {
+        behaviour_ty: BehaviourTypeId,
+    },
+    CreateEntityInstance {
+        entity_instance: EntityInstance,
+    },
+    AddComponent {
+        id: Uuid,
+        component: ComponentTypeId,
+    },
+    RemoveComponent {
+        id: Uuid,
+        component: ComponentTypeId,
+    },
+    DeleteEntityInstance {
+        id: Uuid,
+    },
+
+    // Config Manager
+    GetInstanceConfig,
+    #[serde(rename = "get_graphql_server_config")]
+    GetGraphQLServerConfig,
+    GetPluginsConfig,
+    GetRemotesConfig,
+    GetPluginConfig {
+        plugin_name: String,
+    },
+}
+
+/// The kinds of types which can be provided by an out-of-process plugin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginHostTypeKind {
+    Components,
+    EntityTypes,
+    RelationTypes,
+    FlowTypes,
+}
+
+/// The types provided by a type provider of an out-of-process plugin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginHostTypes {
+    Components(Components),
+    EntityTypes(EntityTypes),
+    RelationTypes(RelationTypes),
+    FlowTypes(FlowTypes),
+}
+
+impl PluginHostTypes {
+    pub fn kind(&self) -> PluginHostTypeKind {
+        match self {
+            PluginHostTypes::Components(_) => PluginHostTypeKind::Components,
+            PluginHostTypes::EntityTypes(_) => PluginHostTypeKind::EntityTypes,
+            PluginHostTypes::RelationTypes(_) => PluginHostTypeKind::RelationTypes,
+            PluginHostTypes::FlowTypes(_) => PluginHostTypeKind::FlowTypes,
+        }
+    }
+}
+
+impl PartialEq for PluginHostTypes {
+    fn eq(&self, other: &Self) -> bool {
+        serde_json::to_value(self).ok() == serde_json::to_value(other).ok()
+    }
+}
+
+/// The errors of the entity instance manager of the runtime.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginHostEntityInstanceError {
+    UuidTaken(Uuid),
+    UnknownEntityType(EntityTypeId),
+    CreatedInstanceMissing,
+    MissingComponent(ComponentTypeId),
+    MissingInstance(Uuid),
+    PermissionDenied(String),
+}
+
+impl From<ReactiveEntityCreationError> for PluginHostEntityInstanceError {
+    fn from(error: ReactiveEntityCreationError) -> Self {
+        match error {
+            ReactiveEntityCreationError::UuidTaken(id) => PluginHostEntityInstanceError::UuidTaken(id),
+            ReactiveEntityCreationError::MissingInstance => PluginHostEntityInstanceError::CreatedInstanceMissing,
+            ReactiveEntityCreationError::ReactiveEntityRegistrationError(ReactiveEntityRegistrationError::UuidTaken(id)) => {
+                PluginHostEntityInstanceError::UuidTaken(id)
+            }
+            ReactiveEntityCreationError::ReactiveEntityRegistrationError(ReactiveEntityRegistrationError::UnknownEntityType(ty)) => {
+                PluginHostEntityInstanceError::UnknownEntityType(ty)
+            }
+            ReactiveEntityCreationError::ReactiveEntityRegistrationError(ReactiveEntityRegistrationError::PermissionDenied(reason)) => {
+                PluginHostEntityInstanceError::PermissionDenied(reason)
+            }
+        }
+    }
+}
+
+impl From<ReactiveEntityComponentAddError> for PluginHostEntityInstanceError {
+    fn from(error: ReactiveEntityComponentAddError) -> Self {
+        match error {
+            ReactiveEntityComponentAddError::MissingComponent(ty) => PluginHostEntityInstanceError::MissingComponent(ty),
+            ReactiveEntityComponentAddError::MissingInstance(id) => PluginHostEntityInstanceError::MissingInstance(id),
+            ReactiveEntityComponentAddError::PermissionDenied(reason) => PluginHostEntityInstanceError::PermissionDenied(reason),
+        }
+    }
+}
+
+impl From<PluginHostEntityInstanceError> for ReactiveEntityCreationError {
+    fn from(error: PluginHostEntityInstanceError) -> Self {
+        match error {
+            PluginHostEntityInstanceError::UuidTaken(id) => ReactiveEntityCreationError::UuidTaken(id),
+            PluginHostEntityInstanceError::UnknownEntityType(ty) => {
+                ReactiveEntityCreationError::ReactiveEntityRegistrationError(ReactiveEntityRegistrationError::UnknownEntityType(ty))
+            }
+            PluginHostEntityInstanceError::PermissionDenied(reason) => {
+                ReactiveEntityCreationError::ReactiveEntityRegistrationError(ReactiveEntityRegistrationError::PermissionDenied(reason))
+            }
+            PluginHostEntityInstanceError::CreatedInstanceMissing
+            | PluginHostEntityInstanceError::MissingComponent(_)
+            | PluginHostEntityInstanceError::MissingInstance(_) => ReactiveEntityCreationError::MissingInstance,
+        }
+    }
+}
+
+/// Handles the response to a request or to a call.
+pub type PluginHostResponseHandler = Box<dyn FnOnce(Result<Value, String>) + Send + Sync>;
+
+type PluginHostSender = Box<dyn Fn(PluginHostMessage) -> bool + Send + Sync>;
+
+/// The sending side of the connection between the runtime and a plugin host.
+///
+/// Keeps track of the requests and calls which are waiting for a response.
+pub struct PluginHostChannel {
+    sender: RwLock<Option<PluginHostSender>>,
+    pending: DashMap<u64, PluginHostResponseHandler>,
+    next_id: AtomicU64,
+}
+
+impl PluginHostChannel {
+    /// Creates a channel which sends the messages using the given function. The function
+    /// returns false, if the message couldn't be sent.
+    pub fn new<F>(sender: F) -> Self
+    where
+        F: Fn(PluginHostMessage) -> bool + Send + Sync + 'static,
+    {
+        PluginHostChannel {
+            sender: RwLock::new(Some(Box::new(sender))),
+            pending: DashMap::new(),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Sends the given message. Returns false, if the channel has been closed.
+    pub fn send(&self, message: PluginHostMessage) -> bool {
+        let reader = self.sender.read().unwrap();
+        reader.as_ref().is_some_and(|sender| sender(message))
+    }
+
+    /// Sends a request to the plugin. The handler is called with the response.
+    pub fn request(&self, request: PluginHostRequest, handler: PluginHostResponseHandler) {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        self.send_pending(id, PluginHostMessage::Request { id, request }, handler);
+    }
+
+    /// Sends a call to the runtime. The handler is called with the response.
+    pub fn call(&self, call: PluginHostCall, handler: PluginHostResponseHandler) {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        self.send_pending(id, PluginHostMessage::Call { id, call }, handler);
+    }
+
+    fn send_pending(&self, id: u64, message: PluginHostMessage, handler: PluginHostResponseHandler) {
+        self.pending.insert(id, handler);
+        if !self.send(message) {
+            self.respond(id, Err(PLUGIN_HOST_DISCONNECTED.to_string()));
+        }
+    }
+
+    /// Passes the received response to the handler of the request or call with the given id.
+    pub fn respond(&self, id: u64, result: Result<Value, String>) -> bool {
+        let Some((_, handler)) = self.pending.remove(&id) else {
+            return false;
+        };
+        handler(result);
+        true
+    }
+
+    /// Closes the channel. The pending requests and calls are failed.
+    pub fn close(&self) {
+        {
+            let mut writer = self.sender.write().unwrap();
+            *writer = None;
+        }
+        let ids: Vec<u64> = self.pending.iter().map(|pending| *pending.key()).collect();
+        for id in ids {
+            self.respond(id, Err(PLUGIN_HOST_DISCONNECTED.to_string()));
+        }
+    }
+
+    /// Returns true, if the channel has been closed.
+    pub fn is_closed(&self) -> bool {
+        self.sender.read().unwrap().is_none()
+    }
+}
+
+/// Keeps track of the property values which are known to the other side of the connection.
+///
+/// Property values are synchronized in both directions. Setting a value which has been received
+/// from the other side triggers the observer of the property, which must not send the value back.
+#[derive(Debug, Default)]
+pub struct PluginHostPropertyCache(DashMap<(Uuid, String), Value>);
+
+impl PluginHostPropertyCache {
+    pub fn new() -> Self {
+        PluginHostPropertyCache(DashMap::new())
+    }
+
+    /// Remembers a value which has been received from the other side.
+    pub fn received(&self, id: Uuid, property: &str, value: Value) {
+        self.0.insert((id, property.to_string()), value);
+    }
+
+    /// Returns true, if the value has to be sent to the other side.
+    pub fn should_send(&self, id: Uuid, property: &str, value: &Value) -> bool {
+        let key = (id, property.to_string());
+        if self.0.get(&key).is_some_and(|known| *known == *value) {
+            return false;
+        }
+        self.0.insert(key, value.clone());
+        true
+    }
+
+    /// Forgets the values of the entity instance with the given id.
+    pub fn remove(&self, id: Uuid) {
+        self.0.retain(|(instance_id, _), _| *instance_id != id);
+    }
+
+    /// Forgets all values.
+    pub fn clear(&self) {
+        self.0.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::sync::mpsc;
+
+    use serde_json::Value;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use crate::PLUGIN_HOST_DISCONNECTED;
+    use crate::PluginHostCall;
+    use crate::PluginHostChannel;
+    use crate::PluginHostMessage;
+    use crate::PluginHostPropertyCache;
+    use crate::PluginHostRequest;
+
+    #[test]
+    fn message_round_trip_test() {
+        let messages = vec![
+            PluginHostMessage::Ready {
+                name: "reactive-graph-plugin-test".to_string(),
+                version: "0.10.0".to_string(),
+            },
+            PluginHostMessage::Request {
+                id: 1,
+                request: PluginHostRequest::Activate,
+            },
+            PluginHostMessage::Request {
+                id: 2,
+                request: PluginHostRequest::ConfigChanged {
+                    config: json!({ "interval": 10 }),
+                },
+            },
+            PluginHostMessage::Call {
+                id: 3,
+                call: PluginHostCall::GetPluginConfig {
+                    plugin_name: "test".to_string(),
+                },
+            },
+            PluginHostMessage::Response {
+                id: 3,
+                result: Ok(json!(true)),
+            },
+            PluginHostMessage::Response {
+                id: 4,
+                result: Err("failed".to_string()),
+            },
+            PluginHostMessage::PropertyChanged {
+                id: Uuid::new_v4(),
+                property: "value".to_string(),
+                value: json!(42),
+            },
+            PluginHostMessage::Failed {
+                error: "The command manager is not available".to_string(),
+            },
+        ];
+        for message in messages {
+            let line = message.to_line();
+            assert!(line.ends_with('\n'));
+            assert_eq!(1, line.lines().count());
+            assert_eq!(Some(message), PluginHostMessage::parse(&line));
+        }
+    }
+
+    #[test]
+    fn ignore_output_of_plugin_test() {
+        assert_eq!(None, PluginHostMessage::parse("Failed to configure logger"));
+        assert_eq!(None, PluginHostMessage::parse(r#"{"type":"unknown"}"#));
+    }
+
+    #[test]
+    fn property_cache_suppresses_echo_test() {
+        let cache = PluginHostPropertyCache::new();
+        let id = Uuid::new_v4();
+        // A value which has been received must not be sent back
+        cache.received(id, "value", json!(1));
+        assert!(!cache.should_send(id, "value", &json!(1)));
+        // A changed value has to be sent, but only once
+        assert!(cache.should_send(id, "value", &json!(2)));
+        assert!(!cache.should_send(id, "value", &json!(2)));
+        // Changing the value back has to be sent again
+        assert!(cache.should_send(id, "value", &json!(1)));
+        // Other properties and other instances are independent
+        assert!(cache.should_send(id, "other", &json!(1)));
+        assert!(cache.should_send(Uuid::new_v4(), "value", &json!(1)));
+        cache.remove(id);
+        assert!(cache.should_send(id, "value", &json!(1)));
+    }
+
+    #[test]
+    fn channel_request_response_test() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let sent_messages = sent.clone();
+        let channel = PluginHostChannel::new(move |message| {
+            sent_messages.lock().unwrap().push(message);
+            true
+        });
+        let (sender, receiver) = mpsc::channel::<Result<Value, String>>();
+        channel.request(
+            PluginHostRequest::Activate,
+            Box::new(move |result| {
+                let _ = sender.send(result);
+            }),
+        );
+        let id = match sent.lock().unwrap().first() {
+            Some(PluginHostMessage::Request {
+                id,
+                request: PluginHostRequest::Activate,
+            }) => *id,
+            message => panic!("Unexpected message {message:?}"),
+        };
+        assert!(receiver.try_recv().is_err());
+        assert!(channel.respond(id, Ok(Value::Null)));
+        assert_eq!(Ok(Value::Null), receiver.try_recv().unwrap());
+        // The response has already been handled
+        assert!(!channel.respond(id, Ok(Value::Null)));
+    }
+
+    #[test]
+    fn channel_close_fails_pending_test() {
+        let channel = PluginHostChannel::new(|_| true);
+        let (sender, receiver) = mpsc::channel::<Result<Value, String>>();
+        let pending_sender = sender.clone();
+        channel.call(
+            PluginHostCall::GetEntityInstanceIds,
+            Box::new(move |result| {
+                let _ = pending_sender.send(result);
+            }),
+        );
+        assert!(!channel.is_closed());
+        channel.close();
+        assert!(channel.is_closed());
+        assert_eq!(Err(PLUGIN_HOST_DISCONNECTED.to_string()), receiver.try_recv().unwrap());
+        // Calls on a closed channel are failed immediately
+        channel.call(
+            PluginHostCall::GetEntityInstanceIds,
+            Box::new(move |result| {
+                let _ = sender.send(result);
+            }),
+        );
+        assert_eq!(Err(PLUGIN_HOST_DISCONNECTED.to_string()), receiver.try_recv().unwrap());
+        assert!(!channel.send(PluginHostMessage::Request {
+            id: 1,
+            request: PluginHostRequest::Deactivate
+        }));
+    }
+}
@@ -0,0 +1,420 @@
+use std::collections::BTreeMap;
+use std::env::consts::DLL_EXTENSION;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use ed25519_dalek::Signature;
+use ed25519_dalek::Signer;
+use ed25519_dalek::SigningKey;
+use ed25519_dalek::Verifier;
+use ed25519_dalek::VerifyingKey;
+use log::error;
+use log::trace;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+use reactive_graph_plugin_api::PluginDependency;
+use reactive_graph_plugin_api::PluginPackageError;
+
+/// The extension of the manifest which is located next to the dynamic link library.
+pub const PLUGIN_MANIFEST_EXTENSION: &str = "manifest.toml";
+
+/// The extension of the signature of the manifest.
+pub const PLUGIN_SIGNATURE_EXTENSION: &str = "manifest.sig";
+
+/// The name of the checksum of the dynamic link library in the manifest.
+pub const CHECKSUM_SHA256: &str = "sha256";
+
+/// The manifest of a plugin package.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PluginManifest {
+    /// The name of the plugin.
+    pub name: String,
+
+    /// The version of the plugin.
+    pub version: String,
+
+    /// The dependencies of the plugin.
+    #[serde(default)]
+    pub dependencies: Vec<PluginManifestDependency>,
+
+    /// The checksums of the dynamic link library by algorithm.
+    #[serde(default)]
+    pub checksums: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PluginManifestDependency {
+    /// The name of the dependency.
+    pub name: String,
+
+    /// The version requirement of the dependency.
+    pub version: String,
+}
+
+impl PluginManifest {
+    /// Creates a manifest containing the sha256 checksum of the given dynamic link library.
+    pub fn new(name: String, version: String, dependencies: Vec<PluginManifestDependency>, library: &[u8]) -> Self {
+        let mut checksums = BTreeMap::new();
+        checksums.insert(CHECKSUM_SHA256.to_string(), sha256_hex(library));
+        PluginManifest {
+            name,
+            version,
+            dependencies,
+            checksums,
+        }
+    }
+
+    /// Returns true, if the manifest declares the given name, version and dependencies.
+    pub fn matches(&self, name: &str, version: &str, dependencies: &[PluginDependency]) -> bool {
+        let mut expected: Vec<(&str, &str)> = self
+            .dependencies
+            .iter()
+            .map(|dependency| (dependency.name.as_str(), dependency.version.as_str()))
+            .collect();
+        let mut actual: Vec<(&str, &str)> = dependencies.iter().map(|dependency| (dependency.name, dependency.version)).collect();
+        expected.sort_unstable();
+        actual.sort_unstable();
+        self.name == name && self.version == version && expected == actual
+    }
+
+    /// Verifies that the checksum of the dynamic link library matches the manifest.
+    pub fn verify_checksum(&self, path: &Path, library: &[u8]) -> Result<(), PluginPackageError> {
+        let Some(expected) = self.checksums.get(CHECKSUM_SHA256) else {
+            return Err(PluginPackageError::ManifestInvalid(
+                get_manifest_path(path).display().to_string(),
+                format!("Missing checksum {CHECKSUM_SHA256}"),
+            ));
+        };
+        let actual = sha256_hex(library);
+        if !expected.eq_ignore_ascii_case(&actual) {
+            return Err(PluginPackageError::ChecksumMismatch(path.display().to_string(), expected.clone(), actual));
+        }
+        Ok(())
+    }
+}
+
+impl Display for PluginManifest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let dependencies: Vec<String> = self
+            .dependencies
+            .iter()
+            .map(|dependency| format!("{} {}", dependency.name, dependency.version))
+            .collect();
+        write!(f, "{} {} [{}]", self.name, self.version, dependencies.join(", "))
+    }
+}
+
+/// Which plugin packages are trusted.
+#[derive(Debug, Clone, Default)]
+pub struct PluginTrustPolicy {
+    /// The base64 encoded ed25519 public keys which are trusted to sign plugin packages.
+    pub trusted_keys: Vec<String>,
+
+    /// If true, plugin packages without a signed manifest are rejected.
+    pub require_signature: bool,
+}
+
+impl PluginTrustPolicy {
+    pub fn new(trusted_keys: Vec<String>, require_signature: bool) -> Self {
+        PluginTrustPolicy {
+            trusted_keys,
+            require_signature,
+        }
+    }
+}
+
+/// Verifies the plugin package of the dynamic link library at the given path.
+///
+/// Plugins without a manifest are accepted unless a signature is required. If the package
+/// contains a manifest, the checksum of the given content of the dynamic link library is
+/// verified. If the manifest is signed, the signature has to be made by one of the trusted keys.
+pub fn verify_plugin_package(path: &Path, library: &[u8], trust_policy: &PluginTrustPolicy) -> Result<Option<PluginManifest>, PluginPackageError> {
+    let manifest_path = get_manifest_path(path);
+    let signature_path = get_signature_path(path);
+    if !manifest_path.exists() {
+        if trust_policy.require_signature {
+            return Err(PluginPackageError::ManifestMissing(manifest_path.display().to_string()));
+        }
+        return Ok(None);
+    }
+    let manifest_string = read_to_string(&manifest_path)?;
+    let manifest: PluginManifest =
+        toml::from_str(&manifest_string).map_err(|e| PluginPackageError::ManifestInvalid(manifest_path.display().to_string(), e.to_string()))?;
+    if signature_path.exists() {
+        let signature = read_to_string(&signature_path)?;
+        verify_signature(&manifest_path, manifest_string.as_bytes(), &signature, &trust_policy.trusted_keys)?;
+        trace!("The manifest {} is signed by a trusted key", manifest_path.display());
+    } else if trust_policy.require_signature {
+        return Err(PluginPackageError::SignatureMissing(manifest_path.display().to_string()));
+    }
+    manifest.verify_checksum(path, library)?;
+    Ok(Some(manifest))
+}
+
+/// Verifies that the base64 encoded signature of the manifest was made by one of the trusted keys.
+pub fn verify_signature(manifest_path: &Path, manifest: &[u8], signature: &str, trusted_keys: &[String]) -> Result<(), PluginPackageError> {
+    let signature_invalid = |reason: &str| PluginPackageError::SignatureInvalid(manifest_path.display().to_string(), reason.to_string());
+    let signature = STANDARD.decode(signature.trim()).map_err(|e| signature_invalid(&e.to_string()))?;
+    let signature = Signature::from_slice(&signature).map_err(|e| signature_invalid(&e.to_string()))?;
+    if trusted_keys.is_empty() {
+        return Err(signature_invalid("No trusted keys are configured"));
+    }
+    let verified = trusted_keys
+        .iter()
+        .filter_map(|trusted_key| match decode_verifying_key(trusted_key) {
+            Some(verifying_key) => Some(verifying_key),
+            None => {
+                error!("Ignoring invalid trusted key {trusted_key}");
+                None
+            }
+        })
+        .any(|verifying_key| verifying_key.verify(manifest, &signature).is_ok());
+    if !verified {
+        return Err(signature_invalid("The manifest is not signed by any of the trusted keys"));
+    }
+    Ok(())
+}
+
+/// Signs the manifest with the base64 encoded ed25519 secret key and returns the base64 encoded signature.
+pub fn sign_manifest(manifest: &[u8], signing_key: &str) -> Option<String> {
+    let signing_key: [u8; 32] = STANDARD.decode(signing_key.trim()).ok()?.try_into().ok()?;
+    let signature = SigningKey::from_bytes(&signing_key).sign(manifest);
+    Some(STANDARD.encode(signature.to_bytes()))
+}
+
+fn decode_verifying_key(key: &str) -> Option<VerifyingKey> {
+    let key: [u8; 32] = STANDARD.decode(key.trim()).ok()?.try_into().ok()?;
+    VerifyingKey::from_bytes(&key).ok()
+}
+
+fn read_to_string(path: &Path) -> Result<String, PluginPackageError> {
+    fs::read_to_string(path).map_err(|e| PluginPackageError::ReadError(path.display().to_string(), e.to_string()))
+}
+
+/// Returns the hex encoded sha256 digest.
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Returns the path of the manifest of the dynamic link library.
+pub fn get_manifest_path(path: &Path) -> PathBuf {
+    path.with_extension(PLUGIN_MANIFEST_EXTENSION)
+}
+
+/// Returns the path of the signature of the manifest of the dynamic link library.
+pub fn get_signature_path(path: &Path) -> PathBuf {
+    path.with_extension(PLUGIN_SIGNATURE_EXTENSION)
+}
+
+/// Returns the path of the dynamic link library of a manifest or a signature.
+pub fn get_package_dll_path(path: &Path) -> Option<PathBuf> {
+    let file_name = path.file_name()?.to_str()?;
+    let prefix = file_name
+        .strip_suffix(PLUGIN_MANIFEST_EXTENSION)
+        .or_else(|| file_name.strip_suffix(PLUGIN_SIGNATURE_EXTENSION))?
+        .strip_suffix('.')?;
+    Some(path.with_file_name(format!("{prefix}.{DLL_EXTENSION}")))
+}
+
+/// Returns true, if the package files of the dynamic link library are present.
+///
+/// If a signature is required, the manifest and the signature must be present. A signature
+/// without a manifest is never complete.
+pub fn is_package_complete(path: &Path, require_signature: bool) -> bool {
+    let has_manifest = get_manifest_path(path).exists();
+    let has_signature = get_signature_path(path).exists();
+    if require_signature {
+        has_manifest && has_signature
+    } else {
+        has_manifest || !has_signature
+    }
+}
+
+/// Moves the manifest and the signature along with the dynamic link library.
+pub fn move_package_files(from: &Path, to: &Path) {
+    for (from, to) in [
+        (get_manifest_path(from), get_manifest_path(to)),
+        (get_signature_path(from), get_signature_path(to)),
+    ] {
+        if !from.exists() {
+            continue;
+        }
+        match fs::copy(&from, &to).and_then(|_| fs::remove_file(&from)) {
+            Ok(_) => trace!("Moved {} to {}", from.display(), to.display()),
+            Err(e) => error!("Failed to move {} to {}: {:?}", from.display(), to.display(), e),
+        }
+    }
+}
+
+/// Removes the manifest and the signature of the dynamic link library.
+pub fn remove_package_files(path: &Path) {
+    for package_file in [get_manifest_path(path), get_signature_path(path)] {
+        if package_file.exists() && fs::remove_file(&package_file).is_ok() {
+            trace!("Removed {}", package_file.display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use ed25519_dalek::SigningKey;
+    use uuid::Uuid;
+
+    use reactive_graph_plugin_api::PluginDependency;
+    use reactive_graph_plugin_api::PluginPackageError;
+
+    use crate::PluginManifest;
+    use crate::PluginManifestDependency;
+    use crate::PluginTrustPolicy;
+    use crate::get_manifest_path;
+    use crate::get_package_dll_path;
+    use crate::get_signature_path;
+    use crate::is_package_complete;
+    use crate::remove_package_files;
+    use crate::sign_manifest;
+    use crate::verify_plugin_package;
+
+    const LIBRARY: &[u8] = b"library";
+
+    /// Returns the base64 encoded secret key and public key.
+    fn keys(seed: u8) -> (String, String) {
+        let signing_key = SigningKey::from_bytes(&[seed; 32]);
+        (STANDARD.encode(signing_key.to_bytes()), STANDARD.encode(signing_key.verifying_key().to_bytes()))
+    }
+
+    fn manifest() -> PluginManifest {
+        PluginManifest::new(
+            "reactive-graph-plugin-test".to_string(),
+            "0.10.0".to_string(),
+            vec![PluginManifestDependency {
+                name: "reactive-graph-plugin-base".to_string(),
+                version: ">=0.10.0".to_string(),
+            }],
+            LIBRARY,
+        )
+    }
+
+    /// Writes the manifest and optionally the signature of the manifest next to a library
+    /// which doesn't exist. Returns the path of the library.
+    fn write_package(manifest: &str, signing_key: Option<&str>) -> PathBuf {
+        let path = env::temp_dir().join(format!("libreactive_graph_plugin_{}.so", Uuid::new_v4().simple()));
+        fs::write(get_manifest_path(&path), manifest).unwrap();
+        if let Some(signing_key) = signing_key {
+            fs::write(get_signature_path(&path), sign_manifest(manifest.as_bytes(), signing_key).unwrap()).unwrap();
+        }
+        path
+    }
+
+    #[test]
+    fn verify_signed_plugin_package_test() {
+        let (secret_key, public_key) = keys(1);
+        let manifest = manifest();
+        let path = write_package(&toml::to_string(&manifest).unwrap(), Some(&secret_key));
+        let trust_policy = PluginTrustPolicy::new(vec![public_key], true);
+        assert_eq!(Some(manifest), verify_plugin_package(&path, LIBRARY, &trust_policy).unwrap());
+        remove_package_files(&path);
+    }
+
+    #[test]
+    fn verify_unsigned_plugin_package_test() {
+        let (_, public_key) = keys(1);
+        let path = write_package(&toml::to_string(&manifest()).unwrap(), None);
+        let trust_policy = PluginTrustPolicy::new(vec![public_key.clone()], true);
+        assert!(matches!(verify_plugin_package(&path, LIBRARY, &trust_policy), Err(PluginPackageError::SignatureMissing(_))));
+        // Unsigned plugin packages are accepted unless a signature is required
+        let trust_policy = PluginTrustPolicy::new(vec![public_key.clone()], false);
+        assert!(verify_plugin_package(&path, LIBRARY, &trust_policy).unwrap().is_some());
+        remove_package_files(&path);
+
+        // Plugins without a manifest are rejected if a signature is required
+        let trust_policy = PluginTrustPolicy::new(vec![public_key], true);
+        assert!(matches!(verify_plugin_package(&path, LIBRARY, &trust_policy), Err(PluginPackageError::ManifestMissing(_))));
+    }
+
+    #[test]
+    fn verify_plugin_package_signed_by_untrusted_key_test() {
+        let (secret_key, _) = keys(1);
+        let (_, other_public_key) = keys(2);
+        let path = write_package(&toml::to_string(&manifest()).unwrap(), Some(&secret_key));
+        let trust_policy = PluginTrustPolicy::new(vec![other_public_key], true);
+        assert!(matches!(
+            verify_plugin_package(&path, LIBRARY, &trust_policy),
+            Err(PluginPackageError::SignatureInvalid(_, _))
+        ));
+        remove_package_files(&path);
+    }
+
+    #[test]
+    fn verify_plugin_package_tampered_manifest_test() {
+        let (secret_key, public_key) = keys(1);
+        let path = write_package(&toml::to_string(&manifest()).unwrap(), Some(&secret_key));
+        let mut tampered = manifest();
+        tampered.dependencies.clear();
+        fs::write(get_manifest_path(&path), toml::to_string(&tampered).unwrap()).unwrap();
+        let trust_policy = PluginTrustPolicy::new(vec![public_key], true);
+        assert!(matches!(
+            verify_plugin_package(&path, LIBRARY, &trust_policy),
+            Err(PluginPackageError::SignatureInvalid(_, _))
+        ));
+        remove_package_files(&path);
+    }
+
+    #[test]
+    fn verify_plugin_package_checksum_mismatch_test() {
+        let (secret_key, public_key) = keys(1);
+        let path = write_package(&toml::to_string(&manifest()).unwrap(), Some(&secret_key));
+        let trust_policy = PluginTrustPolicy::new(vec![public_key], true);
+        assert!(matches!(
+            verify_plugin_package(&path, b"tampered library", &trust_policy),
+            Err(PluginPackageError::ChecksumMismatch(_, _, _))
+        ));
+        remove_package_files(&path);
+    }
+
+    #[test]
+    fn manifest_matches_test() {
+        let manifest = manifest();
+        let dependencies = [PluginDependency::new("reactive-graph-plugin-base", ">=0.10.0")];
+        assert!(manifest.matches("reactive-graph-plugin-test", "0.10.0", &dependencies));
+        assert!(!manifest.matches("reactive-graph-plugin-other", "0.10.0", &dependencies));
+        assert!(!manifest.matches("reactive-graph-plugin-test", "0.11.0", &dependencies));
+        assert!(!manifest.matches("reactive-graph-plugin-test", "0.10.0", &[]));
+        assert!(!manifest.matches("reactive-graph-plugin-test", "0.10.0", &[PluginDependency::new("reactive-graph-plugin-base", ">=0.9.0")]));
+    }
+
+    #[test]
+    fn package_complete_test() {
+        let (secret_key, _) = keys(1);
+        let path = write_package(&toml::to_string(&manifest()).unwrap(), Some(&secret_key));
+        assert!(is_package_complete(&path, true));
+        assert_eq!(Some(path.clone()), get_package_dll_path(&get_manifest_path(&path)));
+        assert_eq!(Some(path.clone()), get_package_dll_path(&get_signature_path(&path)));
+        assert_eq!(None, get_package_dll_path(&path));
+        // The signature is missing
+        fs::remove_file(get_signature_path(&path)).unwrap();
+        assert!(!is_package_complete(&path, true));
+        assert!(is_package_complete(&path, false));
+        // The manifest is missing
+        fs::write(get_signature_path(&path), "signature").unwrap();
+        fs::remove_file(get_manifest_path(&path)).unwrap();
+        assert!(!is_package_complete(&path, true));
+        assert!(!is_package_complete(&path, false));
+        remove_package_files(&path);
+        assert!(!is_package_complete(&path, true));
+        assert!(is_package_complete(&path, false));
+    }
+}
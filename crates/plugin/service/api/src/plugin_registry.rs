@@ -0,0 +1,606 @@
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::env::consts::DLL_EXTENSION;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fs;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+
+use semver::Version;
+use semver::VersionReq;
+use serde::Deserialize;
+use serde::Serialize;
+
+use reactive_graph_plugin_api::PluginRegistryError;
+
+use crate::PluginManifest;
+use crate::PluginManifestDependency;
+use crate::get_manifest_path;
+use crate::get_signature_path;
+use crate::sha256_hex;
+
+/// The filename of the index of a plugin registry.
+pub const PLUGIN_REGISTRY_INDEX: &str = "index.toml";
+
+/// The index of a file based plugin registry.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PluginRegistryIndex {
+    /// The plugin packages in the registry.
+    #[serde(default, rename = "package")]
+    pub packages: Vec<PluginRegistryPackage>,
+}
+
+/// A plugin package in the plugin registry.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PluginRegistryPackage {
+    /// The name of the plugin.
+    pub name: String,
+
+    /// The version of the plugin.
+    pub version: String,
+
+    /// The file name of the package file, which is located in the folder of the index.
+    pub file: String,
+
+    /// The hex encoded sha256 digest of the package file.
+    #[serde(default)]
+    pub sha256: Option<String>,
+
+    /// The dependencies of the plugin.
+    #[serde(default)]
+    pub dependencies: Vec<PluginManifestDependency>,
+}
+
+/// A version requirement on a plugin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginRequirement {
+    /// The name of the required plugin.
+    pub name: String,
+
+    /// The version requirement.
+    pub version: VersionReq,
+
+    /// The plugin which declares the requirement. None, if the requirement was requested by the user.
+    pub required_by: Option<String>,
+}
+
+impl PluginRequirement {
+    pub fn new(name: &str, version: &str) -> Result<Self, PluginRegistryError> {
+        Ok(PluginRequirement {
+            name: name.to_string(),
+            version: parse_version_requirement(name, version)?,
+            required_by: None,
+        })
+    }
+}
+
+impl Display for PluginRequirement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.required_by {
+            Some(required_by) => write!(f, "{} (required by {})", self.version, required_by),
+            None => write!(f, "{} (requested)", self.version),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluginRegistryActionKind {
+    /// The plugin is not installed yet.
+    Install,
+
+    /// A lower version of the plugin is installed.
+    Upgrade,
+
+    /// A higher version of the plugin is installed.
+    Downgrade,
+
+    /// The version of the plugin is already installed.
+    Keep,
+}
+
+/// What happens with a plugin of the resolved dependency closure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginRegistryAction {
+    pub kind: PluginRegistryActionKind,
+
+    /// The plugin package to install.
+    pub package: PluginRegistryPackage,
+
+    /// The currently installed version of the plugin.
+    pub installed_version: Option<String>,
+}
+
+impl Display for PluginRegistryAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let installed_version = self.installed_version.clone().unwrap_or_default();
+        match self.kind {
+            PluginRegistryActionKind::Install => write!(f, "Install   {} {}", self.package.name, self.package.version),
+            PluginRegistryActionKind::Upgrade => write!(f, "Upgrade   {} {} -> {}", self.package.name, installed_version, self.package.version),
+            PluginRegistryActionKind::Downgrade => write!(f, "Downgrade {} {} -> {}", self.package.name, installed_version, self.package.version),
+            PluginRegistryActionKind::Keep => write!(f, "Keep      {} {}", self.package.name, self.package.version),
+        }
+    }
+}
+
+/// A validated plugin package.
+#[derive(Debug, Clone)]
+struct PluginRegistryEntry {
+    version: Version,
+    dependencies: Vec<PluginRequirement>,
+    package: PluginRegistryPackage,
+}
+
+/// A file based plugin registry which doesn't require network access.
+#[derive(Debug, Clone)]
+pub struct PluginRegistry {
+    /// The folder containing the index.
+    location: PathBuf,
+
+    entries: Vec<PluginRegistryEntry>,
+}
+
+impl PluginRegistry {
+    /// Opens the plugin registry. The location is either the folder containing the index or the index file itself.
+    pub fn open(location: &Path) -> Result<Self, PluginRegistryError> {
+        let index_path = if location.is_dir() {
+            location.join(PLUGIN_REGISTRY_INDEX)
+        } else {
+            location.to_path_buf()
+        };
+        let index_string = fs::read_to_string(&index_path).map_err(|e| PluginRegistryError::ReadError(index_path.display().to_string(), e.to_string()))?;
+        let index: PluginRegistryIndex =
+            toml::from_str(&index_string).map_err(|e| PluginRegistryError::ParseError(index_path.display().to_string(), e.to_string()))?;
+        let location = index_path.parent().map(Path::to_path_buf).unwrap_or_default();
+        PluginRegistry::new(location, index)
+    }
+
+    /// Creates a plugin registry from the index. Package files are located relative to the given folder.
+    pub fn new(location: PathBuf, index: PluginRegistryIndex) -> Result<Self, PluginRegistryError> {
+        let mut entries = Vec::new();
+        for package in index.packages {
+            let version = Version::parse(&package.version)
+                .map_err(|e| PluginRegistryError::InvalidVersion(package.name.clone(), package.version.clone(), e.to_string()))?;
+            let required_by = format!("{} {}", package.name, package.version);
+            let dependencies = package
+                .dependencies
+                .iter()
+                .map(|dependency| {
+                    Ok(PluginRequirement {
+                        name: dependency.name.clone(),
+                        version: parse_version_requirement(&dependency.name, &dependency.version)?,
+                        required_by: Some(required_by.clone()),
+                    })
+                })
+                .collect::<Result<Vec<_>, PluginRegistryError>>()?;
+            entries.push(PluginRegistryEntry {
+                version,
+                dependencies,
+                package,
+            });
+        }
+        // Highest versions first
+        entries.sort_by(|a, b| a.package.name.cmp(&b.package.name).then_with(|| b.version.cmp(&a.version)));
+        Ok(PluginRegistry { location, entries })
+    }
+
+    /// Returns the plugin packages sorted by name and by version, highest versions first.
+    pub fn get_packages(&self) -> Vec<PluginRegistryPackage> {
+        self.entries.iter().map(|entry| entry.package.clone()).collect()
+    }
+
+    /// Returns the versions of the plugin with the given name, highest versions first.
+    pub fn get_versions(&self, name: &str) -> Vec<String> {
+        self.get_entries(name).map(|entry| entry.package.version.clone()).collect()
+    }
+
+    /// Returns the highest version of the plugin matching the version requirement.
+    pub fn find(&self, name: &str, version: &VersionReq) -> Option<PluginRegistryPackage> {
+        self.get_entries(name)
+            .find(|entry| version.matches(&entry.version))
+            .map(|entry| entry.package.clone())
+    }
+
+    /// Returns the path of the package file.
+    pub fn get_package_path(&self, package: &PluginRegistryPackage) -> PathBuf {
+        self.location.join(&package.file)
+    }
+
+    /// Resolves the dependency closure of the requested plugins.
+    ///
+    /// Each plugin is resolved to exactly one version which satisfies all requirements on it. The
+    /// highest matching version is selected, except for the preferred versions which are selected
+    /// first if they match. If a selection leads to a conflict, the next lower version is tried.
+    pub fn resolve(
+        &self,
+        requirements: &[PluginRequirement],
+        preferred: &BTreeMap<String, Version>,
+    ) -> Result<Vec<PluginRegistryPackage>, PluginRegistryError> {
+        let selected = self.solve(requirements.iter().cloned().collect(), BTreeMap::new(), Vec::new(), preferred)?;
+        Ok(selected.into_values().map(|entry| entry.package.clone()).collect())
+    }
+
+    /// Plans installing, upgrading or downgrading the requested plugins and their dependencies.
+    ///
+    /// Installed dependencies are kept if they satisfy the requirements. The requested plugins are
+    /// resolved to the highest version matching the requirement, which allows upgrades as well as
+    /// downgrades. Fails if an installed plugin which isn't part of the plan would be broken.
+    pub fn plan(
+        &self,
+        requirements: &[PluginRequirement],
+        installed: &BTreeMap<String, PluginManifest>,
+    ) -> Result<Vec<PluginRegistryAction>, PluginRegistryError> {
+        let preferred: BTreeMap<String, Version> = installed
+            .values()
+            .filter(|manifest| !requirements.iter().any(|requirement| requirement.name == manifest.name))
+            .filter_map(|manifest| Version::parse(&manifest.version).ok().map(|version| (manifest.name.clone(), version)))
+            .collect();
+        let packages = self.resolve(requirements, &preferred)?;
+        for manifest in installed.values() {
+            if packages.iter().any(|package| package.name == manifest.name) {
+                continue;
+            }
+            for dependency in manifest.dependencies.iter() {
+                let Some(package) = packages.iter().find(|package| package.name == dependency.name) else {
+                    continue;
+                };
+                let version_requirement = parse_version_requirement(&dependency.name, &dependency.version)?;
+                let version = Version::parse(&package.version)
+                    .map_err(|e| PluginRegistryError::InvalidVersion(package.name.clone(), package.version.clone(), e.to_string()))?;
+                if !version_requirement.matches(&version) {
+                    return Err(PluginRegistryError::DependentConflict(
+                        manifest.name.clone(),
+                        dependency.name.clone(),
+                        dependency.version.clone(),
+                        package.version.clone(),
+                    ));
+                }
+            }
+        }
+        Ok(packages
+            .into_iter()
+            .map(|package| {
+                let installed_version = installed.get(&package.name).map(|manifest| manifest.version.clone());
+                let kind = match installed_version.as_ref().and_then(|version| Version::parse(version).ok()) {
+                    None => PluginRegistryActionKind::Install,
+                    Some(installed_version) => match Version::parse(&package.version) {
+                        Ok(version) if version > installed_version => PluginRegistryActionKind::Upgrade,
+                        Ok(version) if version < installed_version => PluginRegistryActionKind::Downgrade,
+                        _ => PluginRegistryActionKind::Keep,
+                    },
+                };
+                PluginRegistryAction {
+                    kind,
+                    package,
+                    installed_version,
+                }
+            })
+            .collect())
+    }
+
+    /// Copies the package file into the deploy folder, from where it will be hot deployed.
+    ///
+    /// The manifest and the signature located next to the package file are copied first, so that
+    /// the plugin package is complete when the hot deployment picks up the package file.
+    pub fn install(&self, package: &PluginRegistryPackage, deploy_location: &Path) -> Result<PathBuf, PluginRegistryError> {
+        // The package file must not be located outside the plugin registry
+        let mut components = Path::new(&package.file).components();
+        if !matches!((components.next(), components.next()), (Some(Component::Normal(_)), None)) {
+            return Err(PluginRegistryError::InvalidPackageFile(package.name.clone(), package.version.clone(), package.file.clone()));
+        }
+        let package_path = self.get_package_path(package);
+        let install_error = |e: String| PluginRegistryError::InstallError(package_path.display().to_string(), e);
+        let bytes = fs::read(&package_path).map_err(|e| install_error(e.to_string()))?;
+        if let Some(expected) = &package.sha256 {
+            let actual = sha256_hex(&bytes);
+            if !expected.eq_ignore_ascii_case(&actual) {
+                return Err(PluginRegistryError::ChecksumMismatch(package_path.display().to_string(), expected.clone(), actual));
+            }
+        }
+        let file_name = package_path.file_name().ok_or_else(|| install_error("Not a file".to_string()))?;
+        let deploy_path = deploy_location.join(file_name);
+        for (from, to) in [
+            (get_signature_path(&package_path), get_signature_path(&deploy_path)),
+            (get_manifest_path(&package_path), get_manifest_path(&deploy_path)),
+        ] {
+            if from.exists() {
+                fs::copy(&from, &to).map_err(|e| install_error(e.to_string()))?;
+            }
+        }
+        fs::write(&deploy_path, bytes).map_err(|e| install_error(e.to_string()))?;
+        Ok(deploy_path)
+    }
+
+    fn get_entries(&self, name: &str) -> impl Iterator<Item = &PluginRegistryEntry> {
+        self.entries.iter().filter(move |entry| entry.package.name == name)
+    }
+
+    fn solve<'a>(
+        &'a self,
+        mut pending: VecDeque<PluginRequirement>,
+        selected: BTreeMap<String, &'a PluginRegistryEntry>,
+        mut requirements: Vec<PluginRequirement>,
+        preferred: &BTreeMap<String, Version>,
+    ) -> Result<BTreeMap<String, &'a PluginRegistryEntry>, PluginRegistryError> {
+        let Some(requirement) = pending.pop_front() else {
+            return Ok(selected);
+        };
+        requirements.push(requirement.clone());
+        if let Some(entry) = selected.get(&requirement.name) {
+            if requirement.version.matches(&entry.version) {
+                return self.solve(pending, selected, requirements, preferred);
+            }
+            return Err(conflict(&requirement.name, &requirements));
+        }
+        let name_requirements: Vec<&PluginRequirement> = requirements.iter().filter(|r| r.name == requirement.name).collect();
+        let mut candidates: Vec<&PluginRegistryEntry> = self
+            .get_entries(&requirement.name)
+            .filter(|entry| name_requirements.iter().all(|r| r.version.matches(&entry.version)))
+            .collect();
+        if candidates.is_empty() {
+            if self.get_entries(&requirement.name).next().is_none() {
+                return Err(PluginRegistryError::PluginNotFound(requirement.name));
+            }
+            if name_requirements.len() == 1 {
+                return Err(PluginRegistryError::NoMatchingVersion(requirement.name, requirement.version.to_string()));
+            }
+            return Err(conflict(&requirement.name, &requirements));
+        }
+        if let Some(preferred_version) = preferred.get(&requirement.name) {
+            candidates.sort_by_key(|entry| &entry.version != preferred_version);
+        }
+        let mut last_error = None;
+        for candidate in candidates {
+            let mut selected = selected.clone();
+            selected.insert(requirement.name.clone(), candidate);
+            let mut pending = pending.clone();
+            pending.extend(candidate.dependencies.iter().cloned());
+            match self.solve(pending, selected, requirements.clone(), preferred) {
+                Ok(selected) => return Ok(selected),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error.unwrap_or(PluginRegistryError::PluginNotFound(requirement.name)))
+    }
+}
+
+/// Returns the manifests of the installed plugins by name.
+///
+/// Fails if an installed plugin has no manifest, because a plan can't be checked against a
+/// plugin whose name, version and dependencies are unknown.
+pub fn get_installed_plugins(install_location: &Path) -> Result<BTreeMap<String, PluginManifest>, PluginRegistryError> {
+    let mut installed = BTreeMap::new();
+    let Ok(dir) = fs::read_dir(install_location) else {
+        return Ok(installed);
+    };
+    for entry in dir.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|extension| extension.to_str()) != Some(DLL_EXTENSION) {
+            continue;
+        }
+        let manifest_path = get_manifest_path(&path);
+        if !manifest_path.exists() {
+            return Err(PluginRegistryError::ManifestMissing(path.display().to_string()));
+        }
+        let manifest = fs::read_to_string(&manifest_path)
+            .map_err(|e| e.to_string())
+            .and_then(|manifest| toml::from_str::<PluginManifest>(&manifest).map_err(|e| e.to_string()))
+            .map_err(|e| PluginRegistryError::ManifestInvalid(manifest_path.display().to_string(), e))?;
+        installed.insert(manifest.name.clone(), manifest);
+    }
+    Ok(installed)
+}
+
+fn parse_version_requirement(name: &str, version: &str) -> Result<VersionReq, PluginRegistryError> {
+    VersionReq::parse(version).map_err(|e| PluginRegistryError::InvalidVersionRequirement(name.to_string(), version.to_string(), e.to_string()))
+}
+
+fn conflict(name: &str, requirements: &[PluginRequirement]) -> PluginRegistryError {
+    PluginRegistryError::Conflict(name.to_string(), requirements.iter().filter(|r| r.name == name).map(|r| r.to_string()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::env;
+    use std::env::consts::DLL_EXTENSION;
+    use std::fs;
+    use std::path::PathBuf;
+
+    use uuid::Uuid;
+
+    use reactive_graph_plugin_api::PluginRegistryError;
+
+    use crate::PluginManifest;
+    use crate::PluginManifestDependency;
+    use crate::PluginRegistry;
+    use crate::PluginRegistryActionKind;
+    use crate::PluginRegistryIndex;
+    use crate::PluginRegistryPackage;
+    use crate::PluginRequirement;
+    use crate::get_installed_plugins;
+    use crate::get_manifest_path;
+    use crate::get_signature_path;
+
+    fn package(name: &str, version: &str, dependencies: &[(&str, &str)]) -> PluginRegistryPackage {
+        PluginRegistryPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+            file: format!("lib{name}.{version}.{DLL_EXTENSION}"),
+            sha256: None,
+            dependencies: dependencies
+                .iter()
+                .map(|(name, version)| PluginManifestDependency {
+                    name: name.to_string(),
+                    version: version.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    fn manifest(name: &str, version: &str, dependencies: &[(&str, &str)]) -> PluginManifest {
+        let package = package(name, version, dependencies);
+        PluginManifest {
+            name: package.name,
+            version: package.version,
+            dependencies: package.dependencies,
+            checksums: BTreeMap::new(),
+        }
+    }
+
+    fn registry() -> PluginRegistry {
+        let index = PluginRegistryIndex {
+            packages: vec![
+                package("base", "1.0.0", &[]),
+                package("base", "1.2.0", &[]),
+                package("base", "2.0.0", &[]),
+                package("json", "1.0.0", &[("base", "^1.0")]),
+                package("json", "2.0.0", &[("base", "^2.0")]),
+                package("http", "1.0.0", &[("base", "^1.1"), ("json", "^1.0")]),
+                package("mqtt", "1.0.0", &[("base", "~1.0")]),
+            ],
+        };
+        PluginRegistry::new(Default::default(), index).expect("Failed to create the plugin registry")
+    }
+
+    fn versions(packages: Vec<PluginRegistryPackage>) -> Vec<String> {
+        packages.into_iter().map(|package| format!("{} {}", package.name, package.version)).collect()
+    }
+
+    #[test]
+    fn resolve_highest_version() {
+        let requirements = [PluginRequirement::new("json", "*").unwrap()];
+        let packages = registry().resolve(&requirements, &BTreeMap::new()).expect("Failed to resolve");
+        assert_eq!(vec!["base 2.0.0", "json 2.0.0"], versions(packages));
+    }
+
+    #[test]
+    fn resolve_dependency_closure() {
+        let requirements = [PluginRequirement::new("http", "^1").unwrap()];
+        let packages = registry().resolve(&requirements, &BTreeMap::new()).expect("Failed to resolve");
+        assert_eq!(vec!["base 1.2.0", "http 1.0.0", "json 1.0.0"], versions(packages));
+    }
+
+    #[test]
+    fn resolve_backtracks_on_conflicts() {
+        // The latest json requires base ^2.0, but mqtt requires base ~1.0
+        let requirements = [PluginRequirement::new("mqtt", "*").unwrap(), PluginRequirement::new("json", "*").unwrap()];
+        let packages = registry().resolve(&requirements, &BTreeMap::new()).expect("Failed to resolve");
+        assert_eq!(vec!["base 1.0.0", "json 1.0.0", "mqtt 1.0.0"], versions(packages));
+    }
+
+    #[test]
+    fn resolve_detects_conflicts() {
+        let requirements = [PluginRequirement::new("mqtt", "*").unwrap(), PluginRequirement::new("base", "^2").unwrap()];
+        let result = registry().resolve(&requirements, &BTreeMap::new());
+        assert!(matches!(result, Err(PluginRegistryError::Conflict(name, _)) if name == "base"));
+    }
+
+    #[test]
+    fn resolve_unknown_plugin() {
+        let requirements = [PluginRequirement::new("unknown", "*").unwrap()];
+        let result = registry().resolve(&requirements, &BTreeMap::new());
+        assert!(matches!(result, Err(PluginRegistryError::PluginNotFound(name)) if name == "unknown"));
+        let requirements = [PluginRequirement::new("base", "^3").unwrap()];
+        let result = registry().resolve(&requirements, &BTreeMap::new());
+        assert!(matches!(result, Err(PluginRegistryError::NoMatchingVersion(name, _)) if name == "base"));
+    }
+
+    #[test]
+    fn plan_keeps_installed_dependencies() {
+        let installed = BTreeMap::from([("base".to_string(), manifest("base", "1.0.0", &[]))]);
+        let requirements = [PluginRequirement::new("json", "^1").unwrap()];
+        let actions = registry().plan(&requirements, &installed).expect("Failed to plan");
+        let kinds: Vec<_> = actions.iter().map(|action| (action.package.name.as_str(), action.kind)).collect();
+        assert_eq!(vec![("base", PluginRegistryActionKind::Keep), ("json", PluginRegistryActionKind::Install)], kinds);
+    }
+
+    #[test]
+    fn plan_upgrade_and_downgrade() {
+        let installed = BTreeMap::from([
+            ("base".to_string(), manifest("base", "1.0.0", &[])),
+            ("json".to_string(), manifest("json", "1.0.0", &[("base", "^1.0")])),
+        ]);
+        let requirements = [PluginRequirement::new("base", "^1").unwrap()];
+        let actions = registry().plan(&requirements, &installed).expect("Failed to plan");
+        assert_eq!(PluginRegistryActionKind::Upgrade, actions[0].kind);
+        assert_eq!("1.2.0", actions[0].package.version);
+
+        let installed = BTreeMap::from([("base".to_string(), manifest("base", "1.2.0", &[]))]);
+        let requirements = [PluginRequirement::new("base", "=1.0.0").unwrap()];
+        let actions = registry().plan(&requirements, &installed).expect("Failed to plan");
+        assert_eq!(PluginRegistryActionKind::Downgrade, actions[0].kind);
+        assert_eq!(Some("1.2.0".to_string()), actions[0].installed_version);
+    }
+
+    #[test]
+    fn plan_detects_broken_dependents() {
+        let installed = BTreeMap::from([
+            ("base".to_string(), manifest("base", "1.0.0", &[])),
+            ("mqtt".to_string(), manifest("mqtt", "1.0.0", &[("base", "~1.0")])),
+        ]);
+        let requirements = [PluginRequirement::new("base", "^2").unwrap()];
+        let result = registry().plan(&requirements, &installed);
+        assert!(matches!(result, Err(PluginRegistryError::DependentConflict(dependent, _, _, _)) if dependent == "mqtt"));
+    }
+
+    fn temp_dir() -> PathBuf {
+        let path = env::temp_dir().join(format!("reactive_graph_plugin_registry_{}", Uuid::new_v4().simple()));
+        fs::create_dir_all(&path).unwrap();
+        path
+    }
+
+    #[test]
+    fn installed_plugins_without_manifest() {
+        let install_location = temp_dir();
+        let path = install_location.join(format!("libbase.1.{DLL_EXTENSION}"));
+        fs::write(&path, "library").unwrap();
+        let result = get_installed_plugins(&install_location);
+        assert!(matches!(result, Err(PluginRegistryError::ManifestMissing(_))));
+
+        fs::write(get_manifest_path(&path), toml::to_string(&manifest("base", "1.0.0", &[])).unwrap()).unwrap();
+        let installed = get_installed_plugins(&install_location).expect("Failed to read the installed plugins");
+        assert_eq!(Some("1.0.0".to_string()), installed.get("base").map(|manifest| manifest.version.clone()));
+        fs::remove_dir_all(install_location).unwrap();
+    }
+
+    #[test]
+    fn install_copies_package_files() {
+        let registry_location = temp_dir();
+        let deploy_location = temp_dir();
+        let package = package("base", "1.0.0", &[]);
+        let package_path = registry_location.join(&package.file);
+        fs::write(&package_path, "library").unwrap();
+        fs::write(get_manifest_path(&package_path), "manifest").unwrap();
+        fs::write(get_signature_path(&package_path), "signature").unwrap();
+        let index = PluginRegistryIndex {
+            packages: vec![package.clone()],
+        };
+        let registry = PluginRegistry::new(registry_location.clone(), index).expect("Failed to create the plugin registry");
+        let deploy_path = registry.install(&package, &deploy_location).expect("Failed to install");
+        assert_eq!("library", fs::read_to_string(&deploy_path).unwrap());
+        assert_eq!("manifest", fs::read_to_string(get_manifest_path(&deploy_path)).unwrap());
+        assert_eq!("signature", fs::read_to_string(get_signature_path(&deploy_path)).unwrap());
+        fs::remove_dir_all(registry_location).unwrap();
+        fs::remove_dir_all(deploy_location).unwrap();
+    }
+
+    #[test]
+    fn install_rejects_package_files_outside_the_registry() {
+        let registry_location = temp_dir();
+        let deploy_location = temp_dir();
+        for file in ["../libbase.so", "/tmp/libbase.so", "plugins/libbase.so", ".", ""] {
+            let mut package = package("base", "1.0.0", &[]);
+            package.file = file.to_string();
+            let index = PluginRegistryIndex {
+                packages: vec![package.clone()],
+            };
+            let registry = PluginRegistry::new(registry_location.clone(), index).expect("Failed to create the plugin registry");
+            let result = registry.install(&package, &deploy_location);
+            assert!(matches!(result, Err(PluginRegistryError::InvalidPackageFile(_, _, _))), "{file}");
+        }
+        fs::remove_dir_all(registry_location).unwrap();
+        fs::remove_dir_all(deploy_location).unwrap();
+    }
+}
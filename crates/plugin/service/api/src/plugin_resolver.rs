@@ -3,6 +3,7 @@ use springtime_di::injectable;
 
 use reactive_graph_lifecycle::Lifecycle;
 
+use crate::PluginResolverDiagnostics;
 use crate::PluginResolverMode;
 use crate::PluginTransitionResult;
 
@@ -25,4 +26,7 @@ pub trait PluginResolver: Send + Sync + Lifecycle {
 
     /// Returns the resolve mode.
     fn get_mode(&self) -> PluginResolverMode;
+
+    /// Returns the state, the dependencies and the state transitions of all plugins.
+    fn diagnostics(&self) -> PluginResolverDiagnostics;
 }
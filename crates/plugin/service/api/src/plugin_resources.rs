@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use springtime_di::injectable;
+use uuid::Uuid;
+
+use reactive_graph_behaviour_model_api::ComponentBehaviourTypeId;
+use reactive_graph_behaviour_model_api::EntityBehaviourTypeId;
+use reactive_graph_behaviour_model_api::RelationBehaviourTypeId;
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::FlowTypeId;
+use reactive_graph_graph::RelationTypeId;
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_plugin_api::PluginContext;
+
+/// The resources which a plugin has registered through the registries of the plugin context.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PluginResources {
+    /// The ids of the component providers.
+    pub component_providers: Vec<String>,
+
+    /// The components provided by the component providers.
+    pub components: Vec<ComponentTypeId>,
+
+    /// The ids of the entity type providers.
+    pub entity_type_providers: Vec<String>,
+
+    /// The entity types provided by the entity type providers.
+    pub entity_types: Vec<EntityTypeId>,
+
+    /// The ids of the relation type providers.
+    pub relation_type_providers: Vec<String>,
+
+    /// The relation types provided by the relation type providers.
+    pub relation_types: Vec<RelationTypeId>,
+
+    /// The ids of the flow type providers.
+    pub flow_type_providers: Vec<String>,
+
+    /// The flow types provided by the flow type providers.
+    pub flow_types: Vec<FlowTypeId>,
+
+    /// The components created through the component manager.
+    pub created_components: Vec<ComponentTypeId>,
+
+    /// The entity types created through the entity type manager.
+    pub created_entity_types: Vec<EntityTypeId>,
+
+    /// The relation types created through the relation type manager.
+    pub created_relation_types: Vec<RelationTypeId>,
+
+    /// The flow types created through the flow type manager.
+    pub created_flow_types: Vec<FlowTypeId>,
+
+    /// The entity behaviours.
+    pub entity_behaviours: Vec<EntityBehaviourTypeId>,
+
+    /// The entity component behaviours.
+    pub entity_component_behaviours: Vec<ComponentBehaviourTypeId>,
+
+    /// The relation behaviours.
+    pub relation_behaviours: Vec<RelationBehaviourTypeId>,
+
+    /// The relation component behaviours.
+    pub relation_component_behaviours: Vec<ComponentBehaviourTypeId>,
+
+    /// The ids of the flow instances created by the plugin.
+    pub flow_instances: Vec<Uuid>,
+
+    /// The ids of the web resource providers.
+    pub web_resource_providers: Vec<Uuid>,
+
+    /// The ids of the command entity instances created by the plugin.
+    pub commands: Vec<Uuid>,
+}
+
+impl PluginResources {
+    /// Returns the number of registrations. The provided types are counted by their providers.
+    pub fn count(&self) -> usize {
+        self.component_providers.len()
+            + self.entity_type_providers.len()
+            + self.relation_type_providers.len()
+            + self.flow_type_providers.len()
+            + self.created_components.len()
+            + self.created_entity_types.len()
+            + self.created_relation_types.len()
+            + self.created_flow_types.len()
+            + self.entity_behaviours.len()
+            + self.entity_component_behaviours.len()
+            + self.relation_behaviours.len()
+            + self.relation_component_behaviours.len()
+            + self.flow_instances.len()
+            + self.web_resource_providers.len()
+            + self.commands.len()
+    }
+
+    /// Returns true, if the plugin has nothing registered.
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+}
+
+#[injectable]
+#[async_trait]
+pub trait PluginResourceManager: Send + Sync + Lifecycle {
+    /// Returns a plugin context for the plugin with the given id which tracks the resources
+    /// which are registered through the given plugin context.
+    ///
+    /// Previously tracked resources of the plugin are forgotten.
+    fn track(&self, id: &Uuid, plugin_context: Arc<dyn PluginContext + Send + Sync>) -> Arc<dyn PluginContext + Send + Sync>;
+
+    /// Returns the resources which are currently registered by the plugin with the given id.
+    fn get_resources(&self, id: &Uuid) -> Option<PluginResources>;
+
+    /// Hands over the resources of the plugin with the id `previous` to the newer version of the
+    /// plugin with the given id. Returns the handed over resources.
+    ///
+    /// Resources which have been handed over are not unregistered by the previous version.
+    fn handover(&self, previous: &Uuid, id: &Uuid) -> PluginResources;
+
+    /// Unregisters the resources which the plugin with the given id has not unregistered
+    /// itself and stops tracking the plugin. Returns the leaked resources.
+    async fn cleanup(&self, id: &Uuid) -> PluginResources;
+}
@@ -3,10 +3,12 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use springtime_di::injectable;
 
+use crate::PluginConfigManager;
 use crate::PluginContainerManager;
 use crate::PluginContextFactory;
 use crate::PluginRepositoryManager;
 use crate::PluginResolver;
+use crate::PluginResourceManager;
 use reactive_graph_lifecycle::Lifecycle;
 
 #[injectable]
@@ -16,7 +18,11 @@ pub trait PluginSystem: Lifecycle {
 
     fn get_plugin_container_manager(&self) -> Arc<dyn PluginContainerManager + Send + Sync>;
 
+    fn get_plugin_config_manager(&self) -> Arc<dyn PluginConfigManager + Send + Sync>;
+
     fn get_plugin_repository_manager(&self) -> Arc<dyn PluginRepositoryManager + Send + Sync>;
 
     fn get_plugin_resolver(&self) -> Arc<dyn PluginResolver + Send + Sync>;
+
+    fn get_plugin_resource_manager(&self) -> Arc<dyn PluginResourceManager + Send + Sync>;
 }
@@ -0,0 +1,24 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+use springtime_di::injectable;
+use uuid::Uuid;
+
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_plugin_api::PluginUpgradeError;
+
+#[injectable]
+#[async_trait]
+pub trait PluginUpgradeManager: Send + Sync + Lifecycle {
+    /// Upgrades the active plugin with the given id to the new version of the plugin which is
+    /// located at the given path in the deploy folder. Returns the id of the new version.
+    ///
+    /// The new version is loaded and activated side by side with the running version. The
+    /// behaviours of the running version are handed over to the behaviours of the new version
+    /// including their state, one instance at a time. Afterwards the previous version is
+    /// stopped and uninstalled.
+    ///
+    /// If the new version can't be activated, the new version is removed and the previous
+    /// version is restarted.
+    async fn upgrade(&self, id: &Uuid, deploy_path: &Path) -> Result<Uuid, PluginUpgradeError>;
+}
@@ -1,26 +1,38 @@
+use std::collections::VecDeque;
+#[cfg(feature = "dynamic")]
+use std::env;
 use std::fs;
+#[cfg(feature = "dynamic")]
+use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::RwLock;
 
 use dashmap::DashSet;
+#[cfg(feature = "dynamic")]
 use libloading::Library;
 use log::debug;
 use log::error;
 use log::info;
 use log::trace;
+use log::warn;
+use serde_json::Value;
 use springtime_di::instance_provider::ComponentInstanceProviderError;
 use uuid::Uuid;
 
 use reactive_graph_plugin_api::PLUGIN_API_VERSION;
 use reactive_graph_plugin_api::PLUGIN_NAME_PREFIX;
 use reactive_graph_plugin_api::Plugin;
+use reactive_graph_plugin_api::PluginCapabilities;
 use reactive_graph_plugin_api::PluginContext;
 use reactive_graph_plugin_api::PluginDeclaration;
 use reactive_graph_plugin_api::PluginDependency;
 use reactive_graph_plugin_api::PluginDeployError;
 use reactive_graph_plugin_api::PluginDisableError;
 use reactive_graph_plugin_api::PluginLoadingError;
+#[cfg(feature = "dynamic")]
+use reactive_graph_plugin_api::PluginPackageError;
 use reactive_graph_plugin_api::PluginRefreshingState;
 use reactive_graph_plugin_api::PluginResolveState;
 use reactive_graph_plugin_api::PluginStartError;
@@ -31,14 +43,25 @@ use reactive_graph_plugin_api::PluginStoppingState;
 use reactive_graph_plugin_api::PluginUninstallError;
 use reactive_graph_plugin_api::PluginUninstallingState;
 use reactive_graph_plugin_api::RUSTC_VERSION;
+use reactive_graph_plugin_service_api::MAX_PLUGIN_STATE_HISTORY;
+use reactive_graph_plugin_service_api::PluginIsolation;
+use reactive_graph_plugin_service_api::PluginManifest;
+use reactive_graph_plugin_service_api::PluginStateTransition;
 use reactive_graph_plugin_service_api::PluginTransitionResult;
 use reactive_graph_plugin_service_api::PluginTransitionResult::Changed;
 use reactive_graph_plugin_service_api::PluginTransitionResult::NoChange;
+use reactive_graph_plugin_service_api::PluginTrustPolicy;
 use reactive_graph_plugin_service_api::get_deploy_path;
 use reactive_graph_plugin_service_api::get_install_path;
+use reactive_graph_plugin_service_api::move_package_files;
+use reactive_graph_plugin_service_api::remove_package_files;
+use reactive_graph_plugin_service_api::sha256_hex;
+#[cfg(feature = "dynamic")]
+use reactive_graph_plugin_service_api::verify_plugin_package;
 
 use crate::PluginProxy;
 use crate::PluginRegistrar;
+use crate::RemotePlugin;
 
 /// The plugin container holds the meta information and the library.
 pub struct PluginContainer {
@@ -59,14 +82,32 @@ pub struct PluginContainer {
     /// The plugin declaration.
     pub plugin_declaration: RwLock<Option<PluginDeclaration>>,
 
+    /// The verified manifest of the plugin package.
+    pub manifest: RwLock<Option<PluginManifest>>,
+
     /// The plugin context.
     pub proxy: Arc<RwLock<Option<Arc<PluginProxy>>>>,
 
     /// The loaded library.
+    #[cfg(feature = "dynamic")]
     pub library: RwLock<Option<Arc<Library>>>,
 
+    /// The path of the private copy of the verified dynamic link library. The library is loaded
+    /// from the private copy, so the verified file can't be replaced before it is loaded.
+    pub library_path: RwLock<Option<PathBuf>>,
+
+    /// The sha256 checksum of the verified dynamic link library. The private copy is verified
+    /// again before it is loaded.
+    pub library_checksum: RwLock<Option<String>>,
+
+    /// The plugin declaration of a plugin which is linked statically into the binary.
+    pub static_plugin_declaration: Option<PluginDeclaration>,
+
     /// The dependencies of the plugin.
     pub dependencies: DashSet<PluginDependency>,
+
+    /// The recent state transitions of the plugin.
+    pub history: VecDeque<PluginStateTransition>,
 }
 
 impl PluginContainer {
@@ -77,12 +118,45 @@ impl PluginContainer {
             path,
             state: PluginState::Installed,
             plugin_declaration: RwLock::new(None),
+            manifest: RwLock::new(None),
             proxy: Arc::new(RwLock::new(None)),
+            #[cfg(feature = "dynamic")]
             library: RwLock::new(None),
+            library_path: RwLock::new(None),
+            library_checksum: RwLock::new(None),
+            static_plugin_declaration: None,
             dependencies: DashSet::new(),
+            history: VecDeque::new(),
         }
     }
 
+    /// Creates a container for a plugin which is linked statically into the binary.
+    ///
+    /// The plugin has no file in the plugin installation folder, so the name of the plugin is
+    /// used as stem.
+    pub fn new_static(plugin_declaration: PluginDeclaration) -> Self {
+        PluginContainer {
+            static_plugin_declaration: Some(plugin_declaration),
+            ..PluginContainer::new(plugin_declaration.name.to_string(), PathBuf::new())
+        }
+    }
+
+    /// Returns true, if the plugin is linked statically into the binary.
+    pub fn is_static(&self) -> bool {
+        self.static_plugin_declaration.is_some()
+    }
+
+    /// Records the transition from the given state to the current state, if the state has changed.
+    pub fn record_transition(&mut self, from: PluginState) {
+        if from == self.state {
+            return;
+        }
+        if self.history.len() >= MAX_PLUGIN_STATE_HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(PluginStateTransition::new(from, self.state));
+    }
+
     /// Moves the file from the folder `deploy` to the folder `installed`
     pub fn deploy_dll(&mut self) -> PluginTransitionResult {
         if self.state != PluginState::Refreshing(PluginRefreshingState::Deploying) {
@@ -105,6 +179,7 @@ impl PluginContainer {
                 match fs::remove_file(&deploy_path) {
                     Ok(_) => {
                         trace!("Removed plugin from {}", deploy_path.display());
+                        move_package_files(&deploy_path, &install_path);
                         self.path = install_path.clone();
                         self.state = PluginState::Refreshing(PluginRefreshingState::Installed);
                         debug!("Plugin {} successfully deployed from {} to {}", self.id, deploy_path.display(), install_path.display());
@@ -131,14 +206,76 @@ impl PluginContainer {
         }
     }
 
-    /// Loads the dynamic link library into memory.
-    pub fn load_dll(&mut self) -> PluginTransitionResult {
+    /// Verifies the plugin package and loads the dynamic link library into memory.
+    ///
+    /// A statically linked plugin is already loaded.
+    pub fn load_dll(&mut self, trust_policy: &PluginTrustPolicy) -> PluginTransitionResult {
         if self.state != PluginState::Installed && self.state != PluginState::Refreshing(PluginRefreshingState::Installed) {
             return NoChange;
         }
         let refreshing = self.state == PluginState::Refreshing(PluginRefreshingState::Installed);
+        if self.is_static() {
+            debug!("Plugin {} is linked statically", self.id);
+            if refreshing {
+                self.state = PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::Loaded));
+            } else {
+                self.state = PluginState::Resolving(PluginResolveState::Loaded);
+            }
+            return Changed;
+        }
+        self.load_library(trust_policy, refreshing)
+    }
+
+    #[cfg(feature = "dynamic")]
+    fn load_library(&mut self, trust_policy: &PluginTrustPolicy, refreshing: bool) -> PluginTransitionResult {
+        let verified = fs::read(&self.path)
+            .map_err(|e| PluginPackageError::ReadError(self.path.display().to_string(), e.to_string()))
+            .and_then(|library| verify_plugin_package(&self.path, &library, trust_policy).map(|manifest| (manifest, library)));
+        let library = match verified {
+            Ok((manifest, library)) => {
+                let mut writer = self.manifest.write().unwrap();
+                *writer = manifest;
+                library
+            }
+            Err(e) => {
+                error!("Plugin {} rejected the plugin package located at {}: {}", self.id, self.path.display(), e);
+                let resolve_state = match e {
+                    PluginPackageError::ManifestMissing(_) => PluginResolveState::ManifestMissing,
+                    PluginPackageError::ReadError(_, _) | PluginPackageError::ManifestInvalid(_, _) => PluginResolveState::ManifestInvalid,
+                    PluginPackageError::SignatureMissing(_) => PluginResolveState::SignatureMissing,
+                    PluginPackageError::SignatureInvalid(_, _) => PluginResolveState::SignatureInvalid,
+                    PluginPackageError::ChecksumMismatch(_, _, _) => PluginResolveState::ChecksumMismatch,
+                };
+                if refreshing {
+                    self.state = PluginState::Refreshing(PluginRefreshingState::Resolving(resolve_state));
+                } else {
+                    self.state = PluginState::Resolving(resolve_state);
+                }
+                return Changed;
+            }
+        };
+        let checksum = sha256_hex(&library);
+        let library_path = match write_private_copy(&self.path, &library) {
+            Ok(library_path) => library_path,
+            Err(e) => {
+                error!("Plugin {} failed to copy the dynamic link library located at {}: {:?}", self.id, self.path.display(), e);
+                self.state = PluginState::Uninstalling(PluginUninstallingState::UnloadDll);
+                return Changed;
+            }
+        };
+        {
+            let mut writer = self.library_path.write().unwrap();
+            *writer = Some(library_path.clone());
+            let mut writer = self.library_checksum.write().unwrap();
+            *writer = Some(checksum.clone());
+        }
+        if let Err(e) = verify_private_copy(&library_path, &checksum) {
+            error!("Plugin {} refused to load the dynamic link library located at {}: {}", self.id, self.path.display(), e);
+            self.state = PluginState::Uninstalling(PluginUninstallingState::UnloadDll);
+            return Changed;
+        }
         unsafe {
-            match Library::new(self.path.as_os_str()) {
+            match Library::new(library_path.as_os_str()) {
                 Ok(library) => {
                     let mut writer = self.library.write().unwrap();
                     *writer = Some(Arc::new(library));
@@ -159,6 +296,17 @@ impl PluginContainer {
         }
     }
 
+    #[cfg(not(feature = "dynamic"))]
+    fn load_library(&mut self, _trust_policy: &PluginTrustPolicy, _refreshing: bool) -> PluginTransitionResult {
+        error!(
+            "Plugin {} located at {} can't be loaded: Dynamically linked plugins are not supported by this build",
+            self.id,
+            self.path.display()
+        );
+        self.state = PluginState::Disabled;
+        Changed
+    }
+
     /// Unloads the dynamic link library.
     pub fn unload_dll(&mut self) -> PluginTransitionResult {
         if self.state != PluginState::Uninstalling(PluginUninstallingState::UnloadDll)
@@ -172,12 +320,21 @@ impl PluginContainer {
             // This drops the plugin declaration
             *writer = None;
         }
+        {
+            let mut writer = self.manifest.write().unwrap();
+            *writer = None;
+        }
+        #[cfg(feature = "dynamic")]
         {
             let mut writer = self.library.write().unwrap();
             // This drops the library
             *writer = None;
             debug!("Plugin {} unloaded dynamic linked library located at {}", self.id, self.path.display());
         }
+        if let Some(library_path) = self.library_path.write().unwrap().take() {
+            remove_private_copy(&library_path);
+        }
+        self.library_checksum.write().unwrap().take();
         self.dependencies = DashSet::new();
         if refreshing {
             self.state = PluginState::Refreshing(PluginRefreshingState::Uninstalling(PluginUninstallingState::UninstallDll));
@@ -195,6 +352,12 @@ impl PluginContainer {
             return NoChange;
         }
         let refreshing = self.state == PluginState::Refreshing(PluginRefreshingState::Uninstalling(PluginUninstallingState::UninstallDll));
+        if self.is_static() {
+            // There is no file to remove.
+            self.state = PluginState::Uninstalled;
+            return NoChange;
+        }
+        remove_package_files(&self.path);
         if !self.path.exists() {
             if refreshing {
                 self.state = PluginState::Refreshing(PluginRefreshingState::Deploying);
@@ -230,32 +393,87 @@ impl PluginContainer {
             return NoChange;
         }
         let refreshing = self.state == PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::Loaded));
-        let reader = self.library.read().unwrap();
-        if let Some(library) = reader.as_ref() {
-            let library = library.clone();
-            unsafe {
-                trace!("Plugin {} is reading dynamic linked library symbol plugin_declaration", self.id);
-                match library.get::<*mut PluginDeclaration>(b"plugin_declaration\0") {
-                    Ok(plugin_declaration) => {
-                        {
-                            let mut writer = self.plugin_declaration.write().unwrap();
-                            *writer = Some(plugin_declaration.read());
-                        }
-                        debug!("Plugin {} successfully loaded plugin declaration", self.id);
-                        if refreshing {
-                            self.state = PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::PluginDeclarationLoaded));
-                        } else {
-                            self.state = PluginState::Resolving(PluginResolveState::PluginDeclarationLoaded);
-                        }
-                        return Changed;
-                    }
-                    Err(e) => {
-                        error!("Plugin {} failed to get symbol plugin_declaration: {}", self.id, e);
-                    }
+        let plugin_api_version = self.read_plugin_api_version();
+        if plugin_api_version.as_deref() != Some(PLUGIN_API_VERSION) {
+            error!(
+                "Plugin {} is not compatible: Expected plugin_api_version {} - Actual {}",
+                self.id,
+                PLUGIN_API_VERSION,
+                plugin_api_version.as_deref().unwrap_or("unknown")
+            );
+            if refreshing {
+                self.state = PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::PluginApiVersionMismatch));
+            } else {
+                self.state = PluginState::Resolving(PluginResolveState::PluginApiVersionMismatch);
+            }
+            return Changed;
+        }
+        let Some(plugin_declaration) = self.read_plugin_declaration() else {
+            return NoChange;
+        };
+        {
+            let mut writer = self.plugin_declaration.write().unwrap();
+            *writer = Some(plugin_declaration);
+        }
+        debug!("Plugin {} successfully loaded plugin declaration", self.id);
+        if refreshing {
+            self.state = PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::PluginDeclarationLoaded));
+        } else {
+            self.state = PluginState::Resolving(PluginResolveState::PluginDeclarationLoaded);
+        }
+        Changed
+    }
+
+    /// Reads the plugin API version from the symbol `plugin_api_version` of the dynamic link library.
+    ///
+    /// The layout of the plugin declaration depends on the plugin API version, so the plugin
+    /// declaration must not be read before the plugin API version has been checked. Plugins which
+    /// have been compiled against an older plugin API don't export the symbol.
+    #[cfg(feature = "dynamic")]
+    fn read_plugin_api_version(&self) -> Option<String> {
+        if self.static_plugin_declaration.is_some() {
+            return Some(PLUGIN_API_VERSION.to_string());
+        }
+        let library = self.library.read().unwrap().as_ref().cloned()?;
+        unsafe {
+            trace!("Plugin {} is reading dynamic linked library symbol plugin_api_version", self.id);
+            match library.get::<*const &str>(b"plugin_api_version\0") {
+                Ok(plugin_api_version) => Some(plugin_api_version.read().to_string()),
+                Err(e) => {
+                    error!("Plugin {} failed to get symbol plugin_api_version: {}", self.id, e);
+                    None
                 }
             }
         }
-        NoChange
+    }
+
+    #[cfg(not(feature = "dynamic"))]
+    fn read_plugin_api_version(&self) -> Option<String> {
+        Some(PLUGIN_API_VERSION.to_string())
+    }
+
+    /// Reads the plugin declaration from the symbol `plugin_declaration` of the dynamic link library.
+    #[cfg(feature = "dynamic")]
+    fn read_plugin_declaration(&self) -> Option<PluginDeclaration> {
+        if self.static_plugin_declaration.is_some() {
+            return self.static_plugin_declaration;
+        }
+        let library = self.library.read().unwrap().as_ref().cloned()?;
+        unsafe {
+            trace!("Plugin {} is reading dynamic linked library symbol plugin_declaration", self.id);
+            match library.get::<*mut PluginDeclaration>(b"plugin_declaration\0") {
+                Ok(plugin_declaration) => Some(plugin_declaration.read()),
+                Err(e) => {
+                    error!("Plugin {} failed to get symbol plugin_declaration: {}", self.id, e);
+                    None
+                }
+            }
+        }
+    }
+
+    #[cfg(not(feature = "dynamic"))]
+    fn read_plugin_declaration(&self) -> Option<PluginDeclaration> {
+        self.static_plugin_declaration
     }
 
     /// Checks for compatibility.
@@ -293,6 +511,34 @@ impl PluginContainer {
                     }
                     return Changed;
                 }
+                let dependencies = unsafe { (plugin_declaration.get_dependencies)() };
+                let manifest_mismatch = self
+                    .manifest
+                    .read()
+                    .unwrap()
+                    .as_ref()
+                    .filter(|manifest| !manifest.matches(plugin_declaration.name, plugin_declaration.version, &dependencies))
+                    .map(|manifest| manifest.to_string());
+                if let Some(manifest) = manifest_mismatch {
+                    let dependencies: Vec<String> = dependencies
+                        .iter()
+                        .map(|dependency| format!("{} {}", dependency.name, dependency.version))
+                        .collect();
+                    error!(
+                        "Plugin {} doesn't match the manifest: Expected {} - Actual {} {} [{}]",
+                        self.id,
+                        manifest,
+                        plugin_declaration.name,
+                        plugin_declaration.version,
+                        dependencies.join(", ")
+                    );
+                    if refreshing {
+                        self.state = PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::ManifestMismatch));
+                    } else {
+                        self.state = PluginState::Resolving(PluginResolveState::ManifestMismatch);
+                    }
+                    return Changed;
+                }
                 debug!("Plugin {} is compatible with the rustc_version and the plugin_api_version)", self.id);
                 if refreshing {
                     self.state = PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::PluginCompatible));
@@ -339,8 +585,10 @@ impl PluginContainer {
     }
 
     /// Constructs the proxy for the plugin.
+    ///
+    /// If the plugin is isolated, the plugin is registered in a plugin host process instead.
     #[allow(clippy::collapsible_match)]
-    pub fn construct_proxy(&mut self, plugin_context: Arc<dyn PluginContext + Send + Sync>) -> PluginTransitionResult {
+    pub fn construct_proxy(&mut self, plugin_context: Arc<dyn PluginContext + Send + Sync>, isolation: Option<PluginIsolation>) -> PluginTransitionResult {
         if self.state != PluginState::Starting(PluginStartingState::ConstructingProxy)
             && self.state != PluginState::Refreshing(PluginRefreshingState::Starting(PluginStartingState::ConstructingProxy))
         {
@@ -348,8 +596,33 @@ impl PluginContainer {
         }
         let refreshing = self.state == PluginState::Refreshing(PluginRefreshingState::Starting(PluginStartingState::ConstructingProxy));
 
+        let isolation = match isolation {
+            Some(_) if self.is_static() => {
+                warn!("Plugin {} is linked statically and can't be isolated in a plugin host", self.id);
+                None
+            }
+            isolation => isolation,
+        };
         let reader = self.plugin_declaration.read().unwrap();
         if let Some(plugin_declaration) = *reader {
+            if let Some(isolation) = isolation {
+                trace!("Plugin {} is constructing proxy for the plugin host", self.id);
+                // The plugin host loads the verified private copy of the dynamic link library
+                let path = self.library_path.read().unwrap().clone().unwrap_or_else(|| self.path.clone());
+                let checksum = self.library_checksum.read().unwrap().clone();
+                let plugin = RemotePlugin::new(plugin_declaration.name.to_string(), path, checksum, isolation, plugin_context);
+                let mut writer = self.proxy.write().unwrap();
+                *writer = Some(Arc::new(PluginProxy {
+                    plugin: Box::new(Arc::new(plugin)),
+                }));
+                debug!("Plugin {} successfully constructed proxy for the plugin host", self.id);
+                if refreshing {
+                    self.state = PluginState::Refreshing(PluginRefreshingState::Starting(PluginStartingState::Registering));
+                } else {
+                    self.state = PluginState::Starting(PluginStartingState::Registering);
+                }
+                return Changed;
+            }
             trace!("Plugin {} is constructing proxy", self.id);
             let mut registrar = PluginRegistrar::new(plugin_context);
             unsafe {
@@ -429,6 +702,22 @@ impl PluginContainer {
         Changed
     }
 
+    /// Passes the changed configuration to the plugin, if the plugin is active.
+    pub async fn config_changed(&self, config: Value) {
+        if self.state != PluginState::Active {
+            return;
+        }
+        let proxy = {
+            let reader = self.proxy.read().unwrap();
+            let Some(proxy) = reader.as_ref().cloned() else {
+                return;
+            };
+            proxy
+        };
+        trace!("Plugin {} is being reconfigured", self.id);
+        proxy.config_changed(config).await;
+    }
+
     /// Calls the deactivate method of the plugin
     pub async fn deactivate(&mut self) -> PluginTransitionResult {
         if self.state != PluginState::Stopping(PluginStoppingState::Deactivating)
@@ -535,6 +824,9 @@ impl PluginContainer {
     }
 
     pub fn redeploy(&mut self) -> Result<(), PluginDeployError> {
+        if self.is_static() {
+            return Err(PluginDeployError::StaticallyLinked);
+        }
         match self.state {
             PluginState::Stopping(_) | PluginState::Starting(_) | PluginState::Uninstalling(_) => Err(PluginDeployError::InTransition),
             PluginState::Refreshing(PluginRefreshingState::Resolving(_)) => {
@@ -605,4 +897,222 @@ impl PluginContainer {
         let reader = self.plugin_declaration.read().unwrap();
         (*reader).map(|plugin_declaration| plugin_declaration.plugin_api_version.to_string())
     }
+
+    pub fn config_schema(&self) -> Option<String> {
+        let reader = self.plugin_declaration.read().unwrap();
+        (*reader).and_then(|plugin_declaration| unsafe { (plugin_declaration.get_config_schema)() }.map(str::to_string))
+    }
+
+    pub fn capabilities(&self) -> Option<PluginCapabilities> {
+        let reader = self.plugin_declaration.read().unwrap();
+        (*reader).and_then(|plugin_declaration| unsafe { (plugin_declaration.get_capabilities)() })
+    }
+}
+
+/// The prefix of the folders in the temporary directory which contain the private copies of
+/// the verified dynamic link libraries.
+#[cfg(feature = "dynamic")]
+const PRIVATE_COPY_FOLDER_PREFIX: &str = "reactive-graph-plugin-";
+
+/// Writes the verified dynamic link library into a new private folder of the plugin container.
+///
+/// The name of the folder is unpredictable and the folder must not exist yet, so the folder
+/// can't be prepared by another user.
+#[cfg(feature = "dynamic")]
+fn write_private_copy(path: &Path, library: &[u8]) -> std::io::Result<PathBuf> {
+    let folder = env::temp_dir().join(format!("{PRIVATE_COPY_FOLDER_PREFIX}{}", Uuid::new_v4().simple()));
+    create_private_folder(&folder)?;
+    let library_path = folder.join(path.file_name().unwrap_or(path.as_os_str()));
+    let mut file = fs::OpenOptions::new().write(true).create_new(true).open(&library_path)?;
+    file.write_all(library)?;
+    file.sync_all()?;
+    Ok(library_path)
+}
+
+/// Creates a folder which is owned by and only accessible by the current user.
+#[cfg(all(feature = "dynamic", unix))]
+fn create_private_folder(folder: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::DirBuilderExt;
+    use std::os::unix::fs::MetadataExt;
+
+    fs::DirBuilder::new().mode(0o700).create(folder)?;
+    let metadata = fs::symlink_metadata(folder)?;
+    let owner = unsafe { libc::geteuid() };
+    if !metadata.is_dir() || metadata.uid() != owner || metadata.mode() & 0o077 != 0 {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            format!("The folder {} is not private to the current user", folder.display()),
+        ));
+    }
+    Ok(())
+}
+
+/// Creates a folder in the temporary directory, which is private to the current user on this platform.
+#[cfg(all(feature = "dynamic", not(unix)))]
+fn create_private_folder(folder: &Path) -> std::io::Result<()> {
+    fs::create_dir(folder)
+}
+
+/// Verifies that the private copy of the dynamic link library still has the checksum of the
+/// verified dynamic link library.
+pub(crate) fn verify_private_copy(library_path: &Path, checksum: &str) -> std::io::Result<()> {
+    let actual = sha256_hex(&fs::read(library_path)?);
+    if actual != checksum {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("The checksum {actual} of {} doesn't match the verified checksum {checksum}", library_path.display()),
+        ));
+    }
+    Ok(())
+}
+
+/// Removes the private copy of the dynamic link library and its private folder.
+fn remove_private_copy(library_path: &Path) {
+    if let Err(e) = fs::remove_file(library_path) {
+        error!("Failed to remove {}: {:?}", library_path.display(), e);
+    }
+    if let Some(folder) = library_path.parent() {
+        let _ = fs::remove_dir(folder);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "dynamic")]
+    use std::fs;
+    use std::path::PathBuf;
+
+    use reactive_graph_plugin_api::PLUGIN_API_VERSION;
+    use reactive_graph_plugin_api::PluginCapabilities;
+    use reactive_graph_plugin_api::PluginDeclaration;
+    use reactive_graph_plugin_api::PluginDependency;
+    use reactive_graph_plugin_api::PluginDeployError;
+    use reactive_graph_plugin_api::PluginLoadingError;
+    use reactive_graph_plugin_api::PluginRegistrar;
+    use reactive_graph_plugin_api::PluginResolveState;
+    use reactive_graph_plugin_api::PluginState;
+    use reactive_graph_plugin_api::PluginUninstallingState;
+    use reactive_graph_plugin_api::RUSTC_VERSION;
+    use reactive_graph_plugin_service_api::MAX_PLUGIN_STATE_HISTORY;
+    use reactive_graph_plugin_service_api::PluginTransitionResult::Changed;
+    use reactive_graph_plugin_service_api::PluginTrustPolicy;
+    #[cfg(feature = "dynamic")]
+    use reactive_graph_plugin_service_api::sha256_hex;
+
+    use crate::PluginContainer;
+    #[cfg(feature = "dynamic")]
+    use crate::container::remove_private_copy;
+    #[cfg(feature = "dynamic")]
+    use crate::container::verify_private_copy;
+    #[cfg(feature = "dynamic")]
+    use crate::container::write_private_copy;
+
+    #[allow(improper_ctypes_definitions)]
+    extern "C" fn register(_registrar: &mut dyn PluginRegistrar) -> Result<(), PluginLoadingError> {
+        Ok(())
+    }
+
+    #[allow(improper_ctypes_definitions)]
+    extern "C" fn get_dependencies() -> Vec<PluginDependency> {
+        vec![PluginDependency::new("reactive-graph-plugin-base", ">=0.10.0")]
+    }
+
+    #[allow(improper_ctypes_definitions)]
+    extern "C" fn get_config_schema() -> Option<&'static str> {
+        None
+    }
+
+    #[allow(improper_ctypes_definitions)]
+    extern "C" fn get_capabilities() -> Option<PluginCapabilities> {
+        Some(PluginCapabilities::new().namespace("static"))
+    }
+
+    #[test]
+    fn static_plugin_container_test() {
+        let plugin_declaration = PluginDeclaration {
+            rustc_version: RUSTC_VERSION,
+            plugin_api_version: PLUGIN_API_VERSION,
+            name: "reactive-graph-plugin-static",
+            description: "Statically linked plugin",
+            version: "0.10.0",
+            register,
+            get_dependencies,
+            get_config_schema,
+            get_capabilities,
+        };
+        let mut container = PluginContainer::new_static(plugin_declaration);
+        assert!(container.is_static());
+        assert_eq!("reactive-graph-plugin-static", container.stem);
+        assert_eq!(PluginState::Installed, container.state);
+        assert!(container.name().is_none());
+
+        // The statically linked plugin runs through the same resolver states as a dynamically linked plugin
+        assert_eq!(Changed, container.load_dll(&PluginTrustPolicy::default()));
+        assert_eq!(PluginState::Resolving(PluginResolveState::Loaded), container.state);
+        assert_eq!(Changed, container.load_plugin_declaration());
+        assert_eq!(PluginState::Resolving(PluginResolveState::PluginDeclarationLoaded), container.state);
+        assert_eq!(Some("reactive-graph-plugin-static".to_string()), container.name());
+        assert_eq!(Some(PluginCapabilities::new().namespace("static")), container.capabilities());
+        assert_eq!(Changed, container.check_compatibility());
+        assert_eq!(PluginState::Resolving(PluginResolveState::PluginCompatible), container.state);
+        assert_eq!(Changed, container.load_plugin_dependencies());
+        assert_eq!(PluginState::Resolving(PluginResolveState::DependenciesNotActive), container.state);
+        assert_eq!(1, container.dependencies.len());
+
+        // A statically linked plugin can't be redeployed
+        assert!(matches!(container.redeploy(), Err(PluginDeployError::StaticallyLinked)));
+
+        // Uninstalling a statically linked plugin doesn't touch the file system
+        assert!(container.uninstall().is_ok());
+        assert_eq!(Changed, container.unload_dll());
+        assert_eq!(PluginState::Uninstalling(PluginUninstallingState::UninstallDll), container.state);
+        container.uninstall_dll();
+        assert_eq!(PluginState::Uninstalled, container.state);
+    }
+
+    #[test]
+    fn record_transition_test() {
+        let mut container = PluginContainer::new("test".to_string(), PathBuf::new());
+        container.record_transition(PluginState::Installed);
+        assert!(container.history.is_empty());
+        for _ in 0..MAX_PLUGIN_STATE_HISTORY {
+            container.state = PluginState::Resolving(PluginResolveState::Loaded);
+            container.record_transition(PluginState::Installed);
+        }
+        container.state = PluginState::Disabled;
+        container.record_transition(PluginState::Resolving(PluginResolveState::Loaded));
+        assert_eq!(MAX_PLUGIN_STATE_HISTORY, container.history.len());
+        let last = container.history.back().unwrap();
+        assert_eq!(PluginState::Resolving(PluginResolveState::Loaded), last.from);
+        assert_eq!(PluginState::Disabled, last.to);
+    }
+
+    #[cfg(feature = "dynamic")]
+    #[test]
+    fn private_copy_test() {
+        let path = PathBuf::from("plugins/installed/libreactive_graph_plugin_test.so");
+        let library = b"library".to_vec();
+        let checksum = sha256_hex(&library);
+        let library_path = write_private_copy(&path, &library).expect("Failed to write the private copy");
+        let other_library_path = write_private_copy(&path, &library).expect("Failed to write the private copy");
+        // Each private copy has its own unpredictable folder
+        assert_ne!(library_path.parent(), other_library_path.parent());
+        assert_eq!(Some(path.file_name().unwrap()), library_path.file_name());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            let metadata = fs::metadata(library_path.parent().unwrap()).unwrap();
+            assert_eq!(0o700, metadata.mode() & 0o777);
+        }
+        assert!(verify_private_copy(&library_path, &checksum).is_ok());
+
+        // The private copy has been modified after it has been verified
+        fs::write(&library_path, b"modified").unwrap();
+        assert!(verify_private_copy(&library_path, &checksum).is_err());
+
+        remove_private_copy(&library_path);
+        remove_private_copy(&other_library_path);
+        assert!(!library_path.parent().unwrap().exists());
+        assert!(!other_library_path.parent().unwrap().exists());
+    }
 }
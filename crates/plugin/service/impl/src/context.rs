@@ -16,6 +16,7 @@ use reactive_graph_plugin_api::FlowTypeImportExportManager;
 use reactive_graph_plugin_api::FlowTypeManager;
 use reactive_graph_plugin_api::FlowTypeProviderRegistry;
 use reactive_graph_plugin_api::GraphQLQueryService;
+use reactive_graph_plugin_api::NamespaceManager;
 use reactive_graph_plugin_api::PluginContext;
 use reactive_graph_plugin_api::RelationBehaviourRegistry;
 use reactive_graph_plugin_api::RelationComponentBehaviourRegistry;
@@ -40,6 +41,7 @@ pub struct PluginContextImpl {
     flow_type_manager: Arc<dyn FlowTypeManager + Send + Sync>,
     flow_type_import_export_manager: Arc<dyn FlowTypeImportExportManager + Send + Sync>,
     flow_type_provider_registry: Arc<dyn FlowTypeProviderRegistry + Send + Sync>,
+    namespace_manager: Arc<dyn NamespaceManager + Send + Sync>,
     type_system_event_manager: Arc<dyn TypeSystemEventManager + Send + Sync>,
     entity_instance_manager: Arc<dyn EntityInstanceManager + Send + Sync>,
     relation_instance_manager: Arc<dyn RelationInstanceManager + Send + Sync>,
@@ -69,6 +71,7 @@ impl PluginContextImpl {
         flow_type_manager: Arc<dyn FlowTypeManager + Send + Sync>,
         flow_type_import_export_manager: Arc<dyn FlowTypeImportExportManager + Send + Sync>,
         flow_type_provider_registry: Arc<dyn FlowTypeProviderRegistry + Send + Sync>,
+        namespace_manager: Arc<dyn NamespaceManager + Send + Sync>,
         type_system_event_manager: Arc<dyn TypeSystemEventManager + Send + Sync>,
         entity_instance_manager: Arc<dyn EntityInstanceManager + Send + Sync>,
         relation_instance_manager: Arc<dyn RelationInstanceManager + Send + Sync>,
@@ -95,6 +98,7 @@ impl PluginContextImpl {
             flow_type_manager,
             flow_type_import_export_manager,
             flow_type_provider_registry,
+            namespace_manager,
             type_system_event_manager,
             entity_instance_manager,
             relation_instance_manager,
@@ -160,6 +164,10 @@ impl PluginContext for PluginContextImpl {
         self.flow_type_provider_registry.clone()
     }
 
+    fn get_namespace_manager(&self) -> Arc<dyn NamespaceManager + Send + Sync> {
+        self.namespace_manager.clone()
+    }
+
     fn get_type_system_event_manager(&self) -> Arc<dyn TypeSystemEventManager + Send + Sync> {
         self.type_system_event_manager.clone()
     }
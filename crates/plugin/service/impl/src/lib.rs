@@ -1,19 +1,37 @@
 pub use container::*;
 pub use context::*;
+pub use plugin_config_manager_impl::*;
 pub use plugin_container_manager_impl::*;
 pub use plugin_context_factory_impl::*;
 pub use plugin_repository_manager_impl::*;
 pub use plugin_resolver_impl::*;
+pub use plugin_resource_manager_impl::*;
 pub use plugin_system_impl::*;
+pub use plugin_upgrade_manager_impl::*;
 pub use proxy::*;
 pub(crate) use registrar::*;
+pub use remote_behaviour::*;
+pub use remote_plugin::*;
+pub use restricted_context::*;
+pub use restricted_registries::*;
+pub use tracking_context::*;
+pub use tracking_registries::*;
 
 pub mod container;
 pub mod context;
+pub mod plugin_config_manager_impl;
 pub mod plugin_container_manager_impl;
 pub mod plugin_context_factory_impl;
 pub mod plugin_repository_manager_impl;
 pub mod plugin_resolver_impl;
+pub mod plugin_resource_manager_impl;
 pub mod plugin_system_impl;
+pub mod plugin_upgrade_manager_impl;
 pub mod proxy;
 pub mod registrar;
+pub mod remote_behaviour;
+pub mod remote_plugin;
+pub mod restricted_context;
+pub mod restricted_registries;
+pub mod tracking_context;
+pub mod tracking_registries;
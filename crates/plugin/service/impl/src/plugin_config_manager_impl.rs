@@ -0,0 +1,351 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use dashmap::DashMap;
+use log::debug;
+use log::error;
+use log::info;
+use log::trace;
+use notify::Event;
+use notify::EventKind::Access;
+use notify::EventKind::Modify;
+use notify::RecommendedWatcher;
+use notify::RecursiveMode;
+use notify::Watcher;
+use notify::event::AccessKind::Close;
+use notify::event::AccessMode::Write;
+use notify::event::ModifyKind;
+use serde_json::Value;
+use serde_json::json;
+use springtime_di::Component;
+use springtime_di::component_alias;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use reactive_graph_config_api::ConfigManager;
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_plugin_api::PluginConfigError;
+use reactive_graph_plugin_api::PluginState;
+use reactive_graph_plugin_service_api::PluginConfigManager;
+use reactive_graph_plugin_service_api::PluginContainerManager;
+use reactive_graph_plugin_service_api::get_stem;
+
+const CONFIG_FILE_EXTENSION: &str = "toml";
+
+pub type PluginConfigWatcher = RwLock<Option<RecommendedWatcher>>;
+
+fn create_plugin_configs() -> Arc<DashMap<Uuid, Value>> {
+    Arc::new(DashMap::new())
+}
+
+fn create_plugin_config_watcher() -> PluginConfigWatcher {
+    RwLock::new(None)
+}
+
+#[derive(Component)]
+pub struct PluginConfigManagerImpl {
+    plugin_container_manager: Arc<dyn PluginContainerManager + Send + Sync>,
+
+    config_manager: Arc<dyn ConfigManager + Send + Sync>,
+
+    /// The validated configurations of the plugins.
+    #[component(default = "create_plugin_configs")]
+    configs: Arc<DashMap<Uuid, Value>>,
+
+    #[component(default = "create_plugin_config_watcher")]
+    config_watcher: PluginConfigWatcher,
+}
+
+impl PluginConfigManagerImpl {
+    async fn create_config_watcher(&self) {
+        let plugin_container_manager = self.plugin_container_manager.clone();
+        let config_manager = self.config_manager.clone();
+        let configs = self.configs.clone();
+        let (tx, mut rx) = mpsc::channel::<notify::Result<Event>>(32);
+        tokio::spawn(async move {
+            trace!("Plugin Config Watcher started");
+            while let Some(r) = rx.recv().await {
+                match r {
+                    Ok(event) => {
+                        if event.kind != Access(Close(Write)) && !matches!(event.kind, Modify(ModifyKind::Name(_))) {
+                            continue;
+                        }
+                        trace!("Plugin Config Watcher: Detected file system activity: {event:?}");
+                        for path in event.paths {
+                            if !is_config_file(&path) {
+                                continue;
+                            }
+                            let Some(name) = get_stem(&path) else {
+                                continue;
+                            };
+                            let Some(id) = plugin_container_manager.get_id_by_name(&name) else {
+                                continue;
+                            };
+                            if plugin_container_manager.get_plugin_state(&id) != Some(PluginState::Active) {
+                                continue;
+                            }
+                            let previous_config = configs.get(&id).map(|config| config.value().clone());
+                            match load_config(&plugin_container_manager, &config_manager, &configs, &id) {
+                                Ok(config) => {
+                                    if previous_config.as_ref() == Some(&config) {
+                                        continue;
+                                    }
+                                    info!("[RECONFIGURED] {name}");
+                                    plugin_container_manager.config_changed(&id, config).await;
+                                }
+                                Err(e) => {
+                                    error!("Keeping the previous configuration of plugin {name}: {e}");
+                                }
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        error!("Plugin Config Watcher: Error: {e}");
+                    }
+                }
+            }
+            trace!("Plugin Config Watcher: Finished");
+        });
+        let watcher = notify::recommended_watcher(move |r: notify::Result<Event>| {
+            let tx = tx.clone();
+            futures::executor::block_on(async {
+                if let Err(e) = tx.send(r).await {
+                    trace!("SendError {e}");
+                }
+            });
+        })
+        .ok();
+        let mut writer = self.config_watcher.write().unwrap();
+        *writer = watcher;
+    }
+
+    fn destroy_config_watcher(&self) {
+        let mut writer = self.config_watcher.write().unwrap();
+        *writer = None;
+    }
+}
+
+#[async_trait]
+#[component_alias]
+impl PluginConfigManager for PluginConfigManagerImpl {
+    fn get_config_path(&self, id: &Uuid) -> Option<PathBuf> {
+        get_config_path(&self.plugin_container_manager, &self.config_manager, id)
+    }
+
+    fn get_config_schema(&self, id: &Uuid) -> Option<Value> {
+        self.plugin_container_manager
+            .config_schema(id)
+            .and_then(|config_schema| serde_json::from_str(&config_schema).ok())
+    }
+
+    fn get_config(&self, id: &Uuid) -> Option<Value> {
+        self.configs.get(id).map(|config| config.value().clone())
+    }
+
+    fn get_config_by_name(&self, name: &str) -> Option<Value> {
+        self.plugin_container_manager.get_id_by_name(name).and_then(|id| self.get_config(&id))
+    }
+
+    fn validate_config(&self, id: &Uuid, config: &Value) -> Result<(), PluginConfigError> {
+        validate_config(self.plugin_container_manager.config_schema(id).as_deref(), config)
+    }
+
+    fn load_config(&self, id: &Uuid) -> Result<Value, PluginConfigError> {
+        load_config(&self.plugin_container_manager, &self.config_manager, &self.configs, id)
+    }
+
+    fn remove_config(&self, id: &Uuid) {
+        self.configs.remove(id);
+    }
+
+    fn watch_config(&self) {
+        let config_location = self.config_manager.get_plugins_config().get_config_location();
+        if !config_location.is_dir() {
+            trace!("Not watching the plugin configuration folder {config_location:?} because it does not exist");
+            return;
+        }
+        let mut writer = self.config_watcher.write().unwrap();
+        if let Some(recommended_watcher) = writer.as_mut() {
+            match recommended_watcher.watch(&config_location, RecursiveMode::NonRecursive) {
+                Ok(_) => {
+                    trace!("Watching plugin configuration folder {config_location:?}");
+                }
+                Err(e) => {
+                    error!("Failed to watch plugin configuration folder {config_location:?}: {e}");
+                }
+            }
+        }
+    }
+
+    fn unwatch_config(&self) {
+        let config_location = self.config_manager.get_plugins_config().get_config_location();
+        let mut writer = self.config_watcher.write().unwrap();
+        if let Some(recommended_watcher) = writer.as_mut() {
+            let _ = recommended_watcher.unwatch(&config_location);
+        }
+    }
+}
+
+#[async_trait]
+impl Lifecycle for PluginConfigManagerImpl {
+    async fn init(&self) {
+        self.create_config_watcher().await;
+    }
+
+    async fn post_init(&self) {
+        self.watch_config();
+    }
+
+    async fn pre_shutdown(&self) {
+        self.unwatch_config();
+    }
+
+    async fn shutdown(&self) {
+        self.destroy_config_watcher();
+    }
+}
+
+fn is_config_file(path: &Path) -> bool {
+    path.extension().and_then(|extension| extension.to_str()) == Some(CONFIG_FILE_EXTENSION)
+}
+
+/// The configuration file of a plugin is named after the short name of the plugin.
+fn get_config_path(
+    plugin_container_manager: &Arc<dyn PluginContainerManager + Send + Sync>,
+    config_manager: &Arc<dyn ConfigManager + Send + Sync>,
+    id: &Uuid,
+) -> Option<PathBuf> {
+    let name = plugin_container_manager.name_canonicalized(id)?;
+    let config_location = config_manager.get_plugins_config().get_config_location();
+    Some(config_location.join(name).with_extension(CONFIG_FILE_EXTENSION))
+}
+
+fn load_config(
+    plugin_container_manager: &Arc<dyn PluginContainerManager + Send + Sync>,
+    config_manager: &Arc<dyn ConfigManager + Send + Sync>,
+    configs: &DashMap<Uuid, Value>,
+    id: &Uuid,
+) -> Result<Value, PluginConfigError> {
+    let path = get_config_path(plugin_container_manager, config_manager, id).ok_or(PluginConfigError::MissingPluginDeclaration)?;
+    let config = read_and_validate_config(&path, plugin_container_manager.config_schema(id).as_deref())?;
+    debug!("Plugin {} loaded the configuration from {}", id, path.display());
+    configs.insert(*id, config.clone());
+    Ok(config)
+}
+
+/// Reads the configuration file and validates the configuration against the JSON schema.
+fn read_and_validate_config(path: &Path, config_schema: Option<&str>) -> Result<Value, PluginConfigError> {
+    let config = read_config(path)?;
+    validate_config(config_schema, &config)?;
+    Ok(config)
+}
+
+/// Reads the configuration file. If the configuration file doesn't exist, the configuration is empty.
+fn read_config(path: &Path) -> Result<Value, PluginConfigError> {
+    if !path.exists() {
+        return Ok(json!({}));
+    }
+    let toml_string = fs::read_to_string(path).map_err(|e| PluginConfigError::ReadError(path.display().to_string(), e.to_string()))?;
+    toml::from_str(&toml_string).map_err(|e| PluginConfigError::ParseError(path.display().to_string(), e.to_string()))
+}
+
+/// Validates the configuration against the JSON schema. Plugins without a schema accept any configuration.
+fn validate_config(config_schema: Option<&str>, config: &Value) -> Result<(), PluginConfigError> {
+    let Some(config_schema) = config_schema else {
+        return Ok(());
+    };
+    let config_schema: Value = serde_json::from_str(config_schema).map_err(|e| PluginConfigError::InvalidSchema(e.to_string()))?;
+    let validator = jsonschema::validator_for(&config_schema).map_err(|e| PluginConfigError::InvalidSchema(e.to_string()))?;
+    let errors: Vec<String> = validator
+        .iter_errors(config)
+        .map(|error| match error.instance_path().as_str() {
+            "" => error.to_string(),
+            instance_path => format!("{instance_path}: {error}"),
+        })
+        .collect();
+    if !errors.is_empty() {
+        return Err(PluginConfigError::ValidationFailed(errors));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use reactive_graph_plugin_api::PluginConfigError;
+
+    use crate::plugin_config_manager_impl::read_and_validate_config;
+    use crate::plugin_config_manager_impl::read_config;
+    use crate::plugin_config_manager_impl::validate_config;
+
+    const CONFIG_SCHEMA: &str = r#"{
+        "type": "object",
+        "properties": {
+            "port": { "type": "integer", "minimum": 1 },
+            "host": { "type": "string" }
+        },
+        "required": ["port"]
+    }"#;
+
+    fn write_config(toml: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("{}.toml", Uuid::new_v4()));
+        fs::write(&path, toml).expect("Failed to write the configuration file");
+        path
+    }
+
+    #[test]
+    fn validate_config_test() {
+        assert!(validate_config(None, &json!({ "anything": true })).is_ok());
+        assert!(validate_config(Some(CONFIG_SCHEMA), &json!({ "port": 8080, "host": "localhost" })).is_ok());
+        let Err(PluginConfigError::ValidationFailed(errors)) = validate_config(Some(CONFIG_SCHEMA), &json!({ "port": 0, "host": 1 })) else {
+            panic!("The configuration should be invalid");
+        };
+        assert_eq!(2, errors.len());
+        assert!(errors.iter().any(|error| error.starts_with("/port: ")));
+        assert!(errors.iter().any(|error| error.starts_with("/host: ")));
+        assert!(matches!(validate_config(Some("{"), &json!({})), Err(PluginConfigError::InvalidSchema(_))));
+    }
+
+    #[test]
+    fn read_config_test() {
+        let path = write_config("port = 8080\nhost = \"localhost\"\n");
+        assert_eq!(json!({ "port": 8080, "host": "localhost" }), read_config(&path).unwrap());
+        fs::remove_file(&path).unwrap();
+
+        // A missing configuration file is an empty configuration
+        assert_eq!(json!({}), read_config(&path).unwrap());
+
+        let path = write_config("port = ");
+        assert!(matches!(read_config(&path), Err(PluginConfigError::ParseError(_, _))));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn read_and_validate_config_test() {
+        let path = write_config("port = 8080\n");
+        assert_eq!(json!({ "port": 8080 }), read_and_validate_config(&path, Some(CONFIG_SCHEMA)).unwrap());
+        fs::remove_file(&path).unwrap();
+
+        let path = write_config("port = \"8080\"\n");
+        assert!(matches!(read_and_validate_config(&path, Some(CONFIG_SCHEMA)), Err(PluginConfigError::ValidationFailed(_))));
+        fs::remove_file(&path).unwrap();
+
+        let path = write_config("[port");
+        assert!(matches!(read_and_validate_config(&path, Some(CONFIG_SCHEMA)), Err(PluginConfigError::ParseError(_, _))));
+        fs::remove_file(&path).unwrap();
+
+        // The empty configuration of a missing configuration file is validated as well
+        assert!(matches!(read_and_validate_config(&path, Some(CONFIG_SCHEMA)), Err(PluginConfigError::ValidationFailed(_))));
+        assert_eq!(json!({}), read_and_validate_config(&path, None).unwrap());
+    }
+}
@@ -8,12 +8,15 @@ use log::debug;
 use log::trace;
 use semver::Version;
 use semver::VersionReq;
+use serde_json::Value;
 use springtime_di::Component;
 use springtime_di::component_alias;
 use uuid::Uuid;
 
 use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_plugin_api::PluginCapabilities;
 use reactive_graph_plugin_api::PluginContext;
+use reactive_graph_plugin_api::PluginDeclaration;
 use reactive_graph_plugin_api::PluginDependency;
 use reactive_graph_plugin_api::PluginDeployError;
 use reactive_graph_plugin_api::PluginDisableError;
@@ -27,9 +30,12 @@ use reactive_graph_plugin_api::PluginStoppingState;
 use reactive_graph_plugin_api::PluginUninstallError;
 use reactive_graph_plugin_api::PluginUninstallingState;
 use reactive_graph_plugin_service_api::PluginContainerManager;
+use reactive_graph_plugin_service_api::PluginIsolation;
+use reactive_graph_plugin_service_api::PluginStateTransition;
 use reactive_graph_plugin_service_api::PluginTransitionResult;
 use reactive_graph_plugin_service_api::PluginTransitionResult::Changed;
 use reactive_graph_plugin_service_api::PluginTransitionResult::NoChange;
+use reactive_graph_plugin_service_api::PluginTrustPolicy;
 
 use crate::PluginContainer;
 
@@ -40,7 +46,41 @@ pub struct PluginContainerManagerImpl {
     pub plugin_containers: DashMap<Uuid, PluginContainer>,
 }
 
-impl PluginContainerManagerImpl {}
+impl PluginContainerManagerImpl {
+    /// Returns the plugins which are satisfying the given dependency.
+    ///
+    /// While a plugin is upgraded, both versions of the plugin may satisfy the dependency.
+    fn get_plugins_by_dependency(&self, plugin_dependency: &PluginDependency) -> Vec<Uuid> {
+        let Ok(version_requirement) = VersionReq::parse(plugin_dependency.version) else {
+            return Vec::new();
+        };
+        self.plugin_containers
+            .iter()
+            .filter(|e| {
+                let reader = e.plugin_declaration.read().unwrap();
+                match *reader {
+                    Some(plugin_declaration) => {
+                        plugin_declaration.name == plugin_dependency.name
+                            && Version::parse(plugin_declaration.version)
+                                .map(|version| version_requirement.matches(&version))
+                                .unwrap_or(false)
+                    }
+                    None => false,
+                }
+            })
+            .map(|e| *e.key())
+            .collect()
+    }
+
+    /// Applies a transition to the plugin container with the given id and records the state change.
+    fn transition<R>(&self, id: &Uuid, f: impl FnOnce(&mut PluginContainer) -> R) -> Option<R> {
+        let mut plugin_container = self.plugin_containers.get_mut(id)?;
+        let from = plugin_container.state;
+        let result = f(plugin_container.value_mut());
+        plugin_container.record_transition(from);
+        Some(result)
+    }
+}
 
 #[async_trait]
 #[component_alias]
@@ -57,6 +97,28 @@ impl PluginContainerManager for PluginContainerManagerImpl {
         Some(id)
     }
 
+    fn create_and_register_static_plugin_container(&self, plugin_declaration: PluginDeclaration) -> Option<Uuid> {
+        if self.has(plugin_declaration.name) {
+            return None;
+        }
+        trace!("Creating plugin container for statically linked plugin {}", plugin_declaration.name);
+        let plugin_container = PluginContainer::new_static(plugin_declaration);
+        let id = plugin_container.id;
+        trace!("Registering plugin container {} for statically linked plugin {}", &id, plugin_declaration.name);
+        self.plugin_containers.insert(id, plugin_container);
+        Some(id)
+    }
+
+    fn create_and_register_upgrade_container(&self, id: &Uuid, path: PathBuf) -> Option<Uuid> {
+        let stem = self.get_stem(id)?;
+        trace!("Creating plugin container for the upgrade of plugin {} located at {}", &stem, path.display());
+        let plugin_container = PluginContainer::new(stem.clone(), path);
+        let upgrade_id = plugin_container.id;
+        trace!("Registering plugin container {} for the upgrade of plugin {} {}", &upgrade_id, &stem, id);
+        self.plugin_containers.insert(upgrade_id, plugin_container);
+        Some(upgrade_id)
+    }
+
     fn remove_plugin_container(&self, id: &Uuid) {
         self.plugin_containers.remove(id);
     }
@@ -72,6 +134,13 @@ impl PluginContainerManager for PluginContainerManagerImpl {
             .map(|p| p.id)
     }
 
+    fn get_id_by_name(&self, name: &str) -> Option<Uuid> {
+        self.plugin_containers
+            .iter()
+            .find(|p| p.name().map(|n| n.eq(name)).unwrap_or(false) || p.name_canonicalized().map(|n| n.eq(name)).unwrap_or(false))
+            .map(|p| p.id)
+    }
+
     fn get_stem(&self, id: &Uuid) -> Option<String> {
         self.plugin_containers.get(id).map(|p| p.value().stem.clone())
     }
@@ -104,6 +173,14 @@ impl PluginContainerManager for PluginContainerManagerImpl {
         self.plugin_containers.get(id).and_then(|p| p.value().plugin_api_version())
     }
 
+    fn config_schema(&self, id: &Uuid) -> Option<String> {
+        self.plugin_containers.get(id).and_then(|p| p.value().config_schema())
+    }
+
+    fn capabilities(&self, id: &Uuid) -> Option<PluginCapabilities> {
+        self.plugin_containers.get(id).and_then(|p| p.value().capabilities())
+    }
+
     fn count(&self) -> usize {
         self.plugin_containers.len()
     }
@@ -115,10 +192,16 @@ impl PluginContainerManager for PluginContainerManagerImpl {
     fn count_by_states(&self) -> String {
         let states = [
             PluginState::Installed,
+            PluginState::Resolving(PluginResolveState::ManifestMissing),
+            PluginState::Resolving(PluginResolveState::ManifestInvalid),
+            PluginState::Resolving(PluginResolveState::SignatureMissing),
+            PluginState::Resolving(PluginResolveState::SignatureInvalid),
+            PluginState::Resolving(PluginResolveState::ChecksumMismatch),
             PluginState::Resolving(PluginResolveState::Loaded),
             PluginState::Resolving(PluginResolveState::PluginDeclarationLoaded),
             PluginState::Resolving(PluginResolveState::CompilerVersionMismatch),
             PluginState::Resolving(PluginResolveState::PluginApiVersionMismatch),
+            PluginState::Resolving(PluginResolveState::ManifestMismatch),
             PluginState::Resolving(PluginResolveState::DependenciesNotActive),
             PluginState::Resolving(PluginResolveState::PluginCompatible),
             PluginState::Resolved,
@@ -140,10 +223,16 @@ impl PluginContainerManager for PluginContainerManagerImpl {
             PluginState::Refreshing(PluginRefreshingState::Uninstalling(PluginUninstallingState::UninstallDll)),
             PluginState::Refreshing(PluginRefreshingState::Deploying),
             PluginState::Refreshing(PluginRefreshingState::Installed),
+            PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::ManifestMissing)),
+            PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::ManifestInvalid)),
+            PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::SignatureMissing)),
+            PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::SignatureInvalid)),
+            PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::ChecksumMismatch)),
             PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::Loaded)),
             PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::PluginDeclarationLoaded)),
             PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::CompilerVersionMismatch)),
             PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::PluginApiVersionMismatch)),
+            PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::ManifestMismatch)),
             PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::DependenciesNotActive)),
             PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::PluginCompatible)),
             PluginState::Refreshing(PluginRefreshingState::Starting(PluginStartingState::ConstructingProxy)),
@@ -171,6 +260,10 @@ impl PluginContainerManager for PluginContainerManagerImpl {
         self.plugin_containers.get(id).map(|e| e.state)
     }
 
+    fn get_state_history(&self, id: &Uuid) -> Vec<PluginStateTransition> {
+        self.plugin_containers.get(id).map(|e| e.history.iter().copied().collect()).unwrap_or_default()
+    }
+
     fn get_plugins(&self) -> Vec<Uuid> {
         self.plugin_containers.iter().map(|p| *p.key()).collect()
     }
@@ -210,60 +303,73 @@ impl PluginContainerManager for PluginContainerManagerImpl {
             .map(|e| *e.key())
     }
 
+    fn get_versions_by_name(&self, name: &str) -> Vec<String> {
+        let mut versions: Vec<String> = self
+            .plugin_containers
+            .iter()
+            .filter_map(|e| {
+                let reader = e.plugin_declaration.read().unwrap();
+                match *reader {
+                    Some(plugin_declaration) if plugin_declaration.name == name => Some(plugin_declaration.version.to_string()),
+                    _ => None,
+                }
+            })
+            .collect();
+        versions.sort();
+        versions.dedup();
+        versions
+    }
+
     fn deploy_dll(&self, id: &Uuid) -> PluginTransitionResult {
-        match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => {
-                trace!("Plugin {id} is deploying the dynamic linked library");
-                plugin_container.deploy_dll()
-            }
-            None => NoChange,
-        }
+        self.transition(id, |plugin_container| {
+            trace!("Plugin {id} is deploying the dynamic linked library");
+            plugin_container.deploy_dll()
+        })
+        .unwrap_or(NoChange)
     }
 
-    fn load_dll(&self, id: &Uuid) -> PluginTransitionResult {
-        match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => {
-                trace!("Plugin {id} is loading the dynamic linked library");
-                plugin_container.load_dll()
-            }
-            None => NoChange,
-        }
+    fn load_dll(&self, id: &Uuid, trust_policy: &PluginTrustPolicy) -> PluginTransitionResult {
+        self.transition(id, |plugin_container| {
+            trace!("Plugin {id} is loading the dynamic linked library");
+            plugin_container.load_dll(trust_policy)
+        })
+        .unwrap_or(NoChange)
     }
 
     fn load_plugin_declaration(&self, id: &Uuid) -> PluginTransitionResult {
-        match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => {
-                trace!("Plugin {id} is loading the plugin declaration");
-                plugin_container.value_mut().load_plugin_declaration()
-            }
-            None => NoChange,
-        }
+        self.transition(id, |plugin_container| {
+            trace!("Plugin {id} is loading the plugin declaration");
+            plugin_container.load_plugin_declaration()
+        })
+        .unwrap_or(NoChange)
     }
 
     fn check_plugin_compatibility(&self, id: &Uuid) -> PluginTransitionResult {
-        match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => {
-                trace!("Plugin {id} is checked for compatibility");
-                plugin_container.value_mut().check_compatibility()
-            }
-            None => NoChange,
-        }
+        self.transition(id, |plugin_container| {
+            trace!("Plugin {id} is checked for compatibility");
+            plugin_container.check_compatibility()
+        })
+        .unwrap_or(NoChange)
     }
 
     fn load_plugin_dependencies(&self, id: &Uuid) -> PluginTransitionResult {
-        match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => {
-                trace!("Plugin {id} is loading the list of dependencies");
-                plugin_container.value_mut().load_plugin_dependencies()
-            }
-            None => NoChange,
-        }
+        self.transition(id, |plugin_container| {
+            trace!("Plugin {id} is loading the list of dependencies");
+            plugin_container.load_plugin_dependencies()
+        })
+        .unwrap_or(NoChange)
     }
 
     fn get_dependency_state(&self, dependency: &PluginDependency) -> PluginState {
-        self.get_plugin_by_dependency(dependency)
-            .and_then(|id| self.get_plugin_state(&id))
-            .unwrap_or(PluginState::Uninstalled)
+        let mut dependency_state = PluginState::Uninstalled;
+        for id in self.get_plugins_by_dependency(dependency) {
+            match self.get_plugin_state(&id) {
+                Some(PluginState::Active) => return PluginState::Active,
+                Some(state) => dependency_state = state,
+                None => {}
+            }
+        }
+        dependency_state
     }
 
     fn has_dependencies(&self, id: &Uuid) -> bool {
@@ -307,7 +413,9 @@ impl PluginContainerManager for PluginContainerManagerImpl {
         match self.plugin_containers.get_mut(id) {
             Some(mut plugin_container) => {
                 if plugin_container.state != new_state {
+                    let from = plugin_container.state;
                     plugin_container.state = new_state;
+                    plugin_container.record_transition(from);
                     return Changed;
                 }
                 NoChange
@@ -336,11 +444,9 @@ impl PluginContainerManager for PluginContainerManagerImpl {
         }
     }
 
-    fn construct_proxy(&self, id: &Uuid, plugin_context: Arc<dyn PluginContext + Send + Sync>) -> PluginTransitionResult {
-        match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => plugin_container.construct_proxy(plugin_context.clone()),
-            None => NoChange,
-        }
+    fn construct_proxy(&self, id: &Uuid, plugin_context: Arc<dyn PluginContext + Send + Sync>, isolation: Option<PluginIsolation>) -> PluginTransitionResult {
+        self.transition(id, |plugin_container| plugin_container.construct_proxy(plugin_context.clone(), isolation))
+            .unwrap_or(NoChange)
     }
 
     fn register(&self, id: &Uuid) -> PluginTransitionResult {
@@ -351,12 +457,14 @@ impl PluginContainerManager for PluginContainerManagerImpl {
                 {
                     return NoChange;
                 }
+                let from = plugin_container.state;
                 let refreshing = plugin_container.state == PluginState::Refreshing(PluginRefreshingState::Starting(PluginStartingState::Registering));
                 if refreshing {
                     plugin_container.state = PluginState::Refreshing(PluginRefreshingState::Starting(PluginStartingState::Activating));
                 } else {
                     plugin_container.state = PluginState::Starting(PluginStartingState::Activating);
                 }
+                plugin_container.record_transition(from);
                 Changed
             }
             None => NoChange,
@@ -365,11 +473,22 @@ impl PluginContainerManager for PluginContainerManagerImpl {
 
     async fn activate(&self, id: &Uuid) -> PluginTransitionResult {
         match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => plugin_container.activate().await,
+            Some(mut plugin_container) => {
+                let from = plugin_container.state;
+                let result = plugin_container.activate().await;
+                plugin_container.record_transition(from);
+                result
+            }
             None => NoChange,
         }
     }
 
+    async fn config_changed(&self, id: &Uuid, config: Value) {
+        if let Some(plugin_container) = self.plugin_containers.get(id) {
+            plugin_container.config_changed(config).await;
+        }
+    }
+
     fn are_all_stopped(&self) -> bool {
         self.plugin_containers.iter().all(|p| match p.state {
             PluginState::Installed => true,
@@ -392,7 +511,12 @@ impl PluginContainerManager for PluginContainerManagerImpl {
 
     async fn deactivate(&self, id: &Uuid) -> PluginTransitionResult {
         match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => plugin_container.deactivate().await,
+            Some(mut plugin_container) => {
+                let from = plugin_container.state;
+                let result = plugin_container.deactivate().await;
+                plugin_container.record_transition(from);
+                result
+            }
             None => NoChange,
         }
     }
@@ -405,12 +529,14 @@ impl PluginContainerManager for PluginContainerManagerImpl {
                 {
                     return NoChange;
                 }
+                let from = plugin_container.state;
                 let refreshing = plugin_container.state == PluginState::Refreshing(PluginRefreshingState::Stopping(PluginStoppingState::Unregistering));
                 if refreshing {
                     plugin_container.state = PluginState::Refreshing(PluginRefreshingState::Stopping(PluginStoppingState::RemoveProxy));
                 } else {
                     plugin_container.state = PluginState::Stopping(PluginStoppingState::RemoveProxy);
                 }
+                plugin_container.record_transition(from);
                 Changed
             }
             None => NoChange,
@@ -418,17 +544,11 @@ impl PluginContainerManager for PluginContainerManagerImpl {
     }
 
     fn remove_proxy(&self, id: &Uuid) -> PluginTransitionResult {
-        match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => plugin_container.remove_proxy(),
-            None => NoChange,
-        }
+        self.transition(id, |plugin_container| plugin_container.remove_proxy()).unwrap_or(NoChange)
     }
 
     fn unload_dll(&self, id: &Uuid) -> PluginTransitionResult {
-        match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => plugin_container.value_mut().unload_dll(),
-            None => NoChange,
-        }
+        self.transition(id, |plugin_container| plugin_container.unload_dll()).unwrap_or(NoChange)
     }
 
     fn uninstall_dll(&self, id: &Uuid) -> PluginTransitionResult {
@@ -445,10 +565,8 @@ impl PluginContainerManager for PluginContainerManagerImpl {
     }
 
     fn start(&self, id: &Uuid) -> Result<(), PluginStartError> {
-        match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => plugin_container.start(),
-            None => Err(PluginStartError::Uninstalled),
-        }
+        self.transition(id, |plugin_container| plugin_container.start())
+            .unwrap_or(Err(PluginStartError::Uninstalled))
     }
 
     fn start_by_stem(&self, stem: &str) -> Result<(), PluginStartError> {
@@ -485,10 +603,9 @@ impl PluginContainerManager for PluginContainerManagerImpl {
     }
 
     fn stop(&self, id: &Uuid) -> Result<(), PluginStopError> {
-        let result = match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => plugin_container.stop(),
-            None => Err(PluginStopError::Uninstalled),
-        };
+        let result = self
+            .transition(id, |plugin_container| plugin_container.stop())
+            .unwrap_or(Err(PluginStopError::Uninstalled));
         while self.stop_active_with_unsatisfied_dependencies() {}
         result
     }
@@ -520,24 +637,18 @@ impl PluginContainerManager for PluginContainerManagerImpl {
     }
 
     fn uninstall(&self, id: &Uuid) -> Result<(), PluginUninstallError> {
-        match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => plugin_container.uninstall(),
-            None => Err(PluginUninstallError::AlreadyUninstalled),
-        }
+        self.transition(id, |plugin_container| plugin_container.uninstall())
+            .unwrap_or(Err(PluginUninstallError::AlreadyUninstalled))
     }
 
     fn redeploy(&self, id: &Uuid) -> Result<(), PluginDeployError> {
-        match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => plugin_container.redeploy(),
-            None => Err(PluginDeployError::NotFound),
-        }
+        self.transition(id, |plugin_container| plugin_container.redeploy())
+            .unwrap_or(Err(PluginDeployError::NotFound))
     }
 
     fn disable(&self, id: &Uuid) -> Result<(), PluginDisableError> {
-        match self.plugin_containers.get_mut(id) {
-            Some(mut plugin_container) => plugin_container.disable(),
-            None => Err(PluginDisableError::NotFound),
-        }
+        self.transition(id, |plugin_container| plugin_container.disable())
+            .unwrap_or(Err(PluginDisableError::NotFound))
     }
 }
 
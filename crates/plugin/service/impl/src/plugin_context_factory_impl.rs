@@ -2,8 +2,10 @@ use std::sync::Arc;
 use std::sync::RwLock;
 
 use async_trait::async_trait;
+use dashmap::DashMap;
 use springtime_di::Component;
 use springtime_di::component_alias;
+use uuid::Uuid;
 
 use reactive_graph_behaviour_service_api::EntityBehaviourManager;
 use reactive_graph_behaviour_service_api::EntityBehaviourRegistry;
@@ -17,6 +19,8 @@ use reactive_graph_command_api::CommandManager;
 use reactive_graph_config_api::ConfigManager;
 use reactive_graph_graphql_api::GraphQLQueryService;
 use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_plugin_api::PluginCapabilities;
+use reactive_graph_plugin_api::PluginCapabilityViolation;
 use reactive_graph_plugin_api::PluginContext;
 use reactive_graph_plugin_delegates::CommandManagerDelegate;
 use reactive_graph_plugin_delegates::ComponentImportExportManagerDelegate;
@@ -34,6 +38,7 @@ use reactive_graph_plugin_delegates::FlowTypeImportExportManagerDelegate;
 use reactive_graph_plugin_delegates::FlowTypeManagerDelegate;
 use reactive_graph_plugin_delegates::FlowTypeProviderRegistryDelegate;
 use reactive_graph_plugin_delegates::GraphQLQueryServiceDelegate;
+use reactive_graph_plugin_delegates::NamespaceManagerDelegate;
 use reactive_graph_plugin_delegates::RelationBehaviourRegistryDelegate;
 use reactive_graph_plugin_delegates::RelationComponentBehaviourRegistryDelegate;
 use reactive_graph_plugin_delegates::RelationInstanceManagerDelegate;
@@ -42,6 +47,7 @@ use reactive_graph_plugin_delegates::RelationTypeManagerDelegate;
 use reactive_graph_plugin_delegates::RelationTypeProviderRegistryDelegate;
 use reactive_graph_plugin_delegates::TypeSystemEventManagerDelegate;
 use reactive_graph_plugin_delegates::WebResourceManagerDelegate;
+use reactive_graph_plugin_service_api::PluginConfigManager;
 use reactive_graph_plugin_service_api::PluginContextFactory;
 use reactive_graph_reactive_service_api::ReactiveEntityManager;
 use reactive_graph_reactive_service_api::ReactiveFlowManager;
@@ -56,12 +62,15 @@ use reactive_graph_type_system_api::EntityTypeProviderRegistry;
 use reactive_graph_type_system_api::FlowTypeImportExportManager;
 use reactive_graph_type_system_api::FlowTypeManager;
 use reactive_graph_type_system_api::FlowTypeProviderRegistry;
+use reactive_graph_type_system_api::NamespaceManager;
 use reactive_graph_type_system_api::RelationTypeImportExportManager;
 use reactive_graph_type_system_api::RelationTypeManager;
 use reactive_graph_type_system_api::RelationTypeProviderRegistry;
 use reactive_graph_type_system_api::TypeSystemEventManager;
 
+use crate::PluginCapabilityGuard;
 use crate::PluginContextImpl;
+use crate::RestrictedPluginContext;
 
 pub type PluginContextStorage = RwLock<Option<Arc<dyn PluginContext + Send + Sync>>>;
 
@@ -69,6 +78,10 @@ fn create_plugin_context_storage() -> PluginContextStorage {
     RwLock::new(None)
 }
 
+fn create_capability_guards() -> DashMap<Uuid, Arc<PluginCapabilityGuard>> {
+    DashMap::new()
+}
+
 #[derive(Component)]
 pub struct PluginContextFactoryImpl {
     // Type System
@@ -84,6 +97,7 @@ pub struct PluginContextFactoryImpl {
     flow_type_manager: Arc<dyn FlowTypeManager + Send + Sync>,
     flow_type_import_export_manager: Arc<dyn FlowTypeImportExportManager + Send + Sync>,
     flow_type_provider_registry: Arc<dyn FlowTypeProviderRegistry + Send + Sync>,
+    namespace_manager: Arc<dyn NamespaceManager + Send + Sync>,
     type_system_event_manager: Arc<dyn TypeSystemEventManager + Send + Sync>,
     // Instance System
     reactive_entity_manager: Arc<dyn ReactiveEntityManager + Send + Sync>,
@@ -104,17 +118,22 @@ pub struct PluginContextFactoryImpl {
     web_resource_manager: Arc<dyn WebResourceManager + Send + Sync>,
     // System Services
     config_manager: Arc<dyn ConfigManager + Send + Sync>,
+    plugin_config_manager: Arc<dyn PluginConfigManager + Send + Sync>,
     command_manager: Arc<dyn CommandManager + Send + Sync>,
 
     /// The plugin context.
     #[component(default = "create_plugin_context_storage")]
     pub plugin_context: PluginContextStorage,
+
+    /// The capability guards of the plugins which have declared their capabilities.
+    #[component(default = "create_capability_guards")]
+    capability_guards: DashMap<Uuid, Arc<PluginCapabilityGuard>>,
 }
 
-#[async_trait]
-#[component_alias]
-impl PluginContextFactory for PluginContextFactoryImpl {
-    fn construct_plugin_context(&self) {
+impl PluginContextFactoryImpl {
+    /// Creates a plugin context. If the name of a plugin is given, the namespaces registered via the
+    /// plugin context are owned by the plugin and the plugin can only read its own configuration.
+    fn create_plugin_context(&self, name: Option<&str>) -> Arc<dyn PluginContext + Send + Sync> {
         // Type System
         let component_manager = ComponentManagerDelegate::new(self.component_manager.clone());
         let component_import_export_manager = ComponentImportExportManagerDelegate::new(self.component_import_export_manager.clone());
@@ -128,6 +147,10 @@ impl PluginContextFactory for PluginContextFactoryImpl {
         let flow_type_manager = FlowTypeManagerDelegate::new(self.flow_type_manager.clone());
         let flow_type_import_export_manager = FlowTypeImportExportManagerDelegate::new(self.flow_type_import_export_manager.clone());
         let flow_type_provider_registry = FlowTypeProviderRegistryDelegate::new(&self.flow_type_provider_registry);
+        let namespace_manager = match name {
+            Some(name) => NamespaceManagerDelegate::owned(self.namespace_manager.clone(), name),
+            None => NamespaceManagerDelegate::new(self.namespace_manager.clone()),
+        };
         // Instance System
         let entity_instance_manager =
             EntityInstanceManagerDelegate::new(self.component_manager.clone(), self.entity_type_manager.clone(), self.reactive_entity_manager.clone());
@@ -159,7 +182,10 @@ impl PluginContextFactory for PluginContextFactoryImpl {
         let graphql_query_service = GraphQLQueryServiceDelegate::new(self.graphql_query_service.clone());
         let web_resource_manager = WebResourceManagerDelegate::new(self.web_resource_manager.clone());
         // System Services
-        let config_manager = ConfigManagerDelegate::new(self.config_manager.clone());
+        let config_manager = match name {
+            Some(name) => ConfigManagerDelegate::owned(self.config_manager.clone(), self.plugin_config_manager.clone(), name),
+            None => ConfigManagerDelegate::new(self.config_manager.clone(), self.plugin_config_manager.clone()),
+        };
         let type_system_event_manager = TypeSystemEventManagerDelegate::new(self.type_system_event_manager.clone());
         let command_manager = CommandManagerDelegate::new(self.command_manager.clone());
         let plugin_context = PluginContextImpl::new(
@@ -175,6 +201,7 @@ impl PluginContextFactory for PluginContextFactoryImpl {
             Arc::new(flow_type_manager),
             Arc::new(flow_type_import_export_manager),
             Arc::new(flow_type_provider_registry),
+            Arc::new(namespace_manager),
             Arc::new(type_system_event_manager),
             Arc::new(entity_instance_manager),
             Arc::new(relation_instance_manager),
@@ -188,11 +215,23 @@ impl PluginContextFactory for PluginContextFactoryImpl {
             Arc::new(web_resource_manager),
             Arc::new(command_manager),
         );
-        let plugin_context = Arc::new(plugin_context);
+        Arc::new(plugin_context)
+    }
+}
+
+#[async_trait]
+#[component_alias]
+impl PluginContextFactory for PluginContextFactoryImpl {
+    fn construct_plugin_context(&self) {
+        let plugin_context = self.create_plugin_context(None);
         let mut writer = self.plugin_context.write().unwrap();
         let _ = writer.insert(plugin_context);
     }
 
+    fn create(&self, name: &str) -> Arc<dyn PluginContext + Send + Sync> {
+        self.create_plugin_context(Some(name))
+    }
+
     fn get(&self) -> Option<Arc<dyn PluginContext + Send + Sync>> {
         let reader = self.plugin_context.read().unwrap();
         if let Some(plugin_context) = reader.as_ref() {
@@ -200,6 +239,30 @@ impl PluginContextFactory for PluginContextFactoryImpl {
         }
         None
     }
+
+    fn restrict(
+        &self,
+        id: &Uuid,
+        name: &str,
+        plugin_context: Arc<dyn PluginContext + Send + Sync>,
+        capabilities: Option<PluginCapabilities>,
+    ) -> Arc<dyn PluginContext + Send + Sync> {
+        let capabilities = match capabilities {
+            Some(capabilities) => capabilities,
+            None if self.config_manager.get_plugins_config().is_deny_undeclared_capabilities() => PluginCapabilities::new(),
+            None => {
+                self.capability_guards.remove(id);
+                return plugin_context;
+            }
+        };
+        let guard = Arc::new(PluginCapabilityGuard::new(*id, name.to_string(), capabilities));
+        self.capability_guards.insert(*id, guard.clone());
+        Arc::new(RestrictedPluginContext::new(plugin_context, guard))
+    }
+
+    fn get_capability_violations(&self, id: &Uuid) -> Vec<PluginCapabilityViolation> {
+        self.capability_guards.get(id).map(|guard| guard.get_violations()).unwrap_or_default()
+    }
 }
 
 #[async_trait]
@@ -36,11 +36,16 @@ use reactive_graph_plugin_api::PluginState;
 use reactive_graph_plugin_service_api::PluginContainerManager;
 use reactive_graph_plugin_service_api::PluginRepositoryManager;
 use reactive_graph_plugin_service_api::PluginResolver;
+use reactive_graph_plugin_service_api::PluginUpgradeManager;
 
 use flate2::read::GzDecoder;
 use reactive_graph_plugin_service_api::get_deploy_folder;
 use reactive_graph_plugin_service_api::get_install_path;
+use reactive_graph_plugin_service_api::get_package_dll_path;
 use reactive_graph_plugin_service_api::get_stem;
+use reactive_graph_plugin_service_api::is_package_complete;
+use reactive_graph_plugin_service_api::move_package_files;
+use reactive_graph_plugin_service_api::remove_package_files;
 use tar::Archive;
 use zip::ZipArchive;
 
@@ -61,6 +66,8 @@ pub struct PluginRepositoryManagerImpl {
 
     plugin_resolver: Arc<dyn PluginResolver + Send + Sync>,
 
+    plugin_upgrade_manager: Arc<dyn PluginUpgradeManager + Send + Sync>,
+
     config_manager: Arc<dyn ConfigManager + Send + Sync>,
 
     #[component(default = "create_hot_deploy_watcher")]
@@ -81,6 +88,8 @@ impl PluginRepositoryManagerImpl {
     async fn create_hot_deploy_watcher(&self) {
         let plugin_container_manager = self.plugin_container_manager.clone();
         let plugin_resolver = self.plugin_resolver.clone();
+        let plugin_upgrade_manager = self.plugin_upgrade_manager.clone();
+        let config_manager = self.config_manager.clone();
         let (tx, mut rx) = mpsc::channel::<notify::Result<Event>>(32);
         tokio::spawn(async move {
             trace!("Hot Deploy Watcher started");
@@ -106,12 +115,32 @@ impl PluginRepositoryManagerImpl {
                                 }
                                 continue;
                             }
+                            // Deploy the dynamic link library when its manifest or signature arrives
+                            let path = match get_package_dll_path(&path) {
+                                Some(dll_path) if dll_path.exists() => dll_path,
+                                Some(_) => continue,
+                                None => path,
+                            };
                             if !is_dll(&path) {
                                 continue;
                             }
+                            let require_signature = config_manager.get_plugins_config().is_signature_required();
+                            if !is_package_complete(&path, require_signature) {
+                                debug!("Waiting for the manifest and the signature of {}", path.display());
+                                continue;
+                            }
                             if plugin_container_manager.has(&stem) {
                                 // If plugin with the same stem is already installed, redeploy and start resolver
                                 if let Some(id) = plugin_container_manager.get_id(&stem) {
+                                    // Upgrade active plugins side by side, if hot upgrade is enabled
+                                    if config_manager.get_plugins_config().is_hot_upgrade()
+                                        && plugin_container_manager.get_plugin_state(&id) == Some(PluginState::Active)
+                                    {
+                                        if let Err(e) = plugin_upgrade_manager.upgrade(&id, &path).await {
+                                            error!("Failed to upgrade plugin {} {}: {}", &stem, &id, e);
+                                        }
+                                        continue;
+                                    }
                                     match plugin_container_manager.redeploy(&id) {
                                         Ok(_) => {
                                             plugin_resolver.resolve_until_idle().await;
@@ -129,7 +158,7 @@ impl PluginRepositoryManagerImpl {
                                 }
                             } else {
                                 // Deploy new plugins to the installation folder
-                                if let Ok(install_path) = deploy_plugin(path) {
+                                if let Ok(install_path) = deploy_plugin(path, require_signature) {
                                     // And register a new plugin container and start resolver
                                     if let Some(id) = plugin_container_manager.create_and_register_plugin_container(stem, install_path) {
                                         plugin_resolver.resolve_until_idle().await;
@@ -234,6 +263,7 @@ impl PluginRepositoryManager for PluginRepositoryManagerImpl {
         let Some(hot_deploy_location) = plugins_config.get_hot_deploy_location() else {
             return;
         };
+        let require_signature = plugins_config.is_signature_required();
         trace!("Scanning plugin hot deploy folder {hot_deploy_location:?}");
         let Ok(dir) = fs::read_dir(hot_deploy_location) else {
             return;
@@ -255,7 +285,7 @@ impl PluginRepositoryManager for PluginRepositoryManagerImpl {
                     }
                     continue;
                 }
-                let _ = deploy_plugin(path);
+                let _ = deploy_plugin(path, require_signature);
             }
         }
     }
@@ -304,6 +334,7 @@ impl PluginRepositoryManager for PluginRepositoryManagerImpl {
         }
         for plugin_to_remove in plugins_to_remove {
             if fs::remove_file(&plugin_to_remove).is_ok() {
+                remove_package_files(&plugin_to_remove);
                 trace!("Removed duplicate plugin: {}", plugin_to_remove.display());
             }
         }
@@ -442,11 +473,15 @@ fn extract_zip(archive_path: &Path) -> Result<(), HotDeployError> {
     Ok(())
 }
 
-fn deploy_plugin(deploy_path: PathBuf) -> Result<PathBuf, HotDeployError> {
+fn deploy_plugin(deploy_path: PathBuf, require_signature: bool) -> Result<PathBuf, HotDeployError> {
     debug!("Detected new plugin {}", deploy_path.display());
     if !is_dll(&deploy_path) {
         return Err(HotDeployError::NoDynamicLinkLibrary);
     }
+    if !is_package_complete(&deploy_path, require_signature) {
+        warn!("Failed to deploy plugin: The manifest or the signature of {} is missing", deploy_path.display());
+        return Err(HotDeployError::PackageIncomplete);
+    }
     let Some(install_path) = get_install_path(&deploy_path) else {
         return Err(HotDeployError::InvalidInstallPath);
     };
@@ -468,6 +503,7 @@ fn deploy_plugin(deploy_path: PathBuf) -> Result<PathBuf, HotDeployError> {
     match fs::remove_file(&deploy_path) {
         Ok(_) => {
             debug!("Removed plugin from {}", deploy_path.display());
+            move_package_files(&deploy_path, &install_path);
             Ok(install_path)
         }
         Err(e) => {
@@ -3,6 +3,7 @@ use std::sync::RwLock;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use log::error;
 use log::info;
 use log::trace;
 use log::warn;
@@ -19,13 +20,21 @@ use reactive_graph_plugin_api::PluginStartingState;
 use reactive_graph_plugin_api::PluginState;
 use reactive_graph_plugin_api::PluginStoppingState;
 use reactive_graph_plugin_api::PluginUninstallingState;
+use reactive_graph_plugin_service_api::PluginConfigManager;
 use reactive_graph_plugin_service_api::PluginContainerManager;
 use reactive_graph_plugin_service_api::PluginContextFactory;
+use reactive_graph_plugin_service_api::PluginDependencyDiagnostics;
+use reactive_graph_plugin_service_api::PluginDiagnostics;
+use reactive_graph_plugin_service_api::PluginIsolation;
 use reactive_graph_plugin_service_api::PluginResolver;
+use reactive_graph_plugin_service_api::PluginResolverDiagnostics;
 use reactive_graph_plugin_service_api::PluginResolverMode;
+use reactive_graph_plugin_service_api::PluginResourceManager;
 use reactive_graph_plugin_service_api::PluginTransitionResult;
 use reactive_graph_plugin_service_api::PluginTransitionResult::Changed;
 use reactive_graph_plugin_service_api::PluginTransitionResult::NoChange;
+use reactive_graph_plugin_service_api::PluginTrustPolicy;
+use reactive_graph_type_system_api::NamespaceManager;
 
 const MAX_ITERATIONS: u32 = 1000;
 
@@ -41,8 +50,14 @@ pub struct PluginResolverImpl {
 
     plugin_context_factory: Arc<dyn PluginContextFactory + Send + Sync>,
 
+    plugin_config_manager: Arc<dyn PluginConfigManager + Send + Sync>,
+
+    plugin_resource_manager: Arc<dyn PluginResourceManager + Send + Sync>,
+
     config_manager: Arc<dyn ConfigManager + Send + Sync>,
 
+    namespace_manager: Arc<dyn NamespaceManager + Send + Sync>,
+
     /// The resolver can be in three modes: Starting, Neutral and Stopping.
     #[component(default = "create_plugin_resolver_mode")]
     pub mode: RwLock<PluginResolverMode>,
@@ -53,6 +68,63 @@ impl PluginResolverImpl {
         self.config_manager.get_plugins_config().disabled.unwrap_or(false)
     }
 
+    fn get_trust_policy(&self) -> PluginTrustPolicy {
+        let plugins_config = self.config_manager.get_plugins_config();
+        PluginTrustPolicy::new(plugins_config.get_trusted_keys(), plugins_config.is_signature_required())
+    }
+
+    /// Unregisters the resources which the deactivated plugin has not unregistered itself.
+    async fn cleanup_resources(&self, id: &Uuid) {
+        let leaks = self.plugin_resource_manager.cleanup(id).await;
+        if !leaks.is_empty() {
+            warn!(
+                "[LEAK] {} did not unregister {} resources on deactivation",
+                self.plugin_container_manager.name(id).unwrap_or_else(|| id.to_string()),
+                leaks.count()
+            );
+        }
+    }
+
+    /// Unregisters the namespaces owned by the deactivated plugin. The namespaces are kept if another
+    /// version of the plugin is active or starting, for example during an upgrade.
+    fn release_namespaces(&self, id: &Uuid) {
+        let Some(name) = self.plugin_container_manager.name(id) else {
+            return;
+        };
+        let is_in_use = self.plugin_container_manager.get_plugins().into_iter().any(|other_id| {
+            other_id != *id
+                && self.plugin_container_manager.name(&other_id).as_ref() == Some(&name)
+                && matches!(
+                    self.plugin_container_manager.get_plugin_state(&other_id),
+                    Some(PluginState::Active | PluginState::Starting(_) | PluginState::Refreshing(PluginRefreshingState::Starting(_)))
+                )
+        });
+        if is_in_use {
+            return;
+        }
+        for namespace in self.namespace_manager.unregister_by_owner(&name) {
+            trace!("Unregistered namespace {} owned by {name}", namespace.name);
+        }
+    }
+
+    /// Returns the isolation of the plugin, if the plugin should run in a plugin host process.
+    fn get_isolation(&self, id: &Uuid) -> Option<PluginIsolation> {
+        let plugins_config = self.config_manager.get_plugins_config();
+        let isolated_plugins = plugins_config.get_isolated_plugins();
+        let is_isolated = [
+            self.plugin_container_manager.get_stem(id),
+            self.plugin_container_manager.name(id),
+            self.plugin_container_manager.name_canonicalized(id),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|name| isolated_plugins.contains(&name));
+        if !is_isolated {
+            return None;
+        }
+        Some(PluginIsolation::new(plugins_config.get_plugin_host(), plugins_config.get_plugin_host_max_restarts()))
+    }
+
     fn is_plugin_disabled(&self, id: Uuid) -> bool {
         let stem = self.plugin_container_manager.get_stem(&id);
         let name = self.plugin_container_manager.name(&id);
@@ -87,6 +159,55 @@ impl PluginResolverImpl {
         false
     }
 
+    /// Loads and validates the configuration of the plugin before the plugin gets activated.
+    ///
+    /// If the configuration is invalid, the activation of the plugin fails.
+    fn load_config(&self, id: &Uuid) -> PluginTransitionResult {
+        let Err(e) = self.plugin_config_manager.load_config(id) else {
+            return NoChange;
+        };
+        let name = self.plugin_container_manager.name_canonicalized(id).unwrap_or(id.to_string());
+        error!("[FAILED] {name}: {e}");
+        let new_state = match self.plugin_container_manager.get_plugin_state(id) {
+            Some(PluginState::Refreshing(_)) => PluginState::Refreshing(PluginRefreshingState::Starting(PluginStartingState::ActivationFailed)),
+            _ => PluginState::Starting(PluginStartingState::ActivationFailed),
+        };
+        self.plugin_container_manager.set_state(id, new_state)
+    }
+
+    fn plugin_diagnostics(&self, id: Uuid) -> Option<PluginDiagnostics> {
+        let mut dependencies: Vec<PluginDependencyDiagnostics> = self
+            .plugin_container_manager
+            .get_dependencies(&id)
+            .iter()
+            .map(|dependency| PluginDependencyDiagnostics {
+                name: dependency.name.to_string(),
+                version_range: dependency.version.to_string(),
+                state: self.plugin_container_manager.get_dependency_state(&dependency),
+                satisfied_by: self.plugin_container_manager.get_plugin_by_dependency(&dependency),
+                available_versions: self.plugin_container_manager.get_versions_by_name(dependency.name),
+            })
+            .collect();
+        dependencies.sort_by(|a, b| a.name.cmp(&b.name));
+        Some(PluginDiagnostics {
+            id,
+            stem: self.plugin_container_manager.get_stem(&id)?,
+            name: self.plugin_container_manager.name(&id),
+            version: self.plugin_container_manager.version(&id),
+            state: self.plugin_container_manager.get_plugin_state(&id)?,
+            rustc_version: self.plugin_container_manager.rustc_version(&id),
+            plugin_api_version: self.plugin_container_manager.plugin_api_version(&id),
+            dependencies,
+            history: self.plugin_container_manager.get_state_history(&id),
+            capability_violations: self
+                .plugin_context_factory
+                .get_capability_violations(&id)
+                .iter()
+                .map(|violation| violation.to_string())
+                .collect(),
+        })
+    }
+
     fn log_unsatisfied_dependencies(&self) {
         for id in self.plugin_container_manager.get_plugins_not_having_state(PluginState::Active) {
             let name = self.plugin_container_manager.name_canonicalized(&id).unwrap_or(id.to_string());
@@ -229,6 +350,27 @@ impl PluginResolver for PluginResolverImpl {
                 return Changed;
             }
         }
+        // PluginResolveState::ManifestMismatch --> Uninstalling
+        // PluginResolveState::SignatureInvalid --> Uninstalling
+        // PluginResolveState::ChecksumMismatch --> Uninstalling
+        for resolve_state in [
+            PluginResolveState::ManifestMismatch,
+            PluginResolveState::SignatureInvalid,
+            PluginResolveState::ChecksumMismatch,
+        ] {
+            for id in self.plugin_container_manager.get_plugins_with_states(
+                PluginState::Resolving(resolve_state),
+                PluginState::Refreshing(PluginRefreshingState::Resolving(resolve_state)),
+            ) {
+                if self
+                    .plugin_container_manager
+                    .set_state(&id, PluginState::Uninstalling(PluginUninstallingState::UnloadDll))
+                    == Changed
+                {
+                    return Changed;
+                }
+            }
+        }
         // Deploying --> Installed
         for id in self
             .plugin_container_manager
@@ -239,12 +381,18 @@ impl PluginResolver for PluginResolverImpl {
             }
         }
         // Installed --> PluginResolveState::Loaded
+        //           --> PluginResolveState::ManifestMissing
+        //           --> PluginResolveState::ManifestInvalid
+        //           --> PluginResolveState::SignatureMissing
+        //           --> PluginResolveState::SignatureInvalid
+        //           --> PluginResolveState::ChecksumMismatch
         //           --> Uninstalling
+        let trust_policy = self.get_trust_policy();
         for id in self
             .plugin_container_manager
             .get_plugins_with_states(PluginState::Installed, PluginState::Refreshing(PluginRefreshingState::Installed))
         {
-            if self.plugin_container_manager.load_dll(&id) == Changed {
+            if self.plugin_container_manager.load_dll(&id, &trust_policy) == Changed {
                 return Changed;
             }
         }
@@ -260,6 +408,7 @@ impl PluginResolver for PluginResolverImpl {
         // PluginResolveState::PluginDeclarationLoaded --> PluginResolveState::PluginCompatible
         //                                             --> PluginResolveState::CompilerVersionMismatch
         //                                             --> PluginResolveState::PluginApiVersionMismatch
+        //                                             --> PluginResolveState::ManifestMismatch
         for id in self.plugin_container_manager.get_plugins_with_states(
             PluginState::Resolving(PluginResolveState::PluginDeclarationLoaded),
             PluginState::Refreshing(PluginRefreshingState::Resolving(PluginResolveState::PluginDeclarationLoaded)),
@@ -319,10 +468,17 @@ impl PluginResolver for PluginResolverImpl {
             PluginState::Starting(PluginStartingState::ConstructingProxy),
             PluginState::Refreshing(PluginRefreshingState::Starting(PluginStartingState::ConstructingProxy)),
         ) {
-            if let Some(plugin_context) = self.plugin_context_factory.get() {
-                if self.plugin_container_manager.construct_proxy(&id, plugin_context) == Changed {
-                    return Changed;
-                }
+            // Each plugin gets its own plugin context, which binds the namespaces registered by the plugin to the plugin
+            let name = self.plugin_container_manager.name(&id).unwrap_or_default();
+            let plugin_context = self.plugin_context_factory.create(&name);
+            // The plugin context tracks the resources registered by the plugin
+            let plugin_context = self.plugin_resource_manager.track(&id, plugin_context);
+            // Plugins which have declared their capabilities get a plugin context which enforces them.
+            // Plugins which haven't declared capabilities get no capabilities, if configured.
+            let capabilities = self.plugin_container_manager.capabilities(&id);
+            let plugin_context = self.plugin_context_factory.restrict(&id, &name, plugin_context, capabilities);
+            if self.plugin_container_manager.construct_proxy(&id, plugin_context, self.get_isolation(&id)) == Changed {
+                return Changed;
             }
         }
         // Starting(Registering) --> Starting(Activating)
@@ -339,6 +495,9 @@ impl PluginResolver for PluginResolverImpl {
             PluginState::Starting(PluginStartingState::Activating),
             PluginState::Refreshing(PluginRefreshingState::Starting(PluginStartingState::Activating)),
         ) {
+            if self.load_config(&id) == Changed {
+                return Changed;
+            }
             if self.plugin_container_manager.activate(&id).await == Changed {
                 return Changed;
             }
@@ -349,6 +508,8 @@ impl PluginResolver for PluginResolverImpl {
             PluginState::Refreshing(PluginRefreshingState::Stopping(PluginStoppingState::Deactivating)),
         ) {
             if self.plugin_container_manager.deactivate(&id).await == Changed {
+                self.cleanup_resources(&id).await;
+                self.release_namespaces(&id);
                 return Changed;
             }
         }
@@ -405,6 +566,17 @@ impl PluginResolver for PluginResolverImpl {
         let reader = self.mode.read().unwrap();
         *reader
     }
+
+    fn diagnostics(&self) -> PluginResolverDiagnostics {
+        let mut plugins: Vec<PluginDiagnostics> = self
+            .plugin_container_manager
+            .get_plugins()
+            .into_iter()
+            .filter_map(|id| self.plugin_diagnostics(id))
+            .collect();
+        plugins.sort_by_key(|plugin| plugin.label());
+        PluginResolverDiagnostics { plugins }
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dashmap::DashMap;
+use springtime_di::Component;
+use springtime_di::component_alias;
+use uuid::Uuid;
+
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_plugin_api::PluginContext;
+use reactive_graph_plugin_service_api::PluginResourceManager;
+use reactive_graph_plugin_service_api::PluginResources;
+
+use crate::TrackingPluginContext;
+
+fn create_plugin_contexts() -> DashMap<Uuid, Arc<TrackingPluginContext>> {
+    DashMap::new()
+}
+
+#[derive(Component)]
+pub struct PluginResourceManagerImpl {
+    /// The plugin contexts of the plugins which are tracked.
+    #[component(default = "create_plugin_contexts")]
+    contexts: DashMap<Uuid, Arc<TrackingPluginContext>>,
+}
+
+#[async_trait]
+#[component_alias]
+impl PluginResourceManager for PluginResourceManagerImpl {
+    fn track(&self, id: &Uuid, plugin_context: Arc<dyn PluginContext + Send + Sync>) -> Arc<dyn PluginContext + Send + Sync> {
+        let context = Arc::new(TrackingPluginContext::new(*id, plugin_context));
+        self.contexts.insert(*id, context.clone());
+        context
+    }
+
+    fn get_resources(&self, id: &Uuid) -> Option<PluginResources> {
+        self.contexts.get(id).map(|context| context.get_resources())
+    }
+
+    fn handover(&self, previous: &Uuid, id: &Uuid) -> PluginResources {
+        let (Some(previous), Some(successor)) = (self.contexts.get(previous), self.contexts.get(id)) else {
+            return PluginResources::default();
+        };
+        previous.value().handover(successor.value())
+    }
+
+    async fn cleanup(&self, id: &Uuid) -> PluginResources {
+        let Some((_, context)) = self.contexts.remove(id) else {
+            return PluginResources::default();
+        };
+        context.cleanup().await
+    }
+}
+
+#[async_trait]
+impl Lifecycle for PluginResourceManagerImpl {}
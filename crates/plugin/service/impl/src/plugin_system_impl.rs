@@ -7,18 +7,22 @@ use springtime_di::component_alias;
 use springtime_di::instance_provider::TypedComponentInstanceProvider;
 
 use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_plugin_service_api::PluginConfigManager;
 use reactive_graph_plugin_service_api::PluginContainerManager;
 use reactive_graph_plugin_service_api::PluginContextFactory;
 use reactive_graph_plugin_service_api::PluginRepositoryManager;
 use reactive_graph_plugin_service_api::PluginResolver;
+use reactive_graph_plugin_service_api::PluginResourceManager;
 use reactive_graph_plugin_service_api::PluginSystem;
 
 #[derive(Component)]
 pub struct PluginSystemImpl {
     plugin_container_manager: Arc<dyn PluginContainerManager + Send + Sync>,
+    plugin_config_manager: Arc<dyn PluginConfigManager + Send + Sync>,
     plugin_context_factory: Arc<dyn PluginContextFactory + Send + Sync>,
     plugin_repository_manager: Arc<dyn PluginRepositoryManager + Send + Sync>,
     plugin_resolver: Arc<dyn PluginResolver + Send + Sync>,
+    plugin_resource_manager: Arc<dyn PluginResourceManager + Send + Sync>,
 }
 
 #[async_trait]
@@ -32,6 +36,10 @@ impl PluginSystem for PluginSystemImpl {
         self.plugin_container_manager.clone()
     }
 
+    fn get_plugin_config_manager(&self) -> Arc<dyn PluginConfigManager + Send + Sync> {
+        self.plugin_config_manager.clone()
+    }
+
     fn get_plugin_repository_manager(&self) -> Arc<dyn PluginRepositoryManager + Send + Sync> {
         self.plugin_repository_manager.clone()
     }
@@ -39,18 +47,24 @@ impl PluginSystem for PluginSystemImpl {
     fn get_plugin_resolver(&self) -> Arc<dyn PluginResolver + Send + Sync> {
         self.plugin_resolver.clone()
     }
+
+    fn get_plugin_resource_manager(&self) -> Arc<dyn PluginResourceManager + Send + Sync> {
+        self.plugin_resource_manager.clone()
+    }
 }
 
 #[async_trait]
 impl Lifecycle for PluginSystemImpl {
     async fn init(&self) {
         self.plugin_context_factory.init().await;
+        self.plugin_config_manager.init().await;
         self.plugin_repository_manager.init().await;
         self.plugin_resolver.init().await;
     }
 
     async fn post_init(&self) {
         self.plugin_context_factory.post_init().await;
+        self.plugin_config_manager.post_init().await;
         self.plugin_repository_manager.post_init().await;
         self.plugin_resolver.post_init().await;
     }
@@ -58,12 +72,14 @@ impl Lifecycle for PluginSystemImpl {
     async fn pre_shutdown(&self) {
         self.plugin_resolver.pre_shutdown().await;
         self.plugin_repository_manager.pre_shutdown().await;
+        self.plugin_config_manager.pre_shutdown().await;
         self.plugin_context_factory.pre_shutdown().await;
     }
 
     async fn shutdown(&self) {
         self.plugin_resolver.shutdown().await;
         self.plugin_repository_manager.shutdown().await;
+        self.plugin_config_manager.shutdown().await;
         self.plugin_context_factory.shutdown().await;
     }
 }
@@ -0,0 +1,140 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::debug;
+use log::error;
+use log::info;
+use springtime_di::Component;
+use springtime_di::component_alias;
+use uuid::Uuid;
+
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_plugin_api::PluginState;
+use reactive_graph_plugin_api::PluginUpgradeError;
+use reactive_graph_plugin_service_api::PluginContainerManager;
+use reactive_graph_plugin_service_api::PluginResolver;
+use reactive_graph_plugin_service_api::PluginResourceManager;
+use reactive_graph_plugin_service_api::PluginUpgradeManager;
+use reactive_graph_plugin_service_api::get_install_path;
+use reactive_graph_plugin_service_api::move_package_files;
+
+#[derive(Component)]
+pub struct PluginUpgradeManagerImpl {
+    plugin_container_manager: Arc<dyn PluginContainerManager + Send + Sync>,
+
+    plugin_resolver: Arc<dyn PluginResolver + Send + Sync>,
+
+    plugin_resource_manager: Arc<dyn PluginResourceManager + Send + Sync>,
+}
+
+impl PluginUpgradeManagerImpl {
+    fn name(&self, id: &Uuid) -> String {
+        self.plugin_container_manager.name_canonicalized(id).unwrap_or(id.to_string())
+    }
+
+    async fn resolve(&self) {
+        self.plugin_resolver.resolve_until_idle().await;
+        self.plugin_resolver.transition_to_fallback_states().await;
+    }
+
+    /// Removes the new version of the plugin and unregisters everything it has registered.
+    async fn abort(&self, upgrade_id: &Uuid) {
+        self.resolve().await;
+        self.plugin_resource_manager.cleanup(upgrade_id).await;
+        if self.plugin_container_manager.uninstall(upgrade_id).is_ok() {
+            self.resolve().await;
+        }
+    }
+
+    /// Restarts the previous version of the plugin, because its behaviours may have been handed
+    /// over to the new version of the plugin already.
+    async fn restart(&self, id: &Uuid) {
+        if self.plugin_container_manager.stop(id).is_ok() {
+            self.resolve().await;
+        }
+        if self.plugin_container_manager.start(id).is_ok() {
+            self.resolve().await;
+        }
+    }
+}
+
+#[async_trait]
+#[component_alias]
+impl PluginUpgradeManager for PluginUpgradeManagerImpl {
+    async fn upgrade(&self, id: &Uuid, deploy_path: &Path) -> Result<Uuid, PluginUpgradeError> {
+        let name = self.name(id);
+        match self.plugin_container_manager.get_plugin_state(id) {
+            Some(PluginState::Active) => {}
+            Some(_) => return Err(PluginUpgradeError::NotActive(name)),
+            None => return Err(PluginUpgradeError::PluginNotFound(name)),
+        }
+        if !deploy_path.exists() {
+            return Err(PluginUpgradeError::NewVersionNotFound(name, deploy_path.display().to_string()));
+        }
+        let install_path = install(&name, deploy_path)?;
+        let Some(upgrade_id) = self.plugin_container_manager.create_and_register_upgrade_container(id, install_path) else {
+            return Err(PluginUpgradeError::PluginNotFound(name));
+        };
+
+        // Load and activate the new version side by side with the running version. While the
+        // new version registers its behaviours, the behaviours of the running version are
+        // handed over to the new version.
+        self.plugin_resolver.resolve_until_idle().await;
+        if let Err(e) = self.plugin_container_manager.start(&upgrade_id) {
+            self.abort(&upgrade_id).await;
+            return Err(PluginUpgradeError::ResolveFailed(name, format!("{e:?}")));
+        }
+        self.plugin_resolver.resolve_until_idle().await;
+        let state = self.plugin_container_manager.get_plugin_state(&upgrade_id);
+        if state != Some(PluginState::Active) {
+            self.abort(&upgrade_id).await;
+            self.restart(id).await;
+            return Err(PluginUpgradeError::ActivationFailed(name, format!("{state:?}")));
+        }
+        let handed_over = self.plugin_resource_manager.handover(id, &upgrade_id);
+        debug!("Plugin {name} handed over {} resources to the new version {upgrade_id}", handed_over.count());
+
+        // Stop and uninstall the previous version
+        if self.plugin_container_manager.stop(id).is_ok() {
+            self.plugin_resolver.resolve_until_idle().await;
+        }
+        if self.plugin_container_manager.uninstall(id).is_ok() {
+            self.plugin_resolver.resolve_until_idle().await;
+        }
+        // Start dependent plugins which have been stopped meanwhile
+        while self.plugin_container_manager.start_dependent_with_satisfied_dependencies(&upgrade_id) {
+            self.plugin_resolver.resolve_until_idle().await;
+        }
+        self.plugin_resolver.transition_to_fallback_states().await;
+        info!("[UPGRADED] {name} {}", self.plugin_container_manager.version(&upgrade_id).unwrap_or(String::from("?.?.?")));
+        Ok(upgrade_id)
+    }
+}
+
+#[async_trait]
+impl Lifecycle for PluginUpgradeManagerImpl {}
+
+/// Moves the new version of the plugin from the deploy folder into the installation folder.
+fn install(name: &str, deploy_path: &Path) -> Result<PathBuf, PluginUpgradeError> {
+    let Some(install_path) = get_install_path(deploy_path) else {
+        return Err(PluginUpgradeError::InstallError(name.to_string(), format!("Invalid deploy path {}", deploy_path.display())));
+    };
+    if let Err(e) = fs::copy(deploy_path, &install_path) {
+        error!(
+            "Failed to upgrade plugin {name}: Failed to copy plugin from {} to {}: {e}",
+            deploy_path.display(),
+            install_path.display()
+        );
+        return Err(PluginUpgradeError::InstallError(name.to_string(), e.to_string()));
+    }
+    if let Err(e) = fs::remove_file(deploy_path) {
+        error!("Failed to upgrade plugin {name}: Failed to remove plugin from {}: {e}", deploy_path.display());
+        return Err(PluginUpgradeError::InstallError(name.to_string(), e.to_string()));
+    }
+    move_package_files(deploy_path, &install_path);
+    debug!("Installed new version of plugin {name} from {} to {}", deploy_path.display(), install_path.display());
+    Ok(install_path)
+}
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use serde_json::Value;
 
 use reactive_graph_plugin_api::Plugin;
 use reactive_graph_plugin_api::PluginActivationError;
@@ -21,4 +22,8 @@ impl Plugin for PluginProxy {
     async fn deactivate(&self) -> Result<(), PluginDeactivationError> {
         self.plugin.deactivate().await
     }
+
+    async fn config_changed(&self, config: Value) {
+        self.plugin.config_changed(config).await
+    }
 }
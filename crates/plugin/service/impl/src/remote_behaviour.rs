@@ -0,0 +1,131 @@
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::sync::Weak;
+
+use uuid::Uuid;
+
+use reactive_graph_behaviour_model_api::BehaviourConnect;
+use reactive_graph_behaviour_model_api::BehaviourConnectFailed;
+use reactive_graph_behaviour_model_api::BehaviourCreationError;
+use reactive_graph_behaviour_model_api::BehaviourDisconnect;
+use reactive_graph_behaviour_model_api::BehaviourDisconnectFailed;
+use reactive_graph_behaviour_model_api::BehaviourFactory;
+use reactive_graph_behaviour_model_api::BehaviourFsm;
+use reactive_graph_behaviour_model_api::BehaviourInit;
+use reactive_graph_behaviour_model_api::BehaviourPropertyValidator;
+use reactive_graph_behaviour_model_api::BehaviourShutdown;
+use reactive_graph_behaviour_model_api::BehaviourState;
+use reactive_graph_behaviour_model_api::BehaviourTransitions;
+use reactive_graph_behaviour_model_api::BehaviourTypeId;
+use reactive_graph_behaviour_model_api::BehaviourValidator;
+use reactive_graph_reactive_model_api::ReactiveInstanceContainer;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+
+use crate::remote_plugin::RemotePluginHost;
+
+/// Creates behaviours which are running in the plugin host of an out-of-process plugin.
+pub struct RemoteEntityBehaviourFactory {
+    ty: BehaviourTypeId,
+    host: Weak<RemotePluginHost>,
+}
+
+impl RemoteEntityBehaviourFactory {
+    pub(crate) fn new(ty: BehaviourTypeId, host: Weak<RemotePluginHost>) -> Self {
+        RemoteEntityBehaviourFactory { ty, host }
+    }
+}
+
+impl BehaviourFactory<Uuid, ReactiveEntity> for RemoteEntityBehaviourFactory {
+    fn create(&self, reactive_instance: ReactiveEntity) -> Result<Arc<dyn BehaviourFsm<Uuid, ReactiveEntity> + Send + Sync>, BehaviourCreationError> {
+        let behaviour = Arc::new_cyclic(|this| RemoteEntityBehaviour {
+            reactive_instance,
+            ty: self.ty.clone(),
+            state: RwLock::new(BehaviourState::Created),
+            host: self.host.clone(),
+            this: this.clone(),
+        });
+        behaviour
+            .transition(BehaviourState::Connected)
+            .map_err(BehaviourCreationError::BehaviourTransitionError)?;
+        Ok(behaviour)
+    }
+
+    fn behaviour_ty(&self) -> &BehaviourTypeId {
+        &self.ty
+    }
+}
+
+/// The runtime side of a behaviour which is running in the plugin host.
+///
+/// The behaviour itself is validated, initialized and connected in the plugin host, which
+/// operates on a mirror of the reactive entity.
+pub struct RemoteEntityBehaviour {
+    reactive_instance: ReactiveEntity,
+    ty: BehaviourTypeId,
+    state: RwLock<BehaviourState>,
+    host: Weak<RemotePluginHost>,
+    this: Weak<RemoteEntityBehaviour>,
+}
+
+impl BehaviourFsm<Uuid, ReactiveEntity> for RemoteEntityBehaviour {
+    fn ty(&self) -> &BehaviourTypeId {
+        &self.ty
+    }
+
+    fn get_state(&self) -> BehaviourState {
+        *self.state.read().unwrap()
+    }
+
+    fn set_state(&self, state: BehaviourState) {
+        let mut writer = self.state.write().unwrap();
+        *writer = state;
+    }
+
+    fn get_validator(&self) -> &dyn BehaviourValidator<Uuid, ReactiveEntity> {
+        self
+    }
+
+    fn get_transitions(&self) -> &dyn BehaviourTransitions<Uuid, ReactiveEntity> {
+        self
+    }
+}
+
+impl ReactiveInstanceContainer<Uuid, ReactiveEntity> for RemoteEntityBehaviour {
+    fn get_reactive_instance(&self) -> &ReactiveEntity {
+        &self.reactive_instance
+    }
+}
+
+impl BehaviourValidator<Uuid, ReactiveEntity> for RemoteEntityBehaviour {}
+
+impl BehaviourPropertyValidator<Uuid, ReactiveEntity> for RemoteEntityBehaviour {}
+
+impl BehaviourInit<Uuid, ReactiveEntity> for RemoteEntityBehaviour {}
+
+impl BehaviourShutdown<Uuid, ReactiveEntity> for RemoteEntityBehaviour {}
+
+impl BehaviourConnect<Uuid, ReactiveEntity> for RemoteEntityBehaviour {
+    fn connect(&self) -> Result<(), BehaviourConnectFailed> {
+        let (Some(host), Some(this)) = (self.host.upgrade(), self.this.upgrade()) else {
+            return Err(BehaviourConnectFailed {});
+        };
+        host.connect_behaviour(this)
+    }
+}
+
+impl BehaviourDisconnect<Uuid, ReactiveEntity> for RemoteEntityBehaviour {
+    fn disconnect(&self) -> Result<(), BehaviourDisconnectFailed> {
+        if let Some(host) = self.host.upgrade() {
+            host.disconnect_behaviour(self.reactive_instance.id, &self.ty);
+        }
+        Ok(())
+    }
+}
+
+impl BehaviourTransitions<Uuid, ReactiveEntity> for RemoteEntityBehaviour {}
+
+impl Drop for RemoteEntityBehaviour {
+    fn drop(&mut self) {
+        let _ = self.disconnect();
+    }
+}
@@ -0,0 +1,851 @@
+use std::net::Ipv4Addr;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::RwLock;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use dashmap::DashMap;
+use dashmap::DashSet;
+use log::debug;
+use log::error;
+use log::info;
+use log::trace;
+use log::warn;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::io::BufReader;
+use tokio::net::TcpListener;
+use tokio::net::TcpStream;
+use tokio::process::Child;
+use tokio::process::Command;
+use tokio::sync::Mutex;
+use tokio::sync::mpsc;
+use tokio::sync::oneshot;
+use tokio::time::timeout;
+use uuid::Uuid;
+
+use reactive_graph_behaviour_model_api::BehaviourConnectFailed;
+use reactive_graph_behaviour_model_api::BehaviourFsm;
+use reactive_graph_behaviour_model_api::BehaviourState;
+use reactive_graph_behaviour_model_api::BehaviourTypeId;
+use reactive_graph_behaviour_model_api::ComponentBehaviourTypeId;
+use reactive_graph_behaviour_model_api::EntityBehaviourTypeId;
+use reactive_graph_graph::EntityInstance;
+use reactive_graph_graph::NamespacedTypeContainer;
+use reactive_graph_graph::PropertyInstanceSetter;
+use reactive_graph_plugin_api::Plugin;
+use reactive_graph_plugin_api::PluginActivationError;
+use reactive_graph_plugin_api::PluginContext;
+use reactive_graph_plugin_api::PluginDeactivationError;
+use reactive_graph_plugin_api::PluginHostError;
+use reactive_graph_plugin_api::TypeProvider;
+use reactive_graph_plugin_service_api::PLUGIN_HOST_TOKEN_ENV;
+use reactive_graph_plugin_service_api::PluginHostCall;
+use reactive_graph_plugin_service_api::PluginHostChannel;
+use reactive_graph_plugin_service_api::PluginHostEntityInstanceError;
+use reactive_graph_plugin_service_api::PluginHostMessage;
+use reactive_graph_plugin_service_api::PluginHostPropertyCache;
+use reactive_graph_plugin_service_api::PluginHostRequest;
+use reactive_graph_plugin_service_api::PluginHostTypeKind;
+use reactive_graph_plugin_service_api::PluginHostTypes;
+use reactive_graph_plugin_service_api::PluginIsolation;
+use reactive_graph_reactive_model_api::ReactiveInstanceContainer;
+use reactive_graph_reactive_model_api::ReactivePropertyContainer;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+
+use crate::RemoteEntityBehaviour;
+use crate::RemoteEntityBehaviourFactory;
+use crate::container::verify_private_copy;
+
+/// How long to wait for the plugin host to load the plugin.
+const READY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long to wait for a connection to the plugin host socket to send the token.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The maximum length of the handshake, which only contains the token.
+const MAX_HANDSHAKE_LEN: u64 = 128;
+
+/// How long to wait for the response of the plugin host.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How long to wait for the plugin host to exit after the connection has been closed.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The delay before the first restart of a crashed plugin host. The delay is doubled after each failed restart.
+const RESTART_DELAY: Duration = Duration::from_millis(500);
+
+const MAX_RESTART_DELAY: Duration = Duration::from_secs(30);
+
+/// The ids of the type providers registered by plugin hosts.
+///
+/// The id of a type provider has to be static. Each id is leaked once, no matter how often it
+/// is registered.
+static PROVIDER_IDS: LazyLock<DashSet<&'static str>> = LazyLock::new(DashSet::new);
+
+/// A plugin which runs out of process in a plugin host.
+///
+/// The plugin host is started on activation. The calls of the plugin are served using the
+/// plugin context of the runtime. If the plugin host crashes, the behaviours provided by
+/// the plugin are marked as failed and the plugin host is restarted. The plugin host is not
+/// restarted if it has reported that it can't run the plugin or if the activation has failed.
+pub struct RemotePlugin {
+    host: Arc<RemotePluginHost>,
+}
+
+impl RemotePlugin {
+    pub fn new(name: String, path: PathBuf, checksum: Option<String>, isolation: PluginIsolation, context: Arc<dyn PluginContext + Send + Sync>) -> Self {
+        RemotePlugin {
+            host: Arc::new(RemotePluginHost::new(name, path, checksum, isolation, context)),
+        }
+    }
+}
+
+#[async_trait]
+impl Plugin for RemotePlugin {
+    async fn activate(&self) -> Result<(), PluginActivationError> {
+        self.host.activate().await.map_err(|e| PluginActivationError::ActivationFailed(e.to_string()))
+    }
+
+    async fn deactivate(&self) -> Result<(), PluginDeactivationError> {
+        self.host.deactivate().await
+    }
+
+    async fn config_changed(&self, config: Value) {
+        self.host.config_changed(config).await
+    }
+}
+
+impl Drop for RemotePlugin {
+    fn drop(&mut self) {
+        self.host.active.store(false, Ordering::SeqCst);
+        if let Some(connection) = self.host.take_connection() {
+            // Closing the connection makes the plugin host exit
+            connection.channel.close();
+        }
+    }
+}
+
+/// A running plugin host process.
+struct RemotePluginConnection {
+    channel: PluginHostChannel,
+    child: Mutex<Child>,
+}
+
+impl RemotePluginConnection {
+    async fn shutdown(&self) {
+        self.channel.close();
+        let mut child = self.child.lock().await;
+        if timeout(SHUTDOWN_TIMEOUT, child.wait()).await.is_err() {
+            warn!("The plugin host didn't exit in time and will be killed");
+            let _ = child.kill().await;
+        }
+    }
+}
+
+/// The state of an out-of-process plugin which outlives the plugin host process.
+pub(crate) struct RemotePluginHost {
+    /// The name of the plugin.
+    name: String,
+
+    /// The path of the dynamic link library.
+    path: PathBuf,
+
+    /// The checksum of the verified dynamic link library, which is verified again before the
+    /// plugin host is started.
+    checksum: Option<String>,
+
+    isolation: PluginIsolation,
+
+    /// The plugin context of the runtime.
+    context: Arc<dyn PluginContext + Send + Sync>,
+
+    /// The connection to the running plugin host.
+    connection: RwLock<Option<Arc<RemotePluginConnection>>>,
+
+    /// True, if the plugin is activated.
+    active: AtomicBool,
+
+    /// True, while the crashed plugin host is being restarted.
+    restarting: AtomicBool,
+
+    /// The reason why the plugin host can't run the plugin.
+    failure: RwLock<Option<String>>,
+
+    /// The type providers registered by the plugin.
+    type_providers: DashMap<String, PluginHostTypeKind>,
+
+    /// The entity behaviours registered by the plugin.
+    entity_behaviours: DashSet<EntityBehaviourTypeId>,
+
+    /// The entity component behaviours registered by the plugin.
+    entity_component_behaviours: DashSet<ComponentBehaviourTypeId>,
+
+    /// The behaviours which are connected in the plugin host.
+    behaviours: DashMap<(Uuid, BehaviourTypeId), Arc<RemoteEntityBehaviour>>,
+
+    /// The entity instances whose properties are synchronized with the plugin host.
+    observed: DashMap<Uuid, ReactiveEntity>,
+
+    /// The observed properties of the entity instances.
+    observed_properties: DashSet<(Uuid, String)>,
+
+    /// The handle of the property observers.
+    handle_id: u128,
+
+    property_cache: PluginHostPropertyCache,
+}
+
+impl RemotePluginHost {
+    fn new(name: String, path: PathBuf, checksum: Option<String>, isolation: PluginIsolation, context: Arc<dyn PluginContext + Send + Sync>) -> Self {
+        RemotePluginHost {
+            name,
+            path,
+            checksum,
+            isolation,
+            context,
+            connection: RwLock::new(None),
+            active: AtomicBool::new(false),
+            restarting: AtomicBool::new(false),
+            failure: RwLock::new(None),
+            type_providers: DashMap::new(),
+            entity_behaviours: DashSet::new(),
+            entity_component_behaviours: DashSet::new(),
+            behaviours: DashMap::new(),
+            observed: DashMap::new(),
+            observed_properties: DashSet::new(),
+            handle_id: Uuid::new_v4().as_u128(),
+            property_cache: PluginHostPropertyCache::new(),
+        }
+    }
+
+    async fn activate(self: &Arc<Self>) -> Result<(), PluginHostError> {
+        self.active.store(true, Ordering::SeqCst);
+        if let Err(e) = self.start().await {
+            self.active.store(false, Ordering::SeqCst);
+            self.stop().await;
+            self.cleanup().await;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    async fn deactivate(self: &Arc<Self>) -> Result<(), PluginDeactivationError> {
+        self.active.store(false, Ordering::SeqCst);
+        let result = match self.get_connection() {
+            Some(connection) => self.request(&connection, PluginHostRequest::Deactivate).await.map(|_| ()),
+            None => Err(PluginHostError::NotRunning(self.name.clone())),
+        };
+        self.stop().await;
+        // Unregisters everything the plugin hasn't unregistered itself
+        self.cleanup().await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(PluginHostError::NotRunning(_)) => {
+                warn!("The plugin host of {} is not running", self.name);
+                Ok(())
+            }
+            Err(e) => {
+                error!("{e}");
+                Err(PluginDeactivationError::DeactivationFailed)
+            }
+        }
+    }
+
+    async fn config_changed(self: &Arc<Self>, config: Value) {
+        let Some(connection) = self.get_connection() else {
+            warn!("The configuration of {} has been changed but the plugin host is not running", self.name);
+            return;
+        };
+        if let Err(e) = self.request(&connection, PluginHostRequest::ConfigChanged { config }).await {
+            error!("{e}");
+        }
+    }
+
+    /// Starts the plugin host and activates the plugin.
+    async fn start(self: &Arc<Self>) -> Result<(), PluginHostError> {
+        let connection = self.spawn().await?;
+        {
+            let mut writer = self.connection.write().unwrap();
+            *writer = Some(connection.clone());
+        }
+        if let Err(e) = self.request(&connection, PluginHostRequest::Activate).await {
+            self.stop().await;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Stops the plugin host.
+    async fn stop(&self) {
+        if let Some(connection) = self.take_connection() {
+            connection.shutdown().await;
+        }
+    }
+
+    /// Spawns the plugin host process and waits until the plugin has been loaded.
+    ///
+    /// The plugin host connects to a loopback socket which is dedicated to the protocol. The
+    /// standard output of the plugin host is only logged.
+    async fn spawn(self: &Arc<Self>) -> Result<Arc<RemotePluginConnection>, PluginHostError> {
+        let plugin_host = self.isolation.plugin_host.display().to_string();
+        trace!("Starting plugin host {} for {}", plugin_host, self.path.display());
+        let spawn_failed = |e: std::io::Error| PluginHostError::SpawnFailed(plugin_host.clone(), e.to_string());
+        if let Some(checksum) = &self.checksum {
+            verify_private_copy(&self.path, checksum).map_err(spawn_failed)?;
+        }
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.map_err(spawn_failed)?;
+        let address = listener.local_addr().map_err(spawn_failed)?;
+        let token = Uuid::new_v4().simple().to_string();
+        let mut child = Command::new(&self.isolation.plugin_host)
+            .arg(&self.path)
+            .arg("--connect")
+            .arg(address.to_string())
+            .env(PLUGIN_HOST_TOKEN_ENV, &token)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(spawn_failed)?;
+        if let Some(stdout) = child.stdout.take() {
+            let name = self.name.clone();
+            tokio::spawn(async move {
+                let mut lines = BufReader::new(stdout).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    debug!("[{name}] {line}");
+                }
+            });
+        }
+        self.failure.write().unwrap().take();
+        let stream = match timeout(READY_TIMEOUT, accept(&listener, &token)).await {
+            Ok(Ok(stream)) => stream,
+            _ => {
+                let _ = child.kill().await;
+                return Err(PluginHostError::NotReady(self.name.clone()));
+            }
+        };
+        let (reader, mut writer) = tokio::io::split(stream);
+
+        let (sender, mut receiver) = mpsc::unbounded_channel::<PluginHostMessage>();
+        let connection = Arc::new(RemotePluginConnection {
+            channel: PluginHostChannel::new(move |message| sender.send(message).is_ok()),
+            child: Mutex::new(child),
+        });
+
+        // The connection is shut down after the channel has been closed
+        tokio::spawn(async move {
+            while let Some(message) = receiver.recv().await {
+                if writer.write_all(message.to_line().as_bytes()).await.is_err() || writer.flush().await.is_err() {
+                    break;
+                }
+            }
+            let _ = writer.shutdown().await;
+        });
+
+        let (ready_sender, ready_receiver) = oneshot::channel::<Result<(String, String), String>>();
+        let host = self.clone();
+        let reader_connection = connection.clone();
+        tokio::spawn(async move {
+            let mut ready_sender = Some(ready_sender);
+            let mut lines = BufReader::new(reader).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                match PluginHostMessage::parse(&line) {
+                    Some(PluginHostMessage::Ready { name, version }) => {
+                        if let Some(ready_sender) = ready_sender.take() {
+                            let _ = ready_sender.send(Ok((name, version)));
+                        }
+                    }
+                    Some(PluginHostMessage::Failed { error }) if ready_sender.is_some() => {
+                        if let Some(ready_sender) = ready_sender.take() {
+                            let _ = ready_sender.send(Err(error));
+                        }
+                    }
+                    Some(message) => host.handle_message(&reader_connection, message).await,
+                    None => warn!("Invalid message from the plugin host of {}: {line}", host.name),
+                }
+            }
+            reader_connection.channel.close();
+            host.connection_lost(&reader_connection);
+        });
+
+        match timeout(READY_TIMEOUT, ready_receiver).await {
+            Ok(Ok(Ok((name, version)))) => {
+                debug!("The plugin host has loaded {name} {version}");
+                Ok(connection)
+            }
+            Ok(Ok(Err(error))) => {
+                connection.shutdown().await;
+                Err(PluginHostError::Failed(self.name.clone(), error))
+            }
+            _ => {
+                connection.shutdown().await;
+                Err(PluginHostError::NotReady(self.name.clone()))
+            }
+        }
+    }
+
+    async fn handle_message(self: &Arc<Self>, connection: &Arc<RemotePluginConnection>, message: PluginHostMessage) {
+        match message {
+            PluginHostMessage::Response { id, result } => {
+                connection.channel.respond(id, result);
+            }
+            // The calls are handled in order, because the plugin relies on the order of the registrations
+            PluginHostMessage::Call { id, call } => {
+                let result = self.handle_call(call).await;
+                connection.channel.send(PluginHostMessage::Response { id, result });
+            }
+            PluginHostMessage::PropertyChanged { id, property, value } => {
+                self.property_changed_remotely(id, &property, value);
+            }
+            PluginHostMessage::Failed { error } => {
+                error!("[FAILED] {}: {error}", self.name);
+                self.failure.write().unwrap().replace(error);
+            }
+            PluginHostMessage::Ready { .. } | PluginHostMessage::Request { .. } => {
+                warn!("Unexpected message from the plugin host of {}", self.name);
+            }
+        }
+    }
+
+    /// Serves a call of the plugin using the plugin context of the runtime.
+    async fn handle_call(self: &Arc<Self>, call: PluginHostCall) -> Result<Value, String> {
+        let entity_instance_manager = self.context.get_entity_instance_manager();
+        let config_manager = self.context.get_config_manager();
+        match call {
+            PluginHostCall::RegisterTypes { provider_id, types } => {
+                // The type providers are registered again after the plugin host has been restarted
+                if self.type_providers.insert(provider_id.clone(), types.kind()).is_some() {
+                    return Ok(Value::Null);
+                }
+                let id = static_provider_id(provider_id);
+                match types {
+                    PluginHostTypes::Components(components) => {
+                        self.context
+                            .get_component_provider_registry()
+                            .register_provider(Arc::new(RemoteTypeProvider::new(id, components)))
+                            .await
+                    }
+                    PluginHostTypes::EntityTypes(entity_types) => {
+                        self.context
+                            .get_entity_type_provider_registry()
+                            .register_provider(Arc::new(RemoteTypeProvider::new(id, entity_types)))
+                            .await
+                    }
+                    PluginHostTypes::RelationTypes(relation_types) => {
+                        self.context
+                            .get_relation_type_provider_registry()
+                            .register_provider(Arc::new(RemoteTypeProvider::new(id, relation_types)))
+                            .await
+                    }
+                    PluginHostTypes::FlowTypes(flow_types) => {
+                        self.context
+                            .get_flow_type_provider_registry()
+                            .register_provider(Arc::new(RemoteTypeProvider::new(id, flow_types)))
+                            .await
+                    }
+                }
+                Ok(Value::Null)
+            }
+            PluginHostCall::UnregisterTypes { provider_id, kind } => {
+                if self.type_providers.remove(&provider_id).is_some() {
+                    self.unregister_types(&provider_id, kind).await;
+                }
+                Ok(Value::Null)
+            }
+            PluginHostCall::RegisterEntityBehaviour { ty } => {
+                if self.entity_behaviours.insert(ty.clone()) {
+                    let factory = Arc::new(RemoteEntityBehaviourFactory::new(ty.behaviour_ty.clone(), Arc::downgrade(self)));
+                    self.context.get_entity_behaviour_registry().register(ty, factory).await;
+                }
+                Ok(Value::Null)
+            }
+            PluginHostCall::UnregisterEntityBehaviour { ty } => {
+                if self.entity_behaviours.remove(&ty).is_some() {
+                    self.context.get_entity_behaviour_registry().unregister(&ty).await;
+                }
+                Ok(Value::Null)
+            }
+            PluginHostCall::RegisterEntityComponentBehaviour { ty } => {
+                if self.entity_component_behaviours.insert(ty.clone()) {
+                    let factory = Arc::new(RemoteEntityBehaviourFactory::new(ty.behaviour_ty.clone(), Arc::downgrade(self)));
+                    self.context.get_entity_component_behaviour_registry().register(ty, factory).await;
+                }
+                Ok(Value::Null)
+            }
+            PluginHostCall::UnregisterEntityComponentBehaviour { ty } => {
+                if self.entity_component_behaviours.remove(&ty).is_some() {
+                    self.context.get_entity_component_behaviour_registry().unregister(&ty).await;
+                }
+                Ok(Value::Null)
+            }
+            PluginHostCall::HasEntityInstance { id } => to_value(entity_instance_manager.has(id)),
+            PluginHostCall::GetEntityInstance { id } => to_value(entity_instance_manager.get(id).map(|entity| self.observe(&entity))),
+            PluginHostCall::GetEntityInstanceByLabel { label } => to_value(entity_instance_manager.get_by_label(&label).map(|entity| self.observe(&entity))),
+            PluginHostCall::GetEntityInstanceByLabelWithParams { label } => to_value(
+                entity_instance_manager
+                    .get_by_label_with_params(&label)
+                    .map(|(entity, params)| (self.observe(&entity), params)),
+            ),
+            PluginHostCall::GetEntityInstances => to_value(self.observe_all(entity_instance_manager.get_all())),
+            PluginHostCall::GetEntityInstancesByType { ty } => to_value(self.observe_all(entity_instance_manager.get_by_type(&ty))),
+            PluginHostCall::GetEntityInstanceIds => to_value(entity_instance_manager.get_ids()),
+            PluginHostCall::CountEntityInstances => to_value(entity_instance_manager.count()),
+            PluginHostCall::CountEntityInstancesByType { ty } => to_value(entity_instance_manager.count_by_type(&ty)),
+            PluginHostCall::CountEntityInstancesByComponent { component } => to_value(entity_instance_manager.count_by_component(&component)),
+            PluginHostCall::CountEntityInstancesByBehaviour { behaviour_ty } => to_value(entity_instance_manager.count_by_behaviour(&behaviour_ty)),
+            PluginHostCall::CreateEntityInstance { entity_instance } => to_value(
+                entity_instance_manager
+                    .create(entity_instance)
+                    .map(|entity| self.observe(&entity))
+                    .map_err(PluginHostEntityInstanceError::from),
+            ),
+            PluginHostCall::AddComponent { id, component } => {
+                let result = entity_instance_manager
+                    .add_component(id, &component)
+                    .map_err(PluginHostEntityInstanceError::from);
+                // Observe the properties which have been added by the component
+                if let Some(entity) = self.observed.get(&id).map(|entity| entity.value().clone()) {
+                    self.observe(&entity);
+                }
+                to_value(result)
+            }
+            PluginHostCall::RemoveComponent { id, component } => {
+                entity_instance_manager.remove_component(id, &component);
+                Ok(Value::Null)
+            }
+            PluginHostCall::DeleteEntityInstance { id } => {
+                self.unobserve(id);
+                to_value(entity_instance_manager.delete(id))
+            }
+            PluginHostCall::GetInstanceConfig => to_value(config_manager.get_instance_config()),
+            PluginHostCall::GetGraphQLServerConfig => to_value(config_manager.get_graphql_server_config()),
+            PluginHostCall::GetPluginsConfig => to_value(config_manager.get_plugins_config()),
+            PluginHostCall::GetRemotesConfig => to_value(config_manager.get_remotes_config()),
+            PluginHostCall::GetPluginConfig { plugin_name } => to_value(config_manager.get_plugin_config(&plugin_name)),
+        }
+    }
+
+    async fn unregister_types(&self, provider_id: &str, kind: PluginHostTypeKind) {
+        match kind {
+            PluginHostTypeKind::Components => self.context.get_component_provider_registry().unregister_provider(provider_id).await,
+            PluginHostTypeKind::EntityTypes => self.context.get_entity_type_provider_registry().unregister_provider(provider_id).await,
+            PluginHostTypeKind::RelationTypes => self.context.get_relation_type_provider_registry().unregister_provider(provider_id).await,
+            PluginHostTypeKind::FlowTypes => self.context.get_flow_type_provider_registry().unregister_provider(provider_id).await,
+        }
+    }
+
+    /// Restarts the plugin host after it has exited unexpectedly.
+    fn connection_lost(self: &Arc<Self>, connection: &Arc<RemotePluginConnection>) {
+        let is_current_connection = {
+            let mut writer = self.connection.write().unwrap();
+            let is_current_connection = writer.as_ref().is_some_and(|current| Arc::ptr_eq(current, connection));
+            if is_current_connection {
+                *writer = None;
+            }
+            is_current_connection
+        };
+        if !is_current_connection || !self.active.load(Ordering::SeqCst) {
+            return;
+        }
+        for behaviour in self.behaviours.iter() {
+            behaviour.set_state(BehaviourState::Failed);
+        }
+        self.property_cache.clear();
+        // Restarting doesn't help if the plugin host can't run the plugin
+        if let Some(failure) = self.failure.read().unwrap().clone() {
+            error!("[FAILED] {}: The plugin host has exited and won't be restarted: {failure}", self.name);
+            return;
+        }
+        error!("[CRASHED] {}: The plugin host has exited unexpectedly", self.name);
+        if self.restarting.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let host = self.clone();
+        tokio::spawn(async move {
+            host.restart().await;
+            host.restarting.store(false, Ordering::SeqCst);
+        });
+    }
+
+    async fn restart(self: &Arc<Self>) {
+        let mut delay = RESTART_DELAY;
+        for attempt in 1..=self.isolation.max_restarts {
+            tokio::time::sleep(delay).await;
+            if !self.active.load(Ordering::SeqCst) {
+                return;
+            }
+            info!("[RESTART] {}: Restarting the plugin host ({attempt}/{})", self.name, self.isolation.max_restarts);
+            match self.start().await {
+                Ok(_) => {
+                    self.reconnect_behaviours();
+                    info!("[ACTIVE] {}: The plugin host has been restarted", self.name);
+                    return;
+                }
+                // The activation fails again, if the plugin has failed to activate
+                Err(e @ (PluginHostError::Failed(..) | PluginHostError::RequestFailed(..))) => {
+                    error!("[FAILED] {}: {e}. The plugin host won't be restarted again.", self.name);
+                    return;
+                }
+                Err(e) => error!("[FAILED] {}: {e}", self.name),
+            }
+            delay = (delay * 2).min(MAX_RESTART_DELAY);
+        }
+        error!(
+            "[FAILED] {}: Giving up restarting the plugin host after {} attempts. The behaviours provided by the plugin remain failed.",
+            self.name, self.isolation.max_restarts
+        );
+    }
+
+    /// Connects the behaviours again after the plugin host has been restarted.
+    fn reconnect_behaviours(&self) {
+        let behaviours: Vec<Arc<RemoteEntityBehaviour>> = self.behaviours.iter().map(|behaviour| behaviour.value().clone()).collect();
+        for behaviour in behaviours {
+            if behaviour.get_state() == BehaviourState::Failed {
+                if let Err(e) = behaviour.transition(BehaviourState::Connected) {
+                    error!("Failed to reconnect behaviour {} of {}: {e}", behaviour.ty(), behaviour.get_reactive_instance().id);
+                }
+            } else {
+                self.send_connect_behaviour(&behaviour);
+            }
+        }
+    }
+
+    /// Connects the behaviour in the plugin host. If the plugin host is not running, the
+    /// behaviour is connected after the plugin host has been restarted.
+    pub(crate) fn connect_behaviour(self: &Arc<Self>, behaviour: Arc<RemoteEntityBehaviour>) -> Result<(), BehaviourConnectFailed> {
+        if !self.active.load(Ordering::SeqCst) {
+            return Err(BehaviourConnectFailed {});
+        }
+        let entity = behaviour.get_reactive_instance().clone();
+        self.observe(&entity);
+        self.behaviours.insert((entity.id, behaviour.ty().clone()), behaviour.clone());
+        self.send_connect_behaviour(&behaviour);
+        Ok(())
+    }
+
+    fn send_connect_behaviour(&self, behaviour: &Arc<RemoteEntityBehaviour>) {
+        let Some(connection) = self.get_connection() else {
+            return;
+        };
+        let request = PluginHostRequest::ConnectBehaviour {
+            behaviour_ty: behaviour.ty().clone(),
+            entity_instance: EntityInstance::from(behaviour.get_reactive_instance()),
+        };
+        let name = self.name.clone();
+        let behaviour = Arc::downgrade(behaviour);
+        connection.channel.request(
+            request,
+            Box::new(move |result| {
+                let (Err(e), Some(behaviour)) = (result, behaviour.upgrade()) else {
+                    return;
+                };
+                error!(
+                    "[FAILED] {name}: Failed to connect behaviour {} of {}: {e}",
+                    behaviour.ty(),
+                    behaviour.get_reactive_instance().id
+                );
+                behaviour.set_state(BehaviourState::Failed);
+            }),
+        );
+    }
+
+    /// Disconnects the behaviour in the plugin host.
+    pub(crate) fn disconnect_behaviour(&self, id: Uuid, behaviour_ty: &BehaviourTypeId) {
+        if self.behaviours.remove(&(id, behaviour_ty.clone())).is_none() {
+            return;
+        }
+        let Some(connection) = self.get_connection() else {
+            return;
+        };
+        let name = self.name.clone();
+        let request = PluginHostRequest::DisconnectBehaviour {
+            behaviour_ty: behaviour_ty.clone(),
+            id,
+        };
+        connection.channel.request(
+            request,
+            Box::new(move |result| {
+                if let Err(e) = result {
+                    warn!("{name}: Failed to disconnect behaviour of {id}: {e}");
+                }
+            }),
+        );
+    }
+
+    /// Synchronizes the properties of the entity instance with the plugin host.
+    fn observe(self: &Arc<Self>, entity: &ReactiveEntity) -> EntityInstance {
+        let id = entity.id;
+        self.observed.insert(id, entity.clone());
+        let property_names: Vec<String> = entity.properties.iter().map(|property| property.key().clone()).collect();
+        for property_name in property_names {
+            if !self.observed_properties.insert((id, property_name.clone())) {
+                continue;
+            }
+            let host = Arc::downgrade(self);
+            let property = property_name.clone();
+            entity.observe_with_handle(
+                &property_name,
+                move |value: &Value| {
+                    if let Some(host) = host.upgrade() {
+                        host.property_changed_locally(id, &property, value);
+                    }
+                },
+                self.handle_id,
+            );
+        }
+        EntityInstance::from(entity)
+    }
+
+    fn observe_all(self: &Arc<Self>, entities: Vec<ReactiveEntity>) -> Vec<EntityInstance> {
+        entities.iter().map(|entity| self.observe(entity)).collect()
+    }
+
+    fn unobserve(&self, id: Uuid) {
+        if let Some((_, entity)) = self.observed.remove(&id) {
+            let property_names: Vec<String> = entity.properties.iter().map(|property| property.key().clone()).collect();
+            for property_name in property_names {
+                entity.remove_observer(&property_name, self.handle_id);
+            }
+        }
+        self.observed_properties.retain(|(instance_id, _)| *instance_id != id);
+        self.property_cache.remove(id);
+    }
+
+    fn property_changed_locally(&self, id: Uuid, property: &str, value: &Value) {
+        if !self.property_cache.should_send(id, property, value) {
+            return;
+        }
+        if let Some(connection) = self.get_connection() {
+            connection.channel.send(PluginHostMessage::PropertyChanged {
+                id,
+                property: property.to_string(),
+                value: value.clone(),
+            });
+        }
+    }
+
+    fn property_changed_remotely(&self, id: Uuid, property: &str, value: Value) {
+        let Some(entity) = self.observed.get(&id).map(|entity| entity.value().clone()) else {
+            return;
+        };
+        self.property_cache.received(id, property, value.clone());
+        entity.set(property, value);
+    }
+
+    /// Unregisters the types and behaviours which are left over by the plugin and stops synchronizing properties.
+    async fn cleanup(&self) {
+        let entity_behaviours: Vec<EntityBehaviourTypeId> = self.entity_behaviours.iter().map(|ty| ty.clone()).collect();
+        self.entity_behaviours.clear();
+        for ty in entity_behaviours {
+            self.context.get_entity_behaviour_registry().unregister(&ty).await;
+        }
+        let entity_component_behaviours: Vec<ComponentBehaviourTypeId> = self.entity_component_behaviours.iter().map(|ty| ty.clone()).collect();
+        self.entity_component_behaviours.clear();
+        for ty in entity_component_behaviours {
+            self.context.get_entity_component_behaviour_registry().unregister(&ty).await;
+        }
+        let type_providers: Vec<(String, PluginHostTypeKind)> =
+            self.type_providers.iter().map(|provider| (provider.key().clone(), *provider.value())).collect();
+        self.type_providers.clear();
+        for (provider_id, kind) in type_providers {
+            self.unregister_types(&provider_id, kind).await;
+        }
+        // The behaviours are dropped outside of the map, because dropping a behaviour disconnects it
+        let keys: Vec<(Uuid, BehaviourTypeId)> = self.behaviours.iter().map(|behaviour| behaviour.key().clone()).collect();
+        let behaviours: Vec<_> = keys.iter().filter_map(|key| self.behaviours.remove(key)).collect();
+        drop(behaviours);
+        let ids: Vec<Uuid> = self.observed.iter().map(|entity| *entity.key()).collect();
+        for id in ids {
+            self.unobserve(id);
+        }
+        self.property_cache.clear();
+    }
+
+    async fn request(&self, connection: &RemotePluginConnection, request: PluginHostRequest) -> Result<Value, PluginHostError> {
+        let (sender, receiver) = oneshot::channel();
+        connection.channel.request(
+            request,
+            Box::new(move |result| {
+                let _ = sender.send(result);
+            }),
+        );
+        match timeout(REQUEST_TIMEOUT, receiver).await {
+            Ok(Ok(Ok(value))) => Ok(value),
+            Ok(Ok(Err(e))) => Err(PluginHostError::RequestFailed(self.name.clone(), e)),
+            Ok(Err(_)) => Err(PluginHostError::Disconnected(self.name.clone())),
+            Err(_) => Err(PluginHostError::Timeout(self.name.clone())),
+        }
+    }
+
+    fn get_connection(&self) -> Option<Arc<RemotePluginConnection>> {
+        self.connection.read().unwrap().clone()
+    }
+
+    fn take_connection(&self) -> Option<Arc<RemotePluginConnection>> {
+        self.connection.write().unwrap().take()
+    }
+}
+
+/// Provides the types which have been registered by an out-of-process plugin.
+struct RemoteTypeProvider<T> {
+    id: &'static str,
+    types: T,
+}
+
+impl<T> RemoteTypeProvider<T> {
+    fn new(id: &'static str, types: T) -> Self {
+        RemoteTypeProvider { id, types }
+    }
+}
+
+impl<T: NamespacedTypeContainer + Clone + Send + Sync> TypeProvider<T> for RemoteTypeProvider<T> {
+    fn id<'a>(&self) -> &'a str {
+        self.id
+    }
+
+    fn get_types(&self) -> T {
+        self.types.clone()
+    }
+}
+
+/// Accepts the connection of the plugin host which has been authenticated with the token.
+async fn accept(listener: &TcpListener, token: &str) -> std::io::Result<BufReader<TcpStream>> {
+    loop {
+        let (stream, _) = listener.accept().await?;
+        stream.set_nodelay(true)?;
+        let mut stream = BufReader::new(stream);
+        let mut line = String::new();
+        // A connection which doesn't send the token in time can't block the plugin host
+        let handshake = (&mut stream).take(MAX_HANDSHAKE_LEN).read_line(&mut line);
+        if let Ok(Ok(_)) = timeout(HANDSHAKE_TIMEOUT, handshake).await {
+            if line.trim_end() == token {
+                return Ok(stream);
+            }
+        }
+        drop(stream);
+        warn!("Rejected an unauthenticated connection to the plugin host socket");
+    }
+}
+
+/// Returns the static id of a type provider.
+fn static_provider_id(provider_id: String) -> &'static str {
+    if let Some(id) = PROVIDER_IDS.get(provider_id.as_str()) {
+        return *id;
+    }
+    let id: &'static str = provider_id.leak();
+    PROVIDER_IDS.insert(id);
+    id
+}
+
+fn to_value<T: Serialize>(value: T) -> Result<Value, String> {
+    serde_json::to_value(value).map_err(|e| e.to_string())
+}
@@ -0,0 +1,353 @@
+use std::sync::Arc;
+
+use dashmap::DashSet;
+use log::warn;
+use uuid::Uuid;
+
+use reactive_graph_plugin_api::CommandManager;
+use reactive_graph_plugin_api::ComponentImportExportManager;
+use reactive_graph_plugin_api::ComponentManager;
+use reactive_graph_plugin_api::ComponentProviderRegistry;
+use reactive_graph_plugin_api::ConfigManager;
+use reactive_graph_plugin_api::EntityBehaviourRegistry;
+use reactive_graph_plugin_api::EntityComponentBehaviourRegistry;
+use reactive_graph_plugin_api::EntityInstanceManager;
+use reactive_graph_plugin_api::EntityTypeImportExportManager;
+use reactive_graph_plugin_api::EntityTypeManager;
+use reactive_graph_plugin_api::EntityTypeProviderRegistry;
+use reactive_graph_plugin_api::FlowInstanceManager;
+use reactive_graph_plugin_api::FlowTypeImportExportManager;
+use reactive_graph_plugin_api::FlowTypeManager;
+use reactive_graph_plugin_api::FlowTypeProviderRegistry;
+use reactive_graph_plugin_api::GraphQLQueryService;
+use reactive_graph_plugin_api::NamespaceManager;
+use reactive_graph_plugin_api::PLUGIN_NAME_PREFIX;
+use reactive_graph_plugin_api::PluginCapabilities;
+use reactive_graph_plugin_api::PluginCapabilityViolation;
+use reactive_graph_plugin_api::PluginContext;
+use reactive_graph_plugin_api::RelationBehaviourRegistry;
+use reactive_graph_plugin_api::RelationComponentBehaviourRegistry;
+use reactive_graph_plugin_api::RelationInstanceManager;
+use reactive_graph_plugin_api::RelationTypeImportExportManager;
+use reactive_graph_plugin_api::RelationTypeManager;
+use reactive_graph_plugin_api::RelationTypeProviderRegistry;
+use reactive_graph_plugin_api::TypeSystemEventManager;
+use reactive_graph_plugin_api::WebResourceManager;
+
+use crate::RestrictedComponentImportExportManager;
+use crate::RestrictedComponentManager;
+use crate::RestrictedComponentProviderRegistry;
+use crate::RestrictedConfigManager;
+use crate::RestrictedEntityBehaviourRegistry;
+use crate::RestrictedEntityComponentBehaviourRegistry;
+use crate::RestrictedEntityInstanceManager;
+use crate::RestrictedEntityTypeImportExportManager;
+use crate::RestrictedEntityTypeManager;
+use crate::RestrictedEntityTypeProviderRegistry;
+use crate::RestrictedFlowInstanceManager;
+use crate::RestrictedFlowTypeImportExportManager;
+use crate::RestrictedFlowTypeManager;
+use crate::RestrictedFlowTypeProviderRegistry;
+use crate::RestrictedNamespaceManager;
+use crate::RestrictedRelationBehaviourRegistry;
+use crate::RestrictedRelationComponentBehaviourRegistry;
+use crate::RestrictedRelationInstanceManager;
+use crate::RestrictedRelationTypeImportExportManager;
+use crate::RestrictedRelationTypeManager;
+use crate::RestrictedRelationTypeProviderRegistry;
+use crate::RestrictedWebResourceManager;
+
+/// Checks the calls of a plugin against the capabilities declared by the plugin and records
+/// the capability violations.
+pub struct PluginCapabilityGuard {
+    id: Uuid,
+    name: String,
+    capabilities: PluginCapabilities,
+    violations: DashSet<PluginCapabilityViolation>,
+}
+
+impl PluginCapabilityGuard {
+    pub fn new(id: Uuid, name: String, capabilities: PluginCapabilities) -> Self {
+        PluginCapabilityGuard {
+            id,
+            name,
+            capabilities,
+            violations: DashSet::new(),
+        }
+    }
+
+    pub fn check_namespace(&self, namespace: &str) -> Result<(), PluginCapabilityViolation> {
+        if self.capabilities.allows_namespace(namespace) {
+            return Ok(());
+        }
+        self.deny(PluginCapabilityViolation::Namespace(namespace.to_string()))
+    }
+
+    pub fn check_web_context_path(&self, web_context_path: &str) -> Result<(), PluginCapabilityViolation> {
+        if self.capabilities.allows_web_context_path(web_context_path) {
+            return Ok(());
+        }
+        self.deny(PluginCapabilityViolation::WebContextPath(web_context_path.to_string()))
+    }
+
+    pub fn check_command(&self, command: &str) -> Result<(), PluginCapabilityViolation> {
+        if self.capabilities.allows_command(command) {
+            return Ok(());
+        }
+        self.deny(PluginCapabilityViolation::Command(command.to_string()))
+    }
+
+    pub fn check_config(&self, config: &str) -> Result<(), PluginCapabilityViolation> {
+        if self.capabilities.config {
+            return Ok(());
+        }
+        self.deny(PluginCapabilityViolation::Config(config.to_string()))
+    }
+
+    /// The plugin can always read its own plugin configuration.
+    pub fn check_plugin_config(&self, plugin_name: &str) -> Result<(), PluginCapabilityViolation> {
+        if plugin_name == self.name || plugin_name == self.name.replace(PLUGIN_NAME_PREFIX, "") {
+            return Ok(());
+        }
+        self.check_config(plugin_name)
+    }
+
+    /// Returns the capability violations of the plugin, sorted by their message.
+    pub fn get_violations(&self) -> Vec<PluginCapabilityViolation> {
+        let mut violations: Vec<PluginCapabilityViolation> = self.violations.iter().map(|violation| violation.key().clone()).collect();
+        violations.sort_by_key(|violation| violation.to_string());
+        violations
+    }
+
+    fn deny(&self, violation: PluginCapabilityViolation) -> Result<(), PluginCapabilityViolation> {
+        warn!("[DENIED] Plugin {} ({}): {violation}", self.name, self.id);
+        self.violations.insert(violation.clone());
+        Err(violation)
+    }
+}
+
+/// The plugin context of a plugin which has declared its capabilities.
+///
+/// The registries and the managers which are used for registering, creating or modifying
+/// resources are wrapped, so that the plugin can only register resources it has declared.
+/// Everything else is passed through.
+pub struct RestrictedPluginContext {
+    context: Arc<dyn PluginContext + Send + Sync>,
+    guard: Arc<PluginCapabilityGuard>,
+    component_manager: Arc<dyn ComponentManager + Send + Sync>,
+    component_import_export_manager: Arc<dyn ComponentImportExportManager + Send + Sync>,
+    component_provider_registry: Arc<dyn ComponentProviderRegistry + Send + Sync>,
+    entity_type_manager: Arc<dyn EntityTypeManager + Send + Sync>,
+    entity_type_import_export_manager: Arc<dyn EntityTypeImportExportManager + Send + Sync>,
+    entity_type_provider_registry: Arc<dyn EntityTypeProviderRegistry + Send + Sync>,
+    relation_type_manager: Arc<dyn RelationTypeManager + Send + Sync>,
+    relation_type_import_export_manager: Arc<dyn RelationTypeImportExportManager + Send + Sync>,
+    relation_type_provider_registry: Arc<dyn RelationTypeProviderRegistry + Send + Sync>,
+    flow_type_manager: Arc<dyn FlowTypeManager + Send + Sync>,
+    flow_type_import_export_manager: Arc<dyn FlowTypeImportExportManager + Send + Sync>,
+    flow_type_provider_registry: Arc<dyn FlowTypeProviderRegistry + Send + Sync>,
+    namespace_manager: Arc<dyn NamespaceManager + Send + Sync>,
+    entity_instance_manager: Arc<dyn EntityInstanceManager + Send + Sync>,
+    relation_instance_manager: Arc<dyn RelationInstanceManager + Send + Sync>,
+    flow_instance_manager: Arc<dyn FlowInstanceManager + Send + Sync>,
+    entity_behaviour_registry: Arc<dyn EntityBehaviourRegistry + Send + Sync>,
+    entity_component_behaviour_registry: Arc<dyn EntityComponentBehaviourRegistry + Send + Sync>,
+    relation_behaviour_registry: Arc<dyn RelationBehaviourRegistry + Send + Sync>,
+    relation_component_behaviour_registry: Arc<dyn RelationComponentBehaviourRegistry + Send + Sync>,
+    web_resource_manager: Arc<dyn WebResourceManager + Send + Sync>,
+    config_manager: Arc<dyn ConfigManager + Send + Sync>,
+}
+
+impl RestrictedPluginContext {
+    pub fn new(context: Arc<dyn PluginContext + Send + Sync>, guard: Arc<PluginCapabilityGuard>) -> Self {
+        RestrictedPluginContext {
+            component_manager: Arc::new(RestrictedComponentManager::new(context.get_component_manager(), guard.clone())),
+            component_import_export_manager: Arc::new(RestrictedComponentImportExportManager::new(
+                context.get_component_import_export_manager(),
+                context.get_component_manager(),
+                guard.clone(),
+            )),
+            entity_type_manager: Arc::new(RestrictedEntityTypeManager::new(context.get_entity_type_manager(), guard.clone())),
+            entity_type_import_export_manager: Arc::new(RestrictedEntityTypeImportExportManager::new(
+                context.get_entity_type_import_export_manager(),
+                context.get_entity_type_manager(),
+                guard.clone(),
+            )),
+            relation_type_manager: Arc::new(RestrictedRelationTypeManager::new(context.get_relation_type_manager(), guard.clone())),
+            relation_type_import_export_manager: Arc::new(RestrictedRelationTypeImportExportManager::new(
+                context.get_relation_type_import_export_manager(),
+                context.get_relation_type_manager(),
+                guard.clone(),
+            )),
+            flow_type_manager: Arc::new(RestrictedFlowTypeManager::new(context.get_flow_type_manager(), guard.clone())),
+            flow_type_import_export_manager: Arc::new(RestrictedFlowTypeImportExportManager::new(
+                context.get_flow_type_import_export_manager(),
+                context.get_flow_type_manager(),
+                guard.clone(),
+            )),
+            relation_instance_manager: Arc::new(RestrictedRelationInstanceManager::new(context.get_relation_instance_manager(), guard.clone())),
+            flow_instance_manager: Arc::new(RestrictedFlowInstanceManager::new(context.get_flow_instance_manager(), guard.clone())),
+            component_provider_registry: Arc::new(RestrictedComponentProviderRegistry::new(context.get_component_provider_registry(), guard.clone())),
+            entity_type_provider_registry: Arc::new(RestrictedEntityTypeProviderRegistry::new(context.get_entity_type_provider_registry(), guard.clone())),
+            relation_type_provider_registry: Arc::new(RestrictedRelationTypeProviderRegistry::new(
+                context.get_relation_type_provider_registry(),
+                guard.clone(),
+            )),
+            flow_type_provider_registry: Arc::new(RestrictedFlowTypeProviderRegistry::new(context.get_flow_type_provider_registry(), guard.clone())),
+            namespace_manager: Arc::new(RestrictedNamespaceManager::new(context.get_namespace_manager(), guard.clone())),
+            entity_instance_manager: Arc::new(RestrictedEntityInstanceManager::new(context.get_entity_instance_manager(), guard.clone())),
+            entity_behaviour_registry: Arc::new(RestrictedEntityBehaviourRegistry::new(context.get_entity_behaviour_registry(), guard.clone())),
+            entity_component_behaviour_registry: Arc::new(RestrictedEntityComponentBehaviourRegistry::new(
+                context.get_entity_component_behaviour_registry(),
+                guard.clone(),
+            )),
+            relation_behaviour_registry: Arc::new(RestrictedRelationBehaviourRegistry::new(context.get_relation_behaviour_registry(), guard.clone())),
+            relation_component_behaviour_registry: Arc::new(RestrictedRelationComponentBehaviourRegistry::new(
+                context.get_relation_component_behaviour_registry(),
+                guard.clone(),
+            )),
+            web_resource_manager: Arc::new(RestrictedWebResourceManager::new(context.get_web_resource_manager(), guard.clone())),
+            config_manager: Arc::new(RestrictedConfigManager::new(context.get_config_manager(), guard.clone())),
+            context,
+            guard,
+        }
+    }
+
+    /// Returns the capability violations of the plugin.
+    pub fn get_violations(&self) -> Vec<PluginCapabilityViolation> {
+        self.guard.get_violations()
+    }
+}
+
+impl PluginContext for RestrictedPluginContext {
+    fn get_component_manager(&self) -> Arc<dyn ComponentManager + Send + Sync> {
+        self.component_manager.clone()
+    }
+
+    fn get_component_import_export_manager(&self) -> Arc<dyn ComponentImportExportManager + Send + Sync> {
+        self.component_import_export_manager.clone()
+    }
+
+    fn get_component_provider_registry(&self) -> Arc<dyn ComponentProviderRegistry + Send + Sync> {
+        self.component_provider_registry.clone()
+    }
+
+    fn get_entity_type_manager(&self) -> Arc<dyn EntityTypeManager + Send + Sync> {
+        self.entity_type_manager.clone()
+    }
+
+    fn get_entity_type_import_export_manager(&self) -> Arc<dyn EntityTypeImportExportManager + Send + Sync> {
+        self.entity_type_import_export_manager.clone()
+    }
+
+    fn get_entity_type_provider_registry(&self) -> Arc<dyn EntityTypeProviderRegistry + Send + Sync> {
+        self.entity_type_provider_registry.clone()
+    }
+
+    fn get_relation_type_manager(&self) -> Arc<dyn RelationTypeManager + Send + Sync> {
+        self.relation_type_manager.clone()
+    }
+
+    fn get_relation_type_import_export_manager(&self) -> Arc<dyn RelationTypeImportExportManager + Send + Sync> {
+        self.relation_type_import_export_manager.clone()
+    }
+
+    fn get_relation_type_provider_registry(&self) -> Arc<dyn RelationTypeProviderRegistry + Send + Sync> {
+        self.relation_type_provider_registry.clone()
+    }
+
+    fn get_flow_type_manager(&self) -> Arc<dyn FlowTypeManager + Send + Sync> {
+        self.flow_type_manager.clone()
+    }
+
+    fn get_flow_type_import_export_manager(&self) -> Arc<dyn FlowTypeImportExportManager + Send + Sync> {
+        self.flow_type_import_export_manager.clone()
+    }
+
+    fn get_flow_type_provider_registry(&self) -> Arc<dyn FlowTypeProviderRegistry + Send + Sync> {
+        self.flow_type_provider_registry.clone()
+    }
+
+    fn get_namespace_manager(&self) -> Arc<dyn NamespaceManager + Send + Sync> {
+        self.namespace_manager.clone()
+    }
+
+    fn get_type_system_event_manager(&self) -> Arc<dyn TypeSystemEventManager + Send + Sync> {
+        self.context.get_type_system_event_manager()
+    }
+
+    fn get_entity_instance_manager(&self) -> Arc<dyn EntityInstanceManager + Send + Sync> {
+        self.entity_instance_manager.clone()
+    }
+
+    fn get_relation_instance_manager(&self) -> Arc<dyn RelationInstanceManager + Send + Sync> {
+        self.relation_instance_manager.clone()
+    }
+
+    fn get_flow_instance_manager(&self) -> Arc<dyn FlowInstanceManager + Send + Sync> {
+        self.flow_instance_manager.clone()
+    }
+
+    fn get_entity_behaviour_registry(&self) -> Arc<dyn EntityBehaviourRegistry + Send + Sync> {
+        self.entity_behaviour_registry.clone()
+    }
+
+    fn get_entity_component_behaviour_registry(&self) -> Arc<dyn EntityComponentBehaviourRegistry + Send + Sync> {
+        self.entity_component_behaviour_registry.clone()
+    }
+
+    fn get_relation_behaviour_registry(&self) -> Arc<dyn RelationBehaviourRegistry + Send + Sync> {
+        self.relation_behaviour_registry.clone()
+    }
+
+    fn get_relation_component_behaviour_registry(&self) -> Arc<dyn RelationComponentBehaviourRegistry + Send + Sync> {
+        self.relation_component_behaviour_registry.clone()
+    }
+
+    fn get_graphql_query_service(&self) -> Arc<dyn GraphQLQueryService + Send + Sync> {
+        self.context.get_graphql_query_service()
+    }
+
+    fn get_web_resource_manager(&self) -> Arc<dyn WebResourceManager + Send + Sync> {
+        self.web_resource_manager.clone()
+    }
+
+    fn get_config_manager(&self) -> Arc<dyn ConfigManager + Send + Sync> {
+        self.config_manager.clone()
+    }
+
+    fn get_command_manager(&self) -> Arc<dyn CommandManager + Send + Sync> {
+        self.context.get_command_manager()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use reactive_graph_plugin_api::PluginCapabilities;
+    use reactive_graph_plugin_api::PluginCapabilityViolation;
+
+    use crate::PluginCapabilityGuard;
+
+    #[test]
+    fn plugin_capability_guard_test() {
+        let capabilities = PluginCapabilities::new().namespace("test").web_context_path("test").command("test");
+        let guard = PluginCapabilityGuard::new(Uuid::new_v4(), "reactive-graph-plugin-test".to_string(), capabilities);
+        assert!(guard.check_namespace("test").is_ok());
+        assert!(guard.check_web_context_path("test").is_ok());
+        assert!(guard.check_command("test").is_ok());
+        // The plugin can read its own configuration, with or without the prefix
+        assert!(guard.check_plugin_config("reactive-graph-plugin-test").is_ok());
+        assert!(guard.check_plugin_config("test").is_ok());
+        assert!(guard.get_violations().is_empty());
+
+        assert_eq!(Err(PluginCapabilityViolation::Namespace("core".to_string())), guard.check_namespace("core"));
+        assert_eq!(Err(PluginCapabilityViolation::Command("shutdown".to_string())), guard.check_command("shutdown"));
+        assert_eq!(Err(PluginCapabilityViolation::Config("instance".to_string())), guard.check_config("instance"));
+        assert_eq!(Err(PluginCapabilityViolation::Config("other".to_string())), guard.check_plugin_config("other"));
+        // Repeated violations are recorded once
+        assert!(guard.check_namespace("core").is_err());
+        assert_eq!(4, guard.get_violations().len());
+    }
+}
@@ -0,0 +1,1183 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+use uuid::Uuid;
+
+use reactive_graph_behaviour_model_api::BehaviourFactory;
+use reactive_graph_behaviour_model_api::BehaviourTypeId;
+use reactive_graph_behaviour_model_api::ComponentBehaviourTypeId;
+use reactive_graph_behaviour_model_api::EntityBehaviourTypeId;
+use reactive_graph_behaviour_model_api::RelationBehaviourTypeId;
+use reactive_graph_command_model::COMPONENT_COMMAND;
+use reactive_graph_command_model::CommandProperties::COMMAND_NAME;
+use reactive_graph_config_model::GraphQLServerConfig;
+use reactive_graph_config_model::InstanceConfig;
+use reactive_graph_config_model::PluginsConfig;
+use reactive_graph_config_model::RemotesConfig;
+use reactive_graph_graph::Component;
+use reactive_graph_graph::ComponentAddExtensionError;
+use reactive_graph_graph::ComponentAddPropertyError;
+use reactive_graph_graph::ComponentContainer;
+use reactive_graph_graph::ComponentOrEntityTypeId;
+use reactive_graph_graph::ComponentRemoveExtensionError;
+use reactive_graph_graph::ComponentRemovePropertyError;
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::ComponentTypeIdContainer;
+use reactive_graph_graph::ComponentTypeIds;
+use reactive_graph_graph::ComponentUpdateError;
+use reactive_graph_graph::ComponentUpdateExtensionError;
+use reactive_graph_graph::ComponentUpdatePropertyError;
+use reactive_graph_graph::Components;
+use reactive_graph_graph::EntityInstance;
+use reactive_graph_graph::EntityInstances;
+use reactive_graph_graph::EntityType;
+use reactive_graph_graph::EntityTypeAddComponentError;
+use reactive_graph_graph::EntityTypeAddExtensionError;
+use reactive_graph_graph::EntityTypeAddPropertyError;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::EntityTypeRemoveComponentError;
+use reactive_graph_graph::EntityTypeRemoveExtensionError;
+use reactive_graph_graph::EntityTypeRemovePropertyError;
+use reactive_graph_graph::EntityTypeUpdateError;
+use reactive_graph_graph::EntityTypes;
+use reactive_graph_graph::Extension;
+use reactive_graph_graph::ExtensionTypeId;
+use reactive_graph_graph::Extensions;
+use reactive_graph_graph::FlowInstance;
+use reactive_graph_graph::FlowType;
+use reactive_graph_graph::FlowTypeAddEntityInstanceError;
+use reactive_graph_graph::FlowTypeAddExtensionError;
+use reactive_graph_graph::FlowTypeAddVariableError;
+use reactive_graph_graph::FlowTypeId;
+use reactive_graph_graph::FlowTypeRemoveEntityInstanceError;
+use reactive_graph_graph::FlowTypeRemoveExtensionError;
+use reactive_graph_graph::FlowTypeRemoveVariableError;
+use reactive_graph_graph::FlowTypeUpdateEntityInstanceError;
+use reactive_graph_graph::FlowTypeUpdateError;
+use reactive_graph_graph::FlowTypeUpdateExtensionError;
+use reactive_graph_graph::FlowTypeUpdateVariableError;
+use reactive_graph_graph::FlowTypes;
+use reactive_graph_graph::LabelPattern;
+use reactive_graph_graph::Mutability;
+use reactive_graph_graph::Namespace;
+use reactive_graph_graph::NamespacedTypeGetter;
+use reactive_graph_graph::PropertyInstanceGetter;
+use reactive_graph_graph::PropertyInstances;
+use reactive_graph_graph::PropertyType;
+use reactive_graph_graph::PropertyTypes;
+use reactive_graph_graph::RelationInstance;
+use reactive_graph_graph::RelationInstanceId;
+use reactive_graph_graph::RelationInstances;
+use reactive_graph_graph::RelationType;
+use reactive_graph_graph::RelationTypeAddComponentError;
+use reactive_graph_graph::RelationTypeAddExtensionError;
+use reactive_graph_graph::RelationTypeAddPropertyError;
+use reactive_graph_graph::RelationTypeId;
+use reactive_graph_graph::RelationTypeRemoveComponentError;
+use reactive_graph_graph::RelationTypeRemoveExtensionError;
+use reactive_graph_graph::RelationTypeRemovePropertyError;
+use reactive_graph_graph::RelationTypeUpdateError;
+use reactive_graph_graph::RelationTypeUpdateExtensionError;
+use reactive_graph_graph::RelationTypeUpdatePropertyError;
+use reactive_graph_graph::RelationTypes;
+use reactive_graph_graph::Variable;
+use reactive_graph_plugin_api::ComponentImportExportManager;
+use reactive_graph_plugin_api::ComponentManager;
+use reactive_graph_plugin_api::ComponentProviderRegistry;
+use reactive_graph_plugin_api::ConfigManager;
+use reactive_graph_plugin_api::EntityBehaviourRegistry;
+use reactive_graph_plugin_api::EntityComponentBehaviourRegistry;
+use reactive_graph_plugin_api::EntityInstanceManager;
+use reactive_graph_plugin_api::EntityTypeImportExportManager;
+use reactive_graph_plugin_api::EntityTypeManager;
+use reactive_graph_plugin_api::EntityTypeProviderRegistry;
+use reactive_graph_plugin_api::FlowInstanceManager;
+use reactive_graph_plugin_api::FlowTypeImportExportManager;
+use reactive_graph_plugin_api::FlowTypeManager;
+use reactive_graph_plugin_api::FlowTypeProviderRegistry;
+use reactive_graph_plugin_api::NamespaceManager;
+use reactive_graph_plugin_api::RelationBehaviourRegistry;
+use reactive_graph_plugin_api::RelationComponentBehaviourRegistry;
+use reactive_graph_plugin_api::RelationInstanceManager;
+use reactive_graph_plugin_api::RelationTypeImportExportManager;
+use reactive_graph_plugin_api::RelationTypeManager;
+use reactive_graph_plugin_api::RelationTypeProviderRegistry;
+use reactive_graph_plugin_api::TypeProvider;
+use reactive_graph_plugin_api::WebResourceManager;
+use reactive_graph_plugin_api::WebResourceProvider;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+use reactive_graph_reactive_model_impl::ReactiveFlow;
+use reactive_graph_reactive_model_impl::ReactiveRelation;
+use reactive_graph_reactive_service_api::ReactiveEntityComponentAddError;
+use reactive_graph_reactive_service_api::ReactiveEntityCreationError;
+use reactive_graph_reactive_service_api::ReactiveEntityRegistrationError;
+use reactive_graph_reactive_service_api::ReactiveFlowCreationError;
+use reactive_graph_reactive_service_api::ReactiveRelationComponentAddError;
+use reactive_graph_reactive_service_api::ReactiveRelationComponentRemoveError;
+use reactive_graph_reactive_service_api::ReactiveRelationCreationError;
+use reactive_graph_reactive_service_api::ReactiveRelationPropertyAddError;
+use reactive_graph_reactive_service_api::ReactiveRelationPropertyRemoveError;
+use reactive_graph_reactive_service_api::ReactiveRelationRegistrationError;
+use reactive_graph_type_system_api::ComponentCreationError;
+use reactive_graph_type_system_api::ComponentExportError;
+use reactive_graph_type_system_api::ComponentImportError;
+use reactive_graph_type_system_api::EntityTypeCreationError;
+use reactive_graph_type_system_api::EntityTypeExportError;
+use reactive_graph_type_system_api::EntityTypeImportError;
+use reactive_graph_type_system_api::FlowTypeCreationError;
+use reactive_graph_type_system_api::FlowTypeExportError;
+use reactive_graph_type_system_api::FlowTypeImportError;
+use reactive_graph_type_system_api::NamespaceRegistrationError;
+use reactive_graph_type_system_api::RelationTypeCreationError;
+use reactive_graph_type_system_api::RelationTypeExportError;
+use reactive_graph_type_system_api::RelationTypeImportError;
+
+use crate::PluginCapabilityGuard;
+
+macro_rules! restricted_type_provider_registry {
+    ($registry: ident, $registry_trait: ident, $types: ident) => {
+        /// Only registers type providers whose types are in the namespaces permitted for a plugin.
+        pub struct $registry {
+            registry: Arc<dyn $registry_trait + Send + Sync>,
+            guard: Arc<PluginCapabilityGuard>,
+        }
+
+        impl $registry {
+            pub fn new(registry: Arc<dyn $registry_trait + Send + Sync>, guard: Arc<PluginCapabilityGuard>) -> Self {
+                $registry { registry, guard }
+            }
+        }
+
+        #[async_trait]
+        impl $registry_trait for $registry {
+            async fn register_provider(&self, provider: Arc<dyn TypeProvider<$types>>) {
+                // Check every type in order to record every violation
+                let violations = provider
+                    .get_type_ids()
+                    .to_vec()
+                    .iter()
+                    .filter(|ty| self.guard.check_namespace(&ty.namespace()).is_err())
+                    .count();
+                if violations == 0 {
+                    self.registry.register_provider(provider).await
+                }
+            }
+
+            async fn unregister_provider(&self, id: &str) {
+                self.registry.unregister_provider(id).await
+            }
+        }
+    };
+}
+
+restricted_type_provider_registry!(RestrictedComponentProviderRegistry, ComponentProviderRegistry, Components);
+restricted_type_provider_registry!(RestrictedEntityTypeProviderRegistry, EntityTypeProviderRegistry, EntityTypes);
+restricted_type_provider_registry!(RestrictedRelationTypeProviderRegistry, RelationTypeProviderRegistry, RelationTypes);
+restricted_type_provider_registry!(RestrictedFlowTypeProviderRegistry, FlowTypeProviderRegistry, FlowTypes);
+
+macro_rules! restricted_behaviour_registry {
+    ($registry: ident, $registry_trait: ident, $behaviour_ty: ident, $id: ty, $instance: ty) => {
+        /// Only registers behaviours which are in the namespaces permitted for a plugin.
+        pub struct $registry {
+            registry: Arc<dyn $registry_trait + Send + Sync>,
+            guard: Arc<PluginCapabilityGuard>,
+        }
+
+        impl $registry {
+            pub fn new(registry: Arc<dyn $registry_trait + Send + Sync>, guard: Arc<PluginCapabilityGuard>) -> Self {
+                $registry { registry, guard }
+            }
+        }
+
+        #[async_trait]
+        impl $registry_trait for $registry {
+            async fn register(&self, ty: $behaviour_ty, factory: Arc<dyn BehaviourFactory<$id, $instance> + Send + Sync>) {
+                if self.guard.check_namespace(&ty.behaviour_ty.namespace()).is_ok() {
+                    self.registry.register(ty, factory).await
+                }
+            }
+
+            async fn unregister(&self, ty: &$behaviour_ty) {
+                self.registry.unregister(ty).await
+            }
+        }
+    };
+}
+
+restricted_behaviour_registry!(RestrictedEntityBehaviourRegistry, EntityBehaviourRegistry, EntityBehaviourTypeId, Uuid, ReactiveEntity);
+restricted_behaviour_registry!(
+    RestrictedEntityComponentBehaviourRegistry,
+    EntityComponentBehaviourRegistry,
+    ComponentBehaviourTypeId,
+    Uuid,
+    ReactiveEntity
+);
+restricted_behaviour_registry!(
+    RestrictedRelationBehaviourRegistry,
+    RelationBehaviourRegistry,
+    RelationBehaviourTypeId,
+    RelationInstanceId,
+    ReactiveRelation
+);
+restricted_behaviour_registry!(
+    RestrictedRelationComponentBehaviourRegistry,
+    RelationComponentBehaviourRegistry,
+    ComponentBehaviourTypeId,
+    RelationInstanceId,
+    ReactiveRelation
+);
+
+/// Only registers namespaces which are permitted for a plugin.
+pub struct RestrictedNamespaceManager {
+    manager: Arc<dyn NamespaceManager + Send + Sync>,
+    guard: Arc<PluginCapabilityGuard>,
+}
+
+impl RestrictedNamespaceManager {
+    pub fn new(manager: Arc<dyn NamespaceManager + Send + Sync>, guard: Arc<PluginCapabilityGuard>) -> Self {
+        RestrictedNamespaceManager { manager, guard }
+    }
+}
+
+impl NamespaceManager for RestrictedNamespaceManager {
+    fn get_namespaces(&self) -> Vec<Namespace> {
+        self.manager.get_namespaces()
+    }
+
+    fn get(&self, name: &str) -> Option<Namespace> {
+        self.manager.get(name)
+    }
+
+    fn has(&self, name: &str) -> bool {
+        self.manager.has(name)
+    }
+
+    fn register(&self, namespace: Namespace) -> Result<Namespace, NamespaceRegistrationError> {
+        self.guard
+            .check_namespace(&namespace.name)
+            .map_err(|violation| NamespaceRegistrationError::PermissionDenied(violation.to_string()))?;
+        self.manager.register(namespace)
+    }
+
+    fn unregister(&self, name: &str) -> Option<Namespace> {
+        self.guard.check_namespace(name).ok()?;
+        self.manager.unregister(name)
+    }
+}
+
+/// Only creates commands which are permitted for a plugin.
+///
+/// Commands are entity instances with the component `core__command`.
+pub struct RestrictedEntityInstanceManager {
+    manager: Arc<dyn EntityInstanceManager + Send + Sync>,
+    guard: Arc<PluginCapabilityGuard>,
+}
+
+impl RestrictedEntityInstanceManager {
+    pub fn new(manager: Arc<dyn EntityInstanceManager + Send + Sync>, guard: Arc<PluginCapabilityGuard>) -> Self {
+        RestrictedEntityInstanceManager { manager, guard }
+    }
+}
+
+impl EntityInstanceManager for RestrictedEntityInstanceManager {
+    fn has(&self, id: Uuid) -> bool {
+        self.manager.has(id)
+    }
+
+    fn get(&self, id: Uuid) -> Option<ReactiveEntity> {
+        self.manager.get(id)
+    }
+
+    fn get_by_label(&self, label: &str) -> Option<ReactiveEntity> {
+        self.manager.get_by_label(label)
+    }
+
+    fn get_by_label_with_params(&self, label: &str) -> Option<(ReactiveEntity, HashMap<String, String>)> {
+        self.manager.get_by_label_with_params(label)
+    }
+
+    fn get_by_label_pattern(&self, pattern: &LabelPattern) -> Vec<(ReactiveEntity, HashMap<String, String>)> {
+        self.manager.get_by_label_pattern(pattern)
+    }
+
+    fn get_all(&self) -> Vec<ReactiveEntity> {
+        self.manager.get_all()
+    }
+
+    fn get_by_type(&self, ty: &EntityTypeId) -> Vec<ReactiveEntity> {
+        self.manager.get_by_type(ty)
+    }
+
+    fn get_ids(&self) -> Vec<Uuid> {
+        self.manager.get_ids()
+    }
+
+    fn count(&self) -> usize {
+        self.manager.count()
+    }
+
+    fn count_by_type(&self, ty: &EntityTypeId) -> usize {
+        self.manager.count_by_type(ty)
+    }
+
+    fn count_by_component(&self, component: &ComponentTypeId) -> usize {
+        self.manager.count_by_component(component)
+    }
+
+    fn count_by_behaviour(&self, behaviour_ty: &BehaviourTypeId) -> usize {
+        self.manager.count_by_behaviour(behaviour_ty)
+    }
+
+    fn create(&self, entity_instance: EntityInstance) -> Result<ReactiveEntity, ReactiveEntityCreationError> {
+        if entity_instance.is_a(&COMPONENT_COMMAND) {
+            let command = entity_instance.as_string(COMMAND_NAME).unwrap_or_default();
+            self.guard
+                .check_command(&command)
+                .map_err(|violation| ReactiveEntityRegistrationError::PermissionDenied(violation.to_string()))?;
+        }
+        self.manager.create(entity_instance)
+    }
+
+    fn register(&self, reactive_entity: ReactiveEntity) -> Result<ReactiveEntity, ReactiveEntityRegistrationError> {
+        if reactive_entity.is_a(&COMPONENT_COMMAND) {
+            let command = reactive_entity.as_string(COMMAND_NAME).unwrap_or_default();
+            self.guard
+                .check_command(&command)
+                .map_err(|violation| ReactiveEntityRegistrationError::PermissionDenied(violation.to_string()))?;
+        }
+        self.manager.register(reactive_entity)
+    }
+
+    fn add_component(&self, id: Uuid, component: &ComponentTypeId) -> Result<(), ReactiveEntityComponentAddError> {
+        if component == COMPONENT_COMMAND.deref() {
+            let command = self
+                .manager
+                .get(id)
+                .and_then(|reactive_entity| reactive_entity.as_string(COMMAND_NAME))
+                .unwrap_or_default();
+            self.guard
+                .check_command(&command)
+                .map_err(|violation| ReactiveEntityComponentAddError::PermissionDenied(violation.to_string()))?;
+        }
+        self.manager.add_component(id, component)
+    }
+
+    fn remove_component(&self, id: Uuid, component: &ComponentTypeId) {
+        self.manager.remove_component(id, component)
+    }
+
+    fn delete(&self, id: Uuid) -> bool {
+        self.manager.delete(id)
+    }
+}
+
+/// Only registers web resource providers for the context paths permitted for a plugin.
+pub struct RestrictedWebResourceManager {
+    manager: Arc<dyn WebResourceManager + Send + Sync>,
+    guard: Arc<PluginCapabilityGuard>,
+}
+
+impl RestrictedWebResourceManager {
+    pub fn new(manager: Arc<dyn WebResourceManager + Send + Sync>, guard: Arc<PluginCapabilityGuard>) -> Self {
+        RestrictedWebResourceManager { manager, guard }
+    }
+}
+
+#[async_trait]
+impl WebResourceManager for RestrictedWebResourceManager {
+    async fn register_provider(&self, web_resource_provider: Arc<dyn WebResourceProvider>) {
+        if self.guard.check_web_context_path(&web_resource_provider.get_context_path()).is_ok() {
+            self.manager.register_provider(web_resource_provider).await
+        }
+    }
+
+    async fn unregister_provider(&self, id: Uuid) {
+        self.manager.unregister_provider(id).await
+    }
+}
+
+/// Only permits reading the configuration of the application if the plugin has the capability.
+///
+/// The plugin can always read its own plugin configuration. Denied configurations are returned
+/// with their default values.
+pub struct RestrictedConfigManager {
+    manager: Arc<dyn ConfigManager + Send + Sync>,
+    guard: Arc<PluginCapabilityGuard>,
+}
+
+impl RestrictedConfigManager {
+    pub fn new(manager: Arc<dyn ConfigManager + Send + Sync>, guard: Arc<PluginCapabilityGuard>) -> Self {
+        RestrictedConfigManager { manager, guard }
+    }
+}
+
+impl ConfigManager for RestrictedConfigManager {
+    fn get_instance_config(&self) -> InstanceConfig {
+        if self.guard.check_config("instance").is_err() {
+            return InstanceConfig::default();
+        }
+        self.manager.get_instance_config()
+    }
+
+    fn get_graphql_server_config(&self) -> GraphQLServerConfig {
+        if self.guard.check_config("graphql").is_err() {
+            return GraphQLServerConfig::default();
+        }
+        self.manager.get_graphql_server_config()
+    }
+
+    fn get_plugins_config(&self) -> PluginsConfig {
+        if self.guard.check_config("plugins").is_err() {
+            return PluginsConfig::default();
+        }
+        self.manager.get_plugins_config()
+    }
+
+    fn get_remotes_config(&self) -> RemotesConfig {
+        if self.guard.check_config("remotes").is_err() {
+            return RemotesConfig::default();
+        }
+        self.manager.get_remotes_config()
+    }
+
+    fn get_plugin_config(&self, plugin_name: &str) -> Option<Value> {
+        self.guard.check_plugin_config(plugin_name).ok()?;
+        self.manager.get_plugin_config(plugin_name)
+    }
+}
+
+macro_rules! restricted_import_export_manager {
+    ($manager: ident, $manager_trait: ident, $type_manager_trait: ident, $type: ident, $type_id: ident, $import_error: ident, $export_error: ident) => {
+        /// Only imports types which are in the namespaces permitted for a plugin.
+        ///
+        /// The type is only known after reading the file, so an imported type in a denied
+        /// namespace is removed again.
+        pub struct $manager {
+            manager: Arc<dyn $manager_trait + Send + Sync>,
+            type_manager: Arc<dyn $type_manager_trait + Send + Sync>,
+            guard: Arc<PluginCapabilityGuard>,
+        }
+
+        impl $manager {
+            pub fn new(
+                manager: Arc<dyn $manager_trait + Send + Sync>,
+                type_manager: Arc<dyn $type_manager_trait + Send + Sync>,
+                guard: Arc<PluginCapabilityGuard>,
+            ) -> Self {
+                $manager {
+                    manager,
+                    type_manager,
+                    guard,
+                }
+            }
+        }
+
+        #[async_trait]
+        impl $manager_trait for $manager {
+            async fn import(&self, path: &str) -> Result<$type, $import_error> {
+                let imported = self.manager.import(path).await?;
+                if let Err(violation) = self.guard.check_namespace(&imported.ty.namespace()) {
+                    self.type_manager.delete(&imported.ty);
+                    return Err($import_error::PermissionDenied(violation.to_string()));
+                }
+                Ok(imported)
+            }
+
+            async fn export(&self, ty: &$type_id, path: &str) -> Result<(), $export_error> {
+                self.manager.export(ty, path).await
+            }
+        }
+    };
+}
+
+restricted_import_export_manager!(
+    RestrictedComponentImportExportManager,
+    ComponentImportExportManager,
+    ComponentManager,
+    Component,
+    ComponentTypeId,
+    ComponentImportError,
+    ComponentExportError
+);
+restricted_import_export_manager!(
+    RestrictedEntityTypeImportExportManager,
+    EntityTypeImportExportManager,
+    EntityTypeManager,
+    EntityType,
+    EntityTypeId,
+    EntityTypeImportError,
+    EntityTypeExportError
+);
+restricted_import_export_manager!(
+    RestrictedRelationTypeImportExportManager,
+    RelationTypeImportExportManager,
+    RelationTypeManager,
+    RelationType,
+    RelationTypeId,
+    RelationTypeImportError,
+    RelationTypeExportError
+);
+restricted_import_export_manager!(
+    RestrictedFlowTypeImportExportManager,
+    FlowTypeImportExportManager,
+    FlowTypeManager,
+    FlowType,
+    FlowTypeId,
+    FlowTypeImportError,
+    FlowTypeExportError
+);
+
+/// Only creates and modifies components which are in the namespaces permitted for a plugin.
+pub struct RestrictedComponentManager {
+    manager: Arc<dyn ComponentManager + Send + Sync>,
+    guard: Arc<PluginCapabilityGuard>,
+}
+
+impl RestrictedComponentManager {
+    pub fn new(manager: Arc<dyn ComponentManager + Send + Sync>, guard: Arc<PluginCapabilityGuard>) -> Self {
+        RestrictedComponentManager { manager, guard }
+    }
+
+    fn check(&self, ty: &ComponentTypeId) -> Result<(), String> {
+        self.guard.check_namespace(&ty.namespace()).map_err(|violation| violation.to_string())
+    }
+}
+
+impl ComponentManager for RestrictedComponentManager {
+    fn get_all(&self) -> Components {
+        self.manager.get_all()
+    }
+
+    fn get_by_namespace(&self, namespace: &str) -> Components {
+        self.manager.get_by_namespace(namespace)
+    }
+
+    fn has(&self, ty: &ComponentTypeId) -> bool {
+        self.manager.has(ty)
+    }
+
+    fn has_by_type(&self, namespace: &str, name: &str) -> bool {
+        self.manager.has_by_type(namespace, name)
+    }
+
+    fn get(&self, ty: &ComponentTypeId) -> Option<Component> {
+        self.manager.get(ty)
+    }
+
+    fn get_by_type(&self, namespace: &str, name: &str) -> Option<Component> {
+        self.manager.get_by_type(namespace, name)
+    }
+
+    fn find_by_type_name(&self, search: &str) -> Components {
+        self.manager.find_by_type_name(search)
+    }
+
+    fn count(&self) -> usize {
+        self.manager.count()
+    }
+
+    fn count_by_namespace(&self, namespace: &str) -> usize {
+        self.manager.count_by_namespace(namespace)
+    }
+
+    fn create(&self, ty: &ComponentTypeId, description: &str, properties: PropertyTypes, extensions: Extensions) -> Result<Component, ComponentCreationError> {
+        self.check(ty).map_err(ComponentCreationError::PermissionDenied)?;
+        self.manager.create(ty, description, properties, extensions)
+    }
+
+    fn replace(&self, ty: &ComponentTypeId, component: Component) {
+        if self.check(ty).is_ok() && self.check(&component.ty).is_ok() {
+            self.manager.replace(ty, component)
+        }
+    }
+
+    fn update_description(&self, ty: &ComponentTypeId, description: &str) -> Result<Component, ComponentUpdateError> {
+        self.check(ty).map_err(ComponentUpdateError::PermissionDenied)?;
+        self.manager.update_description(ty, description)
+    }
+
+    fn add_property(&self, ty: &ComponentTypeId, property: PropertyType) -> Result<PropertyType, ComponentAddPropertyError> {
+        self.check(ty).map_err(ComponentAddPropertyError::PermissionDenied)?;
+        self.manager.add_property(ty, property)
+    }
+
+    fn update_property(&self, ty: &ComponentTypeId, property_name: &str, property: PropertyType) -> Result<PropertyType, ComponentUpdatePropertyError> {
+        self.check(ty).map_err(ComponentUpdatePropertyError::PermissionDenied)?;
+        self.manager.update_property(ty, property_name, property)
+    }
+
+    fn remove_property(&self, ty: &ComponentTypeId, property_name: &str) -> Result<PropertyType, ComponentRemovePropertyError> {
+        self.check(ty).map_err(ComponentRemovePropertyError::PermissionDenied)?;
+        self.manager.remove_property(ty, property_name)
+    }
+
+    fn add_extension(&self, ty: &ComponentTypeId, extension: Extension) -> Result<ExtensionTypeId, ComponentAddExtensionError> {
+        self.check(ty).map_err(ComponentAddExtensionError::PermissionDenied)?;
+        self.manager.add_extension(ty, extension)
+    }
+
+    fn update_extension(
+        &self,
+        component_ty: &ComponentTypeId,
+        extension_ty: &ExtensionTypeId,
+        extension: Extension,
+    ) -> Result<Extension, ComponentUpdateExtensionError> {
+        self.check(component_ty).map_err(ComponentUpdateExtensionError::PermissionDenied)?;
+        self.manager.update_extension(component_ty, extension_ty, extension)
+    }
+
+    fn remove_extension(&self, component_ty: &ComponentTypeId, extension_ty: &ExtensionTypeId) -> Result<Extension, ComponentRemoveExtensionError> {
+        self.check(component_ty).map_err(ComponentRemoveExtensionError::PermissionDenied)?;
+        self.manager.remove_extension(component_ty, extension_ty)
+    }
+
+    fn delete(&self, ty: &ComponentTypeId) -> bool {
+        self.check(ty).is_ok() && self.manager.delete(ty)
+    }
+}
+
+/// Only creates and modifies entity types which are in the namespaces permitted for a plugin.
+pub struct RestrictedEntityTypeManager {
+    manager: Arc<dyn EntityTypeManager + Send + Sync>,
+    guard: Arc<PluginCapabilityGuard>,
+}
+
+impl RestrictedEntityTypeManager {
+    pub fn new(manager: Arc<dyn EntityTypeManager + Send + Sync>, guard: Arc<PluginCapabilityGuard>) -> Self {
+        RestrictedEntityTypeManager { manager, guard }
+    }
+
+    fn check(&self, ty: &EntityTypeId) -> Result<(), String> {
+        self.guard.check_namespace(&ty.namespace()).map_err(|violation| violation.to_string())
+    }
+}
+
+impl EntityTypeManager for RestrictedEntityTypeManager {
+    fn get_all(&self) -> EntityTypes {
+        self.manager.get_all()
+    }
+
+    fn get_by_namespace(&self, namespace: &str) -> EntityTypes {
+        self.manager.get_by_namespace(namespace)
+    }
+
+    fn has(&self, ty: &EntityTypeId) -> bool {
+        self.manager.has(ty)
+    }
+
+    fn has_by_type(&self, namespace: &str, name: &str) -> bool {
+        self.manager.has_by_type(namespace, name)
+    }
+
+    fn get(&self, ty: &EntityTypeId) -> Option<EntityType> {
+        self.manager.get(ty)
+    }
+
+    fn get_by_type(&self, namespace: &str, name: &str) -> Option<EntityType> {
+        self.manager.get_by_type(namespace, name)
+    }
+
+    fn find_by_type_name(&self, search: &str) -> EntityTypes {
+        self.manager.find_by_type_name(search)
+    }
+
+    fn count(&self) -> usize {
+        self.manager.count()
+    }
+
+    fn count_by_namespace(&self, namespace: &str) -> usize {
+        self.manager.count_by_namespace(namespace)
+    }
+
+    fn create(
+        &self,
+        ty: &EntityTypeId,
+        description: &str,
+        components: ComponentTypeIds,
+        properties: PropertyTypes,
+        extensions: Extensions,
+    ) -> Result<EntityType, EntityTypeCreationError> {
+        self.check(ty).map_err(EntityTypeCreationError::PermissionDenied)?;
+        self.manager.create(ty, description, components, properties, extensions)
+    }
+
+    fn update_description(&self, ty: &EntityTypeId, description: &str) -> Result<EntityType, EntityTypeUpdateError> {
+        self.check(ty).map_err(EntityTypeUpdateError::PermissionDenied)?;
+        self.manager.update_description(ty, description)
+    }
+
+    fn add_component(&self, ty: &EntityTypeId, component: &ComponentTypeId) -> Result<(), EntityTypeAddComponentError> {
+        self.check(ty).map_err(EntityTypeAddComponentError::PermissionDenied)?;
+        self.manager.add_component(ty, component)
+    }
+
+    fn remove_component(&self, ty: &EntityTypeId, component: &ComponentTypeId) -> Result<ComponentTypeId, EntityTypeRemoveComponentError> {
+        self.check(ty).map_err(EntityTypeRemoveComponentError::PermissionDenied)?;
+        self.manager.remove_component(ty, component)
+    }
+
+    fn add_property(&self, ty: &EntityTypeId, property: PropertyType) -> Result<PropertyType, EntityTypeAddPropertyError> {
+        self.check(ty).map_err(EntityTypeAddPropertyError::PermissionDenied)?;
+        self.manager.add_property(ty, property)
+    }
+
+    fn remove_property(&self, ty: &EntityTypeId, property_name: &str) -> Result<PropertyType, EntityTypeRemovePropertyError> {
+        self.check(ty).map_err(EntityTypeRemovePropertyError::PermissionDenied)?;
+        self.manager.remove_property(ty, property_name)
+    }
+
+    fn add_extension(&self, ty: &EntityTypeId, extension: Extension) -> Result<ExtensionTypeId, EntityTypeAddExtensionError> {
+        self.check(ty).map_err(EntityTypeAddExtensionError::PermissionDenied)?;
+        self.manager.add_extension(ty, extension)
+    }
+
+    fn remove_extension(&self, entity_ty: &EntityTypeId, extension_ty: &ExtensionTypeId) -> Result<Extension, EntityTypeRemoveExtensionError> {
+        self.check(entity_ty).map_err(EntityTypeRemoveExtensionError::PermissionDenied)?;
+        self.manager.remove_extension(entity_ty, extension_ty)
+    }
+
+    fn delete(&self, ty: &EntityTypeId) -> Option<EntityType> {
+        self.check(ty).ok()?;
+        self.manager.delete(ty)
+    }
+
+    fn validate(&self, ty: &EntityTypeId) -> bool {
+        self.manager.validate(ty)
+    }
+}
+
+/// Only creates and modifies relation types which are in the namespaces permitted for a plugin.
+pub struct RestrictedRelationTypeManager {
+    manager: Arc<dyn RelationTypeManager + Send + Sync>,
+    guard: Arc<PluginCapabilityGuard>,
+}
+
+impl RestrictedRelationTypeManager {
+    pub fn new(manager: Arc<dyn RelationTypeManager + Send + Sync>, guard: Arc<PluginCapabilityGuard>) -> Self {
+        RestrictedRelationTypeManager { manager, guard }
+    }
+
+    fn check(&self, ty: &RelationTypeId) -> Result<(), String> {
+        self.guard.check_namespace(&ty.namespace()).map_err(|violation| violation.to_string())
+    }
+}
+
+impl RelationTypeManager for RestrictedRelationTypeManager {
+    fn get_all(&self) -> RelationTypes {
+        self.manager.get_all()
+    }
+
+    fn get_by_namespace(&self, namespace: &str) -> RelationTypes {
+        self.manager.get_by_namespace(namespace)
+    }
+
+    fn has(&self, ty: &RelationTypeId) -> bool {
+        self.manager.has(ty)
+    }
+
+    fn has_by_type(&self, namespace: &str, type_name: &str) -> bool {
+        self.manager.has_by_type(namespace, type_name)
+    }
+
+    fn get(&self, ty: &RelationTypeId) -> Option<RelationType> {
+        self.manager.get(ty)
+    }
+
+    fn get_by_type(&self, namespace: &str, type_name: &str) -> Option<RelationType> {
+        self.manager.get_by_type(namespace, type_name)
+    }
+
+    fn find_by_type_name(&self, search: &str) -> RelationTypes {
+        self.manager.find_by_type_name(search)
+    }
+
+    fn count(&self) -> usize {
+        self.manager.count()
+    }
+
+    fn count_by_namespace(&self, namespace: &str) -> usize {
+        self.manager.count_by_namespace(namespace)
+    }
+
+    fn create(
+        &self,
+        outbound_type: &ComponentOrEntityTypeId,
+        ty: &RelationTypeId,
+        inbound_type: &ComponentOrEntityTypeId,
+        description: &str,
+        components: ComponentTypeIds,
+        properties: PropertyTypes,
+        extensions: Extensions,
+    ) -> Result<RelationType, RelationTypeCreationError> {
+        self.check(ty).map_err(RelationTypeCreationError::PermissionDenied)?;
+        self.manager
+            .create(outbound_type, ty, inbound_type, description, components, properties, extensions)
+    }
+
+    fn update_description(&self, ty: &RelationTypeId, description: &str) -> Result<RelationType, RelationTypeUpdateError> {
+        self.check(ty).map_err(RelationTypeUpdateError::PermissionDenied)?;
+        self.manager.update_description(ty, description)
+    }
+
+    fn add_component(&self, ty: &RelationTypeId, component: &ComponentTypeId) -> Result<(), RelationTypeAddComponentError> {
+        self.check(ty).map_err(RelationTypeAddComponentError::PermissionDenied)?;
+        self.manager.add_component(ty, component)
+    }
+
+    fn remove_component(&self, ty: &RelationTypeId, component: &ComponentTypeId) -> Result<ComponentTypeId, RelationTypeRemoveComponentError> {
+        self.check(ty).map_err(RelationTypeRemoveComponentError::PermissionDenied)?;
+        self.manager.remove_component(ty, component)
+    }
+
+    fn add_property(&self, ty: &RelationTypeId, property: PropertyType) -> Result<PropertyType, RelationTypeAddPropertyError> {
+        self.check(ty).map_err(RelationTypeAddPropertyError::PermissionDenied)?;
+        self.manager.add_property(ty, property)
+    }
+
+    fn update_property(
+        &self,
+        relation_ty: &RelationTypeId,
+        property_name: &str,
+        property_type: PropertyType,
+    ) -> Result<PropertyType, RelationTypeUpdatePropertyError> {
+        self.check(relation_ty).map_err(RelationTypeUpdatePropertyError::PermissionDenied)?;
+        self.manager.update_property(relation_ty, property_name, property_type)
+    }
+
+    fn remove_property(&self, ty: &RelationTypeId, property_name: &str) -> Result<PropertyType, RelationTypeRemovePropertyError> {
+        self.check(ty).map_err(RelationTypeRemovePropertyError::PermissionDenied)?;
+        self.manager.remove_property(ty, property_name)
+    }
+
+    fn add_extension(&self, ty: &RelationTypeId, extension: Extension) -> Result<ExtensionTypeId, RelationTypeAddExtensionError> {
+        self.check(ty).map_err(RelationTypeAddExtensionError::PermissionDenied)?;
+        self.manager.add_extension(ty, extension)
+    }
+
+    fn update_extension(
+        &self,
+        relation_ty: &RelationTypeId,
+        extension_ty: &ExtensionTypeId,
+        extension: Extension,
+    ) -> Result<Extension, RelationTypeUpdateExtensionError> {
+        self.check(relation_ty).map_err(RelationTypeUpdateExtensionError::PermissionDenied)?;
+        self.manager.update_extension(relation_ty, extension_ty, extension)
+    }
+
+    fn remove_extension(&self, relation_ty: &RelationTypeId, extension_ty: &ExtensionTypeId) -> Result<Extension, RelationTypeRemoveExtensionError> {
+        self.check(relation_ty).map_err(RelationTypeRemoveExtensionError::PermissionDenied)?;
+        self.manager.remove_extension(relation_ty, extension_ty)
+    }
+
+    fn delete(&self, ty: &RelationTypeId) -> Option<RelationType> {
+        self.check(ty).ok()?;
+        self.manager.delete(ty)
+    }
+
+    fn validate(&self, ty: &RelationTypeId) -> bool {
+        self.manager.validate(ty)
+    }
+}
+
+/// Only creates and modifies flow types which are in the namespaces permitted for a plugin.
+pub struct RestrictedFlowTypeManager {
+    manager: Arc<dyn FlowTypeManager + Send + Sync>,
+    guard: Arc<PluginCapabilityGuard>,
+}
+
+impl RestrictedFlowTypeManager {
+    pub fn new(manager: Arc<dyn FlowTypeManager + Send + Sync>, guard: Arc<PluginCapabilityGuard>) -> Self {
+        RestrictedFlowTypeManager { manager, guard }
+    }
+
+    fn check(&self, ty: &FlowTypeId) -> Result<(), String> {
+        self.guard.check_namespace(&ty.namespace()).map_err(|violation| violation.to_string())
+    }
+}
+
+impl FlowTypeManager for RestrictedFlowTypeManager {
+    fn get_all(&self) -> FlowTypes {
+        self.manager.get_all()
+    }
+
+    fn get_by_namespace(&self, namespace: &str) -> FlowTypes {
+        self.manager.get_by_namespace(namespace)
+    }
+
+    fn has(&self, ty: &FlowTypeId) -> bool {
+        self.manager.has(ty)
+    }
+
+    fn has_by_type(&self, namespace: &str, name: &str) -> bool {
+        self.manager.has_by_type(namespace, name)
+    }
+
+    fn get(&self, ty: &FlowTypeId) -> Option<FlowType> {
+        self.manager.get(ty)
+    }
+
+    fn get_by_type(&self, namespace: &str, name: &str) -> Option<FlowType> {
+        self.manager.get_by_type(namespace, name)
+    }
+
+    fn find_by_type_name(&self, search: &str) -> FlowTypes {
+        self.manager.find_by_type_name(search)
+    }
+
+    fn count(&self) -> usize {
+        self.manager.count()
+    }
+
+    fn count_by_namespace(&self, namespace: &str) -> usize {
+        self.manager.count_by_namespace(namespace)
+    }
+
+    fn create(
+        &self,
+        ty: &FlowTypeId,
+        description: &str,
+        wrapper_entity_instance: EntityInstance,
+        entity_instances: EntityInstances,
+        relation_instances: RelationInstances,
+        variables: PropertyTypes,
+        extensions: Extensions,
+    ) -> Result<FlowType, FlowTypeCreationError> {
+        self.check(ty).map_err(FlowTypeCreationError::PermissionDenied)?;
+        self.manager
+            .create(ty, description, wrapper_entity_instance, entity_instances, relation_instances, variables, extensions)
+    }
+
+    fn update_description(&self, ty: &FlowTypeId, description: &str) -> Result<FlowType, FlowTypeUpdateError> {
+        self.check(ty).map_err(FlowTypeUpdateError::PermissionDenied)?;
+        self.manager.update_description(ty, description)
+    }
+
+    fn add_entity_instance(&self, ty: &FlowTypeId, entity_instance: EntityInstance) -> Result<(), FlowTypeAddEntityInstanceError> {
+        self.check(ty).map_err(FlowTypeAddEntityInstanceError::PermissionDenied)?;
+        self.manager.add_entity_instance(ty, entity_instance)
+    }
+
+    fn update_entity_instance(
+        &self,
+        ty: &FlowTypeId,
+        id: Uuid,
+        entity_instance: EntityInstance,
+    ) -> Result<(Uuid, EntityInstance), FlowTypeUpdateEntityInstanceError> {
+        self.check(ty).map_err(FlowTypeUpdateEntityInstanceError::PermissionDenied)?;
+        self.manager.update_entity_instance(ty, id, entity_instance)
+    }
+
+    fn remove_entity_instance(&self, ty: &FlowTypeId, id: Uuid) -> Result<Option<(Uuid, EntityInstance)>, FlowTypeRemoveEntityInstanceError> {
+        self.check(ty).map_err(FlowTypeRemoveEntityInstanceError::PermissionDenied)?;
+        self.manager.remove_entity_instance(ty, id)
+    }
+
+    fn add_extension(&self, ty: &FlowTypeId, extension: Extension) -> Result<ExtensionTypeId, FlowTypeAddExtensionError> {
+        self.check(ty).map_err(FlowTypeAddExtensionError::PermissionDenied)?;
+        self.manager.add_extension(ty, extension)
+    }
+
+    fn update_extension(&self, ty: &FlowTypeId, extension_ty: &ExtensionTypeId, extension: Extension) -> Result<Extension, FlowTypeUpdateExtensionError> {
+        self.check(ty).map_err(FlowTypeUpdateExtensionError::PermissionDenied)?;
+        self.manager.update_extension(ty, extension_ty, extension)
+    }
+
+    fn remove_extension(&self, flow_ty: &FlowTypeId, extension_ty: &ExtensionTypeId) -> Result<Extension, FlowTypeRemoveExtensionError> {
+        self.check(flow_ty).map_err(FlowTypeRemoveExtensionError::PermissionDenied)?;
+        self.manager.remove_extension(flow_ty, extension_ty)
+    }
+
+    fn add_variable(&self, ty: &FlowTypeId, variable: PropertyType) -> Result<Variable, FlowTypeAddVariableError> {
+        self.check(ty).map_err(FlowTypeAddVariableError::PermissionDenied)?;
+        self.manager.add_variable(ty, variable)
+    }
+
+    fn update_variable(&self, ty: &FlowTypeId, variable_name: &str, variable: PropertyType) -> Result<Variable, FlowTypeUpdateVariableError> {
+        self.check(ty).map_err(FlowTypeUpdateVariableError::PermissionDenied)?;
+        self.manager.update_variable(ty, variable_name, variable)
+    }
+
+    fn remove_variable(&self, ty: &FlowTypeId, variable_name: &str) -> Result<Variable, FlowTypeRemoveVariableError> {
+        self.check(ty).map_err(FlowTypeRemoveVariableError::PermissionDenied)?;
+        self.manager.remove_variable(ty, variable_name)
+    }
+
+    fn delete(&self, ty: &FlowTypeId) -> Option<FlowType> {
+        self.check(ty).ok()?;
+        self.manager.delete(ty)
+    }
+
+    fn validate(&self, ty: &FlowTypeId) -> bool {
+        self.manager.validate(ty)
+    }
+}
+
+/// Only creates and modifies relation instances whose relation types are in the namespaces
+/// permitted for a plugin.
+pub struct RestrictedRelationInstanceManager {
+    manager: Arc<dyn RelationInstanceManager + Send + Sync>,
+    guard: Arc<PluginCapabilityGuard>,
+}
+
+impl RestrictedRelationInstanceManager {
+    pub fn new(manager: Arc<dyn RelationInstanceManager + Send + Sync>, guard: Arc<PluginCapabilityGuard>) -> Self {
+        RestrictedRelationInstanceManager { manager, guard }
+    }
+
+    fn check(&self, id: &RelationInstanceId) -> Result<(), String> {
+        self.guard.check_namespace(&id.namespace()).map_err(|violation| violation.to_string())
+    }
+}
+
+impl RelationInstanceManager for RestrictedRelationInstanceManager {
+    fn has(&self, id: &RelationInstanceId) -> bool {
+        self.manager.has(id)
+    }
+
+    fn get(&self, id: &RelationInstanceId) -> Option<ReactiveRelation> {
+        self.manager.get(id)
+    }
+
+    fn get_by_outbound_entity(&self, outbound_entity_id: Uuid) -> Vec<ReactiveRelation> {
+        self.manager.get_by_outbound_entity(outbound_entity_id)
+    }
+
+    fn get_by_inbound_entity(&self, inbound_entity_id: Uuid) -> Vec<ReactiveRelation> {
+        self.manager.get_by_inbound_entity(inbound_entity_id)
+    }
+
+    fn get_all(&self) -> Vec<ReactiveRelation> {
+        self.manager.get_all()
+    }
+
+    fn get_by_type(&self, ty: &RelationTypeId) -> Vec<ReactiveRelation> {
+        self.manager.get_by_type(ty)
+    }
+
+    fn get_by_namespace(&self, namespace: &str) -> Vec<ReactiveRelation> {
+        self.manager.get_by_namespace(namespace)
+    }
+
+    fn get_keys(&self) -> Vec<RelationInstanceId> {
+        self.manager.get_keys()
+    }
+
+    fn count(&self) -> usize {
+        self.manager.count()
+    }
+
+    fn count_by_type(&self, ty: &RelationTypeId) -> usize {
+        self.manager.count_by_type(ty)
+    }
+
+    fn count_by_component(&self, component: &ComponentTypeId) -> usize {
+        self.manager.count_by_component(component)
+    }
+
+    fn count_by_behaviour(&self, behaviour_ty: &BehaviourTypeId) -> usize {
+        self.manager.count_by_behaviour(behaviour_ty)
+    }
+
+    fn create(&self, relation_instance: RelationInstance) -> Result<ReactiveRelation, ReactiveRelationCreationError> {
+        self.guard.check_namespace(&relation_instance.namespace()).map_err(|violation| {
+            ReactiveRelationCreationError::ReactiveRelationRegistrationError(ReactiveRelationRegistrationError::PermissionDenied(violation.to_string()))
+        })?;
+        self.manager.create(relation_instance)
+    }
+
+    fn register(&self, reactive_relation: ReactiveRelation) -> Result<ReactiveRelation, ReactiveRelationRegistrationError> {
+        self.guard
+            .check_namespace(&reactive_relation.namespace())
+            .map_err(|violation| ReactiveRelationRegistrationError::PermissionDenied(violation.to_string()))?;
+        self.manager.register(reactive_relation)
+    }
+
+    fn add_component(&self, relation_instance_id: &RelationInstanceId, component: &ComponentTypeId) -> Result<(), ReactiveRelationComponentAddError> {
+        self.check(relation_instance_id).map_err(ReactiveRelationComponentAddError::PermissionDenied)?;
+        self.manager.add_component(relation_instance_id, component)
+    }
+
+    fn remove_component(&self, relation_instance_id: &RelationInstanceId, component: &ComponentTypeId) -> Result<(), ReactiveRelationComponentRemoveError> {
+        self.check(relation_instance_id)
+            .map_err(ReactiveRelationComponentRemoveError::PermissionDenied)?;
+        self.manager.remove_component(relation_instance_id, component)
+    }
+
+    fn add_property(
+        &self,
+        relation_instance_id: &RelationInstanceId,
+        property_name: &str,
+        mutability: Mutability,
+        value: Value,
+    ) -> Result<(), ReactiveRelationPropertyAddError> {
+        self.check(relation_instance_id).map_err(ReactiveRelationPropertyAddError::PermissionDenied)?;
+        self.manager.add_property(relation_instance_id, property_name, mutability, value)
+    }
+
+    fn remove_property(&self, relation_instance_id: &RelationInstanceId, property_name: &str) -> Result<(), ReactiveRelationPropertyRemoveError> {
+        self.check(relation_instance_id)
+            .map_err(ReactiveRelationPropertyRemoveError::PermissionDenied)?;
+        self.manager.remove_property(relation_instance_id, property_name)
+    }
+
+    fn delete(&self, relation_instance_id: &RelationInstanceId) -> bool {
+        self.check(relation_instance_id).is_ok() && self.manager.delete(relation_instance_id)
+    }
+}
+
+/// Only creates and deletes flow instances whose types are in the namespaces permitted for a plugin.
+pub struct RestrictedFlowInstanceManager {
+    manager: Arc<dyn FlowInstanceManager + Send + Sync>,
+    guard: Arc<PluginCapabilityGuard>,
+}
+
+impl RestrictedFlowInstanceManager {
+    pub fn new(manager: Arc<dyn FlowInstanceManager + Send + Sync>, guard: Arc<PluginCapabilityGuard>) -> Self {
+        RestrictedFlowInstanceManager { manager, guard }
+    }
+}
+
+impl FlowInstanceManager for RestrictedFlowInstanceManager {
+    fn has(&self, id: Uuid) -> bool {
+        self.manager.has(id)
+    }
+
+    fn get(&self, id: Uuid) -> Option<ReactiveFlow> {
+        self.manager.get(id)
+    }
+
+    fn get_by_label(&self, label: &str) -> Option<ReactiveFlow> {
+        self.manager.get_by_label(label)
+    }
+
+    fn create(&self, flow_instance: FlowInstance) -> Result<ReactiveFlow, ReactiveFlowCreationError> {
+        self.guard
+            .check_namespace(&flow_instance.ty.namespace())
+            .map_err(|violation| ReactiveFlowCreationError::PermissionDenied(violation.to_string()))?;
+        self.manager.create(flow_instance)
+    }
+
+    fn create_from_type(
+        &self,
+        ty: &FlowTypeId,
+        id: Option<Uuid>,
+        variables: PropertyInstances,
+        properties: PropertyInstances,
+    ) -> Result<ReactiveFlow, ReactiveFlowCreationError> {
+        self.guard
+            .check_namespace(&ty.namespace())
+            .map_err(|violation| ReactiveFlowCreationError::PermissionDenied(violation.to_string()))?;
+        self.manager.create_from_type(ty, id, variables, properties)
+    }
+
+    fn delete(&self, id: Uuid) -> bool {
+        let denied = self
+            .manager
+            .get(id)
+            .is_some_and(|reactive_flow| self.guard.check_namespace(&reactive_flow.namespace()).is_err());
+        !denied && self.manager.delete(id)
+    }
+}
@@ -0,0 +1,555 @@
+use std::fmt::Display;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use dashmap::DashSet;
+use log::warn;
+use uuid::Uuid;
+
+use reactive_graph_behaviour_model_api::ComponentBehaviourTypeId;
+use reactive_graph_behaviour_model_api::EntityBehaviourTypeId;
+use reactive_graph_behaviour_model_api::RelationBehaviourTypeId;
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::FlowTypeId;
+use reactive_graph_graph::RelationTypeId;
+use reactive_graph_plugin_api::CommandManager;
+use reactive_graph_plugin_api::ComponentImportExportManager;
+use reactive_graph_plugin_api::ComponentManager;
+use reactive_graph_plugin_api::ComponentProviderRegistry;
+use reactive_graph_plugin_api::ConfigManager;
+use reactive_graph_plugin_api::EntityBehaviourRegistry;
+use reactive_graph_plugin_api::EntityComponentBehaviourRegistry;
+use reactive_graph_plugin_api::EntityInstanceManager;
+use reactive_graph_plugin_api::EntityTypeImportExportManager;
+use reactive_graph_plugin_api::EntityTypeManager;
+use reactive_graph_plugin_api::EntityTypeProviderRegistry;
+use reactive_graph_plugin_api::FlowInstanceManager;
+use reactive_graph_plugin_api::FlowTypeImportExportManager;
+use reactive_graph_plugin_api::FlowTypeManager;
+use reactive_graph_plugin_api::FlowTypeProviderRegistry;
+use reactive_graph_plugin_api::GraphQLQueryService;
+use reactive_graph_plugin_api::NamespaceManager;
+use reactive_graph_plugin_api::PluginContext;
+use reactive_graph_plugin_api::RelationBehaviourRegistry;
+use reactive_graph_plugin_api::RelationComponentBehaviourRegistry;
+use reactive_graph_plugin_api::RelationInstanceManager;
+use reactive_graph_plugin_api::RelationTypeImportExportManager;
+use reactive_graph_plugin_api::RelationTypeManager;
+use reactive_graph_plugin_api::RelationTypeProviderRegistry;
+use reactive_graph_plugin_api::TypeSystemEventManager;
+use reactive_graph_plugin_api::WebResourceManager;
+use reactive_graph_plugin_service_api::PluginResources;
+
+use crate::TrackingComponentImportExportManager;
+use crate::TrackingComponentManager;
+use crate::TrackingComponentProviderRegistry;
+use crate::TrackingEntityBehaviourRegistry;
+use crate::TrackingEntityComponentBehaviourRegistry;
+use crate::TrackingEntityInstanceManager;
+use crate::TrackingEntityTypeImportExportManager;
+use crate::TrackingEntityTypeManager;
+use crate::TrackingEntityTypeProviderRegistry;
+use crate::TrackingFlowInstanceManager;
+use crate::TrackingFlowTypeImportExportManager;
+use crate::TrackingFlowTypeManager;
+use crate::TrackingFlowTypeProviderRegistry;
+use crate::TrackingRelationBehaviourRegistry;
+use crate::TrackingRelationComponentBehaviourRegistry;
+use crate::TrackingRelationTypeImportExportManager;
+use crate::TrackingRelationTypeManager;
+use crate::TrackingRelationTypeProviderRegistry;
+use crate::TrackingWebResourceManager;
+
+/// The resources which are currently registered by a plugin.
+#[derive(Default)]
+pub struct PluginResourceTracker {
+    pub(crate) component_providers: DashMap<String, Vec<ComponentTypeId>>,
+    pub(crate) entity_type_providers: DashMap<String, Vec<EntityTypeId>>,
+    pub(crate) relation_type_providers: DashMap<String, Vec<RelationTypeId>>,
+    pub(crate) flow_type_providers: DashMap<String, Vec<FlowTypeId>>,
+    pub(crate) created_components: DashSet<ComponentTypeId>,
+    pub(crate) created_entity_types: DashSet<EntityTypeId>,
+    pub(crate) created_relation_types: DashSet<RelationTypeId>,
+    pub(crate) created_flow_types: DashSet<FlowTypeId>,
+    pub(crate) entity_behaviours: DashSet<EntityBehaviourTypeId>,
+    pub(crate) entity_component_behaviours: DashSet<ComponentBehaviourTypeId>,
+    pub(crate) relation_behaviours: DashSet<RelationBehaviourTypeId>,
+    pub(crate) relation_component_behaviours: DashSet<ComponentBehaviourTypeId>,
+    pub(crate) flow_instances: DashSet<Uuid>,
+    pub(crate) web_resource_providers: DashSet<Uuid>,
+    pub(crate) commands: DashSet<Uuid>,
+}
+
+impl PluginResourceTracker {
+    pub fn get_resources(&self) -> PluginResources {
+        let (component_providers, components) = providers(&self.component_providers);
+        let (entity_type_providers, entity_types) = providers(&self.entity_type_providers);
+        let (relation_type_providers, relation_types) = providers(&self.relation_type_providers);
+        let (flow_type_providers, flow_types) = providers(&self.flow_type_providers);
+        PluginResources {
+            component_providers,
+            components,
+            entity_type_providers,
+            entity_types,
+            relation_type_providers,
+            relation_types,
+            flow_type_providers,
+            flow_types,
+            created_components: sorted(&self.created_components),
+            created_entity_types: sorted(&self.created_entity_types),
+            created_relation_types: sorted(&self.created_relation_types),
+            created_flow_types: sorted(&self.created_flow_types),
+            entity_behaviours: sorted(&self.entity_behaviours),
+            entity_component_behaviours: sorted(&self.entity_component_behaviours),
+            relation_behaviours: sorted(&self.relation_behaviours),
+            relation_component_behaviours: sorted(&self.relation_component_behaviours),
+            flow_instances: sorted(&self.flow_instances),
+            web_resource_providers: sorted(&self.web_resource_providers),
+            commands: sorted(&self.commands),
+        }
+    }
+
+    /// Moves the resources which are registered by the successor as well into `handed_over`.
+    /// The created types, the flow instances and the commands are moved to the successor.
+    pub(crate) fn handover(&self, successor: &PluginResourceTracker, handed_over: &PluginResourceTracker) {
+        handover_providers(&self.component_providers, &successor.component_providers, &handed_over.component_providers);
+        handover_providers(&self.entity_type_providers, &successor.entity_type_providers, &handed_over.entity_type_providers);
+        handover_providers(&self.relation_type_providers, &successor.relation_type_providers, &handed_over.relation_type_providers);
+        handover_providers(&self.flow_type_providers, &successor.flow_type_providers, &handed_over.flow_type_providers);
+        handover_items(&self.entity_behaviours, &successor.entity_behaviours, &handed_over.entity_behaviours);
+        handover_items(
+            &self.entity_component_behaviours,
+            &successor.entity_component_behaviours,
+            &handed_over.entity_component_behaviours,
+        );
+        handover_items(&self.relation_behaviours, &successor.relation_behaviours, &handed_over.relation_behaviours);
+        handover_items(
+            &self.relation_component_behaviours,
+            &successor.relation_component_behaviours,
+            &handed_over.relation_component_behaviours,
+        );
+        handover_items(&self.web_resource_providers, &successor.web_resource_providers, &handed_over.web_resource_providers);
+        move_items(&self.created_components, &successor.created_components, &handed_over.created_components);
+        move_items(&self.created_entity_types, &successor.created_entity_types, &handed_over.created_entity_types);
+        move_items(&self.created_relation_types, &successor.created_relation_types, &handed_over.created_relation_types);
+        move_items(&self.created_flow_types, &successor.created_flow_types, &handed_over.created_flow_types);
+        move_items(&self.flow_instances, &successor.flow_instances, &handed_over.flow_instances);
+        move_items(&self.commands, &successor.commands, &handed_over.commands);
+    }
+}
+
+fn handover_providers<T: Clone>(providers: &DashMap<String, Vec<T>>, successor: &DashMap<String, Vec<T>>, handed_over: &DashMap<String, Vec<T>>) {
+    providers.retain(|id, tys| {
+        if !successor.contains_key(id) {
+            return true;
+        }
+        handed_over.insert(id.clone(), tys.clone());
+        false
+    });
+}
+
+fn handover_items<T: Clone + Eq + Hash>(items: &DashSet<T>, successor: &DashSet<T>, handed_over: &DashSet<T>) {
+    items.retain(|item| {
+        if !successor.contains(item) {
+            return true;
+        }
+        handed_over.insert(item.clone());
+        false
+    });
+}
+
+fn move_items<T: Clone + Eq + Hash>(items: &DashSet<T>, successor: &DashSet<T>, handed_over: &DashSet<T>) {
+    items.retain(|item| {
+        successor.insert(item.clone());
+        handed_over.insert(item.clone());
+        false
+    });
+}
+
+/// Returns the sorted ids of the providers and the sorted types of the providers.
+fn providers<T: Clone + Ord>(providers: &DashMap<String, Vec<T>>) -> (Vec<String>, Vec<T>) {
+    let mut ids: Vec<String> = providers.iter().map(|provider| provider.key().clone()).collect();
+    let mut tys: Vec<T> = providers.iter().flat_map(|provider| provider.value().clone()).collect();
+    ids.sort();
+    tys.sort();
+    tys.dedup();
+    (ids, tys)
+}
+
+fn sorted<T: Clone + Eq + Hash + Ord>(set: &DashSet<T>) -> Vec<T> {
+    let mut items: Vec<T> = set.iter().map(|item| item.key().clone()).collect();
+    items.sort();
+    items
+}
+
+/// The plugin context of a single plugin.
+///
+/// The registries and the managers which are used for registering resources are wrapped, so
+/// that the resources registered by the plugin are tracked. Everything else is passed through.
+pub struct TrackingPluginContext {
+    id: Uuid,
+    context: Arc<dyn PluginContext + Send + Sync>,
+    tracker: Arc<PluginResourceTracker>,
+    /// The resources which have been handed over to a newer version of the plugin.
+    handed_over: Arc<PluginResourceTracker>,
+    component_manager: Arc<dyn ComponentManager + Send + Sync>,
+    component_import_export_manager: Arc<dyn ComponentImportExportManager + Send + Sync>,
+    entity_type_manager: Arc<dyn EntityTypeManager + Send + Sync>,
+    entity_type_import_export_manager: Arc<dyn EntityTypeImportExportManager + Send + Sync>,
+    relation_type_manager: Arc<dyn RelationTypeManager + Send + Sync>,
+    relation_type_import_export_manager: Arc<dyn RelationTypeImportExportManager + Send + Sync>,
+    flow_type_manager: Arc<dyn FlowTypeManager + Send + Sync>,
+    flow_type_import_export_manager: Arc<dyn FlowTypeImportExportManager + Send + Sync>,
+    component_provider_registry: Arc<dyn ComponentProviderRegistry + Send + Sync>,
+    entity_type_provider_registry: Arc<dyn EntityTypeProviderRegistry + Send + Sync>,
+    relation_type_provider_registry: Arc<dyn RelationTypeProviderRegistry + Send + Sync>,
+    flow_type_provider_registry: Arc<dyn FlowTypeProviderRegistry + Send + Sync>,
+    entity_instance_manager: Arc<dyn EntityInstanceManager + Send + Sync>,
+    flow_instance_manager: Arc<dyn FlowInstanceManager + Send + Sync>,
+    entity_behaviour_registry: Arc<dyn EntityBehaviourRegistry + Send + Sync>,
+    entity_component_behaviour_registry: Arc<dyn EntityComponentBehaviourRegistry + Send + Sync>,
+    relation_behaviour_registry: Arc<dyn RelationBehaviourRegistry + Send + Sync>,
+    relation_component_behaviour_registry: Arc<dyn RelationComponentBehaviourRegistry + Send + Sync>,
+    web_resource_manager: Arc<dyn WebResourceManager + Send + Sync>,
+}
+
+impl TrackingPluginContext {
+    pub fn new(id: Uuid, context: Arc<dyn PluginContext + Send + Sync>) -> Self {
+        let tracker = Arc::new(PluginResourceTracker::default());
+        let handed_over = Arc::new(PluginResourceTracker::default());
+        TrackingPluginContext {
+            id,
+            component_manager: Arc::new(TrackingComponentManager::new(context.get_component_manager(), tracker.clone(), handed_over.clone())),
+            component_import_export_manager: Arc::new(TrackingComponentImportExportManager::new(
+                context.get_component_import_export_manager(),
+                tracker.clone(),
+            )),
+            entity_type_manager: Arc::new(TrackingEntityTypeManager::new(context.get_entity_type_manager(), tracker.clone(), handed_over.clone())),
+            entity_type_import_export_manager: Arc::new(TrackingEntityTypeImportExportManager::new(
+                context.get_entity_type_import_export_manager(),
+                tracker.clone(),
+            )),
+            relation_type_manager: Arc::new(TrackingRelationTypeManager::new(context.get_relation_type_manager(), tracker.clone(), handed_over.clone())),
+            relation_type_import_export_manager: Arc::new(TrackingRelationTypeImportExportManager::new(
+                context.get_relation_type_import_export_manager(),
+                tracker.clone(),
+            )),
+            flow_type_manager: Arc::new(TrackingFlowTypeManager::new(context.get_flow_type_manager(), tracker.clone(), handed_over.clone())),
+            flow_type_import_export_manager: Arc::new(TrackingFlowTypeImportExportManager::new(context.get_flow_type_import_export_manager(), tracker.clone())),
+            component_provider_registry: Arc::new(TrackingComponentProviderRegistry::new(
+                context.get_component_provider_registry(),
+                tracker.clone(),
+                handed_over.clone(),
+            )),
+            entity_type_provider_registry: Arc::new(TrackingEntityTypeProviderRegistry::new(
+                context.get_entity_type_provider_registry(),
+                tracker.clone(),
+                handed_over.clone(),
+            )),
+            relation_type_provider_registry: Arc::new(TrackingRelationTypeProviderRegistry::new(
+                context.get_relation_type_provider_registry(),
+                tracker.clone(),
+                handed_over.clone(),
+            )),
+            flow_type_provider_registry: Arc::new(TrackingFlowTypeProviderRegistry::new(
+                context.get_flow_type_provider_registry(),
+                tracker.clone(),
+                handed_over.clone(),
+            )),
+            entity_instance_manager: Arc::new(TrackingEntityInstanceManager::new(
+                context.get_entity_instance_manager(),
+                tracker.clone(),
+                handed_over.clone(),
+            )),
+            flow_instance_manager: Arc::new(TrackingFlowInstanceManager::new(context.get_flow_instance_manager(), tracker.clone(), handed_over.clone())),
+            entity_behaviour_registry: Arc::new(TrackingEntityBehaviourRegistry::new(
+                context.get_entity_behaviour_registry(),
+                tracker.clone(),
+                handed_over.clone(),
+            )),
+            entity_component_behaviour_registry: Arc::new(TrackingEntityComponentBehaviourRegistry::new(
+                context.get_entity_component_behaviour_registry(),
+                tracker.clone(),
+                handed_over.clone(),
+            )),
+            relation_behaviour_registry: Arc::new(TrackingRelationBehaviourRegistry::new(
+                context.get_relation_behaviour_registry(),
+                tracker.clone(),
+                handed_over.clone(),
+            )),
+            relation_component_behaviour_registry: Arc::new(TrackingRelationComponentBehaviourRegistry::new(
+                context.get_relation_component_behaviour_registry(),
+                tracker.clone(),
+                handed_over.clone(),
+            )),
+            web_resource_manager: Arc::new(TrackingWebResourceManager::new(context.get_web_resource_manager(), tracker.clone(), handed_over.clone())),
+            context,
+            tracker,
+            handed_over,
+        }
+    }
+
+    /// Returns the resources which are currently registered by the plugin.
+    pub fn get_resources(&self) -> PluginResources {
+        self.tracker.get_resources()
+    }
+
+    /// Hands over the resources of the plugin to the given newer version of the plugin.
+    ///
+    /// Providers and behaviours which have been registered by the successor as well are no
+    /// longer unregistered when this plugin unregisters them. Created types, flow instances and
+    /// commands are passed to the successor. Returns the handed over resources.
+    pub fn handover(&self, successor: &TrackingPluginContext) -> PluginResources {
+        self.tracker.handover(&successor.tracker, &self.handed_over);
+        self.handed_over.get_resources()
+    }
+
+    /// Unregisters the resources which are still registered by the plugin and returns them.
+    ///
+    /// The behaviours and the instances are removed before the types they depend on. The created
+    /// types are deleted before the providers, because they may depend on provided types.
+    pub async fn cleanup(&self) -> PluginResources {
+        let leaks = self.tracker.get_resources();
+        let id = self.id;
+        for ty in &leaks.entity_behaviours {
+            leaked(id, "entity behaviour", ty);
+            self.context.get_entity_behaviour_registry().unregister(ty).await;
+        }
+        for ty in &leaks.entity_component_behaviours {
+            leaked(id, "entity component behaviour", ty);
+            self.context.get_entity_component_behaviour_registry().unregister(ty).await;
+        }
+        for ty in &leaks.relation_behaviours {
+            leaked(id, "relation behaviour", ty);
+            self.context.get_relation_behaviour_registry().unregister(ty).await;
+        }
+        for ty in &leaks.relation_component_behaviours {
+            leaked(id, "relation component behaviour", ty);
+            self.context.get_relation_component_behaviour_registry().unregister(ty).await;
+        }
+        for flow_id in &leaks.flow_instances {
+            leaked(id, "flow instance", flow_id);
+            self.context.get_flow_instance_manager().delete(*flow_id);
+        }
+        for command_id in &leaks.commands {
+            leaked(id, "command", command_id);
+            self.context.get_entity_instance_manager().delete(*command_id);
+        }
+        for provider_id in &leaks.web_resource_providers {
+            leaked(id, "web resource provider", provider_id);
+            self.context.get_web_resource_manager().unregister_provider(*provider_id).await;
+        }
+        for ty in &leaks.created_flow_types {
+            leaked(id, "flow type", ty);
+            self.context.get_flow_type_manager().delete(ty);
+        }
+        for ty in &leaks.created_relation_types {
+            leaked(id, "relation type", ty);
+            self.context.get_relation_type_manager().delete(ty);
+        }
+        for ty in &leaks.created_entity_types {
+            leaked(id, "entity type", ty);
+            self.context.get_entity_type_manager().delete(ty);
+        }
+        for ty in &leaks.created_components {
+            leaked(id, "component", ty);
+            self.context.get_component_manager().delete(ty);
+        }
+        for provider_id in &leaks.flow_type_providers {
+            leaked(id, "flow type provider", provider_id);
+            self.context.get_flow_type_provider_registry().unregister_provider(provider_id).await;
+        }
+        for provider_id in &leaks.relation_type_providers {
+            leaked(id, "relation type provider", provider_id);
+            self.context.get_relation_type_provider_registry().unregister_provider(provider_id).await;
+        }
+        for provider_id in &leaks.entity_type_providers {
+            leaked(id, "entity type provider", provider_id);
+            self.context.get_entity_type_provider_registry().unregister_provider(provider_id).await;
+        }
+        for provider_id in &leaks.component_providers {
+            leaked(id, "component provider", provider_id);
+            self.context.get_component_provider_registry().unregister_provider(provider_id).await;
+        }
+        leaks
+    }
+}
+
+fn leaked<T: Display>(id: Uuid, kind: &str, resource: T) {
+    warn!("[LEAK] Plugin {id} did not unregister {kind} {resource}");
+}
+
+impl PluginContext for TrackingPluginContext {
+    fn get_component_manager(&self) -> Arc<dyn ComponentManager + Send + Sync> {
+        self.component_manager.clone()
+    }
+
+    fn get_component_import_export_manager(&self) -> Arc<dyn ComponentImportExportManager + Send + Sync> {
+        self.component_import_export_manager.clone()
+    }
+
+    fn get_component_provider_registry(&self) -> Arc<dyn ComponentProviderRegistry + Send + Sync> {
+        self.component_provider_registry.clone()
+    }
+
+    fn get_entity_type_manager(&self) -> Arc<dyn EntityTypeManager + Send + Sync> {
+        self.entity_type_manager.clone()
+    }
+
+    fn get_entity_type_import_export_manager(&self) -> Arc<dyn EntityTypeImportExportManager + Send + Sync> {
+        self.entity_type_import_export_manager.clone()
+    }
+
+    fn get_entity_type_provider_registry(&self) -> Arc<dyn EntityTypeProviderRegistry + Send + Sync> {
+        self.entity_type_provider_registry.clone()
+    }
+
+    fn get_relation_type_manager(&self) -> Arc<dyn RelationTypeManager + Send + Sync> {
+        self.relation_type_manager.clone()
+    }
+
+    fn get_relation_type_import_export_manager(&self) -> Arc<dyn RelationTypeImportExportManager + Send + Sync> {
+        self.relation_type_import_export_manager.clone()
+    }
+
+    fn get_relation_type_provider_registry(&self) -> Arc<dyn RelationTypeProviderRegistry + Send + Sync> {
+        self.relation_type_provider_registry.clone()
+    }
+
+    fn get_flow_type_manager(&self) -> Arc<dyn FlowTypeManager + Send + Sync> {
+        self.flow_type_manager.clone()
+    }
+
+    fn get_flow_type_import_export_manager(&self) -> Arc<dyn FlowTypeImportExportManager + Send + Sync> {
+        self.flow_type_import_export_manager.clone()
+    }
+
+    fn get_flow_type_provider_registry(&self) -> Arc<dyn FlowTypeProviderRegistry + Send + Sync> {
+        self.flow_type_provider_registry.clone()
+    }
+
+    fn get_namespace_manager(&self) -> Arc<dyn NamespaceManager + Send + Sync> {
+        self.context.get_namespace_manager()
+    }
+
+    fn get_type_system_event_manager(&self) -> Arc<dyn TypeSystemEventManager + Send + Sync> {
+        self.context.get_type_system_event_manager()
+    }
+
+    fn get_entity_instance_manager(&self) -> Arc<dyn EntityInstanceManager + Send + Sync> {
+        self.entity_instance_manager.clone()
+    }
+
+    fn get_relation_instance_manager(&self) -> Arc<dyn RelationInstanceManager + Send + Sync> {
+        self.context.get_relation_instance_manager()
+    }
+
+    fn get_flow_instance_manager(&self) -> Arc<dyn FlowInstanceManager + Send + Sync> {
+        self.flow_instance_manager.clone()
+    }
+
+    fn get_entity_behaviour_registry(&self) -> Arc<dyn EntityBehaviourRegistry + Send + Sync> {
+        self.entity_behaviour_registry.clone()
+    }
+
+    fn get_entity_component_behaviour_registry(&self) -> Arc<dyn EntityComponentBehaviourRegistry + Send + Sync> {
+        self.entity_component_behaviour_registry.clone()
+    }
+
+    fn get_relation_behaviour_registry(&self) -> Arc<dyn RelationBehaviourRegistry + Send + Sync> {
+        self.relation_behaviour_registry.clone()
+    }
+
+    fn get_relation_component_behaviour_registry(&self) -> Arc<dyn RelationComponentBehaviourRegistry + Send + Sync> {
+        self.relation_component_behaviour_registry.clone()
+    }
+
+    fn get_graphql_query_service(&self) -> Arc<dyn GraphQLQueryService + Send + Sync> {
+        self.context.get_graphql_query_service()
+    }
+
+    fn get_web_resource_manager(&self) -> Arc<dyn WebResourceManager + Send + Sync> {
+        self.web_resource_manager.clone()
+    }
+
+    fn get_config_manager(&self) -> Arc<dyn ConfigManager + Send + Sync> {
+        self.context.get_config_manager()
+    }
+
+    fn get_command_manager(&self) -> Arc<dyn CommandManager + Send + Sync> {
+        self.context.get_command_manager()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use reactive_graph_behaviour_model_api::EntityBehaviourTypeId;
+    use reactive_graph_graph::ComponentTypeId;
+
+    use crate::PluginResourceTracker;
+
+    #[test]
+    fn get_resources_test() {
+        let tracker = PluginResourceTracker::default();
+        assert!(tracker.get_resources().is_empty());
+
+        let a = ComponentTypeId::new_from_type("test", "a");
+        let b = ComponentTypeId::new_from_type("test", "b");
+        tracker.component_providers.insert("provider-2".to_string(), vec![b.clone(), a.clone()]);
+        tracker.component_providers.insert("provider-1".to_string(), vec![a.clone()]);
+        tracker.entity_behaviours.insert(EntityBehaviourTypeId::new_from_type("test", "behaviour"));
+        let flow_id = Uuid::new_v4();
+        tracker.flow_instances.insert(flow_id);
+
+        let resources = tracker.get_resources();
+        assert_eq!(vec!["provider-1".to_string(), "provider-2".to_string()], resources.component_providers);
+        assert_eq!(vec![a, b], resources.components);
+        assert_eq!(vec![EntityBehaviourTypeId::new_from_type("test", "behaviour")], resources.entity_behaviours);
+        assert_eq!(vec![flow_id], resources.flow_instances);
+        // The provided types are counted by their providers
+        assert_eq!(4, resources.count());
+
+        tracker.component_providers.remove("provider-1");
+        tracker.component_providers.remove("provider-2");
+        tracker.entity_behaviours.clear();
+        tracker.flow_instances.remove(&flow_id);
+        assert!(tracker.get_resources().is_empty());
+    }
+
+    #[test]
+    fn handover_test() {
+        let previous = PluginResourceTracker::default();
+        let successor = PluginResourceTracker::default();
+        let handed_over = PluginResourceTracker::default();
+
+        let a = ComponentTypeId::new_from_type("test", "a");
+        let behaviour = EntityBehaviourTypeId::new_from_type("test", "behaviour");
+        let removed_behaviour = EntityBehaviourTypeId::new_from_type("test", "removed");
+        let flow_id = Uuid::new_v4();
+        previous.component_providers.insert("provider".to_string(), vec![a.clone()]);
+        previous.entity_behaviours.insert(behaviour.clone());
+        previous.entity_behaviours.insert(removed_behaviour.clone());
+        previous.flow_instances.insert(flow_id);
+        successor.component_providers.insert("provider".to_string(), vec![a.clone()]);
+        successor.entity_behaviours.insert(behaviour.clone());
+
+        previous.handover(&successor, &handed_over);
+
+        // The behaviour which is not provided by the successor stays with the previous version
+        let resources = previous.get_resources();
+        assert_eq!(vec![removed_behaviour], resources.entity_behaviours);
+        assert_eq!(1, resources.count());
+
+        let resources = handed_over.get_resources();
+        assert_eq!(vec!["provider".to_string()], resources.component_providers);
+        assert_eq!(vec![behaviour], resources.entity_behaviours);
+        assert_eq!(vec![flow_id], resources.flow_instances);
+
+        // The flow instance is passed to the successor
+        assert_eq!(vec![flow_id], successor.get_resources().flow_instances);
+    }
+}
@@ -0,0 +1,951 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use reactive_graph_behaviour_model_api::BehaviourFactory;
+use reactive_graph_behaviour_model_api::BehaviourTypeId;
+use reactive_graph_behaviour_model_api::ComponentBehaviourTypeId;
+use reactive_graph_behaviour_model_api::EntityBehaviourTypeId;
+use reactive_graph_behaviour_model_api::RelationBehaviourTypeId;
+use reactive_graph_command_model::COMPONENT_COMMAND;
+use reactive_graph_graph::Component;
+use reactive_graph_graph::ComponentAddExtensionError;
+use reactive_graph_graph::ComponentAddPropertyError;
+use reactive_graph_graph::ComponentContainer;
+use reactive_graph_graph::ComponentOrEntityTypeId;
+use reactive_graph_graph::ComponentRemoveExtensionError;
+use reactive_graph_graph::ComponentRemovePropertyError;
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::ComponentTypeIds;
+use reactive_graph_graph::ComponentUpdateError;
+use reactive_graph_graph::ComponentUpdateExtensionError;
+use reactive_graph_graph::ComponentUpdatePropertyError;
+use reactive_graph_graph::Components;
+use reactive_graph_graph::EntityInstance;
+use reactive_graph_graph::EntityInstances;
+use reactive_graph_graph::EntityType;
+use reactive_graph_graph::EntityTypeAddComponentError;
+use reactive_graph_graph::EntityTypeAddExtensionError;
+use reactive_graph_graph::EntityTypeAddPropertyError;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::EntityTypeRemoveComponentError;
+use reactive_graph_graph::EntityTypeRemoveExtensionError;
+use reactive_graph_graph::EntityTypeRemovePropertyError;
+use reactive_graph_graph::EntityTypeUpdateError;
+use reactive_graph_graph::EntityTypes;
+use reactive_graph_graph::Extension;
+use reactive_graph_graph::ExtensionTypeId;
+use reactive_graph_graph::Extensions;
+use reactive_graph_graph::FlowInstance;
+use reactive_graph_graph::FlowType;
+use reactive_graph_graph::FlowTypeAddEntityInstanceError;
+use reactive_graph_graph::FlowTypeAddExtensionError;
+use reactive_graph_graph::FlowTypeAddVariableError;
+use reactive_graph_graph::FlowTypeId;
+use reactive_graph_graph::FlowTypeRemoveEntityInstanceError;
+use reactive_graph_graph::FlowTypeRemoveExtensionError;
+use reactive_graph_graph::FlowTypeRemoveVariableError;
+use reactive_graph_graph::FlowTypeUpdateEntityInstanceError;
+use reactive_graph_graph::FlowTypeUpdateError;
+use reactive_graph_graph::FlowTypeUpdateExtensionError;
+use reactive_graph_graph::FlowTypeUpdateVariableError;
+use reactive_graph_graph::FlowTypes;
+use reactive_graph_graph::LabelPattern;
+use reactive_graph_graph::NamespacedTypeIdContainer;
+use reactive_graph_graph::PropertyInstances;
+use reactive_graph_graph::PropertyType;
+use reactive_graph_graph::PropertyTypes;
+use reactive_graph_graph::RelationInstanceId;
+use reactive_graph_graph::RelationInstances;
+use reactive_graph_graph::RelationType;
+use reactive_graph_graph::RelationTypeAddComponentError;
+use reactive_graph_graph::RelationTypeAddExtensionError;
+use reactive_graph_graph::RelationTypeAddPropertyError;
+use reactive_graph_graph::RelationTypeId;
+use reactive_graph_graph::RelationTypeRemoveComponentError;
+use reactive_graph_graph::RelationTypeRemoveExtensionError;
+use reactive_graph_graph::RelationTypeRemovePropertyError;
+use reactive_graph_graph::RelationTypeUpdateError;
+use reactive_graph_graph::RelationTypeUpdateExtensionError;
+use reactive_graph_graph::RelationTypeUpdatePropertyError;
+use reactive_graph_graph::RelationTypes;
+use reactive_graph_graph::Variable;
+use reactive_graph_plugin_api::ComponentImportExportManager;
+use reactive_graph_plugin_api::ComponentManager;
+use reactive_graph_plugin_api::ComponentProviderRegistry;
+use reactive_graph_plugin_api::EntityBehaviourRegistry;
+use reactive_graph_plugin_api::EntityComponentBehaviourRegistry;
+use reactive_graph_plugin_api::EntityInstanceManager;
+use reactive_graph_plugin_api::EntityTypeImportExportManager;
+use reactive_graph_plugin_api::EntityTypeManager;
+use reactive_graph_plugin_api::EntityTypeProviderRegistry;
+use reactive_graph_plugin_api::FlowInstanceManager;
+use reactive_graph_plugin_api::FlowTypeImportExportManager;
+use reactive_graph_plugin_api::FlowTypeManager;
+use reactive_graph_plugin_api::FlowTypeProviderRegistry;
+use reactive_graph_plugin_api::RelationBehaviourRegistry;
+use reactive_graph_plugin_api::RelationComponentBehaviourRegistry;
+use reactive_graph_plugin_api::RelationTypeImportExportManager;
+use reactive_graph_plugin_api::RelationTypeManager;
+use reactive_graph_plugin_api::RelationTypeProviderRegistry;
+use reactive_graph_plugin_api::TypeProvider;
+use reactive_graph_plugin_api::WebResourceManager;
+use reactive_graph_plugin_api::WebResourceProvider;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+use reactive_graph_reactive_model_impl::ReactiveFlow;
+use reactive_graph_reactive_model_impl::ReactiveRelation;
+use reactive_graph_reactive_service_api::ReactiveEntityComponentAddError;
+use reactive_graph_reactive_service_api::ReactiveEntityCreationError;
+use reactive_graph_reactive_service_api::ReactiveEntityRegistrationError;
+use reactive_graph_reactive_service_api::ReactiveFlowCreationError;
+use reactive_graph_type_system_api::ComponentCreationError;
+use reactive_graph_type_system_api::ComponentExportError;
+use reactive_graph_type_system_api::ComponentImportError;
+use reactive_graph_type_system_api::EntityTypeCreationError;
+use reactive_graph_type_system_api::EntityTypeExportError;
+use reactive_graph_type_system_api::EntityTypeImportError;
+use reactive_graph_type_system_api::FlowTypeCreationError;
+use reactive_graph_type_system_api::FlowTypeExportError;
+use reactive_graph_type_system_api::FlowTypeImportError;
+use reactive_graph_type_system_api::RelationTypeCreationError;
+use reactive_graph_type_system_api::RelationTypeExportError;
+use reactive_graph_type_system_api::RelationTypeImportError;
+
+use crate::PluginResourceTracker;
+
+macro_rules! tracking_type_provider_registry {
+    ($registry: ident, $registry_trait: ident, $types: ident, $providers: ident) => {
+        /// Tracks the type providers registered by a plugin.
+        pub struct $registry {
+            registry: Arc<dyn $registry_trait + Send + Sync>,
+            tracker: Arc<PluginResourceTracker>,
+            handed_over: Arc<PluginResourceTracker>,
+        }
+
+        impl $registry {
+            pub fn new(registry: Arc<dyn $registry_trait + Send + Sync>, tracker: Arc<PluginResourceTracker>, handed_over: Arc<PluginResourceTracker>) -> Self {
+                $registry {
+                    registry,
+                    tracker,
+                    handed_over,
+                }
+            }
+        }
+
+        #[async_trait]
+        impl $registry_trait for $registry {
+            async fn register_provider(&self, provider: Arc<dyn TypeProvider<$types>>) {
+                self.tracker
+                    .$providers
+                    .insert(provider.id().to_string(), provider.get_type_ids().to_vec());
+                self.registry.register_provider(provider).await
+            }
+
+            async fn unregister_provider(&self, id: &str) {
+                // The provider has been handed over to a newer version of the plugin
+                if self.handed_over.$providers.remove(id).is_none() {
+                    self.registry.unregister_provider(id).await;
+                }
+                self.tracker.$providers.remove(id);
+            }
+        }
+    };
+}
+
+tracking_type_provider_registry!(TrackingComponentProviderRegistry, ComponentProviderRegistry, Components, component_providers);
+tracking_type_provider_registry!(TrackingEntityTypeProviderRegistry, EntityTypeProviderRegistry, EntityTypes, entity_type_providers);
+tracking_type_provider_registry!(TrackingRelationTypeProviderRegistry, RelationTypeProviderRegistry, RelationTypes, relation_type_providers);
+tracking_type_provider_registry!(TrackingFlowTypeProviderRegistry, FlowTypeProviderRegistry, FlowTypes, flow_type_providers);
+
+macro_rules! tracking_behaviour_registry {
+    ($registry: ident, $registry_trait: ident, $behaviour_ty: ident, $id: ty, $instance: ty, $behaviours: ident) => {
+        /// Tracks the behaviours registered by a plugin.
+        pub struct $registry {
+            registry: Arc<dyn $registry_trait + Send + Sync>,
+            tracker: Arc<PluginResourceTracker>,
+            handed_over: Arc<PluginResourceTracker>,
+        }
+
+        impl $registry {
+            pub fn new(registry: Arc<dyn $registry_trait + Send + Sync>, tracker: Arc<PluginResourceTracker>, handed_over: Arc<PluginResourceTracker>) -> Self {
+                $registry {
+                    registry,
+                    tracker,
+                    handed_over,
+                }
+            }
+        }
+
+        #[async_trait]
+        impl $registry_trait for $registry {
+            async fn register(&self, ty: $behaviour_ty, factory: Arc<dyn BehaviourFactory<$id, $instance> + Send + Sync>) {
+                self.tracker.$behaviours.insert(ty.clone());
+                self.registry.register(ty, factory).await
+            }
+
+            async fn unregister(&self, ty: &$behaviour_ty) {
+                // The behaviour has been handed over to a newer version of the plugin
+                if self.handed_over.$behaviours.remove(ty).is_none() {
+                    self.registry.unregister(ty).await;
+                }
+                self.tracker.$behaviours.remove(ty);
+            }
+        }
+    };
+}
+
+tracking_behaviour_registry!(
+    TrackingEntityBehaviourRegistry,
+    EntityBehaviourRegistry,
+    EntityBehaviourTypeId,
+    Uuid,
+    ReactiveEntity,
+    entity_behaviours
+);
+tracking_behaviour_registry!(
+    TrackingEntityComponentBehaviourRegistry,
+    EntityComponentBehaviourRegistry,
+    ComponentBehaviourTypeId,
+    Uuid,
+    ReactiveEntity,
+    entity_component_behaviours
+);
+tracking_behaviour_registry!(
+    TrackingRelationBehaviourRegistry,
+    RelationBehaviourRegistry,
+    RelationBehaviourTypeId,
+    RelationInstanceId,
+    ReactiveRelation,
+    relation_behaviours
+);
+tracking_behaviour_registry!(
+    TrackingRelationComponentBehaviourRegistry,
+    RelationComponentBehaviourRegistry,
+    ComponentBehaviourTypeId,
+    RelationInstanceId,
+    ReactiveRelation,
+    relation_component_behaviours
+);
+
+/// Tracks the flow instances created by a plugin.
+pub struct TrackingFlowInstanceManager {
+    manager: Arc<dyn FlowInstanceManager + Send + Sync>,
+    tracker: Arc<PluginResourceTracker>,
+    handed_over: Arc<PluginResourceTracker>,
+}
+
+impl TrackingFlowInstanceManager {
+    pub fn new(manager: Arc<dyn FlowInstanceManager + Send + Sync>, tracker: Arc<PluginResourceTracker>, handed_over: Arc<PluginResourceTracker>) -> Self {
+        TrackingFlowInstanceManager { manager, tracker, handed_over }
+    }
+
+    fn track(&self, result: Result<ReactiveFlow, ReactiveFlowCreationError>) -> Result<ReactiveFlow, ReactiveFlowCreationError> {
+        if let Ok(reactive_flow) = &result {
+            self.tracker.flow_instances.insert(reactive_flow.id);
+        }
+        result
+    }
+}
+
+impl FlowInstanceManager for TrackingFlowInstanceManager {
+    fn has(&self, id: Uuid) -> bool {
+        self.manager.has(id)
+    }
+
+    fn get(&self, id: Uuid) -> Option<ReactiveFlow> {
+        self.manager.get(id)
+    }
+
+    fn get_by_label(&self, label: &str) -> Option<ReactiveFlow> {
+        self.manager.get_by_label(label)
+    }
+
+    fn create(&self, flow_instance: FlowInstance) -> Result<ReactiveFlow, ReactiveFlowCreationError> {
+        self.track(self.manager.create(flow_instance))
+    }
+
+    fn create_from_type(
+        &self,
+        ty: &FlowTypeId,
+        id: Option<Uuid>,
+        variables: PropertyInstances,
+        properties: PropertyInstances,
+    ) -> Result<ReactiveFlow, ReactiveFlowCreationError> {
+        self.track(self.manager.create_from_type(ty, id, variables, properties))
+    }
+
+    fn delete(&self, id: Uuid) -> bool {
+        self.tracker.flow_instances.remove(&id);
+        // The flow instance has been handed over to a newer version of the plugin
+        if self.handed_over.flow_instances.remove(&id).is_some() {
+            return false;
+        }
+        self.manager.delete(id)
+    }
+}
+
+/// Tracks the commands created by a plugin.
+///
+/// Commands are entity instances with the component `core__command`.
+pub struct TrackingEntityInstanceManager {
+    manager: Arc<dyn EntityInstanceManager + Send + Sync>,
+    tracker: Arc<PluginResourceTracker>,
+    handed_over: Arc<PluginResourceTracker>,
+}
+
+impl TrackingEntityInstanceManager {
+    pub fn new(manager: Arc<dyn EntityInstanceManager + Send + Sync>, tracker: Arc<PluginResourceTracker>, handed_over: Arc<PluginResourceTracker>) -> Self {
+        TrackingEntityInstanceManager { manager, tracker, handed_over }
+    }
+
+    fn track(&self, reactive_entity: &ReactiveEntity) {
+        if reactive_entity.is_a(&COMPONENT_COMMAND) {
+            self.tracker.commands.insert(reactive_entity.id);
+        }
+    }
+}
+
+impl EntityInstanceManager for TrackingEntityInstanceManager {
+    fn has(&self, id: Uuid) -> bool {
+        self.manager.has(id)
+    }
+
+    fn get(&self, id: Uuid) -> Option<ReactiveEntity> {
+        self.manager.get(id)
+    }
+
+    fn get_by_label(&self, label: &str) -> Option<ReactiveEntity> {
+        self.manager.get_by_label(label)
+    }
+
+    fn get_by_label_with_params(&self, label: &str) -> Option<(ReactiveEntity, HashMap<String, String>)> {
+        self.manager.get_by_label_with_params(label)
+    }
+
+    fn get_by_label_pattern(&self, pattern: &LabelPattern) -> Vec<(ReactiveEntity, HashMap<String, String>)> {
+        self.manager.get_by_label_pattern(pattern)
+    }
+
+    fn get_all(&self) -> Vec<ReactiveEntity> {
+        self.manager.get_all()
+    }
+
+    fn get_by_type(&self, ty: &EntityTypeId) -> Vec<ReactiveEntity> {
+        self.manager.get_by_type(ty)
+    }
+
+    fn get_ids(&self) -> Vec<Uuid> {
+        self.manager.get_ids()
+    }
+
+    fn count(&self) -> usize {
+        self.manager.count()
+    }
+
+    fn count_by_type(&self, ty: &EntityTypeId) -> usize {
+        self.manager.count_by_type(ty)
+    }
+
+    fn count_by_component(&self, component: &ComponentTypeId) -> usize {
+        self.manager.count_by_component(component)
+    }
+
+    fn count_by_behaviour(&self, behaviour_ty: &BehaviourTypeId) -> usize {
+        self.manager.count_by_behaviour(behaviour_ty)
+    }
+
+    fn create(&self, entity_instance: EntityInstance) -> Result<ReactiveEntity, ReactiveEntityCreationError> {
+        let reactive_entity = self.manager.create(entity_instance)?;
+        self.track(&reactive_entity);
+        Ok(reactive_entity)
+    }
+
+    fn register(&self, reactive_entity: ReactiveEntity) -> Result<ReactiveEntity, ReactiveEntityRegistrationError> {
+        let reactive_entity = self.manager.register(reactive_entity)?;
+        self.track(&reactive_entity);
+        Ok(reactive_entity)
+    }
+
+    fn add_component(&self, id: Uuid, component: &ComponentTypeId) -> Result<(), ReactiveEntityComponentAddError> {
+        self.manager.add_component(id, component)?;
+        if component == COMPONENT_COMMAND.deref() {
+            self.tracker.commands.insert(id);
+        }
+        Ok(())
+    }
+
+    fn remove_component(&self, id: Uuid, component: &ComponentTypeId) {
+        self.manager.remove_component(id, component);
+        if component == COMPONENT_COMMAND.deref() {
+            self.tracker.commands.remove(&id);
+        }
+    }
+
+    fn delete(&self, id: Uuid) -> bool {
+        self.tracker.commands.remove(&id);
+        // The command has been handed over to a newer version of the plugin
+        if self.handed_over.commands.remove(&id).is_some() {
+            return false;
+        }
+        self.manager.delete(id)
+    }
+}
+
+/// Tracks the web resource providers registered by a plugin.
+pub struct TrackingWebResourceManager {
+    manager: Arc<dyn WebResourceManager + Send + Sync>,
+    tracker: Arc<PluginResourceTracker>,
+    handed_over: Arc<PluginResourceTracker>,
+}
+
+impl TrackingWebResourceManager {
+    pub fn new(manager: Arc<dyn WebResourceManager + Send + Sync>, tracker: Arc<PluginResourceTracker>, handed_over: Arc<PluginResourceTracker>) -> Self {
+        TrackingWebResourceManager { manager, tracker, handed_over }
+    }
+}
+
+#[async_trait]
+impl WebResourceManager for TrackingWebResourceManager {
+    async fn register_provider(&self, web_resource_provider: Arc<dyn WebResourceProvider>) {
+        self.tracker.web_resource_providers.insert(web_resource_provider.id());
+        self.manager.register_provider(web_resource_provider).await
+    }
+
+    async fn unregister_provider(&self, id: Uuid) {
+        // The web resource provider has been handed over to a newer version of the plugin
+        if self.handed_over.web_resource_providers.remove(&id).is_none() {
+            self.manager.unregister_provider(id).await;
+        }
+        self.tracker.web_resource_providers.remove(&id);
+    }
+}
+
+macro_rules! tracking_import_export_manager {
+    ($manager: ident, $manager_trait: ident, $type: ident, $type_id: ident, $import_error: ident, $export_error: ident, $created: ident) => {
+        /// Tracks the types imported by a plugin.
+        pub struct $manager {
+            manager: Arc<dyn $manager_trait + Send + Sync>,
+            tracker: Arc<PluginResourceTracker>,
+        }
+
+        impl $manager {
+            pub fn new(manager: Arc<dyn $manager_trait + Send + Sync>, tracker: Arc<PluginResourceTracker>) -> Self {
+                $manager { manager, tracker }
+            }
+        }
+
+        #[async_trait]
+        impl $manager_trait for $manager {
+            async fn import(&self, path: &str) -> Result<$type, $import_error> {
+                let imported = self.manager.import(path).await?;
+                self.tracker.$created.insert(imported.ty.clone());
+                Ok(imported)
+            }
+
+            async fn export(&self, ty: &$type_id, path: &str) -> Result<(), $export_error> {
+                self.manager.export(ty, path).await
+            }
+        }
+    };
+}
+
+tracking_import_export_manager!(
+    TrackingComponentImportExportManager,
+    ComponentImportExportManager,
+    Component,
+    ComponentTypeId,
+    ComponentImportError,
+    ComponentExportError,
+    created_components
+);
+tracking_import_export_manager!(
+    TrackingEntityTypeImportExportManager,
+    EntityTypeImportExportManager,
+    EntityType,
+    EntityTypeId,
+    EntityTypeImportError,
+    EntityTypeExportError,
+    created_entity_types
+);
+tracking_import_export_manager!(
+    TrackingRelationTypeImportExportManager,
+    RelationTypeImportExportManager,
+    RelationType,
+    RelationTypeId,
+    RelationTypeImportError,
+    RelationTypeExportError,
+    created_relation_types
+);
+tracking_import_export_manager!(
+    TrackingFlowTypeImportExportManager,
+    FlowTypeImportExportManager,
+    FlowType,
+    FlowTypeId,
+    FlowTypeImportError,
+    FlowTypeExportError,
+    created_flow_types
+);
+
+/// Tracks the components created by a plugin.
+pub struct TrackingComponentManager {
+    manager: Arc<dyn ComponentManager + Send + Sync>,
+    tracker: Arc<PluginResourceTracker>,
+    handed_over: Arc<PluginResourceTracker>,
+}
+
+impl TrackingComponentManager {
+    pub fn new(manager: Arc<dyn ComponentManager + Send + Sync>, tracker: Arc<PluginResourceTracker>, handed_over: Arc<PluginResourceTracker>) -> Self {
+        TrackingComponentManager { manager, tracker, handed_over }
+    }
+}
+
+impl ComponentManager for TrackingComponentManager {
+    fn get_all(&self) -> Components {
+        self.manager.get_all()
+    }
+
+    fn get_by_namespace(&self, namespace: &str) -> Components {
+        self.manager.get_by_namespace(namespace)
+    }
+
+    fn has(&self, ty: &ComponentTypeId) -> bool {
+        self.manager.has(ty)
+    }
+
+    fn has_by_type(&self, namespace: &str, name: &str) -> bool {
+        self.manager.has_by_type(namespace, name)
+    }
+
+    fn get(&self, ty: &ComponentTypeId) -> Option<Component> {
+        self.manager.get(ty)
+    }
+
+    fn get_by_type(&self, namespace: &str, name: &str) -> Option<Component> {
+        self.manager.get_by_type(namespace, name)
+    }
+
+    fn find_by_type_name(&self, search: &str) -> Components {
+        self.manager.find_by_type_name(search)
+    }
+
+    fn count(&self) -> usize {
+        self.manager.count()
+    }
+
+    fn count_by_namespace(&self, namespace: &str) -> usize {
+        self.manager.count_by_namespace(namespace)
+    }
+
+    fn create(&self, ty: &ComponentTypeId, description: &str, properties: PropertyTypes, extensions: Extensions) -> Result<Component, ComponentCreationError> {
+        let component = self.manager.create(ty, description, properties, extensions)?;
+        self.tracker.created_components.insert(ty.clone());
+        Ok(component)
+    }
+
+    fn replace(&self, ty: &ComponentTypeId, component: Component) {
+        self.manager.replace(ty, component)
+    }
+
+    fn update_description(&self, ty: &ComponentTypeId, description: &str) -> Result<Component, ComponentUpdateError> {
+        self.manager.update_description(ty, description)
+    }
+
+    fn add_property(&self, ty: &ComponentTypeId, property: PropertyType) -> Result<PropertyType, ComponentAddPropertyError> {
+        self.manager.add_property(ty, property)
+    }
+
+    fn update_property(&self, ty: &ComponentTypeId, property_name: &str, property: PropertyType) -> Result<PropertyType, ComponentUpdatePropertyError> {
+        self.manager.update_property(ty, property_name, property)
+    }
+
+    fn remove_property(&self, ty: &ComponentTypeId, property_name: &str) -> Result<PropertyType, ComponentRemovePropertyError> {
+        self.manager.remove_property(ty, property_name)
+    }
+
+    fn add_extension(&self, ty: &ComponentTypeId, extension: Extension) -> Result<ExtensionTypeId, ComponentAddExtensionError> {
+        self.manager.add_extension(ty, extension)
+    }
+
+    fn update_extension(
+        &self,
+        component_ty: &ComponentTypeId,
+        extension_ty: &ExtensionTypeId,
+        extension: Extension,
+    ) -> Result<Extension, ComponentUpdateExtensionError> {
+        self.manager.update_extension(component_ty, extension_ty, extension)
+    }
+
+    fn remove_extension(&self, component_ty: &ComponentTypeId, extension_ty: &ExtensionTypeId) -> Result<Extension, ComponentRemoveExtensionError> {
+        self.manager.remove_extension(component_ty, extension_ty)
+    }
+
+    fn delete(&self, ty: &ComponentTypeId) -> bool {
+        self.tracker.created_components.remove(ty);
+        // The component has been handed over to a newer version of the plugin
+        if self.handed_over.created_components.remove(ty).is_some() {
+            return false;
+        }
+        self.manager.delete(ty)
+    }
+}
+
+/// Tracks the entity types created by a plugin.
+pub struct TrackingEntityTypeManager {
+    manager: Arc<dyn EntityTypeManager + Send + Sync>,
+    tracker: Arc<PluginResourceTracker>,
+    handed_over: Arc<PluginResourceTracker>,
+}
+
+impl TrackingEntityTypeManager {
+    pub fn new(manager: Arc<dyn EntityTypeManager + Send + Sync>, tracker: Arc<PluginResourceTracker>, handed_over: Arc<PluginResourceTracker>) -> Self {
+        TrackingEntityTypeManager { manager, tracker, handed_over }
+    }
+}
+
+impl EntityTypeManager for TrackingEntityTypeManager {
+    fn get_all(&self) -> EntityTypes {
+        self.manager.get_all()
+    }
+
+    fn get_by_namespace(&self, namespace: &str) -> EntityTypes {
+        self.manager.get_by_namespace(namespace)
+    }
+
+    fn has(&self, ty: &EntityTypeId) -> bool {
+        self.manager.has(ty)
+    }
+
+    fn has_by_type(&self, namespace: &str, name: &str) -> bool {
+        self.manager.has_by_type(namespace, name)
+    }
+
+    fn get(&self, ty: &EntityTypeId) -> Option<EntityType> {
+        self.manager.get(ty)
+    }
+
+    fn get_by_type(&self, namespace: &str, name: &str) -> Option<EntityType> {
+        self.manager.get_by_type(namespace, name)
+    }
+
+    fn find_by_type_name(&self, search: &str) -> EntityTypes {
+        self.manager.find_by_type_name(search)
+    }
+
+    fn count(&self) -> usize {
+        self.manager.count()
+    }
+
+    fn count_by_namespace(&self, namespace: &str) -> usize {
+        self.manager.count_by_namespace(namespace)
+    }
+
+    fn create(
+        &self,
+        ty: &EntityTypeId,
+        description: &str,
+        components: ComponentTypeIds,
+        properties: PropertyTypes,
+        extensions: Extensions,
+    ) -> Result<EntityType, EntityTypeCreationError> {
+        let entity_type = self.manager.create(ty, description, components, properties, extensions)?;
+        self.tracker.created_entity_types.insert(ty.clone());
+        Ok(entity_type)
+    }
+
+    fn update_description(&self, ty: &EntityTypeId, description: &str) -> Result<EntityType, EntityTypeUpdateError> {
+        self.manager.update_description(ty, description)
+    }
+
+    fn add_component(&self, ty: &EntityTypeId, component: &ComponentTypeId) -> Result<(), EntityTypeAddComponentError> {
+        self.manager.add_component(ty, component)
+    }
+
+    fn remove_component(&self, ty: &EntityTypeId, component: &ComponentTypeId) -> Result<ComponentTypeId, EntityTypeRemoveComponentError> {
+        self.manager.remove_component(ty, component)
+    }
+
+    fn add_property(&self, ty: &EntityTypeId, property: PropertyType) -> Result<PropertyType, EntityTypeAddPropertyError> {
+        self.manager.add_property(ty, property)
+    }
+
+    fn remove_property(&self, ty: &EntityTypeId, property_name: &str) -> Result<PropertyType, EntityTypeRemovePropertyError> {
+        self.manager.remove_property(ty, property_name)
+    }
+
+    fn add_extension(&self, ty: &EntityTypeId, extension: Extension) -> Result<ExtensionTypeId, EntityTypeAddExtensionError> {
+        self.manager.add_extension(ty, extension)
+    }
+
+    fn remove_extension(&self, entity_ty: &EntityTypeId, extension_ty: &ExtensionTypeId) -> Result<Extension, EntityTypeRemoveExtensionError> {
+        self.manager.remove_extension(entity_ty, extension_ty)
+    }
+
+    fn delete(&self, ty: &EntityTypeId) -> Option<EntityType> {
+        self.tracker.created_entity_types.remove(ty);
+        // The entity type has been handed over to a newer version of the plugin
+        if self.handed_over.created_entity_types.remove(ty).is_some() {
+            return None;
+        }
+        self.manager.delete(ty)
+    }
+
+    fn validate(&self, ty: &EntityTypeId) -> bool {
+        self.manager.validate(ty)
+    }
+}
+
+/// Tracks the relation types created by a plugin.
+pub struct TrackingRelationTypeManager {
+    manager: Arc<dyn RelationTypeManager + Send + Sync>,
+    tracker: Arc<PluginResourceTracker>,
+    handed_over: Arc<PluginResourceTracker>,
+}
+
+impl TrackingRelationTypeManager {
+    pub fn new(manager: Arc<dyn RelationTypeManager + Send + Sync>, tracker: Arc<PluginResourceTracker>, handed_over: Arc<PluginResourceTracker>) -> Self {
+        TrackingRelationTypeManager { manager, tracker, handed_over }
+    }
+}
+
+impl RelationTypeManager for TrackingRelationTypeManager {
+    fn get_all(&self) -> RelationTypes {
+        self.manager.get_all()
+    }
+
+    fn get_by_namespace(&self, namespace: &str) -> RelationTypes {
+        self.manager.get_by_namespace(namespace)
+    }
+
+    fn has(&self, ty: &RelationTypeId) -> bool {
+        self.manager.has(ty)
+    }
+
+    fn has_by_type(&self, namespace: &str, type_name: &str) -> bool {
+        self.manager.has_by_type(namespace, type_name)
+    }
+
+    fn get(&self, ty: &RelationTypeId) -> Option<RelationType> {
+        self.manager.get(ty)
+    }
+
+    fn get_by_type(&self, namespace: &str, type_name: &str) -> Option<RelationType> {
+        self.manager.get_by_type(namespace, type_name)
+    }
+
+    fn find_by_type_name(&self, search: &str) -> RelationTypes {
+        self.manager.find_by_type_name(search)
+    }
+
+    fn count(&self) -> usize {
+        self.manager.count()
+    }
+
+    fn count_by_namespace(&self, namespace: &str) -> usize {
+        self.manager.count_by_namespace(namespace)
+    }
+
+    fn create(
+        &self,
+        outbound_type: &ComponentOrEntityTypeId,
+        ty: &RelationTypeId,
+        inbound_type: &ComponentOrEntityTypeId,
+        description: &str,
+        components: ComponentTypeIds,
+        properties: PropertyTypes,
+        extensions: Extensions,
+    ) -> Result<RelationType, RelationTypeCreationError> {
+        let relation_type = self
+            .manager
+            .create(outbound_type, ty, inbound_type, description, components, properties, extensions)?;
+        self.tracker.created_relation_types.insert(ty.clone());
+        Ok(relation_type)
+    }
+
+    fn update_description(&self, ty: &RelationTypeId, description: &str) -> Result<RelationType, RelationTypeUpdateError> {
+        self.manager.update_description(ty, description)
+    }
+
+    fn add_component(&self, ty: &RelationTypeId, component: &ComponentTypeId) -> Result<(), RelationTypeAddComponentError> {
+        self.manager.add_component(ty, component)
+    }
+
+    fn remove_component(&self, ty: &RelationTypeId, component: &ComponentTypeId) -> Result<ComponentTypeId, RelationTypeRemoveComponentError> {
+        self.manager.remove_component(ty, component)
+    }
+
+    fn add_property(&self, ty: &RelationTypeId, property: PropertyType) -> Result<PropertyType, RelationTypeAddPropertyError> {
+        self.manager.add_property(ty, property)
+    }
+
+    fn update_property(
+        &self,
+        relation_ty: &RelationTypeId,
+        property_name: &str,
+        property_type: PropertyType,
+    ) -> Result<PropertyType, RelationTypeUpdatePropertyError> {
+        self.manager.update_property(relation_ty, property_name, property_type)
+    }
+
+    fn remove_property(&self, ty: &RelationTypeId, property_name: &str) -> Result<PropertyType, RelationTypeRemovePropertyError> {
+        self.manager.remove_property(ty, property_name)
+    }
+
+    fn add_extension(&self, ty: &RelationTypeId, extension: Extension) -> Result<ExtensionTypeId, RelationTypeAddExtensionError> {
+        self.manager.add_extension(ty, extension)
+    }
+
+    fn update_extension(
+        &self,
+        relation_ty: &RelationTypeId,
+        extension_ty: &ExtensionTypeId,
+        extension: Extension,
+    ) -> Result<Extension, RelationTypeUpdateExtensionError> {
+        self.manager.update_extension(relation_ty, extension_ty, extension)
+    }
+
+    fn remove_extension(&self, relation_ty: &RelationTypeId, extension_ty: &ExtensionTypeId) -> Result<Extension, RelationTypeRemoveExtensionError> {
+        self.manager.remove_extension(relation_ty, extension_ty)
+    }
+
+    fn delete(&self, ty: &RelationTypeId) -> Option<RelationType> {
+        self.tracker.created_relation_types.remove(ty);
+        // The relation type has been handed over to a newer version of the plugin
+        if self.handed_over.created_relation_types.remove(ty).is_some() {
+            return None;
+        }
+        self.manager.delete(ty)
+    }
+
+    fn validate(&self, ty: &RelationTypeId) -> bool {
+        self.manager.validate(ty)
+    }
+}
+
+/// Tracks the flow types created by a plugin.
+pub struct TrackingFlowTypeManager {
+    manager: Arc<dyn FlowTypeManager + Send + Sync>,
+    tracker: Arc<PluginResourceTracker>,
+    handed_over: Arc<PluginResourceTracker>,
+}
+
+impl TrackingFlowTypeManager {
+    pub fn new(manager: Arc<dyn FlowTypeManager + Send + Sync>, tracker: Arc<PluginResourceTracker>, handed_over: Arc<PluginResourceTracker>) -> Self {
+        TrackingFlowTypeManager { manager, tracker, handed_over }
+    }
+}
+
+impl FlowTypeManager for TrackingFlowTypeManager {
+    fn get_all(&self) -> FlowTypes {
+        self.manager.get_all()
+    }
+
+    fn get_by_namespace(&self, namespace: &str) -> FlowTypes {
+        self.manager.get_by_namespace(namespace)
+    }
+
+    fn has(&self, ty: &FlowTypeId) -> bool {
+        self.manager.has(ty)
+    }
+
+    fn has_by_type(&self, namespace: &str, name: &str) -> bool {
+        self.manager.has_by_type(namespace, name)
+    }
+
+    fn get(&self, ty: &FlowTypeId) -> Option<FlowType> {
+        self.manager.get(ty)
+    }
+
+    fn get_by_type(&self, namespace: &str, name: &str) -> Option<FlowType> {
+        self.manager.get_by_type(namespace, name)
+    }
+
+    fn find_by_type_name(&self, search: &str) -> FlowTypes {
+        self.manager.find_by_type_name(search)
+    }
+
+    fn count(&self) -> usize {
+        self.manager.count()
+    }
+
+    fn count_by_namespace(&self, namespace: &str) -> usize {
+        self.manager.count_by_namespace(namespace)
+    }
+
+    fn create(
+        &self,
+        ty: &FlowTypeId,
+        description: &str,
+        wrapper_entity_instance: EntityInstance,
+        entity_instances: EntityInstances,
+        relation_instances: RelationInstances,
+        variables: PropertyTypes,
+        extensions: Extensions,
+    ) -> Result<FlowType, FlowTypeCreationError> {
+        let flow_type = self
+            .manager
+            .create(ty, description, wrapper_entity_instance, entity_instances, relation_instances, variables, extensions)?;
+        self.tracker.created_flow_types.insert(ty.clone());
+        Ok(flow_type)
+    }
+
+    fn update_description(&self, ty: &FlowTypeId, description: &str) -> Result<FlowType, FlowTypeUpdateError> {
+        self.manager.update_description(ty, description)
+    }
+
+    fn add_entity_instance(&self, ty: &FlowTypeId, entity_instance: EntityInstance) -> Result<(), FlowTypeAddEntityInstanceError> {
+        self.manager.add_entity_instance(ty, entity_instance)
+    }
+
+    fn update_entity_instance(
+        &self,
+        ty: &FlowTypeId,
+        id: Uuid,
+        entity_instance: EntityInstance,
+    ) -> Result<(Uuid, EntityInstance), FlowTypeUpdateEntityInstanceError> {
+        self.manager.update_entity_instance(ty, id, entity_instance)
+    }
+
+    fn remove_entity_instance(&self, ty: &FlowTypeId, id: Uuid) -> Result<Option<(Uuid, EntityInstance)>, FlowTypeRemoveEntityInstanceError> {
+        self.manager.remove_entity_instance(ty, id)
+    }
+
+    fn add_extension(&self, ty: &FlowTypeId, extension: Extension) -> Result<ExtensionTypeId, FlowTypeAddExtensionError> {
+        self.manager.add_extension(ty, extension)
+    }
+
+    fn update_extension(&self, ty: &FlowTypeId, extension_ty: &ExtensionTypeId, extension: Extension) -> Result<Extension, FlowTypeUpdateExtensionError> {
+        self.manager.update_extension(ty, extension_ty, extension)
+    }
+
+    fn remove_extension(&self, flow_ty: &FlowTypeId, extension_ty: &ExtensionTypeId) -> Result<Extension, FlowTypeRemoveExtensionError> {
+        self.manager.remove_extension(flow_ty, extension_ty)
+    }
+
+    fn add_variable(&self, ty: &FlowTypeId, variable: PropertyType) -> Result<Variable, FlowTypeAddVariableError> {
+        self.manager.add_variable(ty, variable)
+    }
+
+    fn update_variable(&self, ty: &FlowTypeId, variable_name: &str, variable: PropertyType) -> Result<Variable, FlowTypeUpdateVariableError> {
+        self.manager.update_variable(ty, variable_name, variable)
+    }
+
+    fn remove_variable(&self, ty: &FlowTypeId, variable_name: &str) -> Result<Variable, FlowTypeRemoveVariableError> {
+        self.manager.remove_variable(ty, variable_name)
+    }
+
+    fn delete(&self, ty: &FlowTypeId) -> Option<FlowType> {
+        self.tracker.created_flow_types.remove(ty);
+        // The flow type has been handed over to a newer version of the plugin
+        if self.handed_over.created_flow_types.remove(ty).is_some() {
+            return None;
+        }
+        self.manager.delete(ty)
+    }
+
+    fn validate(&self, ty: &FlowTypeId) -> bool {
+        self.manager.validate(ty)
+    }
+}
@@ -0,0 +1,83 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fs;
+use std::path::PathBuf;
+
+use clap::Args;
+use clap::ValueEnum;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::client::error::CommandError;
+use reactive_graph_serde::format::SerializationFormat;
+
+/// The file format of a type or instance definition.
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub(crate) enum FileFormatArgs {
+    /// JSON
+    Json,
+    /// JSON5
+    #[cfg(feature = "json5")]
+    Json5,
+    /// TOML
+    #[cfg(feature = "toml")]
+    Toml,
+    /// YAML
+    #[cfg(feature = "yaml")]
+    Yaml,
+}
+
+impl From<FileFormatArgs> for SerializationFormat {
+    fn from(format: FileFormatArgs) -> Self {
+        match format {
+            FileFormatArgs::Json => SerializationFormat::Json,
+            #[cfg(feature = "json5")]
+            FileFormatArgs::Json5 => SerializationFormat::Json5,
+            #[cfg(feature = "toml")]
+            FileFormatArgs::Toml => SerializationFormat::Toml,
+            #[cfg(feature = "yaml")]
+            FileFormatArgs::Yaml => SerializationFormat::Yaml,
+        }
+    }
+}
+
+/// A file which contains a type or instance definition.
+#[derive(Args, Debug, Clone)]
+pub(crate) struct FileArgs {
+    /// The path of the file.
+    pub path: PathBuf,
+
+    /// The file format. If not specified, the file format is determined by the file extension.
+    #[arg(long)]
+    pub format: Option<FileFormatArgs>,
+}
+
+impl FileArgs {
+    pub fn format(&self) -> Result<SerializationFormat, CommandError> {
+        match self.format {
+            Some(format) => Ok(format.into()),
+            None => Ok(SerializationFormat::from_path(&self.path)?),
+        }
+    }
+
+    /// Reads and deserializes the file.
+    pub fn read<T: DeserializeOwned>(&self) -> Result<T, CommandError> {
+        let format = self.format()?;
+        let content = fs::read_to_string(&self.path)?;
+        Ok(format.deserialize(&content)?)
+    }
+
+    /// Serializes the given value and writes it into the file.
+    pub fn write<T: Serialize>(&self, value: &T) -> Result<(), CommandError> {
+        let format = self.format()?;
+        let content = format.serialize(value)?;
+        fs::write(&self.path, content)?;
+        Ok(())
+    }
+}
+
+impl Display for FileArgs {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.path.display())
+    }
+}
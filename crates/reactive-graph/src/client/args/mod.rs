@@ -1,4 +1,5 @@
 pub mod connection;
+pub mod file;
 
 use clap::Parser;
 
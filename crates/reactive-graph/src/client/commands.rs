@@ -1,6 +1,8 @@
 use crate::client::instances::entities::args::EntityInstancesArgs;
 use crate::client::instances::flows::args::FlowInstancesArgs;
+use crate::client::instances::query::args::GraphQueryArgs;
 use crate::client::instances::relations::args::RelationInstancesArgs;
+use crate::client::instances::search::args::SearchArgs;
 use crate::client::introspection::args::IntrospectionQueryArgs;
 use crate::client::system::command::args::ExecuteCommandArgs;
 use crate::client::system::instance::args::InstanceInfoArgs;
@@ -9,6 +11,7 @@ use crate::client::system::remotes::args::RemotesArgs;
 use crate::client::types::components::args::ComponentsArgs;
 use crate::client::types::entities::args::EntityTypesArgs;
 use crate::client::types::flows::args::FlowTypesArgs;
+use crate::client::types::namespaces::args::NamespacesArgs;
 use crate::client::types::relations::args::RelationTypesArgs;
 use clap::Subcommand;
 
@@ -36,6 +39,10 @@ pub(crate) enum ClientCommands {
     Shutdown,
 
     // --- Type System ---
+    /// Prints the namespaces and their metadata.
+    #[non_exhaustive]
+    Namespaces(NamespacesArgs),
+
     /// Manage components.
     #[non_exhaustive]
     Components(ComponentsArgs),
@@ -65,6 +72,14 @@ pub(crate) enum ClientCommands {
     #[non_exhaustive]
     FlowInstances(FlowInstancesArgs),
 
+    /// Executes a graph pattern query (MATCH ... WHERE ... RETURN ... LIMIT ...).
+    #[non_exhaustive]
+    Query(GraphQueryArgs),
+
+    /// Full-text search over the names, descriptions, labels and searchable properties of the instances.
+    #[non_exhaustive]
+    Search(SearchArgs),
+
     // --- Introspection ---
     /// Execute GraphQL introspection queries.
     #[non_exhaustive]
@@ -3,7 +3,9 @@ use std::fmt::Debug;
 use thiserror::Error;
 
 use reactive_graph_client::ReactiveGraphClientExecutionError;
+use reactive_graph_serde::error::DeserializationError;
 use reactive_graph_serde::error::SerializationError;
+use reactive_graph_serde::error::SerializationFormatError;
 
 #[derive(Debug, Error)]
 pub enum CommandError {
@@ -25,6 +27,12 @@ pub enum CommandError {
     NotCreated(String),
     #[error("Serialization failed: {0}")]
     SerializationError(SerializationError),
+    #[error("Deserialization failed: {0}")]
+    DeserializationError(DeserializationError),
+    #[error("Unsupported file format: {0}")]
+    SerializationFormatError(SerializationFormatError),
+    #[error("Failed to access file: {0}")]
+    Io(std::io::Error),
 }
 
 impl CommandError {
@@ -39,6 +47,9 @@ impl CommandError {
             CommandError::NoContent(_) => 1,
             CommandError::NotCreated(_) => 5,
             CommandError::SerializationError(_) => 6,
+            CommandError::DeserializationError(_) => 7,
+            CommandError::SerializationFormatError(_) => 8,
+            CommandError::Io(_) => 9,
         }
     }
 }
@@ -54,3 +65,21 @@ impl From<SerializationError> for CommandError {
         CommandError::SerializationError(e)
     }
 }
+
+impl From<DeserializationError> for CommandError {
+    fn from(e: DeserializationError) -> Self {
+        CommandError::DeserializationError(e)
+    }
+}
+
+impl From<SerializationFormatError> for CommandError {
+    fn from(e: SerializationFormatError) -> Self {
+        CommandError::SerializationFormatError(e)
+    }
+}
+
+impl From<std::io::Error> for CommandError {
+    fn from(e: std::io::Error) -> Self {
+        CommandError::Io(e)
+    }
+}
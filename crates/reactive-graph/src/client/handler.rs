@@ -3,7 +3,9 @@ use std::sync::Arc;
 use crate::client::commands::ClientCommands;
 use crate::client::instances::entities::entity_instances;
 use crate::client::instances::flows::flow_instances;
+use crate::client::instances::query::query;
 use crate::client::instances::relations::relation_instances;
+use crate::client::instances::search::search;
 use crate::client::introspection::introspection_query;
 use crate::client::result::CommandResult;
 use crate::client::system::command::execute_command;
@@ -14,6 +16,7 @@ use crate::client::system::shutdown::shutdown;
 use crate::client::types::components::components;
 use crate::client::types::entities::entity_types;
 use crate::client::types::flows::flow_types;
+use crate::client::types::namespaces::namespaces;
 use crate::client::types::relations::relation_types;
 use reactive_graph_client::ReactiveGraphClient;
 
@@ -26,6 +29,7 @@ pub(crate) async fn handle_command(client: &Arc<ReactiveGraphClient>, command: C
         ClientCommands::Remotes(args) => remotes(client, args).await,
         ClientCommands::Shutdown => shutdown(client).await,
         // Type System
+        ClientCommands::Namespaces(args) => namespaces(client, args).await,
         ClientCommands::Components(args) => components(client, args).await,
         ClientCommands::EntityTypes(args) => entity_types(client, args).await,
         ClientCommands::RelationTypes(args) => relation_types(client, args).await,
@@ -34,6 +38,8 @@ pub(crate) async fn handle_command(client: &Arc<ReactiveGraphClient>, command: C
         ClientCommands::EntityInstances(args) => entity_instances(client, args).await,
         ClientCommands::RelationInstances(args) => relation_instances(client, args).await,
         ClientCommands::FlowInstances(args) => flow_instances(client, args).await,
+        ClientCommands::Query(args) => query(client, args).await,
+        ClientCommands::Search(args) => search(client, args).await,
         // Introspection
         ClientCommands::Introspection(args) => introspection_query(client, args).await,
     }
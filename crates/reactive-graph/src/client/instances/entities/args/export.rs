@@ -0,0 +1,15 @@
+use crate::client::args::file::FileArgs;
+use crate::client::instances::entities::args::id::IdArgs;
+use clap::Args;
+
+/// Exports the entity instance into a file.
+#[derive(Args, Debug, Clone)]
+pub(crate) struct ExportEntityInstanceArgs {
+    /// The entity instance.
+    #[clap(flatten)]
+    pub id: IdArgs,
+
+    /// The file to export into.
+    #[clap(flatten)]
+    pub file: FileArgs,
+}
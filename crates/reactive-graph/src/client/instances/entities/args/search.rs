@@ -1,3 +1,4 @@
+use crate::client::instances::pagination::args::PaginationArgs;
 use crate::client::instances::properties::args::parse_property;
 use crate::client::types::components::args::parse_component_ty;
 use crate::client::types::entities::args::type_id::EntityTypeIdOptions;
@@ -32,6 +33,10 @@ pub(crate) struct SearchEntityInstancesArgs {
     /// The components to search for.
     #[clap(short, long, value_parser = parse_component_ty)]
     pub components: Option<Vec<ComponentTypeId>>,
+
+    /// Pagination and sorting.
+    #[clap(flatten)]
+    pub pagination: PaginationArgs,
 }
 
 impl SearchEntityInstancesArgs {
@@ -61,6 +66,11 @@ impl From<&SearchEntityInstancesArgs> for SearchEntityInstancesVariables {
             .label(search.label.clone())
             .properties(Some(properties.0))
             .components(Some(components.0))
+            .sort(search.pagination.sort())
+            .first(search.pagination.first)
+            .after(search.pagination.after.clone())
+            .last(search.pagination.last)
+            .before(search.pagination.before.clone())
             .build()
     }
 }
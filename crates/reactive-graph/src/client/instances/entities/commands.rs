@@ -1,5 +1,7 @@
+use crate::client::args::file::FileArgs;
 use crate::client::instances::entities::args::add_property::AddPropertyArgs;
 use crate::client::instances::entities::args::create::CreateEntityInstanceArgs;
+use crate::client::instances::entities::args::export::ExportEntityInstanceArgs;
 use crate::client::instances::entities::args::id::IdArgs;
 use crate::client::instances::entities::args::id_and_component::IdAndComponentArgs;
 use crate::client::instances::entities::args::id_and_property::IdAndPropertyArgs;
@@ -49,6 +51,12 @@ pub(crate) enum EntityInstancesCommands {
     // Deletes an entity instance.
     #[non_exhaustive]
     Delete(IdArgs),
+    /// Imports an entity instance from a file.
+    #[non_exhaustive]
+    Import(FileArgs),
+    /// Exports an entity instance into a file.
+    #[non_exhaustive]
+    Export(ExportEntityInstanceArgs),
     /// Prints the JSON Schema of entity instances.
     #[non_exhaustive]
     JsonSchema,
@@ -9,6 +9,7 @@ use crate::client::result::CommandResult;
 use crate::client::types::components::output_format::ComponentTypeIdsOutputFormatWrapper;
 use reactive_graph_client::ReactiveGraphClient;
 use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::EntityInstance;
 use reactive_graph_graph::PropertyInstanceGetter;
 use reactive_graph_graph::PropertyType;
 use reactive_graph_table_model::instances::properties::PropertyInstance;
@@ -116,6 +117,27 @@ pub(crate) async fn entity_instances(client: &Arc<ReactiveGraphClient>, entity_i
             Ok(None) => Err(args.not_found()),
             Err(e) => Err(e.into()),
         },
+        EntityInstancesCommands::Import(args) => {
+            let entity_instance: EntityInstance = args.read()?;
+            match client
+                .instances()
+                .entities()
+                .create(entity_instance.ty, Some(entity_instance.id), Some(entity_instance.description), entity_instance.properties)
+                .await
+            {
+                Ok(Some(entity_instance)) => output_format_wrapper.single(entity_instance),
+                Ok(None) => Err(NoContent("Entity instance not created".to_string())),
+                Err(e) => Err(e.into()),
+            }
+        }
+        EntityInstancesCommands::Export(args) => match client.instances().entities().get_entity_instance_by_id(args.id.clone()).await {
+            Ok(Some(entity_instance)) => {
+                args.file.write(&entity_instance)?;
+                Ok(format!("Entity instance {} exported to {}", args.id.id, args.file).into())
+            }
+            Ok(None) => Err(args.id.not_found()),
+            Err(e) => Err(e.into()),
+        },
         EntityInstancesCommands::JsonSchema => match client.json_schema().instances().entities().await {
             Ok(Some(json_schema)) => Ok(json_schema.into()),
             Ok(None) => Err(NotFound("JSON Schema not available".to_string())),
@@ -0,0 +1,15 @@
+use crate::client::args::file::FileArgs;
+use crate::client::instances::flows::args::id::IdArgs;
+use clap::Args;
+
+/// Exports the flow instance into a file.
+#[derive(Args, Debug, Clone)]
+pub(crate) struct ExportFlowInstanceArgs {
+    /// The flow instance.
+    #[clap(flatten)]
+    pub id: IdArgs,
+
+    /// The file to export into.
+    #[clap(flatten)]
+    pub file: FileArgs,
+}
@@ -5,6 +5,7 @@ use clap::Args;
 pub mod add_entity_instance;
 pub mod create;
 pub mod create_from_type;
+pub mod export;
 pub mod id;
 pub mod label;
 pub mod search;
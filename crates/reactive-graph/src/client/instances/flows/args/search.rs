@@ -1,3 +1,4 @@
+use crate::client::instances::pagination::args::PaginationArgs;
 use crate::client::types::entities::args::type_id::EntityTypeIdOptions;
 use clap::Args;
 use reactive_graph_client::client::instances::flows::variables::search::variables::SearchFlowInstancesVariables;
@@ -17,6 +18,10 @@ pub(crate) struct SearchFlowInstancesArgs {
     /// The label of the entity instance.
     #[clap(short, long)]
     pub label: Option<String>,
+
+    /// Pagination and sorting.
+    #[clap(flatten)]
+    pub pagination: PaginationArgs,
 }
 
 impl SearchFlowInstancesArgs {}
@@ -28,6 +33,11 @@ impl From<&SearchFlowInstancesArgs> for SearchFlowInstancesVariables {
             .ty(ty.map(From::from))
             .id(search.id.map(From::from))
             .label(search.label.clone())
+            .sort(search.pagination.sort())
+            .first(search.pagination.first)
+            .after(search.pagination.after.clone())
+            .last(search.pagination.last)
+            .before(search.pagination.before.clone())
             .build()
     }
 }
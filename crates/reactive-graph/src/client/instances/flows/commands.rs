@@ -1,4 +1,5 @@
 use crate::client::instances::flows::args::create_from_type::CreateFlowInstanceFromTypeArgs;
+use crate::client::instances::flows::args::export::ExportFlowInstanceArgs;
 use crate::client::instances::flows::args::id::IdArgs;
 use crate::client::instances::flows::args::label::LabelArgs;
 use crate::client::instances::flows::args::search::SearchFlowInstancesArgs;
@@ -38,6 +39,9 @@ pub(crate) enum FlowInstancesCommands {
     // Deletes a flow instance.
     #[non_exhaustive]
     Delete(IdArgs),
+    /// Exports a flow instance into a file.
+    #[non_exhaustive]
+    Export(ExportFlowInstanceArgs),
     /// Prints the JSON Schema of flow instances.
     #[non_exhaustive]
     JsonSchema,
@@ -54,6 +54,14 @@ pub(crate) async fn flow_instances(client: &Arc<ReactiveGraphClient>, flow_insta
             Ok(None) => Err(args.not_found()),
             Err(e) => Err(e.into()),
         },
+        FlowInstancesCommands::Export(args) => match client.instances().flows().get_by_id(args.id.clone()).await {
+            Ok(Some(flow_instance)) => {
+                args.file.write(&flow_instance)?;
+                Ok(format!("Flow instance {} exported to {}", args.id.id, args.file).into())
+            }
+            Ok(None) => Err(args.id.not_found()),
+            Err(e) => Err(e.into()),
+        },
         FlowInstancesCommands::JsonSchema => match client.json_schema().instances().flows().await {
             Ok(Some(json_schema)) => Ok(json_schema.into()),
             Ok(None) => Err(NotFound("JSON Schema not available".to_string())),
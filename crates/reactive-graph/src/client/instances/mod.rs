@@ -1,4 +1,7 @@
 pub(crate) mod entities;
 pub(crate) mod flows;
+pub(crate) mod pagination;
 pub(crate) mod properties;
+pub(crate) mod query;
 pub(crate) mod relations;
+pub(crate) mod search;
@@ -0,0 +1,61 @@
+use clap::Args;
+use reactive_graph_graph::PropertySort;
+use reactive_graph_graph::SortDirection;
+use std::error::Error;
+
+/// CLI arguments for paginating and sorting instances.
+#[derive(Args, Debug, Clone)]
+pub(crate) struct PaginationArgs {
+    /// Sorts the instances by the given property. Append ':desc' to sort in descending order.
+    #[clap(long, value_parser = parse_property_sort)]
+    pub sort: Option<Vec<PropertySort>>,
+
+    /// Returns the first n instances.
+    #[clap(long)]
+    pub first: Option<i32>,
+
+    /// Returns the instances after the given cursor.
+    #[clap(long)]
+    pub after: Option<String>,
+
+    /// Returns the last n instances.
+    #[clap(long)]
+    pub last: Option<i32>,
+
+    /// Returns the instances before the given cursor.
+    #[clap(long)]
+    pub before: Option<String>,
+}
+
+impl PaginationArgs {
+    pub fn sort<T: From<PropertySort>>(&self) -> Option<Vec<T>> {
+        self.sort.as_ref().map(|sort| sort.iter().cloned().map(From::from).collect())
+    }
+}
+
+pub fn parse_property_sort(s: &str) -> Result<PropertySort, Box<dyn Error + Send + Sync + 'static>> {
+    let (property, direction) = match s.rsplit_once(':') {
+        Some((property, "asc")) => (property, SortDirection::Ascending),
+        Some((property, "desc")) => (property, SortDirection::Descending),
+        Some((_, direction)) => return Err(format!("invalid sort direction `{direction}`: expected `asc` or `desc`").into()),
+        None => (s, SortDirection::Ascending),
+    };
+    Ok(PropertySort::new(property, direction))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::instances::pagination::args::parse_property_sort;
+    use reactive_graph_graph::SortDirection;
+
+    #[test]
+    fn parse_property_sort_test() {
+        let sort = parse_property_sort("name").expect("Failed to parse sort");
+        assert_eq!("name", sort.property);
+        assert_eq!(SortDirection::Ascending, sort.direction);
+        let sort = parse_property_sort("value:desc").expect("Failed to parse sort");
+        assert_eq!("value", sort.property);
+        assert_eq!(SortDirection::Descending, sort.direction);
+        assert!(parse_property_sort("value:up").is_err());
+    }
+}
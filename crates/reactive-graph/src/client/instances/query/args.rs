@@ -0,0 +1,18 @@
+use clap::Args;
+
+#[derive(Args, Debug, Clone)]
+pub(crate) struct GraphQueryArgs {
+    /// The graph pattern query, for example: MATCH (a:namespace::sensor)-[:connects]->(b) WHERE a.value > 10 RETURN b
+    #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+    pub text: Vec<String>,
+
+    /// Prints the execution plan instead of the matched rows.
+    #[arg(long)]
+    pub explain: bool,
+}
+
+impl GraphQueryArgs {
+    pub fn text(&self) -> String {
+        self.text.join(" ")
+    }
+}
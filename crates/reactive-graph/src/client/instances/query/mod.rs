@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::client::instances::query::args::GraphQueryArgs;
+use crate::client::result::CommandResult;
+use reactive_graph_client::ReactiveGraphClient;
+
+pub(crate) mod args;
+
+pub(crate) async fn query(client: &Arc<ReactiveGraphClient>, args: GraphQueryArgs) -> CommandResult {
+    match client.instances().query().execute_with_plan(args.text()).await {
+        Ok((_, plan)) if args.explain => Ok(plan.into()),
+        Ok((result, _)) => Ok(Value::Array(result.to_objects()).into()),
+        Err(e) => Err(e.into()),
+    }
+}
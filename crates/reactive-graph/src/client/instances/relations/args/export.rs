@@ -0,0 +1,15 @@
+use crate::client::args::file::FileArgs;
+use crate::client::instances::relations::args::id::RelationInstanceIdArgs;
+use clap::Args;
+
+/// Exports the relation instance into a file.
+#[derive(Args, Debug, Clone)]
+pub(crate) struct ExportRelationInstanceArgs {
+    /// The relation instance.
+    #[clap(flatten)]
+    pub id: RelationInstanceIdArgs,
+
+    /// The file to export into.
+    #[clap(flatten)]
+    pub file: FileArgs,
+}
@@ -4,6 +4,7 @@ use clap::Args;
 
 pub mod add_property;
 pub mod create;
+pub mod export;
 pub mod id;
 pub mod id_and_component;
 pub mod id_and_property;
@@ -1,5 +1,7 @@
+use crate::client::args::file::FileArgs;
 use crate::client::instances::relations::args::add_property::AddPropertyArgs;
 use crate::client::instances::relations::args::create::CreateRelationInstanceArgs;
+use crate::client::instances::relations::args::export::ExportRelationInstanceArgs;
 use crate::client::instances::relations::args::id::RelationInstanceIdArgs;
 use crate::client::instances::relations::args::id_and_component::RelationInstanceIdAndComponentArgs;
 use crate::client::instances::relations::args::id_and_property::RelationInstanceIdAndPropertyArgs;
@@ -45,6 +47,12 @@ pub(crate) enum RelationInstancesCommands {
     // Deletes a relation instance.
     #[non_exhaustive]
     Delete(RelationInstanceIdArgs),
+    /// Imports a relation instance from a file.
+    #[non_exhaustive]
+    Import(FileArgs),
+    /// Exports a relation instance into a file.
+    #[non_exhaustive]
+    Export(ExportRelationInstanceArgs),
     /// Prints the JSON Schema of relation instances.
     #[non_exhaustive]
     JsonSchema,
@@ -11,6 +11,7 @@ use reactive_graph_client::ReactiveGraphClient;
 use reactive_graph_graph::ComponentTypeId;
 use reactive_graph_graph::PropertyInstanceGetter;
 use reactive_graph_graph::PropertyType;
+use reactive_graph_graph::RelationInstance;
 use reactive_graph_table_model::instances::properties::PropertyInstance;
 use std::sync::Arc;
 
@@ -119,6 +120,27 @@ pub(crate) async fn relation_instances(client: &Arc<ReactiveGraphClient>, relati
             Ok(None) => Err(args.not_found()),
             Err(e) => Err(e.into()),
         },
+        RelationInstancesCommands::Import(args) => {
+            let relation_instance: RelationInstance = args.read()?;
+            match client
+                .instances()
+                .relations()
+                .create(relation_instance.id(), Some(relation_instance.description), relation_instance.properties)
+                .await
+            {
+                Ok(Some(relation_instance)) => output_format_wrapper.single(relation_instance),
+                Ok(None) => Err(NoContent("Relation instance not created".to_string())),
+                Err(e) => Err(e.into()),
+            }
+        }
+        RelationInstancesCommands::Export(args) => match client.instances().relations().get_by_id(&args.id).await {
+            Ok(Some(relation_instance)) => {
+                args.file.write(&relation_instance)?;
+                Ok(format!("Relation instance {} exported to {}", args.id, args.file).into())
+            }
+            Ok(None) => Err(args.id.not_found()),
+            Err(e) => Err(e.into()),
+        },
         RelationInstancesCommands::JsonSchema => match client.json_schema().instances().relations().await {
             Ok(Some(json_schema)) => Ok(json_schema.into()),
             Ok(None) => Err(NotFound("JSON Schema not available".to_string())),
@@ -0,0 +1,32 @@
+use clap::Args;
+use reactive_graph_graph::DEFAULT_SEARCH_LIMIT;
+use reactive_graph_graph::SearchOptions;
+
+#[derive(Args, Debug, Clone)]
+pub(crate) struct SearchArgs {
+    /// The text to search for in the names, descriptions, labels and searchable properties of the instances.
+    #[arg(required = true, trailing_var_arg = true)]
+    pub text: Vec<String>,
+
+    /// Tokens only match tokens which are equal, not tokens starting with the token.
+    #[arg(long)]
+    pub no_prefix: bool,
+
+    /// Tokens only match tokens without typos.
+    #[arg(long)]
+    pub no_fuzzy: bool,
+
+    /// The maximum number of hits.
+    #[arg(long, default_value_t = DEFAULT_SEARCH_LIMIT)]
+    pub limit: usize,
+}
+
+impl SearchArgs {
+    pub fn text(&self) -> String {
+        self.text.join(" ")
+    }
+
+    pub fn options(&self) -> SearchOptions {
+        SearchOptions::builder().prefix(!self.no_prefix).fuzzy(!self.no_fuzzy).limit(self.limit).build()
+    }
+}
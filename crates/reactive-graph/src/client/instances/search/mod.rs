@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+use serde_json::json;
+
+use crate::client::instances::search::args::SearchArgs;
+use crate::client::result::CommandResult;
+use reactive_graph_client::ReactiveGraphClient;
+use reactive_graph_client::schema_graphql::instances::search_hit::SearchHitInstance;
+
+pub(crate) mod args;
+
+pub(crate) async fn search(client: &Arc<ReactiveGraphClient>, args: SearchArgs) -> CommandResult {
+    match client.instances().search().search_with_options(args.text(), &args.options()).await {
+        Ok(hits) => Ok(Value::Array(
+            hits.into_iter()
+                .map(|hit| {
+                    let fields: Vec<String> = hit.fields.iter().map(|field| field.to_string()).collect();
+                    match hit.instance {
+                        SearchHitInstance::Entity(entity_instance) => json!({ "score": hit.score, "fields": fields, "entity": entity_instance }),
+                        SearchHitInstance::Relation(relation_instance) => json!({ "score": hit.score, "fields": fields, "relation": relation_instance }),
+                    }
+                })
+                .collect(),
+        )
+        .into()),
+        Err(e) => Err(e.into()),
+    }
+}
@@ -1,10 +1,12 @@
 use crate::client::result::CommandResult;
 use crate::client::result::CommandResultBuilder;
+use reactive_graph_graph::NamespacedTypeGetter;
 use reactive_graph_table_model::container::TableInlineFormatSetter;
 use reactive_graph_table_model::container::TableOptions;
 
 use crate::shared::output_format::OutputFormatArgs;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::marker::PhantomData;
 use tabled::Tabled;
 
@@ -28,4 +30,29 @@ impl<S: Serialize + 'static, T: Clone + Tabled + From<S> + TableInlineFormatSett
     pub(crate) fn collection(self, collection: Vec<S>) -> CommandResult {
         CommandResultBuilder::<S, T, O>::collection(collection, self.0).into_command_result()
     }
+
+    /// Renders a table for each namespace. The other output formats contain the namespace of each
+    /// type, so the types are only sorted.
+    pub(crate) fn grouped_by_namespace(self, mut collection: Vec<S>) -> CommandResult
+    where
+        S: NamespacedTypeGetter + Ord,
+    {
+        collection.sort();
+        let heading: fn(&str) -> String = match self.0.clone().unwrap_or_default() {
+            OutputFormatArgs::Table => |namespace| format!("Namespace {namespace}"),
+            OutputFormatArgs::HtmlTable => |namespace| format!("<h3>{namespace}</h3>"),
+            OutputFormatArgs::MarkdownTable => |namespace| format!("### {namespace}"),
+            _ => return self.collection(collection),
+        };
+        let mut groups: BTreeMap<String, Vec<S>> = BTreeMap::new();
+        for item in collection {
+            groups.entry(item.namespace()).or_default().push(item);
+        }
+        let mut tables = Vec::new();
+        for (namespace, items) in groups {
+            let table = CommandResultBuilder::<S, T, O>::collection(items, self.0.clone()).into_command_result()?;
+            tables.push(format!("{}\n{table}", heading(&namespace)));
+        }
+        Ok(tables.join("\n\n").into())
+    }
 }
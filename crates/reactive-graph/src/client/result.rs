@@ -1,7 +1,7 @@
 use crate::client::error::CommandError;
-use crate::client::error::CommandError::NotImplemented;
 use crate::shared::output_format::OutputFormatArgs;
 use reactive_graph_serde::error::SerializationError;
+use reactive_graph_serde::format::SerializationFormat;
 use reactive_graph_table_model::container::DefaultTableContainer;
 use reactive_graph_table_model::container::TableContainer;
 use reactive_graph_table_model::container::TableInlineFormatSetter;
@@ -127,7 +127,10 @@ impl<S: Serialize + 'static, T: Clone + Tabled + From<S> + TableInlineFormatSett
                     .into()),
             },
             #[cfg(feature = "json5")]
-            OutputFormatArgs::Json5 => Err(NotImplemented),
+            OutputFormatArgs::Json5 => match self.object_or_collection {
+                CommandResultBuilderContent::Single(single_object) => Ok(SerializationFormat::Json5.serialize(&single_object)?.into()),
+                CommandResultBuilderContent::Collection(collection) => Ok(SerializationFormat::Json5.serialize(&collection)?.into()),
+            },
             #[cfg(feature = "toml")]
             OutputFormatArgs::Toml => match self.object_or_collection {
                 CommandResultBuilderContent::Single(single_object) => Ok(toml::Value::try_from(single_object)
@@ -142,6 +145,11 @@ impl<S: Serialize + 'static, T: Clone + Tabled + From<S> + TableInlineFormatSett
                     Ok(table.into())
                 }
             },
+            #[cfg(feature = "yaml")]
+            OutputFormatArgs::Yaml => match self.object_or_collection {
+                CommandResultBuilderContent::Single(single_object) => Ok(SerializationFormat::Yaml.serialize(&single_object)?.into()),
+                CommandResultBuilderContent::Collection(collection) => Ok(SerializationFormat::Yaml.serialize(&collection)?.into()),
+            },
         }
     }
 }
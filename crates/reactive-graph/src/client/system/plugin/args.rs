@@ -1,4 +1,5 @@
 use clap::Args;
+use clap::ValueEnum;
 
 use reactive_graph_client::client::plugin::variables::search::variables::SearchPluginVariables;
 
@@ -32,6 +33,24 @@ pub(crate) struct SearchPluginsArgs {
     pub stem: Option<String>,
 }
 
+#[derive(Args, Debug, Clone)]
+pub(crate) struct PluginDiagnosticsArgs {
+    /// The output format. Defaults to the report.
+    #[arg(long)]
+    pub format: Option<DiagnosticsFormatArgs>,
+}
+
+/// The output format of the plugin resolver diagnostics.
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub(crate) enum DiagnosticsFormatArgs {
+    /// Human-readable report
+    Report,
+    /// Dependency graph in the DOT format of graphviz
+    Dot,
+    /// Dependency graph as mermaid flowchart
+    Mermaid,
+}
+
 impl From<SearchPluginsArgs> for SearchPluginVariables {
     fn from(args: SearchPluginsArgs) -> Self {
         SearchPluginVariables {
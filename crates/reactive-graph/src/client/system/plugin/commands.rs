@@ -1,6 +1,7 @@
 use clap::Subcommand;
 
 use crate::client::system::plugin::args::PluginByNameArgs;
+use crate::client::system::plugin::args::PluginDiagnosticsArgs;
 use crate::client::system::plugin::args::SearchPluginsArgs;
 
 #[derive(Subcommand, Debug, Clone)]
@@ -32,4 +33,7 @@ pub(crate) enum PluginsCommands {
     /// Uninstall a plugin.
     #[non_exhaustive]
     Uninstall(PluginByNameArgs),
+    /// Prints the diagnostics of the plugin resolver or exports the dependency graph.
+    #[non_exhaustive]
+    Diagnostics(PluginDiagnosticsArgs),
 }
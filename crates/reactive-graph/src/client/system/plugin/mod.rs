@@ -4,6 +4,7 @@ use crate::client::error::CommandError;
 use crate::client::error::CommandError::NoChange;
 use crate::client::error::CommandError::NotFound;
 use crate::client::result::CommandResult;
+use crate::client::system::plugin::args::DiagnosticsFormatArgs;
 use crate::client::system::plugin::args::PluginsArgs;
 use crate::client::system::plugin::commands::PluginsCommands;
 use reactive_graph_client::ReactiveGraphClient;
@@ -57,6 +58,14 @@ pub(crate) async fn plugins(client: &Arc<ReactiveGraphClient>, plugins_args: Plu
             Ok(false) => Err(NoChange("Plugin wasn't uninstalled.".to_string())),
             Err(e) => Err(e.into()),
         },
+        PluginsCommands::Diagnostics(args) => match client.runtime().plugins().diagnostics().await {
+            Ok(diagnostics) => match args.format.unwrap_or(DiagnosticsFormatArgs::Report) {
+                DiagnosticsFormatArgs::Report => Ok(diagnostics.report.into()),
+                DiagnosticsFormatArgs::Dot => Ok(diagnostics.dot.into()),
+                DiagnosticsFormatArgs::Mermaid => Ok(diagnostics.mermaid.into()),
+            },
+            Err(e) => Err(e.into()),
+        },
     }
 }
 
@@ -0,0 +1,15 @@
+use crate::client::args::file::FileArgs;
+use crate::client::types::components::args::type_id::ComponentTypeIdArgs;
+use clap::Args;
+
+/// Exports the component into a file.
+#[derive(Args, Debug, Clone)]
+pub(crate) struct ExportComponentArgs {
+    /// The component type.
+    #[clap(flatten)]
+    pub ty: ComponentTypeIdArgs,
+
+    /// The file to export into.
+    #[clap(flatten)]
+    pub file: FileArgs,
+}
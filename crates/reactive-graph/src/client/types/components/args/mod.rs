@@ -3,6 +3,7 @@ pub mod add_property;
 pub mod component_extension_type;
 pub mod component_property;
 pub mod create;
+pub mod export;
 pub mod type_id;
 pub mod update_description;
 
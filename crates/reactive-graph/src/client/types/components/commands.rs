@@ -1,10 +1,12 @@
 use clap::Subcommand;
 
+use crate::client::args::file::FileArgs;
 use crate::client::types::components::args::add_extension::ComponentAddExtensionArgs;
 use crate::client::types::components::args::add_property::ComponentAddPropertyArgs;
 use crate::client::types::components::args::component_extension_type::ComponentExtensionTypeIdArgs;
 use crate::client::types::components::args::component_property::ComponentPropertyArgs;
 use crate::client::types::components::args::create::CreateComponentArgs;
+use crate::client::types::components::args::export::ExportComponentArgs;
 use crate::client::types::components::args::type_id::ComponentTypeIdArgs;
 use crate::client::types::components::args::update_description::ComponentUpdateDescriptionArgs;
 
@@ -46,6 +48,12 @@ pub(crate) enum ComponentsCommands {
     /// Updates the description of a component.
     #[non_exhaustive]
     UpdateDescription(ComponentUpdateDescriptionArgs),
+    /// Imports a component from a file.
+    #[non_exhaustive]
+    Import(FileArgs),
+    /// Exports a component into a file.
+    #[non_exhaustive]
+    Export(ExportComponentArgs),
     /// Prints the JSON Schema of components.
     #[non_exhaustive]
     JsonSchema,
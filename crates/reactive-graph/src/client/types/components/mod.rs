@@ -11,6 +11,7 @@ use crate::client::types::components::output_format::ComponentsOutputFormatWrapp
 use crate::client::types::extension::output_format::ExtensionsOutputFormatWrapper;
 use crate::client::types::property_type::output_format::PropertyTypesOutputFormatWrapper;
 use reactive_graph_client::ReactiveGraphClient;
+use reactive_graph_graph::Component;
 use reactive_graph_graph::NamespacedTypeContainer;
 use reactive_graph_graph::TYPE_ID_TYPE_SEPARATOR;
 
@@ -25,7 +26,7 @@ pub(crate) async fn components(client: &Arc<ReactiveGraphClient>, component_args
     };
     match command {
         ComponentsCommands::List => match client.types().components().get_all_components().await {
-            Ok(Some(components)) => output_format_wrapper.collection(components),
+            Ok(Some(components)) => output_format_wrapper.grouped_by_namespace(components),
             Ok(None) => Err(NoContent("No components found".to_string())),
             Err(e) => Err(e.into()),
         },
@@ -91,6 +92,22 @@ pub(crate) async fn components(client: &Arc<ReactiveGraphClient>, component_args
             Ok(None) => Err(args.ty.not_found()),
             Err(e) => Err(e.into()),
         },
+        ComponentsCommands::Import(args) => {
+            let component: Component = args.read()?;
+            match client.types().components().create_component(component).await {
+                Ok(Some(component)) => output_format_wrapper.single(component),
+                Ok(None) => Err(NotCreated("Component wasn't created".to_string())),
+                Err(e) => Err(e.into()),
+            }
+        }
+        ComponentsCommands::Export(args) => match client.types().components().get_component_by_type(args.ty.clone()).await {
+            Ok(Some(component)) => {
+                args.file.write(&component)?;
+                Ok(format!("Component {} exported to {}", component.ty, args.file).into())
+            }
+            Ok(None) => Err(args.ty.not_found()),
+            Err(e) => Err(e.into()),
+        },
         ComponentsCommands::JsonSchema => match client.json_schema().types().components().await {
             Ok(Some(json_schema)) => Ok(json_schema.into()),
             Ok(None) => Err(NotFound("JSON Schema not available".to_string())),
@@ -0,0 +1,15 @@
+use crate::client::args::file::FileArgs;
+use crate::client::types::entities::args::type_id::EntityTypeIdArgs;
+use clap::Args;
+
+/// Exports the entity type into a file.
+#[derive(Args, Debug, Clone)]
+pub(crate) struct ExportEntityTypeArgs {
+    /// The entity type.
+    #[clap(flatten)]
+    pub ty: EntityTypeIdArgs,
+
+    /// The file to export into.
+    #[clap(flatten)]
+    pub file: FileArgs,
+}
@@ -8,6 +8,7 @@ pub mod create;
 pub mod entity_component_type;
 pub mod entity_extension_type;
 pub mod entity_type_property;
+pub mod export;
 pub mod type_id;
 pub mod update_description;
 
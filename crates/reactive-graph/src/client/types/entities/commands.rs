@@ -1,9 +1,11 @@
+use crate::client::args::file::FileArgs;
 use crate::client::types::entities::args::add_extension::EntityTypeAddExtensionArgs;
 use crate::client::types::entities::args::add_property::EntityTypeAddPropertyArgs;
 use crate::client::types::entities::args::create::CreateEntityTypeArgs;
 use crate::client::types::entities::args::entity_component_type::EntityComponentTypeIdArgs;
 use crate::client::types::entities::args::entity_extension_type::EntityExtensionTypeIdArgs;
 use crate::client::types::entities::args::entity_type_property::EntityTypePropertyArgs;
+use crate::client::types::entities::args::export::ExportEntityTypeArgs;
 use crate::client::types::entities::args::type_id::EntityTypeIdArgs;
 use crate::client::types::entities::args::update_description::EntityTypeUpdateDescriptionArgs;
 use clap::Subcommand;
@@ -55,6 +57,12 @@ pub(crate) enum EntityTypesCommands {
     /// Updates the description of an entity type.
     #[non_exhaustive]
     UpdateDescription(EntityTypeUpdateDescriptionArgs),
+    /// Imports an entity type from a file.
+    #[non_exhaustive]
+    Import(FileArgs),
+    /// Exports an entity type into a file.
+    #[non_exhaustive]
+    Export(ExportEntityTypeArgs),
     /// Prints the JSON Schema of entity types.
     #[non_exhaustive]
     JsonSchema,
@@ -12,6 +12,7 @@ use crate::client::types::entities::output_format::EntityTypesOutputFormatWrappe
 use crate::client::types::extension::output_format::ExtensionsOutputFormatWrapper;
 use crate::client::types::property_type::output_format::PropertyTypesOutputFormatWrapper;
 use reactive_graph_client::ReactiveGraphClient;
+use reactive_graph_graph::EntityType;
 use reactive_graph_graph::NamespacedTypeContainer;
 
 pub(crate) mod args;
@@ -25,7 +26,7 @@ pub(crate) async fn entity_types(client: &Arc<ReactiveGraphClient>, entity_type_
     };
     match command {
         EntityTypesCommands::List => match client.types().entities().get_all_entity_types().await {
-            Ok(Some(entity_types)) => output_format_wrapper.collection(entity_types),
+            Ok(Some(entity_types)) => output_format_wrapper.grouped_by_namespace(entity_types),
             Ok(None) => Err(NoContent("No entity types found".to_string())),
             Err(e) => Err(e.into()),
         },
@@ -109,6 +110,22 @@ pub(crate) async fn entity_types(client: &Arc<ReactiveGraphClient>, entity_type_
             Ok(None) => Err(args.ty.not_found()),
             Err(e) => Err(e.into()),
         },
+        EntityTypesCommands::Import(args) => {
+            let entity_type: EntityType = args.read()?;
+            match client.types().entities().create_entity_type(entity_type).await {
+                Ok(Some(entity_type)) => output_format_wrapper.single(entity_type),
+                Ok(None) => Err(NotCreated("Entity type wasn't created".to_string())),
+                Err(e) => Err(e.into()),
+            }
+        }
+        EntityTypesCommands::Export(args) => match client.types().entities().get_entity_type_by_type(args.ty.clone()).await {
+            Ok(Some(entity_type)) => {
+                args.file.write(&entity_type)?;
+                Ok(format!("Entity type {} exported to {}", entity_type.ty, args.file).into())
+            }
+            Ok(None) => Err(args.ty.not_found()),
+            Err(e) => Err(e.into()),
+        },
         EntityTypesCommands::JsonSchema => match client.json_schema().types().entities().await {
             Ok(Some(json_schema)) => Ok(json_schema.into()),
             Ok(None) => Err(NotFound("JSON Schema not available".to_string())),
@@ -0,0 +1,15 @@
+use crate::client::args::file::FileArgs;
+use crate::client::types::flows::args::type_id::FlowTypeIdArgs;
+use clap::Args;
+
+/// Exports the flow type into a file.
+#[derive(Args, Debug, Clone)]
+pub(crate) struct ExportFlowTypeArgs {
+    /// The flow type.
+    #[clap(flatten)]
+    pub ty: FlowTypeIdArgs,
+
+    /// The file to export into.
+    #[clap(flatten)]
+    pub file: FileArgs,
+}
@@ -6,6 +6,7 @@ pub mod add_entity_instance;
 pub mod add_extension;
 pub mod add_variable;
 pub mod create;
+pub mod export;
 pub mod flow_extension_type;
 pub mod flow_type_variable;
 pub mod remove_entity_instance;
@@ -1,7 +1,9 @@
+use crate::client::args::file::FileArgs;
 use crate::client::types::flows::args::add_entity_instance::FlowTypeAddEntityInstanceArgs;
 use crate::client::types::flows::args::add_extension::FlowTypeAddExtensionArgs;
 use crate::client::types::flows::args::add_variable::FlowTypeAddVariableArgs;
 use crate::client::types::flows::args::create::CreateFlowTypeArgs;
+use crate::client::types::flows::args::export::ExportFlowTypeArgs;
 use crate::client::types::flows::args::flow_extension_type::FlowExtensionTypeIdArgs;
 use crate::client::types::flows::args::flow_type_variable::FlowTypeVariableArgs;
 use crate::client::types::flows::args::remove_entity_instance::FlowTypeRemoveEntityInstanceArgs;
@@ -53,6 +55,12 @@ pub(crate) enum FlowTypesCommands {
     /// Removes an entity instance to a flow type.
     #[non_exhaustive]
     RemoveEntityInstance(FlowTypeRemoveEntityInstanceArgs),
+    /// Imports a flow type from a file.
+    #[non_exhaustive]
+    Import(FileArgs),
+    /// Exports a flow type into a file.
+    #[non_exhaustive]
+    Export(ExportFlowTypeArgs),
     /// Prints the JSON Schema of flow types.
     #[non_exhaustive]
     JsonSchema,
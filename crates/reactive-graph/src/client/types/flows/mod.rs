@@ -9,6 +9,7 @@ use crate::client::types::flows::commands::FlowTypesCommands;
 use crate::client::types::flows::output_format::FlowTypesOutputFormatWrapper;
 use crate::client::types::property_type::output_format::PropertyTypesOutputFormatWrapper;
 use reactive_graph_client::ReactiveGraphClient;
+use reactive_graph_graph::FlowType;
 use reactive_graph_graph::NamespacedTypeContainer;
 use std::sync::Arc;
 
@@ -23,7 +24,7 @@ pub(crate) async fn flow_types(client: &Arc<ReactiveGraphClient>, flow_type_args
     };
     match command {
         FlowTypesCommands::List => match client.types().flows().get_all_flow_types().await {
-            Ok(Some(flow_types)) => output_format_wrapper.collection(flow_types),
+            Ok(Some(flow_types)) => output_format_wrapper.grouped_by_namespace(flow_types),
             Ok(None) => Err(NoContent("No flow types found".to_string())),
             Err(e) => Err(e.into()),
         },
@@ -99,6 +100,22 @@ pub(crate) async fn flow_types(client: &Arc<ReactiveGraphClient>, flow_type_args
             Ok(None) => Err(args.ty.not_found()),
             Err(e) => Err(e.into()),
         },
+        FlowTypesCommands::Import(args) => {
+            let flow_type: FlowType = args.read()?;
+            match client.types().flows().create_flow_type(flow_type).await {
+                Ok(Some(flow_type)) => output_format_wrapper.single(flow_type),
+                Ok(None) => Err(NotCreated("Flow type wasn't created".to_string())),
+                Err(e) => Err(e.into()),
+            }
+        }
+        FlowTypesCommands::Export(args) => match client.types().flows().get_flow_type_by_type(args.ty.clone()).await {
+            Ok(Some(flow_type)) => {
+                args.file.write(&flow_type)?;
+                Ok(format!("Flow type {} exported to {}", flow_type.ty, args.file).into())
+            }
+            Ok(None) => Err(args.ty.not_found()),
+            Err(e) => Err(e.into()),
+        },
         FlowTypesCommands::JsonSchema => match client.json_schema().types().flows().await {
             Ok(Some(json_schema)) => Ok(json_schema.into()),
             Ok(None) => Err(NotFound("JSON Schema not available".to_string())),
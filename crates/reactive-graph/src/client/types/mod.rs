@@ -2,5 +2,6 @@ pub(crate) mod components;
 pub(crate) mod entities;
 pub(crate) mod extension;
 pub(crate) mod flows;
+pub(crate) mod namespaces;
 pub(crate) mod property_type;
 pub(crate) mod relations;
@@ -0,0 +1,29 @@
+use clap::Args;
+
+use crate::client::error::CommandError;
+use crate::client::error::CommandError::NotFound;
+use crate::client::types::namespaces::commands::NamespacesCommands;
+use crate::shared::output_format::OutputFormatArgs;
+
+#[derive(Args, Debug, Clone)]
+#[clap(subcommand_required = true)]
+pub(crate) struct NamespacesArgs {
+    #[command(subcommand)]
+    pub(crate) commands: Option<NamespacesCommands>,
+
+    #[arg(global = true, short, long)]
+    pub output_format: Option<OutputFormatArgs>,
+}
+
+/// The namespace.
+#[derive(Args, Debug, Clone)]
+pub(crate) struct NamespaceNameArgs {
+    /// The name of the namespace.
+    pub name: String,
+}
+
+impl NamespaceNameArgs {
+    pub fn not_found(&self) -> CommandError {
+        NotFound(format!("Namespace {} not found", &self.name))
+    }
+}
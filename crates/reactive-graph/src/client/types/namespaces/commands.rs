@@ -0,0 +1,13 @@
+use clap::Subcommand;
+
+use crate::client::types::namespaces::args::NamespaceNameArgs;
+
+#[derive(Subcommand, Debug, Clone)]
+pub(crate) enum NamespacesCommands {
+    /// List all namespaces.
+    #[non_exhaustive]
+    List,
+    /// Prints the metadata of a single namespace.
+    #[non_exhaustive]
+    Get(NamespaceNameArgs),
+}
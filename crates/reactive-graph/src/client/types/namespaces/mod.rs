@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::client::error::CommandError;
+use crate::client::error::CommandError::NoContent;
+use crate::client::result::CommandResult;
+use crate::client::types::namespaces::args::NamespacesArgs;
+use crate::client::types::namespaces::commands::NamespacesCommands;
+use crate::client::types::namespaces::output_format::NamespacesOutputFormatWrapper;
+use reactive_graph_client::ReactiveGraphClient;
+
+pub(crate) mod args;
+pub(crate) mod commands;
+pub(crate) mod output_format;
+
+pub(crate) async fn namespaces(client: &Arc<ReactiveGraphClient>, namespace_args: NamespacesArgs) -> CommandResult {
+    let output_format_wrapper: NamespacesOutputFormatWrapper = namespace_args.output_format.clone().into();
+    let Some(command) = namespace_args.commands else {
+        return Err(CommandError::MissingSubCommand);
+    };
+    match command {
+        NamespacesCommands::List => match client.types().namespaces().get_all_namespaces().await {
+            Ok(Some(namespaces)) => output_format_wrapper.collection(namespaces),
+            Ok(None) => Err(NoContent("No namespaces found".to_string())),
+            Err(e) => Err(e.into()),
+        },
+        NamespacesCommands::Get(args) => match client.types().namespaces().get_namespace_by_name(&args.name).await {
+            Ok(Some(namespace)) => output_format_wrapper.single(namespace),
+            Ok(None) => Err(args.not_found()),
+            Err(e) => Err(e.into()),
+        },
+    }
+}
@@ -0,0 +1,7 @@
+use crate::client::output_format::OutputFormatWrapper;
+
+pub(crate) type NamespacesOutputFormatWrapper = OutputFormatWrapper<
+    reactive_graph_graph::Namespace,
+    reactive_graph_table_model::types::namespace::Namespace,
+    reactive_graph_table_model::types::namespace::NamespacesTableOptions,
+>;
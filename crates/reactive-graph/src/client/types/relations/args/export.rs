@@ -0,0 +1,15 @@
+use crate::client::args::file::FileArgs;
+use crate::client::types::relations::args::type_id::RelationTypeIdArgs;
+use clap::Args;
+
+/// Exports the relation type into a file.
+#[derive(Args, Debug, Clone)]
+pub(crate) struct ExportRelationTypeArgs {
+    /// The relation type.
+    #[clap(flatten)]
+    pub ty: RelationTypeIdArgs,
+
+    /// The file to export into.
+    #[clap(flatten)]
+    pub file: FileArgs,
+}
@@ -5,6 +5,7 @@ use clap::Args;
 pub mod add_extension;
 pub mod add_property;
 pub mod create;
+pub mod export;
 pub mod relation_component_type;
 pub mod relation_extension_type;
 pub mod relation_type_property;
@@ -1,6 +1,8 @@
+use crate::client::args::file::FileArgs;
 use crate::client::types::relations::args::add_extension::RelationTypeAddExtensionArgs;
 use crate::client::types::relations::args::add_property::RelationTypeAddPropertyArgs;
 use crate::client::types::relations::args::create::CreateRelationTypeArgs;
+use crate::client::types::relations::args::export::ExportRelationTypeArgs;
 use crate::client::types::relations::args::relation_component_type::RelationComponentTypeIdArgs;
 use crate::client::types::relations::args::relation_extension_type::RelationExtensionTypeIdArgs;
 use crate::client::types::relations::args::relation_type_property::RelationTypePropertyArgs;
@@ -55,6 +57,12 @@ pub(crate) enum RelationTypesCommands {
     /// Updates the description of a relation type.
     #[non_exhaustive]
     UpdateDescription(RelationTypeUpdateDescriptionArgs),
+    /// Imports a relation type from a file.
+    #[non_exhaustive]
+    Import(FileArgs),
+    /// Exports a relation type into a file.
+    #[non_exhaustive]
+    Export(ExportRelationTypeArgs),
     /// Prints the JSON Schema of relation types.
     #[non_exhaustive]
     JsonSchema,
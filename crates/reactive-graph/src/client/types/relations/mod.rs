@@ -11,6 +11,7 @@ use crate::client::types::relations::commands::RelationTypesCommands;
 use crate::client::types::relations::output_format::RelationTypesOutputFormatWrapper;
 use reactive_graph_client::ReactiveGraphClient;
 use reactive_graph_graph::NamespacedTypeContainer;
+use reactive_graph_graph::RelationType;
 use std::sync::Arc;
 
 pub(crate) mod args;
@@ -24,7 +25,7 @@ pub(crate) async fn relation_types(client: &Arc<ReactiveGraphClient>, relation_t
     };
     match command {
         RelationTypesCommands::List => match client.types().relations().get_all_relation_types().await {
-            Ok(Some(relation_types)) => output_format_wrapper.collection(relation_types),
+            Ok(Some(relation_types)) => output_format_wrapper.grouped_by_namespace(relation_types),
             Ok(None) => Err(NoContent("No relation types found".to_string())),
             Err(e) => Err(e.into()),
         },
@@ -108,6 +109,22 @@ pub(crate) async fn relation_types(client: &Arc<ReactiveGraphClient>, relation_t
             Ok(None) => Err(args.ty.not_found()),
             Err(e) => Err(e.into()),
         },
+        RelationTypesCommands::Import(args) => {
+            let relation_type: RelationType = args.read()?;
+            match client.types().relations().create_relation_type(relation_type).await {
+                Ok(Some(relation_type)) => output_format_wrapper.single(relation_type),
+                Ok(None) => Err(NotCreated("Relation type wasn't created".to_string())),
+                Err(e) => Err(e.into()),
+            }
+        }
+        RelationTypesCommands::Export(args) => match client.types().relations().get_relation_type_by_type(args.ty.clone()).await {
+            Ok(Some(relation_type)) => {
+                args.file.write(&relation_type)?;
+                Ok(format!("Relation type {} exported to {}", relation_type.ty, args.file).into())
+            }
+            Ok(None) => Err(args.ty.not_found()),
+            Err(e) => Err(e.into()),
+        },
         RelationTypesCommands::JsonSchema => match client.json_schema().types().relations().await {
             Ok(Some(json_schema)) => Ok(json_schema.into()),
             Ok(None) => Err(NotFound("JSON Schema not available".to_string())),
@@ -5,6 +5,7 @@ pub mod daemon;
 
 pub mod graphql_schema;
 pub mod json_schema;
+pub mod static_plugins;
 
 use std::time::Duration;
 
@@ -13,6 +14,7 @@ use reactive_graph_runtime_impl::RuntimeBuilder;
 use crate::server::args::logging::init_logging;
 use crate::server::graphql_schema::print_graphql_schema_and_exit;
 use crate::server::json_schema::print_json_schema_and_exit;
+use crate::server::static_plugins::static_plugins;
 use args::ServerArguments;
 
 #[tokio::main]
@@ -62,6 +64,7 @@ pub async fn run(args: ServerArguments) {
         .disable_hot_deploy(args.runtime.plugins.disable_hot_deploy)
         .hot_deploy_location(args.runtime.plugins.hot_deploy_location)
         .install_location(args.runtime.plugins.install_location)
+        .static_plugins(static_plugins())
         .init()
         .await
         .post_init()
@@ -0,0 +1,23 @@
+use reactive_graph_plugin_api::PluginDeclaration;
+
+/// Returns the plugins which are linked statically into the server binary.
+///
+/// A plugin is linked statically by adding the plugin crate as optional dependency and a
+/// feature which enables both, the dependency and the feature `static-plugins`:
+///
+/// ```toml
+/// [features]
+/// plugin-base = ["static-plugins", "dep:reactive-graph-plugin-base"]
+/// ```
+///
+/// Afterward, the plugin declaration of the plugin is added to the registration list:
+///
+/// ```ignore
+/// #[cfg(feature = "plugin-base")]
+/// reactive_graph_plugin_base::plugin_declaration,
+/// ```
+pub fn static_plugins() -> Vec<PluginDeclaration> {
+    vec![
+        // Registration list of statically linked plugins
+    ]
+}
@@ -21,6 +21,8 @@ pub enum OutputFormatArgs {
     Json5,
     // The output is returned as TOML.
     Toml,
+    // The output is returned as YAML.
+    Yaml,
 }
 
 #[derive(Parser, Debug)]
@@ -1,6 +1,7 @@
 use crate::shared::output_format::OutputFormatArgs;
 use crate::shared::output_format::OutputFormatArgsOptional;
 use reactive_graph_serde::error::SerializationError;
+use reactive_graph_serde::format::SerializationFormat;
 use serde::Serialize;
 use std::process::exit;
 use table_to_html::HtmlTable;
@@ -52,6 +53,7 @@ impl<T: Tabled + Serialize> RenderTable for Vec<T> {
                 let table = toml::Value::Table(map);
                 Ok(toml::to_string_pretty(&table).map_err(SerializationError::Toml)?)
             }
+            OutputFormatArgs::Yaml => Ok(SerializationFormat::Yaml.serialize(self)?),
         }
     }
 
@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+use clap::ArgGroup;
+use clap::Parser;
+
+use crate::tooling::codegen::commands::CodegenCommands;
+
+#[derive(Parser, Debug)]
+pub struct CodegenArgs {
+    #[command(subcommand)]
+    pub commands: CodegenCommands,
+}
+
+#[derive(Parser, Debug)]
+pub struct RustModelArgs {
+    /// The directory containing the type definitions. The components, entity types, relation
+    /// types and flow types are read from the subdirectories components, entities, relations
+    /// and flows.
+    #[arg(long, default_value = "types")]
+    pub types: PathBuf,
+
+    /// The output directory. The model is written in crate layout with lib.rs as root file.
+    /// If --single-file is set, the path of the file to write.
+    #[arg(long)]
+    pub output: PathBuf,
+
+    /// Writes the model into a single file with inline modules.
+    #[arg(long)]
+    pub single_file: bool,
+}
+
+#[derive(Parser, Debug)]
+#[clap(group(ArgGroup::new("source").required(true).args(&["schema", "url"])))]
+pub struct TypeScriptClientArgs {
+    /// The file containing the exported schema of the dynamic graph.
+    #[arg(long)]
+    pub schema: Option<PathBuf>,
+
+    /// The url of the dynamic graph endpoint of a running instance.
+    #[arg(long)]
+    pub url: Option<String>,
+
+    /// The bearer token to use for authenticating against the running instance.
+    #[arg(long, requires = "url")]
+    pub bearer: Option<String>,
+
+    /// The output directory. For each namespace a TypeScript module and a GraphQL document are
+    /// written.
+    #[arg(long)]
+    pub output: PathBuf,
+}
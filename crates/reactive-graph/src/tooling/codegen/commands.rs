@@ -0,0 +1,14 @@
+use clap::Subcommand;
+
+use crate::tooling::codegen::args::RustModelArgs;
+use crate::tooling::codegen::args::TypeScriptClientArgs;
+
+#[derive(Subcommand, Debug)]
+pub enum CodegenCommands {
+    /// Generates the rust model crate from the type definitions.
+    Rust(RustModelArgs),
+    /// Generates TypeScript types and typed query documents per namespace from the dynamic
+    /// graph schema.
+    #[command(name = "typescript")]
+    TypeScript(TypeScriptClientArgs),
+}
@@ -0,0 +1,52 @@
+use std::fs::read_to_string;
+
+use anyhow::Result;
+use anyhow::anyhow;
+
+use reactive_graph_dynamic_graph_client_generator::fetch_schema;
+use reactive_graph_dynamic_graph_client_generator::generate as generate_client;
+use reactive_graph_type_system_model_generator::generate_from_directory;
+
+use crate::tooling::codegen::args::CodegenArgs;
+use crate::tooling::codegen::args::RustModelArgs;
+use crate::tooling::codegen::args::TypeScriptClientArgs;
+use crate::tooling::codegen::commands::CodegenCommands;
+
+pub mod args;
+pub mod commands;
+
+pub fn handle_codegen(args: CodegenArgs) -> Result<()> {
+    match args.commands {
+        CodegenCommands::Rust(args) => generate_rust_model(args),
+        CodegenCommands::TypeScript(args) => generate_typescript_client(args),
+    }
+}
+
+fn generate_rust_model(args: RustModelArgs) -> Result<()> {
+    let model = generate_from_directory(&args.types)?;
+    if model.modules.is_empty() {
+        return Err(anyhow!("No type definitions found in {}", args.types.display()));
+    }
+    if args.single_file {
+        model.write_to_file(&args.output)?;
+        println!("Generated {}", args.output.display());
+    } else {
+        for path in model.write_to_directory(&args.output)? {
+            println!("Generated {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+fn generate_typescript_client(args: TypeScriptClientArgs) -> Result<()> {
+    let sdl = match (&args.schema, &args.url) {
+        (Some(schema), _) => read_to_string(schema).map_err(|e| anyhow!("Failed to read the schema {}: {e}", schema.display()))?,
+        (None, Some(url)) => tokio::runtime::Runtime::new()?.block_on(fetch_schema(url, args.bearer.as_deref()))?,
+        (None, None) => return Err(anyhow!("Either --schema or --url must be given")),
+    };
+    let client = generate_client(&sdl)?;
+    for path in client.write_to_directory(&args.output)? {
+        println!("Generated {}", path.display());
+    }
+    Ok(())
+}
@@ -1,9 +1,13 @@
+use crate::tooling::codegen::args::CodegenArgs;
 use crate::tooling::instances::args::InstancesArgs;
 use crate::tooling::update::args::UpdateArgs;
 use clap::Subcommand;
 
 #[derive(Subcommand, Debug)]
 pub enum ToolingCommands {
+    /// Generates code from type definitions and from the dynamic graph schema.
+    Codegen(CodegenArgs),
+
     /// Manage instances.
     Instances(InstancesArgs),
 
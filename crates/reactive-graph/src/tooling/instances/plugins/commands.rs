@@ -1,4 +1,5 @@
 use crate::tooling::instances::plugins::install::args::InstallPluginsFromRepositoryArgs;
+use crate::tooling::instances::plugins::registry::args::PluginRegistryArgs;
 use crate::tooling::instances::plugins::uninstall::args::UninstallPluginArgs;
 use clap::Subcommand;
 
@@ -6,6 +7,8 @@ use clap::Subcommand;
 pub enum PluginsCommands {
     /// Installs plugins from a plugin repository.
     Install(InstallPluginsFromRepositoryArgs),
+    /// Installs plugins from a local plugin registry.
+    Registry(PluginRegistryArgs),
     /// Uninstalls a plugin.
     Uninstall(UninstallPluginArgs),
 }
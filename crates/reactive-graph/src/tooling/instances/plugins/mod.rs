@@ -1,6 +1,7 @@
 use crate::tooling::instances::plugins::args::PluginsArgs;
 use crate::tooling::instances::plugins::commands::PluginsCommands;
 use crate::tooling::instances::plugins::install::install_plugin;
+use crate::tooling::instances::plugins::registry::handle_plugin_registry;
 use crate::tooling::instances::plugins::uninstall::uninstall_plugin;
 use anyhow::Result;
 use std::path::Path;
@@ -8,11 +9,13 @@ use std::path::Path;
 pub mod args;
 pub mod commands;
 pub mod install;
+pub mod registry;
 pub mod uninstall;
 
 pub fn handle_plugins(instance_dir: &Path, args: PluginsArgs) -> Result<()> {
     match args.commands {
         PluginsCommands::Install(args) => install_plugin(instance_dir, args)?,
+        PluginsCommands::Registry(args) => handle_plugin_registry(instance_dir, args)?,
         PluginsCommands::Uninstall(args) => uninstall_plugin(instance_dir, args)?,
     }
     Ok(())
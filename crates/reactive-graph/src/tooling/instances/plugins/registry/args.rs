@@ -0,0 +1,39 @@
+use crate::tooling::instances::plugins::registry::commands::PluginRegistryCommands;
+use clap::ArgAction;
+use clap::Parser;
+use std::path::PathBuf;
+
+#[derive(Parser, Debug)]
+pub struct PluginRegistryArgs {
+    /// The location of the plugin registry. Either the folder containing the index.toml or the index file itself.
+    #[clap(short, long, env = "REACTIVE_GRAPH_PLUGIN_REGISTRY")]
+    pub registry: PathBuf,
+
+    #[command(subcommand)]
+    pub commands: PluginRegistryCommands,
+}
+
+#[derive(Parser, Debug)]
+#[clap(disable_version_flag = true)]
+pub struct InstallPluginFromRegistryArgs {
+    /// The name of the plugin.
+    pub plugin_name: String,
+
+    /// The version requirement. Lower versions than the installed version are downgraded.
+    #[clap(short, long)]
+    pub version: Option<String>,
+
+    /// Only shows which plugins would be installed, upgraded or downgraded.
+    #[clap(long, action=ArgAction::SetTrue)]
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Parser, Debug)]
+pub struct UpgradePluginsFromRegistryArgs {
+    /// The names of the plugins to upgrade. If empty, all installed plugins are upgraded.
+    pub plugin_names: Vec<String>,
+
+    /// Only shows which plugins would be installed or upgraded.
+    #[clap(long, action=ArgAction::SetTrue)]
+    pub dry_run: Option<bool>,
+}
@@ -0,0 +1,13 @@
+use crate::tooling::instances::plugins::registry::args::InstallPluginFromRegistryArgs;
+use crate::tooling::instances::plugins::registry::args::UpgradePluginsFromRegistryArgs;
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug)]
+pub enum PluginRegistryCommands {
+    /// Lists the plugins and their versions in the plugin registry.
+    List,
+    /// Installs, upgrades or downgrades a plugin and its dependencies.
+    Install(InstallPluginFromRegistryArgs),
+    /// Upgrades plugins and their dependencies to the highest compatible versions.
+    Upgrade(UpgradePluginsFromRegistryArgs),
+}
@@ -0,0 +1,69 @@
+use crate::tooling::instances::plugins::registry::args::InstallPluginFromRegistryArgs;
+use crate::tooling::instances::plugins::registry::args::PluginRegistryArgs;
+use crate::tooling::instances::plugins::registry::args::UpgradePluginsFromRegistryArgs;
+use crate::tooling::instances::plugins::registry::commands::PluginRegistryCommands;
+use anyhow::Result;
+use reactive_graph_plugin_service_api::PluginRegistry;
+use reactive_graph_plugin_service_api::PluginRegistryActionKind;
+use reactive_graph_plugin_service_api::PluginRequirement;
+use reactive_graph_plugin_service_api::get_installed_plugins;
+use std::path::Path;
+
+pub mod args;
+pub mod commands;
+
+pub fn handle_plugin_registry(instance_dir: &Path, args: PluginRegistryArgs) -> Result<()> {
+    let registry = PluginRegistry::open(&args.registry)?;
+    match args.commands {
+        PluginRegistryCommands::List => list_plugins(&registry),
+        PluginRegistryCommands::Install(args) => install_plugin_from_registry(instance_dir, &registry, args)?,
+        PluginRegistryCommands::Upgrade(args) => upgrade_plugins_from_registry(instance_dir, &registry, args)?,
+    }
+    Ok(())
+}
+
+fn list_plugins(registry: &PluginRegistry) {
+    let mut names: Vec<String> = registry.get_packages().into_iter().map(|package| package.name).collect();
+    names.dedup();
+    for name in names {
+        println!("{} {}", name, registry.get_versions(&name).join(", "));
+    }
+}
+
+fn install_plugin_from_registry(instance_dir: &Path, registry: &PluginRegistry, args: InstallPluginFromRegistryArgs) -> Result<()> {
+    let requirement = PluginRequirement::new(&args.plugin_name, args.version.as_deref().unwrap_or("*"))?;
+    execute_plan(instance_dir, registry, &[requirement], args.dry_run.unwrap_or_default())
+}
+
+fn upgrade_plugins_from_registry(instance_dir: &Path, registry: &PluginRegistry, args: UpgradePluginsFromRegistryArgs) -> Result<()> {
+    let plugin_names = if args.plugin_names.is_empty() {
+        // Plugins which are not in the registry can't be upgraded
+        get_installed_plugins(&instance_dir.join("plugins").join("installed"))?
+            .into_keys()
+            .filter(|name| !registry.get_versions(name).is_empty())
+            .collect()
+    } else {
+        args.plugin_names
+    };
+    let requirements = plugin_names
+        .iter()
+        .map(|name| PluginRequirement::new(name, "*"))
+        .collect::<Result<Vec<_>, _>>()?;
+    execute_plan(instance_dir, registry, &requirements, args.dry_run.unwrap_or_default())
+}
+
+/// Resolves the dependency closure and copies the plugin packages into the deploy folder.
+fn execute_plan(instance_dir: &Path, registry: &PluginRegistry, requirements: &[PluginRequirement], dry_run: bool) -> Result<()> {
+    let installed = get_installed_plugins(&instance_dir.join("plugins").join("installed"))?;
+    let actions = registry.plan(requirements, &installed)?;
+    let deploy_path = instance_dir.join("plugins").join("deploy").canonicalize()?;
+    for action in actions {
+        println!("{action}");
+        if dry_run || action.kind == PluginRegistryActionKind::Keep {
+            continue;
+        }
+        let path = registry.install(&action.package, &deploy_path)?;
+        println!("          Copied {} to {}", registry.get_package_path(&action.package).display(), path.display());
+    }
+    Ok(())
+}
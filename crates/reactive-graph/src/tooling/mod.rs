@@ -1,10 +1,12 @@
 use crate::tooling::args::ToolingArguments;
+use crate::tooling::codegen::handle_codegen;
 use crate::tooling::commands::ToolingCommands;
 use crate::tooling::instances::handle_instance;
 use crate::tooling::update::handle_update;
 use anyhow::Result;
 
 pub mod args;
+pub mod codegen;
 pub mod commands;
 pub mod instances;
 pub mod releases;
@@ -14,6 +16,7 @@ pub mod update;
 pub fn tooling(args: ToolingArguments) -> Result<()> {
     if let Some(commands) = args.commands {
         match commands {
+            ToolingCommands::Codegen(args) => handle_codegen(args)?,
             ToolingCommands::Instances(args) => handle_instance(args)?,
             ToolingCommands::Update(args) => handle_update(args)?,
         }
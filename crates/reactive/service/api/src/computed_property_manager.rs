@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use springtime_di::injectable;
+use uuid::Uuid;
+
+use reactive_graph_graph::ComputedProperty;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_lifecycle::Lifecycle;
+
+/// Keeps the computed properties of the reactive entity instances up to date.
+///
+/// A property of an entity type or of one of its components is computed, if the property type
+/// has the extension `core::computed`. The value of the extension defines which relations are
+/// followed and how the values of the related instances are aggregated. Computed properties
+/// are recomputed when relations are created or deleted and when the aggregated properties of
+/// the related instances change. Computed properties are immutable, external writes are
+/// rejected.
+#[injectable]
+#[async_trait]
+pub trait ComputedPropertyManager: Send + Sync + Lifecycle {
+    /// Returns the names and the definitions of the computed properties of the given entity type.
+    fn get_computed_properties(&self, ty: &EntityTypeId) -> Vec<(String, ComputedProperty)>;
+
+    /// Recomputes the computed properties of the entity instance with the given id.
+    fn recompute(&self, id: Uuid);
+
+    /// Recomputes the computed properties of all entity instances.
+    fn recompute_all(&self);
+}
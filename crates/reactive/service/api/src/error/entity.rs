@@ -20,6 +20,8 @@ pub enum ReactiveEntityRegistrationError {
     UuidTaken(Uuid),
     #[error("Cannot register reactive entity because entity type {0} is unknown!")]
     UnknownEntityType(EntityTypeId),
+    #[error("Cannot register reactive entity: {0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -28,6 +30,8 @@ pub enum ReactiveEntityComponentAddError {
     MissingComponent(ComponentTypeId),
     #[error("The reactive entity with id {0} doesn't exist!")]
     MissingInstance(Uuid),
+    #[error("Cannot add component to the reactive entity: {0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -20,6 +20,7 @@ pub enum ReactiveFlowCreationError {
     RelationTypeDoesntExist(RelationTypeId),
     InvalidOutboundId(Uuid),
     InvalidInboundId(Uuid),
+    PermissionDenied(String),
 }
 
 impl fmt::Display for ReactiveFlowCreationError {
@@ -69,6 +70,9 @@ impl fmt::Display for ReactiveFlowCreationError {
                     "Failed to construct reactive flow instance: Flow type provides relation instance which inbound refers to entity instance with id {id} which doesn't exist",
                 )
             }
+            ReactiveFlowCreationError::PermissionDenied(reason) => {
+                write!(f, "Failed to construct reactive flow instance: {reason}")
+            }
         }
     }
 }
@@ -1,3 +1,6 @@
 pub mod entity;
 pub mod flow;
+pub mod query;
 pub mod relation;
+pub mod saved_query;
+pub mod traversal;
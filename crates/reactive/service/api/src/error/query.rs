@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+use reactive_graph_graph::GraphQueryParseError;
+use reactive_graph_graph::TypePattern;
+
+#[derive(Debug, Error)]
+pub enum GraphQueryError {
+    #[error("Failed to parse the query: {0}")]
+    ParseError(#[from] GraphQueryParseError),
+    #[error("Neither an entity type nor a component with the name {0} exists!")]
+    UnknownEntityLabel(TypePattern),
+    #[error("Neither a relation type nor a component with the name {0} exists!")]
+    UnknownRelationLabel(TypePattern),
+    #[error("The name {0} is ambiguous, the namespace must be specified: {1}")]
+    AmbiguousLabel(TypePattern, String),
+    #[error("The filter of the variable {0} is invalid: {1}")]
+    InvalidFilter(String, String),
+}
@@ -33,6 +33,8 @@ pub enum ReactiveRelationCreationError {
 pub enum ReactiveRelationRegistrationError {
     #[error("Couldn't register reactive relation {0} because it already exists!")]
     RelationInstanceAlreadyExists(RelationInstanceId),
+    #[error("Cannot register reactive relation: {0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -43,6 +45,8 @@ pub enum ReactiveRelationComponentAddError {
     ComponentNotRegistered(ComponentTypeId),
     #[error("The reactive relation is already {0}!")]
     IsAlreadyA(ComponentTypeId),
+    #[error("Cannot add component to the reactive relation: {0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -53,6 +57,8 @@ pub enum ReactiveRelationComponentRemoveError {
     ComponentNotRegistered(ComponentTypeId),
     #[error("The reactive relation is not a {0}!")]
     IsNotA(ComponentTypeId),
+    #[error("Cannot remove component from the reactive relation: {0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -61,6 +67,8 @@ pub enum ReactiveRelationPropertyAddError {
     MissingInstance(RelationInstanceId),
     #[error("The reactive relation already has property {0}!")]
     PropertyAlreadyExists(String),
+    #[error("Cannot add property to the reactive relation: {0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -71,4 +79,6 @@ pub enum ReactiveRelationPropertyRemoveError {
     MissingProperty(String),
     #[error("Cannot remove property {0} because it is still in use!")]
     PropertyInUseByComponent(ComponentTypeId),
+    #[error("Cannot remove property from the reactive relation: {0}")]
+    PermissionDenied(String),
 }
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+use reactive_graph_graph::PropertyFilterError;
+
+#[derive(Debug, Error)]
+pub enum SavedQueryRegistrationError {
+    #[error("The name of the saved query must not be empty!")]
+    EmptyName,
+    #[error("The saved query {0} already exists!")]
+    AlreadyExists(String),
+    #[error("The filter of the saved query is invalid: {0}")]
+    InvalidFilter(#[from] PropertyFilterError),
+}
@@ -0,0 +1,10 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum GraphTraversalError {
+    #[error("The entity instance {0} doesn't exist!")]
+    EntityNotFound(Uuid),
+    #[error("The flow instance {0} doesn't exist!")]
+    FlowNotFound(Uuid),
+}
@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use springtime_di::injectable;
+
+use crate::GraphQueryError;
+use reactive_graph_graph::GraphQuery;
+use reactive_graph_graph::GraphQueryPlan;
+use reactive_graph_graph::GraphQueryResult;
+use reactive_graph_lifecycle::Lifecycle;
+
+/// Executes pattern matching queries against the reactive entity and relation instances.
+#[injectable]
+#[async_trait]
+pub trait GraphQueryManager: Send + Sync + Lifecycle {
+    /// Resolves the labels of the query and creates an execution plan.
+    ///
+    /// The plan starts with the node pattern which is expected to match the fewest entity
+    /// instances, using the number of instances per type and the property indexes.
+    fn plan(&self, query: &GraphQuery) -> Result<GraphQueryPlan, GraphQueryError>;
+
+    /// Executes the given plan. The execution stops as soon as the limit of the plan is reached.
+    fn execute_plan(&self, plan: &GraphQueryPlan) -> Result<GraphQueryResult, GraphQueryError>;
+
+    /// Parses, plans and executes the given query text.
+    fn execute(&self, text: &str) -> Result<GraphQueryResult, GraphQueryError> {
+        let query = GraphQuery::parse(text)?;
+        let plan = self.plan(&query)?;
+        self.execute_plan(&plan)
+    }
+}
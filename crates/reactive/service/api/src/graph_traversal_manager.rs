@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use springtime_di::injectable;
+use uuid::Uuid;
+
+use crate::GraphTraversalError;
+use reactive_graph_graph::GraphPath;
+use reactive_graph_graph::TraversalOptions;
+use reactive_graph_graph::TraversalVisit;
+use reactive_graph_lifecycle::Lifecycle;
+
+/// Traverses the reactive entity instances along the reactive relation instances.
+#[injectable]
+#[async_trait]
+pub trait GraphTraversalManager: Send + Sync + Lifecycle {
+    /// Visits the entity instances reachable from the start entity instance, including the start
+    /// entity instance itself.
+    fn traverse(&self, start: Uuid, options: &TraversalOptions) -> Result<Vec<TraversalVisit>, GraphTraversalError>;
+
+    /// Returns a path with the fewest relations from the start entity instance to the end entity instance.
+    fn shortest_path(&self, start: Uuid, end: Uuid, options: &TraversalOptions) -> Result<Option<GraphPath>, GraphTraversalError>;
+
+    /// Returns all paths from the start entity instance to the end entity instance which doesn't
+    /// visit an entity instance twice.
+    fn all_paths(&self, start: Uuid, end: Uuid, options: &TraversalOptions) -> Result<Vec<GraphPath>, GraphTraversalError>;
+
+    /// Groups all entity instances into connected components, ignoring the direction of the relations.
+    fn connected_components(&self, options: &TraversalOptions) -> Vec<Vec<Uuid>>;
+
+    /// Returns the cycles through the given entity instance.
+    fn cycles(&self, id: Uuid, options: &TraversalOptions) -> Result<Vec<GraphPath>, GraphTraversalError>;
+
+    /// Returns the cycles through the entity instances of the given flow instance.
+    fn flow_cycles(&self, flow_id: Uuid, options: &TraversalOptions) -> Result<Vec<GraphPath>, GraphTraversalError>;
+}
@@ -1,12 +1,19 @@
 #![cfg_attr(unboxed_closures, feature(unboxed_closures))]
 #![cfg_attr(fn_traits, feature(fn_traits))]
 
+pub use computed_property_manager::*;
 pub use error::entity::*;
 pub use error::flow::*;
+pub use error::query::*;
 pub use error::relation::*;
+pub use error::saved_query::*;
+pub use error::traversal::*;
 pub use event_channels::*;
 pub use flow_instance_provider::*;
+pub use graph_query_manager::*;
+pub use graph_traversal_manager::*;
 pub use property::*;
+pub use property_index_statistics::*;
 pub use reactive_entity_manager::*;
 pub use reactive_flow_manager::*;
 #[cfg(feature = "derive")]
@@ -18,10 +25,16 @@ pub use reactive_instance_event_subscriber::*;
 pub use reactive_instance_events::*;
 pub use reactive_relation_manager::*;
 pub use reactive_system::*;
+pub use saved_query_manager::*;
+pub use search_manager::*;
 
+pub mod computed_property_manager;
 pub mod error;
 pub mod flow_instance_provider;
+pub mod graph_query_manager;
+pub mod graph_traversal_manager;
 pub mod property;
+pub mod property_index_statistics;
 pub mod reactive_entity_manager;
 pub mod reactive_flow_manager;
 pub mod reactive_instance_event_manager;
@@ -31,14 +44,25 @@ pub mod reactive_instance_event_subscriber;
 pub mod reactive_instance_events;
 pub mod reactive_relation_manager;
 pub mod reactive_system;
+pub mod saved_query_manager;
+pub mod search_manager;
 
 pub mod prelude {
+    pub use crate::computed_property_manager::*;
     pub use crate::error::entity::*;
     pub use crate::error::flow::*;
+    pub use crate::error::query::*;
     pub use crate::error::relation::*;
+    pub use crate::error::saved_query::*;
+    pub use crate::error::traversal::*;
     pub use crate::flow_instance_provider::*;
+    pub use crate::graph_query_manager::*;
+    pub use crate::graph_traversal_manager::*;
     pub use crate::property::*;
+    pub use crate::property_index_statistics::*;
     pub use crate::reactive_entity_manager::*;
     pub use crate::reactive_flow_manager::*;
     pub use crate::reactive_relation_manager::*;
+    pub use crate::saved_query_manager::*;
+    pub use crate::search_manager::*;
 }
@@ -0,0 +1,14 @@
+use reactive_graph_graph::TypeDefinitionProperty;
+
+/// Statistics of a secondary index of a property.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PropertyIndexStatistics {
+    /// The type and the name of the indexed property.
+    pub ty: TypeDefinitionProperty,
+    /// The number of indexed instances.
+    pub entries: usize,
+    /// The number of distinct values.
+    pub distinct_values: usize,
+    /// The number of lookups.
+    pub lookups: usize,
+}
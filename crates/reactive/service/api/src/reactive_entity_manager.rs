@@ -5,6 +5,7 @@ use serde_json::Value;
 use springtime_di::injectable;
 use uuid::Uuid;
 
+use crate::PropertyIndexStatistics;
 use crate::ReactiveEntityComponentAddError;
 use crate::ReactiveEntityCreationError;
 use crate::ReactiveEntityPropertyAddError;
@@ -13,10 +14,14 @@ use crate::ReactiveEntityRegistrationError;
 use reactive_graph_behaviour_model_api::BehaviourTypeId;
 use reactive_graph_behaviour_model_api::ComponentBehaviourTypeId;
 use reactive_graph_behaviour_model_api::EntityBehaviourTypeId;
+use reactive_graph_graph::AggregationGroup;
+use reactive_graph_graph::AggregationGroupBy;
 use reactive_graph_graph::ComponentTypeId;
 use reactive_graph_graph::EntityInstance;
 use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::LabelPattern;
 use reactive_graph_graph::Mutability;
+use reactive_graph_graph::PropertyFilterExpression;
 use reactive_graph_graph::PropertyInstances;
 use reactive_graph_lifecycle::Lifecycle;
 use reactive_graph_reactive_model_impl::ReactiveEntity;
@@ -38,12 +43,22 @@ pub trait ReactiveEntityManager: Send + Sync + Lifecycle {
     /// /io/reactive-graph/local/users/PeterPenacka returns: (instance, {"user_id": "PeterPenacka"})
     fn get_by_label_with_params(&self, label: &str) -> Option<(ReactiveEntity, HashMap<String, String>)>;
 
+    /// Returns all reactive entity instances whose label matches the given label pattern
+    /// together with the extracted parameters, ordered by label.
+    /// /org/inexor/sensors/*/temperature
+    /// /org/inexor/sensors/:room/**
+    fn get_by_label_pattern(&self, pattern: &LabelPattern) -> Vec<(ReactiveEntity, HashMap<String, String>)>;
+
     /// Returns all registered reactive entity instances.
     fn get_all(&self) -> Vec<ReactiveEntity>;
 
     /// Returns all reactive entity instances of the given type.
     fn get_by_type(&self, ty: &EntityTypeId) -> Vec<ReactiveEntity>;
 
+    /// Returns the reactive entity instances of the given type which are matching the given
+    /// property filter. Uses the property indexes of the type, if available.
+    fn get_by_type_and_filter(&self, ty: &EntityTypeId, filter: &PropertyFilterExpression) -> Vec<ReactiveEntity>;
+
     /// Returns all reactive entity instances of the given type which are of the given component..
     fn get_by_component(&self, component_ty: &ComponentTypeId) -> Vec<ReactiveEntity>;
 
@@ -56,6 +71,9 @@ pub trait ReactiveEntityManager: Send + Sync + Lifecycle {
     /// Returns the ids of all registered reactive entity instances.
     fn get_ids(&self) -> Vec<Uuid>;
 
+    /// Returns the statistics of the property indexes of the reactive entity instances.
+    fn get_property_index_statistics(&self) -> Vec<PropertyIndexStatistics>;
+
     /// Returns the count of registered reactive entity instances.
     fn count(&self) -> usize;
 
@@ -68,6 +86,23 @@ pub trait ReactiveEntityManager: Send + Sync + Lifecycle {
     /// Returns the count of registered reactive entity instances which behaves as the given behaviour.
     fn count_by_behaviour(&self, behaviour_ty: &BehaviourTypeId) -> usize;
 
+    /// Returns the count of registered reactive entity instances of the given namespace.
+    fn count_by_namespace(&self, namespace: &str) -> usize;
+
+    /// Groups the reactive entity instances and aggregates the numeric values of the given property per group.
+    ///
+    /// Only the instances of the given type, with the given component, of the given namespace and
+    /// matching the given filter are aggregated.
+    fn aggregate(
+        &self,
+        ty: Option<&EntityTypeId>,
+        component_ty: Option<&ComponentTypeId>,
+        namespace: Option<&str>,
+        filter: Option<&PropertyFilterExpression>,
+        group_by: Option<&AggregationGroupBy>,
+        property: Option<&str>,
+    ) -> Vec<AggregationGroup>;
+
     /// Creates a new reactive entity instance of the given type. The reactive instance will be
     /// initialized with the given properties and values. A random id will be generated.
     fn create_reactive_entity(&self, ty: &EntityTypeId, properties: PropertyInstances) -> Result<ReactiveEntity, ReactiveEntityCreationError>;
@@ -4,14 +4,18 @@ use springtime_di::injectable;
 use uuid::Uuid;
 
 use crate::ReactiveRelationComponentRemoveError;
+use reactive_graph_graph::AggregationGroup;
+use reactive_graph_graph::AggregationGroupBy;
 use reactive_graph_graph::ComponentTypeId;
 use reactive_graph_graph::Mutability;
+use reactive_graph_graph::PropertyFilterExpression;
 use reactive_graph_graph::PropertyInstances;
 use reactive_graph_graph::RelationInstance;
 use reactive_graph_graph::RelationInstanceId;
 use reactive_graph_graph::RelationTypeId;
 use reactive_graph_reactive_model_impl::ReactiveRelation;
 
+use crate::PropertyIndexStatistics;
 use crate::ReactiveRelationComponentAddError;
 use crate::ReactiveRelationCreationError;
 use crate::ReactiveRelationPropertyAddError;
@@ -45,6 +49,10 @@ pub trait ReactiveRelationManager: Send + Sync + Lifecycle {
     /// Returns all reactive relation instances of the given type.
     fn get_by_type(&self, ty: &RelationTypeId) -> Vec<ReactiveRelation>;
 
+    /// Returns the reactive relation instances of the given type which are matching the given
+    /// property filter. Uses the property indexes of the type, if available.
+    fn get_by_type_and_filter(&self, ty: &RelationTypeId, filter: &PropertyFilterExpression) -> Vec<ReactiveRelation>;
+
     /// Returns all reactive relation instances of the given type which are of the given component..
     fn get_by_component(&self, component_ty: &ComponentTypeId) -> Vec<ReactiveRelation>;
 
@@ -57,6 +65,9 @@ pub trait ReactiveRelationManager: Send + Sync + Lifecycle {
     /// Returns all relation instance ids.
     fn get_relation_instance_ids(&self) -> Vec<RelationInstanceId>;
 
+    /// Returns the statistics of the property indexes of the reactive relation instances.
+    fn get_property_index_statistics(&self) -> Vec<PropertyIndexStatistics>;
+
     /// Returns the count of registered reactive relation instances.
     fn count(&self) -> usize;
 
@@ -69,6 +80,23 @@ pub trait ReactiveRelationManager: Send + Sync + Lifecycle {
     /// Returns the count of registered reactive relation instances which behaves as the given behaviour.
     fn count_by_behaviour(&self, behaviour_ty: &BehaviourTypeId) -> usize;
 
+    /// Returns the count of registered reactive relation instances of the given namespace.
+    fn count_by_namespace(&self, namespace: &str) -> usize;
+
+    /// Groups the reactive relation instances and aggregates the numeric values of the given property per group.
+    ///
+    /// Only the instances of the given type, with the given component, of the given namespace and
+    /// matching the given filter are aggregated.
+    fn aggregate(
+        &self,
+        ty: Option<&RelationTypeId>,
+        component_ty: Option<&ComponentTypeId>,
+        namespace: Option<&str>,
+        filter: Option<&PropertyFilterExpression>,
+        group_by: Option<&AggregationGroupBy>,
+        property: Option<&str>,
+    ) -> Vec<AggregationGroup>;
+
     /// Creates a new reactive relation instance.
     fn create_reactive_relation(&self, id: &RelationInstanceId, properties: PropertyInstances) -> Result<ReactiveRelation, ReactiveRelationCreationError>;
 
@@ -7,10 +7,15 @@ use reactive_graph_behaviour_service_api::BehaviourSystem;
 use reactive_graph_lifecycle::Lifecycle;
 use reactive_graph_type_system_api::TypeSystem;
 
+use crate::ComputedPropertyManager;
+use crate::GraphQueryManager;
+use crate::GraphTraversalManager;
 use crate::ReactiveEntityManager;
 use crate::ReactiveFlowManager;
 use crate::ReactiveInstanceEventManager;
 use crate::ReactiveRelationManager;
+use crate::SavedQueryManager;
+use crate::SearchManager;
 
 #[injectable]
 #[async_trait]
@@ -23,6 +28,16 @@ pub trait ReactiveSystem: Lifecycle {
 
     fn get_reactive_instance_event_manager(&self) -> Arc<dyn ReactiveInstanceEventManager + Send + Sync>;
 
+    fn get_graph_query_manager(&self) -> Arc<dyn GraphQueryManager + Send + Sync>;
+
+    fn get_graph_traversal_manager(&self) -> Arc<dyn GraphTraversalManager + Send + Sync>;
+
+    fn get_search_manager(&self) -> Arc<dyn SearchManager + Send + Sync>;
+
+    fn get_computed_property_manager(&self) -> Arc<dyn ComputedPropertyManager + Send + Sync>;
+
+    fn get_saved_query_manager(&self) -> Arc<dyn SavedQueryManager + Send + Sync>;
+
     fn type_system(&self) -> Arc<dyn TypeSystem + Send + Sync>;
 
     fn behaviour_system(&self) -> Arc<dyn BehaviourSystem + Send + Sync>;
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use crossbeam::channel::Receiver;
+use springtime_di::injectable;
+
+use reactive_graph_graph::SavedQuery;
+use reactive_graph_graph::SavedQueryChange;
+use reactive_graph_graph::SavedQueryRow;
+use reactive_graph_lifecycle::Lifecycle;
+
+use crate::SavedQueryRegistrationError;
+
+/// Manages named queries over the entity instances whose result sets are materialized.
+///
+/// The result sets are updated incrementally when entity instances are created or deleted and
+/// when their properties are changed. Subscribers receive the changes of the result set instead
+/// of the full result.
+#[injectable]
+#[async_trait]
+pub trait SavedQueryManager: Send + Sync + Lifecycle {
+    /// Registers the saved query and materializes its result set.
+    fn register(&self, saved_query: SavedQuery) -> Result<SavedQuery, SavedQueryRegistrationError>;
+
+    /// Unregisters the saved query with the given name. Returns false, if the saved query
+    /// doesn't exist.
+    fn unregister(&self, name: &str) -> bool;
+
+    /// Returns true, if a saved query with the given name exists.
+    fn has(&self, name: &str) -> bool;
+
+    /// Returns the saved query with the given name.
+    fn get(&self, name: &str) -> Option<SavedQuery>;
+
+    /// Returns all saved queries ordered by name.
+    fn get_all(&self) -> Vec<SavedQuery>;
+
+    /// Returns the ordered rows of the materialized result set of the saved query.
+    fn get_rows(&self, name: &str) -> Option<Vec<SavedQueryRow>>;
+
+    /// Returns the number of entity instances in the result set of the saved query.
+    fn count(&self, name: &str) -> Option<usize>;
+
+    /// Subscribes to the changes of the result set of the saved query. Returns none, if the
+    /// saved query doesn't exist.
+    fn subscribe(&self, name: &str, handle_id: u128) -> Option<Receiver<SavedQueryChange>>;
+
+    /// Stops sending the changes of the result set to the subscriber.
+    fn unsubscribe(&self, name: &str, handle_id: u128);
+}
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use springtime_di::injectable;
+
+use reactive_graph_graph::SearchHit;
+use reactive_graph_graph::SearchOptions;
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+use reactive_graph_reactive_model_impl::ReactiveRelation;
+
+/// An instance found by the full-text search.
+#[derive(Clone)]
+pub enum SearchDocument {
+    Entity(ReactiveEntity),
+    Relation(ReactiveRelation),
+}
+
+/// Full-text search over the names, descriptions, labels and searchable string properties of
+/// the reactive entity instances and reactive relation instances.
+///
+/// String properties are searchable if the property type has the extension `core::search` or
+/// if the type or one of its components has the extension `core::search` with a list of
+/// property names.
+#[injectable]
+#[async_trait]
+pub trait SearchManager: Send + Sync + Lifecycle {
+    /// Returns the ranked instances matching every token of the given text.
+    fn search(&self, text: &str, options: &SearchOptions) -> Vec<SearchHit<SearchDocument>>;
+
+    /// Returns the number of indexed instances.
+    fn count(&self) -> usize;
+
+    /// Rebuilds the search index from all reactive entity instances and reactive relation instances.
+    fn reindex(&self);
+}
@@ -0,0 +1,580 @@
+use std::collections::HashMap;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use log::warn;
+use serde_json::Value;
+use springtime_di::Component;
+use springtime_di::component_alias;
+use uuid::Uuid;
+
+use reactive_graph_graph::ComputedProperty;
+use reactive_graph_graph::ComputedPropertySource;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::ExtensionContainer;
+use reactive_graph_graph::Mutability::Immutable;
+use reactive_graph_graph::PropertyInstanceGetter;
+use reactive_graph_graph::PropertyInstanceSetter;
+use reactive_graph_graph::RelationInstanceId;
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_reactive_model_api::ReactiveInstance;
+use reactive_graph_reactive_model_api::ReactivePropertyContainer;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+use reactive_graph_reactive_model_impl::ReactiveRelation;
+use reactive_graph_reactive_service_api::ComputedPropertyManager;
+use reactive_graph_reactive_service_api::ReactiveEntityManager;
+use reactive_graph_reactive_service_api::ReactiveInstanceEventManager;
+use reactive_graph_reactive_service_api::ReactiveInstanceEventTypes;
+use reactive_graph_reactive_service_api::ReactiveRelationManager;
+use reactive_graph_runtime_model::EXTENSION_COMPUTED;
+use reactive_graph_runtime_model::EventProperties::EVENT;
+use reactive_graph_type_system_api::ComponentManager;
+use reactive_graph_type_system_api::EntityTypeManager;
+
+/// The handle id of the observers which are keeping the computed properties up to date.
+pub const HANDLE_ID_COMPUTED_PROPERTIES: u128 = 0x7c3e9a1f4b2d4e8a8b6c5d4e3f2a1b0c;
+
+/// An instance whose property is aggregated by a computed property.
+#[derive(Clone)]
+enum ComputedPropertyUpstream {
+    Entity(ReactiveEntity),
+    Relation(ReactiveRelation),
+}
+
+impl ComputedPropertyUpstream {
+    fn get(&self, property_name: &str) -> Option<Value> {
+        match self {
+            ComputedPropertyUpstream::Entity(reactive_entity) => reactive_entity.get(property_name),
+            ComputedPropertyUpstream::Relation(reactive_relation) => reactive_relation.get(property_name),
+        }
+    }
+
+    fn observe_with_handle<F>(&self, property_name: &str, subscriber: F, handle_id: u128)
+    where
+        F: FnMut(&Value) + 'static + Send,
+    {
+        match self {
+            ComputedPropertyUpstream::Entity(reactive_entity) => reactive_entity.observe_with_handle(property_name, subscriber, handle_id),
+            ComputedPropertyUpstream::Relation(reactive_relation) => reactive_relation.observe_with_handle(property_name, subscriber, handle_id),
+        }
+    }
+
+    fn remove_observer(&self, property_name: &str, handle_id: u128) {
+        match self {
+            ComputedPropertyUpstream::Entity(reactive_entity) => reactive_entity.remove_observer(property_name, handle_id),
+            ComputedPropertyUpstream::Relation(reactive_relation) => reactive_relation.remove_observer(property_name, handle_id),
+        }
+    }
+
+    fn is_same(&self, other: &ComputedPropertyUpstream) -> bool {
+        match (self, other) {
+            (ComputedPropertyUpstream::Entity(a), ComputedPropertyUpstream::Entity(b)) => a.id == b.id,
+            (ComputedPropertyUpstream::Relation(a), ComputedPropertyUpstream::Relation(b)) => a.id() == b.id(),
+            _ => false,
+        }
+    }
+}
+
+/// An observer of a property of an upstream instance.
+struct ComputedPropertyObserver {
+    upstream: ComputedPropertyUpstream,
+    property_name: String,
+    handle_id: u128,
+}
+
+fn create_computed_property_observers() -> Arc<RwLock<HashMap<Uuid, Vec<ComputedPropertyObserver>>>> {
+    Arc::new(RwLock::new(HashMap::new()))
+}
+
+#[derive(Component)]
+pub struct ComputedPropertyManagerImpl {
+    reactive_entity_manager: Arc<dyn ReactiveEntityManager + Send + Sync>,
+
+    reactive_relation_manager: Arc<dyn ReactiveRelationManager + Send + Sync>,
+
+    reactive_instance_event_manager: Arc<dyn ReactiveInstanceEventManager + Send + Sync>,
+
+    component_manager: Arc<dyn ComponentManager + Send + Sync>,
+
+    entity_type_manager: Arc<dyn EntityTypeManager + Send + Sync>,
+
+    /// The observers of the upstream properties by the id of the entity instance with the
+    /// computed properties.
+    #[component(default = "create_computed_property_observers")]
+    observers: Arc<RwLock<HashMap<Uuid, Vec<ComputedPropertyObserver>>>>,
+}
+
+impl ComputedPropertyManagerImpl {
+    fn evaluator(&self) -> ComputedPropertyEvaluator {
+        ComputedPropertyEvaluator {
+            reactive_entity_manager: self.reactive_entity_manager.clone(),
+            reactive_relation_manager: self.reactive_relation_manager.clone(),
+            component_manager: self.component_manager.clone(),
+            entity_type_manager: self.entity_type_manager.clone(),
+            observers: self.observers.clone(),
+        }
+    }
+
+    /// Recomputes the computed properties when entity instances or relation instances are
+    /// created or deleted.
+    fn subscribe_reactive_instance_events(&self) {
+        let event_types = [
+            ReactiveInstanceEventTypes::EntityInstanceCreated,
+            ReactiveInstanceEventTypes::EntityInstanceDeleted,
+            ReactiveInstanceEventTypes::RelationInstanceCreated,
+            ReactiveInstanceEventTypes::RelationInstanceDeleted,
+        ];
+        for event_type in event_types {
+            let Some(event_instance) = self.reactive_instance_event_manager.get_reactive_instance_event_instance(event_type.clone()) else {
+                continue;
+            };
+            let evaluator = self.evaluator();
+            event_instance.observe_with_handle(
+                &EVENT.property_name(),
+                move |value: &Value| match event_type {
+                    ReactiveInstanceEventTypes::EntityInstanceCreated => {
+                        if let Ok(id) = serde_json::from_value(value.clone()) {
+                            evaluator.update_by_id(id);
+                        }
+                    }
+                    ReactiveInstanceEventTypes::EntityInstanceDeleted => {
+                        if let Ok(id) = serde_json::from_value(value.clone()) {
+                            evaluator.unobserve(id);
+                        }
+                    }
+                    ReactiveInstanceEventTypes::RelationInstanceCreated | ReactiveInstanceEventTypes::RelationInstanceDeleted => {
+                        if let Ok(id) = serde_json::from_value::<RelationInstanceId>(value.clone()) {
+                            evaluator.update_by_id(id.outbound_id);
+                            evaluator.update_by_id(id.inbound_id);
+                        }
+                    }
+                    _ => {}
+                },
+                HANDLE_ID_COMPUTED_PROPERTIES,
+            );
+        }
+    }
+
+    fn unsubscribe_reactive_instance_events(&self) {
+        for event_instance in self.reactive_instance_event_manager.get_reactive_instance_event_instances() {
+            event_instance.remove_observer(&EVENT.property_name(), HANDLE_ID_COMPUTED_PROPERTIES);
+        }
+    }
+}
+
+#[async_trait]
+#[component_alias]
+impl ComputedPropertyManager for ComputedPropertyManagerImpl {
+    fn get_computed_properties(&self, ty: &EntityTypeId) -> Vec<(String, ComputedProperty)> {
+        self.evaluator().get_computed_properties(ty)
+    }
+
+    fn recompute(&self, id: Uuid) {
+        self.evaluator().update_by_id(id);
+    }
+
+    fn recompute_all(&self) {
+        let evaluator = self.evaluator();
+        for reactive_entity in self.reactive_entity_manager.get_all() {
+            evaluator.update(&reactive_entity);
+        }
+    }
+}
+
+#[async_trait]
+impl Lifecycle for ComputedPropertyManagerImpl {
+    async fn post_init(&self) {
+        self.recompute_all();
+        self.subscribe_reactive_instance_events();
+    }
+
+    async fn pre_shutdown(&self) {
+        self.unsubscribe_reactive_instance_events();
+        let ids: Vec<Uuid> = self.observers.read().unwrap().keys().cloned().collect();
+        let evaluator = self.evaluator();
+        for id in ids {
+            evaluator.unobserve(id);
+        }
+    }
+}
+
+/// Computes the computed properties and observes the aggregated properties of the related instances.
+#[derive(Clone)]
+struct ComputedPropertyEvaluator {
+    reactive_entity_manager: Arc<dyn ReactiveEntityManager + Send + Sync>,
+
+    reactive_relation_manager: Arc<dyn ReactiveRelationManager + Send + Sync>,
+
+    component_manager: Arc<dyn ComponentManager + Send + Sync>,
+
+    entity_type_manager: Arc<dyn EntityTypeManager + Send + Sync>,
+
+    observers: Arc<RwLock<HashMap<Uuid, Vec<ComputedPropertyObserver>>>>,
+}
+
+impl ComputedPropertyEvaluator {
+    fn update_by_id(&self, id: Uuid) {
+        if let Some(reactive_entity) = self.reactive_entity_manager.get(id) {
+            self.update(&reactive_entity);
+        }
+    }
+
+    /// Computes the computed properties of the entity instance and replaces the observers of
+    /// the aggregated properties of the related instances.
+    fn update(&self, reactive_entity: &ReactiveEntity) {
+        self.unobserve(reactive_entity.id);
+        let computed_properties = self.get_computed_properties(&reactive_entity.ty);
+        if computed_properties.is_empty() {
+            return;
+        }
+        let mut observers = Vec::new();
+        for (property_name, computed_property) in computed_properties {
+            // Computed properties are only written by the computed property manager
+            if reactive_entity.mutability(&property_name) != Some(Immutable) {
+                reactive_entity.set_mutability(&property_name, Immutable);
+            }
+            let upstreams = self.get_upstreams(reactive_entity, &computed_property);
+            set_if_changed(reactive_entity, &property_name, compute(&computed_property, &upstreams, None));
+            let Some(upstream_property_name) = computed_property.observed_property().cloned() else {
+                continue;
+            };
+            let handle_id = computed_property_handle_id(reactive_entity.id, &property_name);
+            for upstream in upstreams {
+                let evaluator = self.clone();
+                let reactive_entity = reactive_entity.clone();
+                let property_name = property_name.clone();
+                let computed_property = computed_property.clone();
+                let changed_upstream = upstream.clone();
+                upstream.observe_with_handle(
+                    &upstream_property_name,
+                    move |value: &Value| evaluator.recompute_property(&reactive_entity, &property_name, &computed_property, &changed_upstream, value),
+                    handle_id,
+                );
+                observers.push(ComputedPropertyObserver {
+                    upstream,
+                    property_name: upstream_property_name.clone(),
+                    handle_id,
+                });
+            }
+        }
+        self.observers.write().unwrap().insert(reactive_entity.id, observers);
+    }
+
+    /// Recomputes a computed property after the property of an upstream instance has been changed.
+    ///
+    /// The property of the changed upstream instance is locked while its observers are
+    /// notified, so the new value is passed in instead of being read.
+    fn recompute_property(
+        &self,
+        reactive_entity: &ReactiveEntity,
+        property_name: &str,
+        computed_property: &ComputedProperty,
+        changed_upstream: &ComputedPropertyUpstream,
+        value: &Value,
+    ) {
+        let upstreams = self.get_upstreams(reactive_entity, computed_property);
+        set_if_changed(reactive_entity, property_name, compute(computed_property, &upstreams, Some((changed_upstream, value))));
+    }
+
+    /// Removes the observers of the computed properties of the entity instance with the given id.
+    fn unobserve(&self, id: Uuid) {
+        let Some(observers) = self.observers.write().unwrap().remove(&id) else {
+            return;
+        };
+        for observer in observers {
+            observer.upstream.remove_observer(&observer.property_name, observer.handle_id);
+        }
+    }
+
+    /// Returns the instances whose properties are aggregated by the computed property.
+    fn get_upstreams(&self, reactive_entity: &ReactiveEntity, computed_property: &ComputedProperty) -> Vec<ComputedPropertyUpstream> {
+        let mut relations: Vec<(ReactiveRelation, ReactiveEntity)> = Vec::new();
+        if computed_property.direction.is_outbound() {
+            for reactive_relation in self.reactive_relation_manager.get_by_outbound_entity(reactive_entity.id) {
+                let other = reactive_relation.inbound.clone();
+                relations.push((reactive_relation, other));
+            }
+        }
+        if computed_property.direction.is_inbound() {
+            for reactive_relation in self.reactive_relation_manager.get_by_inbound_entity(reactive_entity.id) {
+                let other = reactive_relation.outbound.clone();
+                relations.push((reactive_relation, other));
+            }
+        }
+        relations
+            .into_iter()
+            .filter(|(reactive_relation, _)| computed_property.follows(&reactive_relation.relation_type_id()))
+            .map(|(reactive_relation, other)| match computed_property.source {
+                ComputedPropertySource::Entity => ComputedPropertyUpstream::Entity(other),
+                ComputedPropertySource::Relation => ComputedPropertyUpstream::Relation(reactive_relation),
+            })
+            .collect()
+    }
+
+    /// Returns the computed properties of the entity type and of its components.
+    fn get_computed_properties(&self, ty: &EntityTypeId) -> Vec<(String, ComputedProperty)> {
+        let Some(entity_type) = self.entity_type_manager.get(ty) else {
+            return Vec::new();
+        };
+        let components: Vec<reactive_graph_graph::Component> = entity_type
+            .components
+            .iter()
+            .filter_map(|component_ty| self.component_manager.get(component_ty.key()))
+            .collect();
+        let mut computed_properties = Vec::new();
+        for property_type in entity_type
+            .properties
+            .iter()
+            .chain(components.iter().flat_map(|component| component.properties.iter()))
+        {
+            let Some(extension) = property_type.get_own_extension(&EXTENSION_COMPUTED) else {
+                continue;
+            };
+            match ComputedProperty::try_from(&extension.extension) {
+                Ok(computed_property) => computed_properties.push((property_type.name.clone(), computed_property)),
+                Err(e) => warn!("Invalid computed property {} of entity type {}: {}", property_type.name, ty, e),
+            }
+        }
+        computed_properties.sort_by(|a, b| a.0.cmp(&b.0));
+        computed_properties.dedup_by(|a, b| a.0 == b.0);
+        computed_properties
+    }
+}
+
+/// Computes the value of the computed property from the upstream instances. If an upstream
+/// instance has been changed, its new value is used.
+fn compute(computed_property: &ComputedProperty, upstreams: &[ComputedPropertyUpstream], changed: Option<(&ComputedPropertyUpstream, &Value)>) -> Value {
+    let values: Vec<Value> = upstreams
+        .iter()
+        .map(|upstream| {
+            let Some(property_name) = computed_property.observed_property() else {
+                return Value::Null;
+            };
+            match changed {
+                Some((changed_upstream, value)) if changed_upstream.is_same(upstream) => value.clone(),
+                _ => upstream.get(property_name).unwrap_or(Value::Null),
+            }
+        })
+        .collect();
+    computed_property.compute(&values)
+}
+
+/// Sets the computed property, if the value has been changed. This prevents endless
+/// propagation between computed properties which depend on each other.
+fn set_if_changed(reactive_entity: &ReactiveEntity, property_name: &str, value: Value) {
+    if reactive_entity.get(property_name).as_ref() != Some(&value) {
+        reactive_entity.set(property_name, value);
+    }
+}
+
+/// Returns the handle id of the observers of a computed property of an entity instance.
+fn computed_property_handle_id(id: Uuid, property_name: &str) -> u128 {
+    let mut hasher = DefaultHasher::new();
+    property_name.hash(&mut hasher);
+    id.as_u128() ^ HANDLE_ID_COMPUTED_PROPERTIES ^ hasher.finish() as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    // Do not remove! This import is necessary to make the dependency injection work
+    #[allow(unused_imports)]
+    use reactive_graph_behaviour_service_impl::BehaviourSystemImpl;
+    use reactive_graph_graph::DataType;
+    use reactive_graph_graph::EntityType;
+    use reactive_graph_graph::EntityTypeId;
+    use reactive_graph_graph::Extension;
+    use reactive_graph_graph::Extensions;
+    use reactive_graph_graph::Mutability::Immutable;
+    use reactive_graph_graph::PropertyInstanceGetter;
+    use reactive_graph_graph::PropertyInstanceSetter;
+    use reactive_graph_graph::PropertyInstances;
+    use reactive_graph_graph::PropertyType;
+    use reactive_graph_graph::PropertyTypes;
+    use reactive_graph_graph::RelationInstance;
+    use reactive_graph_graph::RelationInstanceId;
+    use reactive_graph_graph::RelationInstanceTypeId;
+    use reactive_graph_graph::RelationType;
+    use reactive_graph_graph::RelationTypeId;
+    use reactive_graph_lifecycle::Lifecycle;
+    use reactive_graph_reactive_model_api::ReactiveInstance;
+    use reactive_graph_reactive_model_impl::ReactiveEntity;
+    use reactive_graph_reactive_service_api::ReactiveSystem;
+    use reactive_graph_runtime_model::EXTENSION_COMPUTED;
+    // Do not remove! This import is necessary to make the dependency injection work
+    #[allow(unused_imports)]
+    use reactive_graph_type_system_impl::TypeSystemImpl;
+    use reactive_graph_utils_test::r_string;
+
+    use crate::ReactiveSystemImpl;
+
+    /// A hub which counts the connected devices and sums up their power.
+    struct ComputedPropertyTestTypes {
+        hub_ty: EntityTypeId,
+        device_ty: EntityTypeId,
+        connects_ty: RelationTypeId,
+    }
+
+    fn computed_property_type(name: &str, computed_property: Value) -> PropertyType {
+        PropertyType::builder()
+            .name(name)
+            .data_type(DataType::Number)
+            .extensions(Extensions::new().extension(Extension::new(EXTENSION_COMPUTED.clone(), "", computed_property)))
+            .build()
+    }
+
+    fn register_types(reactive_system: &ReactiveSystemImpl) -> ComputedPropertyTestTypes {
+        let type_system = reactive_system.type_system();
+        let namespace = r_string();
+        let hub_ty = EntityTypeId::new_from_type(&namespace, "hub");
+        let device_ty = EntityTypeId::new_from_type(&namespace, "device");
+        let connects_ty = RelationTypeId::new_from_type(&namespace, "connects");
+        let hub_type = EntityType::builder()
+            .ty(hub_ty.clone())
+            .properties(
+                PropertyTypes::new()
+                    .property(computed_property_type(
+                        "devices",
+                        json!({ "direction": "outbound", "relation_type": connects_ty, "function": "count" }),
+                    ))
+                    .property(computed_property_type(
+                        "power",
+                        json!({ "direction": "outbound", "relation_type": connects_ty, "function": "sum", "property": "power" }),
+                    )),
+            )
+            .build();
+        let device_type = EntityType::builder()
+            .ty(device_ty.clone())
+            .properties(PropertyTypes::new().property(PropertyType::new("power", DataType::Number)))
+            .build();
+        let connects_type = RelationType::builder_with_ty(hub_ty.clone(), connects_ty.clone(), device_ty.clone()).build();
+        type_system.get_entity_type_manager().register(hub_type).expect("Failed to register the hub");
+        type_system
+            .get_entity_type_manager()
+            .register(device_type)
+            .expect("Failed to register the device");
+        type_system
+            .get_relation_type_manager()
+            .register(connects_type)
+            .expect("Failed to register the relation type");
+        ComputedPropertyTestTypes {
+            hub_ty,
+            device_ty,
+            connects_ty,
+        }
+    }
+
+    fn create_hub(reactive_system: &ReactiveSystemImpl, types: &ComputedPropertyTestTypes) -> ReactiveEntity {
+        reactive_system
+            .get_reactive_entity_manager()
+            .create_with_id(&types.hub_ty, Uuid::new_v4(), PropertyInstances::new().property("devices", 0).property("power", 0))
+            .expect("Failed to create the hub")
+    }
+
+    fn create_device(reactive_system: &ReactiveSystemImpl, types: &ComputedPropertyTestTypes, power: i64) -> ReactiveEntity {
+        reactive_system
+            .get_reactive_entity_manager()
+            .create_with_id(&types.device_ty, Uuid::new_v4(), PropertyInstances::new().property("power", power))
+            .expect("Failed to create the device")
+    }
+
+    fn connect(reactive_system: &ReactiveSystemImpl, types: &ComputedPropertyTestTypes, hub: &ReactiveEntity, device: &ReactiveEntity) -> RelationInstanceId {
+        let relation_instance = RelationInstance::new_without_properties(hub.id, RelationInstanceTypeId::new_unique_id(types.connects_ty.clone()), device.id);
+        reactive_system
+            .get_reactive_relation_manager()
+            .create_reactive_instance(relation_instance)
+            .expect("Failed to connect the device")
+            .id()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_recompute_on_relation_created_and_deleted() {
+        reactive_graph_utils_test::init_logger();
+        let reactive_system = reactive_graph_di::get_container::<ReactiveSystemImpl>();
+        reactive_system.post_init().await;
+        let types = register_types(&reactive_system);
+
+        let hub = create_hub(&reactive_system, &types);
+        let device_1 = create_device(&reactive_system, &types, 2);
+        let device_2 = create_device(&reactive_system, &types, 3);
+        assert_eq!(Some(json!(0)), hub.get("devices"));
+
+        let relation_1 = connect(&reactive_system, &types, &hub, &device_1);
+        assert_eq!(Some(json!(1)), hub.get("devices"));
+        assert_eq!(Some(2.0), hub.as_f64("power"));
+
+        let relation_2 = connect(&reactive_system, &types, &hub, &device_2);
+        assert_eq!(Some(json!(2)), hub.get("devices"));
+        assert_eq!(Some(5.0), hub.as_f64("power"));
+
+        assert!(reactive_system.get_reactive_relation_manager().delete(&relation_1));
+        assert_eq!(Some(json!(1)), hub.get("devices"));
+        assert_eq!(Some(3.0), hub.as_f64("power"));
+
+        assert!(reactive_system.get_reactive_relation_manager().delete(&relation_2));
+        assert_eq!(Some(json!(0)), hub.get("devices"));
+        assert_eq!(Some(0.0), hub.as_f64("power"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_recompute_on_upstream_property_changed() {
+        reactive_graph_utils_test::init_logger();
+        let reactive_system = reactive_graph_di::get_container::<ReactiveSystemImpl>();
+        reactive_system.post_init().await;
+        let types = register_types(&reactive_system);
+
+        let hub = create_hub(&reactive_system, &types);
+        let device_1 = create_device(&reactive_system, &types, 2);
+        let device_2 = create_device(&reactive_system, &types, 3);
+        connect(&reactive_system, &types, &hub, &device_1);
+        connect(&reactive_system, &types, &hub, &device_2);
+        assert_eq!(Some(5.0), hub.as_f64("power"));
+
+        device_1.set("power", json!(10));
+        assert_eq!(Some(13.0), hub.as_f64("power"));
+        device_2.set("power", json!(0.5));
+        assert_eq!(Some(10.5), hub.as_f64("power"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_computed_properties_are_read_only() {
+        reactive_graph_utils_test::init_logger();
+        let reactive_system = reactive_graph_di::get_container::<ReactiveSystemImpl>();
+        reactive_system.post_init().await;
+        let types = register_types(&reactive_system);
+
+        let hub = create_hub(&reactive_system, &types);
+        let device = create_device(&reactive_system, &types, 2);
+        connect(&reactive_system, &types, &hub, &device);
+        assert_eq!(Some(Immutable), hub.mutability("devices"));
+        assert_eq!(Some(Immutable), hub.mutability("power"));
+
+        hub.set_checked("power", json!(100));
+        assert_eq!(Some(2.0), hub.as_f64("power"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_unobserve_on_entity_deleted() {
+        reactive_graph_utils_test::init_logger();
+        let reactive_system = reactive_graph_di::get_container::<ReactiveSystemImpl>();
+        reactive_system.post_init().await;
+        let types = register_types(&reactive_system);
+
+        let hub = create_hub(&reactive_system, &types);
+        let device = create_device(&reactive_system, &types, 2);
+        connect(&reactive_system, &types, &hub, &device);
+        assert_eq!(Some(2.0), hub.as_f64("power"));
+
+        assert!(reactive_system.get_reactive_entity_manager().delete(hub.id));
+        // The power of the device is no longer observed for the deleted hub
+        device.set("power", json!(10));
+        assert_eq!(Some(2.0), hub.as_f64("power"));
+    }
+}
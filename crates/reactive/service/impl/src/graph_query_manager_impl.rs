@@ -0,0 +1,562 @@
+use std::cell::OnceCell;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+use springtime_di::Component;
+use springtime_di::component_alias;
+
+use reactive_graph_graph::ComponentContainer;
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::EntityInstance;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::GraphQuery;
+use reactive_graph_graph::GraphQueryPlan;
+use reactive_graph_graph::GraphQueryPlanStep;
+use reactive_graph_graph::GraphQueryResult;
+use reactive_graph_graph::InstanceLabel;
+use reactive_graph_graph::NamespacedTypeGetter;
+use reactive_graph_graph::PatternDirection;
+use reactive_graph_graph::Predicate;
+use reactive_graph_graph::PropertyFilter;
+use reactive_graph_graph::PropertyFilterExpression;
+use reactive_graph_graph::PropertyInstanceGetter;
+use reactive_graph_graph::RelationInstance;
+use reactive_graph_graph::RelationTypeId;
+use reactive_graph_graph::ReturnItem;
+use reactive_graph_graph::TypeDefinitionGetter;
+use reactive_graph_graph::TypePattern;
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_reactive_model_api::ReactiveInstance;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+use reactive_graph_reactive_model_impl::ReactiveRelation;
+use reactive_graph_reactive_service_api::GraphQueryError;
+use reactive_graph_reactive_service_api::GraphQueryManager;
+use reactive_graph_reactive_service_api::ReactiveEntityManager;
+use reactive_graph_reactive_service_api::ReactiveRelationManager;
+use reactive_graph_type_system_api::ComponentManager;
+use reactive_graph_type_system_api::EntityTypeManager;
+use reactive_graph_type_system_api::RelationTypeManager;
+
+#[derive(Component)]
+pub struct GraphQueryManagerImpl {
+    reactive_entity_manager: Arc<dyn ReactiveEntityManager + Send + Sync>,
+
+    reactive_relation_manager: Arc<dyn ReactiveRelationManager + Send + Sync>,
+
+    component_manager: Arc<dyn ComponentManager + Send + Sync>,
+
+    entity_type_manager: Arc<dyn EntityTypeManager + Send + Sync>,
+
+    relation_type_manager: Arc<dyn RelationTypeManager + Send + Sync>,
+}
+
+/// A node pattern with resolved label and the filters of the node.
+struct PlannedNode {
+    variable: String,
+    label: Option<InstanceLabel>,
+    filter: Option<Box<PropertyFilter>>,
+}
+
+/// A relation pattern with resolved label and the filters of the relation.
+struct PlannedRelation {
+    variable: String,
+    label: Option<InstanceLabel>,
+    filter: Option<Box<PropertyFilter>>,
+    direction: PatternDirection,
+}
+
+/// An instance bound to a variable.
+#[derive(Clone)]
+enum Bound {
+    Entity(ReactiveEntity),
+    Relation(ReactiveRelation),
+}
+
+type Row = HashMap<String, Bound>;
+
+/// A predicate with compiled property filters.
+enum CompiledPredicate {
+    And(Vec<CompiledPredicate>),
+    Or(Vec<CompiledPredicate>),
+    Not(Box<CompiledPredicate>),
+    Condition(String, PropertyFilterExpression),
+}
+
+impl CompiledPredicate {
+    fn new(predicate: &Predicate) -> Result<Self, GraphQueryError> {
+        Ok(match predicate {
+            Predicate::And(predicates) => CompiledPredicate::And(predicates.iter().map(CompiledPredicate::new).collect::<Result<_, _>>()?),
+            Predicate::Or(predicates) => CompiledPredicate::Or(predicates.iter().map(CompiledPredicate::new).collect::<Result<_, _>>()?),
+            Predicate::Not(predicate) => CompiledPredicate::Not(Box::new(CompiledPredicate::new(predicate)?)),
+            Predicate::Condition { variable, filter } => CompiledPredicate::Condition(
+                variable.clone(),
+                filter.compile().map_err(|e| GraphQueryError::InvalidFilter(variable.clone(), e.to_string()))?,
+            ),
+        })
+    }
+
+    fn test(&self, row: &Row) -> bool {
+        match self {
+            CompiledPredicate::And(predicates) => predicates.iter().all(|predicate| predicate.test(row)),
+            CompiledPredicate::Or(predicates) => predicates.iter().any(|predicate| predicate.test(row)),
+            CompiledPredicate::Not(predicate) => !predicate.test(row),
+            CompiledPredicate::Condition(variable, filter) => match row.get(variable) {
+                Some(Bound::Entity(entity)) => filter.matches(entity),
+                Some(Bound::Relation(relation)) => filter.matches(relation),
+                None => false,
+            },
+        }
+    }
+}
+
+/// A step of the execution plan with compiled property filters.
+enum CompiledStep<'a> {
+    Scan {
+        variable: &'a str,
+        label: &'a Option<InstanceLabel>,
+        filter: Option<PropertyFilterExpression>,
+        /// The matching entity instances are scanned once and shared by all rows.
+        candidates: OnceCell<Vec<ReactiveEntity>>,
+    },
+    Expand {
+        from: &'a str,
+        relation: &'a str,
+        relation_label: &'a Option<InstanceLabel>,
+        relation_filter: Option<PropertyFilterExpression>,
+        direction: PatternDirection,
+        to: &'a str,
+        to_label: &'a Option<InstanceLabel>,
+        to_filter: Option<PropertyFilterExpression>,
+    },
+    Filter(CompiledPredicate),
+}
+
+impl<'a> CompiledStep<'a> {
+    fn new(step: &'a GraphQueryPlanStep) -> Result<Self, GraphQueryError> {
+        Ok(match step {
+            GraphQueryPlanStep::Scan { variable, label, filter, .. } => CompiledStep::Scan {
+                variable,
+                label,
+                filter: compile_filter(variable, filter)?,
+                candidates: OnceCell::new(),
+            },
+            GraphQueryPlanStep::Expand {
+                from,
+                relation,
+                relation_label,
+                relation_filter,
+                direction,
+                to,
+                to_label,
+                to_filter,
+            } => CompiledStep::Expand {
+                from,
+                relation,
+                relation_label,
+                relation_filter: compile_filter(relation, relation_filter)?,
+                direction: *direction,
+                to,
+                to_label,
+                to_filter: compile_filter(to, to_filter)?,
+            },
+            GraphQueryPlanStep::Filter(predicate) => CompiledStep::Filter(CompiledPredicate::new(predicate)?),
+        })
+    }
+}
+
+impl GraphQueryManagerImpl {
+    fn resolve_entity_label(&self, pattern: &TypePattern) -> Result<InstanceLabel, GraphQueryError> {
+        let entity_types: Vec<EntityTypeId> = match &pattern.namespace {
+            Some(namespace) => Some(EntityTypeId::new_from_type(namespace, &pattern.type_name))
+                .filter(|ty| self.entity_type_manager.has(ty))
+                .into_iter()
+                .collect(),
+            None => self
+                .entity_type_manager
+                .find_by_type_name(&pattern.type_name)
+                .iter()
+                .map(|entity_type| entity_type.key().clone())
+                .filter(|ty| ty.type_name() == pattern.type_name)
+                .collect(),
+        };
+        if let Some(label) = Self::unique_label(pattern, entity_types, InstanceLabel::EntityType)? {
+            return Ok(label);
+        }
+        match self.resolve_component_label(pattern)? {
+            Some(label) => Ok(label),
+            None => Err(GraphQueryError::UnknownEntityLabel(pattern.clone())),
+        }
+    }
+
+    fn resolve_relation_label(&self, pattern: &TypePattern) -> Result<InstanceLabel, GraphQueryError> {
+        let relation_types: Vec<RelationTypeId> = match &pattern.namespace {
+            Some(namespace) => Some(RelationTypeId::new_from_type(namespace, &pattern.type_name))
+                .filter(|ty| self.relation_type_manager.has(ty))
+                .into_iter()
+                .collect(),
+            None => self
+                .relation_type_manager
+                .find_by_type_name(&pattern.type_name)
+                .iter()
+                .map(|relation_type| relation_type.key().clone())
+                .filter(|ty| ty.type_name() == pattern.type_name)
+                .collect(),
+        };
+        if let Some(label) = Self::unique_label(pattern, relation_types, InstanceLabel::RelationType)? {
+            return Ok(label);
+        }
+        match self.resolve_component_label(pattern)? {
+            Some(label) => Ok(label),
+            None => Err(GraphQueryError::UnknownRelationLabel(pattern.clone())),
+        }
+    }
+
+    fn resolve_component_label(&self, pattern: &TypePattern) -> Result<Option<InstanceLabel>, GraphQueryError> {
+        let components: Vec<ComponentTypeId> = match &pattern.namespace {
+            Some(namespace) => Some(ComponentTypeId::new_from_type(namespace, &pattern.type_name))
+                .filter(|ty| self.component_manager.has(ty))
+                .into_iter()
+                .collect(),
+            None => self
+                .component_manager
+                .find_by_type_name(&pattern.type_name)
+                .iter()
+                .map(|component| component.key().clone())
+                .filter(|ty| ty.type_name() == pattern.type_name)
+                .collect(),
+        };
+        Self::unique_label(pattern, components, InstanceLabel::Component)
+    }
+
+    fn unique_label<T: ToString>(
+        pattern: &TypePattern,
+        mut candidates: Vec<T>,
+        label: fn(T) -> InstanceLabel,
+    ) -> Result<Option<InstanceLabel>, GraphQueryError> {
+        match candidates.len() {
+            0 => Ok(None),
+            1 => Ok(candidates.pop().map(label)),
+            _ => {
+                let mut candidates: Vec<String> = candidates.iter().map(|ty| ty.to_string()).collect();
+                candidates.sort();
+                Err(GraphQueryError::AmbiguousLabel(pattern.clone(), candidates.join(", ")))
+            }
+        }
+    }
+
+    /// Estimates the number of entity instances matching the label and the filter. Returns true,
+    /// if a property index can be used.
+    fn estimate(&self, label: &Option<InstanceLabel>, filter: &Option<Box<PropertyFilter>>) -> (usize, bool) {
+        let count = match label {
+            Some(InstanceLabel::EntityType(ty)) => self.reactive_entity_manager.count_by_type(ty),
+            Some(InstanceLabel::Component(ty)) => self.reactive_entity_manager.count_by_component(ty),
+            Some(InstanceLabel::RelationType(_)) => 0,
+            None => self.reactive_entity_manager.count(),
+        };
+        let Some(filter) = filter.as_ref().and_then(|filter| filter.compile().ok()) else {
+            return (count, false);
+        };
+        if let Some(InstanceLabel::EntityType(ty)) = label {
+            let type_definition = ty.type_definition();
+            let statistics = self.reactive_entity_manager.get_property_index_statistics();
+            let indexed = filter.equality_conditions().into_iter().find_map(|(property_name, values)| {
+                statistics
+                    .iter()
+                    .find(|statistics| statistics.ty.type_definition == type_definition && statistics.ty.property == property_name)
+                    .map(|statistics| statistics.entries.div_ceil(statistics.distinct_values.max(1)) * values.len())
+            });
+            if let Some(estimated_rows) = indexed {
+                return (estimated_rows.min(count), true);
+            }
+        }
+        // Without an index, assume that the filter matches the half of the instances
+        (count.div_ceil(2), false)
+    }
+
+    fn scan(&self, label: &Option<InstanceLabel>, filter: &Option<PropertyFilterExpression>) -> Vec<ReactiveEntity> {
+        let entities = match (label, filter) {
+            (Some(InstanceLabel::EntityType(ty)), Some(filter)) => return self.reactive_entity_manager.get_by_type_and_filter(ty, filter),
+            (Some(InstanceLabel::EntityType(ty)), None) => self.reactive_entity_manager.get_by_type(ty),
+            (Some(InstanceLabel::Component(ty)), _) => self.reactive_entity_manager.get_by_component(ty),
+            (Some(InstanceLabel::RelationType(_)), _) => Vec::new(),
+            (None, _) => self.reactive_entity_manager.get_all(),
+        };
+        match filter {
+            Some(filter) => entities.into_iter().filter(|entity| filter.matches(entity)).collect(),
+            None => entities,
+        }
+    }
+
+    /// Returns the relations of the entity instance and the entity instance on the other end.
+    fn relations(&self, entity: &ReactiveEntity, direction: PatternDirection) -> Vec<(ReactiveRelation, ReactiveEntity)> {
+        let mut relations = Vec::new();
+        if direction != PatternDirection::Inbound {
+            for relation in self.reactive_relation_manager.get_by_outbound_entity(entity.id) {
+                let inbound = relation.inbound.clone();
+                relations.push((relation, inbound));
+            }
+        }
+        if direction != PatternDirection::Outbound {
+            for relation in self.reactive_relation_manager.get_by_inbound_entity(entity.id) {
+                // Self loops are already contained in the outbound relations
+                if direction == PatternDirection::Both && relation.outbound.id == relation.inbound.id {
+                    continue;
+                }
+                let outbound = relation.outbound.clone();
+                relations.push((relation, outbound));
+            }
+        }
+        relations
+    }
+
+    /// Applies the steps starting with the given step to the row and collects the matching rows.
+    ///
+    /// The rows are produced depth-first, so the execution stops as soon as the limit is reached.
+    /// Returns false, if the limit has been reached.
+    fn execute_steps(&self, steps: &[CompiledStep], row: &mut Row, rows: &mut Vec<Row>, limit: Option<usize>) -> bool {
+        let Some((step, steps)) = steps.split_first() else {
+            rows.push(row.clone());
+            return limit.is_none_or(|limit| rows.len() < limit);
+        };
+        match step {
+            CompiledStep::Scan {
+                variable,
+                label,
+                filter,
+                candidates,
+            } => match row.get(*variable) {
+                Some(Bound::Entity(entity)) => !matches_entity(entity, label, filter) || self.execute_steps(steps, row, rows, limit),
+                Some(Bound::Relation(_)) => true,
+                None => {
+                    for entity in candidates.get_or_init(|| self.scan(label, filter)).iter() {
+                        row.insert(variable.to_string(), Bound::Entity(entity.clone()));
+                        let next = self.execute_steps(steps, row, rows, limit);
+                        row.remove(*variable);
+                        if !next {
+                            return false;
+                        }
+                    }
+                    true
+                }
+            },
+            CompiledStep::Expand {
+                from,
+                relation: relation_variable,
+                relation_label,
+                relation_filter,
+                direction,
+                to,
+                to_label,
+                to_filter,
+            } => {
+                let Some(Bound::Entity(from)) = row.get(*from) else {
+                    return true;
+                };
+                for (relation, other) in self.relations(from, *direction) {
+                    if !matches_relation(&relation, relation_label, relation_filter) || !matches_entity(&other, to_label, to_filter) {
+                        continue;
+                    }
+                    let bind_relation = match row.get(*relation_variable) {
+                        Some(Bound::Relation(bound)) if bound.id() == relation.id() => false,
+                        Some(_) => continue,
+                        None => true,
+                    };
+                    let bind_to = match row.get(*to) {
+                        Some(Bound::Entity(bound)) if bound.id == other.id => false,
+                        Some(_) => continue,
+                        None => true,
+                    };
+                    if bind_relation {
+                        row.insert(relation_variable.to_string(), Bound::Relation(relation));
+                    }
+                    if bind_to {
+                        row.insert(to.to_string(), Bound::Entity(other));
+                    }
+                    let next = self.execute_steps(steps, row, rows, limit);
+                    if bind_relation {
+                        row.remove(*relation_variable);
+                    }
+                    if bind_to {
+                        row.remove(*to);
+                    }
+                    if !next {
+                        return false;
+                    }
+                }
+                true
+            }
+            CompiledStep::Filter(predicate) => !predicate.test(row) || self.execute_steps(steps, row, rows, limit),
+        }
+    }
+
+    fn value(row: &Row, item: &ReturnItem) -> Value {
+        match (row.get(&item.variable), &item.property) {
+            (Some(Bound::Entity(entity)), Some(property)) => entity.get(property).unwrap_or(Value::Null),
+            (Some(Bound::Relation(relation)), Some(property)) => relation.get(property).unwrap_or(Value::Null),
+            (Some(Bound::Entity(entity)), None) => serde_json::to_value(EntityInstance::from(entity.clone())).unwrap_or(Value::Null),
+            (Some(Bound::Relation(relation)), None) => serde_json::to_value(RelationInstance::from(relation.clone())).unwrap_or(Value::Null),
+            (None, _) => Value::Null,
+        }
+    }
+}
+
+fn combine_filters(properties: &[(String, Value)], predicates: Vec<Predicate>) -> Option<Box<PropertyFilter>> {
+    let mut filters: Vec<PropertyFilter> = properties.iter().map(|(name, value)| PropertyFilter::eq(name, value.clone())).collect();
+    filters.extend(predicates.iter().map(|predicate| predicate.to_property_filter()));
+    match filters.len() {
+        0 => None,
+        1 => filters.pop().map(Box::new),
+        _ => Some(Box::new(PropertyFilter {
+            and: Some(filters),
+            ..Default::default()
+        })),
+    }
+}
+
+fn compile_filter(variable: &str, filter: &Option<Box<PropertyFilter>>) -> Result<Option<PropertyFilterExpression>, GraphQueryError> {
+    filter
+        .as_ref()
+        .map(|filter| filter.compile())
+        .transpose()
+        .map_err(|e| GraphQueryError::InvalidFilter(variable.to_string(), e.to_string()))
+}
+
+fn matches_entity(entity: &ReactiveEntity, label: &Option<InstanceLabel>, filter: &Option<PropertyFilterExpression>) -> bool {
+    let label_matches = match label {
+        Some(InstanceLabel::EntityType(ty)) => &entity.ty == ty,
+        Some(InstanceLabel::Component(ty)) => entity.is_a(ty),
+        Some(InstanceLabel::RelationType(_)) => false,
+        None => true,
+    };
+    label_matches && filter.as_ref().is_none_or(|filter| filter.matches(entity))
+}
+
+fn matches_relation(relation: &ReactiveRelation, label: &Option<InstanceLabel>, filter: &Option<PropertyFilterExpression>) -> bool {
+    let label_matches = match label {
+        Some(InstanceLabel::RelationType(ty)) => &relation.relation_type_id() == ty,
+        Some(InstanceLabel::Component(ty)) => relation.is_a(ty),
+        Some(InstanceLabel::EntityType(_)) => false,
+        None => true,
+    };
+    label_matches && filter.as_ref().is_none_or(|filter| filter.matches(relation))
+}
+
+#[async_trait]
+#[component_alias]
+impl GraphQueryManager for GraphQueryManagerImpl {
+    fn plan(&self, query: &GraphQuery) -> Result<GraphQueryPlan, GraphQueryError> {
+        // Conditions on a single variable are pushed down to the node or relation pattern
+        let mut pushed_down: HashMap<String, Vec<Predicate>> = HashMap::new();
+        let mut residual = Vec::new();
+        for predicate in query.predicate.clone().map(Predicate::conjuncts).unwrap_or_default() {
+            let variables = predicate.variables();
+            match variables.iter().next() {
+                Some(variable) if variables.len() == 1 => pushed_down.entry(variable.to_string()).or_default().push(predicate),
+                _ => residual.push(predicate),
+            }
+        }
+        let mut anonymous = 0;
+        let mut next_variable = |variable: &Option<String>| match variable {
+            Some(variable) => variable.clone(),
+            None => {
+                anonymous += 1;
+                format!("_{anonymous}")
+            }
+        };
+        let mut bound: HashSet<String> = HashSet::new();
+        let mut steps = Vec::new();
+        for pattern in query.patterns.iter() {
+            let mut nodes = Vec::new();
+            for node in pattern.nodes() {
+                let variable = next_variable(&node.variable);
+                let label = node.label.as_ref().map(|label| self.resolve_entity_label(label)).transpose()?;
+                let filter = combine_filters(&node.properties, pushed_down.remove(&variable).unwrap_or_default());
+                compile_filter(&variable, &filter)?;
+                nodes.push(PlannedNode { variable, label, filter });
+            }
+            let mut relations = Vec::new();
+            for (relation, _) in pattern.steps.iter() {
+                let variable = next_variable(&relation.variable);
+                let label = relation.label.as_ref().map(|label| self.resolve_relation_label(label)).transpose()?;
+                let filter = combine_filters(&relation.properties, pushed_down.remove(&variable).unwrap_or_default());
+                compile_filter(&variable, &filter)?;
+                relations.push(PlannedRelation {
+                    variable,
+                    label,
+                    filter,
+                    direction: relation.direction,
+                });
+            }
+            // Start with an already bound node or with the node matching the fewest instances
+            let estimates: Vec<(usize, bool)> = nodes.iter().map(|node| self.estimate(&node.label, &node.filter)).collect();
+            let start = nodes
+                .iter()
+                .position(|node| bound.contains(&node.variable))
+                .or_else(|| (0..nodes.len()).min_by_key(|i| estimates[*i].0))
+                .unwrap_or_default();
+            let (estimated_rows, indexed) = estimates[start];
+            steps.push(GraphQueryPlanStep::Scan {
+                variable: nodes[start].variable.clone(),
+                label: nodes[start].label.clone(),
+                filter: nodes[start].filter.clone(),
+                indexed,
+                estimated_rows,
+            });
+            let expand = |from: &PlannedNode, relation: &PlannedRelation, direction: PatternDirection, to: &PlannedNode| GraphQueryPlanStep::Expand {
+                from: from.variable.clone(),
+                relation: relation.variable.clone(),
+                relation_label: relation.label.clone(),
+                relation_filter: relation.filter.clone(),
+                direction,
+                to: to.variable.clone(),
+                to_label: to.label.clone(),
+                to_filter: to.filter.clone(),
+            };
+            for i in start..relations.len() {
+                steps.push(expand(&nodes[i], &relations[i], relations[i].direction, &nodes[i + 1]));
+            }
+            for i in (0..start).rev() {
+                steps.push(expand(&nodes[i + 1], &relations[i], relations[i].direction.reverse(), &nodes[i]));
+            }
+            bound.extend(nodes.into_iter().map(|node| node.variable));
+            bound.extend(relations.into_iter().map(|relation| relation.variable));
+        }
+        steps.extend(residual.into_iter().map(GraphQueryPlanStep::Filter));
+        let returns = match query.returns.is_empty() {
+            true => query
+                .variables()
+                .into_iter()
+                .map(|variable| ReturnItem {
+                    variable,
+                    property: None,
+                    alias: None,
+                })
+                .collect(),
+            false => query.returns.clone(),
+        };
+        Ok(GraphQueryPlan {
+            steps,
+            returns,
+            limit: query.limit,
+        })
+    }
+
+    fn execute_plan(&self, plan: &GraphQueryPlan) -> Result<GraphQueryResult, GraphQueryError> {
+        let steps = plan.steps.iter().map(CompiledStep::new).collect::<Result<Vec<_>, _>>()?;
+        let mut rows = Vec::new();
+        if plan.limit != Some(0) {
+            self.execute_steps(&steps, &mut Row::new(), &mut rows, plan.limit);
+        }
+        let rows = rows
+            .iter()
+            .map(|row| plan.returns.iter().map(|item| Self::value(row, item)).collect())
+            .collect();
+        Ok(GraphQueryResult::new(plan.columns(), rows))
+    }
+}
+
+#[async_trait]
+impl Lifecycle for GraphQueryManagerImpl {}
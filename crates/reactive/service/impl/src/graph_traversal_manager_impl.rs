@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use springtime_di::Component;
+use springtime_di::component_alias;
+use uuid::Uuid;
+
+use reactive_graph_graph::GraphPath;
+use reactive_graph_graph::RelationInstanceId;
+use reactive_graph_graph::TraversalGraph;
+use reactive_graph_graph::TraversalOptions;
+use reactive_graph_graph::TraversalVisit;
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_reactive_model_api::ReactiveInstance;
+use reactive_graph_reactive_service_api::GraphTraversalError;
+use reactive_graph_reactive_service_api::GraphTraversalManager;
+use reactive_graph_reactive_service_api::ReactiveEntityManager;
+use reactive_graph_reactive_service_api::ReactiveFlowManager;
+use reactive_graph_reactive_service_api::ReactiveRelationManager;
+
+#[derive(Component)]
+pub struct GraphTraversalManagerImpl {
+    reactive_entity_manager: Arc<dyn ReactiveEntityManager + Send + Sync>,
+
+    reactive_relation_manager: Arc<dyn ReactiveRelationManager + Send + Sync>,
+
+    reactive_flow_manager: Arc<dyn ReactiveFlowManager + Send + Sync>,
+}
+
+/// Traverses the relations using the outbound and inbound indexes of the reactive relation manager.
+struct ReactiveRelationGraph<'a> {
+    reactive_relation_manager: &'a (dyn ReactiveRelationManager + Send + Sync),
+}
+
+impl TraversalGraph for ReactiveRelationGraph<'_> {
+    fn outbound_relations(&self, id: Uuid) -> Vec<RelationInstanceId> {
+        self.reactive_relation_manager
+            .get_by_outbound_entity(id)
+            .iter()
+            .map(|relation_instance| relation_instance.id())
+            .collect()
+    }
+
+    fn inbound_relations(&self, id: Uuid) -> Vec<RelationInstanceId> {
+        self.reactive_relation_manager
+            .get_by_inbound_entity(id)
+            .iter()
+            .map(|relation_instance| relation_instance.id())
+            .collect()
+    }
+}
+
+impl GraphTraversalManagerImpl {
+    fn graph(&self) -> ReactiveRelationGraph<'_> {
+        ReactiveRelationGraph {
+            reactive_relation_manager: self.reactive_relation_manager.as_ref(),
+        }
+    }
+
+    fn check_entity(&self, id: Uuid) -> Result<(), GraphTraversalError> {
+        if !self.reactive_entity_manager.has(id) {
+            return Err(GraphTraversalError::EntityNotFound(id));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+#[component_alias]
+impl GraphTraversalManager for GraphTraversalManagerImpl {
+    fn traverse(&self, start: Uuid, options: &TraversalOptions) -> Result<Vec<TraversalVisit>, GraphTraversalError> {
+        self.check_entity(start)?;
+        Ok(self.graph().traverse(start, options))
+    }
+
+    fn shortest_path(&self, start: Uuid, end: Uuid, options: &TraversalOptions) -> Result<Option<GraphPath>, GraphTraversalError> {
+        self.check_entity(start)?;
+        self.check_entity(end)?;
+        Ok(self.graph().shortest_path(start, end, options))
+    }
+
+    fn all_paths(&self, start: Uuid, end: Uuid, options: &TraversalOptions) -> Result<Vec<GraphPath>, GraphTraversalError> {
+        self.check_entity(start)?;
+        self.check_entity(end)?;
+        Ok(self.graph().all_paths(start, end, options))
+    }
+
+    fn connected_components(&self, options: &TraversalOptions) -> Vec<Vec<Uuid>> {
+        let mut ids = self.reactive_entity_manager.get_ids();
+        ids.sort();
+        self.graph().connected_components(ids, options)
+    }
+
+    fn cycles(&self, id: Uuid, options: &TraversalOptions) -> Result<Vec<GraphPath>, GraphTraversalError> {
+        self.check_entity(id)?;
+        Ok(self.graph().cycles(vec![id], options))
+    }
+
+    fn flow_cycles(&self, flow_id: Uuid, options: &TraversalOptions) -> Result<Vec<GraphPath>, GraphTraversalError> {
+        let reactive_flow = self.reactive_flow_manager.get(flow_id).ok_or(GraphTraversalError::FlowNotFound(flow_id))?;
+        let mut ids: Vec<Uuid> = reactive_flow.entity_instances.read().unwrap().keys().copied().collect();
+        ids.sort();
+        Ok(self.graph().cycles(ids, options))
+    }
+}
+
+impl Lifecycle for GraphTraversalManagerImpl {}
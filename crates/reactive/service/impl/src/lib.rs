@@ -1,11 +1,25 @@
+pub use computed_property_manager_impl::*;
+pub use graph_query_manager_impl::*;
+pub use graph_traversal_manager_impl::*;
+pub use property_index::*;
 pub use reactive_entity_manager_impl::*;
 pub use reactive_flow_manager_impl::*;
 pub use reactive_instance_event_manager_impl::*;
+pub use reactive_instance_type_reference_provider::*;
 pub use reactive_relation_manager_impl::*;
 pub use reactive_system_impl::*;
+pub use saved_query_manager_impl::*;
+pub use search_manager_impl::*;
 
+pub mod computed_property_manager_impl;
+pub mod graph_query_manager_impl;
+pub mod graph_traversal_manager_impl;
+pub mod property_index;
 pub mod reactive_entity_manager_impl;
 pub mod reactive_flow_manager_impl;
 pub mod reactive_instance_event_manager_impl;
+pub mod reactive_instance_type_reference_provider;
 pub mod reactive_relation_manager_impl;
 pub mod reactive_system_impl;
+pub mod saved_query_manager_impl;
+pub mod search_manager_impl;
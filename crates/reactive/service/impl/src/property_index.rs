@@ -0,0 +1,435 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use dashmap::DashMap;
+use dashmap::DashSet;
+use dashmap::mapref::entry::Entry;
+use serde_json::Value;
+
+use reactive_graph_graph::Component;
+use reactive_graph_graph::ExtensionContainer;
+use reactive_graph_graph::ExtensionTypeId;
+use reactive_graph_graph::PropertyFilterExpression;
+use reactive_graph_graph::PropertyTypes;
+use reactive_graph_graph::TypeDefinitionGetter;
+use reactive_graph_graph::TypeDefinitionProperty;
+use reactive_graph_reactive_service_api::PropertyIndexStatistics;
+use reactive_graph_runtime_model::EXTENSION_INDEX;
+
+/// The handle id of the observers which are keeping the property indexes up to date.
+pub const HANDLE_ID_PROPERTY_INDEX: u128 = 0x1c7d5f0e8a2b4c3d9e6f7a8b9c0d1e2f;
+
+/// Secondary index of the values of a property of the instances of a type.
+pub struct PropertyIndex<K>
+where
+    K: Clone + Eq + Hash,
+{
+    /// The ids of the instances by index key.
+    entries: DashMap<String, DashSet<K>>,
+    /// The index keys by id of the instances.
+    keys: DashMap<K, String>,
+    /// The number of lookups.
+    lookups: AtomicUsize,
+    /// True, if all instances of the type have been added to the index.
+    complete: AtomicBool,
+}
+
+impl<K> PropertyIndex<K>
+where
+    K: Clone + Eq + Hash,
+{
+    pub fn new() -> Self {
+        PropertyIndex {
+            entries: DashMap::new(),
+            keys: DashMap::new(),
+            lookups: AtomicUsize::new(0),
+            complete: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns true, if all instances of the type have been added to the index. Incomplete
+    /// indexes must not be used for lookups.
+    pub fn is_complete(&self) -> bool {
+        self.complete.load(Ordering::Acquire)
+    }
+
+    /// Marks the index as complete after all instances of the type have been added.
+    pub fn set_complete(&self) {
+        self.complete.store(true, Ordering::Release);
+    }
+
+    /// Inserts or updates the value of the instance with the given id.
+    ///
+    /// The key of the instance stays locked until the entries are updated, so concurrent updates
+    /// of the same instance are applied one after another. The instance is added to the new entry
+    /// before it is removed from the previous entry, so lookups never miss the instance.
+    pub fn update(&self, id: K, value: &Value) {
+        let key = index_key(value);
+        match self.keys.entry(id.clone()) {
+            Entry::Occupied(mut entry) => {
+                if entry.get() == &key {
+                    return;
+                }
+                self.entries.entry(key.clone()).or_default().insert(id.clone());
+                let previous_key = entry.insert(key);
+                self.remove_entry(&previous_key, &id);
+            }
+            Entry::Vacant(entry) => {
+                self.entries.entry(key.clone()).or_default().insert(id);
+                entry.insert(key);
+            }
+        }
+    }
+
+    /// Removes the instance with the given id from the index.
+    pub fn remove(&self, id: &K) {
+        if let Some((_, key)) = self.keys.remove(id) {
+            self.remove_entry(&key, id);
+        }
+    }
+
+    /// Returns the ids of the instances having one of the given values.
+    pub fn lookup(&self, values: &[&Value]) -> Vec<K> {
+        self.lookups.fetch_add(1, Ordering::Relaxed);
+        let keys: HashSet<String> = values.iter().map(|value| index_key(value)).collect();
+        let mut ids = HashSet::new();
+        for key in keys {
+            if let Some(entry) = self.entries.get(&key) {
+                ids.extend(entry.iter().map(|id| id.key().clone()));
+            }
+        }
+        ids.into_iter().collect()
+    }
+
+    /// Returns the statistics of the index.
+    pub fn statistics(&self, ty: TypeDefinitionProperty) -> PropertyIndexStatistics {
+        PropertyIndexStatistics {
+            ty,
+            entries: self.keys.len(),
+            distinct_values: self.entries.len(),
+            lookups: self.lookups.load(Ordering::Relaxed),
+        }
+    }
+
+    fn remove_entry(&self, key: &str, id: &K) {
+        let is_empty = match self.entries.get(key) {
+            Some(entry) => {
+                entry.remove(id);
+                entry.is_empty()
+            }
+            None => false,
+        };
+        if is_empty {
+            self.entries.remove_if(key, |_, entry| entry.is_empty());
+        }
+    }
+}
+
+impl<K> Default for PropertyIndex<K>
+where
+    K: Clone + Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The property indexes of the instances by type and property name.
+pub struct PropertyIndexes<T, K>(DashMap<(T, String), Arc<PropertyIndex<K>>>)
+where
+    T: Clone + Eq + Hash + TypeDefinitionGetter,
+    K: Clone + Eq + Hash;
+
+impl<T, K> PropertyIndexes<T, K>
+where
+    T: Clone + Eq + Hash + TypeDefinitionGetter,
+    K: Clone + Eq + Hash,
+{
+    pub fn new() -> Self {
+        PropertyIndexes(DashMap::new())
+    }
+
+    /// Returns the index of the property of the given type. Creates the index if it doesn't exist.
+    pub fn get_or_create(&self, ty: &T, property_name: &str) -> Arc<PropertyIndex<K>> {
+        self.0.entry((ty.clone(), property_name.to_string())).or_default().value().clone()
+    }
+
+    /// Returns the names of the indexed properties of the given type.
+    pub fn property_names(&self, ty: &T) -> Vec<String> {
+        self.0.iter().filter(|index| &index.key().0 == ty).map(|index| index.key().1.clone()).collect()
+    }
+
+    /// Drops the indexes of the given type whose properties are no longer indexed. Returns the
+    /// names of the properties of the dropped indexes.
+    ///
+    /// A dropped index isn't updated anymore. If the property is indexed again, a new index is
+    /// created and backfilled.
+    pub fn retain(&self, ty: &T, indexed_property_names: &[String]) -> Vec<String> {
+        let dropped: Vec<String> = self
+            .property_names(ty)
+            .into_iter()
+            .filter(|property_name| !indexed_property_names.contains(property_name))
+            .collect();
+        for property_name in dropped.iter() {
+            self.0.remove(&(ty.clone(), property_name.clone()));
+        }
+        dropped
+    }
+
+    /// Removes the instance with the given id from all indexes of the given type.
+    pub fn remove(&self, ty: &T, id: &K) {
+        for index in self.0.iter().filter(|index| &index.key().0 == ty) {
+            index.value().remove(id);
+        }
+    }
+
+    /// Returns the ids of the candidates which may match the given filter. Only the complete
+    /// indexes of the given indexed properties are used. Returns none, if no index can be used
+    /// for the filter.
+    pub fn lookup(&self, ty: &T, filter: &PropertyFilterExpression, indexed_property_names: &[String]) -> Option<Vec<K>> {
+        filter.equality_conditions().into_iter().find_map(|(property_name, values)| {
+            if !indexed_property_names.iter().any(|name| name == property_name) {
+                return None;
+            }
+            let index = self.0.get(&(ty.clone(), property_name.to_string()))?.value().clone();
+            if !index.is_complete() {
+                return None;
+            }
+            Some(index.lookup(&values))
+        })
+    }
+
+    /// Returns the statistics of all indexes.
+    pub fn statistics(&self) -> Vec<PropertyIndexStatistics> {
+        self.0
+            .iter()
+            .map(|index| {
+                let (ty, property_name) = index.key();
+                index
+                    .value()
+                    .statistics(TypeDefinitionProperty::new(ty.type_definition(), property_name.clone()))
+            })
+            .collect()
+    }
+}
+
+impl<T, K> Default for PropertyIndexes<T, K>
+where
+    T: Clone + Eq + Hash + TypeDefinitionGetter,
+    K: Clone + Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the names of the indexed properties.
+///
+/// A property is indexed if the property type has the extension `core::index` or if the type
+/// or one of its components has the extension `core::index` with a list of property names.
+pub fn indexed_property_names<E: ExtensionContainer>(ty: &E, properties: &PropertyTypes, components: &[Component]) -> Vec<String> {
+    extension_property_names(ty, properties, components, &EXTENSION_INDEX)
+}
+
+/// Returns the names of the properties which are marked by the given extension.
+///
+/// A property is marked if the property type has the extension (and the extension isn't false)
+/// or if the type or one of its components has the extension with a list of property names.
+pub fn extension_property_names<E: ExtensionContainer>(
+    ty: &E,
+    properties: &PropertyTypes,
+    components: &[Component],
+    extension_ty: &ExtensionTypeId,
+) -> Vec<String> {
+    let mut names: Vec<String> = extension_container_property_names(ty, extension_ty);
+    for property_type in properties.iter().chain(components.iter().flat_map(|component| component.properties.iter())) {
+        if property_type
+            .get_own_extension(extension_ty)
+            .map(|extension| extension.extension != Value::Bool(false))
+            .unwrap_or(false)
+        {
+            names.push(property_type.name.clone());
+        }
+    }
+    for component in components {
+        names.extend(extension_container_property_names(component, extension_ty));
+    }
+    names.sort();
+    names.dedup();
+    names
+}
+
+fn extension_container_property_names<E: ExtensionContainer>(extension_container: &E, extension_ty: &ExtensionTypeId) -> Vec<String> {
+    extension_container
+        .get_own_extension(extension_ty)
+        .and_then(|extension| extension.extension.as_array().cloned())
+        .map(|names| names.iter().filter_map(|name| name.as_str().map(str::to_string)).collect())
+        .unwrap_or_default()
+}
+
+/// Returns the index key of the given value. Numbers with the same numeric value have the same
+/// key.
+pub fn index_key(value: &Value) -> String {
+    match value {
+        Value::Number(number) => {
+            if let Some(n) = number.as_i64() {
+                n.to_string()
+            } else if let Some(n) = number.as_u64() {
+                n.to_string()
+            } else {
+                match number.as_f64() {
+                    Some(n) if n.fract() == 0.0 && n >= i64::MIN as f64 && n <= i64::MAX as f64 => (n as i64).to_string(),
+                    _ => number.to_string(),
+                }
+            }
+        }
+        _ => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use serde_json::json;
+
+    use crate::PropertyIndex;
+    use crate::PropertyIndexes;
+    use crate::index_key;
+    use crate::indexed_property_names;
+    use reactive_graph_graph::Component;
+    use reactive_graph_graph::ComponentTypeId;
+    use reactive_graph_graph::EntityTypeId;
+    use reactive_graph_graph::Extension;
+    use reactive_graph_graph::Extensions;
+    use reactive_graph_graph::PropertyFilter;
+    use reactive_graph_graph::PropertyType;
+    use reactive_graph_graph::PropertyTypes;
+    use reactive_graph_runtime_model::EXTENSION_INDEX;
+
+    #[test]
+    fn index_key_test() {
+        assert_eq!(index_key(&json!(3)), index_key(&json!(3.0)));
+        assert_ne!(index_key(&json!(3)), index_key(&json!("3")));
+        assert_ne!(index_key(&json!(3)), index_key(&json!(3.5)));
+    }
+
+    #[test]
+    fn property_index_test() {
+        let index = PropertyIndex::<u32>::new();
+        index.update(1, &json!("a"));
+        index.update(2, &json!("a"));
+        index.update(3, &json!("b"));
+        let mut ids = index.lookup(&[&json!("a")]);
+        ids.sort();
+        assert_eq!(vec![1, 2], ids);
+        // Update the value
+        index.update(2, &json!("b"));
+        assert_eq!(vec![1], index.lookup(&[&json!("a")]));
+        assert_eq!(2, index.lookup(&[&json!("b")]).len());
+        assert_eq!(3, index.lookup(&[&json!("a"), &json!("b"), &json!("b")]).len());
+        index.remove(&1);
+        assert!(index.lookup(&[&json!("a")]).is_empty());
+    }
+
+    #[test]
+    fn property_index_concurrent_update_test() {
+        let index = Arc::new(PropertyIndex::<u32>::new());
+        let threads: Vec<_> = (0..8)
+            .map(|thread| {
+                let index = index.clone();
+                std::thread::spawn(move || {
+                    for i in 0..1000 {
+                        index.update(1, &json!((thread + i) % 4));
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        // The instance is in exactly one entry
+        assert_eq!(1, index.lookup(&[&json!(0), &json!(1), &json!(2), &json!(3)]).len());
+        let entries: usize = (0..4).map(|value| index.lookup(&[&json!(value)]).len()).sum();
+        assert_eq!(1, entries);
+    }
+
+    #[test]
+    fn property_indexes_test() {
+        let ty = EntityTypeId::new_from_type("test", "sensor");
+        let indexes = PropertyIndexes::<EntityTypeId, u32>::new();
+        let indexed_property_names = vec!["name".to_string()];
+        indexes.get_or_create(&ty, "name").update(1, &json!("sensor-1"));
+        indexes.get_or_create(&ty, "name").update(2, &json!("sensor-2"));
+
+        let filter: PropertyFilter = serde_json::from_value(json!({ "name": "name", "eq": "sensor-2" })).unwrap();
+        // The index is not complete yet
+        assert_eq!(None, indexes.lookup(&ty, &filter.compile().unwrap(), &indexed_property_names));
+        indexes.get_or_create(&ty, "name").set_complete();
+        assert_eq!(Some(vec![2]), indexes.lookup(&ty, &filter.compile().unwrap(), &indexed_property_names));
+        // The property is no longer indexed
+        assert_eq!(None, indexes.lookup(&ty, &filter.compile().unwrap(), &[]));
+        // No index for the property
+        let filter: PropertyFilter = serde_json::from_value(json!({ "name": "serial", "eq": 1 })).unwrap();
+        assert_eq!(None, indexes.lookup(&ty, &filter.compile().unwrap(), &indexed_property_names));
+        assert_eq!(vec!["name".to_string()], indexes.property_names(&ty));
+
+        indexes.remove(&ty, &2);
+        let statistics = indexes.statistics();
+        assert_eq!(1, statistics.len());
+        assert_eq!("name", statistics[0].ty.property);
+        assert_eq!(1, statistics[0].entries);
+        assert_eq!(1, statistics[0].lookups);
+    }
+
+    #[test]
+    fn property_indexes_retain_test() {
+        let ty = EntityTypeId::new_from_type("test", "sensor");
+        let indexes = PropertyIndexes::<EntityTypeId, u32>::new();
+        let indexed_property_names = vec!["name".to_string()];
+        let index = indexes.get_or_create(&ty, "name");
+        index.update(1, &json!("sensor"));
+        index.set_complete();
+        assert!(indexes.retain(&ty, &indexed_property_names).is_empty());
+
+        // The index extension has been removed
+        assert_eq!(vec!["name".to_string()], indexes.retain(&ty, &[]));
+        assert!(indexes.property_names(&ty).is_empty());
+
+        // The instance 2 is created while the property is not indexed and isn't added to any index
+        let filter: PropertyFilter = serde_json::from_value(json!({ "name": "name", "eq": "sensor" })).unwrap();
+        let filter = filter.compile().unwrap();
+
+        // The index extension has been added again: the new index is not complete until it has been backfilled
+        let index = indexes.get_or_create(&ty, "name");
+        assert!(!index.is_complete());
+        assert_eq!(None, indexes.lookup(&ty, &filter, &indexed_property_names));
+        index.update(1, &json!("sensor"));
+        index.update(2, &json!("sensor"));
+        index.set_complete();
+        let mut ids = indexes.lookup(&ty, &filter, &indexed_property_names).unwrap();
+        ids.sort();
+        assert_eq!(vec![1, 2], ids);
+    }
+
+    #[test]
+    fn indexed_property_names_test() {
+        let mut indexed_property = PropertyType::string("serial");
+        indexed_property.extensions = Extensions::new().extension(Extension::new(EXTENSION_INDEX.clone(), "", json!(true)));
+        let properties = PropertyTypes::new().property(indexed_property).property(PropertyType::string("description"));
+        let component = Component::builder()
+            .ty(ComponentTypeId::new_from_type("test", "named"))
+            .properties(PropertyTypes::new().property(PropertyType::string("name")))
+            .extensions(Extensions::new().extension(Extension::new(EXTENSION_INDEX.clone(), "", json!(["name"]))))
+            .build();
+        let entity_type_extensions = Extensions::new();
+        assert_eq!(
+            vec!["name".to_string(), "serial".to_string()],
+            indexed_property_names(&entity_type_extensions, &properties, &[component])
+        );
+    }
+}
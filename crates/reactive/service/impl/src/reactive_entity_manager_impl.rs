@@ -20,12 +20,16 @@ use reactive_graph_behaviour_model_api::ComponentBehaviourTypeId;
 use reactive_graph_behaviour_model_api::EntityBehaviourTypeId;
 use reactive_graph_behaviour_service_api::EntityBehaviourManager;
 use reactive_graph_behaviour_service_api::EntityComponentBehaviourManager;
+use reactive_graph_graph::AggregationGroup;
+use reactive_graph_graph::AggregationGroupBy;
 use reactive_graph_graph::ComponentContainer;
 use reactive_graph_graph::ComponentTypeId;
 use reactive_graph_graph::EntityInstance;
 use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::LabelPattern;
 use reactive_graph_graph::Mutability;
 use reactive_graph_graph::NamespacedTypeGetter;
+use reactive_graph_graph::PropertyFilterExpression;
 use reactive_graph_graph::PropertyInstanceGetter;
 use reactive_graph_graph::PropertyInstances;
 use reactive_graph_graph::PropertyTypeContainer;
@@ -33,9 +37,11 @@ use reactive_graph_graph::PropertyTypeDefinition;
 use reactive_graph_graph::TypeDefinitionComponent;
 use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_graph::TypeDefinitionProperty;
+use reactive_graph_graph::aggregate_instances;
 use reactive_graph_lifecycle::Lifecycle;
 use reactive_graph_reactive_model_api::ReactivePropertyContainer;
 use reactive_graph_reactive_model_impl::ReactiveEntity;
+use reactive_graph_reactive_service_api::PropertyIndexStatistics;
 use reactive_graph_reactive_service_api::ReactiveEntityComponentAddError;
 use reactive_graph_reactive_service_api::ReactiveEntityCreationError;
 use reactive_graph_reactive_service_api::ReactiveEntityManager;
@@ -53,6 +59,11 @@ use reactive_graph_type_system_api::TypeSystemEventManager;
 use reactive_graph_type_system_api::TypeSystemEventSubscriber;
 use reactive_graph_type_system_api::TypeSystemEventTypes;
 
+use crate::HANDLE_ID_PROPERTY_INDEX;
+use crate::PropertyIndex;
+use crate::PropertyIndexes;
+use crate::indexed_property_names;
+
 static HANDLE_ID_ENTITY_TYPE_COMPONENT_ADDED: u128 = 0x6ba7b8109e1513d350b300c04fe530c7;
 static HANDLE_ID_ENTITY_TYPE_COMPONENT_REMOVED: u128 = 0x6ba8b8119e1513d350b300c04fe630c7;
 static HANDLE_ID_ENTITY_TYPE_PROPERTY_ADDED: u128 = 0x6ba7b8109e2613d350b300c04fe640c7;
@@ -95,6 +106,9 @@ pub struct ReactiveEntityManagerImpl {
     #[component(default = "create_label_path_tree")]
     label_path_tree: RwLock<PathTree<Uuid>>,
 
+    #[component(default = "PropertyIndexes::new")]
+    property_indexes: PropertyIndexes<EntityTypeId, Uuid>,
+
     #[component(default = "create_running_state")]
     running: Arc<AtomicBool>,
 
@@ -103,6 +117,74 @@ pub struct ReactiveEntityManagerImpl {
     // TODO: Type Cache
 }
 
+impl ReactiveEntityManagerImpl {
+    /// Returns the names of the indexed properties of the given entity type.
+    fn get_indexed_property_names(&self, ty: &EntityTypeId) -> Vec<String> {
+        let Some(entity_type) = self.entity_type_manager.get(ty) else {
+            return Vec::new();
+        };
+        let components: Vec<reactive_graph_graph::Component> = entity_type
+            .components
+            .iter()
+            .filter_map(|component_ty| self.component_manager.get(component_ty.key()))
+            .collect();
+        indexed_property_names(&entity_type, &entity_type.properties, &components)
+    }
+
+    /// Returns the index of the property of the entity type. If the index is not complete yet, the
+    /// existing instances of the entity type are added to the index.
+    fn get_or_backfill_index(&self, ty: &EntityTypeId, property_name: &str) -> Arc<PropertyIndex<Uuid>> {
+        let index = self.property_indexes.get_or_create(ty, property_name);
+        if !index.is_complete() {
+            for reactive_entity in self.get_by_type(ty) {
+                observe_indexed_property(&index, &reactive_entity, property_name);
+            }
+            index.set_complete();
+        }
+        index
+    }
+
+    /// Drops the indexes of the properties which are no longer indexed, because the index
+    /// extension has been removed. The indexes are created and backfilled again, if the
+    /// properties are indexed again.
+    fn drop_unindexed_properties(&self, ty: &EntityTypeId, indexed_property_names: &[String]) {
+        for property_name in self.property_indexes.retain(ty, indexed_property_names) {
+            for reactive_entity in self.get_by_type(ty) {
+                reactive_entity.remove_observer(&property_name, HANDLE_ID_PROPERTY_INDEX);
+            }
+        }
+    }
+
+    /// Adds the reactive entity to the property indexes and keeps the indexes up to date.
+    fn index_reactive_instance(&self, reactive_entity: &ReactiveEntity) {
+        let indexed_property_names = self.get_indexed_property_names(&reactive_entity.ty);
+        self.drop_unindexed_properties(&reactive_entity.ty, &indexed_property_names);
+        for property_name in indexed_property_names {
+            let index = self.get_or_backfill_index(&reactive_entity.ty, &property_name);
+            observe_indexed_property(&index, reactive_entity, &property_name);
+        }
+    }
+
+    /// Removes the reactive entity from the property indexes.
+    fn unindex_reactive_instance(&self, reactive_entity: &ReactiveEntity) {
+        for property_name in self.property_indexes.property_names(&reactive_entity.ty) {
+            reactive_entity.remove_observer(&property_name, HANDLE_ID_PROPERTY_INDEX);
+        }
+        self.property_indexes.remove(&reactive_entity.ty, &reactive_entity.id);
+    }
+}
+
+/// Adds the value of the property of the reactive entity to the index and keeps the index up to date.
+fn observe_indexed_property(index: &Arc<PropertyIndex<Uuid>>, reactive_entity: &ReactiveEntity, property_name: &str) {
+    let Some(value) = reactive_entity.get(property_name) else {
+        return;
+    };
+    index.update(reactive_entity.id, &value);
+    let index = index.clone();
+    let id = reactive_entity.id;
+    reactive_entity.observe_with_handle(property_name, move |value: &Value| index.update(id, value), HANDLE_ID_PROPERTY_INDEX);
+}
+
 #[async_trait]
 #[component_alias]
 impl ReactiveEntityManager for ReactiveEntityManagerImpl {
@@ -130,6 +212,20 @@ impl ReactiveEntityManager for ReactiveEntityManagerImpl {
         })
     }
 
+    fn get_by_label_pattern(&self, pattern: &LabelPattern) -> Vec<(ReactiveEntity, HashMap<String, String>)> {
+        let mut matches: Vec<(String, ReactiveEntity, HashMap<String, String>)> = self
+            .reactive_entity_instances
+            .iter()
+            .filter_map(|e| {
+                let label = e.value().get(LABEL.property_name()).and_then(|v| v.as_str().map(|s| s.to_string()))?;
+                let params = pattern.matches(&label)?;
+                Some((label, e.value().clone(), params))
+            })
+            .collect();
+        matches.sort_by(|a, b| a.0.cmp(&b.0));
+        matches.into_iter().map(|(_, entity, params)| (entity, params)).collect()
+    }
+
     fn get_all(&self) -> Vec<ReactiveEntity> {
         self.reactive_entity_instances.iter().map(|e| e.value().clone()).collect()
     }
@@ -142,6 +238,29 @@ impl ReactiveEntityManager for ReactiveEntityManagerImpl {
             .collect()
     }
 
+    fn get_by_type_and_filter(&self, ty: &EntityTypeId, filter: &PropertyFilterExpression) -> Vec<ReactiveEntity> {
+        // Indexes which have been removed are dropped and indexes which have been added to the
+        // entity type or its components are backfilled first
+        let indexed_property_names = self.get_indexed_property_names(ty);
+        self.drop_unindexed_properties(ty, &indexed_property_names);
+        for property_name in indexed_property_names.iter() {
+            self.get_or_backfill_index(ty, property_name);
+        }
+        match self.property_indexes.lookup(ty, filter, &indexed_property_names) {
+            Some(ids) => ids
+                .into_iter()
+                .filter_map(|id| self.get(id))
+                .filter(|e| &e.ty == ty && filter.matches(e))
+                .collect(),
+            None => self
+                .reactive_entity_instances
+                .iter()
+                .filter(|e| &e.ty == ty && filter.matches(e.value()))
+                .map(|e| e.value().clone())
+                .collect(),
+        }
+    }
+
     fn get_by_component(&self, ty: &ComponentTypeId) -> Vec<ReactiveEntity> {
         self.reactive_entity_instances
             .iter()
@@ -166,6 +285,10 @@ impl ReactiveEntityManager for ReactiveEntityManagerImpl {
             .collect()
     }
 
+    fn get_property_index_statistics(&self) -> Vec<PropertyIndexStatistics> {
+        self.property_indexes.statistics()
+    }
+
     fn count(&self) -> usize {
         self.reactive_entity_instances.len()
     }
@@ -182,6 +305,33 @@ impl ReactiveEntityManager for ReactiveEntityManagerImpl {
         self.reactive_entity_instances.iter().filter(|e| e.behaves_as(behaviour_ty)).count()
     }
 
+    fn count_by_namespace(&self, namespace: &str) -> usize {
+        self.reactive_entity_instances.iter().filter(|e| e.namespace() == namespace).count()
+    }
+
+    fn aggregate(
+        &self,
+        ty: Option<&EntityTypeId>,
+        component_ty: Option<&ComponentTypeId>,
+        namespace: Option<&str>,
+        filter: Option<&PropertyFilterExpression>,
+        group_by: Option<&AggregationGroupBy>,
+        property: Option<&str>,
+    ) -> Vec<AggregationGroup> {
+        let reactive_entities = match (ty, filter) {
+            (Some(ty), Some(filter)) => self.get_by_type_and_filter(ty, filter),
+            (Some(ty), None) => self.get_by_type(ty),
+            (None, _) => self.get_all(),
+        };
+        let reactive_entities = reactive_entities.into_iter().filter(|e| {
+            component_ty.is_none_or(|component_ty| e.is_a(component_ty))
+                && namespace.is_none_or(|namespace| e.namespace() == namespace)
+                && filter.is_none_or(|filter| filter.matches(e))
+        });
+        let keys = group_by.map(|group_by| move |e: &ReactiveEntity| group_by.keys(e, &e.ty));
+        aggregate_instances(reactive_entities, keys, property)
+    }
+
     fn get_ids(&self) -> Vec<Uuid> {
         self.reactive_entity_instances.iter().map(|e| *e.key()).collect()
     }
@@ -248,6 +398,8 @@ impl ReactiveEntityManager for ReactiveEntityManagerImpl {
             let mut writer = self.label_path_tree.write().unwrap();
             let _ = writer.insert(&value, reactive_entity.id);
         }
+        // Add to the property indexes
+        self.index_reactive_instance(&reactive_entity);
         self.reactive_instance_event_manager
             .emit_event(ReactiveInstanceEvent::EntityInstanceCreated(reactive_entity.id));
         Ok(reactive_entity)
@@ -407,6 +559,8 @@ impl ReactiveEntityManager for ReactiveEntityManagerImpl {
     fn unregister_reactive_instance(&self, id: Uuid) -> bool {
         match self.get(id) {
             Some(entity_instance) => {
+                // Remove from the property indexes
+                self.unindex_reactive_instance(&entity_instance);
                 // Remove entity behaviours
                 self.entity_behaviour_manager.remove_behaviours(entity_instance.clone());
                 // Remove entity component behaviours
@@ -625,10 +779,16 @@ mod tests {
     use reactive_graph_behaviour_service_impl::BehaviourSystemImpl;
     use reactive_graph_graph::EntityType;
     use reactive_graph_graph::EntityTypeId;
+    use reactive_graph_graph::Extension;
+    use reactive_graph_graph::PropertyFilter;
+    use reactive_graph_graph::PropertyInstances;
+    use reactive_graph_graph::PropertyType;
     use reactive_graph_graph::PropertyTypes;
     use reactive_graph_reactive_model_impl::ReactiveEntity;
     use reactive_graph_reactive_service_api::ReactiveSystem;
+    use reactive_graph_runtime_model::EXTENSION_INDEX;
     use reactive_graph_utils_test::r_string;
+    use serde_json::json;
     // Do not remove! This import is necessary to make the dependency injection work
     #[allow(unused_imports)]
     use reactive_graph_type_system_impl::TypeSystemImpl;
@@ -746,4 +906,50 @@ mod tests {
         assert!(reactive_entity_manager.has(id), "The reactive entity with id should be registered!");
         assert_eq!(reactive_entity_manager.count(), 1);
     }
+
+    #[test]
+    fn test_readd_index_extension() {
+        reactive_graph_utils_test::init_logger();
+
+        let reactive_system = reactive_graph_di::get_container::<ReactiveSystemImpl>();
+        let type_system = reactive_system.type_system();
+        let entity_type_manager = type_system.get_entity_type_manager();
+        let reactive_entity_manager = reactive_system.get_reactive_entity_manager();
+
+        let ty = EntityTypeId::new_from_type("test", "sensor");
+        let index_extension = Extension::new(EXTENSION_INDEX.clone(), "", json!(["name"]));
+        let entity_type = EntityType::builder()
+            .ty(ty.clone())
+            .properties(PropertyTypes::new().property(PropertyType::string("name")))
+            .build();
+        entity_type_manager.register(entity_type).expect("Failed to register entity type");
+        entity_type_manager
+            .add_extension(&ty, index_extension.clone())
+            .expect("Failed to add the index extension");
+
+        let filter: PropertyFilter = serde_json::from_value(json!({ "name": "name", "eq": "sensor" })).unwrap();
+        let filter = filter.compile().unwrap();
+        let properties = PropertyInstances::new().property("name", json!("sensor"));
+        let id_1 = reactive_entity_manager.create_reactive_entity(&ty, properties.clone()).unwrap().id;
+        assert_eq!(1, reactive_entity_manager.get_by_type_and_filter(&ty, &filter).len());
+        assert_eq!(1, reactive_entity_manager.get_property_index_statistics().len());
+
+        // Remove the index extension and create an instance which is not indexed
+        entity_type_manager
+            .remove_extension(&ty, &EXTENSION_INDEX)
+            .expect("Failed to remove the index extension");
+        let id_2 = reactive_entity_manager.create_reactive_entity(&ty, properties).unwrap().id;
+        assert!(reactive_entity_manager.get_property_index_statistics().is_empty());
+
+        // Add the index extension again: the index is backfilled with both instances
+        entity_type_manager
+            .add_extension(&ty, index_extension)
+            .expect("Failed to add the index extension");
+        let mut ids: Vec<_> = reactive_entity_manager.get_by_type_and_filter(&ty, &filter).iter().map(|e| e.id).collect();
+        ids.sort();
+        let mut expected = vec![id_1, id_2];
+        expected.sort();
+        assert_eq!(expected, ids);
+        assert_eq!(2, reactive_entity_manager.get_property_index_statistics()[0].entries);
+    }
 }
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::FlowTypeId;
+use reactive_graph_graph::RelationTypeId;
+use reactive_graph_graph::TypeDefinition;
+use reactive_graph_graph::TypeIdType;
+use reactive_graph_reactive_model_api::ReactiveInstance;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+use reactive_graph_reactive_service_api::ReactiveEntityManager;
+use reactive_graph_reactive_service_api::ReactiveFlowManager;
+use reactive_graph_reactive_service_api::ReactiveRelationManager;
+use reactive_graph_type_system_api::TypeInstanceReferenceProvider;
+
+pub const REACTIVE_INSTANCE_TYPE_REFERENCE_PROVIDER_ID: &str = "reactive_instances";
+
+/// Reports the reactive instances which are referencing a type to the type dependency manager.
+pub struct ReactiveInstanceTypeReferenceProvider {
+    reactive_entity_manager: Arc<dyn ReactiveEntityManager + Send + Sync>,
+    reactive_relation_manager: Arc<dyn ReactiveRelationManager + Send + Sync>,
+    reactive_flow_manager: Arc<dyn ReactiveFlowManager + Send + Sync>,
+}
+
+impl ReactiveInstanceTypeReferenceProvider {
+    pub fn new(
+        reactive_entity_manager: Arc<dyn ReactiveEntityManager + Send + Sync>,
+        reactive_relation_manager: Arc<dyn ReactiveRelationManager + Send + Sync>,
+        reactive_flow_manager: Arc<dyn ReactiveFlowManager + Send + Sync>,
+    ) -> Self {
+        Self {
+            reactive_entity_manager,
+            reactive_relation_manager,
+            reactive_flow_manager,
+        }
+    }
+
+    /// Deletes the given entity instances and the relation instances which are connected with them.
+    fn delete_entities(&self, reactive_entities: Vec<ReactiveEntity>) -> usize {
+        let mut deleted = 0;
+        for reactive_entity in reactive_entities {
+            let relations = self
+                .reactive_relation_manager
+                .get_by_outbound_entity(reactive_entity.id)
+                .into_iter()
+                .chain(self.reactive_relation_manager.get_by_inbound_entity(reactive_entity.id));
+            for reactive_relation in relations {
+                if self.reactive_relation_manager.delete(&reactive_relation.id()) {
+                    deleted += 1;
+                }
+            }
+            if self.reactive_entity_manager.delete(reactive_entity.id) {
+                deleted += 1;
+            }
+        }
+        deleted
+    }
+}
+
+impl TypeInstanceReferenceProvider for ReactiveInstanceTypeReferenceProvider {
+    fn id(&self) -> &str {
+        REACTIVE_INSTANCE_TYPE_REFERENCE_PROVIDER_ID
+    }
+
+    fn count_references(&self, ty: &TypeDefinition) -> usize {
+        match ty.type_id_type {
+            TypeIdType::Component => ComponentTypeId::try_from(ty)
+                .map(|ty| self.reactive_entity_manager.count_by_component(&ty) + self.reactive_relation_manager.count_by_component(&ty))
+                .unwrap_or(0),
+            TypeIdType::EntityType => EntityTypeId::try_from(ty)
+                .map(|ty| self.reactive_entity_manager.count_by_type(&ty))
+                .unwrap_or(0),
+            TypeIdType::RelationType => RelationTypeId::try_from(ty)
+                .map(|ty| self.reactive_relation_manager.count_by_type(&ty))
+                .unwrap_or(0),
+            TypeIdType::FlowType => FlowTypeId::try_from(ty)
+                .map(|ty| self.reactive_flow_manager.get_by_type(&ty).len())
+                .unwrap_or(0),
+            _ => 0,
+        }
+    }
+
+    fn delete_references(&self, ty: &TypeDefinition) -> usize {
+        match ty.type_id_type {
+            TypeIdType::Component => {
+                let Ok(ty) = ComponentTypeId::try_from(ty) else {
+                    return 0;
+                };
+                let deleted_relations = self
+                    .reactive_relation_manager
+                    .get_by_component(&ty)
+                    .into_iter()
+                    .filter(|reactive_relation| self.reactive_relation_manager.delete(&reactive_relation.id()))
+                    .count();
+                deleted_relations + self.delete_entities(self.reactive_entity_manager.get_by_component(&ty))
+            }
+            TypeIdType::EntityType => {
+                let Ok(ty) = EntityTypeId::try_from(ty) else {
+                    return 0;
+                };
+                self.delete_entities(self.reactive_entity_manager.get_by_type(&ty))
+            }
+            TypeIdType::RelationType => {
+                let Ok(ty) = RelationTypeId::try_from(ty) else {
+                    return 0;
+                };
+                self.reactive_relation_manager
+                    .get_by_type(&ty)
+                    .into_iter()
+                    .filter(|reactive_relation| self.reactive_relation_manager.delete(&reactive_relation.id()))
+                    .count()
+            }
+            TypeIdType::FlowType => {
+                let Ok(ty) = FlowTypeId::try_from(ty) else {
+                    return 0;
+                };
+                self.reactive_flow_manager
+                    .get_by_type(&ty)
+                    .into_iter()
+                    .filter(|reactive_flow| self.reactive_flow_manager.delete(reactive_flow.id))
+                    .count()
+            }
+            _ => 0,
+        }
+    }
+}
@@ -7,6 +7,7 @@ use async_trait::async_trait;
 use dashmap::DashMap;
 use dashmap::DashSet;
 use reactive_graph_reactive_service_api::EventChannels;
+use reactive_graph_reactive_service_api::PropertyIndexStatistics;
 use reactive_graph_reactive_service_api::ReactiveInstanceEvent;
 use reactive_graph_reactive_service_api::ReactiveInstanceEventManager;
 use reactive_graph_reactive_service_api::ReactiveRelationComponentAddError;
@@ -27,11 +28,15 @@ use reactive_graph_behaviour_model_api::ComponentBehaviourTypeId;
 use reactive_graph_behaviour_model_api::RelationBehaviourTypeId;
 use reactive_graph_behaviour_service_api::RelationBehaviourManager;
 use reactive_graph_behaviour_service_api::RelationComponentBehaviourManager;
+use reactive_graph_graph::AggregationGroup;
+use reactive_graph_graph::AggregationGroupBy;
 use reactive_graph_graph::ComponentContainer;
 use reactive_graph_graph::ComponentOrEntityTypeId;
 use reactive_graph_graph::ComponentTypeId;
 use reactive_graph_graph::Mutability;
 use reactive_graph_graph::NamespacedTypeGetter;
+use reactive_graph_graph::PropertyFilterExpression;
+use reactive_graph_graph::PropertyInstanceGetter;
 use reactive_graph_graph::PropertyInstances;
 use reactive_graph_graph::PropertyTypeContainer;
 use reactive_graph_graph::PropertyTypeDefinition;
@@ -41,6 +46,7 @@ use reactive_graph_graph::RelationTypeId;
 use reactive_graph_graph::TypeDefinitionComponent;
 use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_graph::TypeDefinitionProperty;
+use reactive_graph_graph::aggregate_instances;
 use reactive_graph_lifecycle::Lifecycle;
 use reactive_graph_reactive_model_api::ReactiveInstance;
 use reactive_graph_reactive_model_api::ReactivePropertyContainer;
@@ -55,6 +61,11 @@ use reactive_graph_type_system_api::TypeSystemEventSubscriber;
 use reactive_graph_type_system_api::TypeSystemEventTypes;
 use springtime_di::component_alias;
 
+use crate::HANDLE_ID_PROPERTY_INDEX;
+use crate::PropertyIndex;
+use crate::PropertyIndexes;
+use crate::indexed_property_names;
+
 static HANDLE_ID_RELATION_TYPE_COMPONENT_ADDED: u128 = 0x6ba7b9210e1513d350b300c04fe530c7;
 static HANDLE_ID_RELATION_TYPE_COMPONENT_REMOVED: u128 = 0x6ba8b8119e1513ee59b300c04fe630c7;
 static HANDLE_ID_RELATION_TYPE_PROPERTY_ADDED: u128 = 0x6bb9b9232e1513d350b300c04fe530c7;
@@ -173,6 +184,79 @@ pub struct ReactiveRelationManagerImpl {
 
     #[component(default = "create_event_channels")]
     event_channels: EventChannels,
+
+    #[component(default = "PropertyIndexes::new")]
+    property_indexes: PropertyIndexes<RelationTypeId, RelationInstanceId>,
+}
+
+impl ReactiveRelationManagerImpl {
+    /// Returns the names of the indexed properties of the given relation type.
+    fn get_indexed_property_names(&self, ty: &RelationTypeId) -> Vec<String> {
+        let Some(relation_type) = self.relation_type_manager.get(ty) else {
+            return Vec::new();
+        };
+        let components: Vec<reactive_graph_graph::Component> = relation_type
+            .components
+            .iter()
+            .filter_map(|component_ty| self.component_manager.get(component_ty.key()))
+            .collect();
+        indexed_property_names(&relation_type, &relation_type.properties, &components)
+    }
+
+    /// Returns the index of the property of the relation type. If the index is not complete yet, the
+    /// existing instances of the relation type are added to the index.
+    fn get_or_backfill_index(&self, ty: &RelationTypeId, property_name: &str) -> Arc<PropertyIndex<RelationInstanceId>> {
+        let index = self.property_indexes.get_or_create(ty, property_name);
+        if !index.is_complete() {
+            for reactive_relation in self.get_by_type(ty) {
+                observe_indexed_property(&index, &reactive_relation, property_name);
+            }
+            index.set_complete();
+        }
+        index
+    }
+
+    /// Drops the indexes of the properties which are no longer indexed, because the index
+    /// extension has been removed. The indexes are created and backfilled again, if the
+    /// properties are indexed again.
+    fn drop_unindexed_properties(&self, ty: &RelationTypeId, indexed_property_names: &[String]) {
+        for property_name in self.property_indexes.retain(ty, indexed_property_names) {
+            for reactive_relation in self.get_by_type(ty) {
+                reactive_relation.remove_observer(&property_name, HANDLE_ID_PROPERTY_INDEX);
+            }
+        }
+    }
+
+    /// Adds the reactive relation to the property indexes and keeps the indexes up to date.
+    fn index_reactive_instance(&self, reactive_relation: &ReactiveRelation) {
+        let ty = reactive_relation.relation_type_id();
+        let indexed_property_names = self.get_indexed_property_names(&ty);
+        self.drop_unindexed_properties(&ty, &indexed_property_names);
+        for property_name in indexed_property_names {
+            let index = self.get_or_backfill_index(&ty, &property_name);
+            observe_indexed_property(&index, reactive_relation, &property_name);
+        }
+    }
+
+    /// Removes the reactive relation from the property indexes.
+    fn unindex_reactive_instance(&self, reactive_relation: &ReactiveRelation) {
+        let ty = reactive_relation.relation_type_id();
+        for property_name in self.property_indexes.property_names(&ty) {
+            reactive_relation.remove_observer(&property_name, HANDLE_ID_PROPERTY_INDEX);
+        }
+        self.property_indexes.remove(&ty, &reactive_relation.id());
+    }
+}
+
+/// Adds the value of the property of the reactive relation to the index and keeps the index up to date.
+fn observe_indexed_property(index: &Arc<PropertyIndex<RelationInstanceId>>, reactive_relation: &ReactiveRelation, property_name: &str) {
+    let Some(value) = reactive_relation.get(property_name) else {
+        return;
+    };
+    let id = reactive_relation.id();
+    index.update(id.clone(), &value);
+    let index = index.clone();
+    reactive_relation.observe_with_handle(property_name, move |value: &Value| index.update(id.clone(), value), HANDLE_ID_PROPERTY_INDEX);
 }
 
 #[async_trait]
@@ -212,6 +296,29 @@ impl ReactiveRelationManager for ReactiveRelationManagerImpl {
             .collect()
     }
 
+    fn get_by_type_and_filter(&self, ty: &RelationTypeId, filter: &PropertyFilterExpression) -> Vec<ReactiveRelation> {
+        // Indexes which have been removed are dropped and indexes which have been added to the
+        // relation type or its components are backfilled first
+        let indexed_property_names = self.get_indexed_property_names(ty);
+        self.drop_unindexed_properties(ty, &indexed_property_names);
+        for property_name in indexed_property_names.iter() {
+            self.get_or_backfill_index(ty, property_name);
+        }
+        match self.property_indexes.lookup(ty, filter, &indexed_property_names) {
+            Some(ids) => ids
+                .into_iter()
+                .filter_map(|id| self.get(&id))
+                .filter(|r| &r.relation_type_id() == ty && filter.matches(r))
+                .collect(),
+            None => self
+                .reactive_relation_instances
+                .iter()
+                .filter(|r| &r.relation_type_id() == ty && filter.matches(r.value()))
+                .map(|r| r.value().clone())
+                .collect(),
+        }
+    }
+
     fn get_by_component(&self, ty: &ComponentTypeId) -> Vec<ReactiveRelation> {
         self.reactive_relation_instances
             .iter()
@@ -252,10 +359,41 @@ impl ReactiveRelationManager for ReactiveRelationManagerImpl {
         self.reactive_relation_instances.iter().filter(|r| r.behaves_as(behaviour_ty)).count()
     }
 
+    fn count_by_namespace(&self, namespace: &str) -> usize {
+        self.reactive_relation_instances.iter().filter(|r| r.namespace() == namespace).count()
+    }
+
+    fn aggregate(
+        &self,
+        ty: Option<&RelationTypeId>,
+        component_ty: Option<&ComponentTypeId>,
+        namespace: Option<&str>,
+        filter: Option<&PropertyFilterExpression>,
+        group_by: Option<&AggregationGroupBy>,
+        property: Option<&str>,
+    ) -> Vec<AggregationGroup> {
+        let reactive_relations = match (ty, filter) {
+            (Some(ty), Some(filter)) => self.get_by_type_and_filter(ty, filter),
+            (Some(ty), None) => self.get_by_type(ty),
+            (None, _) => self.get_all(),
+        };
+        let reactive_relations = reactive_relations.into_iter().filter(|r| {
+            component_ty.is_none_or(|component_ty| r.is_a(component_ty))
+                && namespace.is_none_or(|namespace| r.namespace() == namespace)
+                && filter.is_none_or(|filter| filter.matches(r))
+        });
+        let keys = group_by.map(|group_by| move |r: &ReactiveRelation| group_by.keys(r, &r.relation_type_id()));
+        aggregate_instances(reactive_relations, keys, property)
+    }
+
     fn get_relation_instance_ids(&self) -> Vec<RelationInstanceId> {
         self.reactive_relation_instances.iter().map(|e| e.key().clone()).collect()
     }
 
+    fn get_property_index_statistics(&self) -> Vec<PropertyIndexStatistics> {
+        self.property_indexes.statistics()
+    }
+
     fn create_reactive_relation(&self, id: &RelationInstanceId, properties: PropertyInstances) -> Result<ReactiveRelation, ReactiveRelationCreationError> {
         let relation_instance = RelationInstance::builder()
             .outbound_id(id.outbound_id)
@@ -378,6 +516,8 @@ impl ReactiveRelationManager for ReactiveRelationManagerImpl {
                 reactive_relation.components.insert(component_ty.clone());
             });
         }
+        // Add to the property indexes
+        self.index_reactive_instance(&reactive_relation);
         // Add component behaviours
         self.relation_component_behaviour_manager.add_behaviours_to_relation(reactive_relation.clone());
         // Add relation behaviours
@@ -538,6 +678,8 @@ impl ReactiveRelationManager for ReactiveRelationManagerImpl {
     fn unregister_reactive_instance(&self, id: &RelationInstanceId) {
         match self.get(id) {
             Some(relation_instance) => {
+                // Remove from the property indexes
+                self.unindex_reactive_instance(&relation_instance);
                 // Remove relation behaviours
                 self.relation_behaviour_manager.remove_behaviours(relation_instance.clone());
                 // Remove component behaviours
@@ -6,19 +6,32 @@ use springtime_di::Component;
 use springtime_di::component_alias;
 
 use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_reactive_service_api::ComputedPropertyManager;
+use reactive_graph_reactive_service_api::GraphQueryManager;
+use reactive_graph_reactive_service_api::GraphTraversalManager;
 use reactive_graph_reactive_service_api::ReactiveEntityManager;
 use reactive_graph_reactive_service_api::ReactiveFlowManager;
 use reactive_graph_reactive_service_api::ReactiveInstanceEventManager;
 use reactive_graph_reactive_service_api::ReactiveRelationManager;
 use reactive_graph_reactive_service_api::ReactiveSystem;
+use reactive_graph_reactive_service_api::SavedQueryManager;
+use reactive_graph_reactive_service_api::SearchManager;
 use reactive_graph_type_system_api::TypeSystem;
 
+use crate::REACTIVE_INSTANCE_TYPE_REFERENCE_PROVIDER_ID;
+use crate::ReactiveInstanceTypeReferenceProvider;
+
 #[derive(Component)]
 pub struct ReactiveSystemImpl {
     reactive_entity_manager: Arc<dyn ReactiveEntityManager + Send + Sync>,
     reactive_relation_manager: Arc<dyn ReactiveRelationManager + Send + Sync>,
     reactive_flow_manager: Arc<dyn ReactiveFlowManager + Send + Sync>,
     reactive_instance_event_manager: Arc<dyn ReactiveInstanceEventManager + Send + Sync>,
+    graph_query_manager: Arc<dyn GraphQueryManager + Send + Sync>,
+    graph_traversal_manager: Arc<dyn GraphTraversalManager + Send + Sync>,
+    search_manager: Arc<dyn SearchManager + Send + Sync>,
+    computed_property_manager: Arc<dyn ComputedPropertyManager + Send + Sync>,
+    saved_query_manager: Arc<dyn SavedQueryManager + Send + Sync>,
 
     type_system: Arc<dyn TypeSystem + Send + Sync>,
     behaviour_system: Arc<dyn BehaviourSystem + Send + Sync>,
@@ -43,6 +56,26 @@ impl ReactiveSystem for ReactiveSystemImpl {
         self.reactive_instance_event_manager.clone()
     }
 
+    fn get_graph_query_manager(&self) -> Arc<dyn GraphQueryManager + Send + Sync> {
+        self.graph_query_manager.clone()
+    }
+
+    fn get_graph_traversal_manager(&self) -> Arc<dyn GraphTraversalManager + Send + Sync> {
+        self.graph_traversal_manager.clone()
+    }
+
+    fn get_search_manager(&self) -> Arc<dyn SearchManager + Send + Sync> {
+        self.search_manager.clone()
+    }
+
+    fn get_computed_property_manager(&self) -> Arc<dyn ComputedPropertyManager + Send + Sync> {
+        self.computed_property_manager.clone()
+    }
+
+    fn get_saved_query_manager(&self) -> Arc<dyn SavedQueryManager + Send + Sync> {
+        self.saved_query_manager.clone()
+    }
+
     fn type_system(&self) -> Arc<dyn TypeSystem + Send + Sync> {
         self.type_system.clone()
     }
@@ -59,6 +92,13 @@ impl Lifecycle for ReactiveSystemImpl {
         self.reactive_relation_manager.init().await;
         self.reactive_flow_manager.init().await;
         self.reactive_instance_event_manager.init().await;
+        self.type_system
+            .get_type_dependency_manager()
+            .register_instance_reference_provider(Arc::new(ReactiveInstanceTypeReferenceProvider::new(
+                self.reactive_entity_manager.clone(),
+                self.reactive_relation_manager.clone(),
+                self.reactive_flow_manager.clone(),
+            )));
     }
 
     async fn post_init(&self) {
@@ -66,9 +106,18 @@ impl Lifecycle for ReactiveSystemImpl {
         self.reactive_relation_manager.post_init().await;
         self.reactive_flow_manager.post_init().await;
         self.reactive_instance_event_manager.post_init().await;
+        self.search_manager.post_init().await;
+        self.computed_property_manager.post_init().await;
+        self.saved_query_manager.post_init().await;
     }
 
     async fn pre_shutdown(&self) {
+        self.saved_query_manager.pre_shutdown().await;
+        self.computed_property_manager.pre_shutdown().await;
+        self.search_manager.pre_shutdown().await;
+        self.type_system
+            .get_type_dependency_manager()
+            .unregister_instance_reference_provider(REACTIVE_INSTANCE_TYPE_REFERENCE_PROVIDER_ID);
         self.reactive_instance_event_manager.pre_shutdown().await;
         self.reactive_flow_manager.pre_shutdown().await;
         self.reactive_relation_manager.pre_shutdown().await;
@@ -0,0 +1,388 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::sync::Weak;
+
+use async_trait::async_trait;
+use crossbeam::channel::Receiver;
+use crossbeam::channel::Sender;
+use dashmap::DashMap;
+use dashmap::mapref::entry::Entry;
+use serde_json::Value;
+use springtime_di::Component;
+use springtime_di::component_alias;
+use uuid::Uuid;
+
+use reactive_graph_graph::SavedQuery;
+use reactive_graph_graph::SavedQueryChange;
+use reactive_graph_graph::SavedQueryResultSet;
+use reactive_graph_graph::SavedQueryRow;
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_reactive_model_api::ReactivePropertyContainer;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+use reactive_graph_reactive_service_api::ReactiveEntityManager;
+use reactive_graph_reactive_service_api::ReactiveInstanceEventManager;
+use reactive_graph_reactive_service_api::ReactiveInstanceEventTypes;
+use reactive_graph_reactive_service_api::SavedQueryManager;
+use reactive_graph_reactive_service_api::SavedQueryRegistrationError;
+use reactive_graph_runtime_model::EventProperties::EVENT;
+
+/// The handle id of the observers of the reactive instance events which are keeping the
+/// saved queries up to date.
+pub const HANDLE_ID_SAVED_QUERIES: u128 = 0x2d9b4f6e8a1c4b3d9e7f6a5b4c3d2e1f;
+
+fn create_saved_queries() -> Arc<DashMap<String, Arc<MaterializedSavedQuery>>> {
+    Arc::new(DashMap::new())
+}
+
+#[derive(Component)]
+pub struct SavedQueryManagerImpl {
+    reactive_entity_manager: Arc<dyn ReactiveEntityManager + Send + Sync>,
+
+    reactive_instance_event_manager: Arc<dyn ReactiveInstanceEventManager + Send + Sync>,
+
+    #[component(default = "create_saved_queries")]
+    saved_queries: Arc<DashMap<String, Arc<MaterializedSavedQuery>>>,
+}
+
+impl SavedQueryManagerImpl {
+    fn get_materialized(&self, name: &str) -> Option<Arc<MaterializedSavedQuery>> {
+        self.saved_queries.get(name).map(|saved_query| saved_query.value().clone())
+    }
+
+    /// Adds created entity instances to and removes deleted entity instances from the result sets.
+    fn subscribe_reactive_instance_events(&self) {
+        let event_types = [
+            ReactiveInstanceEventTypes::EntityInstanceCreated,
+            ReactiveInstanceEventTypes::EntityInstanceDeleted,
+        ];
+        for event_type in event_types {
+            let Some(event_instance) = self.reactive_instance_event_manager.get_reactive_instance_event_instance(event_type.clone()) else {
+                continue;
+            };
+            let saved_queries = self.saved_queries.clone();
+            let reactive_entity_manager = self.reactive_entity_manager.clone();
+            event_instance.observe_with_handle(
+                &EVENT.property_name(),
+                move |value: &Value| {
+                    let Ok(id) = serde_json::from_value::<Uuid>(value.clone()) else {
+                        return;
+                    };
+                    let materialized_saved_queries: Vec<Arc<MaterializedSavedQuery>> =
+                        saved_queries.iter().map(|saved_query| saved_query.value().clone()).collect();
+                    match event_type {
+                        ReactiveInstanceEventTypes::EntityInstanceCreated => {
+                            if let Some(reactive_entity) = reactive_entity_manager.get(id) {
+                                for materialized in materialized_saved_queries {
+                                    materialized.observe(&reactive_entity);
+                                }
+                            }
+                        }
+                        ReactiveInstanceEventTypes::EntityInstanceDeleted => {
+                            for materialized in materialized_saved_queries {
+                                materialized.unobserve(id);
+                            }
+                        }
+                        _ => {}
+                    }
+                },
+                HANDLE_ID_SAVED_QUERIES,
+            );
+        }
+    }
+
+    fn unsubscribe_reactive_instance_events(&self) {
+        for event_instance in self.reactive_instance_event_manager.get_reactive_instance_event_instances() {
+            event_instance.remove_observer(&EVENT.property_name(), HANDLE_ID_SAVED_QUERIES);
+        }
+    }
+}
+
+#[async_trait]
+#[component_alias]
+impl SavedQueryManager for SavedQueryManagerImpl {
+    fn register(&self, saved_query: SavedQuery) -> Result<SavedQuery, SavedQueryRegistrationError> {
+        if saved_query.name.is_empty() {
+            return Err(SavedQueryRegistrationError::EmptyName);
+        }
+        let materialized = Arc::new(MaterializedSavedQuery::new(SavedQueryResultSet::new(saved_query.clone())?));
+        // The saved query is registered first, so that entity instances which are created
+        // meanwhile are observed by the event handler
+        let Entry::Vacant(entry) = self.saved_queries.entry(saved_query.name.clone()) else {
+            return Err(SavedQueryRegistrationError::AlreadyExists(saved_query.name));
+        };
+        entry.insert(materialized.clone());
+        let candidates = match &saved_query.ty {
+            Some(ty) => self.reactive_entity_manager.get_by_type(ty),
+            None => self.reactive_entity_manager.get_all(),
+        };
+        for reactive_entity in candidates {
+            materialized.observe(&reactive_entity);
+            // The entity instance may have been deleted meanwhile
+            if !self.reactive_entity_manager.has(reactive_entity.id) {
+                materialized.unobserve(reactive_entity.id);
+            }
+        }
+        Ok(saved_query)
+    }
+
+    fn unregister(&self, name: &str) -> bool {
+        match self.saved_queries.remove(name) {
+            Some((_, materialized)) => {
+                materialized.unobserve_all();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn has(&self, name: &str) -> bool {
+        self.saved_queries.contains_key(name)
+    }
+
+    fn get(&self, name: &str) -> Option<SavedQuery> {
+        self.get_materialized(name).map(|materialized| materialized.saved_query())
+    }
+
+    fn get_all(&self) -> Vec<SavedQuery> {
+        let mut saved_queries: Vec<SavedQuery> = self.saved_queries.iter().map(|materialized| materialized.saved_query()).collect();
+        saved_queries.sort_by(|a, b| a.name.cmp(&b.name));
+        saved_queries
+    }
+
+    fn get_rows(&self, name: &str) -> Option<Vec<SavedQueryRow>> {
+        self.get_materialized(name).map(|materialized| materialized.result_set.read().unwrap().rows())
+    }
+
+    fn count(&self, name: &str) -> Option<usize> {
+        self.get_materialized(name).map(|materialized| materialized.result_set.read().unwrap().len())
+    }
+
+    fn subscribe(&self, name: &str, handle_id: u128) -> Option<Receiver<SavedQueryChange>> {
+        let materialized = self.get_materialized(name)?;
+        let (sender, receiver) = crossbeam::channel::unbounded();
+        materialized.subscribers.insert(handle_id, sender);
+        Some(receiver)
+    }
+
+    fn unsubscribe(&self, name: &str, handle_id: u128) {
+        if let Some(materialized) = self.get_materialized(name) {
+            materialized.subscribers.remove(&handle_id);
+        }
+    }
+}
+
+#[async_trait]
+impl Lifecycle for SavedQueryManagerImpl {
+    async fn post_init(&self) {
+        self.subscribe_reactive_instance_events();
+    }
+
+    async fn pre_shutdown(&self) {
+        self.unsubscribe_reactive_instance_events();
+        for materialized in self.saved_queries.iter() {
+            materialized.unobserve_all();
+        }
+    }
+}
+
+/// A saved query with its materialized result set and its subscribers.
+struct MaterializedSavedQuery {
+    result_set: RwLock<SavedQueryResultSet>,
+
+    /// The handle id of the observers of the properties of the candidates.
+    handle_id: u128,
+
+    /// The observed candidates by id.
+    entity_instances: DashMap<Uuid, ReactiveEntity>,
+
+    /// The property values of the observed candidates by id.
+    rows: DashMap<Uuid, CachedRow>,
+
+    subscribers: DashMap<u128, Sender<SavedQueryChange>>,
+}
+
+impl MaterializedSavedQuery {
+    fn new(result_set: SavedQueryResultSet) -> Self {
+        MaterializedSavedQuery {
+            result_set: RwLock::new(result_set),
+            handle_id: Uuid::new_v4().as_u128(),
+            entity_instances: DashMap::new(),
+            rows: DashMap::new(),
+            subscribers: DashMap::new(),
+        }
+    }
+
+    fn saved_query(&self) -> SavedQuery {
+        self.result_set.read().unwrap().saved_query().clone()
+    }
+
+    /// Evaluates the entity instance and observes its properties, if it's a candidate of the saved query.
+    fn observe(self: &Arc<Self>, reactive_entity: &ReactiveEntity) {
+        if !self.result_set.read().unwrap().saved_query().applies_to(&reactive_entity.ty) {
+            return;
+        }
+        let Entry::Vacant(entry) = self.entity_instances.entry(reactive_entity.id) else {
+            return;
+        };
+        entry.insert(reactive_entity.clone());
+        let id = reactive_entity.id;
+        // The row is created before the properties are observed, so that no change gets lost
+        self.rows.insert(id, CachedRow::default());
+        for property_name in property_names(reactive_entity) {
+            let materialized: Weak<MaterializedSavedQuery> = Arc::downgrade(self);
+            let observed_property_name = property_name.clone();
+            reactive_entity.observe_with_handle(
+                &property_name,
+                move |value: &Value| {
+                    if let Some(materialized) = materialized.upgrade() {
+                        materialized.update_property(id, &observed_property_name, value);
+                    }
+                },
+                self.handle_id,
+            );
+        }
+        let values = snapshot(reactive_entity);
+        if let Some(mut row) = self.rows.get_mut(&id) {
+            // Values which have been observed meanwhile are newer than the values which have been read
+            for (property_name, value) in values {
+                row.values.entry(property_name).or_insert(value);
+            }
+            row.complete = true;
+            self.update(id, Some(row.values.clone()));
+        }
+    }
+
+    /// Updates the cached value of the observed property and the row of the entity instance.
+    ///
+    /// Runs while the property notifies its observers. Other properties can't be read here,
+    /// because they may be notifying their observers at the same time.
+    fn update_property(&self, id: Uuid, property_name: &str, value: &Value) {
+        let Some(mut row) = self.rows.get_mut(&id) else {
+            return;
+        };
+        row.values.insert(property_name.to_string(), value.clone());
+        if row.complete {
+            self.update(id, Some(row.values.clone()));
+        }
+    }
+
+    /// Stops observing the entity instance and removes it from the result set.
+    fn unobserve(&self, id: Uuid) {
+        if let Some((_, reactive_entity)) = self.entity_instances.remove(&id) {
+            for property_name in property_names(&reactive_entity) {
+                reactive_entity.remove_observer(&property_name, self.handle_id);
+            }
+        }
+        self.rows.remove(&id);
+        self.update(id, None);
+    }
+
+    fn unobserve_all(&self) {
+        let ids: Vec<Uuid> = self.entity_instances.iter().map(|entity_instance| *entity_instance.key()).collect();
+        for id in ids {
+            self.unobserve(id);
+        }
+    }
+
+    /// Updates the result set and sends the change to the subscribers.
+    fn update(&self, id: Uuid, properties: Option<BTreeMap<String, Value>>) {
+        let change = self.result_set.write().unwrap().update(id, properties);
+        if let Some(change) = change {
+            // Subscribers which have been disconnected are removed
+            self.subscribers.retain(|_, sender| sender.send(change.clone()).is_ok());
+        }
+    }
+}
+
+/// The cached property values of an observed entity instance.
+#[derive(Default)]
+struct CachedRow {
+    values: BTreeMap<String, Value>,
+
+    /// The values of the properties which haven't changed yet have been read.
+    complete: bool,
+}
+
+fn property_names(reactive_entity: &ReactiveEntity) -> Vec<String> {
+    reactive_entity.properties.iter().map(|property| property.key().clone()).collect()
+}
+
+/// Returns the values of the properties of the entity instance. Must not be called while a
+/// property of the entity instance notifies its observers.
+fn snapshot(reactive_entity: &ReactiveEntity) -> BTreeMap<String, Value> {
+    reactive_entity
+        .properties
+        .iter()
+        .map(|property| (property.key().clone(), property.get()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    // Do not remove! This import is necessary to make the dependency injection work
+    #[allow(unused_imports)]
+    use reactive_graph_behaviour_service_impl::BehaviourSystemImpl;
+    use reactive_graph_graph::EntityType;
+    use reactive_graph_graph::EntityTypeId;
+    use reactive_graph_graph::PropertyInstanceSetter;
+    use reactive_graph_graph::PropertyInstances;
+    use reactive_graph_graph::PropertyType;
+    use reactive_graph_graph::PropertyTypes;
+    use reactive_graph_graph::SavedQuery;
+    use reactive_graph_reactive_service_api::ReactiveSystem;
+    // Do not remove! This import is necessary to make the dependency injection work
+    #[allow(unused_imports)]
+    use reactive_graph_type_system_impl::TypeSystemImpl;
+
+    use crate::ReactiveSystemImpl;
+
+    #[test]
+    fn test_saved_query_concurrent_property_updates() {
+        reactive_graph_utils_test::init_logger();
+
+        let reactive_system = reactive_graph_di::get_container::<ReactiveSystemImpl>();
+        let entity_type_manager = reactive_system.type_system().get_entity_type_manager();
+        let reactive_entity_manager = reactive_system.get_reactive_entity_manager();
+        let saved_query_manager = reactive_system.get_saved_query_manager();
+
+        let ty = EntityTypeId::new_from_type("test", "point");
+        let entity_type = EntityType::builder()
+            .ty(ty.clone())
+            .properties(PropertyTypes::new().property(PropertyType::number("x")).property(PropertyType::number("y")))
+            .build();
+        entity_type_manager.register(entity_type).expect("Failed to register entity type");
+        let reactive_entity = reactive_entity_manager
+            .create_reactive_entity(&ty, PropertyInstances::new().property("x", 0).property("y", 0))
+            .expect("Failed to create entity");
+        saved_query_manager
+            .register(SavedQuery::builder().name("points").ty(ty).build())
+            .expect("Failed to register the saved query");
+
+        // Both properties are changed at the same time
+        let (sender, receiver) = crossbeam::channel::unbounded();
+        for property_name in ["x", "y"] {
+            let reactive_entity = reactive_entity.clone();
+            let sender = sender.clone();
+            thread::spawn(move || {
+                for i in 1..=1000 {
+                    reactive_entity.set(property_name, json!(i));
+                }
+                sender.send(()).unwrap();
+            });
+        }
+        for _ in 0..2 {
+            receiver.recv_timeout(Duration::from_secs(10)).expect("The property updates are deadlocked");
+        }
+
+        let rows = saved_query_manager.get_rows("points").expect("The saved query doesn't exist");
+        assert_eq!(1, rows.len());
+        assert_eq!(json!(1000), rows[0].properties["x"]);
+        assert_eq!(json!(1000), rows[0].properties["y"]);
+    }
+}
@@ -0,0 +1,323 @@
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use serde_json::Value;
+use springtime_di::Component;
+use springtime_di::component_alias;
+use uuid::Uuid;
+
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::PropertyInstanceGetter;
+use reactive_graph_graph::PropertyTypeDefinition;
+use reactive_graph_graph::RelationInstanceId;
+use reactive_graph_graph::RelationTypeId;
+use reactive_graph_graph::SearchField;
+use reactive_graph_graph::SearchHit;
+use reactive_graph_graph::SearchIndex;
+use reactive_graph_graph::SearchOptions;
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_reactive_model_api::ReactiveInstance;
+use reactive_graph_reactive_model_api::ReactivePropertyContainer;
+use reactive_graph_reactive_model_impl::ReactiveEntity;
+use reactive_graph_reactive_model_impl::ReactiveRelation;
+use reactive_graph_reactive_service_api::ReactiveEntityManager;
+use reactive_graph_reactive_service_api::ReactiveInstanceEventManager;
+use reactive_graph_reactive_service_api::ReactiveInstanceEventTypes;
+use reactive_graph_reactive_service_api::ReactiveRelationManager;
+use reactive_graph_reactive_service_api::SearchDocument;
+use reactive_graph_reactive_service_api::SearchManager;
+use reactive_graph_runtime_model::EXTENSION_SEARCH;
+use reactive_graph_runtime_model::EventProperties::EVENT;
+use reactive_graph_runtime_model::LabeledProperties::LABEL;
+use reactive_graph_type_system_api::ComponentManager;
+use reactive_graph_type_system_api::EntityTypeManager;
+use reactive_graph_type_system_api::RelationTypeManager;
+
+use crate::extension_property_names;
+
+/// The handle id of the observers which are keeping the search index up to date.
+pub const HANDLE_ID_SEARCH_INDEX: u128 = 0x5e4a8c1d2b3f4e6a9c7d8e0f1a2b3c4d;
+
+/// The id of an instance in the search index.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum SearchDocumentId {
+    Entity(Uuid),
+    Relation(RelationInstanceId),
+}
+
+fn create_search_index() -> Arc<RwLock<SearchIndex<SearchDocumentId>>> {
+    Arc::new(RwLock::new(SearchIndex::new()))
+}
+
+#[derive(Component)]
+pub struct SearchManagerImpl {
+    reactive_entity_manager: Arc<dyn ReactiveEntityManager + Send + Sync>,
+
+    reactive_relation_manager: Arc<dyn ReactiveRelationManager + Send + Sync>,
+
+    reactive_instance_event_manager: Arc<dyn ReactiveInstanceEventManager + Send + Sync>,
+
+    component_manager: Arc<dyn ComponentManager + Send + Sync>,
+
+    entity_type_manager: Arc<dyn EntityTypeManager + Send + Sync>,
+
+    relation_type_manager: Arc<dyn RelationTypeManager + Send + Sync>,
+
+    #[component(default = "create_search_index")]
+    search_index: Arc<RwLock<SearchIndex<SearchDocumentId>>>,
+}
+
+impl SearchManagerImpl {
+    fn indexer(&self) -> SearchIndexer {
+        SearchIndexer {
+            search_index: self.search_index.clone(),
+            component_manager: self.component_manager.clone(),
+            entity_type_manager: self.entity_type_manager.clone(),
+            relation_type_manager: self.relation_type_manager.clone(),
+        }
+    }
+
+    /// Keeps the search index up to date when instances are created or deleted.
+    fn subscribe_reactive_instance_events(&self) {
+        let event_types = [
+            ReactiveInstanceEventTypes::EntityInstanceCreated,
+            ReactiveInstanceEventTypes::EntityInstanceDeleted,
+            ReactiveInstanceEventTypes::RelationInstanceCreated,
+            ReactiveInstanceEventTypes::RelationInstanceDeleted,
+        ];
+        for event_type in event_types {
+            let Some(event_instance) = self.reactive_instance_event_manager.get_reactive_instance_event_instance(event_type.clone()) else {
+                continue;
+            };
+            let indexer = self.indexer();
+            let reactive_entity_manager = self.reactive_entity_manager.clone();
+            let reactive_relation_manager = self.reactive_relation_manager.clone();
+            event_instance.observe_with_handle(
+                &EVENT.property_name(),
+                move |value: &Value| match event_type {
+                    ReactiveInstanceEventTypes::EntityInstanceCreated => {
+                        if let Some(reactive_entity) = serde_json::from_value(value.clone()).ok().and_then(|id| reactive_entity_manager.get(id)) {
+                            indexer.index_entity(&reactive_entity);
+                        }
+                    }
+                    ReactiveInstanceEventTypes::EntityInstanceDeleted => {
+                        if let Ok(id) = serde_json::from_value(value.clone()) {
+                            indexer.remove(&SearchDocumentId::Entity(id));
+                        }
+                    }
+                    ReactiveInstanceEventTypes::RelationInstanceCreated => {
+                        if let Some(reactive_relation) = serde_json::from_value(value.clone())
+                            .ok()
+                            .and_then(|id: RelationInstanceId| reactive_relation_manager.get(&id))
+                        {
+                            indexer.index_relation(&reactive_relation);
+                        }
+                    }
+                    ReactiveInstanceEventTypes::RelationInstanceDeleted => {
+                        if let Ok(id) = serde_json::from_value(value.clone()) {
+                            indexer.remove(&SearchDocumentId::Relation(id));
+                        }
+                    }
+                    _ => {}
+                },
+                HANDLE_ID_SEARCH_INDEX,
+            );
+        }
+    }
+
+    fn unsubscribe_reactive_instance_events(&self) {
+        for event_instance in self.reactive_instance_event_manager.get_reactive_instance_event_instances() {
+            event_instance.remove_observer(&EVENT.property_name(), HANDLE_ID_SEARCH_INDEX);
+        }
+    }
+
+    fn get_document(&self, id: &SearchDocumentId) -> Option<SearchDocument> {
+        match id {
+            SearchDocumentId::Entity(id) => self.reactive_entity_manager.get(*id).map(SearchDocument::Entity),
+            SearchDocumentId::Relation(id) => self.reactive_relation_manager.get(id).map(SearchDocument::Relation),
+        }
+    }
+}
+
+#[async_trait]
+#[component_alias]
+impl SearchManager for SearchManagerImpl {
+    fn search(&self, text: &str, options: &SearchOptions) -> Vec<SearchHit<SearchDocument>> {
+        let hits = self.search_index.read().unwrap().search(text, options);
+        hits.into_iter()
+            .filter_map(|hit| {
+                let document = self.get_document(&hit.instance)?;
+                Some(hit.map(|_| document))
+            })
+            .collect()
+    }
+
+    fn count(&self) -> usize {
+        self.search_index.read().unwrap().len()
+    }
+
+    fn reindex(&self) {
+        let indexer = self.indexer();
+        for reactive_entity in self.reactive_entity_manager.get_all() {
+            indexer.unindex_entity(&reactive_entity);
+        }
+        for reactive_relation in self.reactive_relation_manager.get_all() {
+            indexer.unindex_relation(&reactive_relation);
+        }
+        self.search_index.write().unwrap().clear();
+        for reactive_entity in self.reactive_entity_manager.get_all() {
+            indexer.index_entity(&reactive_entity);
+        }
+        for reactive_relation in self.reactive_relation_manager.get_all() {
+            indexer.index_relation(&reactive_relation);
+        }
+    }
+}
+
+#[async_trait]
+impl Lifecycle for SearchManagerImpl {
+    async fn post_init(&self) {
+        self.reindex();
+        self.subscribe_reactive_instance_events();
+    }
+
+    async fn pre_shutdown(&self) {
+        self.unsubscribe_reactive_instance_events();
+    }
+}
+
+/// Adds instances to the search index and observes their searchable properties.
+#[derive(Clone)]
+struct SearchIndexer {
+    search_index: Arc<RwLock<SearchIndex<SearchDocumentId>>>,
+
+    component_manager: Arc<dyn ComponentManager + Send + Sync>,
+
+    entity_type_manager: Arc<dyn EntityTypeManager + Send + Sync>,
+
+    relation_type_manager: Arc<dyn RelationTypeManager + Send + Sync>,
+}
+
+impl SearchIndexer {
+    fn index_entity(&self, reactive_entity: &ReactiveEntity) {
+        let property_names = self.get_entity_property_names(&reactive_entity.ty);
+        self.index_instance(
+            SearchDocumentId::Entity(reactive_entity.id),
+            reactive_entity,
+            &reactive_entity.name,
+            &reactive_entity.description,
+            property_names,
+        );
+    }
+
+    fn unindex_entity(&self, reactive_entity: &ReactiveEntity) {
+        let property_names = self.get_entity_property_names(&reactive_entity.ty);
+        self.unindex_instance(&SearchDocumentId::Entity(reactive_entity.id), reactive_entity, property_names);
+    }
+
+    fn index_relation(&self, reactive_relation: &ReactiveRelation) {
+        let property_names = self.get_relation_property_names(&reactive_relation.relation_type_id());
+        self.index_instance(
+            SearchDocumentId::Relation(reactive_relation.id()),
+            reactive_relation,
+            &reactive_relation.name,
+            &reactive_relation.description,
+            property_names,
+        );
+    }
+
+    fn unindex_relation(&self, reactive_relation: &ReactiveRelation) {
+        let property_names = self.get_relation_property_names(&reactive_relation.relation_type_id());
+        self.unindex_instance(&SearchDocumentId::Relation(reactive_relation.id()), reactive_relation, property_names);
+    }
+
+    /// Indexes the name, the description, the label and the searchable properties of the
+    /// instance. The label and the searchable properties are observed for changes.
+    fn index_instance<T: ReactivePropertyContainer + PropertyInstanceGetter>(
+        &self,
+        id: SearchDocumentId,
+        instance: &T,
+        name: &str,
+        description: &str,
+        property_names: Vec<String>,
+    ) {
+        {
+            let mut search_index = self.search_index.write().unwrap();
+            search_index.index(id.clone(), SearchField::Name, name);
+            search_index.index(id.clone(), SearchField::Description, description);
+        }
+        for (property_name, field) in searchable_fields(property_names) {
+            if let Some(value) = instance.get(&property_name) {
+                self.update(&id, &field, &value);
+            }
+            let indexer = self.clone();
+            let id = id.clone();
+            instance.observe_with_handle(&property_name, move |value: &Value| indexer.update(&id, &field, value), HANDLE_ID_SEARCH_INDEX);
+        }
+    }
+
+    /// Removes the instance from the search index and stops observing its properties.
+    fn unindex_instance<T: ReactivePropertyContainer>(&self, id: &SearchDocumentId, instance: &T, property_names: Vec<String>) {
+        for (property_name, _) in searchable_fields(property_names) {
+            instance.remove_observer(&property_name, HANDLE_ID_SEARCH_INDEX);
+        }
+        self.remove(id);
+    }
+
+    /// Updates the indexed text of a searchable property. Non-string values are not searchable.
+    fn update(&self, id: &SearchDocumentId, field: &SearchField, value: &Value) {
+        let mut search_index = self.search_index.write().unwrap();
+        // The observers of a removed instance must not add the instance again
+        if !search_index.contains(id) {
+            return;
+        }
+        match value.as_str() {
+            Some(text) => search_index.index(id.clone(), field.clone(), text),
+            None => search_index.remove_field(id, field),
+        }
+    }
+
+    fn remove(&self, id: &SearchDocumentId) {
+        self.search_index.write().unwrap().remove(id);
+    }
+
+    fn get_entity_property_names(&self, ty: &EntityTypeId) -> Vec<String> {
+        let Some(entity_type) = self.entity_type_manager.get(ty) else {
+            return Vec::new();
+        };
+        let components: Vec<reactive_graph_graph::Component> = entity_type
+            .components
+            .iter()
+            .filter_map(|component_ty| self.component_manager.get(component_ty.key()))
+            .collect();
+        extension_property_names(&entity_type, &entity_type.properties, &components, &EXTENSION_SEARCH)
+    }
+
+    fn get_relation_property_names(&self, ty: &RelationTypeId) -> Vec<String> {
+        let Some(relation_type) = self.relation_type_manager.get(ty) else {
+            return Vec::new();
+        };
+        let components: Vec<reactive_graph_graph::Component> = relation_type
+            .components
+            .iter()
+            .filter_map(|component_ty| self.component_manager.get(component_ty.key()))
+            .collect();
+        extension_property_names(&relation_type, &relation_type.properties, &components, &EXTENSION_SEARCH)
+    }
+}
+
+/// Returns the search fields of the label and the given searchable properties.
+fn searchable_fields(property_names: Vec<String>) -> Vec<(String, SearchField)> {
+    let mut fields = vec![(LABEL.property_name(), SearchField::Label)];
+    fields.extend(
+        property_names
+            .into_iter()
+            .filter(|property_name| *property_name != LABEL.property_name())
+            .map(|property_name| {
+                let field = SearchField::Property(property_name.clone());
+                (property_name, field)
+            }),
+    );
+    fields
+}
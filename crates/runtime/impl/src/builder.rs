@@ -6,6 +6,7 @@ use std::sync::Arc;
 use tokio::time::Duration;
 
 use crate::get_runtime;
+use reactive_graph_plugin_api::PluginDeclaration;
 use reactive_graph_remotes_model::InstanceAddress;
 use reactive_graph_runtime_api::Runtime;
 
@@ -223,6 +224,15 @@ impl RuntimeBuilder<ConfigFilesLoaded, NotRunning> {
         self
     }
 
+    /// Registers plugins which are linked statically into the binary.
+    pub fn static_plugins<I: IntoIterator<Item = PluginDeclaration>>(self, plugin_declarations: I) -> RuntimeBuilder<ConfigFilesLoaded, NotRunning> {
+        let plugin_container_manager = self.runtime.get_plugin_container_manager();
+        for plugin_declaration in plugin_declarations {
+            plugin_container_manager.create_and_register_static_plugin_container(plugin_declaration);
+        }
+        self
+    }
+
     pub fn get(self) -> Arc<dyn Runtime> {
         self.runtime
     }
@@ -39,16 +39,23 @@ use reactive_graph_lifecycle::Lifecycle;
 use reactive_graph_plugin_graphql_api::PluginGraphQLSystem;
 use reactive_graph_plugin_graphql_api::PluginQueryService;
 use reactive_graph_plugin_graphql_api::PluginSchemaManager;
+use reactive_graph_plugin_service_api::PluginConfigManager;
 use reactive_graph_plugin_service_api::PluginContainerManager;
 use reactive_graph_plugin_service_api::PluginContextFactory;
 use reactive_graph_plugin_service_api::PluginRepositoryManager;
 use reactive_graph_plugin_service_api::PluginResolver;
+use reactive_graph_plugin_service_api::PluginResourceManager;
 use reactive_graph_plugin_service_api::PluginSystem;
+use reactive_graph_reactive_service_api::ComputedPropertyManager;
+use reactive_graph_reactive_service_api::GraphQueryManager;
+use reactive_graph_reactive_service_api::GraphTraversalManager;
 use reactive_graph_reactive_service_api::ReactiveEntityManager;
 use reactive_graph_reactive_service_api::ReactiveFlowManager;
 use reactive_graph_reactive_service_api::ReactiveInstanceEventManager;
 use reactive_graph_reactive_service_api::ReactiveRelationManager;
 use reactive_graph_reactive_service_api::ReactiveSystem;
+use reactive_graph_reactive_service_api::SavedQueryManager;
+use reactive_graph_reactive_service_api::SearchManager;
 use reactive_graph_remotes_api::InstanceService;
 use reactive_graph_remotes_api::RemotesManager;
 use reactive_graph_remotes_api::RemotesSystem;
@@ -76,6 +83,7 @@ use reactive_graph_type_system_api::NamespaceManager;
 use reactive_graph_type_system_api::RelationTypeImportExportManager;
 use reactive_graph_type_system_api::RelationTypeManager;
 use reactive_graph_type_system_api::RelationTypeProviderRegistry;
+use reactive_graph_type_system_api::TypeDependencyManager;
 use reactive_graph_type_system_api::TypeSystem;
 use reactive_graph_type_system_api::TypeSystemEventManager;
 
@@ -262,6 +270,10 @@ impl TypeSystem for RuntimeImpl {
         self.type_system.get_relation_type_provider_registry()
     }
 
+    fn get_type_dependency_manager(&self) -> Arc<dyn TypeDependencyManager + Send + Sync> {
+        self.type_system.get_type_dependency_manager()
+    }
+
     fn get_type_system_event_manager(&self) -> Arc<dyn TypeSystemEventManager + Send + Sync> {
         self.type_system.get_type_system_event_manager()
     }
@@ -284,6 +296,26 @@ impl ReactiveSystem for RuntimeImpl {
         self.reactive_system.get_reactive_instance_event_manager()
     }
 
+    fn get_graph_query_manager(&self) -> Arc<dyn GraphQueryManager + Send + Sync> {
+        self.reactive_system.get_graph_query_manager()
+    }
+
+    fn get_graph_traversal_manager(&self) -> Arc<dyn GraphTraversalManager + Send + Sync> {
+        self.reactive_system.get_graph_traversal_manager()
+    }
+
+    fn get_search_manager(&self) -> Arc<dyn SearchManager + Send + Sync> {
+        self.reactive_system.get_search_manager()
+    }
+
+    fn get_computed_property_manager(&self) -> Arc<dyn ComputedPropertyManager + Send + Sync> {
+        self.reactive_system.get_computed_property_manager()
+    }
+
+    fn get_saved_query_manager(&self) -> Arc<dyn SavedQueryManager + Send + Sync> {
+        self.reactive_system.get_saved_query_manager()
+    }
+
     fn type_system(&self) -> Arc<dyn TypeSystem + Send + Sync> {
         self.reactive_system.type_system()
     }
@@ -484,6 +516,10 @@ impl PluginSystem for RuntimeImpl {
         self.plugin_system.get_plugin_container_manager()
     }
 
+    fn get_plugin_config_manager(&self) -> Arc<dyn PluginConfigManager + Send + Sync> {
+        self.plugin_system.get_plugin_config_manager()
+    }
+
     fn get_plugin_repository_manager(&self) -> Arc<dyn PluginRepositoryManager + Send + Sync> {
         self.plugin_system.get_plugin_repository_manager()
     }
@@ -491,6 +527,10 @@ impl PluginSystem for RuntimeImpl {
     fn get_plugin_resolver(&self) -> Arc<dyn PluginResolver + Send + Sync> {
         self.plugin_system.get_plugin_resolver()
     }
+
+    fn get_plugin_resource_manager(&self) -> Arc<dyn PluginResourceManager + Send + Sync> {
+        self.plugin_system.get_plugin_resource_manager()
+    }
 }
 
 #[async_trait]
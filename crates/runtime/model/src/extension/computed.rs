@@ -0,0 +1,4 @@
+use crate::NAMESPACE_CORE;
+use reactive_graph_graph::extension_ty;
+
+extension_ty!(EXTENSION_COMPUTED, NAMESPACE_CORE, EXTENSION_NAME_COMPUTED, "computed");
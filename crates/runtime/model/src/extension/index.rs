@@ -0,0 +1,4 @@
+use crate::NAMESPACE_CORE;
+use reactive_graph_graph::extension_ty;
+
+extension_ty!(EXTENSION_INDEX, NAMESPACE_CORE, EXTENSION_NAME_INDEX, "index");
@@ -1,5 +1,11 @@
+pub use crate::computed::*;
 pub use crate::divergent::*;
+pub use crate::index::*;
+pub use crate::search::*;
 pub use crate::type_category::*;
 
+pub mod computed;
 pub mod divergent;
+pub mod index;
+pub mod search;
 pub mod type_category;
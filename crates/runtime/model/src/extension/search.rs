@@ -0,0 +1,4 @@
+use crate::NAMESPACE_CORE;
+use reactive_graph_graph::extension_ty;
+
+extension_ty!(EXTENSION_SEARCH, NAMESPACE_CORE, EXTENSION_NAME_SEARCH, "search");
@@ -10,6 +10,9 @@ pub enum DeserializationError {
     #[cfg(feature = "toml")]
     #[error("Failed to deserialize TOML failed: {0}")]
     Toml(#[from] toml::de::Error),
+    #[cfg(feature = "yaml")]
+    #[error("Failed to deserialize YAML failed: {0}")]
+    Yaml(#[from] serde_norway::Error),
 }
 
 #[derive(Debug, Error)]
@@ -22,4 +25,13 @@ pub enum SerializationError {
     #[cfg(feature = "toml")]
     #[error("Failed to serialize TOML: {0}")]
     Toml(#[from] toml::ser::Error),
+    #[cfg(feature = "yaml")]
+    #[error("Failed to serialize YAML: {0}")]
+    Yaml(#[from] serde_norway::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SerializationFormatError {
+    #[error("The file format {0} is not supported")]
+    UnsupportedFormat(String),
 }
@@ -0,0 +1,151 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::error::DeserializationError;
+use crate::error::SerializationError;
+use crate::error::SerializationFormatError;
+
+/// The file formats which can be used to read and write type and instance definitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SerializationFormat {
+    Json,
+    #[cfg(feature = "json5")]
+    Json5,
+    #[cfg(feature = "toml")]
+    Toml,
+    #[cfg(feature = "yaml")]
+    Yaml,
+}
+
+impl SerializationFormat {
+    /// Returns all enabled formats.
+    pub fn all() -> Vec<SerializationFormat> {
+        vec![
+            SerializationFormat::Json,
+            #[cfg(feature = "json5")]
+            SerializationFormat::Json5,
+            #[cfg(feature = "toml")]
+            SerializationFormat::Toml,
+            #[cfg(feature = "yaml")]
+            SerializationFormat::Yaml,
+        ]
+    }
+
+    /// Returns the format for the given file extension.
+    pub fn from_extension(extension: &str) -> Result<SerializationFormat, SerializationFormatError> {
+        match extension.to_lowercase().as_str() {
+            "json" => Ok(SerializationFormat::Json),
+            #[cfg(feature = "json5")]
+            "json5" => Ok(SerializationFormat::Json5),
+            #[cfg(feature = "toml")]
+            "toml" => Ok(SerializationFormat::Toml),
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => Ok(SerializationFormat::Yaml),
+            _ => Err(SerializationFormatError::UnsupportedFormat(extension.to_string())),
+        }
+    }
+
+    /// Returns the format for the extension of the given path.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<SerializationFormat, SerializationFormatError> {
+        match path.as_ref().extension().and_then(|extension| extension.to_str()) {
+            Some(extension) => SerializationFormat::from_extension(extension),
+            None => Err(SerializationFormatError::UnsupportedFormat(Default::default())),
+        }
+    }
+
+    /// Returns the default file extension of the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            SerializationFormat::Json => "json",
+            #[cfg(feature = "json5")]
+            SerializationFormat::Json5 => "json5",
+            #[cfg(feature = "toml")]
+            SerializationFormat::Toml => "toml",
+            #[cfg(feature = "yaml")]
+            SerializationFormat::Yaml => "yaml",
+        }
+    }
+
+    /// Deserializes the given content.
+    pub fn deserialize<T: DeserializeOwned>(&self, content: &str) -> Result<T, DeserializationError> {
+        match self {
+            SerializationFormat::Json => Ok(serde_json::from_str(content)?),
+            #[cfg(feature = "json5")]
+            SerializationFormat::Json5 => Ok(json5::from_str(content)?),
+            #[cfg(feature = "toml")]
+            SerializationFormat::Toml => Ok(toml::from_str(content)?),
+            #[cfg(feature = "yaml")]
+            SerializationFormat::Yaml => Ok(serde_norway::from_str(content)?),
+        }
+    }
+
+    /// Serializes the given value. The output is pretty printed if the format supports it.
+    pub fn serialize<T: Serialize>(&self, value: &T) -> Result<String, SerializationError> {
+        match self {
+            SerializationFormat::Json => Ok(serde_json::to_string_pretty(value)?),
+            #[cfg(feature = "json5")]
+            SerializationFormat::Json5 => Ok(json5::to_string(value)?),
+            #[cfg(feature = "toml")]
+            SerializationFormat::Toml => Ok(toml::to_string_pretty(value)?),
+            #[cfg(feature = "yaml")]
+            SerializationFormat::Yaml => Ok(serde_norway::to_string(value)?),
+        }
+    }
+}
+
+impl Display for SerializationFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.extension())
+    }
+}
+
+impl FromStr for SerializationFormat {
+    type Err = SerializationFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SerializationFormat::from_extension(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    use crate::format::SerializationFormat;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Definition {
+        name: String,
+        description: String,
+        values: Vec<i64>,
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let definition = Definition {
+            name: "test".to_string(),
+            description: "A test definition".to_string(),
+            values: vec![1, 2, 3],
+        };
+        for format in SerializationFormat::all() {
+            let content = format.serialize(&definition).expect("Failed to serialize");
+            let deserialized: Definition = format.deserialize(&content).expect("Failed to deserialize");
+            assert_eq!(definition, deserialized, "Round trip failed for {format}");
+        }
+    }
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(SerializationFormat::Json, SerializationFormat::from_path("types/component.json").unwrap());
+        #[cfg(feature = "yaml")]
+        assert_eq!(SerializationFormat::Yaml, SerializationFormat::from_path("types/component.yml").unwrap());
+        assert!(SerializationFormat::from_path("types/component.xml").is_err());
+        assert!(SerializationFormat::from_path("types/component").is_err());
+    }
+}
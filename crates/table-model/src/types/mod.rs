@@ -5,6 +5,7 @@ pub mod extension;
 pub mod flows;
 pub mod json_value;
 pub mod mutability;
+pub mod namespace;
 pub mod properties;
 pub mod relations;
 pub mod socket_type;
@@ -0,0 +1,67 @@
+use tabled::Table;
+use tabled::Tabled;
+use tabled::settings::Modify;
+use tabled::settings::Style;
+use tabled::settings::Width;
+use tabled::settings::object::Segment;
+
+use crate::container::DefaultTableContainer;
+use crate::container::TableInlineFormat;
+use crate::container::TableInlineFormatSetter;
+use crate::container::TableOptions;
+
+#[derive(Clone, Debug, Tabled)]
+pub struct Namespace {
+    /// The name of the namespace.
+    pub name: String,
+
+    /// Textual description of the namespace.
+    pub description: String,
+
+    /// The name of the plugin which owns the namespace.
+    pub owner: String,
+
+    /// The version of the types of the namespace.
+    pub version: String,
+
+    /// The tags of the namespace.
+    pub tags: String,
+
+    /// Links to the documentation of the namespace.
+    pub documentation: String,
+
+    #[tabled(skip)]
+    inline_format: TableInlineFormat,
+}
+
+impl TableInlineFormatSetter for Namespace {
+    fn set_table_inline_format(&mut self, table_inline_format: TableInlineFormat) {
+        self.inline_format = table_inline_format;
+    }
+}
+
+impl From<reactive_graph_graph::Namespace> for Namespace {
+    fn from(namespace: reactive_graph_graph::Namespace) -> Self {
+        Namespace {
+            name: namespace.name,
+            description: namespace.description,
+            owner: namespace.owner.unwrap_or_default(),
+            version: namespace.version.unwrap_or_default(),
+            tags: namespace.tags.join(", "),
+            documentation: namespace.documentation.join("\n"),
+            inline_format: Default::default(),
+        }
+    }
+}
+
+pub type NamespacesTableContainer = DefaultTableContainer<reactive_graph_graph::Namespace, Namespace, NamespacesTableOptions>;
+
+pub struct NamespacesTableOptions;
+
+impl TableOptions for NamespacesTableOptions {
+    fn options(table: &mut Table) -> &mut Table {
+        table
+            .with(Style::extended())
+            .with(Modify::new(Segment::new(0.., 1..2)).with(Width::wrap(40).keep_words(true)))
+    }
+}
@@ -14,6 +14,8 @@ pub enum ComponentRegistrationError {
 pub enum ComponentCreationError {
     #[error("Failed to create component because registration failed: {0}")]
     RegistrationError(#[from] ComponentRegistrationError),
+    #[error("Failed to create component: {0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -26,6 +28,8 @@ pub enum ComponentImportError {
     Deserialization(#[from] DeserializationError),
     #[error("Failed to import component because registration failed: {0}")]
     RegistrationError(#[from] ComponentRegistrationError),
+    #[error("Failed to import component: {0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
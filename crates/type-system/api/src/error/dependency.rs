@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+use reactive_graph_graph::prelude::*;
+
+#[derive(Debug, Error)]
+pub enum TypeDeleteError {
+    #[error("Failed to delete non existent type {0}!")]
+    TypeDoesNotExist(TypeDefinition),
+    #[error("Failed to delete type {0} because types of kind {1:?} cannot be deleted!")]
+    UnsupportedTypeIdType(TypeDefinition, TypeIdType),
+    #[error("Failed to delete type {0} because it is referenced by {1} types and {2} instances!")]
+    TypeIsReferenced(TypeDefinition, usize, usize),
+}
@@ -14,6 +14,8 @@ pub enum EntityTypeRegistrationError {
 pub enum EntityTypeCreationError {
     #[error("Failed to create entity type: {0}")]
     RegistrationError(#[from] EntityTypeRegistrationError),
+    #[error("Failed to create entity type: {0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -26,6 +28,8 @@ pub enum EntityTypeImportError {
     Deserialization(#[from] DeserializationError),
     #[error("Failed to import entity type because registration failed: {0}")]
     RegistrationError(#[from] EntityTypeRegistrationError),
+    #[error("Failed to import entity type: {0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
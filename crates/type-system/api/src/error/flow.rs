@@ -18,6 +18,8 @@ pub enum FlowTypeRegistrationError {
 pub enum FlowTypeCreationError {
     #[error("Failed to create flow type because registration failed: {0}")]
     RegistrationError(#[from] FlowTypeRegistrationError),
+    #[error("Failed to create flow type: {0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -30,6 +32,8 @@ pub enum FlowTypeImportError {
     Deserialization(#[from] DeserializationError),
     #[error("Failed to import flow type because registration failed: {0}")]
     RegistrationError(#[from] FlowTypeRegistrationError),
+    #[error("Failed to import flow type: {0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
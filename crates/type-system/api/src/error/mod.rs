@@ -1,4 +1,6 @@
 pub mod component;
+pub mod dependency;
 pub mod entity;
 pub mod flow;
+pub mod namespace;
 pub mod relation;
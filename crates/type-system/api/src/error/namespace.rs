@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum NamespaceRegistrationError {
+    #[error("Failed to register namespace because the name is empty!")]
+    EmptyName,
+    #[error("Failed to register namespace {0} because it is already owned by {1}!")]
+    NamespaceAlreadyOwned(String, String),
+    #[error("Failed to register namespace: {0}")]
+    PermissionDenied(String),
+}
@@ -22,6 +22,8 @@ pub enum RelationTypeRegistrationError {
 pub enum RelationTypeCreationError {
     #[error("Failed to create relation type because registration failed: {0}")]
     RegistrationError(#[from] RelationTypeRegistrationError),
+    #[error("Failed to create relation type: {0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
@@ -34,6 +36,8 @@ pub enum RelationTypeImportError {
     Deserialization(#[from] DeserializationError),
     #[error("Failed to import relation type because registration failed: {0}")]
     RegistrationError(#[from] RelationTypeRegistrationError),
+    #[error("Failed to import relation type: {0}")]
+    PermissionDenied(String),
 }
 
 #[derive(Debug, Error)]
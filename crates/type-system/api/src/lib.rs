@@ -6,8 +6,10 @@ pub use entity_type_import_export_manager::*;
 pub use entity_type_manager::*;
 pub use entity_type_provider_registry::*;
 pub use error::component::*;
+pub use error::dependency::*;
 pub use error::entity::*;
 pub use error::flow::*;
+pub use error::namespace::*;
 pub use error::relation::*;
 pub use flow_type_import_export_manager::*;
 pub use flow_type_manager::*;
@@ -17,6 +19,9 @@ pub use relation_type_import_export_manager::*;
 pub use relation_type_manager::*;
 pub use relation_type_provider_registry::*;
 pub use runtime_types_provider::*;
+pub use type_delete_guard::*;
+pub use type_dependencies::*;
+pub use type_dependency_manager::*;
 pub use type_provider::*;
 pub use type_system::*;
 pub use type_system_event_manager::*;
@@ -26,6 +31,8 @@ pub use type_system_events::*;
 #[cfg(feature = "json5")]
 pub use json5;
 pub use serde_json;
+#[cfg(feature = "yaml")]
+pub use serde_norway;
 #[cfg(feature = "toml")]
 pub use toml;
 
@@ -55,6 +62,9 @@ pub mod relation_type_import_export_manager;
 pub mod relation_type_manager;
 pub mod relation_type_provider_registry;
 pub mod runtime_types_provider;
+pub mod type_delete_guard;
+pub mod type_dependencies;
+pub mod type_dependency_manager;
 pub mod type_provider;
 pub mod type_system;
 pub mod type_system_event_manager;
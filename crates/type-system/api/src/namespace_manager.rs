@@ -3,9 +3,45 @@ use std::collections::HashSet;
 use async_trait::async_trait;
 use springtime_di::injectable;
 
+use crate::NamespaceRegistrationError;
+use reactive_graph_graph::Namespace;
+
 #[injectable]
 #[async_trait]
 pub trait NamespaceManager: Send + Sync {
-    /// Returns all namespaces.
+    /// Returns the names of all namespaces.
+    ///
+    /// This includes the registered namespaces and the namespaces of the types.
     fn get_all(&self) -> HashSet<String>;
+
+    /// Returns the metadata of all namespaces, ordered by name.
+    ///
+    /// Namespaces of types which have not been registered are returned without metadata.
+    fn get_namespaces(&self) -> Vec<Namespace>;
+
+    /// Returns the metadata of the namespace with the given name.
+    fn get(&self, name: &str) -> Option<Namespace>;
+
+    /// Returns the namespaces which are owned by the given plugin.
+    fn get_by_owner(&self, owner: &str) -> Vec<Namespace>;
+
+    /// Returns true, if the namespace with the given name is registered or used by a type.
+    fn has(&self, name: &str) -> bool;
+
+    /// Returns true, if metadata for the namespace with the given name has been registered.
+    fn is_registered(&self, name: &str) -> bool;
+
+    /// Registers the metadata of a namespace.
+    ///
+    /// Registering a namespace again replaces the metadata, unless the namespace is owned by
+    /// another plugin.
+    fn register(&self, namespace: Namespace) -> Result<Namespace, NamespaceRegistrationError>;
+
+    /// Unregisters the metadata of the namespace with the given name.
+    ///
+    /// The types of the namespace are not affected.
+    fn unregister(&self, name: &str) -> Option<Namespace>;
+
+    /// Unregisters the metadata of all namespaces owned by the given plugin.
+    fn unregister_by_owner(&self, owner: &str) -> Vec<Namespace>;
 }
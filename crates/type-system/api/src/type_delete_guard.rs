@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use springtime_di::injectable;
+
+use crate::TypeDeleteError;
+use crate::TypeDependencyManager;
+use reactive_graph_graph::TypeDefinition;
+use reactive_graph_lifecycle::Lifecycle;
+
+/// Refuses to delete types which are still referenced by other types or by instances.
+///
+/// The type managers can't depend on the type dependency manager, because the type dependency
+/// manager depends on the type managers. Therefore, the type dependency manager is bound to the
+/// guard when the type system is initialized.
+#[injectable]
+#[async_trait]
+pub trait TypeDeleteGuard: Send + Sync + Lifecycle {
+    /// Binds the type dependency manager which finds the references to a type.
+    fn bind(&self, type_dependency_manager: Arc<dyn TypeDependencyManager + Send + Sync>);
+
+    /// Returns an error, if the given type is still referenced and deleting the type hasn't been permitted.
+    fn check(&self, ty: &TypeDefinition) -> Result<(), TypeDeleteError>;
+
+    /// Permits deleting the given type, even if the type is still referenced.
+    ///
+    /// Used by the cascade and the orphan delete policy and by type providers which unregister their types.
+    fn permit(&self, ty: &TypeDefinition);
+
+    /// Revokes the permission to delete the given type.
+    fn revoke(&self, ty: &TypeDefinition);
+}
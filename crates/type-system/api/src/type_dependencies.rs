@@ -0,0 +1,136 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use thiserror::Error;
+
+use reactive_graph_graph::TypeDefinition;
+
+/// The kind of reference from a dependent type to the type it depends on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TypeDependencyKind {
+    /// The entity type or relation type is composed of the component.
+    Component,
+    /// The relation type uses the component or entity type as outbound type.
+    OutboundType,
+    /// The relation type uses the component or entity type as inbound type.
+    InboundType,
+    /// The flow type uses the entity type for the wrapper entity instance.
+    WrapperEntityType,
+    /// The flow type contains an entity instance of the entity type.
+    EntityInstance,
+    /// The flow type contains a relation instance of the relation type.
+    RelationInstance,
+}
+
+impl Display for TypeDependencyKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            TypeDependencyKind::Component => "component",
+            TypeDependencyKind::OutboundType => "outbound type",
+            TypeDependencyKind::InboundType => "inbound type",
+            TypeDependencyKind::WrapperEntityType => "wrapper entity type",
+            TypeDependencyKind::EntityInstance => "entity instance",
+            TypeDependencyKind::RelationInstance => "relation instance",
+        };
+        write!(f, "{kind}")
+    }
+}
+
+/// A reference from the dependent type to the type it depends on.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TypeDependency {
+    /// The type which references the other type.
+    pub dependent: TypeDefinition,
+
+    /// The referenced type.
+    pub dependency: TypeDefinition,
+
+    /// How the dependent type references the other type.
+    pub kind: TypeDependencyKind,
+}
+
+impl TypeDependency {
+    pub fn new(dependent: TypeDefinition, dependency: TypeDefinition, kind: TypeDependencyKind) -> Self {
+        Self { dependent, dependency, kind }
+    }
+}
+
+impl Display for TypeDependency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} uses {} as {}", self.dependent, self.dependency, self.kind)
+    }
+}
+
+/// Controls what happens with the types and the instances which are referencing a type that
+/// should be deleted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TypeDeletePolicy {
+    /// Refuses to delete a type which is still referenced by other types or by instances.
+    #[default]
+    Refuse,
+    /// Deletes the type together with all types and instances which are referencing the type.
+    Cascade,
+    /// Deletes the type only. Types and instances which are referencing the type are left as orphans.
+    Orphan,
+}
+
+/// A problem found by validating a type.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum TypeValidationIssue {
+    #[error("The type {0} does not exist")]
+    TypeDoesNotExist(TypeDefinition),
+    #[error("The type {} references the non existent type {} as {}", .0.dependent, .0.dependency, .0.kind)]
+    MissingDependency(TypeDependency),
+}
+
+/// The result of validating a type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeValidationReport {
+    /// The validated type.
+    pub ty: TypeDefinition,
+
+    /// The problems which have been found.
+    pub issues: Vec<TypeValidationIssue>,
+}
+
+impl TypeValidationReport {
+    pub fn new(ty: TypeDefinition) -> Self {
+        Self { ty, issues: Vec::new() }
+    }
+
+    /// Returns true, if no problems have been found.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// The result of deleting a type.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TypeDeleteReport {
+    /// The types which have been deleted. Contains the dependent types if the cascade policy was used.
+    pub deleted_types: Vec<TypeDefinition>,
+
+    /// The number of instances which have been deleted.
+    pub deleted_instances: usize,
+
+    /// The references which are pointing to deleted types, if the orphan policy was used.
+    pub orphans: Vec<TypeDependency>,
+
+    /// The number of instances which are referencing deleted types, if the orphan policy was used.
+    pub orphaned_instances: usize,
+}
+
+/// Reports and deletes instances which are referencing types.
+///
+/// The type system doesn't know about instances. The instance system registers a provider in
+/// order to take the instances into account when a type gets deleted.
+pub trait TypeInstanceReferenceProvider: Send + Sync {
+    /// Returns the id of the provider.
+    fn id(&self) -> &str;
+
+    /// Returns the number of instances which are referencing the given type.
+    fn count_references(&self, ty: &TypeDefinition) -> usize;
+
+    /// Deletes the instances which are referencing the given type. Returns the number of deleted instances.
+    fn delete_references(&self, ty: &TypeDefinition) -> usize;
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use springtime_di::injectable;
+
+use crate::TypeDeleteError;
+use crate::TypeDeletePolicy;
+use crate::TypeDeleteReport;
+use crate::TypeDependency;
+use crate::TypeInstanceReferenceProvider;
+use crate::TypeValidationReport;
+use reactive_graph_graph::TypeDefinition;
+use reactive_graph_lifecycle::Lifecycle;
+
+#[injectable]
+#[async_trait]
+pub trait TypeDependencyManager: Send + Sync + Lifecycle {
+    /// Returns true, if the type with the given type definition exists.
+    fn has(&self, ty: &TypeDefinition) -> bool;
+
+    /// Returns the references of the given type to other types.
+    fn get_dependencies(&self, ty: &TypeDefinition) -> Vec<TypeDependency>;
+
+    /// Returns the references of other types to the given type.
+    fn get_dependents(&self, ty: &TypeDefinition) -> Vec<TypeDependency>;
+
+    /// Returns all types which are directly or indirectly referencing the given type.
+    ///
+    /// The types are ordered so that a type is listed before the types it depends on.
+    fn get_transitive_dependents(&self, ty: &TypeDefinition) -> Vec<TypeDefinition>;
+
+    /// Returns the number of instances which are referencing the given type.
+    fn count_instance_references(&self, ty: &TypeDefinition) -> usize;
+
+    /// Validates that all types referenced by the given type exists.
+    fn validate(&self, ty: &TypeDefinition) -> TypeValidationReport;
+
+    /// Validates all types and returns the reports of the invalid types.
+    fn validate_all(&self) -> Vec<TypeValidationReport>;
+
+    /// Deletes the given type. The policy decides what happens with the types and instances
+    /// which are referencing the type.
+    fn delete(&self, ty: &TypeDefinition, policy: TypeDeletePolicy) -> Result<TypeDeleteReport, TypeDeleteError>;
+
+    /// Registers a provider of instances which are referencing types.
+    fn register_instance_reference_provider(&self, provider: Arc<dyn TypeInstanceReferenceProvider>);
+
+    /// Unregisters the provider of instances with the given id.
+    fn unregister_instance_reference_provider(&self, id: &str);
+}
@@ -17,6 +17,7 @@ use crate::NamespaceManager;
 use crate::RelationTypeImportExportManager;
 use crate::RelationTypeManager;
 use crate::RelationTypeProviderRegistry;
+use crate::TypeDependencyManager;
 use crate::TypeSystemEventManager;
 
 #[injectable]
@@ -48,5 +49,7 @@ pub trait TypeSystem: Lifecycle {
 
     fn get_relation_type_provider_registry(&self) -> Arc<dyn RelationTypeProviderRegistry + Send + Sync>;
 
+    fn get_type_dependency_manager(&self) -> Arc<dyn TypeDependencyManager + Send + Sync>;
+
     fn get_type_system_event_manager(&self) -> Arc<dyn TypeSystemEventManager + Send + Sync>;
 }
@@ -105,6 +105,25 @@ pub fn type_provider(input: TokenStream) -> TokenStream {
         }
     };
 
+    #[cfg(feature = "yaml")]
+    let yaml = {
+        quote! {
+            match reactive_graph_type_system_api::serde_norway::from_str(asset_str) {
+                Ok(parsed_entry) => {
+                    let entry: <#tys as reactive_graph_graph::NamespacedTypeContainer>::Type = parsed_entry;
+                    reactive_graph_graph::NamespacedTypeContainer::push(&entries, entry);
+                }
+                Err(e) => log::error!("Error in parsing YAML file {filename}: {e}"),
+            }
+        }
+    };
+    #[cfg(not(feature = "yaml"))]
+    let yaml = {
+        quote! {
+            log::error!("Failed to read type definition from {filename}: YAML is not a supported file format!");
+        }
+    };
+
     let expanded = quote! {
         #[derive(rust_embed::RustEmbed)]
         #[folder = #path]
@@ -134,8 +153,10 @@ pub fn type_provider(input: TokenStream) -> TokenStream {
                                     #json5
                                 } else if filename.ends_with(".toml") {
                                     #toml
+                                } else if filename.ends_with(".yaml") || filename.ends_with(".yml") {
+                                    #yaml
                                 } else {
-                                    log::error!("Can't read type definition {}: Only JSON, JSON5 and TOML are supported.", filename);
+                                    log::error!("Can't read type definition {}: Only JSON, JSON5, TOML and YAML are supported.", filename);
                                 }
                             }
                             Err(e) => log::error!("Error in decoding file to UTF-8 {}: {}", filename, e),
@@ -1,8 +1,4 @@
-use std::fs::File;
-use std::io::BufReader;
-use std::io::Read;
-#[allow(unused_imports)]
-use std::io::Write;
+use std::fs;
 use std::path::Path;
 use std::sync::Arc;
 
@@ -12,8 +8,8 @@ use springtime_di::component_alias;
 
 use reactive_graph_graph::ComponentTypeId;
 use reactive_graph_lifecycle::Lifecycle;
-use reactive_graph_serde::error::DeserializationError;
-use reactive_graph_serde::error::SerializationError;
+use reactive_graph_serde::error::SerializationFormatError;
+use reactive_graph_serde::format::SerializationFormat;
 use reactive_graph_type_system_api::ComponentExportError;
 use reactive_graph_type_system_api::ComponentImportError;
 use reactive_graph_type_system_api::ComponentImportExportManager;
@@ -29,19 +25,10 @@ pub struct ComponentImportExportManagerImpl {
 impl ComponentImportExportManager for ComponentImportExportManagerImpl {
     async fn import(&self, path: &str) -> Result<reactive_graph_graph::Component, ComponentImportError> {
         let path = Path::new(path);
-        let file = File::open(path)?;
-        let mut reader = BufReader::new(file);
-        let mut content = String::new();
-        reader.read_to_string(&mut content)?;
-        let component = match path.extension().and_then(|ext| ext.to_str()) {
-            Some("json") => serde_json::from_str::<reactive_graph_graph::Component>(&content).map_err(|e| DeserializationError::Json(e).into()),
-            #[cfg(feature = "json5")]
-            Some("json5") => json5::from_str::<reactive_graph_graph::Component>(&content).map_err(|e| DeserializationError::Json5(e).into()),
-            #[cfg(feature = "toml")]
-            Some("toml") => toml::from_str::<reactive_graph_graph::Component>(&content).map_err(|e| DeserializationError::Toml(e).into()),
-            Some(ext) => Err(ComponentImportError::UnsupportedFormat(ext.to_string())),
-            None => Err(ComponentImportError::UnsupportedFormat(Default::default())),
-        }?;
+        let format = SerializationFormat::from_path(path)
+            .map_err(|SerializationFormatError::UnsupportedFormat(format)| ComponentImportError::UnsupportedFormat(format))?;
+        let content = fs::read_to_string(path)?;
+        let component = format.deserialize::<reactive_graph_graph::Component>(&content)?;
         self.component_manager.register(component).map_err(ComponentImportError::RegistrationError)
     }
 
@@ -50,30 +37,11 @@ impl ComponentImportExportManager for ComponentImportExportManagerImpl {
             return Err(ComponentExportError::ComponentNotFound(ty.clone()));
         };
         let path = Path::new(path);
-        match path.extension().and_then(|ext| ext.to_str()) {
-            Some("json") => match File::create(path) {
-                Ok(file) => serde_json::to_writer_pretty(&file, &component).map_err(|e| SerializationError::Json(e).into()),
-                Err(e) => Err(ComponentExportError::Io(e)),
-            },
-            #[cfg(feature = "json5")]
-            Some("json5") => match File::create(path) {
-                Ok(mut file) => {
-                    let content = json5::to_string(&component).map_err(|e| ComponentExportError::Serialization(SerializationError::Json5(e)))?;
-                    file.write_all(content.as_bytes()).map_err(ComponentExportError::Io)
-                }
-                Err(e) => Err(ComponentExportError::Io(e)),
-            },
-            #[cfg(feature = "toml")]
-            Some("toml") => match File::create(path) {
-                Ok(mut file) => {
-                    let content = toml::to_string_pretty(&component).map_err(|e| ComponentExportError::Serialization(SerializationError::Toml(e)))?;
-                    file.write_all(content.as_bytes()).map_err(ComponentExportError::Io)
-                }
-                Err(e) => Err(ComponentExportError::Io(e)),
-            },
-            Some(ext) => Err(ComponentExportError::UnsupportedFormat(ext.to_string())),
-            None => Err(ComponentExportError::UnsupportedFormat(Default::default())),
-        }
+        let format = SerializationFormat::from_path(path)
+            .map_err(|SerializationFormatError::UnsupportedFormat(format)| ComponentExportError::UnsupportedFormat(format))?;
+        let content = format.serialize(&component)?;
+        fs::write(path, content)?;
+        Ok(())
     }
 }
 
@@ -83,12 +51,15 @@ impl Lifecycle for ComponentImportExportManagerImpl {}
 #[cfg(test)]
 mod test {
     use std::env;
+    use std::fs;
 
     use default_test::DefaultTest;
+    use serde_json::Value;
 
     use crate::TypeSystemImpl;
     use reactive_graph_graph::Component;
     use reactive_graph_graph::NamespacedTypeGetter;
+    use reactive_graph_serde::format::SerializationFormat;
     use reactive_graph_type_system_api::TypeSystem;
 
     #[tokio::test(flavor = "multi_thread")]
@@ -121,4 +92,42 @@ mod test {
             "The imported component should match the constructed component!"
         );
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_export_import_component_formats() {
+        reactive_graph_utils_test::init_logger();
+        let type_system = reactive_graph_di::get_container::<TypeSystemImpl>();
+        let component_manager = type_system.get_component_manager();
+        let component_import_export_manager = type_system.get_component_import_export_manager();
+        let schema: Value = serde_json::from_str(include_str!("../../../../schema/json/component.schema.json")).expect("Invalid JSON schema");
+
+        for format in SerializationFormat::all() {
+            let component = component_manager.register(Component::default_test()).expect("Failed to create component");
+            let component_ty = component.ty.clone();
+
+            let mut path = env::temp_dir();
+            path.push(format!("{}__{}.{}", component_ty.namespace(), component_ty.type_name(), format.extension()));
+            let path = path.into_os_string().into_string().unwrap();
+
+            component_import_export_manager
+                .export(&component_ty, path.as_str())
+                .await
+                .expect("Failed to export component");
+            let document: Value = format
+                .deserialize(&fs::read_to_string(&path).expect("Failed to read exported component"))
+                .expect("Failed to parse exported component");
+            assert_eq!(schema["$id"], document["$id"], "The exported {format} document must reference the JSON schema");
+            for property_name in document.as_object().expect("The exported component must be an object").keys() {
+                assert!(schema["properties"].get(property_name).is_some(), "{property_name} is not defined in the JSON schema");
+            }
+
+            assert!(component_manager.delete(&component_ty), "Failed to delete component!");
+            component_import_export_manager.import(path.as_str()).await.expect("Failed to import component");
+            assert_eq!(
+                component,
+                component_manager.get(&component_ty).unwrap(),
+                "The {format} round trip should not change the component!"
+            );
+        }
+    }
 }
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use log::debug;
+use log::warn;
 use springtime_di::Component;
 use springtime_di::component_alias;
 
@@ -25,10 +26,12 @@ use reactive_graph_graph::Namespaces;
 use reactive_graph_graph::PropertyType;
 use reactive_graph_graph::PropertyTypeContainer;
 use reactive_graph_graph::PropertyTypes;
+use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_lifecycle::Lifecycle;
 use reactive_graph_type_system_api::ComponentCreationError;
 use reactive_graph_type_system_api::ComponentManager;
 use reactive_graph_type_system_api::ComponentRegistrationError;
+use reactive_graph_type_system_api::TypeDeleteGuard;
 use reactive_graph_type_system_api::TypeSystemEvent;
 use reactive_graph_type_system_api::TypeSystemEventManager;
 
@@ -36,6 +39,8 @@ use reactive_graph_type_system_api::TypeSystemEventManager;
 pub struct ComponentManagerImpl {
     event_manager: Arc<dyn TypeSystemEventManager + Send + Sync>,
 
+    type_delete_guard: Arc<dyn TypeDeleteGuard + Send + Sync>,
+
     #[component(default = "Components::new")]
     components: Components,
 }
@@ -241,6 +246,10 @@ impl ComponentManager for ComponentManagerImpl {
     }
 
     fn delete(&self, ty: &ComponentTypeId) -> bool {
+        if let Err(e) = self.type_delete_guard.check(&ty.type_definition()) {
+            warn!("{e}");
+            return false;
+        }
         self.components
             .remove(ty)
             .inspect(|(ty, _)| self.event_manager.emit_event(TypeSystemEvent::ComponentDeleted(ty.clone())))
@@ -7,15 +7,19 @@ use springtime_di::Component;
 use springtime_di::component_alias;
 
 use reactive_graph_graph::Components;
+use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_lifecycle::Lifecycle;
 use reactive_graph_type_system_api::ComponentManager;
 use reactive_graph_type_system_api::ComponentProviderRegistry;
+use reactive_graph_type_system_api::TypeDeleteGuard;
 use reactive_graph_type_system_api::TypeProvider;
 
 #[derive(Component)]
 pub struct ComponentProviderRegistryImpl {
     component_manager: Arc<dyn ComponentManager + Send + Sync>,
 
+    type_delete_guard: Arc<dyn TypeDeleteGuard + Send + Sync>,
+
     #[component(default = "DashMap::new")]
     providers: DashMap<String, Arc<dyn TypeProvider<Components>>>,
 }
@@ -46,7 +50,11 @@ impl ComponentProviderRegistry for ComponentProviderRegistryImpl {
             trace!("Unregistering provider {id}");
             for ty in provider.get_type_ids().into_iter() {
                 trace!("Unregistering component: {ty}");
+                // The types provided by the provider are removed, even if they are still referenced
+                let type_definition = ty.type_definition();
+                self.type_delete_guard.permit(&type_definition);
                 self.component_manager.delete(&ty);
+                self.type_delete_guard.revoke(&type_definition);
             }
         }
     }
@@ -1,8 +1,4 @@
-use std::fs::File;
-use std::io::BufReader;
-use std::io::Read;
-#[allow(unused_imports)]
-use std::io::Write;
+use std::fs;
 use std::path::Path;
 use std::sync::Arc;
 
@@ -13,8 +9,8 @@ use springtime_di::component_alias;
 use reactive_graph_graph::EntityType;
 use reactive_graph_graph::EntityTypeId;
 use reactive_graph_lifecycle::Lifecycle;
-use reactive_graph_serde::error::DeserializationError;
-use reactive_graph_serde::error::SerializationError;
+use reactive_graph_serde::error::SerializationFormatError;
+use reactive_graph_serde::format::SerializationFormat;
 use reactive_graph_type_system_api::EntityTypeExportError;
 use reactive_graph_type_system_api::EntityTypeImportError;
 use reactive_graph_type_system_api::EntityTypeImportExportManager;
@@ -30,19 +26,10 @@ pub struct EntityTypeImportExportManagerImpl {
 impl EntityTypeImportExportManager for EntityTypeImportExportManagerImpl {
     async fn import(&self, path: &str) -> Result<EntityType, EntityTypeImportError> {
         let path = Path::new(path);
-        let file = File::open(path)?;
-        let mut reader = BufReader::new(file);
-        let mut content = String::new();
-        reader.read_to_string(&mut content)?;
-        let entity_type = match path.extension().and_then(|ext| ext.to_str()) {
-            Some("json") => serde_json::from_str::<EntityType>(&content).map_err(|e| DeserializationError::Json(e).into()),
-            #[cfg(feature = "json5")]
-            Some("json5") => json5::from_str::<EntityType>(&content).map_err(|e| DeserializationError::Json5(e).into()),
-            #[cfg(feature = "toml")]
-            Some("toml") => toml::from_str::<EntityType>(&content).map_err(|e| DeserializationError::Toml(e).into()),
-            Some(ext) => Err(EntityTypeImportError::UnsupportedFormat(ext.to_string())),
-            None => Err(EntityTypeImportError::UnsupportedFormat(Default::default())),
-        }?;
+        let format = SerializationFormat::from_path(path)
+            .map_err(|SerializationFormatError::UnsupportedFormat(format)| EntityTypeImportError::UnsupportedFormat(format))?;
+        let content = fs::read_to_string(path)?;
+        let entity_type = format.deserialize::<EntityType>(&content)?;
         self.entity_type_manager.register(entity_type).map_err(EntityTypeImportError::RegistrationError)
     }
 
@@ -51,30 +38,11 @@ impl EntityTypeImportExportManager for EntityTypeImportExportManagerImpl {
             return Err(EntityTypeExportError::EntityTypeNotFound(ty.clone()));
         };
         let path = Path::new(path);
-        match path.extension().and_then(|ext| ext.to_str()) {
-            Some("json") => match File::create(path) {
-                Ok(file) => serde_json::to_writer_pretty(&file, &entity_type).map_err(|e| SerializationError::Json(e).into()),
-                Err(e) => Err(EntityTypeExportError::Io(e)),
-            },
-            #[cfg(feature = "json5")]
-            Some("json5") => match File::create(path) {
-                Ok(mut file) => {
-                    let content = json5::to_string(&entity_type).map_err(|e| EntityTypeExportError::Serialization(SerializationError::Json5(e)))?;
-                    file.write_all(content.as_bytes()).map_err(EntityTypeExportError::Io)
-                }
-                Err(e) => Err(EntityTypeExportError::Io(e)),
-            },
-            #[cfg(feature = "toml")]
-            Some("toml") => match File::create(path) {
-                Ok(mut file) => {
-                    let content = toml::to_string_pretty(&entity_type).map_err(|e| EntityTypeExportError::Serialization(SerializationError::Toml(e)))?;
-                    file.write_all(content.as_bytes()).map_err(EntityTypeExportError::Io)
-                }
-                Err(e) => Err(EntityTypeExportError::Io(e)),
-            },
-            Some(ext) => Err(EntityTypeExportError::UnsupportedFormat(ext.to_string())),
-            None => Err(EntityTypeExportError::UnsupportedFormat(Default::default())),
-        }
+        let format = SerializationFormat::from_path(path)
+            .map_err(|SerializationFormatError::UnsupportedFormat(format)| EntityTypeExportError::UnsupportedFormat(format))?;
+        let content = format.serialize(&entity_type)?;
+        fs::write(path, content)?;
+        Ok(())
     }
 }
 
@@ -84,12 +52,15 @@ impl Lifecycle for EntityTypeImportExportManagerImpl {}
 #[cfg(test)]
 mod test {
     use std::env;
+    use std::fs;
 
     use default_test::DefaultTest;
+    use serde_json::Value;
 
     use crate::TypeSystemImpl;
     use reactive_graph_graph::EntityType;
     use reactive_graph_graph::NamespacedTypeGetter;
+    use reactive_graph_serde::format::SerializationFormat;
     use reactive_graph_type_system_api::TypeSystem;
 
     #[tokio::test(flavor = "multi_thread")]
@@ -130,4 +101,47 @@ mod test {
             "The imported entity type should match with the constructed entity type!"
         );
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_export_import_entity_type_formats() {
+        reactive_graph_utils_test::init_logger();
+        let type_system = reactive_graph_di::get_container::<TypeSystemImpl>();
+        let entity_type_manager = type_system.get_entity_type_manager();
+        let entity_type_import_export_manager = type_system.get_entity_type_import_export_manager();
+        let schema: Value = serde_json::from_str(include_str!("../../../../schema/json/entity-type.schema.json")).expect("Invalid JSON schema");
+
+        for format in SerializationFormat::all() {
+            let entity_type = entity_type_manager
+                .register(EntityType::default_test())
+                .expect("Failed to register entity type!");
+            let entity_ty = entity_type.ty.clone();
+
+            let mut path = env::temp_dir();
+            path.push(format!("{}__{}.{}", entity_ty.namespace(), entity_ty.type_name(), format.extension()));
+            let path = path.into_os_string().into_string().unwrap();
+
+            entity_type_import_export_manager
+                .export(&entity_ty, path.as_str())
+                .await
+                .expect("Failed to export the entity type!");
+            let document: Value = format
+                .deserialize(&fs::read_to_string(&path).expect("Failed to read exported entity type"))
+                .expect("Failed to parse exported entity type");
+            assert_eq!(schema["$id"], document["$id"], "The exported {format} document must reference the JSON schema");
+            for property_name in document.as_object().expect("The exported entity type must be an object").keys() {
+                assert!(schema["properties"].get(property_name).is_some(), "{property_name} is not defined in the JSON schema");
+            }
+
+            entity_type_manager.delete(&entity_ty).expect("Failed to delete the entity type!");
+            entity_type_import_export_manager
+                .import(path.as_str())
+                .await
+                .expect("Failed to import the entity type!");
+            assert_eq!(
+                entity_type,
+                entity_type_manager.get(&entity_ty).unwrap(),
+                "The {format} round trip should not change the entity type!"
+            );
+        }
+    }
 }
@@ -43,6 +43,7 @@ use reactive_graph_type_system_api::ComponentManager;
 use reactive_graph_type_system_api::EntityTypeCreationError;
 use reactive_graph_type_system_api::EntityTypeManager;
 use reactive_graph_type_system_api::EntityTypeRegistrationError;
+use reactive_graph_type_system_api::TypeDeleteGuard;
 use reactive_graph_type_system_api::TypeSystemEvent;
 use reactive_graph_type_system_api::TypeSystemEventManager;
 
@@ -50,6 +51,8 @@ use reactive_graph_type_system_api::TypeSystemEventManager;
 pub struct EntityTypeManagerImpl {
     event_manager: Arc<dyn TypeSystemEventManager + Send + Sync>,
 
+    type_delete_guard: Arc<dyn TypeDeleteGuard + Send + Sync>,
+
     component_manager: Arc<dyn ComponentManager + Send + Sync>,
 
     #[component(default = "EntityTypes::new")]
@@ -315,6 +318,10 @@ impl EntityTypeManager for EntityTypeManagerImpl {
     // TODO: parameter "cascade": relation types, flow types and entity instances (and their dependencies) depends on a entity type
     // TODO: first delete the entity instance of this type, then delete the entity type itself.
     fn delete(&self, entity_ty: &EntityTypeId) -> Option<EntityType> {
+        if let Err(e) = self.type_delete_guard.check(&entity_ty.type_definition()) {
+            warn!("{e}");
+            return None;
+        }
         self.entity_types.remove(entity_ty).map(|(entity_ty, entity_type)| {
             self.event_manager.emit_event(TypeSystemEvent::EntityTypeDeleted(entity_ty.clone()));
             entity_type
@@ -7,15 +7,19 @@ use springtime_di::Component;
 use springtime_di::component_alias;
 
 use reactive_graph_graph::EntityTypes;
+use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_lifecycle::Lifecycle;
 use reactive_graph_type_system_api::EntityTypeManager;
 use reactive_graph_type_system_api::EntityTypeProviderRegistry;
+use reactive_graph_type_system_api::TypeDeleteGuard;
 use reactive_graph_type_system_api::TypeProvider;
 
 #[derive(Component)]
 pub struct EntityTypeProviderRegistryImpl {
     entity_type_manager: Arc<dyn EntityTypeManager + Send + Sync>,
 
+    type_delete_guard: Arc<dyn TypeDeleteGuard + Send + Sync>,
+
     #[component(default = "DashMap::new")]
     providers: DashMap<String, Arc<dyn TypeProvider<EntityTypes>>>, // EntityTypesProviders,
 }
@@ -46,7 +50,11 @@ impl EntityTypeProviderRegistry for EntityTypeProviderRegistryImpl {
             trace!("Unregistering provider {id}");
             for ty in provider.get_type_ids().into_iter() {
                 trace!("Unregistering entity type: {ty}");
+                // The types provided by the provider are removed, even if they are still referenced
+                let type_definition = ty.type_definition();
+                self.type_delete_guard.permit(&type_definition);
                 self.entity_type_manager.delete(&ty);
+                self.type_delete_guard.revoke(&type_definition);
             }
         }
     }
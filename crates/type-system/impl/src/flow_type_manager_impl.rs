@@ -40,6 +40,7 @@ use reactive_graph_graph::PropertyTypes;
 use reactive_graph_graph::RelationInstance;
 use reactive_graph_graph::RelationInstanceId;
 use reactive_graph_graph::RelationInstances;
+use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_graph::Variable;
 use reactive_graph_lifecycle::Lifecycle;
 use reactive_graph_type_system_api::EntityTypeManager;
@@ -47,6 +48,7 @@ use reactive_graph_type_system_api::FlowTypeCreationError;
 use reactive_graph_type_system_api::FlowTypeManager;
 use reactive_graph_type_system_api::FlowTypeRegistrationError;
 use reactive_graph_type_system_api::RelationTypeManager;
+use reactive_graph_type_system_api::TypeDeleteGuard;
 use reactive_graph_type_system_api::TypeSystemEvent;
 use reactive_graph_type_system_api::TypeSystemEventManager;
 
@@ -54,6 +56,8 @@ use reactive_graph_type_system_api::TypeSystemEventManager;
 pub struct FlowTypeManagerImpl {
     event_manager: Arc<dyn TypeSystemEventManager + Send + Sync>,
 
+    type_delete_guard: Arc<dyn TypeDeleteGuard + Send + Sync>,
+
     entity_type_manager: Arc<dyn EntityTypeManager + Send + Sync>,
 
     relation_type_manager: Arc<dyn RelationTypeManager + Send + Sync>,
@@ -251,6 +255,10 @@ impl FlowTypeManager for FlowTypeManagerImpl {
     }
 
     fn delete(&self, flow_ty: &FlowTypeId) -> Option<FlowType> {
+        if let Err(e) = self.type_delete_guard.check(&flow_ty.type_definition()) {
+            warn!("{e}");
+            return None;
+        }
         self.flow_types.remove(flow_ty).map(|(flow_ty, flow_type)| {
             self.event_manager.emit_event(TypeSystemEvent::FlowTypeDeleted(flow_ty.clone()));
             flow_type
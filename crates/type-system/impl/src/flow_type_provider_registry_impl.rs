@@ -7,15 +7,19 @@ use springtime_di::Component;
 use springtime_di::component_alias;
 
 use reactive_graph_graph::FlowTypes;
+use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_lifecycle::Lifecycle;
 use reactive_graph_type_system_api::FlowTypeManager;
 use reactive_graph_type_system_api::FlowTypeProviderRegistry;
+use reactive_graph_type_system_api::TypeDeleteGuard;
 use reactive_graph_type_system_api::TypeProvider;
 
 #[derive(Component)]
 pub struct FlowTypeProviderRegistryImpl {
     flow_type_manager: Arc<dyn FlowTypeManager + Send + Sync>,
 
+    type_delete_guard: Arc<dyn TypeDeleteGuard + Send + Sync>,
+
     #[component(default = "DashMap::new")]
     providers: DashMap<String, Arc<dyn TypeProvider<FlowTypes>>>,
 }
@@ -46,7 +50,11 @@ impl FlowTypeProviderRegistry for FlowTypeProviderRegistryImpl {
             trace!("Unregistering provider {id}");
             for ty in provider.get_type_ids().into_iter() {
                 trace!("Unregistering flow type: {ty}");
+                // The types provided by the provider are removed, even if they are still referenced
+                let type_definition = ty.type_definition();
+                self.type_delete_guard.permit(&type_definition);
                 self.flow_type_manager.delete(&ty);
+                self.type_delete_guard.revoke(&type_definition);
             }
         }
     }
@@ -13,6 +13,8 @@ pub use relation_type_import_export_manager_impl::*;
 pub use relation_type_manager_impl::*;
 pub use relation_type_provider_registry_impl::*;
 pub use runtime_types_provider_impl::*;
+pub use type_delete_guard_impl::*;
+pub use type_dependency_manager_impl::*;
 pub use type_system_event_manager_impl::*;
 pub use type_system_impl::*;
 
@@ -31,5 +33,7 @@ pub mod relation_type_import_export_manager_impl;
 pub mod relation_type_manager_impl;
 pub mod relation_type_provider_registry_impl;
 pub mod runtime_types_provider_impl;
+pub mod type_delete_guard_impl;
+pub mod type_dependency_manager_impl;
 pub mod type_system_event_manager_impl;
 pub mod type_system_impl;
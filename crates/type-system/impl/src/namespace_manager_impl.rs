@@ -2,26 +2,38 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use dashmap::DashMap;
+use dashmap::mapref::entry::Entry;
+use log::debug;
 use springtime_di::Component;
 use springtime_di::component_alias;
 
+use reactive_graph_graph::Namespace;
 use reactive_graph_graph::NamespacedTypeGetter;
 use reactive_graph_type_system_api::ComponentManager;
 use reactive_graph_type_system_api::EntityTypeManager;
+use reactive_graph_type_system_api::FlowTypeManager;
 use reactive_graph_type_system_api::NamespaceManager;
+use reactive_graph_type_system_api::NamespaceRegistrationError;
 use reactive_graph_type_system_api::RelationTypeManager;
+use reactive_graph_type_system_api::TypeSystemEvent;
+use reactive_graph_type_system_api::TypeSystemEventManager;
 
 #[derive(Component)]
 pub struct NamespaceManagerImpl {
+    event_manager: Arc<dyn TypeSystemEventManager + Send + Sync>,
     component_manager: Arc<dyn ComponentManager + Send + Sync>,
     entity_type_manager: Arc<dyn EntityTypeManager + Send + Sync>,
     relation_type_manager: Arc<dyn RelationTypeManager + Send + Sync>,
-    flow_type_manager: Arc<dyn RelationTypeManager + Send + Sync>,
+    flow_type_manager: Arc<dyn FlowTypeManager + Send + Sync>,
+
+    #[component(default = "DashMap::new")]
+    namespaces: DashMap<String, Namespace>,
 }
-#[async_trait]
-#[component_alias]
-impl NamespaceManager for NamespaceManagerImpl {
-    fn get_all(&self) -> HashSet<String> {
+
+impl NamespaceManagerImpl {
+    /// Returns the namespaces of the types.
+    fn get_type_namespaces(&self) -> HashSet<String> {
         self.component_manager
             .get_all()
             .iter()
@@ -32,3 +44,156 @@ impl NamespaceManager for NamespaceManagerImpl {
             .collect()
     }
 }
+
+#[async_trait]
+#[component_alias]
+impl NamespaceManager for NamespaceManagerImpl {
+    fn get_all(&self) -> HashSet<String> {
+        let mut namespaces = self.get_type_namespaces();
+        namespaces.extend(self.namespaces.iter().map(|namespace| namespace.key().clone()));
+        namespaces
+    }
+
+    fn get_namespaces(&self) -> Vec<Namespace> {
+        let mut namespaces: Vec<Namespace> = self.get_all().into_iter().map(|name| self.get(&name).unwrap_or(Namespace::new(name))).collect();
+        namespaces.sort();
+        namespaces
+    }
+
+    fn get(&self, name: &str) -> Option<Namespace> {
+        if let Some(namespace) = self.namespaces.get(name) {
+            return Some(namespace.value().clone());
+        }
+        if self.get_type_namespaces().contains(name) {
+            return Some(Namespace::new(name));
+        }
+        None
+    }
+
+    fn get_by_owner(&self, owner: &str) -> Vec<Namespace> {
+        let mut namespaces: Vec<Namespace> = self
+            .namespaces
+            .iter()
+            .filter(|namespace| namespace.is_owned_by(owner))
+            .map(|namespace| namespace.value().clone())
+            .collect();
+        namespaces.sort();
+        namespaces
+    }
+
+    fn has(&self, name: &str) -> bool {
+        self.is_registered(name) || self.get_type_namespaces().contains(name)
+    }
+
+    fn is_registered(&self, name: &str) -> bool {
+        self.namespaces.contains_key(name)
+    }
+
+    fn register(&self, namespace: Namespace) -> Result<Namespace, NamespaceRegistrationError> {
+        if namespace.name.is_empty() {
+            return Err(NamespaceRegistrationError::EmptyName);
+        }
+        match self.namespaces.entry(namespace.name.clone()) {
+            Entry::Occupied(mut entry) => {
+                if let Some(owner) = &entry.get().owner {
+                    if namespace.owner.as_ref() != Some(owner) {
+                        return Err(NamespaceRegistrationError::NamespaceAlreadyOwned(namespace.name.clone(), owner.clone()));
+                    }
+                }
+                entry.insert(namespace.clone());
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(namespace.clone());
+            }
+        }
+        debug!("Registered namespace {namespace}");
+        self.event_manager.emit_event(TypeSystemEvent::TypeSystemChanged);
+        Ok(namespace)
+    }
+
+    fn unregister(&self, name: &str) -> Option<Namespace> {
+        let (_, namespace) = self.namespaces.remove(name)?;
+        debug!("Unregistered namespace {namespace}");
+        self.event_manager.emit_event(TypeSystemEvent::TypeSystemChanged);
+        Some(namespace)
+    }
+
+    fn unregister_by_owner(&self, owner: &str) -> Vec<Namespace> {
+        self.get_by_owner(owner)
+            .into_iter()
+            .filter_map(|namespace| self.unregister(&namespace.name))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use default_test::DefaultTest;
+
+    use crate::TypeSystemImpl;
+    use reactive_graph_graph::Component;
+    use reactive_graph_graph::Namespace;
+    use reactive_graph_graph::NamespacedTypeGetter;
+    use reactive_graph_type_system_api::NamespaceRegistrationError;
+    use reactive_graph_type_system_api::TypeSystem;
+    use reactive_graph_utils_test::r_string;
+
+    #[test]
+    fn test_namespaces_of_types() {
+        reactive_graph_utils_test::init_logger();
+        let type_system = reactive_graph_di::get_container::<TypeSystemImpl>();
+        let namespace_manager = type_system.get_namespace_manager();
+        let component = Component::default_test();
+        type_system
+            .get_component_manager()
+            .register(component.clone())
+            .expect("Failed to register component");
+        let namespace = component.namespace();
+        assert!(namespace_manager.get_all().contains(&namespace));
+        assert!(namespace_manager.has(&namespace));
+        assert!(!namespace_manager.is_registered(&namespace));
+        assert_eq!(Some(Namespace::new(&namespace)), namespace_manager.get(&namespace));
+    }
+
+    #[test]
+    fn test_register_namespace() {
+        reactive_graph_utils_test::init_logger();
+        let type_system = reactive_graph_di::get_container::<TypeSystemImpl>();
+        let namespace_manager = type_system.get_namespace_manager();
+        let name = r_string();
+        let owner = r_string();
+        let namespace = Namespace::builder()
+            .name(&name)
+            .description("Logical gates")
+            .owner(&owner)
+            .version("1.0.0")
+            .build();
+        namespace_manager.register(namespace.clone()).expect("Failed to register namespace");
+        assert!(namespace_manager.has(&name));
+        assert!(namespace_manager.is_registered(&name));
+        assert!(namespace_manager.get_all().contains(&name));
+        assert_eq!(Some(namespace.clone()), namespace_manager.get(&name));
+        assert_eq!(vec![namespace.clone()], namespace_manager.get_by_owner(&owner));
+        assert!(namespace_manager.get_namespaces().contains(&namespace));
+
+        // The owner can replace the metadata
+        let updated = Namespace::builder().name(&name).description("Updated").owner(&owner).build();
+        namespace_manager.register(updated.clone()).expect("Failed to replace namespace");
+        assert_eq!(Some(updated), namespace_manager.get(&name));
+
+        // Another plugin cannot take over the namespace
+        let other = Namespace::builder().name(&name).owner(r_string()).build();
+        assert!(matches!(namespace_manager.register(other), Err(NamespaceRegistrationError::NamespaceAlreadyOwned(_, _))));
+
+        assert_eq!(1, namespace_manager.unregister_by_owner(&owner).len());
+        assert!(!namespace_manager.has(&name));
+        assert!(namespace_manager.get(&name).is_none());
+    }
+
+    #[test]
+    fn test_register_namespace_with_empty_name() {
+        let type_system = reactive_graph_di::get_container::<TypeSystemImpl>();
+        let namespace_manager = type_system.get_namespace_manager();
+        assert!(matches!(namespace_manager.register(Namespace::new("")), Err(NamespaceRegistrationError::EmptyName)));
+    }
+}
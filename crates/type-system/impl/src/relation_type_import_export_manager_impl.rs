@@ -1,8 +1,4 @@
-use std::fs::File;
-use std::io::BufReader;
-use std::io::Read;
-#[allow(unused_imports)]
-use std::io::Write;
+use std::fs;
 use std::path::Path;
 use std::sync::Arc;
 
@@ -13,8 +9,8 @@ use springtime_di::component_alias;
 use reactive_graph_graph::RelationType;
 use reactive_graph_graph::RelationTypeId;
 use reactive_graph_lifecycle::Lifecycle;
-use reactive_graph_serde::error::DeserializationError;
-use reactive_graph_serde::error::SerializationError;
+use reactive_graph_serde::error::SerializationFormatError;
+use reactive_graph_serde::format::SerializationFormat;
 use reactive_graph_type_system_api::RelationTypeExportError;
 use reactive_graph_type_system_api::RelationTypeImportError;
 use reactive_graph_type_system_api::RelationTypeImportExportManager;
@@ -30,19 +26,10 @@ pub struct RelationTypeImportExportManagerImpl {
 impl RelationTypeImportExportManager for RelationTypeImportExportManagerImpl {
     async fn import(&self, path: &str) -> Result<RelationType, RelationTypeImportError> {
         let path = Path::new(path);
-        let file = File::open(path)?;
-        let mut reader = BufReader::new(file);
-        let mut content = String::new();
-        reader.read_to_string(&mut content)?;
-        let relation_type = match path.extension().and_then(|ext| ext.to_str()) {
-            Some("json") => serde_json::from_str::<RelationType>(&content).map_err(|e| DeserializationError::Json(e).into()),
-            #[cfg(feature = "json5")]
-            Some("json5") => json5::from_str::<RelationType>(&content).map_err(|e| DeserializationError::Json5(e).into()),
-            #[cfg(feature = "toml")]
-            Some("toml") => toml::from_str::<RelationType>(&content).map_err(|e| DeserializationError::Toml(e).into()),
-            Some(ext) => Err(RelationTypeImportError::UnsupportedFormat(ext.to_string())),
-            None => Err(RelationTypeImportError::UnsupportedFormat(Default::default())),
-        }?;
+        let format = SerializationFormat::from_path(path)
+            .map_err(|SerializationFormatError::UnsupportedFormat(format)| RelationTypeImportError::UnsupportedFormat(format))?;
+        let content = fs::read_to_string(path)?;
+        let relation_type = format.deserialize::<RelationType>(&content)?;
         self.relation_type_manager
             .register(relation_type)
             .map_err(RelationTypeImportError::RegistrationError)
@@ -53,30 +40,11 @@ impl RelationTypeImportExportManager for RelationTypeImportExportManagerImpl {
             return Err(RelationTypeExportError::RelationTypeNotFound(ty.clone()));
         };
         let path = Path::new(path);
-        match path.extension().and_then(|ext| ext.to_str()) {
-            Some("json") => match File::create(path) {
-                Ok(file) => serde_json::to_writer_pretty(&file, &relation_type).map_err(|e| SerializationError::Json(e).into()),
-                Err(e) => Err(RelationTypeExportError::Io(e)),
-            },
-            #[cfg(feature = "json5")]
-            Some("json5") => match File::create(path) {
-                Ok(mut file) => {
-                    let content = json5::to_string(&relation_type).map_err(|e| RelationTypeExportError::Serialization(SerializationError::Json5(e)))?;
-                    file.write_all(content.as_bytes()).map_err(RelationTypeExportError::Io)
-                }
-                Err(e) => Err(RelationTypeExportError::Io(e)),
-            },
-            #[cfg(feature = "toml")]
-            Some("toml") => match File::create(path) {
-                Ok(mut file) => {
-                    let content = toml::to_string_pretty(&relation_type).map_err(|e| RelationTypeExportError::Serialization(SerializationError::Toml(e)))?;
-                    file.write_all(content.as_bytes()).map_err(RelationTypeExportError::Io)
-                }
-                Err(e) => Err(RelationTypeExportError::Io(e)),
-            },
-            Some(ext) => Err(RelationTypeExportError::UnsupportedFormat(ext.to_string())),
-            None => Err(RelationTypeExportError::UnsupportedFormat(Default::default())),
-        }
+        let format = SerializationFormat::from_path(path)
+            .map_err(|SerializationFormatError::UnsupportedFormat(format)| RelationTypeExportError::UnsupportedFormat(format))?;
+        let content = format.serialize(&relation_type)?;
+        fs::write(path, content)?;
+        Ok(())
     }
 }
 
@@ -38,6 +38,7 @@ use reactive_graph_graph::RelationTypeUpdateError;
 use reactive_graph_graph::RelationTypeUpdateExtensionError;
 use reactive_graph_graph::RelationTypeUpdatePropertyError;
 use reactive_graph_graph::RelationTypes;
+use reactive_graph_graph::TypeDefinitionGetter;
 use reactive_graph_lifecycle::Lifecycle;
 use reactive_graph_runtime_model::EXTENSION_DIVERGENT;
 use reactive_graph_type_system_api::ComponentManager;
@@ -45,6 +46,7 @@ use reactive_graph_type_system_api::EntityTypeManager;
 use reactive_graph_type_system_api::RelationTypeCreationError;
 use reactive_graph_type_system_api::RelationTypeManager;
 use reactive_graph_type_system_api::RelationTypeRegistrationError;
+use reactive_graph_type_system_api::TypeDeleteGuard;
 use reactive_graph_type_system_api::TypeSystemEvent;
 use reactive_graph_type_system_api::TypeSystemEventManager;
 
@@ -52,6 +54,8 @@ use reactive_graph_type_system_api::TypeSystemEventManager;
 pub struct RelationTypeManagerImpl {
     event_manager: Arc<dyn TypeSystemEventManager + Send + Sync>,
 
+    type_delete_guard: Arc<dyn TypeDeleteGuard + Send + Sync>,
+
     component_manager: Arc<dyn ComponentManager + Send + Sync>,
 
     entity_type_manager: Arc<dyn EntityTypeManager + Send + Sync>,
@@ -382,6 +386,10 @@ impl RelationTypeManager for RelationTypeManagerImpl {
 
     // TODO: parameter "cascade": flow types and relation instances (and their dependencies) depends on a relation type
     fn delete(&self, ty: &RelationTypeId) -> Option<RelationType> {
+        if let Err(e) = self.type_delete_guard.check(&ty.type_definition()) {
+            warn!("{e}");
+            return None;
+        }
         self.relation_types.remove(ty).map(|(ty, relation_type)| {
             self.event_manager.emit_event(TypeSystemEvent::RelationTypeDeleted(ty.clone()));
             relation_type
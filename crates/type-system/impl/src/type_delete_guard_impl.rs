@@ -0,0 +1,71 @@
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use dashmap::DashSet;
+use springtime_di::Component;
+use springtime_di::component_alias;
+
+use reactive_graph_graph::TypeDefinition;
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_type_system_api::TypeDeleteError;
+use reactive_graph_type_system_api::TypeDeleteGuard;
+use reactive_graph_type_system_api::TypeDependencyManager;
+
+pub type TypeDependencyManagerBinding = RwLock<Option<Arc<dyn TypeDependencyManager + Send + Sync>>>;
+
+fn create_type_dependency_manager_binding() -> TypeDependencyManagerBinding {
+    RwLock::new(None)
+}
+
+#[derive(Component)]
+pub struct TypeDeleteGuardImpl {
+    #[component(default = "create_type_dependency_manager_binding")]
+    type_dependency_manager: TypeDependencyManagerBinding,
+
+    #[component(default = "DashSet::new")]
+    permitted: DashSet<TypeDefinition>,
+}
+
+#[async_trait]
+#[component_alias]
+impl TypeDeleteGuard for TypeDeleteGuardImpl {
+    fn bind(&self, type_dependency_manager: Arc<dyn TypeDependencyManager + Send + Sync>) {
+        let mut writer = self.type_dependency_manager.write().unwrap();
+        *writer = Some(type_dependency_manager);
+    }
+
+    fn check(&self, ty: &TypeDefinition) -> Result<(), TypeDeleteError> {
+        if self.permitted.contains(ty) {
+            return Ok(());
+        }
+        let reader = self.type_dependency_manager.read().unwrap();
+        let Some(type_dependency_manager) = reader.as_ref() else {
+            return Ok(());
+        };
+        let dependents = type_dependency_manager.get_dependents(ty).len();
+        let instances = type_dependency_manager.count_instance_references(ty);
+        if dependents > 0 || instances > 0 {
+            return Err(TypeDeleteError::TypeIsReferenced(ty.clone(), dependents, instances));
+        }
+        Ok(())
+    }
+
+    fn permit(&self, ty: &TypeDefinition) {
+        self.permitted.insert(ty.clone());
+    }
+
+    fn revoke(&self, ty: &TypeDefinition) {
+        self.permitted.remove(ty);
+    }
+}
+
+#[async_trait]
+impl Lifecycle for TypeDeleteGuardImpl {
+    async fn shutdown(&self) {
+        // The type dependency manager depends on the type managers, which depend on the guard
+        let mut writer = self.type_dependency_manager.write().unwrap();
+        *writer = None;
+        self.permitted.clear();
+    }
+}
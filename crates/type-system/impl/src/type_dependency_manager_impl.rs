@@ -0,0 +1,383 @@
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dashmap::DashMap;
+use log::debug;
+use log::trace;
+use springtime_di::Component;
+use springtime_di::component_alias;
+
+use reactive_graph_graph::ComponentOrEntityTypeId;
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::EntityTypeId;
+use reactive_graph_graph::FlowTypeId;
+use reactive_graph_graph::NamespacedTypeGetter;
+use reactive_graph_graph::RelationTypeId;
+use reactive_graph_graph::TypeDefinition;
+use reactive_graph_graph::TypeDefinitionGetter;
+use reactive_graph_graph::TypeIdType;
+use reactive_graph_lifecycle::Lifecycle;
+use reactive_graph_type_system_api::ComponentManager;
+use reactive_graph_type_system_api::EntityTypeManager;
+use reactive_graph_type_system_api::FlowTypeManager;
+use reactive_graph_type_system_api::RelationTypeManager;
+use reactive_graph_type_system_api::TypeDeleteError;
+use reactive_graph_type_system_api::TypeDeleteGuard;
+use reactive_graph_type_system_api::TypeDeletePolicy;
+use reactive_graph_type_system_api::TypeDeleteReport;
+use reactive_graph_type_system_api::TypeDependency;
+use reactive_graph_type_system_api::TypeDependencyKind;
+use reactive_graph_type_system_api::TypeDependencyManager;
+use reactive_graph_type_system_api::TypeInstanceReferenceProvider;
+use reactive_graph_type_system_api::TypeValidationIssue;
+use reactive_graph_type_system_api::TypeValidationReport;
+
+/// The wildcard type name of the outbound or inbound type of relation types.
+const WILDCARD_TYPE_NAME: &str = "*";
+
+#[derive(Component)]
+pub struct TypeDependencyManagerImpl {
+    component_manager: Arc<dyn ComponentManager + Send + Sync>,
+
+    entity_type_manager: Arc<dyn EntityTypeManager + Send + Sync>,
+
+    relation_type_manager: Arc<dyn RelationTypeManager + Send + Sync>,
+
+    flow_type_manager: Arc<dyn FlowTypeManager + Send + Sync>,
+
+    type_delete_guard: Arc<dyn TypeDeleteGuard + Send + Sync>,
+
+    #[component(default = "DashMap::new")]
+    instance_reference_providers: DashMap<String, Arc<dyn TypeInstanceReferenceProvider>>,
+}
+
+impl TypeDependencyManagerImpl {
+    /// Returns all types of the type system.
+    fn get_all_types(&self) -> Vec<TypeDefinition> {
+        self.component_manager
+            .get_type_ids()
+            .iter()
+            .map(|ty| ty.type_definition())
+            .chain(self.entity_type_manager.get_type_ids().iter().map(|ty| ty.type_definition()))
+            .chain(self.relation_type_manager.get_type_ids().iter().map(|ty| ty.type_definition()))
+            .chain(self.flow_type_manager.get_type_ids().iter().map(|ty| ty.type_definition()))
+            .collect()
+    }
+
+    /// Returns the references of all types of the type system.
+    fn get_all_dependencies(&self) -> Vec<TypeDependency> {
+        self.get_all_types().iter().flat_map(|ty| self.get_dependencies(ty)).collect()
+    }
+
+    /// Deletes the given type. The delete policy has already been applied.
+    fn delete_type(&self, ty: &TypeDefinition) -> bool {
+        self.type_delete_guard.permit(ty);
+        let deleted = match ty.type_id_type {
+            TypeIdType::Component => ComponentTypeId::try_from(ty).map(|ty| self.component_manager.delete(&ty)).unwrap_or(false),
+            TypeIdType::EntityType => EntityTypeId::try_from(ty).ok().and_then(|ty| self.entity_type_manager.delete(&ty)).is_some(),
+            TypeIdType::RelationType => RelationTypeId::try_from(ty)
+                .ok()
+                .and_then(|ty| self.relation_type_manager.delete(&ty))
+                .is_some(),
+            TypeIdType::FlowType => FlowTypeId::try_from(ty).ok().and_then(|ty| self.flow_type_manager.delete(&ty)).is_some(),
+            _ => false,
+        };
+        self.type_delete_guard.revoke(ty);
+        deleted
+    }
+
+    fn delete_instance_references(&self, ty: &TypeDefinition) -> usize {
+        self.instance_reference_providers.iter().map(|provider| provider.delete_references(ty)).sum()
+    }
+}
+
+fn component_or_entity_type_dependency(dependent: &TypeDefinition, ty: &ComponentOrEntityTypeId, kind: TypeDependencyKind) -> Option<TypeDependency> {
+    if ty.type_name() == WILDCARD_TYPE_NAME {
+        return None;
+    }
+    Some(TypeDependency::new(dependent.clone(), ty.type_definition(), kind))
+}
+
+#[async_trait]
+#[component_alias]
+impl TypeDependencyManager for TypeDependencyManagerImpl {
+    fn has(&self, ty: &TypeDefinition) -> bool {
+        match ty.type_id_type {
+            TypeIdType::Component => ComponentTypeId::try_from(ty).map(|ty| self.component_manager.has(&ty)).unwrap_or(false),
+            TypeIdType::EntityType => EntityTypeId::try_from(ty).map(|ty| self.entity_type_manager.has(&ty)).unwrap_or(false),
+            TypeIdType::RelationType => RelationTypeId::try_from(ty).map(|ty| self.relation_type_manager.has(&ty)).unwrap_or(false),
+            TypeIdType::FlowType => FlowTypeId::try_from(ty).map(|ty| self.flow_type_manager.has(&ty)).unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    fn get_dependencies(&self, ty: &TypeDefinition) -> Vec<TypeDependency> {
+        match ty.type_id_type {
+            TypeIdType::EntityType => {
+                let Some(entity_type) = EntityTypeId::try_from(ty).ok().and_then(|ty| self.entity_type_manager.get(&ty)) else {
+                    return Vec::new();
+                };
+                entity_type
+                    .components
+                    .iter()
+                    .map(|component_ty| TypeDependency::new(ty.clone(), component_ty.type_definition(), TypeDependencyKind::Component))
+                    .collect()
+            }
+            TypeIdType::RelationType => {
+                let Some(relation_type) = RelationTypeId::try_from(ty).ok().and_then(|ty| self.relation_type_manager.get(&ty)) else {
+                    return Vec::new();
+                };
+                relation_type
+                    .components
+                    .iter()
+                    .map(|component_ty| TypeDependency::new(ty.clone(), component_ty.type_definition(), TypeDependencyKind::Component))
+                    .chain(component_or_entity_type_dependency(ty, &relation_type.outbound_type, TypeDependencyKind::OutboundType))
+                    .chain(component_or_entity_type_dependency(ty, &relation_type.inbound_type, TypeDependencyKind::InboundType))
+                    .collect()
+            }
+            TypeIdType::FlowType => {
+                let Some(flow_type) = FlowTypeId::try_from(ty).ok().and_then(|ty| self.flow_type_manager.get(&ty)) else {
+                    return Vec::new();
+                };
+                let mut dependencies = vec![TypeDependency::new(
+                    ty.clone(),
+                    flow_type.wrapper_type().type_definition(),
+                    TypeDependencyKind::WrapperEntityType,
+                )];
+                for entity_ty in flow_type.entity_instances.get_type_ids().iter() {
+                    dependencies.push(TypeDependency::new(ty.clone(), entity_ty.type_definition(), TypeDependencyKind::EntityInstance));
+                }
+                for relation_ty in flow_type.relation_instances.get_type_ids().iter() {
+                    dependencies.push(TypeDependency::new(ty.clone(), relation_ty.type_definition(), TypeDependencyKind::RelationInstance));
+                }
+                dependencies
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn get_dependents(&self, ty: &TypeDefinition) -> Vec<TypeDependency> {
+        self.get_all_dependencies()
+            .into_iter()
+            .filter(|dependency| &dependency.dependency == ty && &dependency.dependent != ty)
+            .collect()
+    }
+
+    fn get_transitive_dependents(&self, ty: &TypeDefinition) -> Vec<TypeDefinition> {
+        let dependencies = self.get_all_dependencies();
+        let mut visited = HashSet::new();
+        visited.insert(ty.clone());
+        // Breadth first search over the reversed references
+        let mut dependents = Vec::new();
+        let mut queue = VecDeque::from([ty.clone()]);
+        while let Some(current) = queue.pop_front() {
+            for dependency in dependencies.iter().filter(|dependency| dependency.dependency == current) {
+                if visited.insert(dependency.dependent.clone()) {
+                    dependents.push(dependency.dependent.clone());
+                    queue.push_back(dependency.dependent.clone());
+                }
+            }
+        }
+        // The most distant dependents have to be deleted first
+        dependents.reverse();
+        dependents
+    }
+
+    fn count_instance_references(&self, ty: &TypeDefinition) -> usize {
+        self.instance_reference_providers.iter().map(|provider| provider.count_references(ty)).sum()
+    }
+
+    fn validate(&self, ty: &TypeDefinition) -> TypeValidationReport {
+        let mut report = TypeValidationReport::new(ty.clone());
+        if !self.has(ty) {
+            report.issues.push(TypeValidationIssue::TypeDoesNotExist(ty.clone()));
+            return report;
+        }
+        for dependency in self.get_dependencies(ty) {
+            if !self.has(&dependency.dependency) {
+                report.issues.push(TypeValidationIssue::MissingDependency(dependency));
+            }
+        }
+        report
+    }
+
+    fn validate_all(&self) -> Vec<TypeValidationReport> {
+        self.get_all_types()
+            .iter()
+            .map(|ty| self.validate(ty))
+            .filter(|report| !report.is_valid())
+            .collect()
+    }
+
+    fn delete(&self, ty: &TypeDefinition, policy: TypeDeletePolicy) -> Result<TypeDeleteReport, TypeDeleteError> {
+        match ty.type_id_type {
+            TypeIdType::Component | TypeIdType::EntityType | TypeIdType::RelationType | TypeIdType::FlowType => {}
+            type_id_type => return Err(TypeDeleteError::UnsupportedTypeIdType(ty.clone(), type_id_type)),
+        }
+        if !self.has(ty) {
+            return Err(TypeDeleteError::TypeDoesNotExist(ty.clone()));
+        }
+        let mut report = TypeDeleteReport::default();
+        match policy {
+            TypeDeletePolicy::Refuse => {
+                let dependents = self.get_dependents(ty);
+                let instances = self.count_instance_references(ty);
+                if !dependents.is_empty() || instances > 0 {
+                    return Err(TypeDeleteError::TypeIsReferenced(ty.clone(), dependents.len(), instances));
+                }
+            }
+            TypeDeletePolicy::Cascade => {
+                for dependent in self.get_transitive_dependents(ty) {
+                    trace!("Cascading delete of {ty} to {dependent}");
+                    report.deleted_instances += self.delete_instance_references(&dependent);
+                    if self.delete_type(&dependent) {
+                        report.deleted_types.push(dependent);
+                    }
+                }
+                report.deleted_instances += self.delete_instance_references(ty);
+            }
+            TypeDeletePolicy::Orphan => {
+                report.orphans = self.get_dependents(ty);
+                report.orphaned_instances = self.count_instance_references(ty);
+            }
+        }
+        if self.delete_type(ty) {
+            report.deleted_types.push(ty.clone());
+        }
+        debug!("Deleted type {ty} with policy {policy:?}");
+        Ok(report)
+    }
+
+    fn register_instance_reference_provider(&self, provider: Arc<dyn TypeInstanceReferenceProvider>) {
+        trace!("Registering instance reference provider {}", provider.id());
+        self.instance_reference_providers.insert(provider.id().to_string(), provider);
+    }
+
+    fn unregister_instance_reference_provider(&self, id: &str) {
+        trace!("Unregistering instance reference provider {id}");
+        self.instance_reference_providers.remove(id);
+    }
+}
+
+#[async_trait]
+impl Lifecycle for TypeDependencyManagerImpl {
+    async fn shutdown(&self) {
+        self.instance_reference_providers.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use default_test::DefaultTest;
+
+    use crate::TypeSystemImpl;
+    use reactive_graph_graph::Component;
+    use reactive_graph_graph::EntityType;
+    use reactive_graph_graph::EntityTypeId;
+    use reactive_graph_graph::Extensions;
+    use reactive_graph_graph::PropertyTypes;
+    use reactive_graph_graph::TypeDefinitionGetter;
+    use reactive_graph_lifecycle::Lifecycle;
+    use reactive_graph_type_system_api::TypeDeleteError;
+    use reactive_graph_type_system_api::TypeDeletePolicy;
+    use reactive_graph_type_system_api::TypeDependencyKind;
+    use reactive_graph_type_system_api::TypeSystem;
+    use reactive_graph_type_system_api::TypeValidationIssue;
+
+    fn create_entity_type_with_component(type_system: &TypeSystemImpl) -> (Component, EntityType) {
+        let component = Component::default_test();
+        type_system
+            .get_component_manager()
+            .register(component.clone())
+            .expect("Failed to register component");
+        let entity_type = EntityType::new(EntityTypeId::default_test(), "", vec![component.ty.clone()], PropertyTypes::new(), Extensions::new());
+        let entity_type = type_system
+            .get_entity_type_manager()
+            .register(entity_type)
+            .expect("Failed to register entity type");
+        (component, entity_type)
+    }
+
+    #[test]
+    fn test_get_dependents() {
+        reactive_graph_utils_test::init_logger();
+        let type_system = reactive_graph_di::get_container::<TypeSystemImpl>();
+        let type_dependency_manager = type_system.get_type_dependency_manager();
+        let (component, entity_type) = create_entity_type_with_component(&type_system);
+
+        let dependents = type_dependency_manager.get_dependents(&component.ty.type_definition());
+        assert_eq!(1, dependents.len());
+        assert_eq!(entity_type.ty.type_definition(), dependents[0].dependent);
+        assert_eq!(TypeDependencyKind::Component, dependents[0].kind);
+
+        let dependencies = type_dependency_manager.get_dependencies(&entity_type.ty.type_definition());
+        assert_eq!(1, dependencies.len());
+        assert_eq!(component.ty.type_definition(), dependencies[0].dependency);
+    }
+
+    #[test]
+    fn test_delete_refuse() {
+        reactive_graph_utils_test::init_logger();
+        let type_system = reactive_graph_di::get_container::<TypeSystemImpl>();
+        let type_dependency_manager = type_system.get_type_dependency_manager();
+        let (component, entity_type) = create_entity_type_with_component(&type_system);
+
+        let result = type_dependency_manager.delete(&component.ty.type_definition(), TypeDeletePolicy::Refuse);
+        assert!(matches!(result, Err(TypeDeleteError::TypeIsReferenced(_, 1, 0))));
+        assert!(type_system.get_component_manager().has(&component.ty));
+        assert!(type_system.get_entity_type_manager().has(&entity_type.ty));
+    }
+
+    #[test]
+    fn test_delete_cascade() {
+        reactive_graph_utils_test::init_logger();
+        let type_system = reactive_graph_di::get_container::<TypeSystemImpl>();
+        let type_dependency_manager = type_system.get_type_dependency_manager();
+        let (component, entity_type) = create_entity_type_with_component(&type_system);
+
+        let report = type_dependency_manager
+            .delete(&component.ty.type_definition(), TypeDeletePolicy::Cascade)
+            .expect("Failed to delete component");
+        assert_eq!(vec![entity_type.ty.type_definition(), component.ty.type_definition()], report.deleted_types);
+        assert!(!type_system.get_component_manager().has(&component.ty));
+        assert!(!type_system.get_entity_type_manager().has(&entity_type.ty));
+    }
+
+    #[test]
+    fn test_delete_orphan() {
+        reactive_graph_utils_test::init_logger();
+        let type_system = reactive_graph_di::get_container::<TypeSystemImpl>();
+        let type_dependency_manager = type_system.get_type_dependency_manager();
+        let (component, entity_type) = create_entity_type_with_component(&type_system);
+
+        let report = type_dependency_manager
+            .delete(&component.ty.type_definition(), TypeDeletePolicy::Orphan)
+            .expect("Failed to delete component");
+        assert_eq!(vec![component.ty.type_definition()], report.deleted_types);
+        assert_eq!(1, report.orphans.len());
+        assert!(type_system.get_entity_type_manager().has(&entity_type.ty));
+
+        let validation_report = type_dependency_manager.validate(&entity_type.ty.type_definition());
+        assert!(!validation_report.is_valid());
+        assert!(
+            matches!(&validation_report.issues[0], TypeValidationIssue::MissingDependency(dependency) if dependency.dependency == component.ty.type_definition())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_delete_referenced_type_via_type_manager() {
+        reactive_graph_utils_test::init_logger();
+        let type_system = reactive_graph_di::get_container::<TypeSystemImpl>();
+        type_system.init().await;
+        let (component, entity_type) = create_entity_type_with_component(&type_system);
+
+        // The component manager refuses to delete the component as long as it is referenced
+        assert!(!type_system.get_component_manager().delete(&component.ty));
+        assert!(type_system.get_component_manager().has(&component.ty));
+
+        assert!(type_system.get_entity_type_manager().delete(&entity_type.ty).is_some());
+        assert!(type_system.get_component_manager().delete(&component.ty));
+    }
+}
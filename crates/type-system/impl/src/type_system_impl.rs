@@ -19,6 +19,8 @@ use reactive_graph_type_system_api::RelationTypeImportExportManager;
 use reactive_graph_type_system_api::RelationTypeManager;
 use reactive_graph_type_system_api::RelationTypeProviderRegistry;
 use reactive_graph_type_system_api::RuntimeTypesProvider;
+use reactive_graph_type_system_api::TypeDeleteGuard;
+use reactive_graph_type_system_api::TypeDependencyManager;
 use reactive_graph_type_system_api::TypeSystem;
 use reactive_graph_type_system_api::TypeSystemEventManager;
 
@@ -37,6 +39,8 @@ pub struct TypeSystemImpl {
     relation_type_manager: Arc<dyn RelationTypeManager + Send + Sync>,
     relation_type_import_export_manager: Arc<dyn RelationTypeImportExportManager + Send + Sync>,
     relation_type_provider_registry: Arc<dyn RelationTypeProviderRegistry + Send + Sync>,
+    type_delete_guard: Arc<dyn TypeDeleteGuard + Send + Sync>,
+    type_dependency_manager: Arc<dyn TypeDependencyManager + Send + Sync>,
     type_system_event_manager: Arc<dyn TypeSystemEventManager + Send + Sync>,
     // TODO: move it out of the type system else the type system cannot be empty!
     runtime_types_provider: Arc<dyn RuntimeTypesProvider + Send + Sync>,
@@ -97,6 +101,10 @@ impl TypeSystem for TypeSystemImpl {
         self.relation_type_provider_registry.clone()
     }
 
+    fn get_type_dependency_manager(&self) -> Arc<dyn TypeDependencyManager + Send + Sync> {
+        self.type_dependency_manager.clone()
+    }
+
     fn get_type_system_event_manager(&self) -> Arc<dyn TypeSystemEventManager + Send + Sync> {
         self.type_system_event_manager.clone()
     }
@@ -110,6 +118,9 @@ impl Lifecycle for TypeSystemImpl {
         self.entity_type_manager.init().await;
         self.relation_type_manager.init().await;
         self.flow_type_manager.init().await;
+        self.type_dependency_manager.init().await;
+        self.type_delete_guard.bind(self.type_dependency_manager.clone());
+        self.type_delete_guard.init().await;
         // Type Providers
         self.component_provider_registry.init().await;
         self.entity_type_provider_registry.init().await;
@@ -133,6 +144,8 @@ impl Lifecycle for TypeSystemImpl {
         self.entity_type_manager.post_init().await;
         self.relation_type_manager.post_init().await;
         self.flow_type_manager.post_init().await;
+        self.type_dependency_manager.post_init().await;
+        self.type_delete_guard.post_init().await;
         // Type Providers
         self.component_provider_registry.post_init().await;
         self.entity_type_provider_registry.post_init().await;
@@ -157,6 +170,8 @@ impl Lifecycle for TypeSystemImpl {
         self.entity_type_provider_registry.pre_shutdown().await;
         self.component_provider_registry.pre_shutdown().await;
         // Type System
+        self.type_delete_guard.pre_shutdown().await;
+        self.type_dependency_manager.pre_shutdown().await;
         self.flow_type_manager.pre_shutdown().await;
         self.relation_type_manager.pre_shutdown().await;
         self.entity_type_manager.pre_shutdown().await;
@@ -175,6 +190,8 @@ impl Lifecycle for TypeSystemImpl {
         self.entity_type_provider_registry.shutdown().await;
         self.component_provider_registry.shutdown().await;
         // Type System
+        self.type_delete_guard.shutdown().await;
+        self.type_dependency_manager.shutdown().await;
         self.flow_type_manager.shutdown().await;
         self.relation_type_manager.shutdown().await;
         self.entity_type_manager.shutdown().await;
@@ -0,0 +1,19 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use reactive_graph_serde::error::DeserializationError;
+
+#[derive(Debug, Error)]
+pub enum ModelGeneratorError {
+    #[error("The types directory {0} does not exist")]
+    TypesDirectoryNotFound(PathBuf),
+    #[error("Failed to read the type definition {0}: {1}")]
+    ReadTypeDefinition(PathBuf, std::io::Error),
+    #[error("Failed to deserialize the type definition {0}: {1}")]
+    DeserializeTypeDefinition(PathBuf, DeserializationError),
+    #[error("Failed to write the generated model to {0}: {1}")]
+    WriteModel(PathBuf, std::io::Error),
+    #[error("The environment variable OUT_DIR is not set. The model can only be generated from a build script.")]
+    OutDirNotSet,
+}
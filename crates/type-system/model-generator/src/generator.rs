@@ -0,0 +1,491 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use reactive_graph_graph::Component;
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::DataType;
+use reactive_graph_graph::EntityType;
+use reactive_graph_graph::FlowType;
+use reactive_graph_graph::NamespacedTypeGetter;
+use reactive_graph_graph::PropertyType;
+use reactive_graph_graph::PropertyTypes;
+use reactive_graph_graph::RelationType;
+
+use crate::model::GeneratedModel;
+use crate::model::ModelKind;
+use crate::model::ModelModule;
+use crate::model::join_lines;
+use crate::names::is_identifier;
+use crate::names::pascal_case_ident;
+use crate::names::snake_case_ident;
+use crate::names::upper_snake_case_ident;
+use crate::types::ModelTypes;
+
+/// The names of the builder methods which cannot be used as property setters.
+const RESERVED_BUILDER_METHODS: [&str; 4] = ["new", "id", "build", "default"];
+
+/// Generates the rust model from the given type definitions.
+pub fn generate(types: &ModelTypes) -> GeneratedModel {
+    ModelGenerator::new(types).generate()
+}
+
+/// The identifiers which are generated for a type.
+struct TypeNames {
+    namespace: String,
+    type_name: String,
+    module: String,
+    pascal_case: String,
+    upper_snake_case: String,
+}
+
+impl TypeNames {
+    fn new<T: NamespacedTypeGetter>(ty: &T, qualified: bool) -> Self {
+        let namespace = ty.namespace();
+        let type_name = ty.type_name();
+        let name = if qualified { format!("{namespace}_{type_name}") } else { type_name.clone() };
+        TypeNames {
+            namespace,
+            type_name,
+            module: snake_case_ident(&name),
+            pascal_case: pascal_case_ident(&name),
+            upper_snake_case: upper_snake_case_ident(&name),
+        }
+    }
+
+    fn namespace_const(&self) -> String {
+        namespace_const(&self.namespace)
+    }
+
+    fn properties_enum(&self) -> String {
+        format!("{}Properties", self.pascal_case)
+    }
+}
+
+struct ModelGenerator<'a> {
+    types: &'a ModelTypes,
+}
+
+impl<'a> ModelGenerator<'a> {
+    fn new(types: &'a ModelTypes) -> Self {
+        ModelGenerator { types }
+    }
+
+    fn generate(&self) -> GeneratedModel {
+        let namespaces = self
+            .types
+            .namespaces()
+            .iter()
+            .map(|namespace| format!("pub const {}: &str = {namespace:?};", namespace_const(namespace)))
+            .collect();
+        let mut modules = Vec::new();
+        let qualified = qualified_type_names(self.types.components.iter());
+        for component in &self.types.components {
+            modules.push(self.component(component, TypeNames::new(component, qualified.contains(&component.type_name()))));
+        }
+        let qualified = qualified_type_names(self.types.entity_types.iter());
+        for entity_type in &self.types.entity_types {
+            modules.push(self.entity_type(entity_type, TypeNames::new(entity_type, qualified.contains(&entity_type.type_name()))));
+        }
+        let qualified = qualified_type_names(self.types.relation_types.iter());
+        for relation_type in &self.types.relation_types {
+            modules.push(self.relation_type(relation_type, TypeNames::new(relation_type, qualified.contains(&relation_type.type_name()))));
+        }
+        let qualified = qualified_type_names(self.types.flow_types.iter());
+        for flow_type in &self.types.flow_types {
+            modules.push(self.flow_type(flow_type, TypeNames::new(flow_type, qualified.contains(&flow_type.type_name()))));
+        }
+        GeneratedModel { namespaces, modules }
+    }
+
+    fn component(&self, component: &Component, names: TypeNames) -> ModelModule {
+        let properties = sorted_properties(&component.properties);
+        let mut imports = vec![format!("crate::{}", names.namespace_const())];
+        let mut items = Vec::new();
+        if !properties.is_empty() {
+            imports.push("reactive_graph_graph::properties".to_string());
+            items.push(properties_macro(&names, &properties));
+        }
+        imports.push("reactive_graph_graph::component_ty".to_string());
+        items.push(format!(
+            "component_ty!(COMPONENT_{0}, {1}, COMPONENT_NAME_{0}, {2:?});",
+            names.upper_snake_case,
+            names.namespace_const(),
+            names.type_name
+        ));
+        if let Some(accessors) = component_model_macro(&names.pascal_case, &properties) {
+            imports.push("reactive_graph_graph::component_model".to_string());
+            items.push(accessors);
+        }
+        ModelModule {
+            kind: ModelKind::Component,
+            name: names.module.clone(),
+            content: module_content(&component.description, imports, items),
+        }
+    }
+
+    fn entity_type(&self, entity_type: &EntityType, names: TypeNames) -> ModelModule {
+        let properties = sorted_properties(&entity_type.properties);
+        let mut imports = vec![format!("crate::{}", names.namespace_const())];
+        let mut items = Vec::new();
+        if !properties.is_empty() {
+            imports.push("reactive_graph_graph::properties".to_string());
+            items.push(properties_macro(&names, &properties));
+        }
+        imports.push("reactive_graph_graph::entity_ty".to_string());
+        items.push(format!(
+            "entity_ty!(ENTITY_TYPE_{0}, {1}, ENTITY_TYPE_NAME_{0}, {2:?});",
+            names.upper_snake_case,
+            names.namespace_const(),
+            names.type_name
+        ));
+        if let Some(accessors) = component_model_macro(&format!("{}Entity", names.pascal_case), &properties) {
+            imports.push("reactive_graph_graph::component_model".to_string());
+            items.push(accessors);
+        }
+        let builder_properties = self.builder_properties(&names, &properties, entity_type.components.iter().map(|ty| ty.key().clone()), &mut imports);
+        imports.extend(["reactive_graph_graph::EntityInstance", "reactive_graph_graph::PropertyInstances", "uuid::Uuid"].map(String::from));
+        if !builder_properties.is_empty() {
+            imports.push("reactive_graph_graph::PropertyTypeDefinition".to_string());
+        }
+        items.push(entity_builder(&names, &builder_properties));
+        ModelModule {
+            kind: ModelKind::EntityType,
+            name: names.module.clone(),
+            content: module_content(&entity_type.description, imports, items),
+        }
+    }
+
+    fn relation_type(&self, relation_type: &RelationType, names: TypeNames) -> ModelModule {
+        let properties = sorted_properties(&relation_type.properties);
+        let mut imports = vec![format!("crate::{}", names.namespace_const())];
+        let mut items = Vec::new();
+        if !properties.is_empty() {
+            imports.push("reactive_graph_graph::properties".to_string());
+            items.push(properties_macro(&names, &properties));
+        }
+        imports.push("reactive_graph_graph::relation_ty".to_string());
+        items.push(format!(
+            "relation_ty!(RELATION_TYPE_{0}, {1}, RELATION_TYPE_NAME_{0}, {2:?});",
+            names.upper_snake_case,
+            names.namespace_const(),
+            names.type_name
+        ));
+        if let Some(accessors) = component_model_macro(&format!("{}Relation", names.pascal_case), &properties) {
+            imports.push("reactive_graph_graph::component_model".to_string());
+            items.push(accessors);
+        }
+        let builder_properties = self.builder_properties(&names, &properties, relation_type.components.iter().map(|ty| ty.key().clone()), &mut imports);
+        imports.extend(
+            [
+                "reactive_graph_graph::PropertyInstances",
+                "reactive_graph_graph::RelationInstance",
+                "reactive_graph_graph::RelationInstanceTypeId",
+                "uuid::Uuid",
+            ]
+            .map(String::from),
+        );
+        if !builder_properties.is_empty() {
+            imports.push("reactive_graph_graph::PropertyTypeDefinition".to_string());
+        }
+        items.push(relation_builder(&names, &builder_properties));
+        ModelModule {
+            kind: ModelKind::RelationType,
+            name: names.module.clone(),
+            content: module_content(&relation_type.description, imports, items),
+        }
+    }
+
+    fn flow_type(&self, flow_type: &FlowType, names: TypeNames) -> ModelModule {
+        let imports = vec![format!("crate::{}", names.namespace_const()), "reactive_graph_graph::flow_ty".to_string()];
+        let items = vec![format!(
+            "flow_ty!(FLOW_TYPE_{0}, {1}, FLOW_TYPE_NAME_{0}, {2:?});",
+            names.upper_snake_case,
+            names.namespace_const(),
+            names.type_name
+        )];
+        ModelModule {
+            kind: ModelKind::FlowType,
+            name: names.module.clone(),
+            content: module_content(&flow_type.description, imports, items),
+        }
+    }
+
+    /// Returns the properties which are initialized by the builder: the properties of the
+    /// type itself and the properties of the components, if the components are part of the
+    /// generated model.
+    fn builder_properties<I: Iterator<Item = ComponentTypeId>>(
+        &self,
+        names: &TypeNames,
+        properties: &[PropertyType],
+        components: I,
+        imports: &mut Vec<String>,
+    ) -> Vec<BuilderProperty> {
+        let mut builder_properties: Vec<BuilderProperty> = properties
+            .iter()
+            .map(|property| BuilderProperty::new(&names.properties_enum(), property))
+            .collect();
+        let qualified = qualified_type_names(self.types.components.iter());
+        let mut components: Vec<_> = components.collect();
+        components.sort();
+        for ty in components {
+            let Some(component) = self.types.get_component(&ty) else {
+                continue;
+            };
+            let component_names = TypeNames::new(component, qualified.contains(&component.type_name()));
+            let mut used = false;
+            for property in sorted_properties(&component.properties) {
+                if builder_properties.iter().any(|builder_property| builder_property.name == property.name) {
+                    continue;
+                }
+                builder_properties.push(BuilderProperty::new(&component_names.properties_enum(), &property));
+                used = true;
+            }
+            if used {
+                imports.push(format!("crate::component::{}::{}", component_names.module, component_names.properties_enum()));
+            }
+        }
+        builder_properties
+    }
+}
+
+/// A property which is initialized by a builder.
+struct BuilderProperty {
+    name: String,
+    variant: String,
+    setter: String,
+    data_type: DataType,
+}
+
+impl BuilderProperty {
+    fn new(properties_enum: &str, property: &PropertyType) -> Self {
+        let mut setter = snake_case_ident(&property.name);
+        if RESERVED_BUILDER_METHODS.contains(&setter.as_str()) {
+            setter = format!("{setter}_property");
+        }
+        BuilderProperty {
+            name: property.name.clone(),
+            variant: format!("{properties_enum}::{}", upper_snake_case_ident(&property.name)),
+            setter,
+            data_type: property.data_type,
+        }
+    }
+
+    fn initializer(&self) -> String {
+        format!("                .property({0}.property_name(), {0}.default_value())", self.variant)
+    }
+
+    fn setter(&self, instance: &str) -> String {
+        let (generics, parameter, value) = match self.data_type {
+            DataType::Bool => ("", "bool", "serde_json::json!(value)"),
+            DataType::Number => ("", "f64", "serde_json::json!(value)"),
+            DataType::String => ("<S: Into<String>>", "S", "serde_json::json!(value.into())"),
+            DataType::Array => ("", "Vec<serde_json::Value>", "serde_json::json!(value)"),
+            DataType::Object => ("", "serde_json::Map<String, serde_json::Value>", "serde_json::json!(value)"),
+            DataType::Null | DataType::Any => ("", "serde_json::Value", "value"),
+        };
+        join_lines(vec![
+            format!("    /// Sets the property `{}` of the {instance}.", self.name),
+            format!("    pub fn {}{generics}(mut self, value: {parameter}) -> Self {{", self.setter),
+            format!("        self.properties = self.properties.property({}.property_name(), {value});", self.variant),
+            "        self".to_string(),
+            "    }".to_string(),
+        ])
+    }
+}
+
+fn entity_builder(names: &TypeNames, properties: &[BuilderProperty]) -> String {
+    let builder = format!("{}Builder", names.pascal_case);
+    let mut lines = vec![
+        format!("/// Builds entity instances of the entity type `{}::{}`.", names.namespace, names.type_name),
+        format!("pub struct {builder} {{"),
+        "    id: Uuid,".to_string(),
+        "    properties: PropertyInstances,".to_string(),
+        "}".to_string(),
+        String::new(),
+        format!("impl {builder} {{"),
+        "    /// Creates a builder which initializes the properties with their default values.".to_string(),
+        "    pub fn new() -> Self {".to_string(),
+        format!("        {builder} {{"),
+        "            id: Uuid::new_v4(),".to_string(),
+    ];
+    lines.extend(properties_initializer(properties));
+    lines.extend([
+        "        }".to_string(),
+        "    }".to_string(),
+        String::new(),
+        "    /// Sets the id of the entity instance.".to_string(),
+        "    pub fn id(mut self, id: Uuid) -> Self {".to_string(),
+        "        self.id = id;".to_string(),
+        "        self".to_string(),
+        "    }".to_string(),
+        String::new(),
+    ]);
+    for property in properties {
+        lines.push(property.setter("entity instance"));
+    }
+    lines.extend([
+        "    /// Builds the entity instance.".to_string(),
+        "    pub fn build(self) -> EntityInstance {".to_string(),
+        "        EntityInstance::builder()".to_string(),
+        format!("            .ty(ENTITY_TYPE_{}.clone())", names.upper_snake_case),
+        "            .id(self.id)".to_string(),
+        "            .properties(self.properties)".to_string(),
+        "            .build()".to_string(),
+        "    }".to_string(),
+        "}".to_string(),
+        String::new(),
+        format!("impl Default for {builder} {{"),
+        "    fn default() -> Self {".to_string(),
+        "        Self::new()".to_string(),
+        "    }".to_string(),
+        "}".to_string(),
+    ]);
+    lines.join("\n")
+}
+
+fn relation_builder(names: &TypeNames, properties: &[BuilderProperty]) -> String {
+    let builder = format!("{}Builder", names.pascal_case);
+    let mut lines = vec![
+        format!("/// Builds relation instances of the relation type `{}::{}`.", names.namespace, names.type_name),
+        format!("pub struct {builder} {{"),
+        "    outbound_id: Uuid,".to_string(),
+        "    inbound_id: Uuid,".to_string(),
+        "    properties: PropertyInstances,".to_string(),
+        "}".to_string(),
+        String::new(),
+        format!("impl {builder} {{"),
+        "    /// Creates a builder for a relation instance between the given entity instances. The".to_string(),
+        "    /// properties are initialized with their default values.".to_string(),
+        "    pub fn new(outbound_id: Uuid, inbound_id: Uuid) -> Self {".to_string(),
+        format!("        {builder} {{"),
+        "            outbound_id,".to_string(),
+        "            inbound_id,".to_string(),
+    ];
+    lines.extend(properties_initializer(properties));
+    lines.extend(["        }".to_string(), "    }".to_string(), String::new()]);
+    for property in properties {
+        lines.push(property.setter("relation instance"));
+    }
+    lines.extend([
+        "    /// Builds the relation instance.".to_string(),
+        "    pub fn build(self) -> RelationInstance {".to_string(),
+        format!(
+            "        RelationInstance::new(self.outbound_id, RelationInstanceTypeId::new_unique_id(RELATION_TYPE_{}.clone()), self.inbound_id, self.properties)",
+            names.upper_snake_case
+        ),
+        "    }".to_string(),
+        "}".to_string(),
+    ]);
+    lines.join("\n")
+}
+
+fn properties_initializer(properties: &[BuilderProperty]) -> Vec<String> {
+    if properties.is_empty() {
+        return vec!["            properties: PropertyInstances::new(),".to_string()];
+    }
+    let mut lines = vec!["            properties: PropertyInstances::new()".to_string()];
+    lines.extend(properties.iter().map(|property| property.initializer()));
+    if let Some(last) = lines.last_mut() {
+        last.push(',');
+    }
+    lines
+}
+
+/// Generates the properties enum using the `properties!` macro.
+fn properties_macro(names: &TypeNames, properties: &[PropertyType]) -> String {
+    let properties: Vec<String> = properties
+        .iter()
+        .map(|property| {
+            format!(
+                "({}, {:?}, {})",
+                upper_snake_case_ident(&property.name),
+                property.name,
+                default_value_literal(&property.data_type.default_value())
+            )
+        })
+        .collect();
+    if properties.len() == 1 {
+        format!("properties!({}, {});", names.properties_enum(), properties[0])
+    } else {
+        format!("properties!(\n    {},\n    {}\n);", names.properties_enum(), properties.join(",\n    "))
+    }
+}
+
+/// Generates the typed getters and setters using the `component_model!` macro.
+///
+/// Properties which names are not valid identifiers are skipped because the accessors are
+/// named after the property.
+fn component_model_macro(trait_name: &str, properties: &[PropertyType]) -> Option<String> {
+    let accessors: Vec<String> = properties
+        .iter()
+        .filter(|property| is_identifier(&property.name))
+        .map(|property| format!("data {} {}", property.name, accessor_data_type(&property.data_type)))
+        .collect();
+    if accessors.is_empty() {
+        return None;
+    }
+    Some(format!("component_model!(\n    {trait_name},\n    {},\n);", accessors.join(",\n    ")))
+}
+
+fn accessor_data_type(data_type: &DataType) -> &'static str {
+    match data_type {
+        DataType::Bool => "bool",
+        DataType::Number => "f64",
+        DataType::String => "string",
+        DataType::Array => "array",
+        DataType::Object => "object",
+        DataType::Null | DataType::Any => "value",
+    }
+}
+
+fn default_value_literal(value: &Value) -> String {
+    match value {
+        Value::Bool(_) | Value::Number(_) => value.to_string(),
+        Value::String(s) => format!("{s:?}"),
+        Value::Null | Value::Array(_) | Value::Object(_) => format!("serde_json::json!({value})"),
+    }
+}
+
+fn namespace_const(namespace: &str) -> String {
+    format!("NAMESPACE_{}", upper_snake_case_ident(namespace))
+}
+
+/// Returns the properties sorted by name.
+fn sorted_properties(properties: &PropertyTypes) -> Vec<PropertyType> {
+    let mut properties = properties.to_vec();
+    properties.sort_by(|a, b| a.name.cmp(&b.name));
+    properties
+}
+
+/// Returns the type names which exists in multiple namespaces. These types are qualified
+/// with their namespace to prevent name clashes.
+fn qualified_type_names<'t, T: NamespacedTypeGetter + 't, I: Iterator<Item = &'t T>>(types: I) -> Vec<String> {
+    let mut namespaces_by_type_name: HashMap<String, Vec<String>> = HashMap::new();
+    for ty in types {
+        namespaces_by_type_name.entry(ty.type_name()).or_default().push(ty.namespace());
+    }
+    namespaces_by_type_name
+        .into_iter()
+        .filter(|(_, namespaces)| namespaces.len() > 1)
+        .map(|(type_name, _)| type_name)
+        .collect()
+}
+
+fn module_content(description: &str, mut imports: Vec<String>, items: Vec<String>) -> String {
+    imports.sort();
+    imports.dedup();
+    let mut lines = Vec::new();
+    for line in description.lines().filter(|line| !line.trim().is_empty()) {
+        lines.push(format!("//! {}", line.trim()));
+    }
+    if !lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines.extend(imports.into_iter().map(|import| format!("use {import};")));
+    for item in items {
+        lines.push(String::new());
+        lines.push(item);
+    }
+    join_lines(lines)
+}
@@ -0,0 +1,181 @@
+//! Generates the rust model of a plugin from the component, entity type, relation type and
+//! flow type definitions.
+//!
+//! The generated model contains the namespace constants, the type id constants, the property
+//! enums, the typed accessors and the builders. The generated code uses the macros of
+//! `reactive_graph_graph`, so the crate using the model must depend on `reactive-graph-graph`,
+//! `serde_json`, `strum_macros`, `paste` and `uuid`.
+//!
+//! The model can be generated from a build script:
+//!
+//! ```no_run
+//! // build.rs
+//! reactive_graph_type_system_model_generator::generate_model("types").expect("Failed to generate the model");
+//! ```
+//!
+//! and included at the root of the crate:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/model.rs"));
+//! ```
+
+use std::env;
+use std::path::Path;
+use std::path::PathBuf;
+
+pub use error::ModelGeneratorError;
+pub use generator::generate;
+pub use model::*;
+pub use types::*;
+
+pub mod error;
+pub mod generator;
+pub mod model;
+pub mod names;
+pub mod types;
+
+/// The name of the file which is generated by the build script helper.
+pub const MODEL_FILE_NAME: &str = "model.rs";
+
+/// Reads the type definitions from the given types directory and generates the model.
+pub fn generate_from_directory<P: AsRef<Path>>(types_directory: P) -> Result<GeneratedModel, ModelGeneratorError> {
+    let types = ModelTypes::from_directory(types_directory)?;
+    Ok(generate(&types))
+}
+
+/// Generates the model from a build script.
+///
+/// The model is written as single file into `OUT_DIR`. The build script is rerun if the type
+/// definitions have been changed.
+pub fn generate_model<P: AsRef<Path>>(types_directory: P) -> Result<PathBuf, ModelGeneratorError> {
+    let out_dir = env::var_os("OUT_DIR").ok_or(ModelGeneratorError::OutDirNotSet)?;
+    println!("cargo:rerun-if-changed={}", types_directory.as_ref().display());
+    let path = PathBuf::from(out_dir).join(MODEL_FILE_NAME);
+    generate_from_directory(types_directory)?.write_to_file(&path)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use reactive_graph_graph::Component;
+    use reactive_graph_graph::DataType;
+    use reactive_graph_graph::EntityType;
+    use reactive_graph_graph::EntityTypeId;
+    use reactive_graph_graph::PropertyType;
+    use reactive_graph_graph::PropertyTypes;
+    use reactive_graph_graph::RelationType;
+
+    use crate::ModelTypes;
+    use crate::generate;
+    use crate::generate_from_directory;
+
+    fn runtime_types_directory() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../impl/types")
+    }
+
+    #[test]
+    fn test_generate_runtime_model() {
+        let model = generate_from_directory(runtime_types_directory()).expect("Failed to generate the model");
+        assert_eq!(vec!["pub const NAMESPACE_CORE: &str = \"core\";".to_string()], model.namespaces);
+
+        let action = model.modules.iter().find(|module| module.name == "action").expect("Missing component action");
+        assert!(action.content.contains("properties!(ActionProperties, (TRIGGER, \"trigger\", false));"));
+        assert!(
+            action
+                .content
+                .contains("component_ty!(COMPONENT_ACTION, NAMESPACE_CORE, COMPONENT_NAME_ACTION, \"action\");")
+        );
+        assert!(action.content.contains("data trigger bool"));
+
+        let shutdown = model
+            .modules
+            .iter()
+            .find(|module| module.name == "shutdown")
+            .expect("Missing entity type shutdown");
+        assert!(shutdown.content.contains("properties!(ShutdownProperties, (DELAY, \"delay\", 0));"));
+        assert!(
+            shutdown
+                .content
+                .contains("entity_ty!(ENTITY_TYPE_SHUTDOWN, NAMESPACE_CORE, ENTITY_TYPE_NAME_SHUTDOWN, \"shutdown\");")
+        );
+        assert!(shutdown.content.contains("pub struct ShutdownBuilder"));
+        // The properties of the components are initialized by the builder
+        assert!(shutdown.content.contains("use crate::component::action::ActionProperties;"));
+        assert!(
+            shutdown
+                .content
+                .contains(".property(ActionProperties::TRIGGER.property_name(), ActionProperties::TRIGGER.default_value())")
+        );
+    }
+
+    #[test]
+    fn test_generate_files() {
+        let model = generate_from_directory(runtime_types_directory()).expect("Failed to generate the model");
+        let files = model.to_files();
+        let paths: Vec<String> = files.iter().map(|(path, _)| path.to_string_lossy().to_string()).collect();
+        assert!(paths.contains(&"lib.rs".to_string()));
+        assert!(paths.contains(&"component/mod.rs".to_string()));
+        assert!(paths.contains(&"component/labeled.rs".to_string()));
+        assert!(paths.contains(&"entity/system_event.rs".to_string()));
+        let (_, lib) = files.iter().find(|(path, _)| path.ends_with("lib.rs")).unwrap();
+        assert!(lib.contains("pub mod component;"));
+        assert!(lib.contains("pub use entity::*;"));
+        assert!(!lib.contains("pub mod relation;"));
+
+        let single_file = model.to_single_file();
+        assert!(single_file.contains("pub mod component {"));
+        assert!(single_file.contains("    pub mod action {"));
+    }
+
+    #[test]
+    fn test_generate_relation_type_and_qualified_names() {
+        let types = ModelTypes {
+            components: vec![
+                Component::new_without_extensions(("a", "named"), "", PropertyTypes::new().property(PropertyType::string("name"))),
+                Component::new_without_extensions(("b", "named"), "", PropertyTypes::new().property(PropertyType::new("type", DataType::Number))),
+            ],
+            entity_types: vec![EntityType::new(
+                ("a", "item"),
+                "",
+                vec![("a", "named").into()],
+                PropertyTypes::new(),
+                Vec::new(),
+            )],
+            relation_types: vec![RelationType::new(
+                EntityTypeId::new_from_type("a", "item"),
+                ("a", "contains"),
+                EntityTypeId::new_from_type("a", "item"),
+                "Contains items",
+                Vec::new(),
+                PropertyTypes::new().property(PropertyType::array("index")),
+                Vec::new(),
+            )],
+            flow_types: Vec::new(),
+        };
+        let model = generate(&types);
+        assert_eq!(2, model.namespaces.len());
+        let names: Vec<&str> = model.modules.iter().map(|module| module.name.as_str()).collect();
+        assert_eq!(vec!["a_named", "b_named", "item", "contains"], names);
+
+        let b_named = &model.modules[1];
+        // Properties which are keywords are available by the enum and the builder, but not by the accessors
+        assert!(b_named.content.contains("(TYPE, \"type\", 0)"));
+        assert!(!b_named.content.contains("component_model!"));
+
+        let item = &model.modules[2];
+        assert!(item.content.contains("use crate::component::a_named::ANamedProperties;"));
+        assert!(item.content.contains("pub fn name<S: Into<String>>(mut self, value: S) -> Self {"));
+
+        let contains = &model.modules[3];
+        assert!(contains.content.starts_with("//! Contains items"));
+        assert!(contains.content.contains("(INDEX, \"index\", serde_json::json!([]))"));
+        assert!(contains.content.contains("pub fn new(outbound_id: Uuid, inbound_id: Uuid) -> Self {"));
+        assert!(
+            contains
+                .content
+                .contains("RelationInstanceTypeId::new_unique_id(RELATION_TYPE_CONTAINS.clone())")
+        );
+    }
+}
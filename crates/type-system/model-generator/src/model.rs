@@ -0,0 +1,179 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::error::ModelGeneratorError;
+
+/// The header of the generated files.
+pub const GENERATED_HEADER: &str = "// This file has been generated by the reactive graph model generator. Do not edit it manually.";
+
+/// The kind of the types which are generated into a module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ModelKind {
+    Component,
+    EntityType,
+    RelationType,
+    FlowType,
+}
+
+impl ModelKind {
+    /// Returns all kinds in the order the modules are generated.
+    pub fn all() -> [ModelKind; 4] {
+        [ModelKind::Component, ModelKind::EntityType, ModelKind::RelationType, ModelKind::FlowType]
+    }
+
+    /// Returns the name of the module containing the types of this kind.
+    pub fn module_name(&self) -> &'static str {
+        match self {
+            ModelKind::Component => "component",
+            ModelKind::EntityType => "entity",
+            ModelKind::RelationType => "relation",
+            ModelKind::FlowType => "flow",
+        }
+    }
+}
+
+/// The generated code of a single type.
+#[derive(Debug, Clone)]
+pub struct ModelModule {
+    /// The kind of the type.
+    pub kind: ModelKind,
+
+    /// The name of the module.
+    pub name: String,
+
+    /// The generated code.
+    pub content: String,
+}
+
+/// The generated model.
+///
+/// The root of the model defines the namespace constants and re-exports the types. All paths
+/// are relative to the root, so the model must be placed at the root of a crate.
+#[derive(Debug, Clone, Default)]
+pub struct GeneratedModel {
+    /// The namespace constants.
+    pub namespaces: Vec<String>,
+
+    /// The modules of the types.
+    pub modules: Vec<ModelModule>,
+}
+
+impl GeneratedModel {
+    fn kinds(&self) -> Vec<ModelKind> {
+        ModelKind::all()
+            .into_iter()
+            .filter(|kind| self.modules.iter().any(|module| module.kind == *kind))
+            .collect()
+    }
+
+    fn modules_of_kind(&self, kind: ModelKind) -> impl Iterator<Item = &ModelModule> {
+        self.modules.iter().filter(move |module| module.kind == kind)
+    }
+
+    fn root_items(&self, kinds: &[ModelKind]) -> Vec<String> {
+        let mut lines = Vec::new();
+        for kind in kinds {
+            lines.push(format!("pub use {}::*;", kind.module_name()));
+        }
+        if !kinds.is_empty() {
+            lines.push(String::new());
+        }
+        lines.extend(self.namespaces.iter().cloned());
+        lines
+    }
+
+    /// Returns the files of the model as crate layout. The root file is `lib.rs` and each
+    /// kind of types gets its own directory.
+    pub fn to_files(&self) -> Vec<(PathBuf, String)> {
+        let kinds = self.kinds();
+        let mut files = Vec::new();
+        let mut lib = vec![GENERATED_HEADER.to_string(), String::new()];
+        lib.extend(self.root_items(&kinds));
+        if !kinds.is_empty() {
+            lib.push(String::new());
+            for kind in &kinds {
+                lib.push(format!("pub mod {};", kind.module_name()));
+            }
+        }
+        files.push((PathBuf::from("lib.rs"), join_lines(lib)));
+        for kind in kinds {
+            let mut mod_rs = vec![GENERATED_HEADER.to_string(), String::new()];
+            for module in self.modules_of_kind(kind) {
+                mod_rs.push(format!("pub use {}::*;", module.name));
+            }
+            mod_rs.push(String::new());
+            for module in self.modules_of_kind(kind) {
+                mod_rs.push(format!("pub mod {};", module.name));
+            }
+            files.push((PathBuf::from(kind.module_name()).join("mod.rs"), join_lines(mod_rs)));
+            for module in self.modules_of_kind(kind) {
+                let file_name = format!("{}.rs", module.name.trim_start_matches("r#"));
+                files.push((PathBuf::from(kind.module_name()).join(file_name), format!("{GENERATED_HEADER}\n\n{}", module.content)));
+            }
+        }
+        files
+    }
+
+    /// Returns the model as a single file with inline modules. The file is meant to be
+    /// included at the root of a crate.
+    pub fn to_single_file(&self) -> String {
+        let kinds = self.kinds();
+        let mut lines = vec![GENERATED_HEADER.to_string(), String::new()];
+        lines.extend(self.root_items(&kinds));
+        for kind in kinds {
+            lines.push(String::new());
+            lines.push(format!("pub mod {} {{", kind.module_name()));
+            for module in self.modules_of_kind(kind) {
+                lines.push(format!("    pub use {}::*;", module.name));
+            }
+            for module in self.modules_of_kind(kind) {
+                lines.push(String::new());
+                lines.push(format!("    pub mod {} {{", module.name));
+                for line in module.content.lines() {
+                    lines.push(indent(line, 2));
+                }
+                lines.push("    }".to_string());
+            }
+            lines.push("}".to_string());
+        }
+        join_lines(lines)
+    }
+
+    /// Writes the model in crate layout into the given directory.
+    pub fn write_to_directory<P: AsRef<Path>>(&self, directory: P) -> Result<Vec<PathBuf>, ModelGeneratorError> {
+        let mut paths = Vec::new();
+        for (path, content) in self.to_files() {
+            let path = directory.as_ref().join(path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(|e| ModelGeneratorError::WriteModel(parent.to_path_buf(), e))?;
+            }
+            fs::write(&path, content).map_err(|e| ModelGeneratorError::WriteModel(path.clone(), e))?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    /// Writes the model as a single file.
+    pub fn write_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), ModelGeneratorError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| ModelGeneratorError::WriteModel(parent.to_path_buf(), e))?;
+        }
+        fs::write(path, self.to_single_file()).map_err(|e| ModelGeneratorError::WriteModel(path.to_path_buf(), e))
+    }
+}
+
+fn indent(line: &str, level: usize) -> String {
+    if line.is_empty() {
+        String::new()
+    } else {
+        format!("{}{line}", "    ".repeat(level))
+    }
+}
+
+pub(crate) fn join_lines(lines: Vec<String>) -> String {
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
+}
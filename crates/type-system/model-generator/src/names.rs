@@ -0,0 +1,91 @@
+const KEYWORDS: [&str; 51] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop",
+    "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "abstract", "become", "box", "do", "final", "gen", "macro", "override", "priv", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Returns true, if the given name can be used as an identifier without escaping.
+pub fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    name != "_" && chars.all(|c| c.is_ascii_alphanumeric() || c == '_') && !KEYWORDS.contains(&name)
+}
+
+/// Converts the given name into a snake case identifier which can be used as module, function
+/// or parameter name.
+pub fn snake_case_ident(name: &str) -> String {
+    let mut ident = words(name).join("_").to_lowercase();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    if KEYWORDS.contains(&ident.as_str()) {
+        ident.insert_str(0, "r#");
+    }
+    ident
+}
+
+/// Converts the given name into an upper snake case identifier which can be used as
+/// name of a constant or an enum variant.
+pub fn upper_snake_case_ident(name: &str) -> String {
+    let mut ident = words(name).join("_").to_uppercase();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    ident
+}
+
+/// Converts the given name into a pascal case identifier which can be used as name of a
+/// struct or a trait.
+pub fn pascal_case_ident(name: &str) -> String {
+    let mut ident: String = words(name)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    ident
+}
+
+fn words(name: &str) -> Vec<&str> {
+    name.split(|c: char| !c.is_ascii_alphanumeric()).filter(|word| !word.is_empty()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::names::is_identifier;
+    use crate::names::pascal_case_ident;
+    use crate::names::snake_case_ident;
+    use crate::names::upper_snake_case_ident;
+
+    #[test]
+    fn test_is_identifier() {
+        assert!(is_identifier("delay"));
+        assert!(is_identifier("cmd_result"));
+        assert!(!is_identifier("type"));
+        assert!(!is_identifier("first-name"));
+        assert!(!is_identifier("1st"));
+        assert!(!is_identifier("_"));
+        assert!(!is_identifier(""));
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!("system_event", snake_case_ident("system_event"));
+        assert_eq!("r#type", snake_case_ident("type"));
+        assert_eq!("first_name", snake_case_ident("first-name"));
+        assert_eq!("SYSTEM_EVENT", upper_snake_case_ident("system_event"));
+        assert_eq!("_1ST", upper_snake_case_ident("1st"));
+        assert_eq!("SystemEvent", pascal_case_ident("system_event"));
+        assert_eq!("GenericFlow", pascal_case_ident("generic-flow"));
+    }
+}
@@ -0,0 +1,110 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::de::DeserializeOwned;
+
+use reactive_graph_graph::Component;
+use reactive_graph_graph::ComponentTypeId;
+use reactive_graph_graph::EntityType;
+use reactive_graph_graph::FlowType;
+use reactive_graph_graph::NamespacedTypeGetter;
+use reactive_graph_graph::RelationType;
+use reactive_graph_serde::format::SerializationFormat;
+
+use crate::error::ModelGeneratorError;
+
+/// The directory containing the components, relative to the types directory.
+pub const COMPONENTS_DIRECTORY: &str = "components";
+
+/// The directory containing the entity types, relative to the types directory.
+pub const ENTITY_TYPES_DIRECTORY: &str = "entities";
+
+/// The directory containing the relation types, relative to the types directory.
+pub const RELATION_TYPES_DIRECTORY: &str = "relations";
+
+/// The directory containing the flow types, relative to the types directory.
+pub const FLOW_TYPES_DIRECTORY: &str = "flows";
+
+/// The type definitions a model is generated from.
+#[derive(Debug, Default)]
+pub struct ModelTypes {
+    pub components: Vec<Component>,
+    pub entity_types: Vec<EntityType>,
+    pub relation_types: Vec<RelationType>,
+    pub flow_types: Vec<FlowType>,
+}
+
+impl ModelTypes {
+    /// Reads the type definitions from the given types directory.
+    ///
+    /// The directory uses the same layout as the type providers of the plugins: the
+    /// subdirectories `components`, `entities`, `relations` and `flows` contains one type
+    /// definition per file. Files with an unsupported file extension are ignored.
+    pub fn from_directory<P: AsRef<Path>>(types_directory: P) -> Result<ModelTypes, ModelGeneratorError> {
+        let types_directory = types_directory.as_ref();
+        if !types_directory.is_dir() {
+            return Err(ModelGeneratorError::TypesDirectoryNotFound(types_directory.to_path_buf()));
+        }
+        let mut types = ModelTypes {
+            components: read_types(&types_directory.join(COMPONENTS_DIRECTORY))?,
+            entity_types: read_types(&types_directory.join(ENTITY_TYPES_DIRECTORY))?,
+            relation_types: read_types(&types_directory.join(RELATION_TYPES_DIRECTORY))?,
+            flow_types: read_types(&types_directory.join(FLOW_TYPES_DIRECTORY))?,
+        };
+        types.components.sort_by(|a, b| a.ty.cmp(&b.ty));
+        types.entity_types.sort_by(|a, b| a.ty.cmp(&b.ty));
+        types.relation_types.sort_by(|a, b| a.ty.cmp(&b.ty));
+        types.flow_types.sort_by(|a, b| a.ty.cmp(&b.ty));
+        Ok(types)
+    }
+
+    /// Returns the component with the given type id.
+    pub fn get_component(&self, ty: &ComponentTypeId) -> Option<&Component> {
+        self.components.iter().find(|component| &component.ty == ty)
+    }
+
+    /// Returns the sorted and distinct namespaces of all types.
+    pub fn namespaces(&self) -> Vec<String> {
+        let mut namespaces: Vec<String> = self
+            .components
+            .iter()
+            .map(|component| component.namespace())
+            .chain(self.entity_types.iter().map(|entity_type| entity_type.namespace()))
+            .chain(self.relation_types.iter().map(|relation_type| relation_type.namespace()))
+            .chain(self.flow_types.iter().map(|flow_type| flow_type.namespace()))
+            .collect();
+        namespaces.sort();
+        namespaces.dedup();
+        namespaces
+    }
+
+    /// Returns true, if no type definitions have been found.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty() && self.entity_types.is_empty() && self.relation_types.is_empty() && self.flow_types.is_empty()
+    }
+}
+
+fn read_types<T: DeserializeOwned>(directory: &Path) -> Result<Vec<T>, ModelGeneratorError> {
+    if !directory.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut paths: Vec<PathBuf> = fs::read_dir(directory)
+        .map_err(|e| ModelGeneratorError::ReadTypeDefinition(directory.to_path_buf(), e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .collect();
+    paths.sort();
+    let mut types = Vec::new();
+    for path in paths {
+        let Ok(format) = SerializationFormat::from_path(&path) else {
+            continue;
+        };
+        let content = fs::read_to_string(&path).map_err(|e| ModelGeneratorError::ReadTypeDefinition(path.clone(), e))?;
+        let ty = format
+            .deserialize(&content)
+            .map_err(|e| ModelGeneratorError::DeserializeTypeDefinition(path.clone(), e))?;
+        types.push(ty);
+    }
+    Ok(types)
+}